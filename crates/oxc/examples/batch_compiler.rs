@@ -0,0 +1,26 @@
+#![allow(clippy::print_stdout)]
+
+use std::{env, path::Path};
+
+use oxc::{BatchCompiler, CompilerOptions};
+
+// Instruction:
+// 1. create a `src` directory with some files
+// 2. run `cargo run -p oxc --example batch_compiler --features="full" -- src dist`
+
+fn main() {
+    let src = env::args().nth(1).unwrap_or_else(|| "src".to_string());
+    let out_dir = env::args().nth(2).unwrap_or_else(|| "dist".to_string());
+
+    let options = CompilerOptions { sourcemap: true, ..CompilerOptions::default() };
+    let ret = BatchCompiler::new(options).with_out_dir(out_dir).compile_dir(Path::new(&src));
+
+    for file in &ret.files {
+        if let Some(output_path) = &file.output_path {
+            println!("{} -> {}", file.path.display(), output_path.display());
+        }
+    }
+    for (_, error) in ret.errors() {
+        println!("{error:?}");
+    }
+}
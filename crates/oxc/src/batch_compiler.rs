@@ -0,0 +1,340 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use rayon::prelude::*;
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{CodegenOptions, CodegenReturn};
+use oxc_diagnostics::{Error, NamedSource, OxcDiagnostic};
+use oxc_isolated_declarations::IsolatedDeclarationsOptions;
+use oxc_mangler::MangleOptions;
+use oxc_minifier::CompressOptions;
+use oxc_parser::ParseOptions;
+use oxc_sourcemap::SourceMap;
+use oxc_span::SourceType;
+use oxc_transformer::{InjectGlobalVariablesConfig, ReplaceGlobalDefinesConfig, TransformOptions};
+
+use crate::CompilerInterface;
+
+/// Options shared by all files compiled by a [`BatchCompiler`].
+///
+/// Each pass is skipped if its options are `None`.
+#[derive(Clone)]
+pub struct CompilerOptions {
+    pub parse: ParseOptions,
+    /// Emit `.d.ts` files.
+    pub isolated_declarations: Option<IsolatedDeclarationsOptions>,
+    pub transform: Option<TransformOptions>,
+    pub define: Option<ReplaceGlobalDefinesConfig>,
+    pub inject: Option<InjectGlobalVariablesConfig>,
+    pub compress: Option<CompressOptions>,
+    pub mangle: Option<MangleOptions>,
+    pub codegen: Option<CodegenOptions>,
+    /// Emit `.map` files.
+    pub sourcemap: bool,
+}
+
+impl Default for CompilerOptions {
+    /// Transform and print, same as [`crate::Compiler`].
+    fn default() -> Self {
+        Self {
+            parse: ParseOptions::default(),
+            isolated_declarations: None,
+            transform: Some(TransformOptions::default()),
+            define: None,
+            inject: None,
+            compress: None,
+            mangle: None,
+            codegen: Some(CodegenOptions::default()),
+            sourcemap: false,
+        }
+    }
+}
+
+/// Compile many files in parallel, with the same [`CompilerOptions`].
+///
+/// Each thread reuses one [`Allocator`] for all the files it compiles.
+///
+/// ```ignore
+/// let ret = BatchCompiler::new(CompilerOptions::default())
+///     .with_out_dir("dist")
+///     .compile_dir(Path::new("src"));
+/// for (path, error) in ret.errors() {
+///     eprintln!("{path:?}: {error:?}");
+/// }
+/// ```
+pub struct BatchCompiler {
+    options: CompilerOptions,
+    out_dir: Option<PathBuf>,
+    root_dir: Option<PathBuf>,
+}
+
+/// Result of compiling a single file with a [`BatchCompiler`].
+pub struct CompiledFile {
+    /// Path of the source file.
+    pub path: PathBuf,
+
+    /// Path the output was written to.
+    ///
+    /// `None` if there is no out dir, or compilation failed.
+    pub output_path: Option<PathBuf>,
+
+    /// The printed code, if there is no out dir.
+    pub code: Option<String>,
+
+    /// The source map, if there is no out dir and [`CompilerOptions::sourcemap`] is enabled.
+    pub map: Option<SourceMap>,
+
+    /// The printed `.d.ts` declarations, if there is no out dir and
+    /// [`CompilerOptions::isolated_declarations`] is enabled.
+    pub declaration: Option<String>,
+
+    /// Errors of all passes, and of reading and writing files.
+    /// Labels refer to the source text of the file.
+    pub errors: Vec<Error>,
+}
+
+pub struct BatchCompilerReturn {
+    /// In the order the files were given, or sorted by path for directories.
+    pub files: Vec<CompiledFile>,
+}
+
+impl BatchCompilerReturn {
+    pub fn has_errors(&self) -> bool {
+        self.files.iter().any(|file| !file.errors.is_empty())
+    }
+
+    /// Errors of all files, with the path of the file they occurred in.
+    pub fn errors(&self) -> impl Iterator<Item = (&Path, &Error)> {
+        self.files
+            .iter()
+            .flat_map(|file| file.errors.iter().map(|error| (file.path.as_path(), error)))
+    }
+}
+
+impl BatchCompiler {
+    pub fn new(options: CompilerOptions) -> Self {
+        Self { options, out_dir: None, root_dir: None }
+    }
+
+    /// Write outputs to `out_dir`, instead of returning them in [`CompiledFile`].
+    ///
+    /// `file.ts` is written to `file.js`, with `file.js.map` and `file.d.ts` if enabled.
+    #[must_use]
+    pub fn with_out_dir<P: Into<PathBuf>>(mut self, out_dir: P) -> Self {
+        self.out_dir = Some(out_dir.into());
+        self
+    }
+
+    /// Directory structure of files under `root_dir` is kept in the out dir.
+    ///
+    /// Defaults to the directory passed to [`BatchCompiler::compile_dir`].
+    /// Files outside of it are written to the root of the out dir.
+    #[must_use]
+    pub fn with_root_dir<P: Into<PathBuf>>(mut self, root_dir: P) -> Self {
+        self.root_dir = Some(root_dir.into());
+        self
+    }
+
+    /// Compile all JavaScript and TypeScript files in `dir`, skipping `.d.ts` files,
+    /// and files ignored by `.gitignore` and `.ignore`.
+    pub fn compile_dir(&self, dir: &Path) -> BatchCompilerReturn {
+        let mut paths = ignore::WalkBuilder::new(dir)
+            .build()
+            .filter_map(Result::ok)
+            .filter(|entry| entry.file_type().is_some_and(|file_type| !file_type.is_dir()))
+            .map(ignore::DirEntry::into_path)
+            .filter(|path| {
+                SourceType::from_path(path)
+                    .is_ok_and(|source_type| !source_type.is_typescript_definition())
+            })
+            .collect::<Vec<_>>();
+        paths.sort_unstable();
+        let root_dir = self.root_dir.as_deref().unwrap_or(dir);
+        self.compile(&paths, root_dir)
+    }
+
+    /// Compile `paths`.
+    pub fn compile_files(&self, paths: &[PathBuf]) -> BatchCompilerReturn {
+        self.compile(paths, self.root_dir.as_deref().unwrap_or(Path::new("")))
+    }
+
+    fn compile(&self, paths: &[PathBuf], root_dir: &Path) -> BatchCompilerReturn {
+        let files = paths
+            .par_iter()
+            .map_init(Allocator::default, |allocator, path| {
+                allocator.reset();
+                self.compile_file(allocator, path, root_dir)
+            })
+            .collect();
+        BatchCompilerReturn { files }
+    }
+
+    fn compile_file(&self, allocator: &Allocator, path: &Path, root_dir: &Path) -> CompiledFile {
+        let mut file = CompiledFile {
+            path: path.to_path_buf(),
+            output_path: None,
+            code: None,
+            map: None,
+            declaration: None,
+            errors: vec![],
+        };
+
+        let source_text = match fs::read_to_string(path) {
+            Ok(source_text) => source_text,
+            Err(err) => {
+                let error =
+                    OxcDiagnostic::error(format!("Failed to read {}: {err}", path.display()));
+                file.errors.push(error.into());
+                return file;
+            }
+        };
+        let source_type = match SourceType::from_path(path) {
+            Ok(source_type) => source_type,
+            Err(err) => {
+                file.errors.push(OxcDiagnostic::error(err.to_string()).into());
+                return file;
+            }
+        };
+
+        let mut compiler = FileCompiler::new(&self.options);
+        compiler.compile_with_allocator(allocator, &source_text, source_type, path);
+
+        if !compiler.errors.is_empty() {
+            let source = Arc::new(NamedSource::new(path.to_string_lossy(), source_text));
+            file.errors = compiler
+                .errors
+                .into_iter()
+                .map(|error| error.with_source_code(Arc::clone(&source)))
+                .collect();
+            return file;
+        }
+
+        let Some(out_dir) = &self.out_dir else {
+            file.code = compiler.code;
+            file.map = compiler.map;
+            file.declaration = compiler.declaration;
+            return file;
+        };
+
+        let relative_path = path.strip_prefix(root_dir).ok().filter(|path| path.is_relative());
+        let output_path = match relative_path {
+            Some(relative_path) => out_dir.join(relative_path),
+            None => out_dir.join(path.file_name().unwrap_or_default()),
+        };
+        let output_path = output_path.with_extension(output_extensions(path).0);
+        if let Err(err) = write_outputs(&output_path, compiler) {
+            let error =
+                OxcDiagnostic::error(format!("Failed to write {}: {err}", output_path.display()));
+            file.errors.push(error.into());
+            return file;
+        }
+        file.output_path = Some(output_path);
+        file
+    }
+}
+
+/// Extensions of the output and `.d.ts` files, keeping the module kind of `.mts`, `.cts`,
+/// `.mjs` and `.cjs` files.
+fn output_extensions(path: &Path) -> (&'static str, &'static str) {
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some("mts" | "mjs") => ("mjs", "d.mts"),
+        Some("cts" | "cjs") => ("cjs", "d.cts"),
+        _ => ("js", "d.ts"),
+    }
+}
+
+/// Write the printed code to `output_path`, with its source map and declarations.
+fn write_outputs(output_path: &Path, compiler: FileCompiler) -> std::io::Result<()> {
+    if let Some(dir) = output_path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let (extension, declaration_extension) = output_extensions(output_path);
+    if let Some(mut code) = compiler.code {
+        if let Some(mut map) = compiler.map {
+            let file_name = output_path.file_name().unwrap_or_default().to_string_lossy();
+            map.set_file(&file_name);
+            fs::write(
+                output_path.with_extension(format!("{extension}.map")),
+                map.to_json_string(),
+            )?;
+            code.push_str("//# sourceMappingURL=");
+            code.push_str(&file_name);
+            code.push_str(".map\n");
+        }
+        fs::write(output_path, code)?;
+    }
+    if let Some(declaration) = compiler.declaration {
+        fs::write(output_path.with_extension(declaration_extension), declaration)?;
+    }
+    Ok(())
+}
+
+/// Compiles a single file with shared [`CompilerOptions`].
+struct FileCompiler<'o> {
+    options: &'o CompilerOptions,
+    errors: Vec<OxcDiagnostic>,
+    code: Option<String>,
+    map: Option<SourceMap>,
+    declaration: Option<String>,
+}
+
+impl<'o> FileCompiler<'o> {
+    fn new(options: &'o CompilerOptions) -> Self {
+        Self { options, errors: vec![], code: None, map: None, declaration: None }
+    }
+}
+
+impl<'o> CompilerInterface for FileCompiler<'o> {
+    fn handle_errors(&mut self, errors: Vec<OxcDiagnostic>) {
+        self.errors.extend(errors);
+    }
+
+    fn enable_sourcemap(&self) -> bool {
+        self.options.sourcemap
+    }
+
+    fn parse_options(&self) -> ParseOptions {
+        self.options.parse
+    }
+
+    fn isolated_declaration_options(&self) -> Option<IsolatedDeclarationsOptions> {
+        self.options.isolated_declarations
+    }
+
+    fn transform_options(&self) -> Option<TransformOptions> {
+        self.options.transform.clone()
+    }
+
+    fn define_options(&self) -> Option<ReplaceGlobalDefinesConfig> {
+        self.options.define.clone()
+    }
+
+    fn inject_options(&self) -> Option<InjectGlobalVariablesConfig> {
+        self.options.inject.clone()
+    }
+
+    fn compress_options(&self) -> Option<CompressOptions> {
+        self.options.compress
+    }
+
+    fn mangle_options(&self) -> Option<MangleOptions> {
+        self.options.mangle.clone()
+    }
+
+    fn codegen_options(&self) -> Option<CodegenOptions> {
+        self.options.codegen.clone()
+    }
+
+    fn after_isolated_declarations(&mut self, ret: CodegenReturn) {
+        self.declaration = Some(ret.code);
+    }
+
+    fn after_codegen(&mut self, ret: CodegenReturn) {
+        self.code = Some(ret.code);
+        self.map = ret.map;
+    }
+}
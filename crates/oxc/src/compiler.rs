@@ -123,10 +123,21 @@ pub trait CompilerInterface {
 
     fn compile(&mut self, source_text: &str, source_type: SourceType, source_path: &Path) {
         let allocator = Allocator::default();
+        self.compile_with_allocator(&allocator, source_text, source_type, source_path);
+    }
 
+    /// Same as [`CompilerInterface::compile`], but allocates the AST in `allocator`,
+    /// so it can be reset and reused between files.
+    fn compile_with_allocator(
+        &mut self,
+        allocator: &Allocator,
+        source_text: &str,
+        source_type: SourceType,
+        source_path: &Path,
+    ) {
         /* Parse */
 
-        let mut parser_return = self.parse(&allocator, source_text, source_type);
+        let mut parser_return = self.parse(allocator, source_text, source_type);
         if self.after_parse(&mut parser_return).is_break() {
             return;
         }
@@ -138,7 +149,7 @@ pub trait CompilerInterface {
 
         /* Isolated Declarations */
         if let Some(options) = self.isolated_declaration_options() {
-            self.isolated_declaration(options, &allocator, &program, source_path);
+            self.isolated_declaration(options, allocator, &program, source_path);
         }
 
         /* Semantic */
@@ -158,7 +169,7 @@ pub trait CompilerInterface {
 
         if let Some(options) = self.transform_options() {
             let mut transformer_return =
-                self.transform(options, &allocator, &mut program, source_path, symbols, scopes);
+                self.transform(options, allocator, &mut program, source_path, symbols, scopes);
 
             if !transformer_return.errors.is_empty() {
                 self.handle_errors(transformer_return.errors);
@@ -175,15 +186,15 @@ pub trait CompilerInterface {
 
         if let Some(config) = self.inject_options() {
             let ret =
-                InjectGlobalVariables::new(&allocator, config).build(symbols, scopes, &mut program);
+                InjectGlobalVariables::new(allocator, config).build(symbols, scopes, &mut program);
             symbols = ret.symbols;
             scopes = ret.scopes;
         }
 
         if let Some(config) = self.define_options() {
             let ret =
-                ReplaceGlobalDefines::new(&allocator, config).build(symbols, scopes, &mut program);
-            Compressor::new(&allocator, CompressOptions::dead_code_elimination())
+                ReplaceGlobalDefines::new(allocator, config).build(symbols, scopes, &mut program);
+            Compressor::new(allocator, CompressOptions::dead_code_elimination())
                 .build_with_symbols_and_scopes(ret.symbols, ret.scopes, &mut program);
             // symbols = ret.symbols;
             // scopes = ret.scopes;
@@ -192,7 +203,7 @@ pub trait CompilerInterface {
         /* Compress */
 
         if let Some(options) = self.compress_options() {
-            self.compress(&allocator, &mut program, options);
+            self.compress(allocator, &mut program, options);
         }
 
         /* Mangler */
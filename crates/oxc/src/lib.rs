@@ -1,13 +1,21 @@
 #![doc = include_str!("../README.md")]
 
+#[cfg(feature = "full")]
+mod batch_compiler;
 #[cfg(feature = "full")]
 mod compiler;
+#[cfg(feature = "full")]
+mod transform_cache;
 
 #[cfg(feature = "napi")]
 pub mod napi;
 
+#[cfg(feature = "full")]
+pub use batch_compiler::{BatchCompiler, BatchCompilerReturn, CompiledFile, CompilerOptions};
 #[cfg(feature = "full")]
 pub use compiler::{Compiler, CompilerInterface};
+#[cfg(feature = "full")]
+pub use transform_cache::{CachedTransform, TransformCache};
 
 pub mod allocator {
     //! Memory arena allocator used by all other submodules.
@@ -8,7 +8,7 @@ use napi::Either;
 use napi_derive::napi;
 use rustc_hash::FxHashMap;
 
-use oxc_transformer::{JsxRuntime, RewriteExtensionsMode};
+use oxc_transformer::{EmotionAutoLabel, JsxRuntime, RewriteExtensionsMode};
 
 use super::{isolated_declarations::IsolatedDeclarationsOptions, source_map::SourceMap};
 
@@ -80,6 +80,15 @@ pub struct TransformOptions {
     /// Configure how TSX and JSX are transformed.
     pub jsx: Option<JsxOptions>,
 
+    /// Enable the styled-components plugin.
+    pub styled_components: Option<StyledComponentsOptions>,
+
+    /// Enable the Emotion plugin.
+    pub emotion: Option<EmotionOptions>,
+
+    /// Rewrite imports which use tsconfig `paths` and `baseUrl` aliases to relative paths.
+    pub tsconfig_paths: Option<TsconfigPathsOptions>,
+
     /// Enable ES2015 transformations.
     pub es2015: Option<Es2015Options>,
 
@@ -98,6 +107,9 @@ impl From<TransformOptions> for oxc_transformer::TransformOptions {
             cwd: options.cwd.map(PathBuf::from).unwrap_or_default(),
             typescript: options.typescript.map(Into::into).unwrap_or_default(),
             react: options.jsx.map(Into::into).unwrap_or_default(),
+            styled_components: options.styled_components.map(Into::into),
+            emotion: options.emotion.map(Into::into),
+            tsconfig_paths: options.tsconfig_paths.map(Into::into),
             es2015: options.es2015.map(Into::into).unwrap_or_default(),
             ..Self::default()
         }
@@ -112,6 +124,15 @@ pub struct TypeScriptOptions {
     pub only_remove_type_imports: Option<bool>,
     pub allow_namespaces: Option<bool>,
     pub allow_declare_fields: Option<bool>,
+    /// Enable legacy decorators, like TypeScript's `experimentalDecorators` option.
+    ///
+    /// @default false
+    pub experimental_decorators: Option<bool>,
+    /// Emit design-time type metadata for decorated declarations, like TypeScript's
+    /// `emitDecoratorMetadata` option. Only takes effect with `experimentalDecorators`.
+    ///
+    /// @default false
+    pub emit_decorator_metadata: Option<bool>,
     /// Also generate a `.d.ts` declaration file for TypeScript files.
     ///
     /// The source file must be compliant with all
@@ -144,6 +165,12 @@ impl From<TypeScriptOptions> for oxc_transformer::TypeScriptOptions {
             allow_namespaces: options.allow_namespaces.unwrap_or(ops.allow_namespaces),
             allow_declare_fields: options.allow_declare_fields.unwrap_or(ops.allow_declare_fields),
             optimize_const_enums: false,
+            experimental_decorators: options
+                .experimental_decorators
+                .unwrap_or(ops.experimental_decorators),
+            emit_decorator_metadata: options
+                .emit_decorator_metadata
+                .unwrap_or(ops.emit_decorator_metadata),
             rewrite_import_extensions: options.rewrite_import_extensions.and_then(|value| {
                 match value {
                     Either::A(v) => {
@@ -160,6 +187,7 @@ impl From<TypeScriptOptions> for oxc_transformer::TypeScriptOptions {
                     },
                 }
             }),
+            const_enums: ops.const_enums,
         }
     }
 }
@@ -173,9 +201,10 @@ pub struct JsxOptions {
     ///
     /// - 'automatic' - auto-import the correct JSX factories
     /// - 'classic' - no auto-import
+    /// - 'dom' - compile to DOM expressions, as used by Solid
     ///
     /// @default 'automatic'
-    #[napi(ts_type = "'classic' | 'automatic'")]
+    #[napi(ts_type = "'classic' | 'automatic' | 'dom'")]
     pub runtime: Option<String>,
 
     /// Emit development-specific information, such as `__source` and `__self`.
@@ -254,6 +283,7 @@ impl From<JsxOptions> for oxc_transformer::JsxOptions {
         oxc_transformer::JsxOptions {
             runtime: match options.runtime.as_deref() {
                 Some("classic") => JsxRuntime::Classic,
+                Some("dom") => JsxRuntime::Dom,
                 /* "automatic" */ _ => JsxRuntime::Automatic,
             },
             development: options.development.unwrap_or(ops.development),
@@ -299,6 +329,111 @@ impl From<ReactRefreshOptions> for oxc_transformer::ReactRefreshOptions {
     }
 }
 
+/// Configure the styled-components plugin.
+///
+/// @see {@link https://styled-components.com/docs/tooling#babel-plugin}
+#[napi(object)]
+pub struct StyledComponentsOptions {
+    /// Add a `displayName` to styled components.
+    ///
+    /// @default true
+    pub display_name: Option<bool>,
+
+    /// Add a `componentId` to styled components, so class names are consistent
+    /// between server and client.
+    ///
+    /// @default true
+    pub ssr: Option<bool>,
+
+    /// Prefix `displayName` with the name of the file.
+    ///
+    /// @default true
+    pub file_name: Option<bool>,
+
+    /// Remove comments and whitespace from CSS in tagged templates.
+    ///
+    /// @default true
+    pub minify: Option<bool>,
+
+    /// Prefix for `componentId`.
+    pub namespace: Option<String>,
+}
+
+impl From<StyledComponentsOptions> for oxc_transformer::StyledComponentsOptions {
+    fn from(options: StyledComponentsOptions) -> Self {
+        let ops = oxc_transformer::StyledComponentsOptions::default();
+        oxc_transformer::StyledComponentsOptions {
+            display_name: options.display_name.unwrap_or(ops.display_name),
+            ssr: options.ssr.unwrap_or(ops.ssr),
+            file_name: options.file_name.unwrap_or(ops.file_name),
+            minify: options.minify.unwrap_or(ops.minify),
+            namespace: options.namespace,
+        }
+    }
+}
+
+/// Configure the Emotion plugin.
+///
+/// @see {@link https://emotion.sh/docs/@emotion/babel-plugin}
+#[napi(object)]
+pub struct EmotionOptions {
+    /// When to add a label to styles.
+    ///
+    /// `dev-only` adds labels when {@link JsxOptions#development} is enabled.
+    ///
+    /// @default 'dev-only'
+    #[napi(ts_type = "'never' | 'dev-only' | 'always'")]
+    pub auto_label: Option<String>,
+
+    /// Format of labels. Supports `[local]`, `[filename]` and `[dirname]`.
+    ///
+    /// @default '[local]'
+    pub label_format: Option<String>,
+}
+
+impl From<EmotionOptions> for oxc_transformer::EmotionOptions {
+    fn from(options: EmotionOptions) -> Self {
+        let ops = oxc_transformer::EmotionOptions::default();
+        oxc_transformer::EmotionOptions {
+            auto_label: match options.auto_label.as_deref() {
+                Some("never") => EmotionAutoLabel::Never,
+                Some("always") => EmotionAutoLabel::Always,
+                /* "dev-only" */ _ => EmotionAutoLabel::DevOnly,
+            },
+            label_format: options.label_format.unwrap_or(ops.label_format),
+        }
+    }
+}
+
+/// Configure rewriting of tsconfig `paths` and `baseUrl` aliases.
+///
+/// @see {@link https://www.typescriptlang.org/tsconfig/#paths}
+#[napi(object)]
+pub struct TsconfigPathsOptions {
+    /// `compilerOptions.baseUrl`. Relative to {@link configDir}.
+    pub base_url: Option<String>,
+
+    /// `compilerOptions.paths`. Relative to {@link baseUrl} if it is set,
+    /// otherwise {@link configDir}.
+    #[napi(ts_type = "Record<string, string[]>")]
+    pub paths: Option<FxHashMap<String, Vec<String>>>,
+
+    /// Directory containing the tsconfig. Relative to {@link TransformOptions#cwd}.
+    ///
+    /// @default cwd
+    pub config_dir: Option<String>,
+}
+
+impl From<TsconfigPathsOptions> for oxc_transformer::TsconfigPathsOptions {
+    fn from(options: TsconfigPathsOptions) -> Self {
+        oxc_transformer::TsconfigPathsOptions {
+            base_url: options.base_url.map(PathBuf::from),
+            paths: options.paths.unwrap_or_default().into_iter().collect(),
+            config_dir: options.config_dir.map(PathBuf::from),
+        }
+    }
+}
+
 #[napi(object)]
 pub struct ArrowFunctionsOptions {
     /// This option enables the following:
@@ -316,14 +451,196 @@ impl From<ArrowFunctionsOptions> for oxc_transformer::ArrowFunctionsOptions {
     }
 }
 
+#[napi(object)]
+pub struct ClassesOptions {
+    /// Not supported yet.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+}
+
+impl From<ClassesOptions> for oxc_transformer::ClassesOptions {
+    fn from(options: ClassesOptions) -> Self {
+        oxc_transformer::ClassesOptions { loose: options.loose.unwrap_or_default() }
+    }
+}
+
+#[napi(object)]
+pub struct TemplateLiteralsOptions {
+    /// Transform template literals to `+` concatenation, instead of `String.prototype.concat` calls.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+}
+
+impl From<TemplateLiteralsOptions> for oxc_transformer::TemplateLiteralsOptions {
+    fn from(options: TemplateLiteralsOptions) -> Self {
+        oxc_transformer::TemplateLiteralsOptions { loose: options.loose.unwrap_or_default() }
+    }
+}
+
+#[napi(object)]
+pub struct SpreadOptions {
+    /// Assume all spread iterables are arrays.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+}
+
+impl From<SpreadOptions> for oxc_transformer::SpreadOptions {
+    fn from(options: SpreadOptions) -> Self {
+        oxc_transformer::SpreadOptions { loose: options.loose.unwrap_or_default() }
+    }
+}
+
+#[napi(object)]
+pub struct ParametersOptions {
+    /// Transform default parameters without preserving function's `length`.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+}
+
+impl From<ParametersOptions> for oxc_transformer::ParametersOptions {
+    fn from(options: ParametersOptions) -> Self {
+        oxc_transformer::ParametersOptions { loose: options.loose.unwrap_or_default() }
+    }
+}
+
+#[napi(object)]
+pub struct DestructuringOptions {
+    /// Assume all destructured iterables are arrays, and objects have no symbol properties.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+    /// Use `Object.assign` instead of `extends` helper.
+    ///
+    /// @default false
+    pub use_built_ins: Option<bool>,
+}
+
+impl From<DestructuringOptions> for oxc_transformer::DestructuringOptions {
+    fn from(options: DestructuringOptions) -> Self {
+        oxc_transformer::DestructuringOptions {
+            loose: options.loose.unwrap_or_default(),
+            use_built_ins: options.use_built_ins.unwrap_or_default(),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct BlockScopingOptions {
+    /// Throw an error if a closure would be required to transform a loop.
+    ///
+    /// @default false
+    pub throw_if_closure_required: Option<bool>,
+    /// Not supported yet.
+    ///
+    /// @default false
+    pub tdz: Option<bool>,
+}
+
+impl From<BlockScopingOptions> for oxc_transformer::BlockScopingOptions {
+    fn from(options: BlockScopingOptions) -> Self {
+        oxc_transformer::BlockScopingOptions {
+            throw_if_closure_required: options.throw_if_closure_required.unwrap_or_default(),
+            tdz: options.tdz.unwrap_or_default(),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct ForOfOptions {
+    /// Use a simpler iterator which does not close the iterator if the loop exits early.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+    /// Assume that all iterables are arrays.
+    ///
+    /// @default false
+    pub assume_array: Option<bool>,
+    /// Allow iterating over array-like objects which are not iterable.
+    ///
+    /// @default false
+    pub allow_array_like: Option<bool>,
+}
+
+impl From<ForOfOptions> for oxc_transformer::ForOfOptions {
+    fn from(options: ForOfOptions) -> Self {
+        oxc_transformer::ForOfOptions {
+            loose: options.loose.unwrap_or_default(),
+            assume_array: options.assume_array.unwrap_or_default(),
+            allow_array_like: options.allow_array_like.unwrap_or_default(),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct RegeneratorOptions {
+    /// Not supported yet.
+    ///
+    /// @default true
+    pub async_generators: Option<bool>,
+    /// Transform generator functions.
+    ///
+    /// @default true
+    pub generators: Option<bool>,
+    /// Not supported yet.
+    ///
+    /// @default true
+    pub r#async: Option<bool>,
+}
+
+impl From<RegeneratorOptions> for oxc_transformer::RegeneratorOptions {
+    fn from(options: RegeneratorOptions) -> Self {
+        oxc_transformer::RegeneratorOptions {
+            async_generators: options.async_generators.unwrap_or(true),
+            generators: options.generators.unwrap_or(true),
+            r#async: options.r#async.unwrap_or(true),
+        }
+    }
+}
+
 #[napi(object)]
 pub struct Es2015Options {
     /// Transform arrow functions into function expressions.
     pub arrow_function: Option<ArrowFunctionsOptions>,
+    /// Transform classes into constructor functions.
+    pub classes: Option<ClassesOptions>,
+    /// Transform shorthand properties and methods in object literals.
+    pub shorthand_properties: Option<bool>,
+    /// Transform computed keys in object literals.
+    pub computed_properties: Option<bool>,
+    /// Transform template literals and tagged templates.
+    pub template_literals: Option<TemplateLiteralsOptions>,
+    /// Transform spread in array literals, calls and `new` expressions.
+    pub spread: Option<SpreadOptions>,
+    /// Transform default, destructured and rest parameters.
+    pub parameters: Option<ParametersOptions>,
+    /// Transform destructuring.
+    pub destructuring: Option<DestructuringOptions>,
+    /// Transform `let` and `const` declarations to `var`.
+    pub block_scoping: Option<BlockScopingOptions>,
+    /// Transform `for...of` loops.
+    pub for_of: Option<ForOfOptions>,
+    /// Transform generator functions.
+    pub regenerator: Option<RegeneratorOptions>,
 }
 
 impl From<Es2015Options> for oxc_transformer::ES2015Options {
     fn from(options: Es2015Options) -> Self {
-        oxc_transformer::ES2015Options { arrow_function: options.arrow_function.map(Into::into) }
+        oxc_transformer::ES2015Options {
+            arrow_function: options.arrow_function.map(Into::into),
+            classes: options.classes.map(Into::into),
+            shorthand_properties: options.shorthand_properties.unwrap_or_default(),
+            computed_properties: options.computed_properties.unwrap_or_default(),
+            template_literals: options.template_literals.map(Into::into),
+            spread: options.spread.map(Into::into),
+            parameters: options.parameters.map(Into::into),
+            destructuring: options.destructuring.map(Into::into),
+            block_scoping: options.block_scoping.map(Into::into),
+            for_of: options.for_of.map(Into::into),
+            regenerator: options.regenerator.map(Into::into),
+        }
     }
 }
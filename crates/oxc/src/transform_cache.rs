@@ -0,0 +1,338 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Write as _,
+    fs::{self, File},
+    io,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex, PoisonError,
+    },
+    time::SystemTime,
+};
+
+use sha1::{Digest, Sha1};
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::Statement;
+use oxc_codegen::{CodegenOptions, CodegenReturn};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_parser::Parser;
+use oxc_sourcemap::SourceMap;
+use oxc_span::SourceType;
+use oxc_transformer::{ConstEnums, TransformOptions};
+
+use crate::CompilerInterface;
+
+/// Default for [`TransformCache::with_max_size`], 256 MiB.
+const DEFAULT_MAX_SIZE: u64 = 256 * 1024 * 1024;
+
+/// On-disk cache of transformed code and source maps, for watch mode.
+///
+/// Entries are keyed by a hash of the source text, source path, the [`TransformOptions`]
+/// and [`CodegenOptions`], the contents of [`TransformCache::with_config_files`], and the
+/// const enums resolved for the imports of the source, so changing any of them is a cache miss.
+/// Entries which are no longer used are evicted when the cache grows larger than
+/// [`TransformCache::with_max_size`], least recently used first.
+///
+/// ```ignore
+/// let cache = TransformCache::new("node_modules/.cache/oxc");
+/// let ret = cache.transform(&source_text, source_type, path, &transform_options, &codegen_options)?;
+/// ```
+pub struct TransformCache {
+    dir: PathBuf,
+    max_size: u64,
+    config_files: Vec<PathBuf>,
+    /// Total size of entries, `None` until the directory is scanned on the first insert.
+    /// Entries written by other processes are counted when it is rescanned to evict entries.
+    size: Mutex<Option<u64>>,
+}
+
+/// Code and source map returned by [`TransformCache::transform`].
+pub struct CachedTransform {
+    pub code: String,
+    pub map: SourceMap,
+    /// Whether the result was read from the cache.
+    pub hit: bool,
+}
+
+impl TransformCache {
+    /// Store entries in `dir`, which is created if it does not exist.
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Self {
+        Self {
+            dir: dir.into(),
+            max_size: DEFAULT_MAX_SIZE,
+            config_files: vec![],
+            size: Mutex::new(None),
+        }
+    }
+
+    /// Files the options were loaded from, e.g. `tsconfig.json` and `.browserslistrc`.
+    ///
+    /// Their contents are part of the cache key, so entries are not used after they change.
+    #[must_use]
+    pub fn with_config_files<I, P>(mut self, config_files: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<PathBuf>,
+    {
+        self.config_files.extend(config_files.into_iter().map(Into::into));
+        self
+    }
+
+    /// Maximum total size of entries in bytes.
+    ///
+    /// Default is 256 MiB.
+    #[must_use]
+    pub fn with_max_size(mut self, max_size: u64) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /// Transform `source_text`, or return the cached result if it was transformed before with
+    /// the same source path and options.
+    ///
+    /// Results with errors are not cached.
+    /// Failing to read or write the cache is not an error, the source is transformed instead.
+    ///
+    /// # Errors
+    ///
+    /// * A list of [OxcDiagnostic] if parsing, semantic analysis or transforming fails.
+    pub fn transform(
+        &self,
+        source_text: &str,
+        source_type: SourceType,
+        source_path: &Path,
+        transform_options: &TransformOptions,
+        codegen_options: &CodegenOptions,
+    ) -> Result<CachedTransform, Vec<OxcDiagnostic>> {
+        let key = self.cache_key(
+            source_text,
+            source_type,
+            source_path,
+            transform_options,
+            codegen_options,
+        );
+        if let Some(cached) = self.get(&key) {
+            return Ok(cached);
+        }
+
+        let mut compiler = CacheCompiler {
+            transform_options,
+            codegen_options,
+            errors: vec![],
+            code: String::new(),
+            map: SourceMap::default(),
+        };
+        compiler.compile(source_text, source_type, source_path);
+        if !compiler.errors.is_empty() {
+            return Err(compiler.errors);
+        }
+
+        let ret = CachedTransform { code: compiler.code, map: compiler.map, hit: false };
+        // The cache is best effort, transforming still succeeded
+        if let Ok(size) = self.insert(&key, &ret) {
+            let _ = self.evict(size);
+        }
+        Ok(ret)
+    }
+
+    /// Remove all entries.
+    ///
+    /// # Errors
+    ///
+    /// * Failed to remove the cache directory.
+    pub fn clear(&self) -> io::Result<()> {
+        *self.size.lock().unwrap_or_else(PoisonError::into_inner) = None;
+        match fs::remove_dir_all(&self.dir) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+            _ => Ok(()),
+        }
+    }
+
+    fn code_path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{key}.js"))
+    }
+
+    fn map_path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{key}.js.map"))
+    }
+
+    fn get(&self, key: &str) -> Option<CachedTransform> {
+        let code_path = self.code_path(key);
+        let code = fs::read_to_string(&code_path).ok()?;
+        let map =
+            SourceMap::from_json_string(&fs::read_to_string(self.map_path(key)).ok()?).ok()?;
+        // Mark as recently used for eviction
+        if let Ok(file) = File::options().append(true).open(&code_path) {
+            let _ = file.set_modified(SystemTime::now());
+        }
+        Some(CachedTransform { code, map, hit: true })
+    }
+
+    /// Returns the size of the entry.
+    fn insert(&self, key: &str, ret: &CachedTransform) -> io::Result<u64> {
+        fs::create_dir_all(&self.dir)?;
+        let map = ret.map.to_json_string();
+        // Write the map first, so an entry with code always has its map
+        self.write_atomic(&self.map_path(key), &map)?;
+        self.write_atomic(&self.code_path(key), &ret.code)?;
+        Ok((map.len() + ret.code.len()) as u64)
+    }
+
+    /// Write to a temporary file and rename it, so other threads and processes reading the
+    /// cache never see a partially written file.
+    fn write_atomic(&self, path: &Path, contents: &str) -> io::Result<()> {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let count = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let temp_path = self.dir.join(format!("{}.{count}.tmp", std::process::id()));
+        fs::write(&temp_path, contents)?;
+        fs::rename(&temp_path, path).inspect_err(|_| {
+            let _ = fs::remove_file(&temp_path);
+        })
+    }
+
+    /// Remove least recently used entries until the total size is at most `max_size`.
+    ///
+    /// The directory is only scanned on the first insert, and when the total size is too large.
+    fn evict(&self, added_size: u64) -> io::Result<()> {
+        let mut size = self.size.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(size) = size.as_mut() {
+            *size += added_size;
+            if *size <= self.max_size {
+                return Ok(());
+            }
+        }
+        // Scanned again on the next insert if scanning fails
+        *size = None;
+
+        let mut entries = vec![];
+        let mut total_size = 0;
+        for entry in fs::read_dir(&self.dir)? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|extension| extension == "js") {
+                let metadata = fs::metadata(&path)?;
+                let map_size = fs::metadata(path.with_extension("js.map")).map_or(0, |m| m.len());
+                let size = metadata.len() + map_size;
+                total_size += size;
+                entries.push((metadata.modified()?, size, path));
+            }
+        }
+        if total_size > self.max_size {
+            entries.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+            for (_, entry_size, path) in entries {
+                if total_size <= self.max_size {
+                    break;
+                }
+                fs::remove_file(&path)?;
+                let _ = fs::remove_file(path.with_extension("js.map"));
+                total_size -= entry_size;
+            }
+        }
+        *size = Some(total_size);
+        Ok(())
+    }
+
+    /// Hex encoded SHA-1 of everything the transformed output depends on.
+    ///
+    /// Options are hashed by their `Debug` output, which includes every field except the
+    /// const enum resolver, so the const enums it resolves for the imports of the source are
+    /// hashed instead.
+    /// The crate version is included, so upgrading invalidates the cache.
+    fn cache_key(
+        &self,
+        source_text: &str,
+        source_type: SourceType,
+        source_path: &Path,
+        transform_options: &TransformOptions,
+        codegen_options: &CodegenOptions,
+    ) -> String {
+        let mut hasher = Sha1::new();
+        hasher.update(env!("CARGO_PKG_VERSION"));
+        hasher.update(format!("{source_type:?}\0{transform_options:?}\0{codegen_options:?}\0"));
+        hasher.update(source_path.to_string_lossy().as_bytes());
+        hasher.update([0]);
+        hasher.update(source_text);
+        for config_file in &self.config_files {
+            hasher.update([0]);
+            hasher.update(config_file.to_string_lossy().as_bytes());
+            // A missing file is hashed differently to an empty file
+            match fs::read(config_file) {
+                Ok(contents) => {
+                    hasher.update(contents.len().to_le_bytes());
+                    hasher.update(contents);
+                }
+                Err(_) => hasher.update([0xff]),
+            }
+        }
+        let const_enums = &transform_options.typescript.const_enums;
+        if !const_enums.is_empty() {
+            hasher.update([0]);
+            hasher.update(resolve_const_enums(const_enums, source_text, source_type, source_path));
+        }
+        hasher.finalize().iter().fold(String::with_capacity(40), |mut key, byte| {
+            let _ = write!(key, "{byte:02x}");
+            key
+        })
+    }
+}
+
+/// Const enums resolved for the imports of `source_text`, in the same way as the transformer.
+///
+/// Enums and members are sorted, so the result does not depend on the order they were resolved in.
+fn resolve_const_enums(
+    const_enums: &ConstEnums,
+    source_text: &str,
+    source_type: SourceType,
+    source_path: &Path,
+) -> String {
+    let allocator = Allocator::default();
+    let program = Parser::new(&allocator, source_text, source_type).parse().program;
+    let mut resolved = String::new();
+    for stmt in &program.body {
+        let Statement::ImportDeclaration(decl) = stmt else { continue };
+        if decl.specifiers.is_none() {
+            continue;
+        }
+        let Some(enums) = const_enums.resolve(&decl.source.value, source_path) else { continue };
+        let enums = enums
+            .iter()
+            .map(|(name, members)| (name, members.iter().collect::<BTreeMap<_, _>>()))
+            .collect::<BTreeMap<_, _>>();
+        let _ = write!(resolved, "{}\0{enums:?}\0", decl.source.value);
+    }
+    resolved
+}
+
+struct CacheCompiler<'o> {
+    transform_options: &'o TransformOptions,
+    codegen_options: &'o CodegenOptions,
+    errors: Vec<OxcDiagnostic>,
+    code: String,
+    map: SourceMap,
+}
+
+impl<'o> CompilerInterface for CacheCompiler<'o> {
+    fn handle_errors(&mut self, errors: Vec<OxcDiagnostic>) {
+        self.errors.extend(errors);
+    }
+
+    fn enable_sourcemap(&self) -> bool {
+        true
+    }
+
+    fn transform_options(&self) -> Option<TransformOptions> {
+        Some(self.transform_options.clone())
+    }
+
+    fn codegen_options(&self) -> Option<CodegenOptions> {
+        Some(self.codegen_options.clone())
+    }
+
+    fn after_codegen(&mut self, ret: CodegenReturn) {
+        self.code = ret.code;
+        if let Some(map) = ret.map {
+            self.map = map;
+        }
+    }
+}
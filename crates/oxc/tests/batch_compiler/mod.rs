@@ -0,0 +1,129 @@
+use std::{
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use oxc::{BatchCompiler, BatchCompilerReturn, CompilerOptions};
+
+/// Create a temp directory containing `files`.
+fn create_dir(name: &str, files: &[(String, String)]) -> PathBuf {
+    let dir =
+        std::env::temp_dir().join(format!("oxc_batch_compiler_{name}_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    for (path, content) in files {
+        let path = dir.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+    dir
+}
+
+/// Compile on multiple threads, even if there is only one CPU.
+fn compile_parallel(f: impl FnOnce() -> BatchCompilerReturn + Send) -> BatchCompilerReturn {
+    rayon::ThreadPoolBuilder::new().num_threads(4).build().unwrap().install(f)
+}
+
+/// Files of varying size, so they finish compiling out of order.
+fn files(count: usize) -> Vec<(String, String)> {
+    (0..count)
+        .map(|i| {
+            let mut padding = String::new();
+            for j in 0..(count - i) * 20 {
+                writeln!(padding, "let x{j} = {j};").unwrap();
+            }
+            (format!("file{i}.ts"), format!("{padding}export const id: number = {i};\n"))
+        })
+        .collect()
+}
+
+#[test]
+fn order_is_preserved() {
+    let files = files(64);
+    let dir = create_dir("order", &files);
+    // Reversed, so order differs from sorted order
+    let paths = files.iter().rev().map(|(path, _)| dir.join(path)).collect::<Vec<_>>();
+
+    let compiler = BatchCompiler::new(CompilerOptions::default());
+    let ret = compile_parallel(|| compiler.compile_files(&paths));
+    assert!(!ret.has_errors(), "{:?}", ret.errors().collect::<Vec<_>>());
+    assert_eq!(ret.files.iter().map(|file| file.path.clone()).collect::<Vec<_>>(), paths);
+    for file in &ret.files {
+        let index = file.path.file_stem().unwrap().to_str().unwrap().trim_start_matches("file");
+        let code = file.code.as_ref().unwrap();
+        assert!(code.ends_with(&format!("export const id = {index};\n")), "{code}");
+    }
+
+    // Directories are compiled in sorted order
+    let ret = compile_parallel(|| compiler.compile_dir(&dir));
+    let mut sorted = paths.clone();
+    sorted.sort_unstable();
+    assert_eq!(ret.files.iter().map(|file| file.path.clone()).collect::<Vec<_>>(), sorted);
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn errors_are_reported_per_file() {
+    let mut files = files(32);
+    for i in (0..files.len()).step_by(5) {
+        files[i].1.push_str(&format!("let broken{i} = ;\n"));
+    }
+    let dir = create_dir("errors", &files);
+    let mut paths = files.iter().map(|(path, _)| dir.join(path)).collect::<Vec<_>>();
+    paths.push(dir.join("missing.ts"));
+
+    let compiler = BatchCompiler::new(CompilerOptions::default());
+    let ret = compile_parallel(|| compiler.compile_files(&paths));
+    assert!(ret.has_errors());
+    assert_eq!(ret.files.len(), paths.len());
+
+    for (i, file) in ret.files.iter().enumerate() {
+        assert_eq!(file.path, paths[i]);
+        if i == files.len() {
+            // Missing file
+            assert_eq!(file.errors.len(), 1);
+            assert!(file.errors[0].to_string().starts_with("Failed to read"));
+        } else if i % 5 == 0 {
+            assert!(!file.errors.is_empty(), "expected error in {}", file.path.display());
+            assert!(file.code.is_none());
+            // Labels refer to the source text of the file
+            let source = file.errors[0].source_code().unwrap();
+            let span = file.errors[0].labels().unwrap().next().unwrap();
+            let contents = source.read_span(span.inner(), 0, 0).unwrap();
+            assert_eq!(std::str::from_utf8(contents.data()).unwrap(), ";");
+        } else {
+            assert!(file.errors.is_empty(), "unexpected error in {}", file.path.display());
+            assert!(file.code.is_some());
+        }
+    }
+
+    let error_paths = ret.errors().map(|(path, _)| path).collect::<Vec<_>>();
+    let mut expected = (0..files.len()).step_by(5).map(|i| paths[i].as_path()).collect::<Vec<_>>();
+    expected.push(Path::new(paths.last().unwrap()));
+    assert_eq!(error_paths, expected);
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn out_dir() {
+    let files = vec![
+        ("src/a.ts".to_string(), "export const a: number = 1;".to_string()),
+        ("src/nested/b.mts".to_string(), "export const b = 2;".to_string()),
+    ];
+    let dir = create_dir("out_dir", &files);
+    let out_dir = dir.join("dist");
+
+    let compiler = BatchCompiler::new(CompilerOptions::default()).with_out_dir(&out_dir);
+    let ret = compile_parallel(|| compiler.compile_dir(&dir.join("src")));
+    assert!(!ret.has_errors());
+    assert_eq!(
+        ret.files.iter().map(|file| file.output_path.clone().unwrap()).collect::<Vec<_>>(),
+        [out_dir.join("a.js"), out_dir.join("nested/b.mjs")]
+    );
+    assert_eq!(fs::read_to_string(out_dir.join("a.js")).unwrap(), "export const a = 1;\n");
+    assert!(ret.files.iter().all(|file| file.code.is_none()));
+
+    fs::remove_dir_all(dir).unwrap();
+}
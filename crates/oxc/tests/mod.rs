@@ -0,0 +1,2 @@
+mod batch_compiler;
+mod transform_cache;
@@ -0,0 +1,244 @@
+use std::{fs, path::PathBuf};
+
+use oxc::{
+    codegen::CodegenOptions,
+    span::SourceType,
+    transformer::{ConstEnums, TransformOptions},
+    CachedTransform, TransformCache,
+};
+
+/// Create an empty temp directory.
+fn create_dir(name: &str) -> PathBuf {
+    let dir =
+        std::env::temp_dir().join(format!("oxc_transform_cache_{name}_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn transform(
+    cache: &TransformCache,
+    source_text: &str,
+    options: &TransformOptions,
+) -> CachedTransform {
+    let path = PathBuf::from("src/index.ts");
+    cache
+        .transform(source_text, SourceType::ts(), &path, options, &CodegenOptions::default())
+        .unwrap()
+}
+
+#[test]
+fn hit_and_miss() {
+    let dir = create_dir("hit");
+    let cache = TransformCache::new(dir.join("cache"));
+    let options = TransformOptions::default();
+
+    let miss = transform(&cache, "let a: number = 1;", &options);
+    assert!(!miss.hit);
+    assert_eq!(miss.code, "let a = 1;\n");
+    let hit = transform(&cache, "let a: number = 1;", &options);
+    assert!(hit.hit);
+    assert_eq!(hit.code, miss.code);
+    assert_eq!(hit.map.to_json_string(), miss.map.to_json_string());
+
+    // Different source text
+    let ret = transform(&cache, "let b: number = 1;", &options);
+    assert!(!ret.hit);
+    assert_eq!(ret.code, "let b = 1;\n");
+
+    // Different source path
+    let ret = cache
+        .transform(
+            "let a: number = 1;",
+            SourceType::ts(),
+            &PathBuf::from("src/other.ts"),
+            &options,
+            &CodegenOptions::default(),
+        )
+        .unwrap();
+    assert!(!ret.hit);
+
+    // Errors are not cached
+    let source_text = "let a = ;";
+    assert!(cache
+        .transform(source_text, SourceType::ts(), &dir, &options, &CodegenOptions::default())
+        .is_err());
+    assert!(cache
+        .transform(source_text, SourceType::ts(), &dir, &options, &CodegenOptions::default())
+        .is_err());
+
+    // Cleared cache
+    cache.clear().unwrap();
+    assert!(!transform(&cache, "let a: number = 1;", &options).hit);
+
+    // Nothing is left behind by atomic writes
+    for entry in fs::read_dir(dir.join("cache")).unwrap() {
+        let path = entry.unwrap().path();
+        assert!(
+            path.to_string_lossy().ends_with(".js") || path.to_string_lossy().ends_with(".js.map"),
+            "{}",
+            path.display()
+        );
+    }
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn option_change() {
+    let dir = create_dir("options");
+    let cache = TransformCache::new(&dir);
+    let source_text = "import { T } from 'mod'; let a: T = 1;";
+    let options = TransformOptions::default();
+
+    assert!(!transform(&cache, source_text, &options).hit);
+    assert!(transform(&cache, source_text, &options).hit);
+
+    let mut changed = TransformOptions::default();
+    changed.typescript.only_remove_type_imports = true;
+    let ret = transform(&cache, source_text, &changed);
+    assert!(!ret.hit);
+    assert_eq!(ret.code, "import { T } from \"mod\";\nlet a = 1;\n");
+
+    let codegen_options = CodegenOptions { minify: true, ..CodegenOptions::default() };
+    let ret =
+        cache.transform(source_text, SourceType::ts(), &dir, &options, &codegen_options).unwrap();
+    assert!(!ret.hit);
+
+    // Original options are still cached
+    let ret = transform(&cache, source_text, &options);
+    assert!(ret.hit);
+    assert_eq!(ret.code, "let a = 1;\nexport {};\n");
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn config_files() {
+    let dir = create_dir("config");
+    let tsconfig = dir.join("tsconfig.json");
+    let browserslistrc = dir.join(".browserslistrc");
+    fs::write(&tsconfig, "{}").unwrap();
+    let cache =
+        TransformCache::new(dir.join("cache")).with_config_files([&tsconfig, &browserslistrc]);
+    let options = TransformOptions::default();
+
+    assert!(!transform(&cache, "let a = 1;", &options).hit);
+    assert!(transform(&cache, "let a = 1;", &options).hit);
+
+    fs::write(&tsconfig, r#"{ "compilerOptions": {} }"#).unwrap();
+    assert!(!transform(&cache, "let a = 1;", &options).hit);
+    assert!(transform(&cache, "let a = 1;", &options).hit);
+
+    // Created
+    fs::write(&browserslistrc, "").unwrap();
+    assert!(!transform(&cache, "let a = 1;", &options).hit);
+    assert!(transform(&cache, "let a = 1;", &options).hit);
+
+    fs::write(&browserslistrc, "chrome 80").unwrap();
+    assert!(!transform(&cache, "let a = 1;", &options).hit);
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn const_enum_resolver() {
+    let dir = create_dir("const_enums");
+    let enums_path = dir.join("enums.ts");
+    fs::write(&enums_path, "export const enum Direction { Up = 1 }").unwrap();
+    let mut options = TransformOptions::default();
+    options.typescript.const_enums = ConstEnums::default().with_resolver(move |specifier, _| {
+        (specifier == "./enums").then(|| {
+            let source_text = fs::read_to_string(&enums_path).ok()?;
+            Some(ConstEnums::collect_exports(&source_text, SourceType::ts()))
+        })?
+    });
+    let cache = TransformCache::new(dir.join("cache"));
+    let source_text = "import { Direction } from './enums'; move(Direction.Up);";
+
+    let ret = transform(&cache, source_text, &options);
+    assert!(!ret.hit);
+    assert_eq!(ret.code, "move(1);\nexport {};\n");
+    assert!(transform(&cache, source_text, &options).hit);
+
+    // The resolver returns different enums when the enum source changes
+    fs::write(dir.join("enums.ts"), "export const enum Direction { Up = 2 }").unwrap();
+    let ret = transform(&cache, source_text, &options);
+    assert!(!ret.hit);
+    assert_eq!(ret.code, "move(2);\nexport {};\n");
+
+    // Files without imports of const enums are not affected
+    assert!(!transform(&cache, "move(0);", &options).hit);
+    fs::write(dir.join("enums.ts"), "export const enum Direction { Up = 3 }").unwrap();
+    assert!(transform(&cache, "move(0);", &options).hit);
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn eviction() {
+    let dir = create_dir("eviction");
+    let options = TransformOptions::default();
+    // All entries have the same size
+    let sources = ["let a = 1;", "let b = 1;", "let c = 1;", "let d = 1;"];
+
+    let entry_size = {
+        let cache = TransformCache::new(dir.join("measure"));
+        transform(&cache, sources[0], &options);
+        fs::read_dir(dir.join("measure"))
+            .unwrap()
+            .map(|entry| entry.unwrap().metadata().unwrap().len())
+            .sum::<u64>()
+    };
+
+    // Fits 2 entries
+    let cache = TransformCache::new(dir.join("cache")).with_max_size(entry_size * 5 / 2);
+    assert!(!transform(&cache, sources[0], &options).hit);
+    std::thread::sleep(std::time::Duration::from_millis(10));
+    assert!(!transform(&cache, sources[1], &options).hit);
+    std::thread::sleep(std::time::Duration::from_millis(10));
+    // Mark `a` as recently used
+    assert!(transform(&cache, sources[0], &options).hit);
+    std::thread::sleep(std::time::Duration::from_millis(10));
+
+    // Evicts `b`, the least recently used entry
+    assert!(!transform(&cache, sources[2], &options).hit);
+    assert_eq!(fs::read_dir(dir.join("cache")).unwrap().count(), 4);
+    assert!(transform(&cache, sources[0], &options).hit);
+    assert!(transform(&cache, sources[2], &options).hit);
+    std::thread::sleep(std::time::Duration::from_millis(10));
+
+    // Evicts `a`
+    assert!(!transform(&cache, sources[1], &options).hit);
+    assert!(transform(&cache, sources[2], &options).hit);
+    assert!(transform(&cache, sources[1], &options).hit);
+    assert!(!transform(&cache, sources[0], &options).hit);
+
+    // A new cache for the same directory counts existing entries
+    let cache = TransformCache::new(dir.join("cache")).with_max_size(entry_size * 5 / 2);
+    assert!(!transform(&cache, sources[3], &options).hit);
+    assert_eq!(fs::read_dir(dir.join("cache")).unwrap().count(), 4);
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn concurrent_transforms() {
+    let dir = create_dir("concurrent");
+    let cache = TransformCache::new(&dir);
+    let options = TransformOptions::default();
+    let source_text = "let a: number = 1;";
+
+    std::thread::scope(|scope| {
+        let handles = (0..8)
+            .map(|_| scope.spawn(|| transform(&cache, source_text, &options).code))
+            .collect::<Vec<_>>();
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), "let a = 1;\n");
+        }
+    });
+    assert!(transform(&cache, source_text, &options).hit);
+    assert_eq!(fs::read_dir(&dir).unwrap().count(), 2);
+
+    fs::remove_dir_all(dir).unwrap();
+}
@@ -1311,7 +1311,7 @@ impl<'a> GenExpr for StaticMemberExpression<'a> {
             p.print_hard_space();
         }
         p.print_ascii_byte(b'.');
-        self.property.print(p, ctx);
+        p.print_property_name(&self.property);
     }
 }
 
@@ -1529,7 +1529,7 @@ impl<'a> Gen for ObjectProperty<'a> {
             if key.name == "__proto__" {
                 shorthand = self.shorthand;
             } else if let Expression::Identifier(ident) = self.value.without_parentheses() {
-                if key.name == p.get_identifier_reference_name(ident) {
+                if p.get_property_name(&key.name) == p.get_identifier_reference_name(ident) {
                     shorthand = true;
                 }
             }
@@ -1567,7 +1567,7 @@ impl<'a> Gen for ObjectProperty<'a> {
 impl<'a> Gen for PropertyKey<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
         match self {
-            Self::StaticIdentifier(ident) => ident.print(p, ctx),
+            Self::StaticIdentifier(ident) => p.print_property_name(ident),
             Self::PrivateIdentifier(ident) => ident.print(p, ctx),
             match_expression!(Self) => {
                 self.to_expression().print_expr(p, Precedence::Comma, Context::empty());
@@ -1881,15 +1881,16 @@ impl<'a> Gen for AssignmentTargetProperty<'a> {
 
 impl<'a> Gen for AssignmentTargetPropertyIdentifier<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
-        let ident_name = p.get_identifier_reference_name(&self.binding).to_owned();
-        if ident_name == self.binding.name.as_str() {
+        let key_name = p.get_property_name(&self.binding.name);
+        let ident_name = p.get_identifier_reference_name(&self.binding);
+        if ident_name == key_name {
             self.binding.print(p, ctx);
         } else {
             // `({x: a} = y);`
-            p.print_str(self.binding.name.as_str());
+            p.print_str(key_name);
             p.print_colon();
             p.print_soft_space();
-            p.print_str(&ident_name);
+            p.print_str(ident_name);
         }
         if let Some(expr) = &self.init {
             p.print_soft_space();
@@ -1904,7 +1905,7 @@ impl<'a> Gen for AssignmentTargetPropertyProperty<'a> {
     fn gen(&self, p: &mut Codegen, ctx: Context) {
         match &self.name {
             PropertyKey::StaticIdentifier(ident) => {
-                ident.print(p, ctx);
+                p.print_property_name(ident);
             }
             PropertyKey::PrivateIdentifier(ident) => {
                 ident.print(p, ctx);
@@ -2051,13 +2052,13 @@ impl<'a> GenExpr for NewExpression<'a> {
             p.print_str("new ");
             self.callee.print_expr(p, Precedence::New, Context::FORBID_CALL);
             p.print_ascii_byte(b'(');
-            let has_comment = p.has_comment(self.span.end - 1)
+            let has_comment = p.has_comment(self.span.end.saturating_sub(1))
                 || self.arguments.iter().any(|item| p.has_comment(item.span().start));
             if has_comment {
                 p.indent();
                 p.print_list_with_comments(&self.arguments, ctx);
                 // Handle `/* comment */);`
-                if !p.print_expr_comments(self.span.end - 1) {
+                if !p.print_expr_comments(self.span.end.saturating_sub(1)) {
                     p.print_soft_newline();
                 }
                 p.dedent();
@@ -2612,9 +2613,10 @@ impl<'a> Gen for AccessorProperty<'a> {
 
 impl<'a> Gen for PrivateIdentifier<'a> {
     fn gen(&self, p: &mut Codegen, _ctx: Context) {
-        p.add_source_mapping_for_name(self.span, &self.name);
+        let name = p.get_private_name(&self.name);
+        p.add_source_mapping_for_name(self.span, name);
         p.print_ascii_byte(b'#');
-        p.print_str(self.name.as_str());
+        p.print_str(name);
     }
 }
 
@@ -2676,7 +2678,7 @@ impl<'a> Gen for BindingProperty<'a> {
         if let PropertyKey::StaticIdentifier(key) = &self.key {
             match &self.value.kind {
                 BindingPatternKind::BindingIdentifier(ident)
-                    if key.name == p.get_binding_identifier_name(ident) =>
+                    if p.get_property_name(&key.name) == p.get_binding_identifier_name(ident) =>
                 {
                     shorthand = true;
                 }
@@ -2684,7 +2686,7 @@ impl<'a> Gen for BindingProperty<'a> {
                     if let BindingPatternKind::BindingIdentifier(ident) =
                         &assignment_pattern.left.kind
                     {
-                        if key.name == p.get_binding_identifier_name(ident) {
+                        if p.get_property_name(&key.name) == p.get_binding_identifier_name(ident) {
                             shorthand = true;
                         }
                     }
@@ -15,7 +15,8 @@ mod sourcemap_builder;
 use std::{borrow::Cow, path::PathBuf};
 
 use oxc_ast::ast::{
-    BindingIdentifier, BlockStatement, Expression, IdentifierReference, Program, Statement,
+    BindingIdentifier, BlockStatement, Expression, IdentifierName, IdentifierReference, Program,
+    Statement,
 };
 use oxc_mangler::Mangler;
 use oxc_span::{GetSpan, Span};
@@ -563,6 +564,32 @@ impl<'a> Codegen<'a> {
         ident.name.as_str()
     }
 
+    fn get_property_name<'n>(&self, name: &'n str) -> &'n str {
+        if let Some(mangler) = &self.mangler {
+            if let Some(name) = mangler.get_property_name(name) {
+                // SAFETY: Hack the lifetime to be part of the allocator.
+                return unsafe { std::mem::transmute_copy(&name) };
+            }
+        }
+        name
+    }
+
+    fn get_private_name<'n>(&self, name: &'n str) -> &'n str {
+        if let Some(mangler) = &self.mangler {
+            if let Some(name) = mangler.get_private_name(name) {
+                // SAFETY: Hack the lifetime to be part of the allocator.
+                return unsafe { std::mem::transmute_copy(&name) };
+            }
+        }
+        name
+    }
+
+    fn print_property_name(&mut self, ident: &IdentifierName) {
+        let name = self.get_property_name(ident.name.as_str());
+        self.add_source_mapping_for_name(ident.span, name);
+        self.print_str(name);
+    }
+
     fn print_space_before_operator(&mut self, next: Operator) {
         if self.prev_op_end != self.code.len() {
             return;
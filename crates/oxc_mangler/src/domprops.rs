@@ -0,0 +1,105 @@
+use phf::{phf_set, Set};
+
+/// Properties of builtin objects and the DOM, which are never renamed or used as new names.
+///
+/// A subset of terser's [domprops](https://github.com/terser/terser/blob/master/tools/domprops.js),
+/// including short names such as `id` and `x`, which new names would otherwise collide with.
+pub(crate) const DOMPROPS: Set<&'static str> = phf_set! {
+    "BYTES_PER_ELEMENT", "E", "EPSILON", "LN10", "LN2", "LOG10E", "LOG2E", "MAX_SAFE_INTEGER",
+    "MAX_VALUE", "MIN_SAFE_INTEGER", "MIN_VALUE", "NEGATIVE_INFINITY", "NaN", "PI",
+    "POSITIVE_INFINITY", "SQRT1_2", "SQRT2", "URL", "UTC", "__defineGetter__", "__defineSetter__",
+    "__lookupGetter__", "__lookupSetter__", "__proto__", "abs", "accessKey", "acos", "acosh",
+    "action", "activeElement", "add", "addEventListener", "adoptNode", "after", "alert", "all",
+    "allSettled", "alt", "altKey", "anchor", "animate", "any", "append", "appendChild", "apply",
+    "arguments", "arrayBuffer", "asIntN", "asUintN", "asin", "asinh", "assert", "assign",
+    "asyncIterator", "at", "atan", "atan2", "atanh", "atob", "attachShadow", "attributes",
+    "autocomplete", "autofocus", "autoplay", "back", "background", "baseURI", "before", "big",
+    "bind", "blink", "blob", "blur", "body", "bold", "border", "bottom", "btoa", "bubbles",
+    "buffer", "button", "buttons", "byteLength", "byteOffset", "call", "callee", "caller",
+    "cancelAnimationFrame", "cancelable", "captureStackTrace", "catch", "cause", "cbrt", "ceil",
+    "ch", "changedTouches", "charAt", "charCode", "charCodeAt", "characterSet", "checkValidity",
+    "checked", "childElementCount", "childNodes", "children", "classList", "className", "clear",
+    "clearInterval", "clearTimeout", "click", "clientHeight", "clientLeft", "clientTop",
+    "clientWidth", "clientX", "clientY", "clone", "cloneNode", "close", "closest", "clz32", "code",
+    "codePointAt", "color", "cols", "compare", "compareDocumentPosition", "compareExchange",
+    "composed", "composedPath", "concat", "confirm", "console", "construct", "constructor",
+    "contains", "contentEditable", "contentType", "controls", "cookie", "copyWithin", "cos",
+    "cosh", "count", "create", "createComment", "createDocumentFragment", "createElement",
+    "createElementNS", "createEvent", "createTextNode", "cssText", "ctrlKey", "currentScript",
+    "currentTarget", "currentTime", "cx", "cy", "data", "dataset", "debug", "defaultPrevented",
+    "defaultView", "defineProperties", "defineProperty", "delete", "deleteProperty", "deltaMode",
+    "deltaX", "deltaY", "deltaZ", "deref", "description", "detached", "detail", "devicePixelRatio",
+    "dir", "disabled", "dispatchEvent", "display", "document", "documentElement", "domain", "done",
+    "dotAll", "download", "draggable", "duration", "dx", "dy", "elements", "ended", "endsWith",
+    "entries", "error", "errors", "eventPhase", "every", "exchange", "exec", "execCommand", "exp",
+    "expm1", "fetch", "files", "fill", "filter", "finally", "find", "findIndex", "findLast",
+    "findLastIndex", "firstChild", "firstElementChild", "fixed", "flags", "flat", "flatMap",
+    "floor", "focus", "font", "fontcolor", "fontsize", "forEach", "form", "formData", "forms",
+    "forward", "fr", "freeze", "from", "fromCharCode", "fromCodePoint", "fromEntries", "fround",
+    "fx", "fy", "get", "getAll", "getAttribute", "getAttributeNames", "getBigInt64",
+    "getBigUint64", "getBoundingClientRect", "getClientRects", "getComputedStyle", "getContext",
+    "getDate", "getDay", "getElementById", "getElementsByClassName", "getElementsByTagName",
+    "getFloat32", "getFloat64", "getFullYear", "getHours", "getInt16", "getInt32", "getInt8",
+    "getMilliseconds", "getMinutes", "getMonth", "getOwnPropertyDescriptor",
+    "getOwnPropertyDescriptors", "getOwnPropertyNames", "getOwnPropertySymbols",
+    "getPropertyValue", "getPrototypeOf", "getRootNode", "getSeconds", "getTime",
+    "getTimezoneOffset", "getUTCDate", "getUTCDay", "getUTCFullYear", "getUTCHours",
+    "getUTCMilliseconds", "getUTCMinutes", "getUTCMonth", "getUTCSeconds", "getUint16",
+    "getUint32", "getUint8", "getYear", "global", "globalThis", "go", "group", "groupBy",
+    "groupEnd", "groups", "has", "hasAttribute", "hasAttributes", "hasChildNodes", "hasFocus",
+    "hasIndices", "hasInstance", "hasOwn", "hasOwnProperty", "head", "headers", "height", "hidden",
+    "high", "history", "host", "hostname", "href", "hreflang", "hypot", "id", "ignoreCase",
+    "images", "importNode", "imul", "includes", "index", "indexOf", "indices", "inert", "info",
+    "innerHTML", "innerHeight", "innerText", "innerWidth", "input", "insertAdjacentElement",
+    "insertAdjacentHTML", "insertAdjacentText", "insertBefore", "is", "isArray", "isComposing",
+    "isConcatSpreadable", "isConnected", "isContentEditable", "isEqualNode", "isExtensible",
+    "isFinite", "isFrozen", "isInteger", "isNaN", "isPrototypeOf", "isSafeInteger", "isSameNode",
+    "isSealed", "isTrusted", "isWellFormed", "italics", "iterator", "join", "json", "k1", "k2",
+    "k3", "k4", "key", "keyCode", "keyFor", "keys", "label", "lang", "lastChild",
+    "lastElementChild", "lastIndex", "lastIndexOf", "lastMatch", "lastParen", "left",
+    "leftContext", "length", "link", "links", "list", "load", "localName", "localStorage",
+    "localeCompare", "location", "log", "log10", "log1p", "log2", "loop", "low", "map", "margin",
+    "match", "matchAll", "matchMedia", "matches", "max", "maxByteLength", "maxLength", "message",
+    "metaKey", "method", "min", "minLength", "movementX", "movementY", "multiline", "multiple",
+    "muted", "name", "namespaceURI", "navigator", "next", "nextElementSibling", "nextSibling",
+    "nodeName", "nodeType", "nodeValue", "normalize", "notify", "now", "of", "offsetHeight",
+    "offsetLeft", "offsetParent", "offsetTop", "offsetWidth", "offsetX", "offsetY", "ok",
+    "opacity", "open", "optimum", "options", "origin", "outerHTML", "outerHeight", "outerText",
+    "outerWidth", "ownKeys", "ownerDocument", "padEnd", "padStart", "padding", "pageX", "pageY",
+    "parentElement", "parentNode", "parse", "parseFloat", "parseInt", "password", "pathname",
+    "pattern", "pause", "paused", "placeholder", "play", "pointerId", "pointerType", "pop", "port",
+    "position", "postMessage", "pow", "prefix", "prepend", "pressure", "preventDefault",
+    "preventExtensions", "previousElementSibling", "previousSibling", "print", "prompt",
+    "propertyIsEnumerable", "protocol", "prototype", "push", "pushState", "querySelector",
+    "querySelectorAll", "queueMicrotask", "r", "race", "random", "raw", "readOnly", "readyState",
+    "redirected", "reduce", "reduceRight", "referrer", "register", "reject", "rel",
+    "relatedTarget", "remove", "removeAttribute", "removeChild", "removeEventListener",
+    "removeProperty", "repeat", "replace", "replaceAll", "replaceChild", "replaceChildren",
+    "replaceState", "replaceWith", "reportValidity", "requestAnimationFrame", "required", "reset",
+    "resizable", "resize", "resolve", "return", "reverse", "revocable", "right", "rightContext",
+    "round", "rows", "rx", "ry", "screen", "screenX", "screenY", "scripts", "scroll", "scrollBy",
+    "scrollHeight", "scrollIntoView", "scrollLeft", "scrollTo", "scrollTop", "scrollWidth", "seal",
+    "search", "select", "selected", "selectedIndex", "self", "sessionStorage", "set",
+    "setAttribute", "setBigInt64", "setBigUint64", "setCustomValidity", "setDate", "setFloat32",
+    "setFloat64", "setFullYear", "setHours", "setInt16", "setInt32", "setInt8", "setInterval",
+    "setMilliseconds", "setMinutes", "setMonth", "setProperty", "setPrototypeOf", "setSeconds",
+    "setTime", "setTimeout", "setUTCDate", "setUTCFullYear", "setUTCHours", "setUTCMilliseconds",
+    "setUTCMinutes", "setUTCMonth", "setUTCSeconds", "setUint16", "setUint32", "setUint8",
+    "setYear", "shadowRoot", "shift", "shiftKey", "sign", "sin", "sinh", "size", "slice", "slot",
+    "small", "some", "sort", "source", "species", "spellcheck", "splice", "split", "sqrt", "src",
+    "srcElement", "stack", "stackTraceLimit", "startsWith", "state", "status", "statusText",
+    "step", "sticky", "stopImmediatePropagation", "stopPropagation", "store", "strike",
+    "stringify", "structuredClone", "style", "sub", "subarray", "submit", "substr", "substring",
+    "sup", "tabIndex", "table", "tagName", "tan", "tanh", "target", "targetTouches", "test",
+    "text", "textContent", "then", "throw", "time", "timeEnd", "timeStamp", "title", "toBlob",
+    "toDataURL", "toDateString", "toExponential", "toFixed", "toGMTString", "toISOString",
+    "toJSON", "toLocaleDateString", "toLocaleLowerCase", "toLocaleString", "toLocaleTimeString",
+    "toLocaleUpperCase", "toLowerCase", "toPrecision", "toPrimitive", "toReversed", "toSorted",
+    "toSpliced", "toString", "toStringTag", "toTimeString", "toUTCString", "toUpperCase",
+    "toWellFormed", "toggleAttribute", "top", "touches", "trace", "transfer",
+    "transferToFixedLength", "transform", "transition", "translate", "trim", "trimEnd", "trimLeft",
+    "trimRight", "trimStart", "trunc", "type", "unicode", "unicodeSets", "unregister",
+    "unscopables", "unshift", "url", "username", "validity", "value", "valueAsNumber", "valueOf",
+    "values", "visibilityState", "volume", "w", "wait", "waitAsync", "warn", "which", "width",
+    "window", "with", "withResolvers", "x", "x1", "x2", "y", "y1", "y2", "z", "zIndex",
+};
@@ -1,14 +1,97 @@
 use itertools::Itertools;
-use oxc_ast::ast::Program;
+use oxc_ast::{
+    ast::{Expression, Program},
+    AstKind,
+};
 use oxc_index::{index_vec, Idx, IndexVec};
-use oxc_semantic::{ReferenceId, ScopeTree, SemanticBuilder, SymbolId, SymbolTable};
+use oxc_semantic::{
+    ReferenceId, Semantic, SemanticBuilder, SymbolFlags, SymbolId, SymbolTable, UNBOUND_SCOPE_ID,
+};
 use oxc_span::CompactStr;
+use regex::Regex;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+mod domprops;
+mod name_cache;
+mod properties;
+
+pub use name_cache::NameCache;
+use properties::PropertyNames;
 
 type Slot = usize;
 
-#[derive(Default)]
+#[derive(Debug, Default, Clone)]
 pub struct MangleOptions {
     pub debug: bool,
+
+    /// Mangle names in the top level scope, for modules and IIFE output.
+    ///
+    /// Exported names are never mangled.
+    pub top_level: bool,
+
+    /// Names which are never mangled, and never used as mangled names.
+    pub reserved: Vec<String>,
+
+    /// Keep names of functions, for code relying on `Function.prototype.name`.
+    pub keep_fnames: KeepNames,
+
+    /// Keep names of classes.
+    pub keep_classnames: KeepNames,
+
+    /// Names from previous builds, see [`Mangler::name_cache`].
+    pub name_cache: NameCache,
+
+    /// Rename properties, disabled by default.
+    pub properties: Option<ManglePropertiesOptions>,
+}
+
+/// Which names to keep for [`MangleOptions::keep_fnames`] and
+/// [`MangleOptions::keep_classnames`].
+#[derive(Debug, Default, Clone)]
+pub enum KeepNames {
+    #[default]
+    None,
+    All,
+    /// Keep names matching the regex.
+    Matching(Regex),
+}
+
+impl KeepNames {
+    pub fn keeps(&self, name: &str) -> bool {
+        match self {
+            Self::None => false,
+            Self::All => true,
+            Self::Matching(regex) => regex.is_match(name),
+        }
+    }
+}
+
+/// Options for renaming object and class properties.
+///
+/// Property names are global, so only properties which are private by convention should be
+/// renamed, e.g. names matching `^_`.
+/// Quoted properties (`a["_b"]`, `{ "_b": 1 }`, `"_b" in a`) are never renamed.
+/// Properties of builtin objects and the DOM, e.g. `length` and `id`, are never renamed,
+/// and never used as new names.
+#[derive(Debug, Default, Clone)]
+pub struct ManglePropertiesOptions {
+    /// Only rename properties matching this regex.
+    ///
+    /// No properties are renamed when `None`.
+    pub regex: Option<Regex>,
+
+    /// Names which are never renamed, and never used as new names.
+    pub reserved: Vec<String>,
+
+    /// Original names mapped to new names from previous runs, for consistent names across
+    /// chunks. Takes precedence over [`NameCache::props`].
+    ///
+    /// The cache with names from this run added is returned by
+    /// [`Mangler::property_name_cache`].
+    pub name_cache: FxHashMap<CompactStr, CompactStr>,
+
+    /// Shorten `#private` class member names.
+    pub private: bool,
 }
 
 /// # Name Mangler / Symbol Minification
@@ -56,6 +139,11 @@ pub struct MangleOptions {
 pub struct Mangler {
     symbol_table: SymbolTable,
 
+    /// Top level names mangled by [`Mangler::build`], with names from the options' cache.
+    var_names: FxHashMap<CompactStr, CompactStr>,
+
+    property_names: PropertyNames,
+
     options: MangleOptions,
 }
 
@@ -80,10 +168,52 @@ impl Mangler {
         Some(self.symbol_table.get_name(symbol_id))
     }
 
+    /// New name of a property, `None` if it is not renamed.
+    pub fn get_property_name(&self, name: &str) -> Option<&str> {
+        self.property_names.get_property_name(name).map(CompactStr::as_str)
+    }
+
+    /// New name of a `#private` class member, without the `#`. `None` if it is not renamed.
+    pub fn get_private_name(&self, name: &str) -> Option<&str> {
+        self.property_names.private_names.get(name).map(CompactStr::as_str)
+    }
+
+    /// [`ManglePropertiesOptions::name_cache`] with the property names renamed by
+    /// [`Mangler::build`] added.
+    ///
+    /// Pass it to the next run for consistent names across chunks.
+    pub fn property_name_cache(&self) -> &FxHashMap<CompactStr, CompactStr> {
+        &self.property_names.properties
+    }
+
+    /// [`MangleOptions::name_cache`] with the names mangled by [`Mangler::build`] added.
+    ///
+    /// Pass it to the next build for stable names across builds and chunks.
+    pub fn name_cache(&self) -> NameCache {
+        let mut name_cache = self.options.name_cache.clone();
+        name_cache.vars.extend(self.var_names.iter().map(|(k, v)| (k.clone(), v.clone())));
+        name_cache
+            .props
+            .extend(self.property_names.properties.iter().map(|(k, v)| (k.clone(), v.clone())));
+        name_cache
+    }
+
     #[must_use]
     pub fn build<'a>(mut self, program: &'a Program<'a>) -> Mangler {
+        if let Some(options) = &self.options.properties {
+            self.property_names = PropertyNames::new(
+                program,
+                options,
+                &self.options.name_cache.props,
+                self.options.debug,
+            );
+        }
+
         let semantic = SemanticBuilder::new().build(program).semantic;
 
+        // Symbols which keep their names, and names which must not be used as mangled names
+        let (mut kept_symbols, mut kept_names) = self.keep_names(&semantic);
+
         // Mangle the symbol table by computing slots from the scope tree.
         // A slot is the occurrence index of a binding identifier inside a scope.
         let (mut symbol_table, scope_tree) = semantic.into_symbol_table_and_scope_tree();
@@ -94,6 +224,31 @@ impl Mangler {
         // All symbols with their assigned slots
         let mut slots: IndexVec<SymbolId, Slot> = index_vec![0; symbol_table.len()];
 
+        let root_unresolved_references = scope_tree.root_unresolved_references();
+
+        // Reuse top level names from previous builds, unless they collide with a kept name,
+        // a global, or a cached name used by another symbol
+        if self.options.top_level {
+            let mut cached_names = FxHashSet::default();
+            for &symbol_id in scope_tree.get_bindings(scope_tree.root_scope_id()).values() {
+                if kept_symbols[symbol_id] {
+                    continue;
+                }
+                let name = symbol_table.get_name(symbol_id);
+                let Some(new_name) = self.options.name_cache.vars.get(name) else { continue };
+                if kept_names.contains(new_name)
+                    || root_unresolved_references.contains_key(new_name)
+                    || !cached_names.insert(new_name.clone())
+                {
+                    continue;
+                }
+                symbol_table.set_name(symbol_id, new_name.clone());
+                kept_symbols[symbol_id] = true;
+            }
+        }
+        // Names of other chunks are not reused
+        kept_names.extend(self.options.name_cache.vars.values().cloned());
+
         // Keep track of the maximum slot number for each scope
         let mut max_slot_for_scope = vec![0; scope_tree.len()];
 
@@ -123,17 +278,18 @@ impl Mangler {
             }
         }
 
-        let frequencies =
-            Self::tally_slot_frequencies(&symbol_table, &scope_tree, total_number_of_slots, &slots);
-
-        let root_unresolved_references = scope_tree.root_unresolved_references();
-        let root_bindings = scope_tree.get_bindings(scope_tree.root_scope_id());
+        let frequencies = Self::tally_slot_frequencies(
+            &symbol_table,
+            &kept_symbols,
+            total_number_of_slots,
+            &slots,
+        );
 
-        let mut reserved_names = Vec::with_capacity(total_number_of_slots);
+        let mut reserved_names = Vec::with_capacity(frequencies.len());
 
         let generate_name = if self.options.debug { debug_name } else { base54 };
         let mut count = 0;
-        for _ in 0..total_number_of_slots {
+        for _ in 0..frequencies.len() {
             let name = loop {
                 let name = generate_name(count);
                 count += 1;
@@ -142,7 +298,7 @@ impl Mangler {
                 if !is_keyword(n)
                     && !is_special_name(n)
                     && !root_unresolved_references.contains_key(n)
-                    && !root_bindings.contains_key(n)
+                    && !kept_names.contains(n)
                 {
                     break name;
                 }
@@ -196,23 +352,87 @@ impl Mangler {
             }
         }
 
+        if self.options.top_level {
+            // Bindings are keyed by the original names
+            for (name, &symbol_id) in scope_tree.get_bindings(scope_tree.root_scope_id()) {
+                let new_name = symbol_table.get_name(symbol_id);
+                if new_name != name {
+                    self.var_names.insert(name.clone(), CompactStr::from(new_name));
+                }
+            }
+        }
+
         self.symbol_table = symbol_table;
         self
     }
 
+    /// Find symbols which are not mangled, and the names which mangled names must not collide
+    /// with.
+    fn keep_names(&self, semantic: &Semantic) -> (IndexVec<SymbolId, bool>, FxHashSet<CompactStr>) {
+        let options = &self.options;
+        let symbol_table = semantic.symbols();
+        let root_scope_id = semantic.scopes().root_scope_id();
+        let mut kept_symbols = index_vec![false; symbol_table.len()];
+        let mut kept_names = options
+            .reserved
+            .iter()
+            .map(|name| CompactStr::from(name.as_str()))
+            .collect::<FxHashSet<_>>();
+
+        for symbol_id in symbol_table.symbol_ids() {
+            let name = symbol_table.get_name(symbol_id);
+            // Function declarations in sloppy mode are not flagged as functions
+            let keep_name = match semantic.nodes().kind(symbol_table.get_declaration(symbol_id)) {
+                AstKind::Function(_) => &options.keep_fnames,
+                AstKind::Class(_) => &options.keep_classnames,
+                // `const f = () => {}` and `const A = class {}` are named after the variable
+                AstKind::VariableDeclarator(decl) if decl.id.kind.is_binding_identifier() => {
+                    match decl.init.as_ref().map(Expression::without_parentheses) {
+                        Some(Expression::FunctionExpression(func)) if func.id.is_none() => {
+                            &options.keep_fnames
+                        }
+                        Some(Expression::ArrowFunctionExpression(_)) => &options.keep_fnames,
+                        Some(Expression::ClassExpression(class)) if class.id.is_none() => {
+                            &options.keep_classnames
+                        }
+                        _ => &KeepNames::None,
+                    }
+                }
+                _ => &KeepNames::None,
+            };
+            let scope_id = symbol_table.get_scope_id(symbol_id);
+            // References never resolve to functions which are not bound to any scope,
+            // so renaming them would break references to the function.
+            let is_unbound = scope_id == UNBOUND_SCOPE_ID;
+            // May be looked up by name in a direct `eval` or `with`
+            let in_direct_eval_scope =
+                !is_unbound && semantic.scopes().get_flags(scope_id).contains_direct_eval();
+            let keep = is_special_name(name)
+                || is_unbound
+                || in_direct_eval_scope
+                || options.reserved.iter().any(|reserved| reserved == name)
+                || keep_name.keeps(name)
+                || (scope_id == root_scope_id
+                    && (!options.top_level
+                        || symbol_table.get_flags(symbol_id).contains(SymbolFlags::Export)));
+            if keep {
+                kept_symbols[symbol_id] = true;
+                kept_names.insert(CompactStr::from(name));
+            }
+        }
+
+        (kept_symbols, kept_names)
+    }
+
     fn tally_slot_frequencies(
         symbol_table: &SymbolTable,
-        scope_tree: &ScopeTree,
+        kept_symbols: &IndexVec<SymbolId, bool>,
         total_number_of_slots: usize,
         slots: &IndexVec<SymbolId, Slot>,
     ) -> Vec<SlotFrequency> {
-        let root_scope_id = scope_tree.root_scope_id();
         let mut frequencies = vec![SlotFrequency::default(); total_number_of_slots];
         for (symbol_id, slot) in slots.iter_enumerated() {
-            if symbol_table.get_scope_id(symbol_id) == root_scope_id {
-                continue;
-            }
-            if is_special_name(symbol_table.get_name(symbol_id)) {
+            if kept_symbols[symbol_id] {
                 continue;
             }
             let index = *slot;
@@ -221,6 +441,8 @@ impl Mangler {
                 symbol_table.get_resolved_reference_ids(symbol_id).len();
             frequencies[index].symbol_ids.push(symbol_id);
         }
+        // Slots of only kept symbols don't need a name
+        frequencies.retain(|x| !x.symbol_ids.is_empty());
         frequencies.sort_unstable_by_key(|x| std::cmp::Reverse(x.frequency));
         frequencies
     }
@@ -0,0 +1,78 @@
+use std::collections::BTreeMap;
+
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+
+use oxc_span::CompactStr;
+
+/// Original names mapped to mangled names, for stable names across builds and chunks.
+///
+/// Pass the cache returned by [`crate::Mangler::name_cache`] to the next build with
+/// [`crate::MangleOptions::name_cache`].
+///
+/// The JSON format is the same as terser's `nameCache`:
+///
+/// ```json
+/// { "vars": { "props": { "$foo": "a" } }, "props": { "props": { "$_bar": "b" } } }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct NameCache {
+    /// Top level names, used with [`crate::MangleOptions::top_level`].
+    pub vars: FxHashMap<CompactStr, CompactStr>,
+
+    /// Property names, used with [`crate::MangleOptions::properties`].
+    pub props: FxHashMap<CompactStr, CompactStr>,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct TerserNameCache {
+    #[serde(default)]
+    vars: TerserProps,
+    #[serde(default)]
+    props: TerserProps,
+}
+
+/// Names are prefixed with `$`, so names such as `__proto__` can be used as keys in JavaScript.
+#[derive(Default, Serialize, Deserialize)]
+struct TerserProps {
+    #[serde(default)]
+    props: BTreeMap<String, String>,
+}
+
+impl TerserProps {
+    fn from_names(names: &FxHashMap<CompactStr, CompactStr>) -> Self {
+        let props = names.iter().map(|(name, new_name)| (format!("${name}"), new_name.to_string()));
+        Self { props: props.collect() }
+    }
+
+    fn into_names(self) -> FxHashMap<CompactStr, CompactStr> {
+        self.props
+            .into_iter()
+            .map(|(name, new_name)| {
+                let name = name.strip_prefix('$').unwrap_or(&name);
+                (CompactStr::from(name), CompactStr::from(new_name))
+            })
+            .collect()
+    }
+}
+
+impl NameCache {
+    /// Read a cache in terser's `nameCache` format.
+    ///
+    /// # Errors
+    ///
+    /// * `json` is not a valid name cache.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        let cache = serde_json::from_str::<TerserNameCache>(json)?;
+        Ok(Self { vars: cache.vars.into_names(), props: cache.props.into_names() })
+    }
+
+    /// Write the cache in terser's `nameCache` format, with names sorted for stable output.
+    pub fn to_json(&self) -> String {
+        let cache = TerserNameCache {
+            vars: TerserProps::from_names(&self.vars),
+            props: TerserProps::from_names(&self.props),
+        };
+        serde_json::to_string(&cache).unwrap_or_default()
+    }
+}
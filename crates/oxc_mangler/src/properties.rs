@@ -0,0 +1,180 @@
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_ast::{
+    ast::{
+        AssignmentTargetPropertyIdentifier, BinaryExpression, BinaryOperator,
+        ComputedMemberExpression, Expression, PrivateIdentifier, Program, PropertyKey,
+        StaticMemberExpression,
+    },
+    visit::walk,
+    Visit,
+};
+use oxc_span::CompactStr;
+
+use crate::{base54, debug_name, domprops::DOMPROPS, ManglePropertiesOptions};
+
+/// Occurrences of a property name.
+#[derive(Default)]
+struct Occurrence {
+    /// Order in which the name first appears in the source.
+    first: usize,
+    count: usize,
+}
+
+/// Collect property names which can be renamed, and all other property names, which new names
+/// must not collide with.
+#[derive(Default)]
+struct CollectProperties<'o> {
+    options: Option<&'o ManglePropertiesOptions>,
+    properties: FxHashMap<CompactStr, Occurrence>,
+    private_names: FxHashMap<CompactStr, Occurrence>,
+    unmangled: FxHashSet<CompactStr>,
+    /// Names used in quotes, `a["_b"]`, `{ "_b": 1 }` and `"_b" in a`.
+    quoted: FxHashSet<CompactStr>,
+}
+
+impl<'o> CollectProperties<'o> {
+    fn add_property(&mut self, name: &str) {
+        let Some(options) = self.options else { return };
+        if options.is_mangled(name) {
+            let first = self.properties.len();
+            self.properties
+                .entry(CompactStr::from(name))
+                .or_insert(Occurrence { first, count: 0 })
+                .count += 1;
+        } else if !self.unmangled.contains(name) {
+            self.unmangled.insert(CompactStr::from(name));
+        }
+    }
+}
+
+impl<'a, 'o> Visit<'a> for CollectProperties<'o> {
+    fn visit_property_key(&mut self, key: &PropertyKey<'a>) {
+        match key {
+            PropertyKey::StaticIdentifier(ident) => self.add_property(&ident.name),
+            // Quoted names are not renamed
+            PropertyKey::StringLiteral(lit) => {
+                self.quoted.insert(CompactStr::from(lit.value.as_str()));
+            }
+            _ => {}
+        }
+        walk::walk_property_key(self, key);
+    }
+
+    fn visit_static_member_expression(&mut self, expr: &StaticMemberExpression<'a>) {
+        self.add_property(&expr.property.name);
+        walk::walk_static_member_expression(self, expr);
+    }
+
+    fn visit_computed_member_expression(&mut self, expr: &ComputedMemberExpression<'a>) {
+        if let Expression::StringLiteral(lit) = &expr.expression {
+            self.quoted.insert(CompactStr::from(lit.value.as_str()));
+        }
+        walk::walk_computed_member_expression(self, expr);
+    }
+
+    fn visit_binary_expression(&mut self, expr: &BinaryExpression<'a>) {
+        if let (BinaryOperator::In, Expression::StringLiteral(lit)) = (expr.operator, &expr.left) {
+            self.quoted.insert(CompactStr::from(lit.value.as_str()));
+        }
+        walk::walk_binary_expression(self, expr);
+    }
+
+    fn visit_assignment_target_property_identifier(
+        &mut self,
+        it: &AssignmentTargetPropertyIdentifier<'a>,
+    ) {
+        // `({ _a } = b)`
+        self.add_property(&it.binding.name);
+        walk::walk_assignment_target_property_identifier(self, it);
+    }
+
+    fn visit_private_identifier(&mut self, ident: &PrivateIdentifier<'a>) {
+        if self.options.is_some_and(|options| options.private) {
+            let first = self.private_names.len();
+            self.private_names
+                .entry(CompactStr::from(ident.name.as_str()))
+                .or_insert(Occurrence { first, count: 0 })
+                .count += 1;
+        }
+    }
+}
+
+impl ManglePropertiesOptions {
+    fn is_mangled(&self, name: &str) -> bool {
+        // Builtins, including `__proto__` which sets the prototype in object literals
+        !DOMPROPS.contains(name)
+            && self.regex.as_ref().is_some_and(|regex| regex.is_match(name))
+            && !self.reserved.iter().any(|reserved| reserved == name)
+    }
+}
+
+/// New names of properties and `#private` names.
+#[derive(Debug, Default)]
+pub(crate) struct PropertyNames {
+    /// Name cache from the options, with the names assigned in this program added.
+    pub properties: FxHashMap<CompactStr, CompactStr>,
+    pub private_names: FxHashMap<CompactStr, CompactStr>,
+    /// Quoted names are not renamed, even if they are in the name cache.
+    quoted: FxHashSet<CompactStr>,
+}
+
+impl PropertyNames {
+    pub(crate) fn new(
+        program: &Program,
+        options: &ManglePropertiesOptions,
+        name_cache: &FxHashMap<CompactStr, CompactStr>,
+        debug: bool,
+    ) -> Self {
+        let mut collector = CollectProperties { options: Some(options), ..Default::default() };
+        collector.visit_program(program);
+        let generate_name = if debug { debug_name } else { base54 };
+
+        // Reuse names from the caches, for consistent names across chunks
+        let mut properties = name_cache.clone();
+        properties.extend(options.name_cache.iter().map(|(k, v)| (k.clone(), v.clone())));
+        let mut used = properties.values().cloned().collect::<FxHashSet<_>>();
+        used.extend(collector.unmangled);
+        used.extend(collector.quoted.iter().cloned());
+        used.extend(options.reserved.iter().map(|name| CompactStr::from(name.as_str())));
+
+        let mut count = 0;
+        for name in Self::sort_by_frequency(collector.properties) {
+            if properties.contains_key(&name) || collector.quoted.contains(&name) {
+                continue;
+            }
+            let new_name = loop {
+                let new_name = generate_name(count);
+                count += 1;
+                if !used.contains(&new_name) && !DOMPROPS.contains(new_name.as_str()) {
+                    break new_name;
+                }
+            };
+            used.insert(new_name.clone());
+            properties.insert(name, new_name);
+        }
+
+        // Private names are scoped to the class, so they can't collide with other names
+        let private_names = Self::sort_by_frequency(collector.private_names)
+            .into_iter()
+            .enumerate()
+            .map(|(i, name)| (name, generate_name(i)))
+            .collect();
+
+        Self { properties, private_names, quoted: collector.quoted }
+    }
+
+    pub(crate) fn get_property_name(&self, name: &str) -> Option<&CompactStr> {
+        if self.quoted.contains(name) {
+            return None;
+        }
+        self.properties.get(name)
+    }
+
+    /// Most frequent names first, so they get the shortest names.
+    fn sort_by_frequency(names: FxHashMap<CompactStr, Occurrence>) -> Vec<CompactStr> {
+        let mut names = names.into_iter().collect::<Vec<_>>();
+        names.sort_unstable_by(|(_, a), (_, b)| b.count.cmp(&a.count).then(a.first.cmp(&b.first)));
+        names.into_iter().map(|(name, _)| name).collect()
+    }
+}
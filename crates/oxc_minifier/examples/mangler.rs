@@ -38,6 +38,8 @@ fn main() -> std::io::Result<()> {
 fn mangler(source_text: &str, source_type: SourceType, debug: bool) -> String {
     let allocator = Allocator::default();
     let ret = Parser::new(&allocator, source_text, source_type).parse();
-    let mangler = Mangler::new().with_options(MangleOptions { debug }).build(&ret.program);
+    let mangler = Mangler::new()
+        .with_options(MangleOptions { debug, ..MangleOptions::default() })
+        .build(&ret.program);
     CodeGenerator::new().with_mangler(Some(mangler)).build(&ret.program).code
 }
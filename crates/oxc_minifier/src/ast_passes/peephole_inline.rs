@@ -0,0 +1,695 @@
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::{CloneIn, Vec};
+use oxc_ast::{ast::*, Visit};
+use oxc_semantic::Reference;
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::{scope::ScopeId, symbol::SymbolId};
+use oxc_traverse::{Ancestor, Traverse, TraverseCtx};
+
+use crate::{options::InlineLevel, CompressOptions, CompressorPass};
+
+/// Inline constants and functions.
+///
+/// * `const` variables with literal values, e.g. `const a = 1; f(a)` -> `f(1)`
+/// * Functions which are called once and only return an expression,
+///   e.g. `function f(a) { return a + 1 } g(f(1))` -> `g(1 + 1)`
+///
+/// Functions are inlined if they don't use `this`, `arguments`, `super` or `new.target`,
+/// all references in the returned expression resolve to the same bindings at the call site,
+/// and the arguments are literals or variables which are never reassigned.
+///
+/// Inlined functions are removed, unused constants are removed by `RemoveUnusedDeclarations`.
+/// Nothing is inlined if the program references `eval`, or where a direct `eval` or `with` may
+/// look up the inlined binding by name.
+///
+/// Terser option: `inline`.
+pub struct PeepholeInline<'a> {
+    options: CompressOptions,
+
+    /// Literal values of `const` variables, with the end of their declarator.
+    /// Only references after the declaration are inlined, to keep TDZ errors.
+    constants: FxHashMap<SymbolId, (Expression<'a>, u32)>,
+
+    /// Functions which may be inlined at their call site.
+    functions: FxHashMap<SymbolId, InlineFunction<'a>>,
+
+    /// Arguments of the function being inlined, to replace references to its parameters.
+    arguments: FxHashMap<SymbolId, Expression<'a>>,
+
+    changed: bool,
+}
+
+struct InlineFunction<'a> {
+    /// The returned expression, taken out of the function after it is visited until it is
+    /// inlined, or restored when leaving the statements which declare the function.
+    body: Option<Expression<'a>>,
+
+    params: std::vec::Vec<SymbolId>,
+
+    /// References in `body` other than the parameters, which must resolve to the same symbols
+    /// at the call site.
+    free_references: std::vec::Vec<(Atom<'a>, Option<SymbolId>)>,
+
+    /// End of the `const` declarator of an arrow function or function expression.
+    /// Calls before it are not inlined, to keep TDZ errors.
+    declaration_end: Option<u32>,
+
+    inlined: bool,
+}
+
+impl<'a> CompressorPass<'a> for PeepholeInline<'a> {
+    fn changed(&self) -> bool {
+        self.changed
+    }
+
+    fn build(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.changed = false;
+        if ctx.scopes().root_unresolved_references().contains_key("eval") {
+            return;
+        }
+        oxc_traverse::walk_program(self, program, ctx);
+        self.constants.clear();
+    }
+}
+
+impl<'a> Traverse<'a> for PeepholeInline<'a> {
+    fn enter_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
+        for stmt in stmts.iter_mut() {
+            if let Statement::VariableDeclaration(decl) = stmt {
+                if decl.kind.is_const() {
+                    for declarator in &decl.declarations {
+                        self.collect_const(declarator, ctx);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Functions are taken after they are visited, so constants and calls in the returned
+    /// expression are inlined even if the function is not.
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        match stmt {
+            // Function declarations in blocks have Annex B semantics
+            Statement::FunctionDeclaration(func)
+                if matches!(
+                    ctx.parent(),
+                    Ancestor::ProgramBody(_) | Ancestor::FunctionBodyStatements(_)
+                ) =>
+            {
+                self.take_function(func, None, ctx);
+            }
+            Statement::VariableDeclaration(decl) if decl.kind.is_const() => {
+                for declarator in decl.declarations.iter_mut() {
+                    self.take_function_expression(declarator, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn exit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, _ctx: &mut TraverseCtx<'a>) {
+        if self.functions.is_empty() {
+            return;
+        }
+        stmts.retain_mut(|stmt| match stmt {
+            Statement::FunctionDeclaration(func) => {
+                let Some(symbol_id) = func.id.as_ref().and_then(|id| id.symbol_id.get()) else {
+                    return true;
+                };
+                let Some(function) = self.functions.remove(&symbol_id) else { return true };
+                if function.inlined {
+                    return false;
+                }
+                if let Some(body) = func.body.as_mut() {
+                    Self::restore_body(&mut body.statements, function.body);
+                }
+                true
+            }
+            Statement::VariableDeclaration(decl) if decl.kind.is_const() => {
+                decl.declarations.retain_mut(|declarator| {
+                    let BindingPatternKind::BindingIdentifier(id) = &declarator.id.kind else {
+                        return true;
+                    };
+                    let Some(symbol_id) = id.symbol_id.get() else { return true };
+                    let Some(function) = self.functions.remove(&symbol_id) else { return true };
+                    if function.inlined {
+                        return false;
+                    }
+                    match &mut declarator.init {
+                        Some(Expression::ArrowFunctionExpression(arrow)) => {
+                            Self::restore_body(&mut arrow.body.statements, function.body);
+                        }
+                        Some(Expression::FunctionExpression(func)) => {
+                            if let Some(body) = func.body.as_mut() {
+                                Self::restore_body(&mut body.statements, function.body);
+                            }
+                        }
+                        _ => {}
+                    }
+                    true
+                });
+                !decl.declarations.is_empty()
+            }
+            _ => true,
+        });
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Expression::CallExpression(call) = expr {
+            if let Some(inlined) = self.try_inline_call(call, ctx) {
+                *expr = inlined;
+                self.changed = true;
+            }
+        }
+        self.try_inline_identifier(expr, ctx);
+    }
+
+    fn exit_object_property(&mut self, prop: &mut ObjectProperty<'a>, _ctx: &mut TraverseCtx<'a>) {
+        // `{ a }` -> `{ a: 1 }`
+        if prop.shorthand && !matches!(prop.value, Expression::Identifier(_)) {
+            prop.shorthand = false;
+        }
+    }
+}
+
+impl<'a> PeepholeInline<'a> {
+    pub fn new(options: CompressOptions) -> Self {
+        Self {
+            options,
+            constants: FxHashMap::default(),
+            functions: FxHashMap::default(),
+            arguments: FxHashMap::default(),
+            changed: false,
+        }
+    }
+
+    fn collect_const(&mut self, declarator: &VariableDeclarator<'a>, ctx: &TraverseCtx<'a>) {
+        let BindingPatternKind::BindingIdentifier(id) = &declarator.id.kind else { return };
+        let Some(symbol_id) = id.symbol_id.get() else { return };
+        let Some(init) = &declarator.init else { return };
+        if self.options.inline < InlineLevel::Constants || !Self::is_literal(init) {
+            return;
+        }
+        if ctx.symbols().get_resolved_references(symbol_id).any(Reference::is_write) {
+            return;
+        }
+        if ctx.symbols().get_resolved_reference_ids(symbol_id).len() > 1
+            && !Self::is_small_literal(init)
+        {
+            return;
+        }
+        self.constants.insert(symbol_id, (init.clone_in(ctx.ast.allocator), declarator.span.end));
+    }
+
+    /// Take the returned expression out of the arrow function or function expression
+    /// `declarator` is initialized with, if it may be inlined.
+    fn take_function_expression(
+        &mut self,
+        declarator: &mut VariableDeclarator<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) {
+        if self.options.inline < InlineLevel::Functions {
+            return;
+        }
+        let BindingPatternKind::BindingIdentifier(id) = &declarator.id.kind else { return };
+        let Some(symbol_id) = id.symbol_id.get() else { return };
+        let declaration_end = declarator.span.end;
+        match declarator.init.as_mut() {
+            Some(Expression::ArrowFunctionExpression(arrow)) if arrow.expression => {
+                let arrow = &mut **arrow;
+                if arrow.r#async {
+                    return;
+                }
+                let Some(Statement::ExpressionStatement(stmt)) = arrow.body.statements.first_mut()
+                else {
+                    return;
+                };
+                self.take_body(
+                    symbol_id,
+                    &arrow.params,
+                    &mut stmt.expression,
+                    Some(declaration_end),
+                    ctx,
+                );
+            }
+            Some(Expression::FunctionExpression(func)) if func.id.is_none() => {
+                self.take_function(func, Some((symbol_id, declaration_end)), ctx);
+            }
+            _ => {}
+        }
+    }
+
+    /// Take the returned expression out of `func` if it may be inlined.
+    ///
+    /// `declarator` is the symbol and end of the `const` declarator for function expressions.
+    fn take_function(
+        &mut self,
+        func: &mut Function<'a>,
+        declarator: Option<(SymbolId, u32)>,
+        ctx: &TraverseCtx<'a>,
+    ) {
+        if self.options.inline < InlineLevel::Functions
+            || func.r#async
+            || func.generator
+            || func.declare
+            || func.this_param.is_some()
+        {
+            return;
+        }
+        let (symbol_id, declaration_end) = match declarator {
+            Some((symbol_id, end)) => (symbol_id, Some(end)),
+            None => match func.id.as_ref().and_then(|id| id.symbol_id.get()) {
+                Some(symbol_id) => (symbol_id, None),
+                None => return,
+            },
+        };
+        let Some(body) = func.body.as_mut() else { return };
+        if body.statements.len() != 1 || !body.directives.is_empty() {
+            return;
+        }
+        let Some(Statement::ReturnStatement(ret)) = body.statements.first_mut() else { return };
+        let Some(argument) = &mut ret.argument else { return };
+        self.take_body(symbol_id, &func.params, argument, declaration_end, ctx);
+    }
+
+    fn take_body(
+        &mut self,
+        symbol_id: SymbolId,
+        params: &FormalParameters<'a>,
+        body: &mut Expression<'a>,
+        declaration_end: Option<u32>,
+        ctx: &TraverseCtx<'a>,
+    ) {
+        // Called once, and will be removed after inlining
+        let references = ctx.symbols().get_resolved_reference_ids(symbol_id);
+        if references.len() != 1 {
+            return;
+        }
+        if !self.options.toplevel
+            && ctx.symbols().get_scope_id(symbol_id) == ctx.scopes().root_scope_id()
+        {
+            return;
+        }
+
+        if params.rest.is_some() {
+            return;
+        }
+        let mut param_ids = vec![];
+        for param in &params.items {
+            let BindingPatternKind::BindingIdentifier(id) = &param.pattern.kind else { return };
+            let Some(param_id) = id.symbol_id.get() else { return };
+            // Each parameter is replaced by its argument, which must be evaluated at most once
+            if ctx.symbols().get_resolved_reference_ids(param_id).len() > 1
+                || ctx
+                    .symbols()
+                    .get_resolved_references(param_id)
+                    .any(|reference| !reference.flags().is_read_only())
+            {
+                return;
+            }
+            param_ids.push(param_id);
+        }
+        if !param_ids.is_empty() && self.options.inline < InlineLevel::FunctionsWithArguments {
+            return;
+        }
+
+        let mut collector =
+            CollectFreeReferences { params: &param_ids, ctx, references: vec![], valid: true };
+        collector.visit_expression(body);
+        if !collector.valid {
+            return;
+        }
+        let free_references = collector.references;
+
+        let body = ctx.ast.move_expression(body);
+        self.functions.insert(
+            symbol_id,
+            InlineFunction {
+                body: Some(body),
+                params: param_ids,
+                free_references,
+                declaration_end,
+                inlined: false,
+            },
+        );
+    }
+
+    fn restore_body(statements: &mut Vec<'a, Statement<'a>>, body: Option<Expression<'a>>) {
+        let Some(body) = body else { return };
+        match statements.first_mut() {
+            Some(Statement::ReturnStatement(ret)) => ret.argument = Some(body),
+            Some(Statement::ExpressionStatement(stmt)) => stmt.expression = body,
+            _ => {}
+        }
+    }
+
+    /// `f(1)` -> returned expression of `f`, with references to its parameters replaced.
+    fn try_inline_call(
+        &mut self,
+        call: &mut CallExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        if self.functions.is_empty() || call.optional {
+            return None;
+        }
+        let Expression::Identifier(callee) = &call.callee else { return None };
+        let symbol_id = ctx.symbols().get_reference(callee.reference_id()?).symbol_id()?;
+        let function = self.functions.get(&symbol_id)?;
+        function.body.as_ref()?;
+        if function.declaration_end.is_some_and(|end| call.span.start < end) {
+            return None;
+        }
+        if function.params.is_empty() && !call.arguments.is_empty() {
+            return None;
+        }
+        if !call.arguments.iter().all(|argument| {
+            argument.as_expression().is_some_and(|argument| Self::is_constant(argument, ctx))
+        }) {
+            return None;
+        }
+        let scope_id = ctx.current_scope_id();
+        if Self::in_direct_eval_scope(scope_id, symbol_id, ctx) {
+            return None;
+        }
+        if !function
+            .free_references
+            .iter()
+            .all(|(name, symbol_id)| ctx.scopes().find_binding(scope_id, name) == *symbol_id)
+        {
+            return None;
+        }
+
+        let function = self.functions.get_mut(&symbol_id)?;
+        let body = function.body.take()?;
+        function.inlined = true;
+        ctx.delete_reference_for_identifier(callee);
+
+        let mut arguments = call.arguments.drain(..);
+        for &param_id in &function.params {
+            let argument = match arguments.next() {
+                Some(argument) => argument.into_expression(),
+                None => ctx.ast.void_0(SPAN),
+            };
+            if ctx.symbols().get_resolved_reference_ids(param_id).is_empty() {
+                Self::delete_argument(&argument, ctx);
+            } else {
+                self.arguments.insert(param_id, argument);
+            }
+        }
+        for argument in arguments {
+            Self::delete_argument(&argument.into_expression(), ctx);
+        }
+        Some(body)
+    }
+
+    /// Replace references to parameters of inlined functions and constants.
+    fn try_inline_identifier(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::Identifier(ident) = expr else { return };
+        let Some(reference_id) = ident.reference_id.get() else { return };
+        let Some(symbol_id) = ctx.symbols().get_reference(reference_id).symbol_id() else {
+            return;
+        };
+        if let Some(argument) = self.arguments.remove(&symbol_id) {
+            ctx.delete_reference(reference_id, &ident.name);
+            *expr = argument;
+            self.changed = true;
+            // The argument may be a constant
+            self.try_inline_identifier(expr, ctx);
+            return;
+        }
+        let Some((value, end)) = self.constants.get(&symbol_id) else { return };
+        if ident.span.start < *end
+            || Self::in_direct_eval_scope(ctx.current_scope_id(), symbol_id, ctx)
+        {
+            return;
+        }
+        let value = value.clone_in(ctx.ast.allocator);
+        ctx.delete_reference(reference_id, &ident.name);
+        *expr = value;
+        self.changed = true;
+    }
+
+    /// Is there a direct `eval` or `with` in any scope from `scope_id` up to the scope of
+    /// `symbol_id`? `DirectEval` is set on ancestors too, so this also covers `eval` in scopes
+    /// which can see the symbol.
+    fn in_direct_eval_scope(scope_id: ScopeId, symbol_id: SymbolId, ctx: &TraverseCtx<'a>) -> bool {
+        let binding_scope_id = ctx.symbols().get_scope_id(symbol_id);
+        for scope_id in ctx.scopes().ancestors(scope_id) {
+            if ctx.scopes().get_flags(scope_id).contains_direct_eval() {
+                return true;
+            }
+            if scope_id == binding_scope_id {
+                break;
+            }
+        }
+        false
+    }
+
+    fn delete_argument(argument: &Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Expression::Identifier(ident) = argument {
+            ctx.delete_reference_for_identifier(ident);
+        }
+    }
+
+    fn is_literal(expr: &Expression<'a>) -> bool {
+        matches!(
+            expr,
+            Expression::BooleanLiteral(_)
+                | Expression::NullLiteral(_)
+                | Expression::NumericLiteral(_)
+                | Expression::StringLiteral(_)
+                | Expression::BigIntLiteral(_)
+        )
+    }
+
+    /// Literals which are not longer than a mangled name, so all references can be inlined.
+    fn is_small_literal(expr: &Expression<'a>) -> bool {
+        match expr {
+            Expression::BooleanLiteral(_) | Expression::NullLiteral(_) => true,
+            Expression::NumericLiteral(lit) => {
+                lit.value.fract() == 0.0 && (0.0..1000.0).contains(&lit.value)
+            }
+            _ => false,
+        }
+    }
+
+    /// Literals, and variables which are never reassigned.
+    /// Their value is the same when evaluated at any point in the inlined function.
+    fn is_constant(expr: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        match expr {
+            Expression::Identifier(ident) => ident
+                .reference_id
+                .get()
+                .and_then(|reference_id| ctx.symbols().get_reference(reference_id).symbol_id())
+                .is_some_and(|symbol_id| {
+                    !ctx.symbols().get_resolved_references(symbol_id).any(Reference::is_write)
+                }),
+            _ => Self::is_literal(expr),
+        }
+    }
+}
+
+/// Collect references in a function body which is inlined, and check that it does not depend on
+/// the function it is in.
+struct CollectFreeReferences<'a, 'c> {
+    params: &'c [SymbolId],
+    ctx: &'c TraverseCtx<'a>,
+    references: std::vec::Vec<(Atom<'a>, Option<SymbolId>)>,
+    valid: bool,
+}
+
+impl<'a, 'c> Visit<'a> for CollectFreeReferences<'a, 'c> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        if ident.name == "arguments" {
+            self.valid = false;
+            return;
+        }
+        let symbol_id = ident
+            .reference_id
+            .get()
+            .and_then(|reference_id| self.ctx.symbols().get_reference(reference_id).symbol_id());
+        if symbol_id.is_some_and(|symbol_id| self.params.contains(&symbol_id)) {
+            return;
+        }
+        self.references.push((ident.name.clone(), symbol_id));
+    }
+
+    fn visit_this_expression(&mut self, _it: &ThisExpression) {
+        self.valid = false;
+    }
+
+    fn visit_super(&mut self, _it: &Super) {
+        self.valid = false;
+    }
+
+    fn visit_meta_property(&mut self, _it: &MetaProperty<'a>) {
+        self.valid = false;
+    }
+
+    // Nested scopes would have to be moved to the call site
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: oxc_syntax::scope::ScopeFlags) {
+        self.valid = false;
+    }
+
+    fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'a>) {
+        self.valid = false;
+    }
+
+    fn visit_class(&mut self, _it: &Class<'a>) {
+        self.valid = false;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+
+    use crate::{options::InlineLevel, tester, CompressOptions};
+
+    fn test_with_options(source_text: &str, expected: &str, options: CompressOptions) {
+        let allocator = Allocator::default();
+        let mut pass = super::PeepholeInline::new(options);
+        tester::test(&allocator, source_text, expected, &mut pass);
+    }
+
+    fn test(source_text: &str, expected: &str) {
+        test_with_options(source_text, expected, CompressOptions::default());
+    }
+
+    fn test_same(source_text: &str) {
+        test(source_text, source_text);
+    }
+
+    #[test]
+    fn constants() {
+        test("const a = 1; f(a, a)", "const a = 1; f(1, 1)");
+        test("const a = 'foo'; f(a)", "const a = 'foo'; f('foo')");
+        test("const a = true, b = null; f(a, b)", "const a = true, b = null; f(true, null)");
+        test("const a = 1; f({ a })", "const a = 1; f({ a: 1 })");
+        test(
+            "function g() { const a = 1; return () => a }",
+            "function g() { const a = 1; return () => 1 }",
+        );
+        // Only small literals are inlined into multiple references
+        test_same("const a = 'foo'; f(a, a)");
+        test_same("const a = 1000; f(a, a)");
+        // TDZ
+        test_same("f(a); const a = 1;");
+        test_same("let a = 1; f(a)");
+        test_same("const a = g(); f(a)");
+        // In functions which are not inlined
+        test(
+            "const k = 5; function g(p) { return p * k }",
+            "const k = 5; function g(p) { return p * 5 }",
+        );
+        test(
+            "function h() { const k = 5; const g = (p) => p * k; return [g(x), g(y)] }",
+            "function h() { const k = 5; const g = (p) => p * 5; return [g(x), g(y)] }",
+        );
+        test(
+            "function h() { const k = 5; function g(p) { return p * k + z } return (() => { let z; return g(1) })() }",
+            "function h() { const k = 5; function g(p) { return p * 5 + z } return (() => { let z; return g(1) })() }",
+        );
+        // Inlined constants are no longer shadowed at the call site
+        test(
+            "function h() { const k = 5; function g(p) { return p * k } return (() => { let k; return g(1) })() }",
+            "function h() { const k = 5; return (() => { let k; return 1 * 5 })() }",
+        );
+    }
+
+    #[test]
+    fn functions() {
+        test("function g() { function f() { return 1 } return f() }", "function g() { return 1 }");
+        test("function g() { const f = () => x + 1; return f() }", "function g() { return x + 1 }");
+        test(
+            "function g() { const f = function() { return [x] }; return f() }",
+            "function g() { return [x] }",
+        );
+        test("function g() { const f = () => 1; return h(f()) }", "function g() { return h(1) }");
+        // Called more than once
+        test_same("function g() { function f() { return 1 } return f() + f() }");
+        // Not only a return statement
+        test_same("function g() { function f() { x(); return 1 } return f() }");
+        // `this`, `arguments`, nested functions
+        test_same("function g() { function f() { return this } return f() }");
+        test_same("function g() { function f() { return arguments } return f() }");
+        test_same("function g() { function f() { return () => 1 } return f() }");
+        test_same("function g() { const f = async () => 1; return f() }");
+        // Shadowed at the call site
+        test_same(
+            "function g() { function f() { return x } return (() => { let x; return f() })() }",
+        );
+        // Calls in inlined functions
+        test(
+            "function g() { const f = () => 1; function h() { return f() } return h() }",
+            "function g() { return 1 }",
+        );
+        // Shadowed at the call site after inlining a call
+        test(
+            "function g() { const f = () => x; function h() { return f() } return (() => { let x; return h() })() }",
+            "function g() { function h() { return x } return (() => { let x; return h() })() }",
+        );
+        // TDZ
+        test_same("function g() { f(); const f = () => 1 }");
+        // Top level
+        test_same("function f() { return 1 } f()");
+        test_with_options(
+            "function f() { return 1 } f()",
+            "1",
+            CompressOptions { toplevel: true, ..CompressOptions::default() },
+        );
+    }
+
+    #[test]
+    fn functions_with_arguments() {
+        test(
+            "function g(y) { function f(a, b) { return a + b } return f(1, y) }",
+            "function g(y) { return 1 + y }",
+        );
+        test("function g() { const f = (a) => a; return f(1) }", "function g() { return 1 }");
+        test(
+            "function g() { const f = (a, b) => [a, b]; return f(1) }",
+            "function g() { return [1, void 0] }",
+        );
+        test("function g(y) { const f = (a) => 1; return f(y, 2) }", "function g(y) { return 1 }");
+        test(
+            "function g() { const a = 1; const f = (b) => b; return f(a) }",
+            "function g() { const a = 1; return 1 }",
+        );
+        // Arguments which may change, or parameters used more than once
+        test_same("function g() { const f = (a) => a; return f(h()) }");
+        test_same("function g(y) { y = 1; const f = (a) => a; return f(y) }");
+        test_same("function g() { const f = (a) => a + a; return f(1) }");
+        test_same("function g() { const f = (a = 1) => a; return f(1) }");
+        test_same("function g() { const f = (...a) => a; return f(1) }");
+
+        test_with_options(
+            "function g() { const f = (a) => a; return f(1) }",
+            "function g() { const f = (a) => a; return f(1) }",
+            CompressOptions { inline: InlineLevel::Functions, ..CompressOptions::default() },
+        );
+        test_with_options(
+            "function g() { const f = () => 1; return f() }",
+            "function g() { const f = () => 1; return f() }",
+            CompressOptions { inline: InlineLevel::Constants, ..CompressOptions::default() },
+        );
+        test_with_options(
+            "const a = 1; f(a)",
+            "const a = 1; f(a)",
+            CompressOptions { inline: InlineLevel::Off, ..CompressOptions::default() },
+        );
+    }
+
+    #[test]
+    fn direct_eval() {
+        test_same("function g() { const a = 1; eval('a'); return a }");
+        test_same("function g(o) { const a = 1; with (o) { return a } }");
+        test_same("function g(o) { function f() { return a } var a = 1; with (o) { return f() } }");
+        test_same("function g(o) { const a = 1; with (o) { x } return a }");
+        test(
+            "function g(o) { with (o) { x } } function h() { const a = 1; return a }",
+            "function g(o) { with (o) { x } } function h() { const a = 1; return 1 }",
+        );
+    }
+}
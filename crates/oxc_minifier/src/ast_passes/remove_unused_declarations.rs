@@ -0,0 +1,592 @@
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, visit::walk, Visit};
+use oxc_ecmascript::side_effects::MayHaveSideEffects;
+use oxc_semantic::{IsGlobalReference, Reference, SymbolTable};
+use oxc_span::GetSpan;
+use oxc_syntax::{
+    scope::{ScopeFlags, ScopeId},
+    symbol::SymbolId,
+};
+use oxc_traverse::{Ancestor, Traverse, TraverseCtx};
+
+use crate::{CompressOptions, CompressorPass};
+
+/// Remove declarations which are never referenced.
+///
+/// * Function declarations
+/// * Class declarations without side effects
+/// * Variable declarators whose initializer has no side effects
+/// * Import specifiers, keeping the import for its side effects
+/// * Names of function and class expressions
+///
+/// Calls annotated with `/* #__PURE__ */`, and calls to functions annotated with
+/// `/* #__NO_SIDE_EFFECTS__ */`, are treated as having no side effects.
+///
+/// Top level declarations are only removed with `compress.toplevel`.
+/// Nothing is removed from scopes containing a direct `eval` or `with`, which can reference
+/// any binding by name.
+///
+/// Removing a declaration deletes its references, so declarations only used by removed
+/// declarations are removed on the next iteration.
+///
+/// Terser option: `unused: true`.
+pub struct RemoveUnusedDeclarations {
+    options: CompressOptions,
+
+    /// Start of calls annotated with `/* #__PURE__ */`
+    pure_calls: FxHashSet<u32>,
+
+    /// Start of functions and declarations annotated with `/* #__NO_SIDE_EFFECTS__ */`
+    no_side_effects_annotations: FxHashSet<u32>,
+
+    /// Functions annotated with `/* #__NO_SIDE_EFFECTS__ */`
+    no_side_effects_functions: FxHashSet<SymbolId>,
+
+    /// Scopes of functions which use `arguments`
+    functions_using_arguments: FxHashSet<ScopeId>,
+
+    /// Class declarations and function declarations which can be extended, with the position
+    /// from which they are initialized
+    constructors: FxHashMap<SymbolId, u32>,
+
+    changed: bool,
+}
+
+impl<'a> CompressorPass<'a> for RemoveUnusedDeclarations {
+    fn changed(&self) -> bool {
+        self.changed
+    }
+
+    fn build(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.changed = false;
+        self.collect_annotations(program);
+        self.functions_using_arguments = ArgumentsUsage::collect(program, ctx.symbols());
+        self.constructors.clear();
+        oxc_traverse::walk_program(self, program, ctx);
+    }
+}
+
+impl<'a> Traverse<'a> for RemoveUnusedDeclarations {
+    fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        // Function declarations are hoisted, except in blocks which may not be executed
+        if func.is_declaration()
+            && !func.r#async
+            && !func.generator
+            && matches!(
+                ctx.parent(),
+                Ancestor::ProgramBody(_) | Ancestor::FunctionBodyStatements(_)
+            )
+        {
+            if let Some(symbol_id) = func.id.as_ref().and_then(|id| id.symbol_id.get()) {
+                self.constructors.insert(symbol_id, 0);
+            }
+        }
+
+        // `/* #__NO_SIDE_EFFECTS__ */ function f() {}`
+        // `/* #__NO_SIDE_EFFECTS__ */ export function f() {}`
+        let annotated = self.no_side_effects_annotations.contains(&func.span.start)
+            || match ctx.parent() {
+                Ancestor::ExportNamedDeclarationDeclaration(decl) => {
+                    self.no_side_effects_annotations.contains(&decl.span().start)
+                }
+                Ancestor::ExportDefaultDeclarationDeclaration(decl) => {
+                    self.no_side_effects_annotations.contains(&decl.span().start)
+                }
+                _ => false,
+            };
+        if annotated {
+            if let Some(symbol_id) = func.id.as_ref().and_then(|id| id.symbol_id.get()) {
+                self.no_side_effects_functions.insert(symbol_id);
+            }
+        }
+    }
+
+    fn enter_class(&mut self, class: &mut Class<'a>, _ctx: &mut TraverseCtx<'a>) {
+        if class.is_declaration() {
+            if let Some(symbol_id) = class.id.as_ref().and_then(|id| id.symbol_id.get()) {
+                self.constructors.insert(symbol_id, class.span.end);
+            }
+        }
+    }
+
+    fn enter_variable_declarator(
+        &mut self,
+        decl: &mut VariableDeclarator<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+        // `const f = /* #__NO_SIDE_EFFECTS__ */ () => {}`
+        // `const /* #__NO_SIDE_EFFECTS__ */ f = function() {}`
+        let Some(init) = &decl.init else { return };
+        if !matches!(
+            init,
+            Expression::ArrowFunctionExpression(_) | Expression::FunctionExpression(_)
+        ) {
+            return;
+        }
+        let BindingPatternKind::BindingIdentifier(id) = &decl.id.kind else { return };
+        if self.no_side_effects_annotations.contains(&init.span().start)
+            || self.no_side_effects_annotations.contains(&id.span.start)
+        {
+            if let Some(symbol_id) = id.symbol_id.get() {
+                self.no_side_effects_functions.insert(symbol_id);
+            }
+        }
+    }
+
+    fn exit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
+        // Function declarations in blocks have Annex B semantics, only remove them from
+        // function bodies and the program.
+        let remove_functions =
+            matches!(ctx.parent(), Ancestor::ProgramBody(_) | Ancestor::FunctionBodyStatements(_));
+        // Removing a declaration can make an earlier declaration in the same list unused
+        loop {
+            let len = stmts.len();
+            let mut changed = false;
+            stmts.retain_mut(|stmt| {
+                let remove = self.remove_unused(stmt, remove_functions, &mut changed, ctx);
+                if remove {
+                    DeleteReferences { ctx }.visit_statement(stmt);
+                }
+                !remove
+            });
+            if stmts.len() != len {
+                changed = true;
+            }
+            if !changed {
+                break;
+            }
+            self.changed = true;
+        }
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        // `var f = function g() {}` -> `var f = function() {}`
+        let id = match expr {
+            Expression::FunctionExpression(func) if !self.options.keep_fnames => &mut func.id,
+            Expression::ClassExpression(class) if !self.options.keep_classnames => &mut class.id,
+            _ => return,
+        };
+        if id.as_ref().is_some_and(|id| Self::is_unused(id, ctx)) {
+            *id = None;
+            self.changed = true;
+        }
+    }
+}
+
+impl<'a> RemoveUnusedDeclarations {
+    pub fn new(options: CompressOptions) -> Self {
+        Self {
+            options,
+            pure_calls: FxHashSet::default(),
+            no_side_effects_annotations: FxHashSet::default(),
+            no_side_effects_functions: FxHashSet::default(),
+            functions_using_arguments: FxHashSet::default(),
+            constructors: FxHashMap::default(),
+            changed: false,
+        }
+    }
+
+    fn collect_annotations(&mut self, program: &Program<'a>) {
+        if !self.pure_calls.is_empty() || !self.no_side_effects_annotations.is_empty() {
+            return;
+        }
+        for comment in &program.comments {
+            let text = comment.span.source_text(program.source_text);
+            if text.contains("#__PURE__") || text.contains("@__PURE__") {
+                self.pure_calls.insert(comment.attached_to);
+            } else if text.contains("#__NO_SIDE_EFFECTS__") || text.contains("@__NO_SIDE_EFFECTS__")
+            {
+                self.no_side_effects_annotations.insert(comment.attached_to);
+            }
+        }
+    }
+
+    /// Whether to remove `stmt`. Unused variable declarators and import specifiers are removed
+    /// from it, setting `changed`.
+    fn remove_unused(
+        &self,
+        stmt: &mut Statement<'a>,
+        remove_functions: bool,
+        changed: &mut bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> bool {
+        match stmt {
+            Statement::FunctionDeclaration(func) => {
+                remove_functions
+                    && !func.declare
+                    && func.id.as_ref().is_some_and(|id| {
+                        self.is_removable(id, ctx) && !self.may_be_read_through_arguments(id, ctx)
+                    })
+            }
+            Statement::ClassDeclaration(class) => {
+                !class.declare
+                    && class.id.as_ref().is_some_and(|id| self.is_removable(id, ctx))
+                    && !self.class_may_have_side_effects(class, ctx)
+            }
+            Statement::VariableDeclaration(decl) => {
+                if decl.declare
+                    || matches!(
+                        decl.kind,
+                        VariableDeclarationKind::Using | VariableDeclarationKind::AwaitUsing
+                    )
+                {
+                    return false;
+                }
+                let len = decl.declarations.len();
+                decl.declarations.retain(|declarator| {
+                    let BindingPatternKind::BindingIdentifier(id) = &declarator.id.kind else {
+                        return true;
+                    };
+                    let remove = self.is_removable(id, ctx)
+                        && !(declarator.kind.is_var()
+                            && declarator.init.is_some()
+                            && self.may_be_read_through_arguments(id, ctx))
+                        && declarator
+                            .init
+                            .as_ref()
+                            .map_or(true, |init| !self.may_have_side_effects(init, ctx));
+                    if remove {
+                        DeleteReferences { ctx }.visit_variable_declarator(declarator);
+                    }
+                    !remove
+                });
+                if decl.declarations.len() != len {
+                    *changed = true;
+                }
+                decl.declarations.is_empty()
+            }
+            Statement::ImportDeclaration(decl) => {
+                // `import { a } from "x"` -> `import "x"`
+                let Some(specifiers) = &mut decl.specifiers else { return false };
+                let len = specifiers.len();
+                specifiers.retain(|specifier| !self.is_removable(specifier.local(), ctx));
+                if specifiers.len() != len {
+                    *changed = true;
+                    if specifiers.is_empty() {
+                        decl.specifiers = None;
+                    }
+                }
+                false
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether the declaration of `id` can be removed if it has no side effects.
+    fn is_removable(&self, id: &BindingIdentifier<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        let Some(symbol_id) = id.symbol_id.get() else { return false };
+        if !self.options.toplevel
+            && ctx.symbols().get_scope_id(symbol_id) == ctx.scopes().root_scope_id()
+        {
+            return false;
+        }
+        Self::is_unused(id, ctx)
+    }
+
+    fn is_unused(id: &BindingIdentifier<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        id.symbol_id.get().is_some_and(|symbol_id| {
+            let scope_id = ctx.symbols().get_scope_id(symbol_id);
+            ctx.symbols().get_resolved_reference_ids(symbol_id).is_empty()
+                && !ctx.scopes().get_flags(scope_id).contains_direct_eval()
+        })
+    }
+
+    /// `function f(x) { var x = 1; return arguments[0] }`
+    /// `function f(x) { function x() {} return arguments[0] }`
+    ///
+    /// A `var` with an initializer or a function declaration which redeclares a parameter
+    /// assigns to it, which is visible through `arguments`.
+    fn may_be_read_through_arguments(
+        &self,
+        id: &BindingIdentifier<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> bool {
+        id.symbol_id.get().is_some_and(|symbol_id| {
+            !ctx.symbols().get_redeclarations(symbol_id).is_empty()
+                && self.functions_using_arguments.contains(&ctx.symbols().get_scope_id(symbol_id))
+        })
+    }
+
+    fn may_have_side_effects(&self, expr: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        let (span, callee, arguments) = match expr {
+            Expression::CallExpression(call) => (call.span, &call.callee, &call.arguments),
+            Expression::NewExpression(new) => (new.span, &new.callee, &new.arguments),
+            Expression::ArrowFunctionExpression(_) => return false,
+            _ => return expr.may_have_side_effects(),
+        };
+        let is_pure = self.pure_calls.contains(&span.start)
+            || matches!(callee, Expression::Identifier(ident) if ident
+                .reference_id
+                .get()
+                .and_then(|reference_id| ctx.symbols().get_reference(reference_id).symbol_id())
+                .is_some_and(|symbol_id| self.no_side_effects_functions.contains(&symbol_id)));
+        !is_pure
+            || arguments.iter().any(|argument| match argument {
+                Argument::SpreadElement(_) => true,
+                _ => self.may_have_side_effects(argument.to_expression(), ctx),
+            })
+    }
+
+    /// `class A extends B {}` throws if `B` is not a constructor, or is not initialized yet.
+    /// `B` must be a class declaration before `A`, or a function declaration, which is never
+    /// reassigned.
+    fn is_constructor(&self, expr: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        let Expression::Identifier(ident) = expr else { return false };
+        let Some(symbol_id) = ident
+            .reference_id
+            .get()
+            .and_then(|reference_id| ctx.symbols().get_reference(reference_id).symbol_id())
+        else {
+            return false;
+        };
+        self.constructors
+            .get(&symbol_id)
+            .is_some_and(|&initialized| initialized <= ident.span.start)
+            && ctx.symbols().get_redeclarations(symbol_id).is_empty()
+            && !ctx.symbols().get_resolved_references(symbol_id).any(Reference::is_write)
+    }
+
+    /// Whether evaluating the class declaration may have side effects, e.g. static blocks and
+    /// initializers of static properties.
+    fn class_may_have_side_effects(&self, class: &Class<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        if !class.decorators.is_empty()
+            || class
+                .super_class
+                .as_ref()
+                .is_some_and(|super_class| !self.is_constructor(super_class, ctx))
+        {
+            return true;
+        }
+        class.body.body.iter().any(|element| match element {
+            ClassElement::StaticBlock(block) => !block.body.is_empty(),
+            ClassElement::MethodDefinition(method) => {
+                !method.decorators.is_empty() || method.key.may_have_side_effects()
+            }
+            ClassElement::PropertyDefinition(prop) => {
+                !prop.decorators.is_empty()
+                    || prop.key.may_have_side_effects()
+                    || (prop.r#static
+                        && prop
+                            .value
+                            .as_ref()
+                            .is_some_and(|value| self.may_have_side_effects(value, ctx)))
+            }
+            ClassElement::AccessorProperty(prop) => {
+                !prop.decorators.is_empty()
+                    || prop.key.may_have_side_effects()
+                    || (prop.r#static
+                        && prop
+                            .value
+                            .as_ref()
+                            .is_some_and(|value| self.may_have_side_effects(value, ctx)))
+            }
+            ClassElement::TSIndexSignature(_) => false,
+        })
+    }
+}
+
+/// Delete the references in a removed node, so the symbols they refer to can become unused.
+struct DeleteReferences<'a, 'c> {
+    ctx: &'c mut TraverseCtx<'a>,
+}
+
+impl<'a, 'c> Visit<'a> for DeleteReferences<'a, 'c> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        if let Some(reference_id) = ident.reference_id.get() {
+            self.ctx.delete_reference(reference_id, &ident.name);
+        }
+    }
+}
+
+/// Collect the scopes of functions which use `arguments`.
+struct ArgumentsUsage<'s> {
+    symbols: &'s SymbolTable,
+    /// Scopes of the enclosing non-arrow functions
+    function_scopes: std::vec::Vec<ScopeId>,
+    functions_using_arguments: FxHashSet<ScopeId>,
+}
+
+impl<'s> ArgumentsUsage<'s> {
+    fn collect(program: &Program<'_>, symbols: &'s SymbolTable) -> FxHashSet<ScopeId> {
+        let mut usage = Self {
+            symbols,
+            function_scopes: vec![],
+            functions_using_arguments: FxHashSet::default(),
+        };
+        usage.visit_program(program);
+        usage.functions_using_arguments
+    }
+}
+
+impl<'a, 's> Visit<'a> for ArgumentsUsage<'s> {
+    fn visit_function(&mut self, func: &Function<'a>, flags: ScopeFlags) {
+        self.function_scopes.push(func.scope_id.get().unwrap());
+        walk::walk_function(self, func, flags);
+        self.function_scopes.pop();
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        if ident.is_global_reference_name("arguments", self.symbols) {
+            if let Some(scope_id) = self.function_scopes.last() {
+                self.functions_using_arguments.insert(*scope_id);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+
+    use crate::{tester, CompressOptions};
+
+    fn test_with_options(source_text: &str, expected: &str, options: CompressOptions) {
+        let allocator = Allocator::default();
+        let mut pass = super::RemoveUnusedDeclarations::new(options);
+        tester::test(&allocator, source_text, expected, &mut pass);
+    }
+
+    fn test(source_text: &str, expected: &str) {
+        test_with_options(source_text, expected, CompressOptions::default());
+    }
+
+    fn test_same(source_text: &str) {
+        test(source_text, source_text);
+    }
+
+    fn test_toplevel(source_text: &str, expected: &str) {
+        test_with_options(
+            source_text,
+            expected,
+            CompressOptions { toplevel: true, ..CompressOptions::default() },
+        );
+    }
+
+    #[test]
+    fn function_declarations() {
+        test("function f() { function g() {} }", "function f() {}");
+        test(
+            "function f() { function g() {} return g }",
+            "function f() { function g() {} return g }",
+        );
+        test("function f() { function g() { h() } function h() {} }", "function f() {}");
+        test("function f() { function h() {} function g() { h() } }", "function f() {}");
+        test_same("function f() { if (x) { function g() {} } }");
+        test_same("function f() { function g() { g() } }");
+    }
+
+    #[test]
+    fn variable_declarations() {
+        test("function f() { var a = 1, b = 2; return b }", "function f() { var b = 2; return b }");
+        test("function f() { let a = [1, {}], b = 'x' }", "function f() {}");
+        test("function f() { const a = () => {}; let b }", "function f() {}");
+        test("function f() { const a = 1, b = a }", "function f() {}");
+        test_same("function f() { var a = g() }");
+        test_same("function f() { var a = 1; a = 2 }");
+        test_same("function f() { var { a } = b }");
+        test_same("function f() { using a = b }");
+        test_same("function f() { for (var i = 0;;); }");
+    }
+
+    #[test]
+    fn class_declarations() {
+        test("function f() { class A {} }", "function f() {}");
+        test("function f() { class A { static a = 1; b = g(); c() {} } }", "function f() {}");
+        test("function f() { class A {} class B extends A {} }", "function f() {}");
+        test_same("function f() { class A { static a = g() } }");
+        test_same("function f() { class A { static { g() } } }");
+        test_same("function f() { class A { [g()]() {} } }");
+        test_same("function f() { class A extends g() {} }");
+        test_same("function f() { class A extends B {} }");
+        test("function f() { function A() {} class B extends A {} }", "function f() {}");
+        test("function f() { class B extends A {} function A() {} }", "function f() {}");
+        test_same("function f() { var A = 1; class B extends A {} }");
+        test_same("function f() { class A {} A = 1; class B extends A {} }");
+        test_same("function f() { class B extends A {} class A {} }");
+        test_same("function f() { async function A() {} class B extends A {} }");
+        test_same("function f() { const A = class {}; class B extends A {} }");
+        test_same("function f() { { function A() {} } class B extends A {} }");
+    }
+
+    #[test]
+    fn toplevel() {
+        test_same("function f() {} class A {} var a = 1; let b = 1;");
+        test_same("import a, { b } from 'x'");
+        test_toplevel("function f() {} class A {} var a = 1; let b = 1;", "");
+        test_toplevel("import a, { b, c } from 'x'; b", "import { b } from 'x'; b");
+        test_toplevel("import a, * as b from 'x'", "import 'x'");
+        test_toplevel(
+            "function f() {} export function g() { f() }",
+            "function f() {} export function g() { f() }",
+        );
+        test_toplevel("var a = 1; export { a }", "var a = 1; export { a }");
+    }
+
+    #[test]
+    fn pure_annotations() {
+        test("function f() { var a = /* #__PURE__ */ g() }", "function f() {}");
+        test("function f() { var a = /* @__PURE__ */ new G(1, 'x') }", "function f() {}");
+        test_same("function f() { var a = /* #__PURE__ */ g(h()) }");
+        test_same("function f() { var a = /* #__PURE__ */ g(...h) }");
+        test_toplevel(
+            "/* #__NO_SIDE_EFFECTS__ */ function g() {} var a = g(); export { g }",
+            "/* #__NO_SIDE_EFFECTS__ */ function g() {} export { g }",
+        );
+        test_toplevel(
+            "export const g = /* #__NO_SIDE_EFFECTS__ */ () => {}; var a = g()",
+            "export const g = /* #__NO_SIDE_EFFECTS__ */ () => {}",
+        );
+        test_toplevel("export function g() {} var a = g()", "export function g() {} var a = g()");
+    }
+
+    #[test]
+    fn function_and_class_names() {
+        test("x = function g() {}", "x = function() {}");
+        test("x = class A {}", "x = class {}");
+        test_same("x = function g() { g() }");
+        test_with_options(
+            "x = function g() {}; y = class A {}",
+            "x = function g() {}; y = class A {}",
+            CompressOptions {
+                keep_fnames: true,
+                keep_classnames: true,
+                ..CompressOptions::default()
+            },
+        );
+    }
+
+    #[test]
+    fn direct_eval() {
+        test_same("function f() { var a = 1; eval('a') }");
+        test_same("function f() { var a = 1; function g() { eval('a') } return g }");
+        test_same("x = function g() { eval('g') }");
+        test(
+            "function f() { var a = 1 } function g() { var b = 1; eval('b') }",
+            "function f() {} function g() { var b = 1; eval('b') }",
+        );
+        test("function f() { var a = 1; (0, eval)('a') }", "function f() { (0, eval)('a') }");
+    }
+
+    #[test]
+    fn arguments() {
+        test_same("function f(x) { var x = 1; return arguments[0] }");
+        test_same("function f(x) { if (y) { var x = 1 } return () => arguments[0] }");
+        test_same("function f(x) { function x() {} return arguments[0] }");
+        test("function f(x) { function x() {} }", "function f(x) {}");
+        test("function f(x) { var x = 1 }", "function f(x) {}");
+        test(
+            "function f(x) { var x; return arguments[0] }",
+            "function f(x) { return arguments[0] }",
+        );
+        test(
+            "function f(x) { var y = 1; return arguments[0] }",
+            "function f(x) { return arguments[0] }",
+        );
+        test(
+            "function f(x) { var x = 1; return function() { return arguments[0] } }",
+            "function f(x) { return function() { return arguments[0] } }",
+        );
+    }
+}
@@ -5,11 +5,11 @@ use oxc_traverse::TraverseCtx;
 
 use crate::{
     ast_passes::{
-        CollapseVariableDeclarations, ExploitAssigns, PeepholeFoldConstants,
+        CollapseVariableDeclarations, ExploitAssigns, PeepholeFoldConstants, PeepholeInline,
         PeepholeMinimizeConditions, PeepholeRemoveDeadCode, PeepholeReplaceKnownMethods,
-        PeepholeSubstituteAlternateSyntax, RemoveSyntax, StatementFusion,
+        PeepholeSubstituteAlternateSyntax, RemoveSyntax, RemoveUnusedDeclarations, StatementFusion,
     },
-    CompressOptions, CompressorPass,
+    CompressOptions, CompressorPass, InlineLevel,
 };
 
 pub struct Compressor<'a> {
@@ -43,6 +43,8 @@ impl<'a> Compressor<'a> {
         }
 
         // See `latePeepholeOptimizations`
+        let mut inline = PeepholeInline::new(self.options);
+        let mut remove_unused_declarations = RemoveUnusedDeclarations::new(self.options);
         let mut passes: [&mut dyn CompressorPass; 6] = [
             &mut StatementFusion::new(),
             &mut PeepholeRemoveDeadCode::new(),
@@ -62,6 +64,18 @@ impl<'a> Compressor<'a> {
                     changed = true;
                 }
             }
+            if self.options.inline > InlineLevel::Off {
+                inline.build(program, &mut ctx);
+                if inline.changed() {
+                    changed = true;
+                }
+            }
+            if self.options.unused {
+                remove_unused_declarations.build(program, &mut ctx);
+                if remove_unused_declarations.changed() {
+                    changed = true;
+                }
+            }
             if !changed {
                 break;
             }
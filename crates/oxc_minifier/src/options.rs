@@ -36,6 +36,46 @@ pub struct CompressOptions {
     ///
     /// Default `true`
     pub typeofs: bool,
+
+    /// Drop unreferenced functions, classes and variables.
+    ///
+    /// Default `true`
+    pub unused: bool,
+
+    /// Drop unreferenced top level functions, classes, variables and imports.
+    /// Requires `unused`.
+    ///
+    /// Default `false`
+    pub toplevel: bool,
+
+    /// Keep unreferenced names of function expressions.
+    ///
+    /// Default `false`
+    pub keep_fnames: bool,
+
+    /// Keep unreferenced names of class expressions.
+    ///
+    /// Default `false`
+    pub keep_classnames: bool,
+
+    /// Inline constants and functions called once.
+    ///
+    /// Default [`InlineLevel::FunctionsWithArguments`]
+    pub inline: InlineLevel,
+}
+
+/// What to inline, see [`CompressOptions::inline`].
+///
+/// Each level includes the previous levels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum InlineLevel {
+    Off = 0,
+    /// `const` variables with literal values.
+    Constants = 1,
+    /// Functions without parameters which are called once and only return an expression.
+    Functions = 2,
+    /// Functions with parameters, when the arguments are literals or never reassigned.
+    FunctionsWithArguments = 3,
 }
 
 #[allow(clippy::derivable_impls)]
@@ -56,6 +96,11 @@ impl CompressOptions {
             join_vars: true,
             loops: true,
             typeofs: true,
+            unused: true,
+            toplevel: false,
+            keep_fnames: false,
+            keep_classnames: false,
+            inline: InlineLevel::FunctionsWithArguments,
         }
     }
 
@@ -69,6 +114,11 @@ impl CompressOptions {
             join_vars: false,
             loops: false,
             typeofs: false,
+            unused: false,
+            toplevel: false,
+            keep_fnames: false,
+            keep_classnames: false,
+            inline: InlineLevel::Off,
         }
     }
 
@@ -1,6 +1,6 @@
 mod dead_code_elimination;
 
-use oxc_minifier::CompressOptions;
+use oxc_minifier::{CompressOptions, InlineLevel};
 
 fn test(source_text: &str, expected: &str) {
     let options = CompressOptions::default();
@@ -57,3 +57,35 @@ fn tagged_template() {
     test("foo(true && o.f)", "foo(o.f)");
     test("foo(true ? o.f : false)", "foo(o.f)");
 }
+
+#[test]
+fn unused() {
+    test("function f() { var a = 1; var b = a; }", "function f() {}");
+    test(
+        "function f() { var a = 1; function g() { return a } var b = /* #__PURE__ */ g() }",
+        "function f() {}",
+    );
+
+    let options =
+        CompressOptions { toplevel: true, inline: InlineLevel::Off, ..CompressOptions::default() };
+    crate::test(
+        "var a = 1; function g() { return a } var b = g(); console.log(b)",
+        "var a = 1; function g() { return a } var b = g(); console.log(b)",
+        options,
+    );
+    crate::test("var a = 1; function g() { return a } var b = /* #__PURE__ */ g()", "", options);
+}
+
+#[test]
+fn inline() {
+    test(
+        "function f(x) { const a = 2; function g(b) { return b * a } return g(x) }",
+        "function f(x) { return x * 2 }",
+    );
+    let options = CompressOptions { toplevel: true, ..CompressOptions::default() };
+    crate::test(
+        "const a = 1; const f = (b) => b + a; console.log(f(2))",
+        "console.log(3)",
+        options,
+    );
+}
@@ -2,16 +2,25 @@ use std::fmt::Write;
 
 use oxc_allocator::Allocator;
 use oxc_codegen::CodeGenerator;
-use oxc_mangler::Mangler;
+use oxc_mangler::{KeepNames, MangleOptions, ManglePropertiesOptions, Mangler, NameCache};
+use oxc_minifier::{CompressOptions, Minifier, MinifierOptions};
 use oxc_parser::Parser;
 use oxc_span::SourceType;
+use regex::Regex;
 
-fn mangle(source_text: &str) -> String {
+fn mangle(source_text: &str, options: MangleOptions) -> String {
+    mangle_with_source_type(source_text, SourceType::mjs(), options)
+}
+
+fn mangle_with_source_type(
+    source_text: &str,
+    source_type: SourceType,
+    options: MangleOptions,
+) -> String {
     let allocator = Allocator::default();
-    let source_type = SourceType::mjs();
     let ret = Parser::new(&allocator, source_text, source_type).parse();
     let program = ret.program;
-    let mangler = Mangler::new().build(&program);
+    let mangler = Mangler::new().with_options(options).build(&program);
     CodeGenerator::new().with_mangler(Some(mangler)).build(&program).code
 }
 
@@ -27,7 +36,7 @@ fn mangler() {
     ];
 
     let snapshot = cases.into_iter().fold(String::new(), |mut w, case| {
-        write!(w, "{case}\n{}\n", mangle(case)).unwrap();
+        write!(w, "{case}\n{}\n", mangle(case, MangleOptions::default())).unwrap();
         w
     });
 
@@ -35,3 +44,265 @@ fn mangler() {
         insta::assert_snapshot!("mangler", snapshot);
     });
 }
+
+#[test]
+fn direct_eval() {
+    let cases = [
+        (
+            SourceType::mjs(),
+            "function foo(a) { let b; eval('a + b'); function bar(c) { return c } }",
+        ),
+        (
+            SourceType::mjs(),
+            "function foo(a) { function bar(b) { eval('a + b') } function baz(c) { return c } }",
+        ),
+        (SourceType::mjs(), "function foo(a) { (0, eval)('a'); eval?.('a') }"),
+        (SourceType::cjs(), "function foo(a, o) { with (o) { return a } }"),
+        (SourceType::cjs(), "function foo(a) { function bar(b, o) { with (o) { b } } return a }"),
+        // Scripts
+        (
+            SourceType::cjs(),
+            "function foo(a) { var b; eval('a + b'); function bar(c) { return c } }",
+        ),
+        (SourceType::cjs(), "var a; eval('a'); function foo(b) { return b }"),
+        (SourceType::cjs(), "function foo(a) { if (a) function f() {} eval('f') }"),
+    ];
+
+    let snapshot = cases.into_iter().fold(String::new(), |mut w, (source_type, case)| {
+        let printed = mangle_with_source_type(case, source_type, MangleOptions::default());
+        write!(w, "{case}\n{printed}\n").unwrap();
+        w
+    });
+
+    insta::with_settings!({ prepend_module_to_snapshot => false, omit_expression => true }, {
+        insta::assert_snapshot!("direct_eval", snapshot);
+    });
+}
+
+#[test]
+fn direct_eval_top_level() {
+    let options = MangleOptions { top_level: true, ..MangleOptions::default() };
+    assert_eq!(
+        mangle_with_source_type(
+            "var foo = 1; function bar(baz) { return baz } eval('foo')",
+            SourceType::cjs(),
+            options.clone()
+        ),
+        "var foo = 1;\nfunction bar(a) {\n\treturn a;\n}\neval(\"foo\");\n"
+    );
+    assert_eq!(
+        mangle_with_source_type(
+            "var foo = 1; function bar(baz) { return baz } (0, eval)('foo')",
+            SourceType::cjs(),
+            options
+        ),
+        "var a = 1;\nfunction b(c) {\n\treturn c;\n}\n(0, eval)(\"foo\");\n"
+    );
+}
+
+#[test]
+fn annex_b_function() {
+    // Functions in `if` statements in scripts are not bound to any scope
+    assert_eq!(
+        mangle_with_source_type(
+            "function g(a) { if (a) function f() { return a } var b = 1; return [f, b] }",
+            SourceType::cjs(),
+            MangleOptions::default()
+        ),
+        "function g(a) {\n\tif (a) function f() {\n\t\treturn a;\n\t}\n\tvar b = 1;\n\treturn [f, b];\n}\n"
+    );
+}
+
+#[test]
+fn mangle_properties() {
+    let options = MangleOptions {
+        properties: Some(ManglePropertiesOptions {
+            regex: Some(Regex::new("^_").unwrap()),
+            reserved: vec!["_keep".into()],
+            private: true,
+            ..ManglePropertiesOptions::default()
+        }),
+        ..MangleOptions::default()
+    };
+    let cases = [
+        "x._foo = x._bar + x._foo; x.bar",
+        "x = { _foo: 1, _bar() {}, get _baz() {}, bar: 2, '_qux': 3 }; x._qux",
+        "x['_foo'] = x._foo; x._bar",
+        "x._keep = x.__proto__; x = { __proto__: null }",
+        "let { _foo, _bar: bar } = x; x = { _foo, bar }",
+        "var _foo; ({ _foo } = x); ({ _bar: _foo } = x)",
+        "class A { _foo = 1; #bar = 2; #baz() { return this.#bar + this._foo } static has(x) { return #bar in x } }",
+        "x.a = x._foo",
+        "if ('_foo' in x) x._foo = x._bar",
+        "x._a = x._b = x._c = x._d = x._e = x._f = x._g = x._h = x._i = x._j = x._k = x._l = x._m = x._n = x._o = x._p = x._q = x._r = x._s = x._t = x._u = x._v = x._w = x._x = x._y = x._z",
+    ];
+
+    let snapshot = cases.into_iter().fold(String::new(), |mut w, case| {
+        write!(w, "{case}\n{}\n", mangle(case, options.clone())).unwrap();
+        w
+    });
+
+    insta::with_settings!({ prepend_module_to_snapshot => false, omit_expression => true }, {
+        insta::assert_snapshot!("mangle_properties", snapshot);
+    });
+}
+
+#[test]
+fn mangle_properties_builtins() {
+    let options = MangleOptions {
+        properties: Some(ManglePropertiesOptions {
+            regex: Some(Regex::new("").unwrap()),
+            ..ManglePropertiesOptions::default()
+        }),
+        ..MangleOptions::default()
+    };
+    let cases = [
+        "x.length = x.foo.id + x.bar.x; x.__proto__ = x.prototype",
+        "x = { toString() {}, foo: 1 }; x.addEventListener(x.foo)",
+    ];
+
+    let snapshot = cases.into_iter().fold(String::new(), |mut w, case| {
+        write!(w, "{case}\n{}\n", mangle(case, options.clone())).unwrap();
+        w
+    });
+
+    insta::with_settings!({ prepend_module_to_snapshot => false, omit_expression => true }, {
+        insta::assert_snapshot!("mangle_properties_builtins", snapshot);
+    });
+}
+
+#[test]
+fn mangle_properties_name_cache() {
+    let properties = ManglePropertiesOptions {
+        regex: Some(Regex::new("^_").unwrap()),
+        ..ManglePropertiesOptions::default()
+    };
+    let allocator = Allocator::default();
+    let program =
+        Parser::new(&allocator, "x._foo = x._bar + x._bar", SourceType::mjs()).parse().program;
+    let options =
+        MangleOptions { properties: Some(properties.clone()), ..MangleOptions::default() };
+    let name_cache =
+        Mangler::new().with_options(options).build(&program).property_name_cache().clone();
+
+    // `_bar` keeps its name from the first chunk, `_baz` gets a new name
+    let options = MangleOptions {
+        properties: Some(ManglePropertiesOptions { name_cache, ..properties }),
+        ..MangleOptions::default()
+    };
+    assert_eq!(mangle("x._baz = x._bar", options), "x.c = x.a;\n");
+}
+
+#[test]
+fn mangle_options() {
+    let top_level = MangleOptions { top_level: true, ..MangleOptions::default() };
+    let reserved = MangleOptions { reserved: vec!["foo".into()], ..MangleOptions::default() };
+    let keep_fnames = MangleOptions { keep_fnames: KeepNames::All, ..MangleOptions::default() };
+    let keep_classnames = MangleOptions {
+        keep_classnames: KeepNames::Matching(Regex::new("^Keep").unwrap()),
+        ..MangleOptions::default()
+    };
+    let cases = [
+        ("top_level", &top_level, "var foo = 1; function bar(a) { return foo + a }"),
+        (
+            "top_level",
+            &top_level,
+            "export function foo(a) { return bar } let bar; export { bar as baz }",
+        ),
+        ("top_level", &top_level, "let x = 1; console.log(x)"),
+        ("reserved", &reserved, "function f(foo, bar) { return foo + bar }"),
+        ("reserved", &reserved, "function f(x, y) { var z; return [x, y, z] }"),
+        (
+            "keep_fnames",
+            &keep_fnames,
+            "function f() { function foo() {} return function bar() {} }",
+        ),
+        (
+            "keep_fnames",
+            &keep_fnames,
+            "function f() { const foo = function() {}, bar = () => {}, baz = (function() {}), qux = 1; return [foo, bar, baz, qux] }",
+        ),
+        (
+            "keep_classnames",
+            &keep_classnames,
+            "function f() { class Keep {} class Other {} return [Keep, Other] }",
+        ),
+        (
+            "keep_classnames",
+            &keep_classnames,
+            "function f() { const Keep = class {}, KeepNamed = class Other {}, Other = class {}; return [Keep, KeepNamed, Other] }",
+        ),
+    ];
+
+    let snapshot = cases.into_iter().fold(String::new(), |mut w, (name, options, case)| {
+        write!(w, "{name}: {case}\n{}\n", mangle(case, options.clone())).unwrap();
+        w
+    });
+
+    insta::with_settings!({ prepend_module_to_snapshot => false, omit_expression => true }, {
+        insta::assert_snapshot!("mangle_options", snapshot);
+    });
+}
+
+#[test]
+fn name_cache() {
+    let options = MangleOptions {
+        top_level: true,
+        properties: Some(ManglePropertiesOptions {
+            regex: Some(Regex::new("^_").unwrap()),
+            ..ManglePropertiesOptions::default()
+        }),
+        ..MangleOptions::default()
+    };
+    let allocator = Allocator::default();
+    let source_text = "var foo = 1, bar = foo + foo; x._foo = x._bar + x._bar";
+    let program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+    let name_cache = Mangler::new().with_options(options.clone()).build(&program).name_cache();
+
+    let json = name_cache.to_json();
+    assert_eq!(
+        json,
+        r#"{"vars":{"props":{"$bar":"b","$foo":"a"}},"props":{"props":{"$_bar":"a","$_foo":"b"}}}"#
+    );
+    assert_eq!(NameCache::from_json(&json).unwrap(), name_cache);
+    assert!(NameCache::from_json("{}").is_ok_and(|cache| cache == NameCache::default()));
+    assert!(NameCache::from_json(r#"{"vars":{"props":[]}}"#).is_err());
+
+    // Cached names are kept, new names don't collide with them
+    let options = MangleOptions { name_cache, ..options };
+    assert_eq!(
+        mangle("var baz = 1, bar = baz; x._baz = x._bar", options.clone()),
+        "var c = 1, b = c;\nx.c = x.a;\n"
+    );
+
+    // Cached names which collide with kept names or globals are not used
+    assert_eq!(
+        mangle("export var b = 1; var bar = b; var foo = bar", options.clone()),
+        "export var b = 1;\nvar c = b;\nvar a = c;\n"
+    );
+    assert_eq!(
+        mangle("var bar = 1, foo = bar; b(foo)", options.clone()),
+        "var c = 1, a = c;\nb(a);\n"
+    );
+    let mut name_cache = NameCache::default();
+    name_cache.vars.insert("foo".into(), "a".into());
+    name_cache.vars.insert("bar".into(), "a".into());
+    let options = MangleOptions { name_cache, ..options };
+    assert_eq!(mangle("var foo = 1, bar = foo", options), "var a = 1, b = a;\n");
+}
+
+#[test]
+fn minifier_mangle_options() {
+    let allocator = Allocator::default();
+    let source_text = "var foo = 1; function bar(baz) { return baz } console.log(bar(foo))";
+    let mut program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+    let options = MinifierOptions {
+        mangle: Some(MangleOptions { top_level: true, ..MangleOptions::default() }),
+        compress: CompressOptions::all_false(),
+    };
+    let ret = Minifier::new(options).build(&allocator, &mut program);
+    assert_eq!(
+        CodeGenerator::new().with_mangler(ret.mangler).build(&program).code,
+        "var a = 1;\nfunction b(c) {\n\treturn c;\n}\nconsole.log(b(a));\n"
+    );
+}
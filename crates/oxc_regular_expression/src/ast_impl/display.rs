@@ -319,8 +319,12 @@ fn character_to_string(
         }
 
         if is_lead_surrogate(cp) {
-            if let Some(peek) = peek.filter(|peek| is_trail_surrogate(peek.value)) {
-                // Lead+Trail
+            if let Some(peek) = peek.filter(|peek| {
+                this.kind == CharacterKind::Symbol
+                    && peek.kind == CharacterKind::Symbol
+                    && is_trail_surrogate(peek.value)
+            }) {
+                // Lead+Trail, written as a single literal character
                 let cp = combine_surrogate_pair(cp, peek.value);
                 let ch = char::from_u32(cp).expect("Invalid surrogate pair `Character`!");
                 return (Cow::Owned(format!("{ch}")), true);
@@ -456,6 +460,7 @@ mod test {
         ("/a{0}|b{1,2}|c{3,}/iu", None),
         (r"/Em🥹j/", None),
         (r"/Em🥹j/u", None),
+        (r"/\uD83D\uDE00/", None),
         (r"/\n\cM\0\x41\./", None),
         (r"/\n\cM\0\x41\./u", None),
         (r"/\n\cM\0\x41\u1234\./", None),
@@ -8,7 +8,7 @@ use oxc_ecmascript::{BoundNames, IsSimpleParameterList};
 use oxc_span::{GetSpan, SourceType};
 
 use crate::{
-    scope::{ScopeFlags, ScopeId},
+    scope::{ScopeFlags, UNBOUND_SCOPE_ID},
     symbol::SymbolFlags,
     SemanticBuilder,
 };
@@ -159,7 +159,7 @@ impl<'a> Binder<'a> for Function<'a> {
                     ident.span,
                     ident.name.clone().into(),
                     SymbolFlags::Function,
-                    ScopeId::new(u32::MAX - 1), // Not bound to any scope.
+                    UNBOUND_SCOPE_ID,
                     builder.current_node_id,
                 );
                 ident.symbol_id.set(Some(symbol_id));
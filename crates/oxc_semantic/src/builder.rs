@@ -392,6 +392,20 @@ impl<'a> SemanticBuilder<'a> {
         self.scope.get_flags(self.current_scope_id)
     }
 
+    /// Flag the current scope and its ancestors as containing a direct `eval` or `with`.
+    fn set_direct_eval_flag(&mut self) {
+        let mut scope_id = Some(self.current_scope_id);
+        while let Some(id) = scope_id {
+            let flags = self.scope.get_flags_mut(id);
+            if flags.contains_direct_eval() {
+                // Ancestors were flagged already
+                break;
+            }
+            *flags |= ScopeFlags::DirectEval;
+            scope_id = self.scope.get_parent_id(id);
+        }
+    }
+
     /// Is the current scope in strict mode?
     pub(crate) fn strict_mode(&self) -> bool {
         self.current_scope_flags().is_strict_mode()
@@ -1789,6 +1803,15 @@ impl<'a> SemanticBuilder<'a> {
             AstKind::ImportSpecifier(specifier) => {
                 specifier.bind(self);
             }
+            // `eval?.()` and `(0, eval)()` are indirect
+            AstKind::CallExpression(call)
+                if !call.optional && call.callee.without_parentheses().is_specific_id("eval") =>
+            {
+                self.set_direct_eval_flag();
+            }
+            AstKind::WithStatement(_) => {
+                self.set_direct_eval_flag();
+            }
             AstKind::ImportDefaultSpecifier(specifier) => {
                 specifier.bind(self);
             }
@@ -2005,7 +2028,12 @@ impl<'a> SemanticBuilder<'a> {
                 }
                 self.current_reference_flags -= ReferenceFlags::Write;
             }
-            AstKind::AssignmentExpression(_) | AstKind::ExportNamedDeclaration(_)
+            AstKind::ExportNamedDeclaration(_) => {
+                // `export { a }` has no binding identifiers to clear the flag on leaving them
+                self.current_symbol_flags -= SymbolFlags::Export;
+                self.current_reference_flags = ReferenceFlags::empty();
+            }
+            AstKind::AssignmentExpression(_)
             | AstKind::TSTypeQuery(_)
             // Clear the reference flags that are set in AstKind::PropertySignature
             | AstKind::PropertyKey(_) => {
@@ -39,9 +39,10 @@ mod unresolved_stack;
 pub use crate::{
     builder::{SemanticBuilder, SemanticBuilderReturn},
     jsdoc::{JSDoc, JSDocFinder, JSDocTag},
+    module_record::ModuleRecordBuilder,
     node::{AstNode, AstNodes, NodeId},
     reference::{Reference, ReferenceFlags, ReferenceId},
-    scope::ScopeTree,
+    scope::{ScopeTree, UNBOUND_SCOPE_ID},
     stats::Stats,
     symbol::{IsGlobalReference, SymbolTable},
 };
@@ -15,6 +15,11 @@ type FxIndexMap<K, V> = IndexMap<K, V, BuildHasherDefault<FxHasher>>;
 pub(crate) type Bindings = FxIndexMap<CompactStr, SymbolId>;
 pub type UnresolvedReferences = FxHashMap<CompactStr, Vec<ReferenceId>>;
 
+/// Scope of function declarations in `if` statements in sloppy mode (Annex B).
+///
+/// These functions are not bound to any scope, and references never resolve to them.
+pub const UNBOUND_SCOPE_ID: ScopeId = ScopeId::new(u32::MAX - 1);
+
 /// Scope Tree
 ///
 /// The scope tree stores lexical scopes created by a program, and all the
@@ -258,3 +258,19 @@ fn test_import_type() {
         .contains_flags(SymbolFlags::TypeImport)
         .test();
 }
+
+#[test]
+fn test_export_specifiers_do_not_leak_export_flag() {
+    let test = SemanticTester::js(
+        "
+    const a = 1;
+    export { a };
+    function foo(b) {}
+    let c;
+    ",
+    );
+    test.has_some_symbol("a").is_exported().test();
+    for name in &["foo", "b", "c"] {
+        test.has_some_symbol(name).is_not_exported().test();
+    }
+}
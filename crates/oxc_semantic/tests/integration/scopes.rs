@@ -114,6 +114,71 @@ fn test_function_level_strict() {
     tester.has_some_symbol("foo").is_not_in_scope(ScopeFlags::StrictMode).test();
 }
 
+#[test]
+fn test_direct_eval() {
+    let tester = SemanticTester::js(
+        "
+    function outer() {
+        let a;
+        function inner() {
+            let b;
+            eval('b');
+        }
+        function sibling() {
+            let c;
+        }
+    }
+    ",
+    );
+    tester.has_root_symbol("outer").is_in_scope(ScopeFlags::DirectEval).test();
+    tester.has_some_symbol("a").is_in_scope(ScopeFlags::DirectEval).test();
+    tester.has_some_symbol("b").is_in_scope(ScopeFlags::DirectEval).test();
+    tester.has_some_symbol("c").is_not_in_scope(ScopeFlags::DirectEval).test();
+
+    // Indirect eval
+    SemanticTester::js("function foo() { let x; (0, eval)('x'); eval?.('x'); }")
+        .has_some_symbol("x")
+        .is_not_in_scope(ScopeFlags::DirectEval)
+        .test();
+
+    // `with` is only allowed in scripts
+    let tester = SemanticTester::js(
+        "
+    function foo(o) {
+        let x;
+        with (o) { x; }
+    }
+    function bar() {
+        let y;
+    }
+    ",
+    )
+    .with_module(false);
+    tester.has_some_symbol("x").is_in_scope(ScopeFlags::DirectEval).test();
+    tester.has_some_symbol("y").is_not_in_scope(ScopeFlags::DirectEval).test();
+    SemanticTester::js("function foo(o) { with (o) {} }")
+        .has_error("'with' statements are not allowed");
+
+    // Direct eval in scripts
+    let tester = SemanticTester::js(
+        "
+    var a;
+    eval('a');
+    function foo() {
+        var b;
+    }
+    ",
+    )
+    .with_module(false);
+    tester.has_root_symbol("a").is_in_scope(ScopeFlags::DirectEval).test();
+    tester.has_some_symbol("b").is_not_in_scope(ScopeFlags::DirectEval).test();
+    SemanticTester::js("function foo() { var a; if (a) function bar() { eval('a') } }")
+        .with_module(false)
+        .has_some_symbol("a")
+        .is_in_scope(ScopeFlags::DirectEval)
+        .test();
+}
+
 #[test]
 fn test_switch_case() {
     SemanticTester::js(
@@ -73,6 +73,9 @@ bitflags! {
         const GetAccessor      = 1 << 7;
         const SetAccessor      = 1 << 8;
         const CatchClause      = 1 << 9;
+        /// Contains a direct `eval` call or a `with` statement, in this scope or a child scope.
+        /// Bindings in this scope may be looked up by name at runtime.
+        const DirectEval       = 1 << 10;
         const Var = Self::Top.bits() | Self::Function.bits() | Self::ClassStaticBlock.bits() | Self::TsModuleBlock.bits();
         const Modifiers = Self::Constructor.bits() | Self::GetAccessor.bits() | Self::SetAccessor.bits();
     }
@@ -96,7 +99,13 @@ impl ScopeFlags {
 
     #[inline]
     pub fn is_block(&self) -> bool {
-        self.is_empty() || *self == Self::StrictMode
+        let flags = *self - Self::DirectEval;
+        flags.is_empty() || flags == Self::StrictMode
+    }
+
+    #[inline]
+    pub fn contains_direct_eval(&self) -> bool {
+        self.contains(Self::DirectEval)
     }
 
     #[inline]
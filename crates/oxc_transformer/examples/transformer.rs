@@ -55,10 +55,10 @@ fn main() {
     let (symbols, scopes) = ret.semantic.into_symbol_table_and_scope_tree();
 
     let transform_options = if let Some(targets) = &targets {
-        TransformOptions::from_preset_env(&EnvOptions {
-            targets: Targets::from_query(targets),
-            ..EnvOptions::default()
-        })
+        TransformOptions::from_preset_env(
+            &EnvOptions { targets: Some(Targets::from_query(targets)), ..EnvOptions::default() },
+            Path::new(""),
+        )
         .unwrap()
     } else {
         TransformOptions::enable_all()
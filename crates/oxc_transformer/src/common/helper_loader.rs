@@ -1,7 +1,7 @@
 //! Utility to load helper functions.
 //!
 //! This module provides functionality to load helper functions in different modes.
-//! It supports runtime, external, and inline (not yet implemented) modes for loading helper functions.
+//! It supports runtime, external, and inline modes for loading helper functions.
 //!
 //! ## Usage
 //!
@@ -47,9 +47,8 @@
 //!
 //! ### Inline ([`HelperLoaderMode::Inline`])
 //!
-//! > Note: This mode is not currently implemented.
-//!
 //! Inline helper functions are inserted directly into the top of program.
+//! Each helper is inserted only once, along with any other helpers it depends on.
 //!
 //! Generated code example:
 //!
@@ -64,6 +63,11 @@
 //!
 //! Unlike other "common" utilities, this one has no transformer. It adds imports to the program
 //! via `ModuleImports` transform.
+//!
+//! In inline mode, helper sources (in `helpers` directory) are parsed into the AST, and inserted
+//! at top of the program via `TopLevelStatements` transform. Each top level declaration in
+//! a helper's source is given a UID in root scope, and references to those declarations,
+//! and to other helpers the helper depends on, are renamed to match.
 
 use std::{borrow::Cow, cell::RefCell};
 
@@ -71,9 +75,18 @@ use rustc_hash::FxHashMap;
 use serde::Deserialize;
 
 use oxc_allocator::{String as AString, Vec};
-use oxc_ast::ast::{Argument, CallExpression, Expression, TSTypeParameterInstantiation};
+use oxc_ast::{
+    ast::{
+        Argument, AssignmentExpression, AssignmentOperator, BindingIdentifier, BindingPatternKind,
+        CallExpression, Expression, IdentifierReference, SimpleAssignmentTarget, Statement,
+        TSTypeParameterInstantiation, UpdateExpression,
+    },
+    visit::walk_mut,
+    VisitMut,
+};
+use oxc_parser::Parser;
 use oxc_semantic::{ReferenceFlags, SymbolFlags};
-use oxc_span::{Atom, SPAN};
+use oxc_span::{Atom, SourceType, SPAN};
 use oxc_traverse::{BoundIdentifier, TraverseCtx};
 
 use crate::TransformCtx;
@@ -83,8 +96,6 @@ use crate::TransformCtx;
 pub enum HelperLoaderMode {
     /// Inline mode: Helper functions are directly inserted into the program.
     ///
-    /// Note: This mode is not currently implemented.
-    ///
     /// Example output:
     /// ```js
     /// function helperName(...arguments) { ... } // Inlined helper function
@@ -136,16 +147,303 @@ fn default_as_module_name() -> Cow<'static, str> {
 
 /// Available helpers.
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[expect(clippy::enum_variant_names)]
 pub enum Helper {
+    ApplyDecs2305,
+    ArrayLikeToArray,
+    ArrayWithHoles,
+    ArrayWithoutHoles,
+    AssertClassBrand,
+    AssertThisInitialized,
+    AsyncGeneratorDelegate,
+    AsyncIterator,
     AsyncToGenerator,
+    AwaitAsyncGenerator,
+    CallSuper,
+    CheckInRHS,
+    CheckPrivateRedeclaration,
+    ClassCallCheck,
+    ClassPrivateFieldGet2,
+    ClassPrivateFieldInitSpec,
+    ClassPrivateFieldLooseBase,
+    ClassPrivateFieldLooseKey,
+    ClassPrivateFieldSet2,
+    ClassPrivateGetter,
+    ClassPrivateMethodInitSpec,
+    ClassPrivateSetter,
+    Construct,
+    CreateClass,
+    CreateForOfIteratorHelper,
+    CreateForOfIteratorHelperLoose,
+    Decorate,
+    DecorateMetadata,
+    DecorateParam,
+    DefineAccessor,
+    DefineProperty,
+    Extends,
+    Get,
+    GetPrototypeOf,
+    Inherits,
+    InteropRequireDefault,
+    InteropRequireWildcard,
+    IsNativeReflectConstruct,
+    IterableToArray,
+    IterableToArrayLimit,
+    NonIterableRest,
+    NonIterableSpread,
+    ObjectDestructuringEmpty,
     ObjectSpread2,
+    ObjectWithoutProperties,
+    ObjectWithoutPropertiesLoose,
+    OverloadYield,
+    PossibleConstructorReturn,
+    ReadOnlyError,
+    RegeneratorRuntime,
+    Set,
+    SetFunctionName,
+    SetPrototypeOf,
+    SlicedToArray,
+    SuperPropBase,
+    SuperPropGet,
+    SuperPropSet,
+    TaggedTemplateLiteral,
+    TaggedTemplateLiteralLoose,
+    ToArray,
+    ToConsumableArray,
+    ToPrimitive,
+    ToPropertyKey,
+    ToSetter,
+    TypeOf,
+    UnsupportedIterableToArray,
+    UsingCtx,
+    WrapAsyncGenerator,
+    WrapRegExp,
+    WriteOnlyError,
 }
 
 impl Helper {
     const fn name(self) -> &'static str {
         match self {
+            Self::ApplyDecs2305 => "applyDecs2305",
+            Self::ArrayLikeToArray => "arrayLikeToArray",
+            Self::ArrayWithHoles => "arrayWithHoles",
+            Self::ArrayWithoutHoles => "arrayWithoutHoles",
+            Self::AssertClassBrand => "assertClassBrand",
+            Self::AssertThisInitialized => "assertThisInitialized",
+            Self::AsyncGeneratorDelegate => "asyncGeneratorDelegate",
+            Self::AsyncIterator => "asyncIterator",
             Self::AsyncToGenerator => "asyncToGenerator",
+            Self::AwaitAsyncGenerator => "awaitAsyncGenerator",
+            Self::CallSuper => "callSuper",
+            Self::CheckInRHS => "checkInRHS",
+            Self::CheckPrivateRedeclaration => "checkPrivateRedeclaration",
+            Self::ClassCallCheck => "classCallCheck",
+            Self::ClassPrivateFieldGet2 => "classPrivateFieldGet2",
+            Self::ClassPrivateFieldInitSpec => "classPrivateFieldInitSpec",
+            Self::ClassPrivateFieldLooseBase => "classPrivateFieldLooseBase",
+            Self::ClassPrivateFieldLooseKey => "classPrivateFieldLooseKey",
+            Self::ClassPrivateFieldSet2 => "classPrivateFieldSet2",
+            Self::ClassPrivateGetter => "classPrivateGetter",
+            Self::ClassPrivateMethodInitSpec => "classPrivateMethodInitSpec",
+            Self::ClassPrivateSetter => "classPrivateSetter",
+            Self::Construct => "construct",
+            Self::CreateClass => "createClass",
+            Self::CreateForOfIteratorHelper => "createForOfIteratorHelper",
+            Self::CreateForOfIteratorHelperLoose => "createForOfIteratorHelperLoose",
+            Self::Decorate => "decorate",
+            Self::DecorateMetadata => "decorateMetadata",
+            Self::DecorateParam => "decorateParam",
+            Self::DefineAccessor => "defineAccessor",
+            Self::DefineProperty => "defineProperty",
+            Self::Extends => "extends",
+            Self::Get => "get",
+            Self::GetPrototypeOf => "getPrototypeOf",
+            Self::Inherits => "inherits",
+            Self::InteropRequireDefault => "interopRequireDefault",
+            Self::InteropRequireWildcard => "interopRequireWildcard",
+            Self::IsNativeReflectConstruct => "isNativeReflectConstruct",
+            Self::IterableToArray => "iterableToArray",
+            Self::IterableToArrayLimit => "iterableToArrayLimit",
+            Self::NonIterableRest => "nonIterableRest",
+            Self::NonIterableSpread => "nonIterableSpread",
+            Self::ObjectDestructuringEmpty => "objectDestructuringEmpty",
             Self::ObjectSpread2 => "objectSpread2",
+            Self::ObjectWithoutProperties => "objectWithoutProperties",
+            Self::ObjectWithoutPropertiesLoose => "objectWithoutPropertiesLoose",
+            Self::OverloadYield => "OverloadYield",
+            Self::PossibleConstructorReturn => "possibleConstructorReturn",
+            Self::ReadOnlyError => "readOnlyError",
+            Self::RegeneratorRuntime => "regeneratorRuntime",
+            Self::Set => "set",
+            Self::SetFunctionName => "setFunctionName",
+            Self::SetPrototypeOf => "setPrototypeOf",
+            Self::SlicedToArray => "slicedToArray",
+            Self::SuperPropBase => "superPropBase",
+            Self::SuperPropGet => "superPropGet",
+            Self::SuperPropSet => "superPropSet",
+            Self::TaggedTemplateLiteral => "taggedTemplateLiteral",
+            Self::TaggedTemplateLiteralLoose => "taggedTemplateLiteralLoose",
+            Self::ToArray => "toArray",
+            Self::ToConsumableArray => "toConsumableArray",
+            Self::ToPrimitive => "toPrimitive",
+            Self::ToPropertyKey => "toPropertyKey",
+            Self::ToSetter => "toSetter",
+            Self::TypeOf => "typeof",
+            Self::UnsupportedIterableToArray => "unsupportedIterableToArray",
+            Self::UsingCtx => "usingCtx",
+            Self::WrapAsyncGenerator => "wrapAsyncGenerator",
+            Self::WrapRegExp => "wrapRegExp",
+            Self::WriteOnlyError => "writeOnlyError",
+        }
+    }
+
+    /// Helpers which this helper's inline source calls.
+    const fn dependencies(self) -> &'static [Self] {
+        match self {
+            Self::ApplyDecs2305 => &[Self::CheckInRHS, Self::SetFunctionName, Self::ToPropertyKey],
+            Self::ArrayWithoutHoles | Self::UnsupportedIterableToArray => &[Self::ArrayLikeToArray],
+            Self::AsyncGeneratorDelegate | Self::AwaitAsyncGenerator | Self::WrapAsyncGenerator => {
+                &[Self::OverloadYield]
+            }
+            Self::CallSuper => &[
+                Self::GetPrototypeOf,
+                Self::IsNativeReflectConstruct,
+                Self::PossibleConstructorReturn,
+            ],
+            Self::CheckInRHS | Self::SetFunctionName | Self::ToPrimitive => &[Self::TypeOf],
+            Self::ClassPrivateFieldGet2
+            | Self::ClassPrivateFieldSet2
+            | Self::ClassPrivateGetter
+            | Self::ClassPrivateSetter => &[Self::AssertClassBrand],
+            Self::ClassPrivateFieldInitSpec | Self::ClassPrivateMethodInitSpec => {
+                &[Self::CheckPrivateRedeclaration]
+            }
+            Self::Construct => &[Self::IsNativeReflectConstruct, Self::SetPrototypeOf],
+            Self::CreateClass | Self::DefineProperty => &[Self::ToPropertyKey],
+            Self::CreateForOfIteratorHelper | Self::CreateForOfIteratorHelperLoose => {
+                &[Self::UnsupportedIterableToArray]
+            }
+            Self::Get => &[Self::SuperPropBase],
+            Self::Inherits => &[Self::SetPrototypeOf],
+            Self::ObjectSpread2 => &[Self::DefineProperty],
+            Self::ObjectWithoutProperties => &[Self::ObjectWithoutPropertiesLoose],
+            Self::PossibleConstructorReturn => &[Self::AssertThisInitialized, Self::TypeOf],
+            Self::Set => &[Self::DefineProperty, Self::SuperPropBase],
+            Self::SlicedToArray => &[
+                Self::ArrayWithHoles,
+                Self::IterableToArrayLimit,
+                Self::UnsupportedIterableToArray,
+                Self::NonIterableRest,
+            ],
+            Self::SuperPropBase => &[Self::GetPrototypeOf],
+            Self::SuperPropGet => &[Self::Get, Self::GetPrototypeOf],
+            Self::SuperPropSet => &[Self::GetPrototypeOf, Self::Set],
+            Self::ToArray => &[
+                Self::ArrayWithHoles,
+                Self::IterableToArray,
+                Self::UnsupportedIterableToArray,
+                Self::NonIterableRest,
+            ],
+            Self::ToConsumableArray => &[
+                Self::ArrayWithoutHoles,
+                Self::IterableToArray,
+                Self::UnsupportedIterableToArray,
+                Self::NonIterableSpread,
+            ],
+            Self::ToPropertyKey => &[Self::ToPrimitive, Self::TypeOf],
+            Self::WrapRegExp => &[Self::Inherits, Self::SetPrototypeOf],
+            _ => &[],
+        }
+    }
+
+    /// Source of the helper, used in inline mode.
+    ///
+    /// Declares the helper as a function named `_<name>`, which calls its dependencies
+    /// as `_<dependency name>`. Any other top level declarations are private to the helper.
+    const fn source(self) -> &'static str {
+        match self {
+            Self::ApplyDecs2305 => include_str!("helpers/applyDecs2305.js"),
+            Self::ArrayLikeToArray => include_str!("helpers/arrayLikeToArray.js"),
+            Self::ArrayWithHoles => include_str!("helpers/arrayWithHoles.js"),
+            Self::ArrayWithoutHoles => include_str!("helpers/arrayWithoutHoles.js"),
+            Self::AssertClassBrand => include_str!("helpers/assertClassBrand.js"),
+            Self::AssertThisInitialized => include_str!("helpers/assertThisInitialized.js"),
+            Self::AsyncGeneratorDelegate => include_str!("helpers/asyncGeneratorDelegate.js"),
+            Self::AsyncIterator => include_str!("helpers/asyncIterator.js"),
+            Self::AsyncToGenerator => include_str!("helpers/asyncToGenerator.js"),
+            Self::AwaitAsyncGenerator => include_str!("helpers/awaitAsyncGenerator.js"),
+            Self::CallSuper => include_str!("helpers/callSuper.js"),
+            Self::CheckInRHS => include_str!("helpers/checkInRHS.js"),
+            Self::CheckPrivateRedeclaration => include_str!("helpers/checkPrivateRedeclaration.js"),
+            Self::ClassCallCheck => include_str!("helpers/classCallCheck.js"),
+            Self::ClassPrivateFieldGet2 => include_str!("helpers/classPrivateFieldGet2.js"),
+            Self::ClassPrivateFieldInitSpec => include_str!("helpers/classPrivateFieldInitSpec.js"),
+            Self::ClassPrivateFieldLooseBase => {
+                include_str!("helpers/classPrivateFieldLooseBase.js")
+            }
+            Self::ClassPrivateFieldLooseKey => include_str!("helpers/classPrivateFieldLooseKey.js"),
+            Self::ClassPrivateFieldSet2 => include_str!("helpers/classPrivateFieldSet2.js"),
+            Self::ClassPrivateGetter => include_str!("helpers/classPrivateGetter.js"),
+            Self::ClassPrivateMethodInitSpec => {
+                include_str!("helpers/classPrivateMethodInitSpec.js")
+            }
+            Self::ClassPrivateSetter => include_str!("helpers/classPrivateSetter.js"),
+            Self::Construct => include_str!("helpers/construct.js"),
+            Self::CreateClass => include_str!("helpers/createClass.js"),
+            Self::CreateForOfIteratorHelper => include_str!("helpers/createForOfIteratorHelper.js"),
+            Self::CreateForOfIteratorHelperLoose => {
+                include_str!("helpers/createForOfIteratorHelperLoose.js")
+            }
+            Self::Decorate => include_str!("helpers/decorate.js"),
+            Self::DecorateMetadata => include_str!("helpers/decorateMetadata.js"),
+            Self::DecorateParam => include_str!("helpers/decorateParam.js"),
+            Self::DefineAccessor => include_str!("helpers/defineAccessor.js"),
+            Self::DefineProperty => include_str!("helpers/defineProperty.js"),
+            Self::Extends => include_str!("helpers/extends.js"),
+            Self::Get => include_str!("helpers/get.js"),
+            Self::GetPrototypeOf => include_str!("helpers/getPrototypeOf.js"),
+            Self::Inherits => include_str!("helpers/inherits.js"),
+            Self::InteropRequireDefault => include_str!("helpers/interopRequireDefault.js"),
+            Self::InteropRequireWildcard => include_str!("helpers/interopRequireWildcard.js"),
+            Self::IsNativeReflectConstruct => include_str!("helpers/isNativeReflectConstruct.js"),
+            Self::IterableToArray => include_str!("helpers/iterableToArray.js"),
+            Self::IterableToArrayLimit => include_str!("helpers/iterableToArrayLimit.js"),
+            Self::NonIterableRest => include_str!("helpers/nonIterableRest.js"),
+            Self::NonIterableSpread => include_str!("helpers/nonIterableSpread.js"),
+            Self::ObjectDestructuringEmpty => include_str!("helpers/objectDestructuringEmpty.js"),
+            Self::ObjectSpread2 => include_str!("helpers/objectSpread2.js"),
+            Self::ObjectWithoutProperties => include_str!("helpers/objectWithoutProperties.js"),
+            Self::ObjectWithoutPropertiesLoose => {
+                include_str!("helpers/objectWithoutPropertiesLoose.js")
+            }
+            Self::OverloadYield => include_str!("helpers/OverloadYield.js"),
+            Self::PossibleConstructorReturn => include_str!("helpers/possibleConstructorReturn.js"),
+            Self::ReadOnlyError => include_str!("helpers/readOnlyError.js"),
+            Self::RegeneratorRuntime => include_str!("helpers/regeneratorRuntime.js"),
+            Self::Set => include_str!("helpers/set.js"),
+            Self::SetFunctionName => include_str!("helpers/setFunctionName.js"),
+            Self::SetPrototypeOf => include_str!("helpers/setPrototypeOf.js"),
+            Self::SlicedToArray => include_str!("helpers/slicedToArray.js"),
+            Self::SuperPropBase => include_str!("helpers/superPropBase.js"),
+            Self::SuperPropGet => include_str!("helpers/superPropGet.js"),
+            Self::SuperPropSet => include_str!("helpers/superPropSet.js"),
+            Self::TaggedTemplateLiteral => include_str!("helpers/taggedTemplateLiteral.js"),
+            Self::TaggedTemplateLiteralLoose => {
+                include_str!("helpers/taggedTemplateLiteralLoose.js")
+            }
+            Self::ToArray => include_str!("helpers/toArray.js"),
+            Self::ToConsumableArray => include_str!("helpers/toConsumableArray.js"),
+            Self::ToPrimitive => include_str!("helpers/toPrimitive.js"),
+            Self::ToPropertyKey => include_str!("helpers/toPropertyKey.js"),
+            Self::ToSetter => include_str!("helpers/toSetter.js"),
+            Self::TypeOf => include_str!("helpers/typeof.js"),
+            Self::UnsupportedIterableToArray => {
+                include_str!("helpers/unsupportedIterableToArray.js")
+            }
+            Self::UsingCtx => include_str!("helpers/usingCtx.js"),
+            Self::WrapAsyncGenerator => include_str!("helpers/wrapAsyncGenerator.js"),
+            Self::WrapRegExp => include_str!("helpers/wrapRegExp.js"),
+            Self::WriteOnlyError => include_str!("helpers/writeOnlyError.js"),
         }
     }
 }
@@ -166,6 +464,16 @@ impl<'a> HelperLoaderStore<'a> {
             loaded_helpers: RefCell::new(FxHashMap::default()),
         }
     }
+
+    /// Returns `true` if `source` is the source of a helper imported from the runtime package.
+    ///
+    /// e.g. `"@babel/runtime/helpers/defineProperty"`.
+    pub(crate) fn is_runtime_helper_source(&self, source: &str) -> bool {
+        matches!(self.mode, HelperLoaderMode::Runtime)
+            && source
+                .strip_prefix(self.module_name.as_ref())
+                .is_some_and(|rest| rest.starts_with("/helpers/"))
+    }
 }
 
 // Public methods implemented directly on `TransformCtx`, as they need access to `TransformCtx::module_imports`.
@@ -216,7 +524,7 @@ impl<'a> TransformCtx<'a> {
                 HelperLoaderStore::transform_for_external_helper(helper, ctx)
             }
             HelperLoaderMode::Inline => {
-                unreachable!("Inline helpers are not supported yet");
+                helper_loader.transform_for_inline_helper(helper, self, ctx)
             }
         }
     }
@@ -270,4 +578,137 @@ impl<'a> HelperLoaderStore<'a> {
         let property = ctx.ast.identifier_name(SPAN, Atom::from(helper.name()));
         Expression::from(ctx.ast.member_expression_static(SPAN, object, property, false))
     }
+
+    fn transform_for_inline_helper(
+        &self,
+        helper: Helper,
+        transform_ctx: &TransformCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let binding = self.load_inline_helper(helper, transform_ctx, ctx);
+        binding.create_read_expression(ctx)
+    }
+
+    /// Insert helper's source at top of program, if it's not been already,
+    /// and return binding for the helper function.
+    fn load_inline_helper(
+        &self,
+        helper: Helper,
+        transform_ctx: &TransformCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        if let Some(binding) = self.loaded_helpers.borrow().get(&helper) {
+            return binding.clone();
+        }
+
+        // Load dependencies first, so they're inserted before this helper
+        let mut bindings = FxHashMap::default();
+        for &dependency in helper.dependencies() {
+            let binding = self.load_inline_helper(dependency, transform_ctx, ctx);
+            bindings.insert(Self::inline_helper_name(dependency, ctx), binding);
+        }
+
+        let helper_name = Self::inline_helper_name(helper, ctx);
+        let ret = Parser::new(ctx.ast.allocator, helper.source(), SourceType::default()).parse();
+        debug_assert!(ret.errors.is_empty(), "Failed to parse `{}` helper", helper.name());
+        let mut stmts = ret.program.body;
+
+        // Generate UIDs for all top level declarations, including the helper function itself
+        let mut helper_binding = None;
+        for stmt in &stmts {
+            match stmt {
+                Statement::FunctionDeclaration(func) => {
+                    let name = func.id.as_ref().unwrap().name.clone();
+                    let binding = ctx.generate_uid_in_root_scope(&name, SymbolFlags::Function);
+                    if name == helper_name {
+                        helper_binding = Some(binding.clone());
+                    }
+                    bindings.insert(name, binding);
+                }
+                Statement::VariableDeclaration(decl) => {
+                    for declarator in &decl.declarations {
+                        let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind
+                        else {
+                            unreachable!()
+                        };
+                        let binding = ctx.generate_uid_in_root_scope(
+                            &ident.name,
+                            SymbolFlags::FunctionScopedVariable,
+                        );
+                        bindings.insert(ident.name.clone(), binding);
+                    }
+                }
+                _ => {}
+            }
+        }
+        let helper_binding = helper_binding.unwrap();
+        self.loaded_helpers.borrow_mut().insert(helper, helper_binding.clone());
+
+        let mut renamer = InlineHelperRenamer::new(&bindings, ctx);
+        renamer.visit_statements(&mut stmts);
+        transform_ctx.top_level_statements.insert_statements(stmts);
+
+        helper_binding
+    }
+
+    /// Get name of helper function in helper's source e.g. `_classCallCheck`.
+    fn inline_helper_name(helper: Helper, ctx: &TraverseCtx<'a>) -> Atom<'a> {
+        ctx.ast.atom(&format!("_{}", helper.name()))
+    }
+}
+
+/// Visitor which renames top level declarations of an inline helper, and references to them,
+/// to their UIDs.
+struct InlineHelperRenamer<'a, 'b> {
+    bindings: &'b FxHashMap<Atom<'a>, BoundIdentifier<'a>>,
+    /// Flags for `IdentifierReference` being visited, if it's an assignment target
+    target_flags: ReferenceFlags,
+    ctx: &'b mut TraverseCtx<'a>,
+}
+
+impl<'a, 'b> InlineHelperRenamer<'a, 'b> {
+    fn new(
+        bindings: &'b FxHashMap<Atom<'a>, BoundIdentifier<'a>>,
+        ctx: &'b mut TraverseCtx<'a>,
+    ) -> Self {
+        Self { bindings, target_flags: ReferenceFlags::Read, ctx }
+    }
+}
+
+impl<'a, 'b> VisitMut<'a> for InlineHelperRenamer<'a, 'b> {
+    fn visit_binding_identifier(&mut self, ident: &mut BindingIdentifier<'a>) {
+        if let Some(binding) = self.bindings.get(&ident.name) {
+            *ident = binding.create_binding_identifier(self.ctx);
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        if let Some(binding) = self.bindings.get(&ident.name) {
+            *ident = binding.create_reference(ReferenceFlags::Read, self.ctx);
+        }
+    }
+
+    fn visit_assignment_expression(&mut self, expr: &mut AssignmentExpression<'a>) {
+        self.target_flags = if expr.operator == AssignmentOperator::Assign {
+            ReferenceFlags::Write
+        } else {
+            ReferenceFlags::read_write()
+        };
+        walk_mut::walk_assignment_expression(self, expr);
+    }
+
+    fn visit_update_expression(&mut self, expr: &mut UpdateExpression<'a>) {
+        self.target_flags = ReferenceFlags::read_write();
+        walk_mut::walk_update_expression(self, expr);
+    }
+
+    fn visit_simple_assignment_target(&mut self, target: &mut SimpleAssignmentTarget<'a>) {
+        if let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) = target {
+            if let Some(binding) = self.bindings.get(&ident.name) {
+                **ident = binding.create_reference(self.target_flags, self.ctx);
+            }
+        } else {
+            walk_mut::walk_simple_assignment_target(self, target);
+        }
+    }
 }
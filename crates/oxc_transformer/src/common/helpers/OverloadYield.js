@@ -0,0 +1,3 @@
+function _OverloadYield(e, d) {
+  this.v = e, this.k = d;
+}
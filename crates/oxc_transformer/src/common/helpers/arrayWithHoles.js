@@ -0,0 +1,3 @@
+function _arrayWithHoles(r) {
+  if (Array.isArray(r)) return r;
+}
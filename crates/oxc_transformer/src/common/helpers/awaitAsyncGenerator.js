@@ -0,0 +1,3 @@
+function _awaitAsyncGenerator(e) {
+  return new _OverloadYield(e, 0);
+}
@@ -0,0 +1,4 @@
+var id = 0;
+function _classPrivateFieldLooseKey(e) {
+  return "__private_" + id++ + "_" + e;
+}
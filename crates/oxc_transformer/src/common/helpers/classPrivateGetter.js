@@ -0,0 +1,3 @@
+function _classPrivateGetter(s, r, a) {
+  return a(_assertClassBrand(s, r));
+}
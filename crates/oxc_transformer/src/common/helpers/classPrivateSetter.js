@@ -0,0 +1,3 @@
+function _classPrivateSetter(s, r, a, t) {
+  return r(_assertClassBrand(s, a), t), t;
+}
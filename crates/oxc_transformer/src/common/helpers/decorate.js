@@ -0,0 +1,6 @@
+function _decorate(e, t, r, n) {
+  var o, c = arguments.length, i = c < 3 ? t : null === n ? n = Object.getOwnPropertyDescriptor(t, r) : n;
+  if ("object" == typeof Reflect && "function" == typeof Reflect.decorate) i = Reflect.decorate(e, t, r, n);
+  else for (var a = e.length - 1; a >= 0; a--) (o = e[a]) && (i = (c < 3 ? o(i) : c > 3 ? o(t, r, i) : o(t, r)) || i);
+  return c > 3 && i && Object.defineProperty(t, r, i), i;
+}
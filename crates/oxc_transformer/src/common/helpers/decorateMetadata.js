@@ -0,0 +1,3 @@
+function _decorateMetadata(e, t) {
+  if ("object" == typeof Reflect && "function" == typeof Reflect.metadata) return Reflect.metadata(e, t);
+}
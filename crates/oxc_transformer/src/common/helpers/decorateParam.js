@@ -0,0 +1,5 @@
+function _decorateParam(e, t) {
+  return function (r, n) {
+    t(r, n, e);
+  };
+}
@@ -0,0 +1,4 @@
+function _defineAccessor(e, r, n, t) {
+  var c = { configurable: true, enumerable: true };
+  return c[e] = t, Object.defineProperty(r, n, c);
+}
@@ -0,0 +1,10 @@
+function _extends() {
+  var n = Object.assign || function (n) {
+    for (var e = 1; e < arguments.length; e++) {
+      var t = arguments[e];
+      for (var r in t) ({}).hasOwnProperty.call(t, r) && (n[r] = t[r]);
+    }
+    return n;
+  };
+  return n.apply(null, arguments);
+}
@@ -0,0 +1,8 @@
+function _get(e, t, r) {
+  if ("undefined" != typeof Reflect && Reflect.get) return Reflect.get.apply(null, arguments);
+  var p = _superPropBase(e, t);
+  if (p) {
+    var n = Object.getOwnPropertyDescriptor(p, t);
+    return n.get ? n.get.call(arguments.length < 3 ? e : r) : n.value;
+  }
+}
@@ -0,0 +1,3 @@
+function _getPrototypeOf(t) {
+  return Object.setPrototypeOf ? Object.getPrototypeOf(t) : t.__proto__ || Object.getPrototypeOf(t);
+}
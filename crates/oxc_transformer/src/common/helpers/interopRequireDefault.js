@@ -0,0 +1,3 @@
+function _interopRequireDefault(e) {
+  return e && e.__esModule ? e : { default: e };
+}
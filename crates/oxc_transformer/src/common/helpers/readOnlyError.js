@@ -0,0 +1,3 @@
+function _readOnlyError(r) {
+  throw new TypeError('"' + r + '" is read-only');
+}
@@ -0,0 +1,16 @@
+function baseSet(e, r, t, o) {
+  var f, i = _superPropBase(e, r);
+  if (i) {
+    if ((f = Object.getOwnPropertyDescriptor(i, r)).set) return f.set.call(o, t), true;
+    if (!f.writable) return false;
+  }
+  if (f = Object.getOwnPropertyDescriptor(o, r)) {
+    if (!f.writable) return false;
+    f.value = t, Object.defineProperty(o, r, f);
+  } else _defineProperty(o, r, t);
+  return true;
+}
+function _set(e, r, t, o, f) {
+  if (!("undefined" != typeof Reflect && Reflect.set ? Reflect.set(e, r, t, o || e) : baseSet(e, r, t, o || e)) && f) throw new TypeError("failed to set property");
+  return t;
+}
@@ -0,0 +1,7 @@
+function _setFunctionName(e, t, n) {
+  "symbol" == _typeof(t) && (t = (t = t.description) ? "[" + t + "]" : "");
+  try {
+    Object.defineProperty(e, "name", { configurable: true, value: n ? n + " " + t : t });
+  } catch (e) {}
+  return e;
+}
@@ -0,0 +1,3 @@
+function _setPrototypeOf(t, e) {
+  return Object.setPrototypeOf ? Object.setPrototypeOf(t, e) : (t.__proto__ = e, t);
+}
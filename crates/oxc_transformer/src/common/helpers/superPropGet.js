@@ -0,0 +1,6 @@
+function _superPropGet(t, o, e, r) {
+  var p = _get(_getPrototypeOf(1 & r ? t.prototype : t), o, e);
+  return 2 & r && "function" == typeof p ? function (t) {
+    return p.apply(e, t);
+  } : p;
+}
@@ -0,0 +1,3 @@
+function _superPropSet(t, e, o, r, p, f) {
+  return _set(_getPrototypeOf(f ? t.prototype : t), e, o, r, p);
+}
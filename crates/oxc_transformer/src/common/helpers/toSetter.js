@@ -0,0 +1,9 @@
+function _toSetter(t, e, n) {
+  e || (e = []);
+  var r = e.length++;
+  return Object.defineProperty({}, "_", {
+    set: function (o) {
+      e[r] = o, t.apply(n, e);
+    }
+  });
+}
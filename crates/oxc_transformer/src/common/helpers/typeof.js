@@ -0,0 +1,5 @@
+function _typeof(o) {
+  "@babel/helpers - typeof";
+
+  return "function" == typeof Symbol && "symbol" == typeof Symbol.iterator ? typeof o : o && "function" == typeof Symbol && o.constructor === Symbol && o !== Symbol.prototype ? "symbol" : typeof o;
+}
@@ -0,0 +1,3 @@
+function _writeOnlyError(r) {
+  throw new TypeError('"' + r + '" is write-only');
+}
@@ -155,7 +155,11 @@ impl<'a> ModuleImportsStore<'a> {
     }
 
     /// Insert `import` / `require` statements at top of program.
-    fn insert_into_program(&self, transform_ctx: &TransformCtx<'a>, ctx: &mut TraverseCtx<'a>) {
+    pub(crate) fn insert_into_program(
+        &self,
+        transform_ctx: &TransformCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
         if transform_ctx.source_type.is_script() {
             self.insert_require_statements(transform_ctx, ctx);
         } else {
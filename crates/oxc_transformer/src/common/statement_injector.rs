@@ -9,6 +9,7 @@
 //! ```rs
 //! self.ctx.statement_injector.insert_before(address, statement);
 //! self.ctx.statement_injector.insert_after(address, statement);
+//! self.ctx.statement_injector.insert_many_before(address, statements);
 //! self.ctx.statement_injector.insert_many_after(address, statements);
 //! ```
 
@@ -66,7 +67,6 @@ impl<'a> StatementInjectorStore<'a> {
     }
 
     /// Add a statement to be inserted immediately before the target statement.
-    #[expect(dead_code)]
     pub fn insert_before(&self, target: Address, stmt: Statement<'a>) {
         let mut insertions = self.insertions.borrow_mut();
         let adjacent_stmts = insertions.entry(target).or_default();
@@ -78,13 +78,26 @@ impl<'a> StatementInjectorStore<'a> {
     }
 
     /// Add a statement to be inserted immediately after the target statement.
-    #[expect(dead_code)]
     pub fn insert_after(&self, target: Address, stmt: Statement<'a>) {
         let mut insertions = self.insertions.borrow_mut();
         let adjacent_stmts = insertions.entry(target).or_default();
         adjacent_stmts.push(AdjacentStatement { stmt, direction: Direction::After });
     }
 
+    /// Add multiple statements to be inserted immediately before the target statement.
+    pub fn insert_many_before(&self, target: Address, stmts: Vec<Statement<'a>>) {
+        let mut insertions = self.insertions.borrow_mut();
+        let adjacent_stmts = insertions.entry(target).or_default();
+        let index = adjacent_stmts
+            .iter()
+            .position(|s| matches!(s.direction, Direction::After))
+            .unwrap_or(adjacent_stmts.len());
+        adjacent_stmts.splice(
+            index..index,
+            stmts.into_iter().map(|stmt| AdjacentStatement { stmt, direction: Direction::Before }),
+        );
+    }
+
     /// Add multiple statements to be inserted immediately after the target statement.
     pub fn insert_many_after(&self, target: Address, stmts: Vec<Statement<'a>>) {
         let mut insertions = self.insertions.borrow_mut();
@@ -13,7 +13,7 @@ use crate::{
         statement_injector::StatementInjectorStore, top_level_statements::TopLevelStatementsStore,
         var_declarations::VarDeclarationsStore,
     },
-    TransformOptions,
+    CompilerAssumptions, TransformOptions,
 };
 
 pub struct TransformCtx<'a> {
@@ -29,6 +29,9 @@ pub struct TransformCtx<'a> {
 
     pub source_text: &'a str,
 
+    /// <https://babeljs.io/docs/assumptions>
+    pub assumptions: CompilerAssumptions,
+
     // Helpers
     /// Manage helper loading
     pub helper_loader: HelperLoaderStore<'a>,
@@ -58,6 +61,7 @@ impl<'a> TransformCtx<'a> {
             source_path,
             source_type: SourceType::default(),
             source_text: "",
+            assumptions: options.assumptions,
             helper_loader: HelperLoaderStore::new(&options.helper_loader),
             module_imports: ModuleImportsStore::new(),
             var_declarations: VarDeclarationsStore::new(),
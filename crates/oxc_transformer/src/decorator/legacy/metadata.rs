@@ -0,0 +1,295 @@
+//! Legacy decorators: design-time type metadata.
+//!
+//! When `emitDecoratorMetadata` is enabled, decorated declarations get additional
+//! `babelHelpers.decorateMetadata(key, type)` decorators describing their types:
+//!
+//! * `design:type`: Type of a property, or `Function` for a method.
+//! * `design:paramtypes`: Types of the parameters of a method, setter or constructor.
+//! * `design:returntype`: Return type of a method.
+//!
+//! Types are serialized to runtime values the same way as TypeScript does, except that we have no
+//! type information. So:
+//!
+//! * A type reference to a class, function or variable declared in this file is emitted as-is.
+//! * A type reference to an import or a global is guarded against it not existing at runtime:
+//!   `typeof Foo === "undefined" ? Object : Foo`.
+//! * A type reference to an interface, type alias, type parameter or enum is serialized as `Object`.
+//!
+//! Reference: <https://github.com/microsoft/TypeScript/blob/main/src/compiler/transformers/typeSerializer.ts>
+
+use oxc_ast::ast::*;
+use oxc_semantic::{ReferenceFlags, SymbolFlags};
+use oxc_span::{Atom, SPAN};
+use oxc_traverse::TraverseCtx;
+
+use crate::{common::helper_loader::Helper, TransformCtx};
+
+/// A type serialized to the runtime value which represents it.
+#[derive(Clone, Copy)]
+enum SerializedType<'a, 'b> {
+    /// Global constructor e.g. `String`, `Object`.
+    Global(&'static str),
+    /// `void 0`
+    Void,
+    /// Reference to a value. `guarded` if it may not exist at runtime.
+    Reference { name: &'b TSTypeName<'a>, guarded: bool },
+}
+
+/// Create `babelHelpers.decorateMetadata(key, value)` decorator.
+pub(super) fn create_metadata_decorator<'a>(
+    key: &'static str,
+    value: Expression<'a>,
+    transform_ctx: &TransformCtx<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Decorator<'a> {
+    let arguments = ctx.ast.vec_from_iter([
+        Argument::from(ctx.ast.expression_string_literal(SPAN, key)),
+        Argument::from(value),
+    ]);
+    let call = transform_ctx.helper_call_expr(Helper::DecorateMetadata, arguments, ctx);
+    ctx.ast.decorator(SPAN, call)
+}
+
+/// Serialize type of a type annotation. No annotation is serialized as `Object`.
+pub(super) fn serialize_type_annotation<'a>(
+    annotation: Option<&TSTypeAnnotation<'a>>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let serialized = annotation
+        .map_or(SerializedType::Global("Object"), |a| serialize_type(&a.type_annotation, ctx));
+    create_serialized_expression(serialized, ctx)
+}
+
+/// Serialize type of a parameter, looking through default values (`x: T = 1`).
+pub(super) fn serialize_parameter_type<'a>(
+    param: &FormalParameter<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let annotation =
+        param.pattern.type_annotation.as_deref().or_else(|| match &param.pattern.kind {
+            BindingPatternKind::AssignmentPattern(assign) => assign.left.type_annotation.as_deref(),
+            _ => None,
+        });
+    serialize_type_annotation(annotation, ctx)
+}
+
+/// Serialize type of a rest parameter. The element type of the array is used, as TypeScript does.
+pub(super) fn serialize_rest_parameter_type<'a>(
+    rest: &BindingRestElement<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let element_type = rest.argument.type_annotation.as_ref().and_then(|annotation| {
+        match &annotation.type_annotation {
+            TSType::TSArrayType(array) => Some(&array.element_type),
+            TSType::TSTypeReference(reference) if is_array_reference(reference) => {
+                reference.type_parameters.as_ref().and_then(|params| params.params.first())
+            }
+            _ => None,
+        }
+    });
+    let serialized =
+        element_type.map_or(SerializedType::Global("Object"), |ty| serialize_type(ty, ctx));
+    create_serialized_expression(serialized, ctx)
+}
+
+/// Create `[Type1, Type2]` of a function's parameter types.
+pub(super) fn serialize_parameter_types<'a>(
+    params: &FormalParameters<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let mut elements = ctx.ast.vec_with_capacity(params.items.len() + 1);
+    for param in &params.items {
+        let ty = serialize_parameter_type(param, ctx);
+        elements.push(ArrayExpressionElement::from(ty));
+    }
+    if let Some(rest) = &params.rest {
+        let ty = serialize_rest_parameter_type(rest, ctx);
+        elements.push(ArrayExpressionElement::from(ty));
+    }
+    ctx.ast.expression_array(SPAN, elements, None)
+}
+
+/// Serialize return type of a method. No annotation is serialized as `void 0`,
+/// or `Promise` for an async method.
+pub(super) fn serialize_return_type<'a>(
+    func: &Function<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let serialized = match &func.return_type {
+        Some(annotation) => serialize_type(&annotation.type_annotation, ctx),
+        None if func.r#async => SerializedType::Global("Promise"),
+        None => SerializedType::Void,
+    };
+    create_serialized_expression(serialized, ctx)
+}
+
+fn serialize_type<'a, 'b>(ty: &'b TSType<'a>, ctx: &TraverseCtx<'a>) -> SerializedType<'a, 'b> {
+    match ty {
+        TSType::TSVoidKeyword(_)
+        | TSType::TSUndefinedKeyword(_)
+        | TSType::TSNullKeyword(_)
+        | TSType::TSNeverKeyword(_) => SerializedType::Void,
+        TSType::TSStringKeyword(_) | TSType::TSTemplateLiteralType(_) => {
+            SerializedType::Global("String")
+        }
+        TSType::TSNumberKeyword(_) => SerializedType::Global("Number"),
+        TSType::TSBigIntKeyword(_) => SerializedType::Global("BigInt"),
+        TSType::TSBooleanKeyword(_) | TSType::TSTypePredicate(_) => {
+            SerializedType::Global("Boolean")
+        }
+        TSType::TSSymbolKeyword(_) => SerializedType::Global("Symbol"),
+        TSType::TSFunctionType(_) | TSType::TSConstructorType(_) => {
+            SerializedType::Global("Function")
+        }
+        TSType::TSArrayType(_) | TSType::TSTupleType(_) => SerializedType::Global("Array"),
+        TSType::TSLiteralType(literal) => match &literal.literal {
+            TSLiteral::StringLiteral(_) | TSLiteral::TemplateLiteral(_) => {
+                SerializedType::Global("String")
+            }
+            TSLiteral::NumericLiteral(_) => SerializedType::Global("Number"),
+            TSLiteral::BigIntLiteral(_) => SerializedType::Global("BigInt"),
+            TSLiteral::BooleanLiteral(_) => SerializedType::Global("Boolean"),
+            TSLiteral::NullLiteral(_) => SerializedType::Void,
+            TSLiteral::UnaryExpression(unary) => match &unary.argument {
+                Expression::BigIntLiteral(_) => SerializedType::Global("BigInt"),
+                _ => SerializedType::Global("Number"),
+            },
+            TSLiteral::RegExpLiteral(_) => SerializedType::Global("Object"),
+        },
+        TSType::TSTypeOperatorType(operator) => match operator.operator {
+            TSTypeOperatorOperator::Readonly => serialize_type(&operator.type_annotation, ctx),
+            TSTypeOperatorOperator::Keyof | TSTypeOperatorOperator::Unique => {
+                SerializedType::Global("Object")
+            }
+        },
+        TSType::TSParenthesizedType(ty) => serialize_type(&ty.type_annotation, ctx),
+        TSType::TSUnionType(union) => serialize_union_or_intersection(&union.types, ctx),
+        TSType::TSIntersectionType(intersection) => {
+            serialize_union_or_intersection(&intersection.types, ctx)
+        }
+        TSType::TSTypeReference(reference) => serialize_type_reference(&reference.type_name, ctx),
+        _ => SerializedType::Global("Object"),
+    }
+}
+
+/// A union or intersection is serialized as the type of its members, if they are all the same
+/// (ignoring `null`, `undefined` and `never`). Otherwise it's `Object`.
+fn serialize_union_or_intersection<'a, 'b>(
+    types: &'b [TSType<'a>],
+    ctx: &TraverseCtx<'a>,
+) -> SerializedType<'a, 'b> {
+    let mut serialized: Option<&'static str> = None;
+    for ty in types {
+        let name = match serialize_type(ty, ctx) {
+            SerializedType::Global(name) => name,
+            SerializedType::Void => continue,
+            SerializedType::Reference { .. } => return SerializedType::Global("Object"),
+        };
+        if name == "Object" || serialized.is_some_and(|serialized| serialized != name) {
+            return SerializedType::Global("Object");
+        }
+        serialized = Some(name);
+    }
+    serialized.map_or(SerializedType::Void, SerializedType::Global)
+}
+
+fn serialize_type_reference<'a, 'b>(
+    name: &'b TSTypeName<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> SerializedType<'a, 'b> {
+    let root = get_root_identifier(name);
+    let symbol_id = root
+        .reference_id
+        .get()
+        .and_then(|reference_id| ctx.symbols().get_reference(reference_id).symbol_id());
+    let Some(symbol_id) = symbol_id else {
+        return SerializedType::Reference { name, guarded: true };
+    };
+
+    let flags = ctx.symbols().get_flags(symbol_id);
+    if flags.intersects(SymbolFlags::Class | SymbolFlags::Variable | SymbolFlags::Function) {
+        SerializedType::Reference { name, guarded: false }
+    } else if flags.contains(SymbolFlags::Import)
+        || (flags.contains(SymbolFlags::ValueModule)
+            && matches!(name, TSTypeName::QualifiedName(_)))
+    {
+        SerializedType::Reference { name, guarded: true }
+    } else {
+        SerializedType::Global("Object")
+    }
+}
+
+fn get_root_identifier<'a, 'b>(name: &'b TSTypeName<'a>) -> &'b IdentifierReference<'a> {
+    match name {
+        TSTypeName::IdentifierReference(ident) => ident,
+        TSTypeName::QualifiedName(qualified) => get_root_identifier(&qualified.left),
+    }
+}
+
+fn is_array_reference(reference: &TSTypeReference) -> bool {
+    matches!(&reference.type_name, TSTypeName::IdentifierReference(ident) if ident.name == "Array")
+}
+
+fn create_serialized_expression<'a>(
+    serialized: SerializedType<'a, '_>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    match serialized {
+        SerializedType::Global(name) => create_global_ident(name, ctx),
+        SerializedType::Void => ctx.ast.void_0(SPAN),
+        SerializedType::Reference { name, guarded: false } => {
+            create_type_name_expression(name, ctx)
+        }
+        SerializedType::Reference { name, guarded: true } => {
+            // `typeof Foo === "undefined" ? Object : Foo`
+            let root = get_root_identifier(name);
+            let root = create_identifier(root, ctx);
+            let test = ctx.ast.expression_binary(
+                SPAN,
+                ctx.ast.expression_unary(SPAN, UnaryOperator::Typeof, root),
+                BinaryOperator::StrictEquality,
+                ctx.ast.expression_string_literal(SPAN, "undefined"),
+            );
+            let consequent = create_global_ident("Object", ctx);
+            let alternate = create_type_name_expression(name, ctx);
+            ctx.ast.expression_conditional(SPAN, test, consequent, alternate)
+        }
+    }
+}
+
+/// Convert `A.B.C` type name to a member expression.
+fn create_type_name_expression<'a>(
+    name: &TSTypeName<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    match name {
+        TSTypeName::IdentifierReference(ident) => create_identifier(ident, ctx),
+        TSTypeName::QualifiedName(qualified) => {
+            let object = create_type_name_expression(&qualified.left, ctx);
+            let property = ctx.ast.identifier_name(SPAN, qualified.right.name.clone());
+            Expression::from(ctx.ast.member_expression_static(SPAN, object, property, false))
+        }
+    }
+}
+
+/// Create a value reference to the same binding as a type reference.
+fn create_identifier<'a>(
+    ident: &IdentifierReference<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let symbol_id = ident
+        .reference_id
+        .get()
+        .and_then(|reference_id| ctx.symbols().get_reference(reference_id).symbol_id());
+    let ident = ctx.create_reference_id(SPAN, ident.name.clone(), symbol_id, ReferenceFlags::Read);
+    ctx.ast.expression_from_identifier_reference(ident)
+}
+
+pub(super) fn create_global_ident<'a>(
+    name: &'static str,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let symbol_id = ctx.scopes().find_binding(ctx.current_scope_id(), name);
+    let ident = ctx.create_reference_id(SPAN, Atom::from(name), symbol_id, ReferenceFlags::Read);
+    ctx.ast.expression_from_identifier_reference(ident)
+}
@@ -0,0 +1,603 @@
+//! TypeScript: Legacy Decorators
+//!
+//! This plugin transforms decorators the same way as TypeScript does when its
+//! `experimentalDecorators` option is enabled. Decorators are applied after the class is defined,
+//! by calling the `decorate` helper (TypeScript's `__decorate`).
+//!
+//! Enabled by `experimentalDecorators` TypeScript option, or `version: "legacy"` option of
+//! `proposal-decorators` plugin.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```ts
+//! @dec
+//! class C {
+//!   @prop x: string;
+//!   @method m(@param y: number) {}
+//!   @method static s() {}
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! let C = class {
+//!   x;
+//!   m(y) {}
+//!   static s() {}
+//! };
+//! babelHelpers.decorate([prop], C.prototype, "x", void 0);
+//! babelHelpers.decorate([method, babelHelpers.decorateParam(0, param)], C.prototype, "m", null);
+//! babelHelpers.decorate([method], C, "s", null);
+//! C = babelHelpers.decorate([dec], C);
+//! ```
+//!
+//! ## Options
+//!
+//! ### `emitDecoratorMetadata`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Add `babelHelpers.decorateMetadata(...)` decorators describing design-time types of decorated
+//! declarations. See [`metadata`] module for details.
+//!
+//! ## Missing features
+//!
+//! * Decorators on class expressions are not supported, as in TypeScript.
+//!   They produce an error.
+//! * Enums in metadata are serialized as `Object`, rather than `Number` or `String`.
+//!
+//! ## Implementation
+//!
+//! Implementation based on TypeScript's
+//! [legacyDecorators transform](https://github.com/microsoft/TypeScript/blob/main/src/compiler/transformers/legacyDecorators.ts).
+//!
+//! A class declaration with class decorators or constructor parameter decorators is converted to
+//! a `let` declaration on entering its statement, so that the class binding can be reassigned.
+//! Metadata is gathered on entering the class, before type annotations are removed by TypeScript
+//! transform. Decorators are removed from the class on exiting it, and decoration statements
+//! are inserted after the class's statement.
+//!
+//! ## References:
+//! * TypeScript decorators documentation: <https://www.typescriptlang.org/docs/handbook/decorators.html>
+
+mod metadata;
+
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::{Box as ArenaBox, GetAddress, Vec as ArenaVec};
+use oxc_ast::{ast::*, VisitMut, NONE};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::{ScopeId, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_traverse::{BoundIdentifier, Traverse, TraverseCtx};
+
+use crate::{common::helper_loader::Helper, TransformCtx};
+
+use super::utils::{create_assignment, create_member, reparent_scopes, ReferenceReplacer};
+use metadata::{
+    create_global_ident, create_metadata_decorator, serialize_parameter_type,
+    serialize_parameter_types, serialize_return_type, serialize_type_annotation,
+};
+
+pub struct LegacyDecorator<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    emit_decorator_metadata: bool,
+    /// Classes whose declarations have been converted to `let` declarations, keyed by class scope.
+    converted_classes: FxHashMap<ScopeId, ConvertedClass<'a>>,
+    /// Decoration statements of the class which has just been exited,
+    /// waiting for its parent `Statement` to insert them.
+    pending: Option<PendingStatements<'a>>,
+}
+
+/// Class declaration converted to a `let` declaration.
+struct ConvertedClass<'a> {
+    /// Binding of the `let` declaration
+    binding: BoundIdentifier<'a>,
+    /// `true` for `export default class`, which is exported after decoration
+    is_export_default: bool,
+}
+
+struct PendingStatements<'a> {
+    statements: Vec<Statement<'a>>,
+    /// Alias of class binding for references inside the class, set to the class by
+    /// `let C = _C = class C {}`
+    alias: Option<BoundIdentifier<'a>>,
+}
+
+impl<'a, 'ctx> LegacyDecorator<'a, 'ctx> {
+    pub fn new(emit_decorator_metadata: bool, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            ctx,
+            emit_decorator_metadata,
+            converted_classes: FxHashMap::default(),
+            pending: None,
+        }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for LegacyDecorator<'a, 'ctx> {
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        match stmt {
+            Statement::ClassDeclaration(class) => {
+                if Self::needs_class_reassignment(class) {
+                    *stmt = self.convert_class_declaration(stmt, false, ctx);
+                }
+            }
+            Statement::ExportNamedDeclaration(decl) => {
+                if let Some(Declaration::ClassDeclaration(class)) = &decl.declaration {
+                    if Self::needs_class_reassignment(class) {
+                        let declaration = decl.declaration.take().unwrap();
+                        let Declaration::ClassDeclaration(class) = declaration else {
+                            unreachable!()
+                        };
+                        let var_decl = self.convert_class(class, None, false, ctx);
+                        decl.declaration = Some(Declaration::VariableDeclaration(var_decl));
+                    }
+                }
+            }
+            Statement::ExportDefaultDeclaration(decl) => {
+                if let ExportDefaultDeclarationKind::ClassDeclaration(class) = &decl.declaration {
+                    // Anonymous class needs a binding to refer to it in decoration statements
+                    if Self::needs_class_reassignment(class)
+                        || (class.id.is_none() && has_decorators(class))
+                    {
+                        *stmt = self.convert_class_declaration(stmt, true, ctx);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Some(PendingStatements { statements, alias }) = self.pending.take() else { return };
+
+        if let Some(alias) = alias {
+            // `let C = class {}` -> `let C = _C = class {}`
+            let declarator = match stmt {
+                Statement::VariableDeclaration(decl) => decl.declarations.first_mut(),
+                Statement::ExportNamedDeclaration(decl) => match &mut decl.declaration {
+                    Some(Declaration::VariableDeclaration(decl)) => decl.declarations.first_mut(),
+                    _ => None,
+                },
+                _ => None,
+            };
+            if let Some(init) = declarator.and_then(|declarator| declarator.init.as_mut()) {
+                self.ctx.var_declarations.insert(&alias, None, ctx);
+                let class = ctx.ast.move_expression(init);
+                *init = create_assignment(alias.create_read_write_target(ctx), class, ctx);
+            }
+        }
+
+        self.ctx.statement_injector.insert_many_after(stmt.address(), statements);
+    }
+
+    fn enter_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !has_decorators(class) {
+            return;
+        }
+
+        if class.is_expression()
+            && !self.converted_classes.contains_key(&class.scope_id.get().unwrap())
+        {
+            self.ctx.error(
+                OxcDiagnostic::error(
+                    "Decorators on class expressions are not supported with legacy decorators.",
+                )
+                .with_label(class.span),
+            );
+            return;
+        }
+
+        self.transform_parameter_decorators(class, ctx);
+        if self.emit_decorator_metadata {
+            self.add_metadata(class, ctx);
+        }
+    }
+
+    fn exit_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        let converted = self.converted_classes.remove(&class.scope_id.get().unwrap());
+        if converted.is_none() && (class.is_expression() || !has_decorators(class)) {
+            return;
+        }
+
+        let binding = match &converted {
+            Some(converted) => converted.binding.clone(),
+            None => BoundIdentifier::from_binding_ident(class.id.as_ref().unwrap()),
+        };
+
+        let mut statements = vec![];
+        for is_static in [false, true] {
+            for element in class.body.body.iter_mut() {
+                if element.r#static() != is_static {
+                    continue;
+                }
+                if let Some(stmt) = self.create_member_decoration(element, &binding, ctx) {
+                    statements.push(stmt);
+                }
+            }
+        }
+
+        let mut alias = None;
+        if let Some(converted) = converted {
+            // References to the class inside the class body refer to the undecorated class,
+            // via an alias, as the binding is not initialized until class definition is complete
+            let class_symbol_id = converted.binding.symbol_id;
+            let alias_binding = ctx.generate_uid_in_current_scope(
+                &converted.binding.name,
+                SymbolFlags::FunctionScopedVariable,
+            );
+            let mut replacer = ReferenceReplacer::new(class_symbol_id, &alias_binding, ctx);
+            replacer.visit_class_body(&mut class.body);
+            if replacer.replaced {
+                // Keep class's name, which is otherwise inferred from the binding
+                let class_scope_id = class.scope_id.get().unwrap();
+                let name = converted.binding.name.clone();
+                let id = ctx.generate_binding(name, class_scope_id, SymbolFlags::Class);
+                class.id = Some(id.create_binding_identifier(ctx));
+                alias = Some(alias_binding);
+            } else {
+                let scope_id = ctx.current_scope_id();
+                ctx.scopes_mut().remove_binding(scope_id, &alias_binding.name.to_compact_str());
+            }
+
+            if let Some(stmt) = self.create_class_decoration(class, &binding, alias.as_ref(), ctx) {
+                statements.push(stmt);
+            }
+
+            if converted.is_export_default {
+                // `export default C;`
+                *ctx.symbols_mut().get_flags_mut(binding.symbol_id) |= SymbolFlags::Export;
+                let declaration = ctx.ast.export_default_declaration_kind_expression(
+                    binding.create_read_expression(ctx),
+                );
+                let exported = ctx.ast.module_export_name_identifier_name(SPAN, "default");
+                statements.push(ctx.ast.statement_module_declaration(
+                    ctx.ast.module_declaration_export_default_declaration(
+                        SPAN,
+                        declaration,
+                        exported,
+                    ),
+                ));
+            }
+        }
+
+        self.pending = Some(PendingStatements { statements, alias });
+    }
+}
+
+impl<'a, 'ctx> LegacyDecorator<'a, 'ctx> {
+    /// Class binding needs to be reassigned if the class itself is decorated,
+    /// or its constructor has parameter decorators.
+    fn needs_class_reassignment(class: &Class<'a>) -> bool {
+        !class.decorators.is_empty()
+            || class.body.body.iter().any(|element| match element {
+                ClassElement::MethodDefinition(method) if method.kind.is_constructor() => {
+                    has_parameter_decorators(&method.value.params)
+                }
+                _ => false,
+            })
+    }
+
+    /// Convert class declaration statement to a `let` declaration statement.
+    fn convert_class_declaration(
+        &mut self,
+        stmt: &mut Statement<'a>,
+        is_export_default: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let class = match ctx.ast.move_statement(stmt) {
+            Statement::ClassDeclaration(class) => class,
+            Statement::ExportDefaultDeclaration(decl) => match decl.unbox().declaration {
+                ExportDefaultDeclarationKind::ClassDeclaration(class) => class,
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        };
+
+        let binding = if class.id.is_none() {
+            Some(ctx.generate_uid_in_current_scope("default", SymbolFlags::BlockScopedVariable))
+        } else {
+            None
+        };
+        Statement::VariableDeclaration(self.convert_class(class, binding, is_export_default, ctx))
+    }
+
+    /// Convert `class C {}` to `let C = class {}`.
+    fn convert_class(
+        &mut self,
+        mut class: ArenaBox<'a, Class<'a>>,
+        binding: Option<BoundIdentifier<'a>>,
+        is_export_default: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ArenaBox<'a, VariableDeclaration<'a>> {
+        let (binding, id) = if let Some(binding) = binding {
+            let id = binding.create_binding_identifier(ctx);
+            (binding, id)
+        } else {
+            let id = class.id.take().unwrap();
+            let binding = BoundIdentifier::from_binding_ident(&id);
+            let flags = ctx.symbols_mut().get_flags_mut(binding.symbol_id);
+            *flags = (*flags - SymbolFlags::Class) | SymbolFlags::BlockScopedVariable;
+            (binding, id)
+        };
+        class.r#type = ClassType::ClassExpression;
+
+        let class_scope_id = class.scope_id.get().unwrap();
+        self.converted_classes
+            .insert(class_scope_id, ConvertedClass { binding: binding.clone(), is_export_default });
+
+        let kind = VariableDeclarationKind::Let;
+        let declarator = ctx.ast.variable_declarator(
+            SPAN,
+            kind,
+            ctx.ast.binding_pattern(
+                ctx.ast.binding_pattern_kind_from_binding_identifier(id),
+                NONE,
+                false,
+            ),
+            Some(Expression::ClassExpression(class)),
+            false,
+        );
+        ctx.ast.alloc_variable_declaration(SPAN, kind, ctx.ast.vec1(declarator), false)
+    }
+
+    /// Move parameter decorators to the decorators of their method, or of the class for
+    /// constructor parameters.
+    ///
+    /// `m(@dec x) {}` -> `@babelHelpers.decorateParam(0, dec) m(x) {}`
+    fn transform_parameter_decorators(&self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        for element in class.body.body.iter_mut() {
+            let ClassElement::MethodDefinition(method) = element else { continue };
+            if !has_parameter_decorators(&method.value.params) {
+                continue;
+            }
+
+            let mut param_decorators = vec![];
+            for (index, param) in (0u32..).zip(method.value.params.items.iter_mut()) {
+                for decorator in ctx.ast.move_vec(&mut param.decorators) {
+                    let arguments = ctx.ast.vec_from_iter([
+                        Argument::from(ctx.ast.expression_numeric_literal(
+                            SPAN,
+                            f64::from(index),
+                            index.to_string(),
+                            NumberBase::Decimal,
+                        )),
+                        Argument::from(decorator.expression),
+                    ]);
+                    let call = self.ctx.helper_call_expr(Helper::DecorateParam, arguments, ctx);
+                    param_decorators.push(ctx.ast.decorator(SPAN, call));
+                }
+            }
+
+            if method.kind.is_constructor() {
+                class.decorators.extend(param_decorators);
+            } else {
+                method.decorators.extend(param_decorators);
+            }
+        }
+    }
+
+    /// Add `decorateMetadata` decorators to decorated class and class elements.
+    fn add_metadata(&self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        let mut constructor_params = None;
+        for element in class.body.body.iter_mut() {
+            match element {
+                ClassElement::MethodDefinition(method) => {
+                    if method.kind.is_constructor() {
+                        if method.value.body.is_some() {
+                            constructor_params =
+                                Some(serialize_parameter_types(&method.value.params, ctx));
+                        }
+                        continue;
+                    }
+                    if method.decorators.is_empty() {
+                        continue;
+                    }
+                    let func = &method.value;
+                    let (ty, param_types) = match method.kind {
+                        MethodDefinitionKind::Method => (
+                            create_global_ident("Function", ctx),
+                            serialize_parameter_types(&func.params, ctx),
+                        ),
+                        MethodDefinitionKind::Get => (
+                            serialize_type_annotation(func.return_type.as_deref(), ctx),
+                            ctx.ast.expression_array(SPAN, ctx.ast.vec(), None),
+                        ),
+                        MethodDefinitionKind::Set => (
+                            match func.params.items.first() {
+                                Some(param) => serialize_parameter_type(param, ctx),
+                                None => create_global_ident("Object", ctx),
+                            },
+                            serialize_parameter_types(&func.params, ctx),
+                        ),
+                        MethodDefinitionKind::Constructor => unreachable!(),
+                    };
+                    let return_type = (method.kind == MethodDefinitionKind::Method)
+                        .then(|| serialize_return_type(func, ctx));
+
+                    let mut decorators = vec![
+                        create_metadata_decorator("design:type", ty, self.ctx, ctx),
+                        create_metadata_decorator("design:paramtypes", param_types, self.ctx, ctx),
+                    ];
+                    if let Some(return_type) = return_type {
+                        decorators.push(create_metadata_decorator(
+                            "design:returntype",
+                            return_type,
+                            self.ctx,
+                            ctx,
+                        ));
+                    }
+                    method.decorators.extend(decorators);
+                }
+                ClassElement::PropertyDefinition(prop) => {
+                    if prop.decorators.is_empty() {
+                        continue;
+                    }
+                    let ty = serialize_type_annotation(prop.type_annotation.as_deref(), ctx);
+                    let decorator = create_metadata_decorator("design:type", ty, self.ctx, ctx);
+                    prop.decorators.push(decorator);
+                }
+                ClassElement::AccessorProperty(prop) => {
+                    if prop.decorators.is_empty() {
+                        continue;
+                    }
+                    let ty = serialize_type_annotation(prop.type_annotation.as_deref(), ctx);
+                    let decorator = create_metadata_decorator("design:type", ty, self.ctx, ctx);
+                    prop.decorators.push(decorator);
+                }
+                ClassElement::StaticBlock(_) | ClassElement::TSIndexSignature(_) => {}
+            }
+        }
+
+        if !class.decorators.is_empty() {
+            if let Some(param_types) = constructor_params {
+                let decorator =
+                    create_metadata_decorator("design:paramtypes", param_types, self.ctx, ctx);
+                class.decorators.push(decorator);
+            }
+        }
+    }
+
+    /// Create decoration statement for a class element.
+    ///
+    /// `babelHelpers.decorate([dec], C.prototype, "m", null);`
+    fn create_member_decoration(
+        &self,
+        element: &mut ClassElement<'a>,
+        binding: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Statement<'a>> {
+        let is_static = element.r#static();
+        let (decorators, key, descriptor) = match element {
+            ClassElement::MethodDefinition(method) if !method.kind.is_constructor() => {
+                let method = &mut **method;
+                (&mut method.decorators, &mut method.key, ctx.ast.expression_null_literal(SPAN))
+            }
+            ClassElement::PropertyDefinition(prop) => {
+                let prop = &mut **prop;
+                (&mut prop.decorators, &mut prop.key, ctx.ast.void_0(SPAN))
+            }
+            ClassElement::AccessorProperty(prop) => {
+                let prop = &mut **prop;
+                (&mut prop.decorators, &mut prop.key, ctx.ast.expression_null_literal(SPAN))
+            }
+            _ => return None,
+        };
+        if decorators.is_empty() {
+            return None;
+        }
+
+        if let PropertyKey::PrivateIdentifier(ident) = key {
+            self.ctx.error(
+                OxcDiagnostic::error("Decorators are not valid on private members.")
+                    .with_label(ident.span),
+            );
+            decorators.clear();
+            return None;
+        }
+
+        let decorators = Self::take_decorators(decorators, ctx);
+        let key = self.create_key(key, ctx);
+        let object = binding.create_read_expression(ctx);
+        let target = if is_static { object } else { create_member(object, "prototype", ctx) };
+
+        let arguments = ctx.ast.vec_from_iter([
+            Argument::from(decorators),
+            Argument::from(target),
+            Argument::from(key),
+            Argument::from(descriptor),
+        ]);
+        let call = self.ctx.helper_call_expr(Helper::Decorate, arguments, ctx);
+        Some(ctx.ast.statement_expression(SPAN, call))
+    }
+
+    /// Create decoration statement for the class.
+    ///
+    /// `C = babelHelpers.decorate([dec], C);` or `C = _C = babelHelpers.decorate([dec], C);`
+    fn create_class_decoration(
+        &self,
+        class: &mut Class<'a>,
+        binding: &BoundIdentifier<'a>,
+        alias: Option<&BoundIdentifier<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Statement<'a>> {
+        if class.decorators.is_empty() {
+            return None;
+        }
+
+        let decorators = Self::take_decorators(&mut class.decorators, ctx);
+        let arguments = ctx.ast.vec_from_iter([
+            Argument::from(decorators),
+            Argument::from(binding.create_read_expression(ctx)),
+        ]);
+        let mut value = self.ctx.helper_call_expr(Helper::Decorate, arguments, ctx);
+        if let Some(alias) = alias {
+            value = create_assignment(alias.create_read_write_target(ctx), value, ctx);
+        }
+        let assignment = create_assignment(binding.create_write_target(ctx), value, ctx);
+        Some(ctx.ast.statement_expression(SPAN, assignment))
+    }
+
+    /// Remove decorators and return them as an array expression `[dec1, dec2]`.
+    fn take_decorators(
+        decorators: &mut ArenaVec<'a, Decorator<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let scope_id = ctx.current_scope_id();
+        let elements =
+            ctx.ast.vec_from_iter(ctx.ast.move_vec(decorators).into_iter().map(|decorator| {
+                let mut expr = decorator.expression;
+                reparent_scopes(&mut expr, scope_id, ctx);
+                ArrayExpressionElement::from(expr)
+            }));
+        ctx.ast.expression_array(SPAN, elements, None)
+    }
+
+    /// Create key of class element for decoration call.
+    ///
+    /// A computed key which is not a literal is stored in a temp var:
+    /// `class C { @dec [foo()]() {} }` -> `class C { [_foo = foo()]() {} }`
+    fn create_key(&self, key: &mut PropertyKey<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        match key {
+            PropertyKey::StaticIdentifier(ident) => {
+                ctx.ast.expression_string_literal(SPAN, ident.name.clone())
+            }
+            PropertyKey::StringLiteral(lit) => {
+                ctx.ast.expression_string_literal(SPAN, lit.value.clone())
+            }
+            PropertyKey::NumericLiteral(lit) => {
+                ctx.ast.expression_numeric_literal(SPAN, lit.value, lit.raw, lit.base)
+            }
+            _ => {
+                let expr = key.to_expression_mut();
+                let binding = ctx.generate_uid_in_current_scope_based_on_node(
+                    expr,
+                    SymbolFlags::FunctionScopedVariable,
+                );
+                self.ctx.var_declarations.insert(&binding, None, ctx);
+                let value = ctx.ast.move_expression(expr);
+                *expr = create_assignment(binding.create_read_write_target(ctx), value, ctx);
+                binding.create_read_expression(ctx)
+            }
+        }
+    }
+}
+
+/// Returns `true` if class, any of its elements, or any method parameters have decorators.
+fn has_decorators(class: &Class) -> bool {
+    !class.decorators.is_empty()
+        || class.body.body.iter().any(|element| match element {
+            ClassElement::MethodDefinition(method) => {
+                !method.decorators.is_empty() || has_parameter_decorators(&method.value.params)
+            }
+            ClassElement::PropertyDefinition(prop) => !prop.decorators.is_empty(),
+            ClassElement::AccessorProperty(prop) => !prop.decorators.is_empty(),
+            ClassElement::StaticBlock(_) | ClassElement::TSIndexSignature(_) => false,
+        })
+}
+
+fn has_parameter_decorators(params: &FormalParameters) -> bool {
+    params.items.iter().any(|param| !param.decorators.is_empty())
+}
@@ -0,0 +1,78 @@
+//! Decorators
+//!
+//! * Legacy decorators, as implemented by TypeScript with `experimentalDecorators` option.
+//!   See [`legacy`] module.
+//! * Decorators as specified by the 2023-05 version of the decorators proposal.
+//!   See [`spec`] module.
+
+use oxc_ast::ast::*;
+use oxc_traverse::{Traverse, TraverseCtx};
+
+use crate::TransformCtx;
+
+mod legacy;
+mod options;
+mod spec;
+mod utils;
+
+use legacy::LegacyDecorator;
+use spec::SpecDecorator;
+
+pub use options::{DecoratorOptions, DecoratorVersion};
+
+pub struct Decorator<'a, 'ctx> {
+    // Plugins
+    legacy: Option<LegacyDecorator<'a, 'ctx>>,
+    spec: Option<SpecDecorator<'a, 'ctx>>,
+}
+
+impl<'a, 'ctx> Decorator<'a, 'ctx> {
+    pub fn new(options: Option<DecoratorOptions>, ctx: &'ctx TransformCtx<'a>) -> Self {
+        let legacy = options
+            .filter(DecoratorOptions::is_legacy)
+            .map(|options| LegacyDecorator::new(options.emit_decorator_metadata, ctx));
+        let spec = options.filter(|options| !options.is_legacy()).map(|_| SpecDecorator::new(ctx));
+        Self { legacy, spec }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for Decorator<'a, 'ctx> {
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(spec) = &mut self.spec {
+            spec.exit_program(program, ctx);
+        }
+    }
+
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(legacy) = &mut self.legacy {
+            legacy.enter_statement(stmt, ctx);
+        }
+        if let Some(spec) = &mut self.spec {
+            spec.enter_statement(stmt, ctx);
+        }
+    }
+
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(legacy) = &mut self.legacy {
+            legacy.exit_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(spec) = &mut self.spec {
+            spec.enter_expression(expr, ctx);
+        }
+    }
+
+    fn enter_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(legacy) = &mut self.legacy {
+            legacy.enter_class(class, ctx);
+        }
+    }
+
+    fn exit_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(legacy) = &mut self.legacy {
+            legacy.exit_class(class, ctx);
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use serde::Deserialize;
+
+use crate::TypeScriptOptions;
+
+/// Options for [`proposal-decorators`](https://babel.dev/docs/babel-plugin-proposal-decorators).
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct DecoratorOptions {
+    /// Which decorators proposal to implement.
+    pub version: DecoratorVersion,
+
+    /// Emit design-time type metadata for decorated declarations.
+    /// Only applies to legacy decorators.
+    ///
+    /// Set from TypeScript's `emitDecoratorMetadata` option.
+    #[serde(skip)]
+    pub emit_decorator_metadata: bool,
+}
+
+/// Decorators proposal version.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum DecoratorVersion {
+    /// TypeScript's `experimentalDecorators`.
+    #[serde(rename = "legacy")]
+    Legacy,
+    /// Decorators proposal as of the May 2023 TC39 meeting.
+    #[default]
+    #[serde(rename = "2023-05")]
+    V2023_05,
+}
+
+impl DecoratorOptions {
+    /// Legacy decorators, when TypeScript's `experimentalDecorators` option is enabled.
+    pub fn from_typescript(options: &TypeScriptOptions) -> Option<Self> {
+        options.experimental_decorators.then_some(Self {
+            version: DecoratorVersion::Legacy,
+            emit_decorator_metadata: options.emit_decorator_metadata,
+        })
+    }
+
+    pub fn is_legacy(&self) -> bool {
+        self.version == DecoratorVersion::Legacy
+    }
+}
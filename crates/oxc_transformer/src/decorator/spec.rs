@@ -0,0 +1,862 @@
+//! Proposal: Decorators (2023-05)
+//!
+//! This plugin transforms decorators as specified by the
+//! [decorators proposal](https://github.com/tc39/proposal-decorators), as of the May 2023 TC39 meeting.
+//!
+//! Decorators are applied by the `applyDecs2305` helper, called in a static block at the start of
+//! the class body, so it runs before any other static elements are evaluated.
+//!
+//! Enabled by `version: "2023-05"` option of `proposal-decorators` plugin (the default).
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! @dec
+//! class C {
+//!   @prop x = 1;
+//!   @method m() {}
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _C, _initClass, _init_x, _initProto;
+//! class C {
+//!   static {
+//!     ({
+//!       e: [_init_x, _initProto],
+//!       c: [_C, _initClass]
+//!     } = babelHelpers.applyDecs2305(this, [[prop, 0, "x"], [method, 2, "m"]], [dec]));
+//!   }
+//!   x = (_initProto(this), _init_x(this, 1));
+//!   m() {}
+//!   static {
+//!     _initClass();
+//!   }
+//! }
+//! ```
+//!
+//! References to the class binding are replaced with references to `_C`, which holds the class
+//! returned by class decorators.
+//!
+//! ## Missing features
+//!
+//! * Decorators on private class elements, and on `accessor` auto-accessors.
+//!   These produce an error, and the decorators are removed, so the rest of the class is still
+//!   transformed.
+//! * Static elements of a class with class decorators are defined on the undecorated class.
+//! * Computed keys of undecorated elements are evaluated after decorators.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-proposal-decorators](https://babel.dev/docs/babel-plugin-proposal-decorators).
+//!
+//! Classes are transformed on entering them, so the static blocks and field initializers this
+//! transform creates are then transformed by other plugins.
+//!
+//! Decorator expressions, other than identifiers and member expressions on identifiers, are
+//! evaluated before the class and stored in temp vars (`_dec = foo()`), so they are evaluated in
+//! the enclosing scope, in order.
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-proposal-decorators>
+//! * `applyDecs2305` helper: <https://github.com/babel/babel/blob/main/packages/babel-helpers/src/helpers/applyDecs2305.ts>
+//! * Decorators proposal: <https://github.com/tc39/proposal-decorators>
+
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::{GetAddress, Vec as ArenaVec};
+use oxc_ast::{ast::*, visit::walk_mut, VisitMut, NONE};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::SPAN;
+use oxc_syntax::{
+    identifier::is_identifier_name,
+    reference::{ReferenceFlags, ReferenceId},
+    scope::{ScopeFlags, ScopeId},
+    symbol::SymbolFlags,
+};
+use oxc_traverse::{BoundIdentifier, Traverse, TraverseCtx};
+
+use crate::{common::helper_loader::Helper, TransformCtx};
+
+use super::utils::{create_assignment, create_member, reparent_scopes};
+
+/// Kinds of class element, as understood by `applyDecs2305`.
+const FIELD: u32 = 0;
+const METHOD: u32 = 2;
+const GETTER: u32 = 3;
+const SETTER: u32 = 4;
+/// Flag for static elements.
+const STATIC: u32 = 8;
+/// Flag for decorators which are paired with their `this` values.
+const DECORATORS_HAVE_THIS: u32 = 16;
+
+pub struct SpecDecorator<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    /// References to decorated classes, mapped to the binding which holds the decorated class.
+    /// Replaced on exiting program.
+    class_references: FxHashMap<ReferenceId, BoundIdentifier<'a>>,
+}
+
+impl<'a, 'ctx> SpecDecorator<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx, class_references: FxHashMap::default() }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for SpecDecorator<'a, 'ctx> {
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.class_references.is_empty() {
+            return;
+        }
+        let references = std::mem::take(&mut self.class_references);
+        ClassReferenceReplacer { references, ctx }.visit_program(program);
+    }
+
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let (class, export_name) = match stmt {
+            Statement::ClassDeclaration(class) => (&mut **class, None),
+            Statement::ExportNamedDeclaration(decl) => match &mut decl.declaration {
+                Some(Declaration::ClassDeclaration(class)) => {
+                    let name = class.id.as_ref().map(|id| id.name.clone());
+                    (&mut **class, name)
+                }
+                _ => return,
+            },
+            Statement::ExportDefaultDeclaration(decl) => match &mut decl.declaration {
+                ExportDefaultDeclarationKind::ClassDeclaration(class) => {
+                    (&mut **class, Some(Atom::from("default")))
+                }
+                _ => return,
+            },
+            _ => return,
+        };
+        if !has_decorators(class) {
+            return;
+        }
+
+        // Anonymous `export default class {}` needs a name to refer to it
+        if class.id.is_none() && !class.decorators.is_empty() {
+            let binding = ctx.generate_uid_in_current_scope("default", SymbolFlags::Class);
+            class.id = Some(binding.create_binding_identifier(ctx));
+        }
+
+        let (memos, class_binding) = self.transform_class(class, ctx);
+
+        let memos = memos
+            .into_iter()
+            .map(|(binding, value)| {
+                let assignment = create_assignment(binding.create_write_target(ctx), value, ctx);
+                ctx.ast.statement_expression(SPAN, assignment)
+            })
+            .collect();
+
+        // Class decorators replace the class binding, so export the binding holding decorated class.
+        // `export class C {}` -> `class C {}; export { _C as C };`
+        if let (Some(class_binding), Some(export_name)) = (class_binding, export_name) {
+            let class = match ctx.ast.move_statement(stmt) {
+                Statement::ExportNamedDeclaration(decl) => match decl.unbox().declaration {
+                    Some(Declaration::ClassDeclaration(class)) => class,
+                    _ => unreachable!(),
+                },
+                Statement::ExportDefaultDeclaration(decl) => match decl.unbox().declaration {
+                    ExportDefaultDeclarationKind::ClassDeclaration(class) => class,
+                    _ => unreachable!(),
+                },
+                _ => unreachable!(),
+            };
+            // Class binding is no longer exported
+            if let Some(id) = &class.id {
+                let flags = ctx.symbols_mut().get_flags_mut(id.symbol_id.get().unwrap());
+                *flags -= SymbolFlags::Export;
+            }
+            *ctx.symbols_mut().get_flags_mut(class_binding.symbol_id) |= SymbolFlags::Export;
+            *stmt = Statement::ClassDeclaration(class);
+
+            let local =
+                ModuleExportName::IdentifierReference(class_binding.create_read_reference(ctx));
+            let exported = ctx.ast.module_export_name_identifier_name(SPAN, export_name);
+            let specifier =
+                ctx.ast.export_specifier(SPAN, local, exported, ImportOrExportKind::Value);
+            let export =
+                ctx.ast.plain_export_named_declaration(SPAN, ctx.ast.vec1(specifier), None);
+            self.ctx
+                .statement_injector
+                .insert_after(stmt.address(), Statement::ExportNamedDeclaration(export));
+        }
+
+        self.ctx.statement_injector.insert_many_before(stmt.address(), memos);
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::ClassExpression(class) = expr else { return };
+        if !has_decorators(class) {
+            return;
+        }
+
+        let (memos, class_binding) = self.transform_class(class, ctx);
+        if memos.is_empty() && class_binding.is_none() {
+            return;
+        }
+
+        // `@dec class {}` -> `(_dec = foo(), class {}, _C)`
+        let class = ctx.ast.move_expression(expr);
+        let mut exprs = ctx.ast.vec_with_capacity(memos.len() + 2);
+        exprs.extend(memos.into_iter().map(|(binding, value)| {
+            create_assignment(binding.create_read_write_target(ctx), value, ctx)
+        }));
+        exprs.push(class);
+        if let Some(class_binding) = class_binding {
+            exprs.push(class_binding.create_read_expression(ctx));
+        }
+        *expr = ctx.ast.expression_sequence(SPAN, exprs);
+    }
+}
+
+/// Value to evaluate before the class, and temp var to store it in.
+type Memo<'a> = (BoundIdentifier<'a>, Expression<'a>);
+
+/// Decorator expressions of a class or class element, ready to pass to `applyDecs2305`.
+struct Decorators<'a> {
+    value: Expression<'a>,
+    have_this: bool,
+}
+
+impl<'a, 'ctx> SpecDecorator<'a, 'ctx> {
+    /// Transform decorators of a class.
+    ///
+    /// Returns values to evaluate before the class, and binding which holds the decorated
+    /// class if class has class decorators.
+    fn transform_class(
+        &mut self,
+        class: &mut Class<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Vec<Memo<'a>>, Option<BoundIdentifier<'a>>) {
+        let class_scope_id = class.scope_id.get().unwrap();
+        let mut memos = vec![];
+
+        let class_decorators = if class.decorators.is_empty() {
+            None
+        } else {
+            let decorators = ctx.ast.move_vec(&mut class.decorators);
+            Some(self.create_decorators(decorators, &mut memos, ctx))
+        };
+
+        let mut member_decs = ctx.ast.vec();
+        let mut field_inits = vec![];
+        let mut has_proto_initializers = false;
+        let mut has_static_initializers = false;
+        for element in class.body.body.iter_mut() {
+            let (decorators, key, is_static, kind) = match element {
+                ClassElement::MethodDefinition(method) => {
+                    let kind = match method.kind {
+                        MethodDefinitionKind::Method => METHOD,
+                        MethodDefinitionKind::Get => GETTER,
+                        MethodDefinitionKind::Set => SETTER,
+                        MethodDefinitionKind::Constructor => continue,
+                    };
+                    let method = &mut **method;
+                    (&mut method.decorators, &mut method.key, method.r#static, kind)
+                }
+                ClassElement::PropertyDefinition(prop) => {
+                    let prop = &mut **prop;
+                    (&mut prop.decorators, &mut prop.key, prop.r#static, FIELD)
+                }
+                ClassElement::AccessorProperty(prop) => {
+                    if !prop.decorators.is_empty() {
+                        self.ctx.error(
+                            OxcDiagnostic::error(
+                                "Decorators on `accessor` properties are not supported yet.",
+                            )
+                            .with_label(prop.span),
+                        );
+                        prop.decorators.clear();
+                    }
+                    continue;
+                }
+                ClassElement::StaticBlock(_) | ClassElement::TSIndexSignature(_) => continue,
+            };
+            if decorators.is_empty() {
+                continue;
+            }
+            if let PropertyKey::PrivateIdentifier(ident) = key {
+                self.ctx.error(
+                    OxcDiagnostic::error(
+                        "Decorators on private class elements are not supported yet.",
+                    )
+                    .with_label(ident.span),
+                );
+                decorators.clear();
+                continue;
+            }
+
+            let decorators = ctx.ast.move_vec(decorators);
+            let decorators = self.create_decorators(decorators, &mut memos, ctx);
+            let name = self.create_key(key, &mut memos, ctx);
+
+            let mut flags = kind;
+            if is_static {
+                flags |= STATIC;
+            }
+            if decorators.have_this {
+                flags |= DECORATORS_HAVE_THIS;
+            }
+            member_decs.push(ArrayExpressionElement::from(ctx.ast.expression_array(
+                SPAN,
+                ctx.ast.vec_from_iter([
+                    ArrayExpressionElement::from(decorators.value),
+                    ArrayExpressionElement::from(create_number(flags, ctx)),
+                    ArrayExpressionElement::from(name),
+                ]),
+                None,
+            )));
+
+            if kind == FIELD {
+                let ClassElement::PropertyDefinition(prop) = element else { unreachable!() };
+                let binding = self.create_init_binding(&prop.key, ctx);
+                // `x = 1` -> `x = _init_x(this, 1)`
+                let mut arguments = ctx.ast.vec1(Argument::from(ctx.ast.expression_this(SPAN)));
+                if let Some(value) = prop.value.take() {
+                    arguments.push(Argument::from(value));
+                }
+                prop.value = Some(ctx.ast.expression_call(
+                    SPAN,
+                    binding.create_read_expression(ctx),
+                    NONE,
+                    arguments,
+                    false,
+                ));
+                field_inits.push(binding);
+            } else if is_static {
+                has_static_initializers = true;
+            } else {
+                has_proto_initializers = true;
+            }
+        }
+
+        if member_decs.is_empty() && class_decorators.is_none() {
+            return (memos, None);
+        }
+
+        // Result of `applyDecs2305(...).e`
+        let mut element_targets = field_inits;
+        let init_proto = has_proto_initializers.then(|| {
+            let binding = self.create_temp_var("initProto", ctx);
+            element_targets.push(binding.clone());
+            binding
+        });
+        let init_static = has_static_initializers.then(|| {
+            let binding = self.create_temp_var("initStatic", ctx);
+            element_targets.push(binding.clone());
+            binding
+        });
+
+        // Result of `applyDecs2305(...).c`
+        let class_result = class_decorators.as_ref().map(|_| {
+            let name = class.id.as_ref().map_or_else(|| Atom::from("Class"), |id| id.name.clone());
+            let class_binding = self.create_temp_var(&name, ctx);
+            let init_class = self.create_temp_var("initClass", ctx);
+            (class_binding, init_class)
+        });
+
+        // `babelHelpers.applyDecs2305(this, [...], [...])`
+        let mut arguments = ctx.ast.vec_from_iter([
+            Argument::from(ctx.ast.expression_this(SPAN)),
+            Argument::from(ctx.ast.expression_array(SPAN, member_decs, None)),
+        ]);
+        let mut class_decs_have_this = false;
+        if let Some(decorators) = class_decorators {
+            class_decs_have_this = decorators.have_this;
+            let value = match decorators.value {
+                value @ Expression::ArrayExpression(_) => value,
+                value => ctx.ast.expression_array(
+                    SPAN,
+                    ctx.ast.vec1(ArrayExpressionElement::from(value)),
+                    None,
+                ),
+            };
+            arguments.push(Argument::from(value));
+        } else {
+            arguments.push(Argument::from(ctx.ast.expression_array(SPAN, ctx.ast.vec(), None)));
+        }
+        if class_decs_have_this {
+            arguments.push(Argument::from(create_number(1, ctx)));
+        }
+        let call = self.ctx.helper_call_expr(Helper::ApplyDecs2305, arguments, ctx);
+
+        let apply = match (&class_result, element_targets.is_empty()) {
+            // `[_init_x, _initProto] = babelHelpers.applyDecs2305(...).e`
+            (None, _) => ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                create_array_target(&element_targets, ctx),
+                create_member(call, "e", ctx),
+            ),
+            // `[_C, _initClass] = babelHelpers.applyDecs2305(...).c`
+            (Some((class_binding, init_class)), true) => ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                create_array_target(&[class_binding.clone(), init_class.clone()], ctx),
+                create_member(call, "c", ctx),
+            ),
+            // `({ e: [_init_x], c: [_C, _initClass] } = babelHelpers.applyDecs2305(...))`
+            (Some((class_binding, init_class)), false) => {
+                let properties = ctx.ast.vec_from_iter([
+                    create_property_target("e", &element_targets, ctx),
+                    create_property_target("c", &[class_binding.clone(), init_class.clone()], ctx),
+                ]);
+                let target = ctx.ast.assignment_target_assignment_target_pattern(
+                    ctx.ast
+                        .assignment_target_pattern_object_assignment_target(SPAN, properties, None),
+                );
+                let assignment =
+                    ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, call);
+                ctx.ast.expression_parenthesized(SPAN, assignment)
+            }
+        };
+
+        // `static { [...] = babelHelpers.applyDecs2305(...); _initStatic(this); }`
+        let mut stmts = ctx.ast.vec1(ctx.ast.statement_expression(SPAN, apply));
+        if let Some(init_static) = &init_static {
+            stmts.push(ctx.ast.statement_expression(SPAN, create_init_call(init_static, ctx)));
+        }
+        class.body.body.insert(0, create_static_block(class_scope_id, stmts, ctx));
+
+        if let Some(init_proto) = init_proto {
+            Self::insert_init_proto(class, &init_proto, ctx);
+        }
+
+        let Some((class_binding, init_class)) = class_result else { return (memos, None) };
+
+        // `static { _initClass(); }`
+        let call = ctx.ast.expression_call(
+            SPAN,
+            init_class.create_read_expression(ctx),
+            NONE,
+            ctx.ast.vec(),
+            false,
+        );
+        let stmts = ctx.ast.vec1(ctx.ast.statement_expression(SPAN, call));
+        class.body.body.push(create_static_block(class_scope_id, stmts, ctx));
+
+        // Replace existing references to class with references to decorated class
+        if let Some(id) = &class.id {
+            let symbol_id = id.symbol_id.get().unwrap();
+            for &reference_id in ctx.symbols().get_resolved_reference_ids(symbol_id) {
+                self.class_references.insert(reference_id, class_binding.clone());
+            }
+        }
+
+        (memos, Some(class_binding))
+    }
+
+    /// Convert decorators to value passed to `applyDecs2305`.
+    ///
+    /// * Single decorator: `dec`.
+    /// * Multiple decorators: `[dec1, dec2]`.
+    /// * Any member expression decorator: `[obj, obj.dec, void 0, dec2]` (`this` value, decorator pairs).
+    fn create_decorators(
+        &self,
+        decorators: ArenaVec<'a, Decorator<'a>>,
+        memos: &mut Vec<Memo<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Decorators<'a> {
+        let mut pairs = vec![];
+        for decorator in decorators {
+            pairs.push(self.memoize_decorator(decorator.expression, memos, ctx));
+        }
+
+        let have_this = pairs.iter().any(|(this, _)| this.is_some());
+        if !have_this && pairs.len() == 1 {
+            let (_, value) = pairs.pop().unwrap();
+            return Decorators { value, have_this };
+        }
+
+        let mut elements = ctx.ast.vec();
+        for (this, decorator) in pairs {
+            if have_this {
+                let this = this.unwrap_or_else(|| ctx.ast.void_0(SPAN));
+                elements.push(ArrayExpressionElement::from(this));
+            }
+            elements.push(ArrayExpressionElement::from(decorator));
+        }
+        Decorators { value: ctx.ast.expression_array(SPAN, elements, None), have_this }
+    }
+
+    /// Store decorator expression in a temp var if evaluating it later would have a different
+    /// result. Returns decorator's `this` value (if any), and decorator.
+    ///
+    /// * `dec` -> `dec`
+    /// * `obj.dec` -> `obj`, `obj.dec`
+    /// * `foo().dec` -> `_obj = foo()` + `_obj`, `_obj.dec`
+    /// * `dec()` -> `_dec = dec()` + `_dec`
+    fn memoize_decorator(
+        &self,
+        expr: Expression<'a>,
+        memos: &mut Vec<Memo<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Option<Expression<'a>>, Expression<'a>) {
+        let scope_id = ctx.current_scope_id();
+        match expr {
+            Expression::Identifier(_) => (None, expr),
+            Expression::StaticMemberExpression(mut member)
+                if !matches!(member.object, Expression::Super(_)) =>
+            {
+                let this = if let Expression::Identifier(ident) = &member.object {
+                    clone_identifier_reference(ident, ctx)
+                } else {
+                    let mut object = ctx.ast.move_expression(&mut member.object);
+                    reparent_scopes(&mut object, scope_id, ctx);
+                    let binding = self.create_temp_var_based_on_node(&object, ctx);
+                    memos.push((binding.clone(), object));
+                    member.object = binding.create_read_expression(ctx);
+                    binding.create_read_expression(ctx)
+                };
+                (Some(this), Expression::StaticMemberExpression(member))
+            }
+            mut expr => {
+                reparent_scopes(&mut expr, scope_id, ctx);
+                let binding = self.create_temp_var("dec", ctx);
+                memos.push((binding.clone(), expr));
+                (None, binding.create_read_expression(ctx))
+            }
+        }
+    }
+
+    /// Create name of class element passed to `applyDecs2305`.
+    ///
+    /// Computed keys which are not literals are evaluated before the class and stored in a temp var:
+    /// `@dec [foo()]() {}` -> `_computedKey = foo()` + `[_computedKey]() {}`
+    fn create_key(
+        &self,
+        key: &mut PropertyKey<'a>,
+        memos: &mut Vec<Memo<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        match key {
+            PropertyKey::StaticIdentifier(ident) => {
+                ctx.ast.expression_string_literal(SPAN, ident.name.clone())
+            }
+            PropertyKey::StringLiteral(lit) => {
+                ctx.ast.expression_string_literal(SPAN, lit.value.clone())
+            }
+            PropertyKey::NumericLiteral(lit) => {
+                ctx.ast.expression_numeric_literal(SPAN, lit.value, lit.raw, lit.base)
+            }
+            _ => {
+                let expr = key.to_expression_mut();
+                let mut value = ctx.ast.move_expression(expr);
+                reparent_scopes(&mut value, ctx.current_scope_id(), ctx);
+                let binding = self.create_temp_var("computedKey", ctx);
+                memos.push((binding.clone(), value));
+                *expr = binding.create_read_expression(ctx);
+                binding.create_read_expression(ctx)
+            }
+        }
+    }
+
+    /// Create `_init_x` binding for a decorated field.
+    fn create_init_binding(
+        &self,
+        key: &PropertyKey<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        let name = match key {
+            PropertyKey::StaticIdentifier(ident) => format!("init_{}", ident.name),
+            PropertyKey::StringLiteral(lit) if is_identifier_name(&lit.value) => {
+                format!("init_{}", lit.value)
+            }
+            _ => "init_computedKey".to_string(),
+        };
+        self.create_temp_var(&name, ctx)
+    }
+
+    /// Insert `_initProto(this)` call, which applies method decorators' initializers to the instance.
+    ///
+    /// * Class has instance fields: `x = 1` -> `x = (_initProto(this), 1)` in first field.
+    /// * Class has constructor: `_initProto(this)` at start of constructor,
+    ///   or `super()` -> `_initProto(super())` in derived class.
+    /// * Otherwise: create `constructor() { _initProto(this); }`.
+    fn insert_init_proto(
+        class: &mut Class<'a>,
+        init_proto: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let field = class.body.body.iter_mut().find_map(|element| match element {
+            ClassElement::PropertyDefinition(prop) if !prop.r#static => Some(prop),
+            _ => None,
+        });
+        if let Some(field) = field {
+            let call = create_init_call(init_proto, ctx);
+            field.value = Some(match field.value.take() {
+                Some(value) => {
+                    ctx.ast.expression_sequence(SPAN, ctx.ast.vec_from_iter([call, value]))
+                }
+                None => ctx
+                    .ast
+                    .expression_sequence(SPAN, ctx.ast.vec_from_iter([call, ctx.ast.void_0(SPAN)])),
+            });
+            return;
+        }
+
+        let is_derived = class.super_class.is_some();
+        let constructor = class.body.body.iter_mut().find_map(|element| match element {
+            ClassElement::MethodDefinition(method) if method.kind.is_constructor() => {
+                Some(&mut method.value)
+            }
+            _ => None,
+        });
+        if let Some(constructor) = constructor {
+            let Some(body) = constructor.body.as_mut() else { return };
+            if is_derived {
+                SuperCallWrapper { init_proto, ctx }.visit_function_body(body);
+            } else {
+                let call = create_init_call(init_proto, ctx);
+                body.statements.insert(0, ctx.ast.statement_expression(SPAN, call));
+            }
+            return;
+        }
+
+        let class_scope_id = class.scope_id.get().unwrap();
+        let constructor = create_constructor(class_scope_id, is_derived, init_proto, ctx);
+        // Insert after the static block containing `applyDecs2305` call
+        class.body.body.insert(1, constructor);
+    }
+
+    fn create_temp_var(&self, name: &str, ctx: &mut TraverseCtx<'a>) -> BoundIdentifier<'a> {
+        let binding = ctx.generate_uid_in_current_scope(name, SymbolFlags::FunctionScopedVariable);
+        self.ctx.var_declarations.insert(&binding, None, ctx);
+        binding
+    }
+
+    fn create_temp_var_based_on_node(
+        &self,
+        expr: &Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        let binding = ctx
+            .generate_uid_in_current_scope_based_on_node(expr, SymbolFlags::FunctionScopedVariable);
+        self.ctx.var_declarations.insert(&binding, None, ctx);
+        binding
+    }
+}
+
+/// Create constructor which calls `_initProto(this)`.
+///
+/// * Base class: `constructor() { _initProto(this); }`
+/// * Derived class: `constructor(..._args) { _initProto(super(..._args)); }`
+fn create_constructor<'a>(
+    class_scope_id: ScopeId,
+    is_derived: bool,
+    init_proto: &BoundIdentifier<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> ClassElement<'a> {
+    let scope_id = ctx.create_child_scope(
+        class_scope_id,
+        ScopeFlags::Function | ScopeFlags::Constructor | ScopeFlags::StrictMode,
+    );
+
+    let (params, call) = if is_derived {
+        let args_binding = ctx.generate_uid("args", scope_id, SymbolFlags::FunctionScopedVariable);
+        let rest =
+            ctx.ast.alloc_binding_rest_element(SPAN, args_binding.create_binding_pattern(ctx));
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            ctx.ast.vec(),
+            Some(rest),
+        );
+        let argument =
+            ctx.ast.argument_spread_element(SPAN, args_binding.create_read_expression(ctx));
+        let super_call = ctx.ast.expression_call(
+            SPAN,
+            ctx.ast.expression_super(SPAN),
+            NONE,
+            ctx.ast.vec1(argument),
+            false,
+        );
+        let call = ctx.ast.expression_call(
+            SPAN,
+            init_proto.create_read_expression(ctx),
+            NONE,
+            ctx.ast.vec1(Argument::from(super_call)),
+            false,
+        );
+        (params, call)
+    } else {
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            ctx.ast.vec(),
+            NONE,
+        );
+        (params, create_init_call(init_proto, ctx))
+    };
+
+    let body = ctx.ast.alloc_function_body(
+        SPAN,
+        ctx.ast.vec(),
+        ctx.ast.vec1(ctx.ast.statement_expression(SPAN, call)),
+    );
+    let function = ctx.ast.alloc_function_with_scope_id(
+        FunctionType::FunctionExpression,
+        SPAN,
+        None,
+        false,
+        false,
+        false,
+        NONE,
+        NONE,
+        params,
+        NONE,
+        Some(body),
+        scope_id,
+    );
+    ctx.ast.class_element_method_definition(
+        MethodDefinitionType::MethodDefinition,
+        SPAN,
+        ctx.ast.vec(),
+        ctx.ast.property_key_identifier_name(SPAN, "constructor"),
+        function,
+        MethodDefinitionKind::Constructor,
+        false,
+        false,
+        false,
+        false,
+        None,
+    )
+}
+
+/// Create `static { ... }` block.
+fn create_static_block<'a>(
+    class_scope_id: ScopeId,
+    stmts: ArenaVec<'a, Statement<'a>>,
+    ctx: &mut TraverseCtx<'a>,
+) -> ClassElement<'a> {
+    let scope_id = ctx.create_child_scope(class_scope_id, ScopeFlags::ClassStaticBlock);
+    ClassElement::StaticBlock(ctx.ast.alloc_static_block_with_scope_id(SPAN, stmts, scope_id))
+}
+
+/// Create `_init(this)`.
+fn create_init_call<'a>(
+    binding: &BoundIdentifier<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    ctx.ast.expression_call(
+        SPAN,
+        binding.create_read_expression(ctx),
+        NONE,
+        ctx.ast.vec1(Argument::from(ctx.ast.expression_this(SPAN))),
+        false,
+    )
+}
+
+/// Create `[_a, _b]` assignment target.
+fn create_array_target<'a>(
+    bindings: &[BoundIdentifier<'a>],
+    ctx: &mut TraverseCtx<'a>,
+) -> AssignmentTarget<'a> {
+    let elements =
+        ctx.ast.vec_from_iter(bindings.iter().map(|binding| {
+            Some(ctx.ast.assignment_target_maybe_default_assignment_target(
+                binding.create_write_target(ctx),
+            ))
+        }));
+    ctx.ast.assignment_target_assignment_target_pattern(
+        ctx.ast.assignment_target_pattern_array_assignment_target(SPAN, elements, None, None),
+    )
+}
+
+/// Create `key: [_a, _b]` assignment target property.
+fn create_property_target<'a>(
+    key: &'static str,
+    bindings: &[BoundIdentifier<'a>],
+    ctx: &mut TraverseCtx<'a>,
+) -> AssignmentTargetProperty<'a> {
+    let target = create_array_target(bindings, ctx);
+    ctx.ast.assignment_target_property_assignment_target_property_property(
+        SPAN,
+        ctx.ast.property_key_identifier_name(SPAN, key),
+        ctx.ast.assignment_target_maybe_default_assignment_target(target),
+    )
+}
+
+fn create_number<'a>(value: u32, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+    ctx.ast.expression_numeric_literal(
+        SPAN,
+        f64::from(value),
+        value.to_string(),
+        NumberBase::Decimal,
+    )
+}
+
+fn clone_identifier_reference<'a>(
+    ident: &IdentifierReference<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let symbol_id = ident
+        .reference_id
+        .get()
+        .and_then(|reference_id| ctx.symbols().get_reference(reference_id).symbol_id());
+    let ident = ctx.create_reference_id(SPAN, ident.name.clone(), symbol_id, ReferenceFlags::Read);
+    ctx.ast.expression_from_identifier_reference(ident)
+}
+
+/// Returns `true` if class or any of its elements have decorators.
+fn has_decorators(class: &Class) -> bool {
+    !class.decorators.is_empty()
+        || class.body.body.iter().any(|element| match element {
+            ClassElement::MethodDefinition(method) => !method.decorators.is_empty(),
+            ClassElement::PropertyDefinition(prop) => !prop.decorators.is_empty(),
+            ClassElement::AccessorProperty(prop) => !prop.decorators.is_empty(),
+            ClassElement::StaticBlock(_) | ClassElement::TSIndexSignature(_) => false,
+        })
+}
+
+/// Visitor to replace `super(...)` with `_initProto(super(...))`.
+/// Does not enter non-arrow functions or classes, as `super()` is not legal in them.
+struct SuperCallWrapper<'a, 'b> {
+    init_proto: &'b BoundIdentifier<'a>,
+    ctx: &'b mut TraverseCtx<'a>,
+}
+
+impl<'a, 'b> VisitMut<'a> for SuperCallWrapper<'a, 'b> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        walk_mut::walk_expression(self, expr);
+        if matches!(expr, Expression::CallExpression(call) if matches!(call.callee, Expression::Super(_)))
+        {
+            let super_call = self.ctx.ast.move_expression(expr);
+            *expr = self.ctx.ast.expression_call(
+                SPAN,
+                self.init_proto.create_read_expression(self.ctx),
+                NONE,
+                self.ctx.ast.vec1(Argument::from(super_call)),
+                false,
+            );
+        }
+    }
+
+    fn visit_function(&mut self, _func: &mut Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_class(&mut self, _class: &mut Class<'a>) {}
+}
+
+/// Visitor to replace references to decorated classes with the bindings holding decorated classes.
+struct ClassReferenceReplacer<'a, 'b> {
+    references: FxHashMap<ReferenceId, BoundIdentifier<'a>>,
+    ctx: &'b mut TraverseCtx<'a>,
+}
+
+impl<'a, 'b> VisitMut<'a> for ClassReferenceReplacer<'a, 'b> {
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        let Some(reference_id) = ident.reference_id.get() else { return };
+        let Some(binding) = self.references.get(&reference_id) else { return };
+        let reference = self.ctx.symbols().get_reference(reference_id);
+        let flags = reference.flags();
+        if let Some(symbol_id) = reference.symbol_id() {
+            self.ctx.symbols_mut().delete_resolved_reference(symbol_id, reference_id);
+        }
+        *ident = binding.create_spanned_reference(ident.span, flags, self.ctx);
+    }
+}
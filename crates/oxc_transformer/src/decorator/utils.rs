@@ -0,0 +1,115 @@
+//! Decorators
+//! Utility functions.
+
+use std::cell::Cell;
+
+use oxc_ast::{ast::*, visit::walk_mut, VisitMut};
+use oxc_span::SPAN;
+use oxc_syntax::{
+    scope::{ScopeFlags, ScopeId},
+    symbol::SymbolId,
+};
+use oxc_traverse::{BoundIdentifier, TraverseCtx};
+
+/// Set parent of all scopes which are direct children of `expr`'s enclosing scope
+/// (i.e. scopes of functions, classes etc. within `expr`) to `parent_scope_id`.
+///
+/// Class bodies are strict mode code, so if new parent scope is not strict mode,
+/// `StrictMode` flag is removed from scopes which are not strict mode in their own right.
+///
+/// Used when moving decorator expressions out of class body.
+pub(super) fn reparent_scopes<'a>(
+    expr: &mut Expression<'a>,
+    parent_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    let is_strict = ctx.scopes().get_flags(parent_scope_id).is_strict_mode();
+    ScopeReparenter { parent_scope_id, depth: 0, is_strict, ctx }.visit_expression(expr);
+}
+
+struct ScopeReparenter<'a, 'ctx> {
+    parent_scope_id: ScopeId,
+    depth: u32,
+    /// `true` if current scope is strict mode code
+    is_strict: bool,
+    ctx: &'ctx mut TraverseCtx<'a>,
+}
+
+impl<'a, 'ctx> VisitMut<'a> for ScopeReparenter<'a, 'ctx> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        let scope_id = scope_id.get().unwrap();
+        if self.depth == 0 {
+            self.ctx.scopes_mut().change_parent_id(scope_id, Some(self.parent_scope_id));
+        }
+        if !self.is_strict {
+            self.ctx.scopes_mut().get_flags_mut(scope_id).remove(ScopeFlags::StrictMode);
+        }
+        self.depth += 1;
+    }
+
+    fn leave_scope(&mut self) {
+        self.depth -= 1;
+    }
+
+    fn visit_function(&mut self, func: &mut Function<'a>, flags: ScopeFlags) {
+        let is_strict = self.is_strict;
+        self.is_strict = is_strict || func.is_strict();
+        walk_mut::walk_function(self, func, flags);
+        self.is_strict = is_strict;
+    }
+
+    fn visit_class(&mut self, class: &mut Class<'a>) {
+        let is_strict = self.is_strict;
+        self.is_strict = true;
+        walk_mut::walk_class(self, class);
+        self.is_strict = is_strict;
+    }
+}
+
+/// Visitor which replaces all references to symbol `from` with references to `to`.
+pub(super) struct ReferenceReplacer<'a, 'b> {
+    from: SymbolId,
+    to: &'b BoundIdentifier<'a>,
+    /// `true` if any reference was replaced
+    pub replaced: bool,
+    ctx: &'b mut TraverseCtx<'a>,
+}
+
+impl<'a, 'b> ReferenceReplacer<'a, 'b> {
+    pub fn new(from: SymbolId, to: &'b BoundIdentifier<'a>, ctx: &'b mut TraverseCtx<'a>) -> Self {
+        Self { from, to, replaced: false, ctx }
+    }
+}
+
+impl<'a, 'b> VisitMut<'a> for ReferenceReplacer<'a, 'b> {
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        let Some(reference_id) = ident.reference_id.get() else { return };
+        let reference = self.ctx.symbols().get_reference(reference_id);
+        if reference.symbol_id() != Some(self.from) {
+            return;
+        }
+        let flags = reference.flags();
+        self.ctx.symbols_mut().delete_resolved_reference(self.from, reference_id);
+        *ident = self.to.create_spanned_reference(ident.span, flags, self.ctx);
+        self.replaced = true;
+    }
+}
+
+/// Create `object.property` member expression.
+pub(super) fn create_member<'a>(
+    object: Expression<'a>,
+    property: &'static str,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    let property = ctx.ast.identifier_name(SPAN, property);
+    Expression::from(ctx.ast.member_expression_static(SPAN, object, property, false))
+}
+
+/// Create `target = value` assignment expression.
+pub(super) fn create_assignment<'a>(
+    target: AssignmentTarget<'a>,
+    value: Expression<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value)
+}
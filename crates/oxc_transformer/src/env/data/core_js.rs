@@ -0,0 +1,88 @@
+use std::sync::OnceLock;
+
+use cow_utils::CowUtils;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::env::{targets::version::Version, Versions};
+
+/// Minimum version of each target which supports the feature polyfilled by a core-js module.
+///
+/// Reference: <https://github.com/zloirock/core-js/tree/master/packages/core-js-compat>
+fn compat_data() -> &'static FxHashMap<String, Versions> {
+    static DATA: OnceLock<FxHashMap<String, Versions>> = OnceLock::new();
+    DATA.get_or_init(|| {
+        serde_json::from_str(include_str!("./core_js_compat/data.json"))
+            .expect("failed to parse json")
+    })
+}
+
+/// core-js modules loaded by each core-js entry point e.g. `core-js/stable`.
+fn entries() -> &'static FxHashMap<String, Vec<String>> {
+    static ENTRIES: OnceLock<FxHashMap<String, Vec<String>>> = OnceLock::new();
+    ENTRIES.get_or_init(|| {
+        serde_json::from_str(include_str!("./core_js_compat/entries.json"))
+            .expect("failed to parse json")
+    })
+}
+
+/// core-js modules added in each version of core-js.
+fn modules_by_versions() -> &'static FxHashMap<String, Vec<String>> {
+    static MODULES: OnceLock<FxHashMap<String, Vec<String>>> = OnceLock::new();
+    MODULES.get_or_init(|| {
+        serde_json::from_str(include_str!("./core_js_compat/modules_by_versions.json"))
+            .expect("failed to parse json")
+    })
+}
+
+/// Get core-js modules loaded by a core-js entry point, in the order they're loaded.
+///
+/// `source` is normalized the same as Babel does, so `core-js/stable/index.js` is the same
+/// entry point as `core-js/stable`.
+///
+/// Returns `None` if `source` is not a core-js entry point.
+pub fn core_js_entry_modules(source: &str) -> Option<&'static [String]> {
+    let source = source.cow_replace('\\', "/");
+    let mut source = source.cow_to_lowercase().into_owned();
+    if let Some(stripped) = source.strip_suffix(".js") {
+        source.truncate(stripped.len());
+    }
+    if let Some(stripped) = source.strip_suffix("/index") {
+        source.truncate(stripped.len());
+    } else if let Some(stripped) = source.strip_suffix('/') {
+        source.truncate(stripped.len());
+    }
+    entries().get(&source).map(Vec::as_slice)
+}
+
+/// Get all core-js modules which exist in `version` of core-js.
+pub fn core_js_available_modules(version: Version) -> FxHashSet<&'static str> {
+    modules_by_versions()
+        .iter()
+        .filter(|(added_in, _)| {
+            added_in.parse::<Version>().is_ok_and(|added_in| added_in <= version)
+        })
+        .flat_map(|(_, modules)| modules.iter().map(String::as_str))
+        .collect()
+}
+
+/// Returns `true` if core-js module `name` is required for any of `targets`.
+///
+/// As in [`Versions::should_enable`], a target which is missing from the compat data
+/// requires the polyfill, as it means the target does not support the feature at all.
+/// If `targets` is empty, all polyfills are required.
+pub fn is_core_js_module_required(name: &str, targets: &Versions) -> bool {
+    let Some(feature) = compat_data().get(name) else { return false };
+    if targets.is_any_target() {
+        return true;
+    }
+    targets.iter().any(|(target_name, target_version)| {
+        feature
+            .get(target_name)
+            .or_else(|| match target_name.as_str() {
+                // Fall back to Chrome versions if Android browser data is missing
+                "android" => feature.get("chrome"),
+                _ => None,
+            })
+            .map_or(true, |feature_version| feature_version > target_version)
+    })
+}
@@ -1,3 +1,5 @@
 mod babel;
+mod core_js;
 
 pub use babel::can_enable_plugin;
+pub use core_js::{core_js_available_modules, core_js_entry_modules, is_core_js_module_required};
@@ -2,6 +2,8 @@ mod data;
 mod options;
 mod targets;
 
-pub use data::can_enable_plugin;
+pub use data::{
+    can_enable_plugin, core_js_available_modules, core_js_entry_modules, is_core_js_module_required,
+};
 pub use options::EnvOptions;
-pub use targets::{Targets, Versions};
+pub use targets::{version::Version, Targets, Versions};
@@ -1,3 +1,5 @@
+use std::path::Path;
+
 use serde::Deserialize;
 use serde_json::Value;
 
@@ -12,8 +14,10 @@ fn default_as_true() -> bool {
 #[derive(Default, Debug, Clone, Deserialize)]
 #[serde(default, rename_all = "camelCase", deny_unknown_fields)]
 pub struct EnvOptions {
-    #[serde(default)]
-    pub targets: Targets,
+    /// Browserslist query, or minimum versions of target environments.
+    ///
+    /// If not set, targets are loaded from browserslist config.
+    pub targets: Option<Targets>,
 
     #[serde(default = "default_as_true")]
     pub bugfixes: bool,
@@ -24,7 +28,8 @@ pub struct EnvOptions {
     /// Unused.
     pub loose: bool,
 
-    /// Unused.
+    /// Module format to transform ES modules to.
+    /// `"commonjs"`, `"cjs"`, `"amd"` or `"umd"`. `false` or `"auto"` to preserve ES modules.
     pub modules: Option<Value>,
 
     /// Unused.
@@ -36,29 +41,58 @@ pub struct EnvOptions {
     /// Unused.
     pub exclude: Option<Value>,
 
-    /// Unused.
+    /// How to add core-js polyfills. `"entry"`, `"usage"` or `false`.
     pub use_built_ins: Option<Value>,
 
-    /// Unused.
+    /// core-js version, e.g. `"3.33"`, or `{ "version": "3.33", "proposals": false }`.
+    /// Only core-js 3 is supported.
     pub corejs: Option<Value>,
 
     /// Unused.
     pub force_all_transforms: bool,
 
-    /// Unused.
+    /// Path to start searching for browserslist config from, searching up to the root directory.
+    ///
+    /// Relative to `cwd`. Defaults to `cwd`.
     pub config_path: Option<String>,
 
-    /// Unused.
+    /// Don't load targets from browserslist config, when `targets` is not set.
     pub ignore_browserslist_config: bool,
 
+    /// Environment section of browserslist config to use, e.g. `"development"`.
+    ///
+    /// Defaults to `BROWSERSLIST_ENV` or `NODE_ENV` environment variable, or `"production"`.
+    pub browserslist_env: Option<String>,
+
     /// Unused.
     pub shipped_proposals: bool,
 }
 
 impl EnvOptions {
+    /// Resolve targets.
+    ///
+    /// If `targets` option is not set, targets are loaded from browserslist config
+    /// (`.browserslistrc`, `browserslist` or `browserslist` field of `package.json`),
+    /// found by searching from `config_path` (relative to `cwd`) up to the root directory.
+    /// If no config is found, or `ignore_browserslist_config` is set, `defaults` query is used.
+    ///
     /// # Errors
     ///
-    pub fn get_targets(&self) -> Result<Versions, Error> {
-        self.targets.clone().get_targets()
+    /// * The query is not supported or invalid.
+    /// * Browserslist config cannot be read or is invalid.
+    pub fn get_targets(&self, cwd: &Path) -> Result<Versions, Error> {
+        if let Some(targets) = &self.targets {
+            return targets.clone().get_targets();
+        }
+        if self.ignore_browserslist_config {
+            return Targets::default().get_targets();
+        }
+        // Config search needs an absolute path to walk up directories from
+        let cwd = std::env::current_dir().unwrap_or_default().join(cwd);
+        let path = match &self.config_path {
+            Some(config_path) => cwd.join(config_path),
+            None => cwd,
+        };
+        Targets::from_browserslist_config(&path, self.browserslist_env.as_deref())
     }
 }
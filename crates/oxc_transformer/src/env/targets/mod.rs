@@ -81,6 +81,10 @@ impl Versions {
         data
     }
 
+    /// Returns `true` if any of the targets does not support `feature`.
+    ///
+    /// A target which is missing from the feature data does not support the feature at all
+    /// (e.g. `ie` for ES2015 features), so the feature is enabled for it.
     pub fn should_enable(&self, feature: &Versions) -> bool {
         self.iter().any(|(target_name, target_version)| {
             feature
@@ -92,7 +96,7 @@ impl Versions {
                     "android" => feature.get("chrome"),
                     _ => None,
                 })
-                .map_or(false, |feature_version| feature_version > target_version)
+                .map_or(true, |feature_version| feature_version > target_version)
         })
     }
 }
@@ -109,4 +113,13 @@ mod tests {
         feature.insert("chrome".to_string(), "51.0.0".parse::<Version>().unwrap());
         assert!(!targets.should_enable(&feature));
     }
+
+    #[test]
+    fn should_enable_target_missing_from_feature() {
+        let mut targets = Versions::default();
+        targets.insert("ie".to_string(), "11.0.0".parse::<Version>().unwrap());
+        let mut feature = Versions::default();
+        feature.insert("chrome".to_string(), "49.0.0".parse::<Version>().unwrap());
+        assert!(targets.should_enable(&feature));
+    }
 }
@@ -2,7 +2,7 @@
 //!
 //! This file is copied from <https://github.com/swc-project/swc/blob/ea14fc8e5996dcd736b8deb4cc99262d07dfff44/crates/preset_env_base/src/query.rs>
 
-use std::sync::OnceLock;
+use std::{path::Path, sync::OnceLock};
 
 use dashmap::DashMap;
 use rustc_hash::FxHashMap;
@@ -36,6 +36,32 @@ impl Targets {
         Targets::Query(Query::Single(query.into()))
     }
 
+    /// Load targets from browserslist config (`.browserslistrc`, `browserslist` or
+    /// `browserslist` field of `package.json`), searching from `path` up to the root directory.
+    ///
+    /// `env` selects the environment section of the config, e.g. `"development"`.
+    /// If no config is found, `defaults` query is used.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// * The config cannot be read, or is invalid.
+    /// * A query in the config is not supported, or is invalid.
+    pub fn from_browserslist_config(path: &Path, env: Option<&str>) -> Result<Versions, Error> {
+        let opts = browserslist::Opts {
+            path: Some(path.to_string_lossy().into_owned()),
+            env: env.map(ToString::to_string),
+            ..Query::opts()
+        };
+        match browserslist::execute(&opts) {
+            Ok(distribs) => Ok(Versions::parse_versions(distribs)),
+            Err(err) => {
+                let msg = format!("failed to load browserslist config: {err}");
+                Err(OxcDiagnostic::error(msg).into())
+            }
+        }
+    }
+
     /// Parse the query and return the parsed Versions.
     ///
     /// # Errors
@@ -129,6 +155,14 @@ fn cache() -> &'static DashMap<Query, Versions> {
 }
 
 impl Query {
+    fn opts() -> browserslist::Opts {
+        browserslist::Opts {
+            mobile_to_desktop: true,
+            ignore_unknown_versions: true,
+            ..browserslist::Opts::default()
+        }
+    }
+
     fn get_value(&self) -> String {
         match self {
             Query::Single(s) => s.clone(),
@@ -141,14 +175,7 @@ impl Query {
         where
             T: AsRef<str>,
         {
-            match browserslist::resolve(
-                s,
-                &browserslist::Opts {
-                    mobile_to_desktop: true,
-                    ignore_unknown_versions: true,
-                    ..browserslist::Opts::default()
-                },
-            ) {
+            match browserslist::resolve(s, &Query::opts()) {
                 Ok(distribs) => {
                     let versions = Versions::parse_versions(distribs);
 
@@ -184,11 +211,86 @@ impl Query {
 
 #[cfg(test)]
 mod tests {
-    use super::Query;
+    use std::{fs, path::PathBuf};
+
+    use super::{Query, Targets, Version, Versions};
 
     #[test]
     fn test_empty() {
         let res = Query::Single(String::new()).exec().unwrap();
         assert!(!res.is_any_target(), "empty query should return non-empty result");
     }
+
+    /// Create a temp directory containing `files`.
+    fn create_dir(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let dir = std::env::temp_dir()
+            .join(format!("oxc_transformer_browserslist_{name}_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        for (path, content) in files {
+            let path = dir.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+        dir
+    }
+
+    fn versions(targets: &[(&str, &str)]) -> Versions {
+        let mut versions = Versions::default();
+        for (name, version) in targets {
+            versions.insert((*name).to_string(), version.parse::<Version>().unwrap());
+        }
+        versions
+    }
+
+    #[test]
+    fn browserslist_config_browserslistrc() {
+        let dir = create_dir("rc", &[(".browserslistrc", "chrome 80\nfirefox 90\n")]);
+        let targets = Targets::from_browserslist_config(&dir, None).unwrap();
+        assert_eq!(targets.0, versions(&[("chrome", "80"), ("firefox", "90")]).0);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn browserslist_config_package_json() {
+        let dir = create_dir("pkg", &[("package.json", r#"{ "browserslist": ["safari 14"] }"#)]);
+        let targets = Targets::from_browserslist_config(&dir, None).unwrap();
+        assert_eq!(targets.0, versions(&[("safari", "14")]).0);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn browserslist_config_searches_parent_directories() {
+        let dir = create_dir("parent", &[(".browserslistrc", "chrome 80"), ("src/a/.keep", "")]);
+        let targets = Targets::from_browserslist_config(&dir.join("src/a"), None).unwrap();
+        assert_eq!(targets.0, versions(&[("chrome", "80")]).0);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn browserslist_config_env() {
+        let config = "[production]\nchrome 80\n\n[development]\nfirefox 100\n";
+        let dir = create_dir("env", &[(".browserslistrc", config)]);
+        let targets = Targets::from_browserslist_config(&dir, Some("development")).unwrap();
+        assert_eq!(targets.0, versions(&[("firefox", "100")]).0);
+        let targets = Targets::from_browserslist_config(&dir, Some("production")).unwrap();
+        assert_eq!(targets.0, versions(&[("chrome", "80")]).0);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn browserslist_config_errors() {
+        // Duplicate config
+        let dir = create_dir(
+            "duplicate",
+            &[(".browserslistrc", "chrome 80"), ("package.json", r#"{ "browserslist": "ie 11" }"#)],
+        );
+        let err = Targets::from_browserslist_config(&dir, None).unwrap_err();
+        assert!(err.to_string().starts_with("failed to load browserslist config"), "{err}");
+        fs::remove_dir_all(dir).unwrap();
+
+        // Invalid query
+        let dir = create_dir("invalid", &[(".browserslistrc", "not a browser 1")]);
+        assert!(Targets::from_browserslist_config(&dir, None).is_err());
+        fs::remove_dir_all(dir).unwrap();
+    }
 }
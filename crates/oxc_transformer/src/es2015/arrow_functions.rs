@@ -194,6 +194,15 @@ impl<'a> Traverse<'a> for ArrowFunctions<'a> {
         if let Some(this_var) = self.this_var_stack.pop() {
             let Some(body) = &mut func.body else { unreachable!() };
 
+            // Body may have been moved into another function by async-to-generator plugin,
+            // leaving this function with a new scope
+            let scope_id = func.scope_id.get().unwrap();
+            if ctx.symbols().get_scope_id(this_var.symbol_id) != scope_id {
+                let old_scope_id = ctx.symbols().get_scope_id(this_var.symbol_id);
+                ctx.scopes_mut().move_binding(old_scope_id, scope_id, &this_var.name);
+                ctx.symbols_mut().set_scope_id(this_var.symbol_id, scope_id);
+            }
+
             self.insert_this_var_statement_at_the_top_of_statements(
                 &mut body.statements,
                 &this_var,
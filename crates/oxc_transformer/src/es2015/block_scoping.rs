@@ -0,0 +1,1487 @@
+//! ES2015: Block Scoping
+//!
+//! This plugin transforms `let` and `const` declarations to `var`.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! let a = 1;
+//! {
+//!   let a = 2;
+//!   const b = a;
+//!   b = 3;
+//! }
+//! for (let i = 0; i < 3; i++) {
+//!   fns.push(() => i);
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var a = 1;
+//! {
+//!   var _a = 2;
+//!   var b = _a;
+//!   babelHelpers.readOnlyError("b"), 3;
+//! }
+//! var _loop = function (i) {
+//!   fns.push(() => i);
+//! };
+//! for (var i = 0; i < 3; i++) {
+//!   _loop(i);
+//! }
+//! ```
+//!
+//! Bindings are hoisted to the enclosing function scope. A binding is renamed if its name clashes
+//! with another binding visible from where it's declared, or with a global.
+//!
+//! Function declarations in blocks which are block-scoped (in strict mode, or async / generator
+//! functions) are converted to `var` declarations at the start of the block,
+//! and hoisted in the same way.
+//!
+//! `let` and `const` declarations inserted by other transforms are also converted to `var`.
+//!
+//! `let` declarations without an initializer inside loops are initialized with `void 0`,
+//! so each iteration starts with a fresh value.
+//!
+//! If a closure inside a loop body captures a binding declared in the loop, the loop body is moved
+//! into a function which is called on each iteration, so each iteration gets its own copy of
+//! the bindings. `break`, `continue` and `return` which jump out of the loop body are converted
+//! to a value returned from the function, which is checked after the call.
+//!
+//! ## Options
+//!
+//! ### `throwIfClosureRequired`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! When `true`, report an error if a loop body would need to be wrapped in a function,
+//! instead of wrapping it.
+//!
+//! ### `tdz`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Not supported yet.
+//!
+//! ## Missing features
+//!
+//! Implementation is incomplete at present. Still TODO:
+//!
+//! * `tdz` option.
+//! * Function declarations in `switch` cases and in blocks in sloppy mode are not transformed.
+//! * Assignments to `const` bindings in destructuring patterns and `for-in` / `for-of` heads
+//!   are not converted to `readOnlyError` calls.
+//! * Closures in loop heads (`for (let i = 0; fns.push(() => i), i < 3; i++) {}`) do not
+//!   capture a per-iteration binding.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-block-scoping](https://babel.dev/docs/babel-plugin-transform-block-scoping).
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-block-scoping>
+//! * Let and const declarations in spec: <https://tc39.es/ecma262/#sec-let-and-const-declarations>
+
+use std::cell::Cell;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Deserialize;
+
+use oxc_allocator::{Address, GetAddress, Vec as ArenaVec};
+use oxc_ast::{ast::*, visit::walk_mut, Visit, VisitMut, NONE};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::{Atom, GetSpan, Span, SPAN};
+use oxc_syntax::{
+    number::NumberBase,
+    operator::{AssignmentOperator, BinaryOperator, UnaryOperator},
+    reference::ReferenceFlags,
+    scope::{ScopeFlags, ScopeId},
+    symbol::{SymbolFlags, SymbolId},
+};
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse, TraverseCtx};
+
+use crate::{
+    common::helper_loader::Helper,
+    es2015::{binding_pattern_to_assignment_target, collect_bindings},
+    TransformCtx,
+};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct BlockScopingOptions {
+    pub throw_if_closure_required: bool,
+    pub tdz: bool,
+}
+
+pub struct BlockScoping<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    options: BlockScopingOptions,
+    /// `true` if classes transform is enabled. Class declarations are converted to `let`
+    /// declarations, so their bindings are hoisted too.
+    hoist_classes: bool,
+    /// New names of bindings which have been renamed
+    renamed: FxHashMap<SymbolId, Atom<'a>>,
+    /// `const` bindings which have been converted to `var`
+    const_symbols: FxHashSet<SymbolId>,
+    /// Loops whose bodies will be wrapped in a function, outermost first
+    loops: Vec<LoopClosure>,
+}
+
+/// Loop whose body will be wrapped in a function on exit.
+struct LoopClosure {
+    /// Address of the loop statement (or outermost label of it)
+    address: Address,
+    /// `true` if loop statement is in a statement list
+    in_statement_list: bool,
+    /// Scope of loop body block. It becomes the scope of the function.
+    body_scope_id: ScopeId,
+    /// Bindings declared in loop head which are referenced in loop body,
+    /// and whether they're written to in loop body
+    captured: Vec<(SymbolId, bool)>,
+}
+
+impl<'a, 'ctx> BlockScoping<'a, 'ctx> {
+    pub fn new(
+        options: BlockScopingOptions,
+        hoist_classes: bool,
+        ctx: &'ctx TransformCtx<'a>,
+    ) -> Self {
+        Self {
+            ctx,
+            options,
+            hoist_classes,
+            renamed: FxHashMap::default(),
+            const_symbols: FxHashSet::default(),
+            loops: vec![],
+        }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for BlockScoping<'a, 'ctx> {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.hoist_bindings(program.scope_id.get().unwrap(), ctx);
+    }
+
+    fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.hoist_bindings(func.scope_id.get().unwrap(), ctx);
+    }
+
+    fn enter_arrow_function_expression(
+        &mut self,
+        arrow: &mut ArrowFunctionExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.hoist_bindings(arrow.scope_id.get().unwrap(), ctx);
+    }
+
+    fn enter_static_block(&mut self, block: &mut StaticBlock<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.hoist_bindings(block.scope_id.get().unwrap(), ctx);
+    }
+
+    fn enter_block_statement(&mut self, block: &mut BlockStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        Self::transform_block_functions(block, ctx);
+        self.hoist_bindings(block.scope_id.get().unwrap(), ctx);
+    }
+
+    fn enter_switch_statement(
+        &mut self,
+        stmt: &mut SwitchStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.hoist_bindings(stmt.scope_id.get().unwrap(), ctx);
+    }
+
+    fn enter_for_statement(&mut self, stmt: &mut ForStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.hoist_bindings(stmt.scope_id.get().unwrap(), ctx);
+    }
+
+    fn enter_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.hoist_bindings(stmt.scope_id.get().unwrap(), ctx);
+    }
+
+    fn enter_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.hoist_bindings(stmt.scope_id.get().unwrap(), ctx);
+    }
+
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        // Labelled loops are handled at the outermost label
+        if !matches!(ctx.parent(), Ancestor::LabeledStatementBody(_)) {
+            self.enter_loop(stmt, ctx);
+        }
+    }
+
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.loops.last().is_some_and(|closure| closure.address == stmt.address()) {
+            let closure = self.loops.pop().unwrap();
+            self.wrap_loop_body(stmt, closure, ctx);
+        }
+    }
+
+    fn enter_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !matches!(decl.kind, VariableDeclarationKind::Let | VariableDeclarationKind::Const) {
+            return;
+        }
+
+        // `let x;` in a loop -> `var x = void 0;`, so value is not carried over between iterations
+        let init_undefined = decl.kind == VariableDeclarationKind::Let
+            && !matches!(
+                ctx.parent(),
+                Ancestor::ForInStatementLeft(_) | Ancestor::ForOfStatementLeft(_)
+            )
+            && self.is_in_loop(ctx);
+
+        decl.kind = VariableDeclarationKind::Var;
+        for declarator in decl.declarations.iter_mut() {
+            declarator.kind = VariableDeclarationKind::Var;
+            if init_undefined && declarator.init.is_none() {
+                declarator.init = Some(ctx.ast.void_0(SPAN));
+            }
+        }
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !self.const_symbols.is_empty() {
+            self.transform_const_violation(expr, ctx);
+        }
+    }
+
+    fn enter_binding_identifier(
+        &mut self,
+        ident: &mut BindingIdentifier<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.renamed.is_empty() {
+            return;
+        }
+        if let Some(name) = ident.symbol_id.get().and_then(|id| self.renamed.get(&id)) {
+            ident.name = name.clone();
+        }
+    }
+
+    fn enter_identifier_reference(
+        &mut self,
+        ident: &mut IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.renamed.is_empty() {
+            return;
+        }
+        if let Some(name) = get_symbol_id(ident, ctx).and_then(|id| self.renamed.get(&id)) {
+            ident.name = name.clone();
+        }
+    }
+}
+
+impl<'a, 'ctx> BlockScoping<'a, 'ctx> {
+    /// Move `let` / `const` bindings declared in a scope to the scope `var`s are hoisted to,
+    /// renaming them if required.
+    fn hoist_bindings(&mut self, scope_id: ScopeId, ctx: &mut TraverseCtx<'a>) {
+        let symbol_ids = lexical_bindings(scope_id, self.hoist_classes, ctx);
+        if symbol_ids.is_empty() {
+            return;
+        }
+
+        let hoist_scope_id = self.hoist_scope_id(scope_id, ctx);
+        for symbol_id in symbol_ids {
+            let flags = ctx.symbols_mut().get_flags_mut(symbol_id);
+            if flags.contains(SymbolFlags::ConstVariable) {
+                self.const_symbols.insert(symbol_id);
+            }
+            flags.remove(SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable);
+            flags.insert(SymbolFlags::FunctionScopedVariable);
+
+            if scope_id == hoist_scope_id {
+                continue;
+            }
+
+            // Rename if name is visible from this scope, so hoisted binding would shadow it,
+            // or is used as a global
+            let name = ctx.symbols().names[symbol_id].clone();
+            let is_conflict = Self::is_hoist_conflict(scope_id, &name, ctx);
+
+            ctx.scopes_mut().remove_binding(scope_id, &name);
+            let name = if is_conflict {
+                let new_name = ctx.generate_uid_name(&name);
+                ctx.symbols_mut().set_name(symbol_id, new_name.clone());
+                self.renamed.insert(symbol_id, ctx.ast.atom(&new_name));
+                new_name
+            } else {
+                name
+            };
+            ctx.scopes_mut().add_binding(hoist_scope_id, name, symbol_id);
+            ctx.symbols_mut().set_scope_id(symbol_id, hoist_scope_id);
+        }
+    }
+
+    /// Check if hoisting a binding declared in `scope_id` would shadow another binding,
+    /// or a global.
+    fn is_hoist_conflict(scope_id: ScopeId, name: &str, ctx: &TraverseCtx<'a>) -> bool {
+        let parent_scope_id = ctx.scopes().get_parent_id(scope_id).unwrap();
+        ctx.scopes().find_binding(parent_scope_id, name).is_some()
+            || ctx.scopes().root_unresolved_references().contains_key(name)
+    }
+
+    /// Convert `let` / `const` declarations which other transforms inserted into `stmts`
+    /// after their scope was entered to `var` declarations.
+    ///
+    /// References to these bindings have already been visited, so they cannot be renamed.
+    /// A binding whose name would conflict if hoisted stays in its original scope.
+    pub fn exit_statements(
+        &mut self,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        for stmt in stmts.iter_mut() {
+            let decl = match stmt {
+                Statement::VariableDeclaration(decl) => decl,
+                Statement::ExportNamedDeclaration(export) => match &mut export.declaration {
+                    Some(Declaration::VariableDeclaration(decl)) => decl,
+                    _ => continue,
+                },
+                _ => continue,
+            };
+            if !matches!(decl.kind, VariableDeclarationKind::Let | VariableDeclarationKind::Const) {
+                continue;
+            }
+
+            decl.kind = VariableDeclarationKind::Var;
+            for declarator in decl.declarations.iter_mut() {
+                declarator.kind = VariableDeclarationKind::Var;
+                for (_, symbol_id) in collect_bindings(&declarator.id) {
+                    let flags = ctx.symbols_mut().get_flags_mut(symbol_id);
+                    flags.remove(SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable);
+                    flags.insert(SymbolFlags::FunctionScopedVariable);
+
+                    let name = ctx.symbols().names[symbol_id].clone();
+                    let scope_id = ctx.symbols().get_scope_id(symbol_id);
+                    let hoist_scope_id = self.hoist_scope_id(scope_id, ctx);
+                    if scope_id == hoist_scope_id || Self::is_hoist_conflict(scope_id, &name, ctx) {
+                        continue;
+                    }
+                    ctx.scopes_mut().remove_binding(scope_id, &name);
+                    ctx.scopes_mut().add_binding(hoist_scope_id, name, symbol_id);
+                    ctx.symbols_mut().set_scope_id(symbol_id, hoist_scope_id);
+                }
+            }
+        }
+    }
+
+    /// Convert block-scoped function declarations in a block to `var` declarations
+    /// at the start of the block, so their bindings are hoisted like `let` bindings.
+    ///
+    /// `{ f(); function f() {} }` -> `{ var f = function () {}; f(); }`
+    ///
+    /// Only functions which are block-scoped (in strict mode, or async / generator functions)
+    /// are converted. Functions declared directly in `switch` cases are not transformed.
+    fn transform_block_functions(block: &mut BlockStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !block.body.iter().any(|stmt| is_block_scoped_function(stmt, ctx)) {
+            return;
+        }
+
+        let mut functions = ctx.ast.vec();
+        let mut rest = ctx.ast.vec();
+        for stmt in ctx.ast.move_vec(&mut block.body) {
+            if !is_block_scoped_function(&stmt, ctx) {
+                rest.push(stmt);
+                continue;
+            }
+            let Statement::FunctionDeclaration(mut func) = stmt else { unreachable!() };
+            let id = func.id.take().unwrap();
+            let symbol_id = id.symbol_id.get().unwrap();
+            ctx.symbols_mut().get_flags_mut(symbol_id).remove(SymbolFlags::Function);
+
+            func.r#type = FunctionType::FunctionExpression;
+            let span = func.span;
+            let id = ctx.ast.binding_pattern(
+                ctx.ast.binding_pattern_kind_from_binding_identifier(id),
+                NONE,
+                false,
+            );
+            let declarator = ctx.ast.variable_declarator(
+                span,
+                VariableDeclarationKind::Var,
+                id,
+                Some(Expression::FunctionExpression(func)),
+                false,
+            );
+            functions.push(Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+                span,
+                VariableDeclarationKind::Var,
+                ctx.ast.vec1(declarator),
+                false,
+            )));
+        }
+        functions.extend(rest);
+        block.body = functions;
+    }
+
+    /// Get scope which bindings declared in `scope_id` are hoisted to.
+    ///
+    /// This is the enclosing function scope, or body of a loop which will be wrapped in a function.
+    fn hoist_scope_id(&self, scope_id: ScopeId, ctx: &TraverseCtx<'a>) -> ScopeId {
+        ctx.scopes()
+            .ancestors(scope_id)
+            .find(|&id| ctx.scopes().get_flags(id).is_var() || self.is_closure_scope(id))
+            .unwrap()
+    }
+
+    fn is_closure_scope(&self, scope_id: ScopeId) -> bool {
+        self.loops.iter().any(|closure| closure.body_scope_id == scope_id)
+    }
+
+    /// Check if current node is inside a loop body, within the scope bindings are hoisted to.
+    fn is_in_loop(&self, ctx: &TraverseCtx<'a>) -> bool {
+        for ancestor in ctx.ancestors() {
+            match ancestor {
+                Ancestor::ForStatementBody(_)
+                | Ancestor::ForInStatementBody(_)
+                | Ancestor::ForOfStatementBody(_)
+                | Ancestor::WhileStatementBody(_)
+                | Ancestor::DoWhileStatementBody(_) => return true,
+                Ancestor::BlockStatementBody(block)
+                    if self.is_closure_scope(block.scope_id().get().unwrap()) =>
+                {
+                    return false
+                }
+                Ancestor::FunctionBodyStatements(_)
+                | Ancestor::ArrowFunctionExpressionBody(_)
+                | Ancestor::StaticBlockBody(_)
+                | Ancestor::ProgramBody(_) => return false,
+                _ => {}
+            }
+        }
+        false
+    }
+
+    /// Transform assignment to a `const` binding.
+    ///
+    /// * `x = 1` -> `babelHelpers.readOnlyError("x"), 1`
+    /// * `x += 1` -> `babelHelpers.readOnlyError("x"), x + 1`
+    /// * `x &&= 1` -> `x && (babelHelpers.readOnlyError("x"), 1)`
+    /// * `x++` -> `babelHelpers.readOnlyError("x"), +x`
+    fn transform_const_violation(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            Expression::AssignmentExpression(assign) => {
+                let AssignmentTarget::AssignmentTargetIdentifier(ident) = &assign.left else {
+                    return;
+                };
+                if !self.is_const_reference(ident, ctx) {
+                    return;
+                }
+
+                let error = self.create_read_only_error(&ident.name, ctx);
+                let operator = assign.operator;
+                let AssignmentTarget::AssignmentTargetIdentifier(ident) =
+                    ctx.ast.move_assignment_target(&mut assign.left)
+                else {
+                    unreachable!()
+                };
+                let right = ctx.ast.move_expression(&mut assign.right);
+
+                *expr = if operator == AssignmentOperator::Assign {
+                    ctx.delete_reference_for_identifier(&ident);
+                    create_sequence(error, right, ctx)
+                } else {
+                    set_read_flag(&ident, ctx);
+                    let left = Expression::Identifier(ident);
+                    if let Some(operator) = operator.to_logical_operator() {
+                        let right = create_sequence(error, right, ctx);
+                        ctx.ast.expression_logical(assign.span, left, operator, right)
+                    } else {
+                        let operator = operator.to_binary_operator().unwrap();
+                        let value = ctx.ast.expression_binary(SPAN, left, operator, right);
+                        create_sequence(error, value, ctx)
+                    }
+                };
+            }
+            Expression::UpdateExpression(update) => {
+                let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) = &update.argument
+                else {
+                    return;
+                };
+                if !self.is_const_reference(ident, ctx) {
+                    return;
+                }
+
+                set_read_flag(ident, ctx);
+                let error = self.create_read_only_error(&ident.name, ctx);
+                let Expression::UpdateExpression(update) = ctx.ast.move_expression(expr) else {
+                    unreachable!()
+                };
+                let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) =
+                    update.unbox().argument
+                else {
+                    unreachable!()
+                };
+                let value = ctx.ast.expression_unary(
+                    SPAN,
+                    UnaryOperator::UnaryPlus,
+                    Expression::Identifier(ident),
+                );
+                *expr = create_sequence(error, value, ctx);
+            }
+            _ => {}
+        }
+    }
+
+    fn is_const_reference(&self, ident: &IdentifierReference<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        get_symbol_id(ident, ctx).is_some_and(|symbol_id| self.const_symbols.contains(&symbol_id))
+    }
+
+    /// `babelHelpers.readOnlyError("x")`
+    fn create_read_only_error(&self, name: &Atom<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let argument = Argument::from(ctx.ast.expression_string_literal(SPAN, name.clone()));
+        self.ctx.helper_call_expr(Helper::ReadOnlyError, ctx.ast.vec1(argument), ctx)
+    }
+
+    /// Determine if loop body needs to be wrapped in a function.
+    ///
+    /// It does if a function in the loop body references a `let` / `const` binding declared
+    /// in the loop head or body.
+    fn enter_loop(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let in_statement_list = matches!(
+            ctx.parent(),
+            Ancestor::ProgramBody(_)
+                | Ancestor::BlockStatementBody(_)
+                | Ancestor::FunctionBodyStatements(_)
+                | Ancestor::SwitchCaseConsequent(_)
+                | Ancestor::StaticBlockBody(_)
+                | Ancestor::TSModuleBlockBody(_)
+        );
+        let address = stmt.address();
+
+        let (head_scope_id, body) = match get_loop_mut(stmt) {
+            Some(LoopMut::Head(scope_id, body)) => (Some(scope_id), body),
+            Some(LoopMut::NoHead(body)) => (None, body),
+            None => return,
+        };
+
+        let head_symbol_ids = head_scope_id
+            .map(|scope_id| lexical_bindings(scope_id, false, ctx))
+            .unwrap_or_default();
+        let mut finder = ClosureFinder::new(&head_symbol_ids, self.hoist_classes, ctx);
+        finder.visit_statement(body);
+        if !finder.is_closure_required() {
+            return;
+        }
+        let captured = head_symbol_ids
+            .iter()
+            .filter(|symbol_id| finder.referenced.contains(symbol_id))
+            .map(|&symbol_id| (symbol_id, finder.written.contains(&symbol_id)))
+            .collect();
+
+        if self.options.throw_if_closure_required {
+            let error = OxcDiagnostic::error(
+                "Compiling let/const in this block would add a closure (throwIfClosureRequired).",
+            )
+            .with_label(body.span());
+            self.ctx.error(error);
+            return;
+        }
+
+        // Loop body needs to be a block, to provide a scope for the function
+        let body_scope_id = if let Statement::BlockStatement(block) = body {
+            block.scope_id.get().unwrap()
+        } else {
+            let parent_scope_id = head_scope_id.unwrap_or_else(|| ctx.current_scope_id());
+            let scope_id = ctx.insert_scope_below_statement(body, ScopeFlags::empty());
+            ctx.scopes_mut().change_parent_id(scope_id, Some(parent_scope_id));
+            let old_body = ctx.ast.move_statement(body);
+            *body = Statement::BlockStatement(ctx.ast.alloc_block_statement_with_scope_id(
+                old_body.span(),
+                ctx.ast.vec1(old_body),
+                scope_id,
+            ));
+            scope_id
+        };
+
+        self.loops.push(LoopClosure { address, in_statement_list, body_scope_id, captured });
+    }
+
+    /// Wrap loop body in a function, and call it in loop body.
+    ///
+    /// ```js
+    /// for (let i = 0; i < 3; i++) {
+    ///   fns.push(() => i);
+    ///   if (i === 1) break;
+    /// }
+    /// ```
+    /// ->
+    /// ```js
+    /// var _loop = function (i) {
+    ///   fns.push(() => i);
+    ///   if (i === 1) return 1;
+    /// };
+    /// for (var i = 0; i < 3; i++) {
+    ///   if (_loop(i)) break;
+    /// }
+    /// ```
+    fn wrap_loop_body(
+        &self,
+        stmt: &mut Statement<'a>,
+        closure: LoopClosure,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let LoopClosure { in_statement_list, body_scope_id: closure_scope_id, captured, .. } =
+            closure;
+        let hoist_scope_id = self.hoist_scope_id(ctx.current_scope_id(), ctx);
+        let (is_async, is_generator) = get_function_kind(ctx);
+
+        // If loop is not in a statement list, it's wrapped in a block with the declaration
+        let wrapper_scope_id = if in_statement_list {
+            None
+        } else {
+            Some(ctx.insert_scope_below_statement(stmt, ScopeFlags::empty()))
+        };
+        let decl_scope_id = wrapper_scope_id.unwrap_or_else(|| ctx.current_scope_id());
+
+        let (body_parent_scope_id, body) = match get_loop_mut(stmt) {
+            Some(LoopMut::Head(scope_id, body)) => (scope_id, body),
+            Some(LoopMut::NoHead(body)) => (decl_scope_id, body),
+            None => unreachable!(),
+        };
+        let Statement::BlockStatement(block) = body else { unreachable!() };
+        let body_span = block.span;
+        let mut statements = ctx.ast.move_vec(&mut block.body);
+
+        // Loop head bindings become function params.
+        // If they're written to in the loop body, params get new names, and are copied back
+        // to the loop head bindings at end of the function.
+        let mut params = ctx.ast.vec();
+        let mut arguments = ctx.ast.vec();
+        let mut bindings = vec![];
+        let mut updates = vec![];
+        for (symbol_id, is_written) in captured {
+            let name = ctx.ast.atom(ctx.symbols().get_name(symbol_id));
+            let outer = BoundIdentifier::new(name.clone(), symbol_id);
+            let flags = SymbolFlags::FunctionScopedVariable;
+            let inner = if is_written {
+                ctx.generate_uid(&name, closure_scope_id, flags)
+            } else {
+                ctx.generate_binding(name, closure_scope_id, flags)
+            };
+            params.push(ctx.ast.plain_formal_parameter(SPAN, inner.create_binding_pattern(ctx)));
+            arguments.push(Argument::from(outer.create_read_expression(ctx)));
+            if is_written {
+                updates.push((outer, inner.clone()));
+            }
+            bindings.push((symbol_id, inner));
+        }
+
+        let mut loop_body =
+            LoopBody::new(&bindings, &updates, closure_scope_id, hoist_scope_id, ctx);
+        loop_body.visit_statements(&mut statements);
+        loop_body.counting = false;
+        loop_body.visit_statements(&mut statements);
+        let LoopBody {
+            jump_count,
+            jumps,
+            has_return,
+            has_await,
+            has_yield,
+            this_binding,
+            arguments_binding,
+            vars,
+            ..
+        } = loop_body;
+        // Function is only async / a generator if the loop body needs it to be.
+        // In an async function, `yield` is `await` converted by async-to-generator plugin,
+        // which converts the enclosing function to a generator too.
+        let is_generator = has_yield && (is_generator || is_async);
+        let is_async = is_async && has_await;
+
+        if !updates.is_empty() {
+            let update = create_updates(&updates, ctx);
+            statements.push(ctx.ast.statement_expression(SPAN, update));
+        }
+
+        // `var _loop = function (i) { ... };`
+        *ctx.scopes_mut().get_flags_mut(closure_scope_id) =
+            ctx.scopes().get_new_scope_flags(ScopeFlags::Function, decl_scope_id);
+        ctx.scopes_mut().change_parent_id(closure_scope_id, Some(decl_scope_id));
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            params,
+            NONE,
+        );
+        let function_body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), statements);
+        let func = ctx.ast.alloc_function_with_scope_id(
+            FunctionType::FunctionExpression,
+            SPAN,
+            None,
+            is_generator,
+            is_async,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(function_body),
+            closure_scope_id,
+        );
+
+        let loop_binding =
+            ctx.generate_uid("loop", hoist_scope_id, SymbolFlags::FunctionScopedVariable);
+        let mut declarators = ctx.ast.vec();
+        if let Some(this_binding) = this_binding {
+            declarators.push(create_declarator(
+                &this_binding,
+                Some(ctx.ast.expression_this(SPAN)),
+                ctx,
+            ));
+        }
+        if let Some(arguments_binding) = arguments_binding {
+            let arguments = create_arguments_reference(ctx);
+            declarators.push(create_declarator(&arguments_binding, Some(arguments), ctx));
+        }
+        declarators.push(create_declarator(
+            &loop_binding,
+            Some(Expression::FunctionExpression(func)),
+            ctx,
+        ));
+        for (var, span) in vars {
+            let ident = ctx.ast.binding_identifier_with_symbol_id(span, var.name, var.symbol_id);
+            let pattern = ctx.ast.binding_pattern(
+                ctx.ast.binding_pattern_kind_from_binding_identifier(ident),
+                NONE,
+                false,
+            );
+            declarators.push(ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Var,
+                pattern,
+                None,
+                false,
+            ));
+        }
+
+        // `_loop(i)`
+        let mut call = ctx.ast.expression_call(
+            SPAN,
+            loop_binding.create_read_expression(ctx),
+            NONE,
+            arguments,
+            false,
+        );
+        if is_generator {
+            call = ctx.ast.expression_yield(SPAN, true, Some(call));
+        } else if is_async {
+            call = ctx.ast.expression_await(SPAN, call);
+        }
+
+        let mut new_body = ctx.ast.vec();
+        if jumps.is_empty() && !has_return {
+            // `_loop(i);`
+            new_body.push(ctx.ast.statement_expression(SPAN, call));
+        } else if jump_count == 1 && !has_return {
+            // `if (_loop(i)) break;`
+            let jump = jumps[0].create_statement(ctx);
+            new_body.push(ctx.ast.statement_if(SPAN, call, jump, None));
+        } else {
+            // `_ret = _loop(i);`
+            let ret_binding =
+                ctx.generate_uid("ret", hoist_scope_id, SymbolFlags::FunctionScopedVariable);
+            declarators.push(create_declarator(&ret_binding, None, ctx));
+            let assign = ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                ret_binding.create_write_target(ctx),
+                call,
+            );
+            new_body.push(ctx.ast.statement_expression(SPAN, assign));
+
+            // `if (_ret === 0) break;`
+            for (index, jump) in jumps.iter().enumerate() {
+                let test = ctx.ast.expression_binary(
+                    SPAN,
+                    ret_binding.create_read_expression(ctx),
+                    BinaryOperator::StrictEquality,
+                    create_number(index, ctx),
+                );
+                let jump = jump.create_statement(ctx);
+                new_body.push(ctx.ast.statement_if(SPAN, test, jump, None));
+            }
+
+            // `if (_ret) return _ret.v;`
+            if has_return {
+                let value = Expression::from(ctx.ast.member_expression_static(
+                    SPAN,
+                    ret_binding.create_read_expression(ctx),
+                    ctx.ast.identifier_name(SPAN, "v"),
+                    false,
+                ));
+                let ret = ctx.ast.statement_return(SPAN, Some(value));
+                let test = ret_binding.create_read_expression(ctx);
+                new_body.push(ctx.ast.statement_if(SPAN, test, ret, None));
+            }
+        }
+
+        let new_body_scope_id = ctx.create_child_scope(body_parent_scope_id, ScopeFlags::empty());
+        *body = Statement::BlockStatement(ctx.ast.alloc_block_statement_with_scope_id(
+            body_span,
+            new_body,
+            new_body_scope_id,
+        ));
+
+        let decl = Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            declarators,
+            false,
+        ));
+        if let Some(wrapper_scope_id) = wrapper_scope_id {
+            let loop_stmt = ctx.ast.move_statement(stmt);
+            *stmt = Statement::BlockStatement(ctx.ast.alloc_block_statement_with_scope_id(
+                SPAN,
+                ctx.ast.vec_from_iter([decl, loop_stmt]),
+                wrapper_scope_id,
+            ));
+        } else {
+            self.ctx.statement_injector.insert_before(stmt.address(), decl);
+        }
+    }
+}
+
+/// Loop statement, with the `ScopeId` of its head if it has one, and its body.
+enum LoopMut<'a, 'b> {
+    Head(ScopeId, &'b mut Statement<'a>),
+    NoHead(&'b mut Statement<'a>),
+}
+
+/// Get loop statement, unwrapping any labels.
+fn get_loop_mut<'a, 'b>(mut stmt: &'b mut Statement<'a>) -> Option<LoopMut<'a, 'b>> {
+    while let Statement::LabeledStatement(labeled) = stmt {
+        stmt = &mut labeled.body;
+    }
+    match stmt {
+        Statement::ForStatement(stmt) => {
+            Some(LoopMut::Head(stmt.scope_id.get().unwrap(), &mut stmt.body))
+        }
+        Statement::ForInStatement(stmt) => {
+            Some(LoopMut::Head(stmt.scope_id.get().unwrap(), &mut stmt.body))
+        }
+        Statement::ForOfStatement(stmt) => {
+            Some(LoopMut::Head(stmt.scope_id.get().unwrap(), &mut stmt.body))
+        }
+        Statement::WhileStatement(stmt) => Some(LoopMut::NoHead(&mut stmt.body)),
+        Statement::DoWhileStatement(stmt) => Some(LoopMut::NoHead(&mut stmt.body)),
+        _ => None,
+    }
+}
+
+/// Get whether enclosing function is async and/or a generator.
+fn get_function_kind(ctx: &TraverseCtx) -> (bool, bool) {
+    for ancestor in ctx.ancestors() {
+        match ancestor {
+            Ancestor::FunctionBody(func) => return (*func.r#async(), *func.generator()),
+            Ancestor::ArrowFunctionExpressionBody(arrow) => return (*arrow.r#async(), false),
+            _ => {}
+        }
+    }
+    (false, false)
+}
+
+/// Get `let` / `const` bindings declared in a scope, and class declarations if `include_classes`.
+fn lexical_bindings(scope_id: ScopeId, include_classes: bool, ctx: &TraverseCtx) -> Vec<SymbolId> {
+    ctx.scopes()
+        .get_bindings(scope_id)
+        .values()
+        .copied()
+        .filter(|&symbol_id| {
+            let flags = ctx.symbols().get_flags(symbol_id);
+            (flags.contains(SymbolFlags::BlockScopedVariable)
+                && !flags.intersects(SymbolFlags::Function | SymbolFlags::CatchVariable))
+                || (include_classes && flags.contains(SymbolFlags::Class))
+        })
+        .collect()
+}
+
+/// Check if statement is a function declaration whose binding is block-scoped.
+fn is_block_scoped_function(stmt: &Statement, ctx: &TraverseCtx) -> bool {
+    let Statement::FunctionDeclaration(func) = stmt else { return false };
+    func.body.is_some()
+        && func.id.as_ref().and_then(|id| id.symbol_id.get()).is_some_and(|symbol_id| {
+            ctx.symbols().get_flags(symbol_id).contains(SymbolFlags::BlockScopedVariable)
+        })
+}
+
+fn get_symbol_id(ident: &IdentifierReference, ctx: &TraverseCtx) -> Option<SymbolId> {
+    ident.reference_id.get().and_then(|id| ctx.symbols().get_reference(id).symbol_id())
+}
+
+fn set_read_flag(ident: &IdentifierReference, ctx: &mut TraverseCtx) {
+    if let Some(reference_id) = ident.reference_id.get() {
+        *ctx.symbols_mut().get_reference_mut(reference_id).flags_mut() = ReferenceFlags::Read;
+    }
+}
+
+/// `a, b`
+fn create_sequence<'a>(
+    a: Expression<'a>,
+    b: Expression<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    ctx.ast.expression_sequence(SPAN, ctx.ast.vec_from_iter([a, b]))
+}
+
+/// `i = _i` or `i = _i, j = _j`
+fn create_updates<'a>(
+    updates: &[(BoundIdentifier<'a>, BoundIdentifier<'a>)],
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let is_sequence = updates.len() > 1;
+    let mut exprs = ctx.ast.vec_from_iter(updates.iter().map(|(outer, inner)| {
+        let target = if is_sequence {
+            outer.create_read_write_target(ctx)
+        } else {
+            outer.create_write_target(ctx)
+        };
+        let value = inner.create_read_expression(ctx);
+        ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value)
+    }));
+    if is_sequence {
+        ctx.ast.expression_sequence(SPAN, exprs)
+    } else {
+        exprs.pop().unwrap()
+    }
+}
+
+fn create_declarator<'a>(
+    binding: &BoundIdentifier<'a>,
+    init: Option<Expression<'a>>,
+    ctx: &TraverseCtx<'a>,
+) -> VariableDeclarator<'a> {
+    ctx.ast.variable_declarator(
+        SPAN,
+        VariableDeclarationKind::Var,
+        binding.create_binding_pattern(ctx),
+        init,
+        false,
+    )
+}
+
+/// `arguments`
+fn create_arguments_reference<'a>(ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+    let symbol_id = ctx.scopes().find_binding(ctx.current_scope_id(), "arguments");
+    let ident =
+        ctx.create_reference_id(SPAN, Atom::from("arguments"), symbol_id, ReferenceFlags::Read);
+    ctx.ast.expression_from_identifier_reference(ident)
+}
+
+fn create_number<'a>(value: usize, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+    #[expect(clippy::cast_precision_loss)]
+    let number = value as f64;
+    ctx.ast.expression_numeric_literal(
+        SPAN,
+        number,
+        ctx.ast.str(&value.to_string()),
+        NumberBase::Decimal,
+    )
+}
+
+/// Visitor to find if a loop body contains a function which captures a `let` / `const` binding
+/// declared in the loop.
+struct ClosureFinder<'a, 'c> {
+    ctx: &'c TraverseCtx<'a>,
+    /// `let` / `const` bindings declared in the loop, outside any functions
+    lexical: FxHashSet<SymbolId>,
+    /// Include class declarations in `lexical`
+    include_classes: bool,
+    /// Symbols referenced in the loop body
+    referenced: FxHashSet<SymbolId>,
+    /// Symbols written to in the loop body
+    written: FxHashSet<SymbolId>,
+    /// Symbols referenced in functions in the loop body
+    captured: FxHashSet<SymbolId>,
+    /// Whether each scope entered is a function scope
+    scope_is_function: Vec<bool>,
+    function_depth: usize,
+}
+
+impl<'a, 'c> ClosureFinder<'a, 'c> {
+    fn new(head_symbol_ids: &[SymbolId], include_classes: bool, ctx: &'c TraverseCtx<'a>) -> Self {
+        Self {
+            ctx,
+            lexical: head_symbol_ids.iter().copied().collect(),
+            include_classes,
+            referenced: FxHashSet::default(),
+            written: FxHashSet::default(),
+            captured: FxHashSet::default(),
+            scope_is_function: vec![],
+            function_depth: 0,
+        }
+    }
+
+    fn is_closure_required(&self) -> bool {
+        self.captured.iter().any(|symbol_id| self.lexical.contains(symbol_id))
+    }
+}
+
+impl<'a, 'c> Visit<'a> for ClosureFinder<'a, 'c> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        let scope_id = scope_id.get().unwrap();
+        let is_function = self.ctx.scopes().get_flags(scope_id).is_var();
+        if is_function {
+            self.function_depth += 1;
+        } else if self.function_depth == 0 {
+            self.lexical.extend(lexical_bindings(scope_id, self.include_classes, self.ctx));
+        }
+        self.scope_is_function.push(is_function);
+    }
+
+    fn leave_scope(&mut self) {
+        if self.scope_is_function.pop().unwrap() {
+            self.function_depth -= 1;
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        let Some(reference_id) = ident.reference_id.get() else { return };
+        let reference = self.ctx.symbols().get_reference(reference_id);
+        let Some(symbol_id) = reference.symbol_id() else { return };
+        self.referenced.insert(symbol_id);
+        if reference.is_write() {
+            self.written.insert(symbol_id);
+        }
+        if self.function_depth > 0 {
+            self.captured.insert(symbol_id);
+        }
+    }
+}
+
+/// `break` or `continue` which jumps out of a loop body which is wrapped in a function.
+struct Jump<'a> {
+    is_break: bool,
+    label: Option<Atom<'a>>,
+}
+
+impl<'a> Jump<'a> {
+    fn create_statement(&self, ctx: &TraverseCtx<'a>) -> Statement<'a> {
+        let label = self.label.as_ref().map(|label| ctx.ast.label_identifier(SPAN, label.clone()));
+        if self.is_break {
+            ctx.ast.statement_break(SPAN, label)
+        } else {
+            ctx.ast.statement_continue(SPAN, label)
+        }
+    }
+}
+
+/// Visitor to transform a loop body which is moved into a function.
+///
+/// * References to loop head bindings are pointed to the function params.
+/// * `this` and `arguments` are replaced with `_this` and `_arguments`.
+/// * `var` declarations are converted to assignments, and declared outside the function.
+/// * `break` and `continue` which jump out of the loop body are replaced with `return <index>`.
+/// * `return x` is replaced with `return { v: x }`.
+///
+/// The visitor is run twice. The first pass only counts jumps, and makes no changes.
+struct LoopBody<'a, 'b> {
+    ctx: &'b mut TraverseCtx<'a>,
+    /// Loop head bindings and the function params which replace them
+    bindings: &'b [(SymbolId, BoundIdentifier<'a>)],
+    /// Loop head bindings which are written to, and the function params which replace them
+    updates: &'b [(BoundIdentifier<'a>, BoundIdentifier<'a>)],
+    closure_scope_id: ScopeId,
+    hoist_scope_id: ScopeId,
+    /// `true` in first pass
+    counting: bool,
+    /// Number of `break` / `continue` statements which jump out of the loop body
+    jump_count: usize,
+    /// Distinct `break` / `continue` statements which jump out of the loop body
+    jumps: Vec<Jump<'a>>,
+    has_return: bool,
+    /// `true` if loop body contains `await` or `for await`, outside any functions
+    has_await: bool,
+    /// `true` if loop body contains `yield`, outside any functions
+    has_yield: bool,
+    this_binding: Option<BoundIdentifier<'a>>,
+    arguments_binding: Option<BoundIdentifier<'a>>,
+    /// `var` bindings which need to be declared outside the function
+    vars: Vec<(BoundIdentifier<'a>, Span)>,
+    /// `true` if a `var` declaration was replaced with an empty statement
+    removed_var: bool,
+    /// Depth of functions and classes, excluding arrow functions
+    this_depth: usize,
+    /// Depth of functions, including arrow functions
+    function_depth: usize,
+    loop_depth: usize,
+    switch_depth: usize,
+    labels: Vec<Atom<'a>>,
+}
+
+impl<'a, 'b> LoopBody<'a, 'b> {
+    fn new(
+        bindings: &'b [(SymbolId, BoundIdentifier<'a>)],
+        updates: &'b [(BoundIdentifier<'a>, BoundIdentifier<'a>)],
+        closure_scope_id: ScopeId,
+        hoist_scope_id: ScopeId,
+        ctx: &'b mut TraverseCtx<'a>,
+    ) -> Self {
+        Self {
+            ctx,
+            bindings,
+            updates,
+            closure_scope_id,
+            hoist_scope_id,
+            counting: true,
+            jump_count: 0,
+            jumps: vec![],
+            has_return: false,
+            has_await: false,
+            has_yield: false,
+            this_binding: None,
+            arguments_binding: None,
+            vars: vec![],
+            removed_var: false,
+            this_depth: 0,
+            function_depth: 0,
+            loop_depth: 0,
+            switch_depth: 0,
+            labels: vec![],
+        }
+    }
+
+    /// Transform `break` / `continue` to `return <index>` if it jumps out of loop body.
+    fn transform_jump(&mut self, stmt: &mut Statement<'a>) {
+        let (is_break, label) = match stmt {
+            Statement::BreakStatement(stmt) => (true, stmt.label.as_ref()),
+            Statement::ContinueStatement(stmt) => (false, stmt.label.as_ref()),
+            _ => unreachable!(),
+        };
+        let jumps_out = match label {
+            Some(label) => !self.labels.contains(&label.name),
+            None if is_break => self.loop_depth == 0 && self.switch_depth == 0,
+            None => self.loop_depth == 0,
+        };
+        if !jumps_out {
+            return;
+        }
+
+        if self.counting {
+            self.jump_count += 1;
+            return;
+        }
+
+        let label = label.map(|label| label.name.clone());
+        let index = self
+            .jumps
+            .iter()
+            .position(|jump| jump.is_break == is_break && jump.label == label)
+            .unwrap_or_else(|| {
+                self.jumps.push(Jump { is_break, label });
+                self.jumps.len() - 1
+            });
+        let value = if self.jump_count == 1 && !self.has_return { 1 } else { index };
+
+        // Copy updated loop head bindings back before jumping out
+        let mut value = create_number(value, self.ctx);
+        if !self.updates.is_empty() {
+            let update = create_updates(self.updates, self.ctx);
+            value = create_sequence(update, value, self.ctx);
+        }
+        *stmt = self.ctx.ast.statement_return(stmt.span(), Some(value));
+    }
+
+    /// Check if `var` declaration declares bindings outside the loop body,
+    /// and can be converted to assignments.
+    fn is_hoistable(&self, decl: &VariableDeclaration<'a>) -> bool {
+        decl.kind.is_var()
+            && decl.declarations.iter().all(|declarator| {
+                collect_bindings(&declarator.id).into_iter().all(|(_, symbol_id)| {
+                    self.ctx.symbols().get_scope_id(symbol_id) != self.closure_scope_id
+                })
+            })
+    }
+
+    /// Convert `var` declaration to assignments, and record bindings to be declared outside.
+    ///
+    /// * `var a = 1, b;` -> `a = 1`
+    /// * `var { a, b } = obj;` -> `({ a, b } = obj)`
+    fn hoist_var_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        is_statement: bool,
+    ) -> Option<Expression<'a>> {
+        let declarations = self.ctx.ast.move_vec(&mut decl.declarations);
+        let is_read_write = !is_statement
+            || declarations.iter().filter(|declarator| declarator.init.is_some()).count() > 1;
+        let mut exprs = self.ctx.ast.vec();
+        for declarator in declarations {
+            let target = if let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind {
+                self.add_var(ident);
+                if declarator.init.is_none() {
+                    continue;
+                }
+                let binding = BoundIdentifier::from_binding_ident(ident);
+                if is_read_write {
+                    binding.create_read_write_target(self.ctx)
+                } else {
+                    binding.create_write_target(self.ctx)
+                }
+            } else {
+                self.convert_binding_pattern(declarator.id)
+            };
+            // Destructuring declarations always have an initializer
+            let init = declarator.init.unwrap();
+            exprs.push(self.ctx.ast.expression_assignment(
+                declarator.span,
+                AssignmentOperator::Assign,
+                target,
+                init,
+            ));
+        }
+        match exprs.len() {
+            0 => None,
+            1 => exprs.pop(),
+            _ => Some(self.ctx.ast.expression_sequence(decl.span, exprs)),
+        }
+    }
+
+    /// Convert a binding pattern to an assignment target,
+    /// and record its bindings to be declared outside the function.
+    fn convert_binding_pattern(&mut self, pattern: BindingPattern<'a>) -> AssignmentTarget<'a> {
+        let ast = self.ctx.ast;
+        binding_pattern_to_assignment_target(pattern, ast, &mut |ident| {
+            self.add_var(ident);
+            let binding = BoundIdentifier::from_binding_ident(ident);
+            binding.create_spanned_write_target(ident.span, self.ctx)
+        })
+    }
+
+    /// Record `var` binding to be declared outside the function.
+    fn add_var(&mut self, ident: &BindingIdentifier<'a>) {
+        let symbol_id = ident.symbol_id.get().unwrap();
+        if !self.vars.iter().any(|(var, _)| var.symbol_id == symbol_id) {
+            self.vars.push((BoundIdentifier::new(ident.name.clone(), symbol_id), ident.span));
+        }
+    }
+
+    /// Convert `var x` or `var { x }` in left of `for-in` / `for-of` to `x` or `{ x }`.
+    fn transform_for_left(&mut self, left: &mut ForStatementLeft<'a>) {
+        if self.counting || self.function_depth > 0 {
+            return;
+        }
+        let ForStatementLeft::VariableDeclaration(decl) = left else { return };
+        if !self.is_hoistable(decl) {
+            return;
+        }
+        let declarator = self.ctx.ast.move_vec(&mut decl.declarations).into_iter().next().unwrap();
+        let target = self.convert_binding_pattern(declarator.id);
+        *left = ForStatementLeft::from(target);
+    }
+}
+
+impl<'a, 'b> VisitMut<'a> for LoopBody<'a, 'b> {
+    fn visit_statements(&mut self, stmts: &mut ArenaVec<'a, Statement<'a>>) {
+        walk_mut::walk_statements(self, stmts);
+        if self.removed_var {
+            stmts.retain(|stmt| !matches!(stmt, Statement::EmptyStatement(_)));
+            self.removed_var = false;
+        }
+    }
+
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        if self.function_depth == 0 {
+            match stmt {
+                Statement::BreakStatement(_) | Statement::ContinueStatement(_) => {
+                    self.transform_jump(stmt);
+                    return;
+                }
+                Statement::ReturnStatement(ret) => {
+                    self.has_return = true;
+                    if let Some(argument) = &mut ret.argument {
+                        self.visit_expression(argument);
+                    }
+                    if !self.counting {
+                        // `return x` -> `return { v: x }`
+                        let value =
+                            ret.argument.take().unwrap_or_else(|| self.ctx.ast.void_0(SPAN));
+                        let property = self.ctx.ast.alloc_object_property(
+                            SPAN,
+                            PropertyKind::Init,
+                            self.ctx.ast.property_key_identifier_name(SPAN, "v"),
+                            value,
+                            None,
+                            false,
+                            false,
+                            false,
+                        );
+                        ret.argument = Some(self.ctx.ast.expression_object(
+                            SPAN,
+                            self.ctx.ast.vec1(ObjectPropertyKind::ObjectProperty(property)),
+                            None,
+                        ));
+                    }
+                    return;
+                }
+                Statement::VariableDeclaration(decl)
+                    if !self.counting && self.is_hoistable(decl) =>
+                {
+                    let span = decl.span;
+                    *stmt = if let Some(expr) = self.hoist_var_declaration(decl, true) {
+                        self.ctx.ast.statement_expression(span, expr)
+                    } else {
+                        self.removed_var = true;
+                        self.ctx.ast.statement_empty(span)
+                    };
+                }
+                _ => {}
+            }
+        }
+        walk_mut::walk_statement(self, stmt);
+    }
+
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if !self.counting && self.this_depth == 0 {
+            match expr {
+                Expression::ThisExpression(this) => {
+                    let span = this.span;
+                    let binding = self.this_binding.get_or_insert_with(|| {
+                        self.ctx.generate_uid(
+                            "this",
+                            self.hoist_scope_id,
+                            SymbolFlags::FunctionScopedVariable,
+                        )
+                    });
+                    *expr = binding.create_spanned_read_expression(span, self.ctx);
+                    return;
+                }
+                Expression::Identifier(ident)
+                    if ident.name == "arguments" && get_symbol_id(ident, self.ctx).is_none() =>
+                {
+                    let span = ident.span;
+                    self.ctx.delete_reference_for_identifier(ident);
+                    let binding = self.arguments_binding.get_or_insert_with(|| {
+                        self.ctx.generate_uid(
+                            "arguments",
+                            self.hoist_scope_id,
+                            SymbolFlags::FunctionScopedVariable,
+                        )
+                    });
+                    *expr = binding.create_spanned_read_expression(span, self.ctx);
+                    return;
+                }
+                _ => {}
+            }
+        }
+        walk_mut::walk_expression(self, expr);
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        if self.counting {
+            return;
+        }
+        let Some(symbol_id) = get_symbol_id(ident, self.ctx) else { return };
+        let Some((_, binding)) = self.bindings.iter().find(|(id, _)| *id == symbol_id) else {
+            return;
+        };
+        // Point reference to function param
+        let reference_id = ident.reference_id.get().unwrap();
+        let flags = self.ctx.symbols().get_reference(reference_id).flags();
+        self.ctx.symbols_mut().delete_resolved_reference(symbol_id, reference_id);
+        let reference_id = self.ctx.create_bound_reference(binding.symbol_id, flags);
+        ident.reference_id.set(Some(reference_id));
+        ident.name = binding.name.clone();
+    }
+
+    fn visit_function(&mut self, func: &mut Function<'a>, flags: ScopeFlags) {
+        self.this_depth += 1;
+        self.function_depth += 1;
+        walk_mut::walk_function(self, func, flags);
+        self.function_depth -= 1;
+        self.this_depth -= 1;
+    }
+
+    fn visit_await_expression(&mut self, expr: &mut AwaitExpression<'a>) {
+        if self.function_depth == 0 {
+            self.has_await = true;
+        }
+        walk_mut::walk_await_expression(self, expr);
+    }
+
+    fn visit_yield_expression(&mut self, expr: &mut YieldExpression<'a>) {
+        if self.function_depth == 0 {
+            self.has_yield = true;
+        }
+        walk_mut::walk_yield_expression(self, expr);
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &mut ArrowFunctionExpression<'a>) {
+        self.function_depth += 1;
+        walk_mut::walk_arrow_function_expression(self, arrow);
+        self.function_depth -= 1;
+    }
+
+    fn visit_class(&mut self, class: &mut Class<'a>) {
+        self.this_depth += 1;
+        walk_mut::walk_class(self, class);
+        self.this_depth -= 1;
+    }
+
+    fn visit_labeled_statement(&mut self, stmt: &mut LabeledStatement<'a>) {
+        self.labels.push(stmt.label.name.clone());
+        walk_mut::walk_labeled_statement(self, stmt);
+        self.labels.pop();
+    }
+
+    fn visit_switch_statement(&mut self, stmt: &mut SwitchStatement<'a>) {
+        self.switch_depth += 1;
+        walk_mut::walk_switch_statement(self, stmt);
+        self.switch_depth -= 1;
+    }
+
+    fn visit_for_statement(&mut self, stmt: &mut ForStatement<'a>) {
+        if !self.counting && self.function_depth == 0 {
+            let init = match &mut stmt.init {
+                Some(ForStatementInit::VariableDeclaration(decl)) if self.is_hoistable(decl) => {
+                    Some(self.hoist_var_declaration(decl, false))
+                }
+                _ => None,
+            };
+            if let Some(init) = init {
+                stmt.init = init.map(|expr| self.ctx.ast.for_statement_init_expression(expr));
+            }
+        }
+        self.loop_depth += 1;
+        walk_mut::walk_for_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>) {
+        self.transform_for_left(&mut stmt.left);
+        self.loop_depth += 1;
+        walk_mut::walk_for_in_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>) {
+        if stmt.r#await && self.function_depth == 0 {
+            self.has_await = true;
+        }
+        self.transform_for_left(&mut stmt.left);
+        self.loop_depth += 1;
+        walk_mut::walk_for_of_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_while_statement(&mut self, stmt: &mut WhileStatement<'a>) {
+        self.loop_depth += 1;
+        walk_mut::walk_while_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_do_while_statement(&mut self, stmt: &mut DoWhileStatement<'a>) {
+        self.loop_depth += 1;
+        walk_mut::walk_do_while_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+}
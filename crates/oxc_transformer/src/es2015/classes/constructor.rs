@@ -0,0 +1,222 @@
+//! ES2015: Classes
+//! Transform of class constructor into constructor function.
+//!
+//! * Class has no constructor: create one.
+//!   `class A {}` -> `function A() { babelHelpers.classCallCheck(this, A); }`
+//!   `class A extends B {}` ->
+//!   `function A() { babelHelpers.classCallCheck(this, A); return babelHelpers.callSuper(this, A, arguments); }`
+//! * Class has constructor: add `classCallCheck` at top of it.
+//! * Derived class: `this` is replaced with `_this`, which is initialized by `super()` call,
+//!   and returned at end of constructor.
+//!   If arrow functions transform has already captured `this` in a `var _this = this;` statement,
+//!   that binding is reused, so the arrow functions see the value initialized by `super()`.
+//!   ```js
+//!   function A(x) {
+//!     var _this;
+//!     babelHelpers.classCallCheck(this, A);
+//!     _this = babelHelpers.callSuper(this, A, [x]);
+//!     _this.x = x;
+//!     return _this;
+//!   }
+//!   ```
+
+use oxc_allocator::Box;
+use oxc_ast::{ast::*, NONE};
+use oxc_span::SPAN;
+use oxc_syntax::{
+    scope::{ScopeFlags, ScopeId},
+    symbol::SymbolFlags,
+};
+use oxc_traverse::{BoundIdentifier, TraverseCtx};
+
+use crate::common::helper_loader::Helper;
+
+use super::{create_global_ident, create_var_statement, Classes, MethodTransformer};
+
+impl<'a, 'ctx> Classes<'a, 'ctx> {
+    /// Transform class constructor into a function declaration, creating it if required.
+    #[expect(clippy::too_many_arguments)]
+    pub(super) fn transform_constructor(
+        &self,
+        constructor: Option<Box<'a, Function<'a>>>,
+        class_id: BindingIdentifier<'a>,
+        class_binding: &BoundIdentifier<'a>,
+        is_derived: bool,
+        class_scope_id: ScopeId,
+        is_strict: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Box<'a, Function<'a>> {
+        let Some(mut func) = constructor else {
+            return self.create_constructor(
+                class_id,
+                class_binding,
+                is_derived,
+                class_scope_id,
+                is_strict,
+                ctx,
+            );
+        };
+
+        Self::update_method_scope_flags(&mut func, is_strict, ctx);
+        let scope_id = func.scope_id.get().unwrap();
+
+        let this_binding = is_derived.then(|| {
+            take_this_capture(func.body.as_mut().unwrap()).unwrap_or_else(|| {
+                ctx.generate_uid("this", scope_id, SymbolFlags::FunctionScopedVariable)
+            })
+        });
+        let has_top_level_super_call =
+            func.body.as_ref().unwrap().statements.iter().any(is_super_call_statement);
+
+        MethodTransformer::new(
+            self.ctx,
+            self.spread.as_ref(),
+            class_binding,
+            false,
+            true,
+            this_binding.as_ref(),
+            ctx,
+        )
+        .transform(&mut func);
+
+        let body = func.body.as_mut().unwrap();
+        let mut prefix = vec![];
+        if let Some(this_binding) = &this_binding {
+            prefix.push(create_var_statement(this_binding, ctx));
+        }
+        if !self.ctx.assumptions.no_class_calls {
+            prefix.push(self.create_class_call_check(class_binding, ctx));
+        }
+        body.statements.splice(0..0, prefix);
+
+        // `return _this;`
+        if let Some(this_binding) = &this_binding {
+            if !matches!(body.statements.last(), Some(Statement::ReturnStatement(_))) {
+                let this_expr = this_binding.create_read_expression(ctx);
+                let argument = if has_top_level_super_call {
+                    this_expr
+                } else {
+                    self.ctx.helper_call_expr(
+                        Helper::AssertThisInitialized,
+                        ctx.ast.vec1(Argument::from(this_expr)),
+                        ctx,
+                    )
+                };
+                body.statements.push(ctx.ast.statement_return(SPAN, Some(argument)));
+            }
+        }
+
+        func.r#type = FunctionType::FunctionDeclaration;
+        func.id = Some(class_id);
+        func
+    }
+
+    /// Create constructor function for class which has no constructor.
+    fn create_constructor(
+        &self,
+        class_id: BindingIdentifier<'a>,
+        class_binding: &BoundIdentifier<'a>,
+        is_derived: bool,
+        class_scope_id: ScopeId,
+        is_strict: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Box<'a, Function<'a>> {
+        let flags = if is_strict {
+            ScopeFlags::Function | ScopeFlags::StrictMode
+        } else {
+            ScopeFlags::Function
+        };
+        let scope_id = ctx.create_child_scope(class_scope_id, flags);
+
+        let mut stmts = ctx.ast.vec();
+        if !self.ctx.assumptions.no_class_calls {
+            stmts.push(self.create_class_call_check(class_binding, ctx));
+        }
+        if is_derived {
+            // `return babelHelpers.callSuper(this, A, arguments);`
+            let arguments = ctx.ast.vec_from_iter([
+                Argument::from(ctx.ast.expression_this(SPAN)),
+                Argument::from(class_binding.create_read_expression(ctx)),
+                Argument::from(create_global_ident("arguments", ctx)),
+            ]);
+            let call_super = self.ctx.helper_call_expr(Helper::CallSuper, arguments, ctx);
+            stmts.push(ctx.ast.statement_return(SPAN, Some(call_super)));
+        }
+
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            ctx.ast.vec(),
+            NONE,
+        );
+        let body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), stmts);
+        ctx.ast.alloc_function_with_scope_id(
+            FunctionType::FunctionDeclaration,
+            SPAN,
+            Some(class_id),
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(body),
+            scope_id,
+        )
+    }
+
+    /// `babelHelpers.classCallCheck(this, A);`
+    fn create_class_call_check(
+        &self,
+        class_binding: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let arguments = ctx.ast.vec_from_iter([
+            Argument::from(ctx.ast.expression_this(SPAN)),
+            Argument::from(class_binding.create_read_expression(ctx)),
+        ]);
+        let call = self.ctx.helper_call_expr(Helper::ClassCallCheck, arguments, ctx);
+        ctx.ast.statement_expression(SPAN, call)
+    }
+}
+
+/// Remove `var _this = this;` statement inserted by arrow functions transform at top of constructor,
+/// and return its binding.
+///
+/// `this` cannot be used before `super()` in a derived class constructor, so this statement
+/// can only come from the arrow functions transform.
+fn take_this_capture<'a>(body: &mut FunctionBody<'a>) -> Option<BoundIdentifier<'a>> {
+    let index = body
+        .statements
+        .iter()
+        .take_while(|stmt| matches!(stmt, Statement::VariableDeclaration(_)))
+        .position(|stmt| {
+            let Statement::VariableDeclaration(decl) = stmt else { unreachable!() };
+            decl.kind == VariableDeclarationKind::Var
+                && matches!(
+                    decl.declarations.as_slice(),
+                    [VariableDeclarator {
+                        id: BindingPattern { kind: BindingPatternKind::BindingIdentifier(_), .. },
+                        init: Some(Expression::ThisExpression(_)),
+                        ..
+                    }]
+                )
+        })?;
+    let Statement::VariableDeclaration(decl) = body.statements.remove(index) else {
+        unreachable!()
+    };
+    let BindingPatternKind::BindingIdentifier(ident) = &decl.declarations[0].id.kind else {
+        unreachable!()
+    };
+    Some(BoundIdentifier::from_binding_ident(ident))
+}
+
+/// Check if statement is `super(...);`.
+fn is_super_call_statement(stmt: &Statement) -> bool {
+    matches!(
+        stmt,
+        Statement::ExpressionStatement(expr_stmt)
+            if matches!(&expr_stmt.expression, Expression::CallExpression(call) if matches!(call.callee, Expression::Super(_)))
+    )
+}
@@ -0,0 +1,454 @@
+//! ES2015: Classes
+//! Transform of `super`, `this` and `new.target` within class methods and constructor.
+//!
+//! * `super.x` -> `babelHelpers.superPropGet(A, "x", this, 1)`
+//! * `super.x(1)` -> `babelHelpers.superPropGet(A, "x", this, 3)([1])`
+//! * `super.x = 1` -> `babelHelpers.superPropSet(A, "x", 1, this, 1, 1)`
+//! * `super.x += 1` -> `babelHelpers.superPropSet(A, "x", babelHelpers.superPropGet(A, "x", this, 1) + 1, this, 1, 1)`
+//! * `new.target` -> `this.constructor` in constructor, `void 0` in methods.
+//!
+//! In static methods, the flag indicating access is to the prototype is omitted.
+//!
+//! In derived class constructor only:
+//! * `super(x)` -> `_this = babelHelpers.callSuper(this, A, [x])`
+//! * `this` -> `_this`
+//! * `return;` -> `return _this;`
+//! * `return x;` -> `return babelHelpers.possibleConstructorReturn(_this, x);`
+//!
+//! `super` cannot appear in a function in source, so `super` in a nested function must be
+//! in a function created by another transform (e.g. an arrow function converted to a function,
+//! or a loop body wrapped in a function by block scoping transform). `super` in these functions
+//! is transformed too, with `this` of the method captured in a `_this` var.
+
+use oxc_ast::{ast::*, visit::walk_mut, VisitMut, NONE};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::{GetSpan, SPAN};
+use oxc_syntax::{
+    number::NumberBase, operator::AssignmentOperator, scope::ScopeFlags, symbol::SymbolFlags,
+};
+use oxc_traverse::{BoundIdentifier, TraverseCtx};
+
+use crate::{common::helper_loader::Helper, TransformCtx};
+
+use super::{create_var_statement, Spread};
+
+pub(super) struct MethodTransformer<'a, 'b> {
+    transform_ctx: &'b TransformCtx<'a>,
+    /// Spread transform, if enabled
+    spread: Option<&'b Spread<'a, 'b>>,
+    /// Binding for the class constructor function
+    class_binding: &'b BoundIdentifier<'a>,
+    is_static: bool,
+    is_constructor: bool,
+    /// Binding which replaces `this` in derived class constructor
+    this_binding: Option<&'b BoundIdentifier<'a>>,
+    /// Depth of arrow functions. `return` is only transformed outside arrow functions.
+    arrow_depth: u32,
+    /// Depth of functions created by other transforms
+    function_depth: u32,
+    /// Binding for `this` of the method, used for `super` in nested functions
+    outer_this_binding: Option<BoundIdentifier<'a>>,
+    /// Temp vars to declare at top of function
+    temp_bindings: Vec<BoundIdentifier<'a>>,
+    scope_id: Option<oxc_syntax::scope::ScopeId>,
+    ctx: &'b mut TraverseCtx<'a>,
+}
+
+impl<'a, 'b> MethodTransformer<'a, 'b> {
+    pub(super) fn new(
+        transform_ctx: &'b TransformCtx<'a>,
+        spread: Option<&'b Spread<'a, 'b>>,
+        class_binding: &'b BoundIdentifier<'a>,
+        is_static: bool,
+        is_constructor: bool,
+        this_binding: Option<&'b BoundIdentifier<'a>>,
+        ctx: &'b mut TraverseCtx<'a>,
+    ) -> Self {
+        Self {
+            transform_ctx,
+            spread,
+            class_binding,
+            is_static,
+            is_constructor,
+            this_binding,
+            arrow_depth: 0,
+            function_depth: 0,
+            outer_this_binding: None,
+            temp_bindings: vec![],
+            scope_id: None,
+            ctx,
+        }
+    }
+
+    pub(super) fn transform(mut self, func: &mut Function<'a>) {
+        self.scope_id = func.scope_id.get();
+        let body = func.body.as_mut().unwrap();
+        self.visit_function_body(body);
+        if !self.temp_bindings.is_empty() {
+            let stmts = self
+                .temp_bindings
+                .iter()
+                .map(|binding| create_var_statement(binding, self.ctx))
+                .collect::<Vec<_>>();
+            body.statements.splice(0..0, stmts);
+        }
+        // `var _this = this;`
+        if let Some(binding) = &self.outer_this_binding {
+            let kind = VariableDeclarationKind::Var;
+            let init = self.ctx.ast.expression_this(SPAN);
+            let declarator = self.ctx.ast.variable_declarator(
+                SPAN,
+                kind,
+                binding.create_binding_pattern(self.ctx),
+                Some(init),
+                false,
+            );
+            let stmt = Statement::VariableDeclaration(self.ctx.ast.alloc_variable_declaration(
+                SPAN,
+                kind,
+                self.ctx.ast.vec1(declarator),
+                false,
+            ));
+            body.statements.insert(0, stmt);
+        }
+    }
+}
+
+impl<'a, 'b> VisitMut<'a> for MethodTransformer<'a, 'b> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            // `this` -> `_this`
+            Expression::ThisExpression(this) if self.function_depth == 0 => {
+                if let Some(this_binding) = self.this_binding {
+                    *expr = this_binding.create_spanned_read_expression(this.span, self.ctx);
+                }
+            }
+            // `new.target`
+            Expression::MetaProperty(meta)
+                if self.function_depth == 0
+                    && meta.meta.name == "new"
+                    && meta.property.name == "target" =>
+            {
+                *expr = if self.is_constructor {
+                    // `this.constructor`. Not `_this`, as `new.target` may be before `super()`.
+                    let property = self.ctx.ast.identifier_name(SPAN, "constructor");
+                    let this = self.ctx.ast.expression_this(SPAN);
+                    Expression::from(
+                        self.ctx.ast.member_expression_static(meta.span, this, property, false),
+                    )
+                } else {
+                    self.ctx.ast.void_0(meta.span)
+                };
+            }
+            // `super(...)`
+            Expression::CallExpression(call) if matches!(call.callee, Expression::Super(_)) => {
+                self.visit_arguments(&mut call.arguments);
+                *expr = self.transform_super_call(expr, false);
+            }
+            // `super.x(...)`
+            Expression::CallExpression(call) if is_super_member(&call.callee) => {
+                self.visit_super_member_key(&mut call.callee);
+                self.visit_arguments(&mut call.arguments);
+                *expr = self.transform_super_method_call(expr);
+            }
+            // `super.x = ...`
+            Expression::AssignmentExpression(assign)
+                if assign
+                    .left
+                    .as_member_expression()
+                    .is_some_and(|member| matches!(member.object(), Expression::Super(_))) =>
+            {
+                if let Some(MemberExpression::ComputedMemberExpression(member)) =
+                    assign.left.as_member_expression_mut()
+                {
+                    self.visit_expression(&mut member.expression);
+                }
+                self.visit_expression(&mut assign.right);
+                *expr = self.transform_super_assignment(expr);
+            }
+            // `super.x`
+            _ if is_super_member(expr) => {
+                self.visit_super_member_key(expr);
+                *expr = self.transform_super_member(expr);
+            }
+            Expression::UpdateExpression(update)
+                if update
+                    .argument
+                    .as_member_expression()
+                    .is_some_and(|member| matches!(member.object(), Expression::Super(_))) =>
+            {
+                self.transform_ctx.error(
+                    OxcDiagnostic::error(
+                        "Update expressions on `super` properties are not supported yet.",
+                    )
+                    .with_label(update.span),
+                );
+            }
+            _ => walk_mut::walk_expression(self, expr),
+        }
+    }
+
+    fn visit_expression_statement(&mut self, stmt: &mut ExpressionStatement<'a>) {
+        // `super(...);` as a statement. `_this` is only written, not read.
+        if let Expression::CallExpression(call) = &mut stmt.expression {
+            if matches!(call.callee, Expression::Super(_)) {
+                self.visit_arguments(&mut call.arguments);
+                stmt.expression = self.transform_super_call(&mut stmt.expression, true);
+                return;
+            }
+        }
+        walk_mut::walk_expression_statement(self, stmt);
+    }
+
+    fn visit_return_statement(&mut self, stmt: &mut ReturnStatement<'a>) {
+        walk_mut::walk_return_statement(self, stmt);
+        if self.arrow_depth > 0 || self.function_depth > 0 {
+            return;
+        }
+        let Some(this_binding) = self.this_binding else { return };
+
+        let this_expr = this_binding.create_read_expression(self.ctx);
+        stmt.argument = Some(match stmt.argument.take() {
+            // `return x;` -> `return babelHelpers.possibleConstructorReturn(_this, x);`
+            Some(argument) => {
+                let arguments = self
+                    .ctx
+                    .ast
+                    .vec_from_iter([Argument::from(this_expr), Argument::from(argument)]);
+                self.transform_ctx.helper_call_expr(
+                    Helper::PossibleConstructorReturn,
+                    arguments,
+                    self.ctx,
+                )
+            }
+            // `return;` -> `return _this;`
+            None => this_expr,
+        });
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &mut ArrowFunctionExpression<'a>) {
+        self.arrow_depth += 1;
+        walk_mut::walk_arrow_function_expression(self, arrow);
+        self.arrow_depth -= 1;
+    }
+
+    fn visit_function(&mut self, func: &mut Function<'a>, flags: ScopeFlags) {
+        self.function_depth += 1;
+        walk_mut::walk_function(self, func, flags);
+        self.function_depth -= 1;
+    }
+
+    // `super` has a different meaning in object methods and classes
+    fn visit_object_property(&mut self, prop: &mut ObjectProperty<'a>) {
+        if prop.method || prop.kind != PropertyKind::Init {
+            self.visit_property_key(&mut prop.key);
+        } else {
+            walk_mut::walk_object_property(self, prop);
+        }
+    }
+
+    fn visit_class(&mut self, _class: &mut Class<'a>) {}
+}
+
+impl<'a, 'b> MethodTransformer<'a, 'b> {
+    /// Visit computed key of `super[key]`.
+    fn visit_super_member_key(&mut self, expr: &mut Expression<'a>) {
+        if let Expression::ComputedMemberExpression(member) = expr {
+            self.visit_expression(&mut member.expression);
+        }
+    }
+
+    /// `super(x)` -> `_this = babelHelpers.callSuper(this, A, [x])`
+    fn transform_super_call(
+        &mut self,
+        expr: &mut Expression<'a>,
+        is_statement: bool,
+    ) -> Expression<'a> {
+        let Expression::CallExpression(call) = self.ctx.ast.move_expression(expr) else {
+            unreachable!()
+        };
+        let call = call.unbox();
+
+        let mut arguments = self.ctx.ast.vec_with_capacity(3);
+        arguments.push(Argument::from(self.ctx.ast.expression_this(SPAN)));
+        arguments.push(Argument::from(self.class_binding.create_read_expression(self.ctx)));
+        if !call.arguments.is_empty() {
+            arguments.push(Argument::from(self.create_array(call.arguments)));
+        }
+        let call_super =
+            self.transform_ctx.helper_call_expr(Helper::CallSuper, arguments, self.ctx);
+
+        let Some(this_binding) = self.this_binding else {
+            // `super()` outside derived class constructor is a syntax error
+            return call_super;
+        };
+        let target = if is_statement {
+            this_binding.create_write_target(self.ctx)
+        } else {
+            this_binding.create_read_write_target(self.ctx)
+        };
+        self.ctx.ast.expression_assignment(
+            call.span,
+            AssignmentOperator::Assign,
+            target,
+            call_super,
+        )
+    }
+
+    /// `super.x(y)` -> `babelHelpers.superPropGet(A, "x", this, 3)([y])`
+    fn transform_super_method_call(&mut self, expr: &mut Expression<'a>) -> Expression<'a> {
+        let Expression::CallExpression(call) = self.ctx.ast.move_expression(expr) else {
+            unreachable!()
+        };
+        let CallExpression { span, mut callee, arguments, .. } = call.unbox();
+        let key = self.take_super_member_key(&mut callee);
+        let flags = if self.is_static { 2 } else { 3 };
+        let callee = self.create_super_prop_get(key, Some(flags));
+        let arguments = self.ctx.ast.vec1(Argument::from(self.create_array(arguments)));
+        self.ctx.ast.expression_call(span, callee, NONE, arguments, false)
+    }
+
+    /// `super.x` -> `babelHelpers.superPropGet(A, "x", this, 1)`
+    fn transform_super_member(&mut self, expr: &mut Expression<'a>) -> Expression<'a> {
+        let key = self.take_super_member_key(expr);
+        let flags = if self.is_static { None } else { Some(1) };
+        self.create_super_prop_get(key, flags)
+    }
+
+    /// `super.x = y` -> `babelHelpers.superPropSet(A, "x", y, this, 1, 1)`
+    /// `super.x += y` -> `babelHelpers.superPropSet(A, "x", babelHelpers.superPropGet(A, "x", this, 1) + y, this, 1, 1)`
+    fn transform_super_assignment(&mut self, expr: &mut Expression<'a>) -> Expression<'a> {
+        let Expression::AssignmentExpression(assign) = self.ctx.ast.move_expression(expr) else {
+            unreachable!()
+        };
+        let AssignmentExpression { span, operator, left, right } = assign.unbox();
+        let mut member = Expression::from(left.into_member_expression());
+        let mut key = self.take_super_member_key(&mut member);
+
+        let value = if operator == AssignmentOperator::Assign {
+            right
+        } else if let Some(binary_operator) = operator.to_binary_operator() {
+            // Key must only be evaluated once
+            let get_key = if let Expression::StringLiteral(lit) = &key {
+                self.ctx.ast.expression_string_literal(lit.span, lit.value.clone())
+            } else {
+                let binding = self.ctx.generate_uid_based_on_node(
+                    &key,
+                    self.scope_id.unwrap(),
+                    SymbolFlags::FunctionScopedVariable,
+                );
+                key = self.ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    binding.create_read_write_target(self.ctx),
+                    key,
+                );
+                let get_key = binding.create_read_expression(self.ctx);
+                self.temp_bindings.push(binding);
+                get_key
+            };
+            let flags = if self.is_static { None } else { Some(1) };
+            let current = self.create_super_prop_get(get_key, flags);
+            self.ctx.ast.expression_binary(SPAN, current, binary_operator, right)
+        } else {
+            self.transform_ctx.error(
+                OxcDiagnostic::error(
+                    "Logical assignments to `super` properties are not supported yet.",
+                )
+                .with_label(span),
+            );
+            right
+        };
+
+        let mut arguments = self.ctx.ast.vec_with_capacity(6);
+        arguments.push(Argument::from(self.class_binding.create_read_expression(self.ctx)));
+        arguments.push(Argument::from(key));
+        arguments.push(Argument::from(value));
+        arguments.push(Argument::from(self.create_this()));
+        // `isStrict`. Class bodies are always strict mode.
+        arguments.push(Argument::from(self.create_number(1)));
+        if !self.is_static {
+            arguments.push(Argument::from(self.create_number(1)));
+        }
+        let set = self.transform_ctx.helper_call_expr(Helper::SuperPropSet, arguments, self.ctx);
+        let Expression::CallExpression(mut call) = set else { unreachable!() };
+        call.span = span;
+        Expression::CallExpression(call)
+    }
+
+    /// `babelHelpers.superPropGet(A, key, this, flags)`
+    fn create_super_prop_get(&mut self, key: Expression<'a>, flags: Option<u8>) -> Expression<'a> {
+        let mut arguments = self.ctx.ast.vec_with_capacity(4);
+        arguments.push(Argument::from(self.class_binding.create_read_expression(self.ctx)));
+        arguments.push(Argument::from(key));
+        arguments.push(Argument::from(self.create_this()));
+        if let Some(flags) = flags {
+            arguments.push(Argument::from(self.create_number(flags)));
+        }
+        self.transform_ctx.helper_call_expr(Helper::SuperPropGet, arguments, self.ctx)
+    }
+
+    /// Get key of `super.x` / `super[x]` as an expression.
+    fn take_super_member_key(&mut self, expr: &mut Expression<'a>) -> Expression<'a> {
+        match self.ctx.ast.move_expression(expr) {
+            Expression::StaticMemberExpression(member) => {
+                let property = &member.property;
+                self.ctx.ast.expression_string_literal(property.span, property.name.clone())
+            }
+            Expression::ComputedMemberExpression(member) => member.unbox().expression,
+            _ => unreachable!(),
+        }
+    }
+
+    /// `this`, or `_this` in derived class constructor or in a nested function.
+    fn create_this(&mut self) -> Expression<'a> {
+        match self.this_binding {
+            Some(this_binding) => this_binding.create_read_expression(self.ctx),
+            None if self.function_depth > 0 => {
+                let scope_id = self.scope_id.unwrap();
+                let binding = self.outer_this_binding.get_or_insert_with(|| {
+                    self.ctx.generate_uid("this", scope_id, SymbolFlags::FunctionScopedVariable)
+                });
+                binding.create_read_expression(self.ctx)
+            }
+            None => self.ctx.ast.expression_this(SPAN),
+        }
+    }
+
+    fn create_number(&self, value: u8) -> Expression<'a> {
+        let raw = self.ctx.ast.str(&value.to_string());
+        self.ctx.ast.expression_numeric_literal(SPAN, f64::from(value), raw, NumberBase::Decimal)
+    }
+
+    /// Convert call arguments to array `[a, ...b]`.
+    ///
+    /// If spread transform is enabled, spread elements are transformed too:
+    /// `[a].concat(babelHelpers.toConsumableArray(b))`.
+    fn create_array(&mut self, arguments: oxc_allocator::Vec<'a, Argument<'a>>) -> Expression<'a> {
+        let has_spread = arguments.iter().any(Argument::is_spread);
+        let elements =
+            self.ctx.ast.vec_from_iter(arguments.into_iter().map(|argument| match argument {
+                Argument::SpreadElement(spread) => ArrayExpressionElement::SpreadElement(spread),
+                argument => {
+                    self.ctx.ast.array_expression_element_expression(argument.into_expression())
+                }
+            }));
+        let span = elements.first().map_or(SPAN, GetSpan::span);
+        let mut array = self.ctx.ast.array_expression(span, elements, None);
+        match self.spread {
+            Some(spread) if has_spread => spread.transform_array(&mut array, self.ctx),
+            _ => Expression::ArrayExpression(self.ctx.ast.alloc(array)),
+        }
+    }
+}
+
+/// Check if expression is `super.x` or `super[x]`.
+fn is_super_member(expr: &Expression) -> bool {
+    match expr {
+        Expression::StaticMemberExpression(member) => matches!(member.object, Expression::Super(_)),
+        Expression::ComputedMemberExpression(member) => {
+            matches!(member.object, Expression::Super(_))
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,818 @@
+//! ES2015: Classes
+//!
+//! This plugin transforms classes to constructor functions, with methods, getters and setters
+//! defined on the prototype via helpers.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! class A extends B {
+//!   constructor(x) {
+//!     super(x);
+//!     this.y = new.target;
+//!   }
+//!   foo() {
+//!     return super.foo();
+//!   }
+//!   get bar() {
+//!     return 1;
+//!   }
+//!   static baz() {}
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! let A = function (_B) {
+//!   function A(x) {
+//!     var _this;
+//!     babelHelpers.classCallCheck(this, A);
+//!     _this = babelHelpers.callSuper(this, A, [x]);
+//!     _this.y = this.constructor;
+//!     return _this;
+//!   }
+//!   babelHelpers.inherits(A, _B);
+//!   return babelHelpers.createClass(A, [{
+//!     key: "foo",
+//!     value: function foo() {
+//!       return babelHelpers.superPropGet(A, "foo", this, 3)([]);
+//!     }
+//!   }, {
+//!     key: "bar",
+//!     get: function () {
+//!       return 1;
+//!     }
+//!   }], [{
+//!     key: "baz",
+//!     value: function baz() {}
+//!   }]);
+//! }(B);
+//! ```
+//!
+//! Class declarations become `let` declarations. When block scoping transform is also enabled,
+//! they become `var` declarations.
+//!
+//! Class fields, private members and static blocks must be transformed first
+//! (by the ES2022 transforms). If any remain, an error is reported and the class is left as is.
+//!
+//! ## Options
+//!
+//! ### `loose`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Not supported yet. See "Missing features" below.
+//!
+//! ### Assumptions
+//!
+//! * `noClassCalls`: Omit the `classCallCheck` which throws if class is called without `new`.
+//!
+//! ## Missing features
+//!
+//! Implementation is incomplete at present. Still TODO:
+//!
+//! * `loose` option, and `setClassMethods`, `constantSuper` and `superIsCallableConstructor` assumptions.
+//! * `/*#__PURE__*/` annotation on the class IIFE.
+//! * Update expressions and logical assignments on `super` properties (`super.x++`, `super.x ||= 1`).
+//! * TDZ check for `this` used before `super()` in a derived class constructor.
+//! * `this` / `arguments` in computed keys refer to the IIFE wrapping the class.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-classes](https://babel.dev/docs/babel-plugin-transform-classes).
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-classes>
+//! * Class definitions in spec: <https://tc39.es/ecma262/#sec-class-definitions>
+
+use std::cell::Cell;
+
+use serde::Deserialize;
+
+use oxc_allocator::{Box, GetAddress};
+use oxc_ast::{ast::*, visit::walk_mut, Visit, VisitMut, NONE};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::{Atom, GetSpan, SPAN};
+use oxc_syntax::{
+    keyword::is_reserved_keyword_or_global_object,
+    reference::ReferenceFlags,
+    scope::{ScopeFlags, ScopeId},
+    symbol::{SymbolFlags, SymbolId},
+};
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse, TraverseCtx};
+
+use crate::{common::helper_loader::Helper, TransformCtx};
+
+use super::{Spread, SpreadOptions};
+
+mod constructor;
+mod method;
+
+use method::MethodTransformer;
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct ClassesOptions {
+    /// Not supported yet.
+    pub loose: bool,
+}
+
+pub struct Classes<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    /// Spread transform, if enabled. Used to transform spread in arguments of `super` calls,
+    /// which spread transform does not visit, as they are created on exit of class.
+    spread: Option<Spread<'a, 'ctx>>,
+}
+
+impl<'a, 'ctx> Classes<'a, 'ctx> {
+    pub fn new(
+        _options: ClassesOptions,
+        spread: Option<SpreadOptions>,
+        ctx: &'ctx TransformCtx<'a>,
+    ) -> Self {
+        Self { ctx, spread: spread.map(|options| Spread::new(options, ctx)) }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for Classes<'a, 'ctx> {
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::ClassExpression(class) = expr else { return };
+        if !self.can_transform(class) {
+            return;
+        }
+
+        let name = match &class.id {
+            Some(id) => ClassName::Expression(BoundIdentifier::from_binding_ident(id)),
+            None => ClassName::Anonymous(Self::get_inferred_name(ctx)),
+        };
+        let Expression::ClassExpression(class) = ctx.ast.move_expression(expr) else {
+            unreachable!()
+        };
+        *expr = self.transform_class(class, name, ctx);
+    }
+
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        match stmt {
+            // `class A {}` -> `let A = function () { ... }();`
+            Statement::ClassDeclaration(class) => {
+                if !self.can_transform(class) {
+                    return;
+                }
+                let Statement::ClassDeclaration(class) = ctx.ast.move_statement(stmt) else {
+                    unreachable!()
+                };
+                let decl = self.transform_class_declaration(class, ctx);
+                *stmt = Statement::VariableDeclaration(decl);
+            }
+            // `export class A {}` -> `export let A = function () { ... }();`
+            Statement::ExportNamedDeclaration(export_decl) => {
+                let Some(Declaration::ClassDeclaration(class)) = &mut export_decl.declaration
+                else {
+                    return;
+                };
+                if !self.can_transform(class) {
+                    return;
+                }
+                let Some(Declaration::ClassDeclaration(class)) = export_decl.declaration.take()
+                else {
+                    unreachable!()
+                };
+                let decl = self.transform_class_declaration(class, ctx);
+                export_decl.declaration = Some(Declaration::VariableDeclaration(decl));
+            }
+            Statement::ExportDefaultDeclaration(export_decl) => {
+                let ExportDefaultDeclarationKind::ClassDeclaration(class) =
+                    &export_decl.declaration
+                else {
+                    return;
+                };
+                if !self.can_transform(class) {
+                    return;
+                }
+                let Statement::ExportDefaultDeclaration(export_decl) = ctx.ast.move_statement(stmt)
+                else {
+                    unreachable!()
+                };
+                let ExportDefaultDeclaration { span, declaration, exported } = export_decl.unbox();
+                let ExportDefaultDeclarationKind::ClassDeclaration(class) = declaration else {
+                    unreachable!()
+                };
+                if let Some(id) = &class.id {
+                    // `export default class A {}` -> `let A = function () { ... }(); export { A as default };`
+                    let binding = BoundIdentifier::from_binding_ident(id);
+                    let decl = self.transform_class_declaration(class, ctx);
+                    *stmt = Statement::VariableDeclaration(decl);
+                    self.ctx.statement_injector.insert_after(
+                        stmt.address(),
+                        Self::create_export_default(span, &binding, ctx),
+                    );
+                } else {
+                    // `export default class {}` -> `export default function () { ... }();`
+                    let name = ClassName::Anonymous(Some(Atom::from("default")));
+                    let expr = self.transform_class(class, name, ctx);
+                    let declaration = ExportDefaultDeclarationKind::from(expr);
+                    *stmt = Statement::ExportDefaultDeclaration(
+                        ctx.ast.alloc_export_default_declaration(span, declaration, exported),
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Name of the constructor function a class is transformed into.
+enum ClassName<'a> {
+    /// Class declaration `class A {}`. Binding is in the scope enclosing the class.
+    Declaration(BoundIdentifier<'a>),
+    /// Named class expression `(class A {})`. Binding is in the class's own scope.
+    Expression(BoundIdentifier<'a>),
+    /// Anonymous class expression, with name inferred from context (e.g. `let A = class {}`).
+    Anonymous(Option<Atom<'a>>),
+}
+
+impl<'a, 'ctx> Classes<'a, 'ctx> {
+    /// Check class can be transformed. Report an error if it contains elements which
+    /// should have been transformed by an earlier transform.
+    fn can_transform(&self, class: &Class<'a>) -> bool {
+        if class.declare {
+            return false;
+        }
+        for element in &class.body.body {
+            let message = match element {
+                ClassElement::MethodDefinition(method) => {
+                    if !matches!(method.key, PropertyKey::PrivateIdentifier(_)) {
+                        continue;
+                    }
+                    "Private methods must be transformed before classes. Enable the private methods transform."
+                }
+                ClassElement::PropertyDefinition(_) | ClassElement::AccessorProperty(_) => {
+                    "Class properties must be transformed before classes. Enable the class properties transform."
+                }
+                ClassElement::StaticBlock(_) => {
+                    "Class static blocks must be transformed before classes. Enable the class static block transform."
+                }
+                ClassElement::TSIndexSignature(_) => continue,
+            };
+            self.ctx.error(OxcDiagnostic::error(message).with_label(element.span()));
+            return false;
+        }
+        true
+    }
+
+    /// Get name for anonymous class expression from its context.
+    ///
+    /// `let A = class {}` -> `A`
+    fn get_inferred_name(ctx: &TraverseCtx<'a>) -> Option<Atom<'a>> {
+        match ctx.parent() {
+            Ancestor::VariableDeclaratorInit(decl) => match &decl.id().kind {
+                BindingPatternKind::BindingIdentifier(ident) => Some(ident.name.clone()),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// `class A {}` -> `let A = function () { ... }();`
+    ///
+    /// If block scoping transform has already hoisted the class's binding, `var` is used instead.
+    fn transform_class_declaration(
+        &self,
+        mut class: Box<'a, Class<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Box<'a, VariableDeclaration<'a>> {
+        let id = class.id.take().unwrap();
+        let binding = BoundIdentifier::from_binding_ident(&id);
+
+        // Class binding becomes a `let` binding, or a `var` binding if it has been hoisted
+        let flags = ctx.symbols_mut().get_flags_mut(binding.symbol_id);
+        let (kind, binding_flags) = if flags.contains(SymbolFlags::FunctionScopedVariable) {
+            (VariableDeclarationKind::Var, SymbolFlags::FunctionScopedVariable)
+        } else {
+            (VariableDeclarationKind::Let, SymbolFlags::BlockScopedVariable)
+        };
+        *flags = binding_flags | (*flags & SymbolFlags::Export);
+
+        let init = self.transform_class(class, ClassName::Declaration(binding), ctx);
+        let id = ctx.ast.binding_pattern(
+            ctx.ast.binding_pattern_kind_from_binding_identifier(id),
+            NONE,
+            false,
+        );
+        let declarator = ctx.ast.variable_declarator(SPAN, kind, id, Some(init), false);
+        ctx.ast.alloc_variable_declaration(SPAN, kind, ctx.ast.vec1(declarator), false)
+    }
+
+    /// `export { A as default };`
+    fn create_export_default(
+        span: Span,
+        binding: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let local = ModuleExportName::IdentifierReference(binding.create_read_reference(ctx));
+        let exported = ModuleExportName::IdentifierName(ctx.ast.identifier_name(SPAN, "default"));
+        let specifier = ctx.ast.export_specifier(SPAN, local, exported, ImportOrExportKind::Value);
+        Statement::ExportNamedDeclaration(ctx.ast.alloc_export_named_declaration(
+            span,
+            None,
+            ctx.ast.vec1(specifier),
+            None,
+            ImportOrExportKind::Value,
+            NONE,
+        ))
+    }
+
+    /// Transform class to an IIFE which returns the constructor function.
+    ///
+    /// The class's scope becomes the scope of the IIFE.
+    fn transform_class(
+        &self,
+        class: Box<'a, Class<'a>>,
+        name: ClassName<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let Class { span, id, super_class, body, scope_id, .. } = class.unbox();
+        let scope_id = scope_id.get().unwrap();
+        let parent_scope_id = ctx.scopes().get_parent_id(scope_id).unwrap();
+        let is_strict = ctx.scopes().get_flags(parent_scope_id).is_strict_mode();
+
+        // Class scope becomes IIFE function scope
+        *ctx.scopes_mut().get_flags_mut(scope_id) = if is_strict {
+            ScopeFlags::Function | ScopeFlags::StrictMode
+        } else {
+            ScopeFlags::Function
+        };
+
+        // Super class is evaluated outside the IIFE and passed in as an argument
+        let mut super_class = super_class.map(|mut super_class| {
+            ScopeParentSetter::new(parent_scope_id, ctx).visit_expression(&mut super_class);
+            super_class
+        });
+
+        let mut body = body;
+        let (class_binding, class_id) =
+            Self::create_class_binding(id, name, scope_id, &mut body, ctx);
+
+        // Split class body into constructor and methods
+        let mut constructor = None;
+        let mut methods = vec![];
+        for element in body.unbox().body {
+            if let ClassElement::MethodDefinition(method) = element {
+                let method = method.unbox();
+                if method.kind == MethodDefinitionKind::Constructor {
+                    constructor = Some(method.value);
+                } else {
+                    methods.push(method);
+                }
+            }
+        }
+
+        let mut stmts = ctx.ast.vec();
+
+        // `function A() { babelHelpers.classCallCheck(this, A); }`
+        let constructor = self.transform_constructor(
+            constructor,
+            class_id,
+            &class_binding,
+            super_class.is_some(),
+            scope_id,
+            is_strict,
+            ctx,
+        );
+        stmts.push(Statement::FunctionDeclaration(constructor));
+
+        // `babelHelpers.inherits(A, _B);`
+        let super_binding = super_class.as_ref().map(|super_class| {
+            let super_binding = ctx.generate_uid_based_on_node(
+                super_class,
+                scope_id,
+                SymbolFlags::FunctionScopedVariable,
+            );
+            let arguments = ctx.ast.vec_from_iter([
+                Argument::from(class_binding.create_read_expression(ctx)),
+                Argument::from(super_binding.create_read_expression(ctx)),
+            ]);
+            let inherits = self.ctx.helper_call_expr(Helper::Inherits, arguments, ctx);
+            stmts.push(ctx.ast.statement_expression(SPAN, inherits));
+            super_binding
+        });
+
+        // `return babelHelpers.createClass(A, [...], [...]);`
+        let mut instance_descriptors = Descriptors::default();
+        let mut static_descriptors = Descriptors::default();
+        for method in methods {
+            let descriptors =
+                if method.r#static { &mut static_descriptors } else { &mut instance_descriptors };
+            self.add_method(method, descriptors, &class_binding, is_strict, ctx);
+        }
+        let mut arguments = ctx.ast.vec1(Argument::from(class_binding.create_read_expression(ctx)));
+        if !instance_descriptors.is_empty() || !static_descriptors.is_empty() {
+            arguments.push(Argument::from(instance_descriptors.into_array(ctx)));
+        }
+        if !static_descriptors.is_empty() {
+            arguments.push(Argument::from(static_descriptors.into_array(ctx)));
+        }
+        let create_class = self.ctx.helper_call_expr(Helper::CreateClass, arguments, ctx);
+        stmts.push(ctx.ast.statement_return(SPAN, Some(create_class)));
+
+        // `function (_B) { ... }(B)`
+        let params = ctx.ast.vec_from_iter(super_binding.iter().map(|super_binding| {
+            ctx.ast.formal_parameter(
+                SPAN,
+                ctx.ast.vec(),
+                super_binding.create_binding_pattern(ctx),
+                None,
+                false,
+                false,
+            )
+        }));
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            params,
+            NONE,
+        );
+        let body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), stmts);
+        let iife = ctx.ast.alloc_function_with_scope_id(
+            FunctionType::FunctionExpression,
+            SPAN,
+            None,
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(body),
+            scope_id,
+        );
+        let arguments = ctx.ast.vec_from_iter(super_class.take().map(Argument::from));
+        ctx.ast.expression_call(span, Expression::FunctionExpression(iife), NONE, arguments, false)
+    }
+
+    /// Create binding for the constructor function inside the IIFE.
+    ///
+    /// Returns the binding, and `BindingIdentifier` for the function declaration.
+    fn create_class_binding(
+        id: Option<BindingIdentifier<'a>>,
+        name: ClassName<'a>,
+        scope_id: ScopeId,
+        body: &mut ClassBody<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (BoundIdentifier<'a>, BindingIdentifier<'a>) {
+        let flags = SymbolFlags::FunctionScopedVariable;
+        let binding = match name {
+            ClassName::Declaration(outer_binding) => {
+                // Create a new binding inside IIFE, and point references to the class
+                // within class body to it
+                let binding = ctx.generate_binding(outer_binding.name.clone(), scope_id, flags);
+                ReferenceRebinder::new(outer_binding.symbol_id, &binding, ctx)
+                    .visit_class_body(body);
+                binding
+            }
+            ClassName::Expression(binding) => {
+                // Reuse existing binding, which is already in class scope
+                *ctx.symbols_mut().get_flags_mut(binding.symbol_id) = flags;
+                return (binding, id.unwrap());
+            }
+            ClassName::Anonymous(name) => {
+                // Use inferred name as is, unless it would shadow a reference within class body
+                match name {
+                    Some(name)
+                        if !is_reserved_keyword_or_global_object(&name)
+                            && !ReferenceFinder::contains_reference(&name, body) =>
+                    {
+                        ctx.generate_binding(name, scope_id, flags)
+                    }
+                    Some(name) => ctx.generate_uid(&name, scope_id, flags),
+                    None => ctx.generate_uid("Class", scope_id, flags),
+                }
+            }
+        };
+        let id = binding.create_binding_identifier(ctx);
+        (binding, id)
+    }
+
+    /// Add method / getter / setter to descriptors.
+    fn add_method(
+        &self,
+        method: MethodDefinition<'a>,
+        descriptors: &mut Descriptors<'a>,
+        class_binding: &BoundIdentifier<'a>,
+        is_strict: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let MethodDefinition { key, mut value, kind, r#static, computed, .. } = method;
+
+        Self::update_method_scope_flags(&mut value, is_strict, ctx);
+
+        MethodTransformer::new(
+            self.ctx,
+            self.spread.as_ref(),
+            class_binding,
+            r#static,
+            false,
+            None,
+            ctx,
+        )
+        .transform(&mut value);
+
+        let static_name = match &key {
+            PropertyKey::StaticIdentifier(ident) if !computed => Some(ident.name.clone()),
+            PropertyKey::StringLiteral(lit) => Some(lit.value.clone()),
+            _ => None,
+        };
+
+        // Name function after method: `{ key: "foo", value: function foo() {} }`
+        if kind == MethodDefinitionKind::Method {
+            if let Some(name) = &static_name {
+                Self::name_function(&mut value, name, ctx);
+            }
+        }
+
+        let key = match key {
+            PropertyKey::StaticIdentifier(ident) if !computed => {
+                ctx.ast.expression_string_literal(ident.span, ident.name.clone())
+            }
+            key => key.into_expression(),
+        };
+
+        let func = Expression::FunctionExpression(value);
+        let descriptor = match kind {
+            MethodDefinitionKind::Get | MethodDefinitionKind::Set => {
+                descriptors.get_or_add_accessor(static_name, key)
+            }
+            _ => descriptors.add(key),
+        };
+        match kind {
+            MethodDefinitionKind::Get => descriptor.get = Some(func),
+            MethodDefinitionKind::Set => descriptor.set = Some(func),
+            _ => descriptor.value = Some(func),
+        }
+    }
+
+    /// Update scope flags of a method which is being converted to a plain function.
+    ///
+    /// * Remove `Constructor` / `GetAccessor` / `SetAccessor` flags from function's scope,
+    ///   and block scopes within it which inherited them.
+    /// * Class bodies are always strict mode. When class is transformed to functions in non-strict code,
+    ///   the functions are no longer strict.
+    pub(super) fn update_method_scope_flags(
+        func: &mut Function<'a>,
+        is_strict: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let scope_id = func.scope_id.get().unwrap();
+        let flags = ctx.scopes_mut().get_flags_mut(scope_id);
+        flags.remove(ScopeFlags::Modifiers);
+        if !is_strict {
+            flags.remove(ScopeFlags::StrictMode);
+        }
+        ScopeFlagsRemover::new(is_strict, ctx).visit_function_body(func.body.as_mut().unwrap());
+    }
+
+    /// Give function a name, if name is a valid identifier, and it would not shadow
+    /// any reference within the function.
+    fn name_function(func: &mut Function<'a>, name: &Atom<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !oxc_syntax::identifier::is_identifier_name(name)
+            || is_reserved_keyword_or_global_object(name)
+            || ReferenceFinder::contains_reference_in_function(name, func)
+        {
+            return;
+        }
+        let scope_id = func.scope_id.get().unwrap();
+        let binding = ctx.generate_binding(name.clone(), scope_id, SymbolFlags::Function);
+        func.id = Some(binding.create_binding_identifier(ctx));
+    }
+}
+
+/// Property descriptor for `createClass`.
+struct Descriptor<'a> {
+    /// Key name, if key is not computed. Used to combine getter and setter.
+    name: Option<Atom<'a>>,
+    key: Expression<'a>,
+    value: Option<Expression<'a>>,
+    get: Option<Expression<'a>>,
+    set: Option<Expression<'a>>,
+}
+
+#[derive(Default)]
+struct Descriptors<'a>(std::vec::Vec<Descriptor<'a>>);
+
+impl<'a> Descriptors<'a> {
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn add(&mut self, key: Expression<'a>) -> &mut Descriptor<'a> {
+        self.0.push(Descriptor { name: None, key, value: None, get: None, set: None });
+        self.0.last_mut().unwrap()
+    }
+
+    /// Get existing accessor descriptor with same name, or add a new one.
+    fn get_or_add_accessor(
+        &mut self,
+        name: Option<Atom<'a>>,
+        key: Expression<'a>,
+    ) -> &mut Descriptor<'a> {
+        if let Some(name) = &name {
+            if let Some(index) = self.0.iter().position(|descriptor| {
+                descriptor.name.as_ref() == Some(name) && descriptor.value.is_none()
+            }) {
+                return &mut self.0[index];
+            }
+        }
+        self.0.push(Descriptor { name, key, value: None, get: None, set: None });
+        self.0.last_mut().unwrap()
+    }
+
+    /// `[{ key: "foo", value: function foo() {} }, { key: "bar", get: function () {} }]`
+    fn into_array(self, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+        let elements = ctx.ast.vec_from_iter(self.0.into_iter().map(|descriptor| {
+            let mut properties = ctx.ast.vec_with_capacity(3);
+            let mut push = |name: &'static str, value: Expression<'a>| {
+                properties.push(ctx.ast.object_property_kind_object_property(
+                    SPAN,
+                    PropertyKind::Init,
+                    ctx.ast.property_key_identifier_name(SPAN, name),
+                    value,
+                    None,
+                    false,
+                    false,
+                    false,
+                ));
+            };
+            push("key", descriptor.key);
+            if let Some(value) = descriptor.value {
+                push("value", value);
+            }
+            if let Some(get) = descriptor.get {
+                push("get", get);
+            }
+            if let Some(set) = descriptor.set {
+                push("set", set);
+            }
+            ctx.ast.array_expression_element_expression(
+                ctx.ast.expression_object(SPAN, properties, None),
+            )
+        }));
+        ctx.ast.expression_array(SPAN, elements, None)
+    }
+}
+
+/// Visitor which points references to `from` symbol to `to` binding instead.
+struct ReferenceRebinder<'a, 'b> {
+    from: SymbolId,
+    to: &'b BoundIdentifier<'a>,
+    ctx: &'b mut TraverseCtx<'a>,
+}
+
+impl<'a, 'b> ReferenceRebinder<'a, 'b> {
+    fn new(from: SymbolId, to: &'b BoundIdentifier<'a>, ctx: &'b mut TraverseCtx<'a>) -> Self {
+        Self { from, to, ctx }
+    }
+}
+
+impl<'a, 'b> VisitMut<'a> for ReferenceRebinder<'a, 'b> {
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        let reference_id = ident.reference_id.get().unwrap();
+        let reference = self.ctx.symbols().get_reference(reference_id);
+        if reference.symbol_id() != Some(self.from) {
+            return;
+        }
+        let flags = reference.flags();
+        self.ctx.delete_reference(reference_id, &ident.name);
+        let reference_id = self.ctx.create_bound_reference(self.to.symbol_id, flags);
+        ident.reference_id.set(Some(reference_id));
+    }
+}
+
+/// Visitor which checks if there is any reference to a name.
+struct ReferenceFinder<'n> {
+    name: &'n str,
+    found: bool,
+}
+
+impl<'n> ReferenceFinder<'n> {
+    fn contains_reference(name: &'n str, body: &ClassBody<'_>) -> bool {
+        let mut finder = Self { name, found: false };
+        finder.visit_class_body(body);
+        finder.found
+    }
+
+    fn contains_reference_in_function(name: &'n str, func: &Function<'_>) -> bool {
+        let mut finder = Self { name, found: false };
+        finder.visit_function(func, ScopeFlags::Function);
+        finder.found
+    }
+}
+
+impl<'a, 'n> Visit<'a> for ReferenceFinder<'n> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        if ident.name == self.name {
+            self.found = true;
+        }
+    }
+}
+
+/// Visitor which sets parent of top-level scopes within an expression.
+struct ScopeParentSetter<'a, 'b> {
+    parent_scope_id: ScopeId,
+    depth: u32,
+    ctx: &'b mut TraverseCtx<'a>,
+}
+
+impl<'a, 'b> ScopeParentSetter<'a, 'b> {
+    fn new(parent_scope_id: ScopeId, ctx: &'b mut TraverseCtx<'a>) -> Self {
+        Self { parent_scope_id, depth: 0, ctx }
+    }
+}
+
+impl<'a, 'b> VisitMut<'a> for ScopeParentSetter<'a, 'b> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        if self.depth == 0 {
+            let scope_id = scope_id.get().unwrap();
+            self.ctx.scopes_mut().change_parent_id(scope_id, Some(self.parent_scope_id));
+        }
+        self.depth += 1;
+    }
+
+    fn leave_scope(&mut self) {
+        self.depth -= 1;
+    }
+}
+
+/// Visitor which removes flags inherited from a class method from scopes within it.
+///
+/// * `Modifiers` are removed from block scopes, up to the first nested function.
+/// * `StrictMode` is removed from all scopes if class is in non-strict code,
+///   except in functions with a `"use strict"` directive.
+struct ScopeFlagsRemover<'a, 'b> {
+    remove_strict_mode: bool,
+    /// `true` for each function scope entered, `false` for each other scope
+    function_scopes: std::vec::Vec<bool>,
+    /// Depth of functions with a `"use strict"` directive
+    use_strict_depth: u32,
+    ctx: &'b mut TraverseCtx<'a>,
+}
+
+impl<'a, 'b> ScopeFlagsRemover<'a, 'b> {
+    fn new(is_strict: bool, ctx: &'b mut TraverseCtx<'a>) -> Self {
+        Self { remove_strict_mode: !is_strict, function_scopes: vec![], use_strict_depth: 0, ctx }
+    }
+}
+
+impl<'a, 'b> VisitMut<'a> for ScopeFlagsRemover<'a, 'b> {
+    fn enter_scope(&mut self, flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        let scope_id = scope_id.get().unwrap();
+        let in_function = self.function_scopes.contains(&true);
+        let scope_flags = self.ctx.scopes_mut().get_flags_mut(scope_id);
+        if !in_function {
+            scope_flags.remove(ScopeFlags::Modifiers);
+        }
+        if self.remove_strict_mode && self.use_strict_depth == 0 {
+            scope_flags.remove(ScopeFlags::StrictMode);
+        }
+        self.function_scopes.push(flags.contains(ScopeFlags::Function));
+    }
+
+    fn leave_scope(&mut self) {
+        self.function_scopes.pop();
+    }
+
+    fn visit_function(&mut self, func: &mut Function<'a>, flags: ScopeFlags) {
+        let is_use_strict = func.body.as_ref().is_some_and(|body| body.has_use_strict_directive());
+        if is_use_strict {
+            // Function and everything within it remains strict mode
+            self.use_strict_depth += 1;
+        }
+        walk_mut::walk_function(self, func, flags);
+        if is_use_strict {
+            self.use_strict_depth -= 1;
+        }
+    }
+}
+
+/// Create `IdentifierReference` for a global var (e.g. `arguments`) as an `Expression`.
+fn create_global_ident<'a>(name: &'static str, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+    let symbol_id = ctx.scopes().find_binding(ctx.current_scope_id(), name);
+    let ident = ctx.create_reference_id(SPAN, Atom::from(name), symbol_id, ReferenceFlags::Read);
+    ctx.ast.expression_from_identifier_reference(ident)
+}
+
+/// Create `var <binding>;` statement.
+fn create_var_statement<'a>(binding: &BoundIdentifier<'a>, ctx: &TraverseCtx<'a>) -> Statement<'a> {
+    let kind = VariableDeclarationKind::Var;
+    let declarator =
+        ctx.ast.variable_declarator(SPAN, kind, binding.create_binding_pattern(ctx), None, false);
+    Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+        SPAN,
+        kind,
+        ctx.ast.vec1(declarator),
+        false,
+    ))
+}
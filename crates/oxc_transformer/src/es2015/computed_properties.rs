@@ -0,0 +1,222 @@
+//! ES2015: Computed Properties
+//!
+//! This plugin transforms object literals containing computed property keys.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! var obj = { a: 1, [b]: 2, c: 3, get [d]() {} };
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _obj;
+//! var obj = (
+//!   _obj = { a: 1 },
+//!   babelHelpers.defineProperty(_obj, b, 2),
+//!   babelHelpers.defineProperty(_obj, "c", 3),
+//!   babelHelpers.defineAccessor("get", _obj, d, function () {}),
+//!   _obj
+//! );
+//! ```
+//!
+//! Properties before the first computed key remain in the object literal.
+//! All properties after it are defined in order, so that keys are evaluated in the original order.
+//!
+//! If the only property after the first computed key is a value property, no temp var is required:
+//! `{ a: 1, [b]: 2 }` -> `babelHelpers.defineProperty({ a: 1 }, b, 2)`.
+//!
+//! ### Assumptions
+//!
+//! * `setComputedProperties`: Use simple assignment `_obj[b] = 2` instead of `defineProperty` helper.
+//!
+//! ## Missing features
+//!
+//! Implementation is incomplete at present. Still TODO:
+//!
+//! * Object literals containing spread elements after a computed key are not transformed.
+//!   These are normally transformed first by object spread transform.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-computed-properties](https://babel.dev/docs/babel-plugin-transform-computed-properties).
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-computed-properties>
+//! * Object initializer in spec: <https://tc39.es/ecma262/#sec-object-initializer>
+
+use oxc_ast::ast::*;
+use oxc_span::SPAN;
+use oxc_syntax::{operator::AssignmentOperator, symbol::SymbolFlags};
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse, TraverseCtx};
+
+use crate::{common::helper_loader::Helper, TransformCtx};
+
+use super::Classes;
+
+pub struct ComputedProperties<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> ComputedProperties<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for ComputedProperties<'a, 'ctx> {
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::ObjectExpression(obj_expr) = expr else { return };
+
+        let Some(first_computed_index) = obj_expr.properties.iter().position(
+            |prop| matches!(prop, ObjectPropertyKind::ObjectProperty(prop) if prop.computed),
+        ) else {
+            return;
+        };
+        if obj_expr
+            .properties
+            .iter()
+            .skip(first_computed_index)
+            .any(|prop| matches!(prop, ObjectPropertyKind::SpreadProperty(_)))
+        {
+            return;
+        }
+
+        let span = obj_expr.span;
+        let computed_props = obj_expr.properties.drain(first_computed_index..).collect::<Vec<_>>();
+        let init = ctx.ast.move_expression(expr);
+
+        *expr = self.transform_object(init, computed_props, span, ctx);
+    }
+}
+
+impl<'a, 'ctx> ComputedProperties<'a, 'ctx> {
+    fn transform_object(
+        &self,
+        init: Expression<'a>,
+        computed_props: Vec<ObjectPropertyKind<'a>>,
+        span: Span,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let set_computed_properties = self.ctx.assumptions.set_computed_properties;
+
+        // `{ a: 1, [b]: 2 }` -> `babelHelpers.defineProperty({ a: 1 }, b, 2)`
+        if computed_props.len() == 1 && !set_computed_properties {
+            if let Some(ObjectPropertyKind::ObjectProperty(prop)) = computed_props.first() {
+                if prop.kind == PropertyKind::Init {
+                    let Some(ObjectPropertyKind::ObjectProperty(prop)) =
+                        computed_props.into_iter().next()
+                    else {
+                        unreachable!()
+                    };
+                    let ObjectProperty { key, value, .. } = prop.unbox();
+                    let key = Self::create_key(key, ctx);
+                    let mut call = self.create_define_property(init, key, value, ctx);
+                    if let Expression::CallExpression(call) = &mut call {
+                        call.span = span;
+                    }
+                    return call;
+                }
+            }
+        }
+
+        // `(_obj = { a: 1 }, babelHelpers.defineProperty(_obj, b, 2), ..., _obj)`
+        let binding = self.create_temp_binding(ctx);
+        let mut expressions = ctx.ast.vec_with_capacity(computed_props.len() + 2);
+        expressions.push(ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            binding.create_read_write_target(ctx),
+            init,
+        ));
+
+        for prop in computed_props {
+            let ObjectPropertyKind::ObjectProperty(prop) = prop else { unreachable!() };
+            let ObjectProperty { kind, key, mut value, computed, .. } = prop.unbox();
+            let is_proto = !computed && key.is_specific_static_name("__proto__");
+            let key = Self::create_key(key, ctx);
+            let object = binding.create_read_expression(ctx);
+            let expr = match kind {
+                PropertyKind::Init if is_proto || set_computed_properties => {
+                    // `_obj[b] = 2`. `__proto__` must be assigned, to set the prototype.
+                    let target = ctx.ast.member_expression_computed(SPAN, object, key, false);
+                    ctx.ast.expression_assignment(
+                        SPAN,
+                        AssignmentOperator::Assign,
+                        AssignmentTarget::from(target),
+                        value,
+                    )
+                }
+                PropertyKind::Init => self.create_define_property(object, key, value, ctx),
+                PropertyKind::Get | PropertyKind::Set => {
+                    // `babelHelpers.defineAccessor("get", _obj, d, function () {})`
+                    if let Expression::FunctionExpression(func) = &mut value {
+                        Classes::update_method_scope_flags(func, true, ctx);
+                    }
+                    let kind_name = if kind == PropertyKind::Get { "get" } else { "set" };
+                    let arguments = ctx.ast.vec_from_iter([
+                        Argument::from(ctx.ast.expression_string_literal(SPAN, kind_name)),
+                        Argument::from(object),
+                        Argument::from(key),
+                        Argument::from(value),
+                    ]);
+                    self.ctx.helper_call_expr(Helper::DefineAccessor, arguments, ctx)
+                }
+            };
+            expressions.push(expr);
+        }
+
+        expressions.push(binding.create_read_expression(ctx));
+        ctx.ast.expression_sequence(span, expressions)
+    }
+
+    /// `babelHelpers.defineProperty(object, key, value)`
+    fn create_define_property(
+        &self,
+        object: Expression<'a>,
+        key: Expression<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let arguments = ctx.ast.vec_from_iter([
+            Argument::from(object),
+            Argument::from(key),
+            Argument::from(value),
+        ]);
+        self.ctx.helper_call_expr(Helper::DefineProperty, arguments, ctx)
+    }
+
+    /// Convert property key to an expression.
+    /// Non-computed identifier keys are converted to string literals: `{ a: 1 }` -> `"a"`.
+    fn create_key(key: PropertyKey<'a>, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+        match key {
+            PropertyKey::StaticIdentifier(ident) => {
+                ctx.ast.expression_string_literal(ident.span, ident.name.clone())
+            }
+            // Private identifiers are not valid in object literals
+            PropertyKey::PrivateIdentifier(_) => unreachable!(),
+            key => key.into_expression(),
+        }
+    }
+
+    /// Create temp var for object.
+    /// Named after variable the object is assigned to, if there is one: `var foo = {...}` -> `_foo`.
+    fn create_temp_binding(&self, ctx: &mut TraverseCtx<'a>) -> BoundIdentifier<'a> {
+        let flags = SymbolFlags::FunctionScopedVariable;
+        let binding = if let Ancestor::VariableDeclaratorInit(decl) = ctx.parent() {
+            if let BindingPatternKind::BindingIdentifier(ident) = &decl.id().kind {
+                let name = ident.name.clone();
+                ctx.generate_uid_in_current_scope(&name, flags)
+            } else {
+                ctx.generate_uid_in_current_scope("obj", flags)
+            }
+        } else {
+            ctx.generate_uid_in_current_scope("obj", flags)
+        };
+        self.ctx.var_declarations.insert(&binding, None, ctx);
+        binding
+    }
+}
@@ -0,0 +1,1110 @@
+//! ES2015: Destructuring
+//!
+//! This plugin transforms destructuring patterns in variable declarations, assignments,
+//! `for-in` / `for-of` loops and `catch` clauses.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! var { a, b: { c = 1 }, ...rest } = obj;
+//! var [x, , y, ...z] = arr;
+//! ({ a, b } = obj);
+//! ```
+//!
+//! Output:
+//! ```js
+//! var a = obj.a,
+//!   _obj$b = obj.b,
+//!   _obj$b$c = _obj$b.c,
+//!   c = _obj$b$c === void 0 ? 1 : _obj$b$c,
+//!   rest = babelHelpers.objectWithoutProperties(obj, ["a", "b"]);
+//! var _arr = babelHelpers.toArray(arr),
+//!   x = _arr[0],
+//!   y = _arr[2],
+//!   z = _arr.slice(3);
+//! a = obj.a, b = obj.b;
+//! ```
+//!
+//! Temp vars are created for values which are referenced more than once, unless they are
+//! identifiers which are never reassigned.
+//!
+//! Destructuring in `for-in` / `for-of` loop heads and `catch` clauses is moved into a declaration
+//! at top of the body. Function parameters are handled by parameters transform.
+//!
+//! ## Options
+//!
+//! ### `loose`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! When `true`, all iterables are assumed to be arrays, and objects are assumed to have no
+//! symbol properties.
+//!
+//! ### `useBuiltIns`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! When `true`, use `Object.assign` instead of `extends` helper.
+//!
+//! ### Assumptions
+//!
+//! * `iterableIsArray`: Array patterns index into the value directly, instead of converting it
+//!   to an array with `slicedToArray` helper.
+//! * `objectRestNoSymbols`: Use `objectWithoutPropertiesLoose` helper for object rest.
+//!
+//! ## Missing features
+//!
+//! Implementation is incomplete at present. Still TODO:
+//!
+//! * Unpacking array literals directly (`var [a, b] = [1, 2]` -> `var a = 1, b = 2`).
+//! * Hoisting excluded keys arrays for object rest to top level of program.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-destructuring](https://babel.dev/docs/babel-plugin-transform-destructuring).
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-destructuring>
+//! * Destructuring assignment in spec: <https://tc39.es/ecma262/#sec-destructuring-assignment>
+//! * Destructuring binding patterns in spec: <https://tc39.es/ecma262/#sec-destructuring-binding-patterns>
+
+use serde::Deserialize;
+
+use oxc_allocator::GetAddress;
+use oxc_ast::{ast::*, AstBuilder, NONE};
+use oxc_ecmascript::BoundNames;
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::{
+    number::NumberBase,
+    operator::{AssignmentOperator, BinaryOperator},
+    reference::ReferenceFlags,
+    scope::{ScopeFlags, ScopeId},
+    symbol::{SymbolFlags, SymbolId},
+};
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse, TraverseCtx};
+
+use crate::{common::helper_loader::Helper, TransformCtx};
+
+use super::spread::{create_to_array, ToArrayKind};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct DestructuringOptions {
+    pub loose: bool,
+    pub use_built_ins: bool,
+}
+
+pub struct Destructuring<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    /// Index into array values directly
+    iterable_is_array: bool,
+    /// Use `objectWithoutPropertiesLoose` helper
+    object_rest_no_symbols: bool,
+    /// Use `Object.assign` instead of `extends` helper
+    use_built_ins: bool,
+}
+
+impl<'a, 'ctx> Destructuring<'a, 'ctx> {
+    pub fn new(options: DestructuringOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            ctx,
+            iterable_is_array: options.loose || ctx.assumptions.iterable_is_array,
+            object_rest_no_symbols: options.loose || ctx.assumptions.object_rest_no_symbols,
+            use_built_ins: options.use_built_ins,
+        }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for Destructuring<'a, 'ctx> {
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        // `export var { a } = obj;` -> `var a = obj.a; export { a };`
+        if let Statement::ExportNamedDeclaration(export) = stmt {
+            if matches!(&export.declaration, Some(Declaration::VariableDeclaration(decl)) if has_pattern(decl))
+            {
+                let Some(Declaration::VariableDeclaration(decl)) = export.declaration.take() else {
+                    unreachable!()
+                };
+                let export_stmt = Self::create_export_specifiers(&decl, export.span, ctx);
+                *stmt = Statement::VariableDeclaration(decl);
+                self.ctx.statement_injector.insert_after(stmt.address(), export_stmt);
+            }
+        }
+
+        if let Statement::VariableDeclaration(decl) = stmt {
+            if has_pattern(decl) {
+                let scope_id = if decl.kind.is_var() {
+                    current_hoist_scope_id(ctx)
+                } else {
+                    ctx.current_scope_id()
+                };
+                self.transform_declaration(decl, scope_id, ctx);
+            }
+        }
+    }
+
+    fn enter_for_statement(&mut self, stmt: &mut ForStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(ForStatementInit::VariableDeclaration(decl)) = &mut stmt.init {
+            if has_pattern(decl) {
+                let scope_id = if decl.kind.is_var() {
+                    current_hoist_scope_id(ctx)
+                } else {
+                    stmt.scope_id.get().unwrap()
+                };
+                self.transform_declaration(decl, scope_id, ctx);
+            }
+        }
+    }
+
+    fn enter_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let scope_id = stmt.scope_id.get().unwrap();
+        Self::transform_for_left(&mut stmt.left, &mut stmt.body, scope_id, ctx);
+    }
+
+    fn enter_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let scope_id = stmt.scope_id.get().unwrap();
+        Self::transform_for_left(&mut stmt.left, &mut stmt.body, scope_id, ctx);
+    }
+
+    /// `catch ({ a }) {}` -> `catch (_ref) { let { a } = _ref; }`
+    ///
+    /// The new declaration is transformed when it's visited.
+    fn enter_catch_clause(&mut self, clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Some(param) = &mut clause.param else { return };
+        if param.pattern.kind.is_binding_identifier() {
+            return;
+        }
+
+        // Catch parameter bindings are declared in scope of the body
+        let body_scope_id = clause.body.scope_id.get().unwrap();
+        let binding = ctx.generate_uid(
+            "ref",
+            body_scope_id,
+            SymbolFlags::CatchVariable | SymbolFlags::FunctionScopedVariable,
+        );
+        let pattern = std::mem::replace(&mut param.pattern, binding.create_binding_pattern(ctx));
+
+        // Bindings are now declared with `let`
+        for (_, symbol_id) in collect_bindings(&pattern) {
+            ctx.symbols_mut().get_flags_mut(symbol_id).remove(SymbolFlags::CatchVariable);
+        }
+
+        let init = binding.create_read_expression(ctx);
+        let decl = create_declaration(VariableDeclarationKind::Let, pattern, init, ctx);
+        clause.body.body.insert(0, decl);
+    }
+
+    /// Transform destructuring assignment.
+    ///
+    /// `({ a, b } = obj);` -> `a = obj.a, b = obj.b;`
+    /// `x = { a, b } = obj;` -> `x = (_obj = obj, a = _obj.a, b = _obj.b, _obj);`
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::AssignmentExpression(assign) = expr else { return };
+        if assign.operator != AssignmentOperator::Assign
+            || !matches!(
+                assign.left,
+                AssignmentTarget::ObjectAssignmentTarget(_)
+                    | AssignmentTarget::ArrayAssignmentTarget(_)
+            )
+        {
+            return;
+        }
+
+        // Value of the expression is not used if it's an expression statement,
+        // unless it's the body of an arrow function `() => ({ a } = obj)`
+        let is_value_used = {
+            let mut ancestors = ctx.ancestors().skip_while(|ancestor| {
+                matches!(ancestor, Ancestor::ParenthesizedExpressionExpression(_))
+            });
+            !matches!(ancestors.next(), Some(Ancestor::ExpressionStatementExpression(_)))
+                || matches!(
+                    ancestors.nth(1),
+                    Some(Ancestor::ArrowFunctionExpressionBody(arrow)) if *arrow.expression()
+                )
+        };
+
+        let Expression::AssignmentExpression(assign) = ctx.ast.move_expression(expr) else {
+            unreachable!()
+        };
+        let AssignmentExpression { span, left, right, .. } = assign.unbox();
+
+        let mode = Mode::Assignment;
+        let mut items = vec![];
+        let (source, result) = if is_value_used {
+            let reference = self.create_ref(right, mode, &mut items, ctx);
+            (Source::Ref(reference.clone()), Some(reference))
+        } else {
+            (Source::Once(Some(right)), None)
+        };
+        let target = Target::from_assignment_target(left, ctx.ast);
+        self.push_source(target, source, mode, &mut items, ctx);
+
+        // Assignments are read from, unless the output is a single assignment in an expression statement
+        let is_read = is_value_used || items.len() > 1;
+        let mut expressions = ctx.ast.vec_with_capacity(items.len() + 1);
+        expressions.extend(items.into_iter().map(|item| match item {
+            Item::Set(Target::Assign(target), value) => {
+                if is_read {
+                    add_read_flag(&target, ctx);
+                }
+                ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value)
+            }
+            Item::Eval(expr) => expr,
+            Item::Set(..) => unreachable!(),
+        }));
+        if let Some(reference) = result {
+            expressions.push(reference.create_read_expression(ctx));
+        }
+
+        *expr = if expressions.len() == 1 {
+            expressions.pop().unwrap()
+        } else {
+            ctx.ast.expression_sequence(span, expressions)
+        };
+    }
+}
+
+impl<'a, 'ctx> Destructuring<'a, 'ctx> {
+    /// Flatten declarators with patterns into multiple declarators.
+    ///
+    /// `var { a, b } = obj;` -> `var a = obj.a, b = obj.b;`
+    fn transform_declaration(
+        &self,
+        decl: &mut VariableDeclaration<'a>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let kind = decl.kind;
+        let mode = Mode::Declaration { kind, scope_id };
+        let declarators = ctx.ast.move_vec(&mut decl.declarations);
+        let mut new_declarators = ctx.ast.vec_with_capacity(declarators.len());
+        for declarator in declarators {
+            if declarator.id.kind.is_binding_identifier() || declarator.init.is_none() {
+                new_declarators.push(declarator);
+                continue;
+            }
+
+            let VariableDeclarator { id, init, .. } = declarator;
+            let mut items = vec![];
+            self.push(Target::from_binding_pattern(id), init.unwrap(), mode, &mut items, ctx);
+            new_declarators.extend(items.into_iter().map(|item| {
+                let Item::Set(Target::Binding(pattern), value) = item else { unreachable!() };
+                ctx.ast.variable_declarator(SPAN, kind, pattern, Some(value), false)
+            }));
+        }
+        decl.declarations = new_declarators;
+    }
+
+    /// Destructure `value` into `target`, adding output to `items`.
+    fn push(
+        &self,
+        target: Target<'a>,
+        value: Expression<'a>,
+        mode: Mode,
+        items: &mut Vec<Item<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.push_source(target, Source::Once(Some(value)), mode, items, ctx);
+    }
+
+    /// Destructure value from `source` into `target`, adding output to `items`.
+    fn push_source(
+        &self,
+        target: Target<'a>,
+        mut source: Source<'a>,
+        mode: Mode,
+        items: &mut Vec<Item<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        match target {
+            Target::Binding(_) | Target::Assign(_) => {
+                items.push(Item::Set(target, source.take(ctx)));
+            }
+            Target::Default(target, default) => {
+                let value = source.take(ctx);
+                // `_tmp === void 0 ? default : _tmp`
+                let temp = self.create_temp(value, mode, items, ctx);
+                let test = ctx.ast.expression_binary(
+                    SPAN,
+                    temp.create_read_expression(ctx),
+                    BinaryOperator::StrictEquality,
+                    ctx.ast.void_0(SPAN),
+                );
+                let value = ctx.ast.expression_conditional(
+                    SPAN,
+                    test,
+                    default,
+                    temp.create_read_expression(ctx),
+                );
+                self.push(*target, value, mode, items, ctx);
+            }
+            Target::Object(properties, rest) => {
+                self.push_object(properties, rest, source, mode, items, ctx);
+            }
+            Target::Array(elements, rest) => {
+                self.push_array(elements, rest, source, mode, items, ctx);
+            }
+        }
+    }
+
+    /// `{ a, b: c, ...d } = obj` ->
+    /// `a = obj.a, c = obj.b, d = babelHelpers.objectWithoutProperties(obj, ["a", "b"])`
+    fn push_object(
+        &self,
+        properties: Vec<(PropertyKey<'a>, Target<'a>)>,
+        rest: Option<Box<Target<'a>>>,
+        mut source: Source<'a>,
+        mode: Mode,
+        items: &mut Vec<Item<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        // `{} = obj` -> `babelHelpers.objectDestructuringEmpty(obj)`
+        if properties.is_empty() && rest.is_none() {
+            let call = self.ctx.helper_call_expr(
+                Helper::ObjectDestructuringEmpty,
+                ctx.ast.vec1(Argument::from(source.take(ctx))),
+                ctx,
+            );
+            self.push_eval(call, mode, items, ctx);
+            return;
+        }
+
+        if properties.len() > 1 || rest.is_some() {
+            if let Source::Once(value) = &mut source {
+                let value = value.take().unwrap();
+                source = Source::Ref(self.create_ref(value, mode, items, ctx));
+            }
+        }
+
+        let Some(rest) = rest else {
+            for (key, target) in properties {
+                let member = create_member(source.take(ctx), key, ctx);
+                self.push(target, member, mode, items, ctx);
+            }
+            return;
+        };
+
+        // Keys need to be referenced again in list of excluded keys.
+        // Memoize computed keys, so they're only evaluated once.
+        let mut all_literal = true;
+        let mut excluded_keys = ctx.ast.vec_with_capacity(properties.len());
+        for (key, target) in properties {
+            let (key, excluded_key) = match key {
+                PropertyKey::StaticIdentifier(ident) => {
+                    let excluded_key = ctx.ast.expression_string_literal(SPAN, ident.name.clone());
+                    (PropertyKey::StaticIdentifier(ident), excluded_key)
+                }
+                PropertyKey::StringLiteral(lit) => {
+                    let excluded_key = ctx.ast.expression_string_literal(SPAN, lit.value.clone());
+                    (PropertyKey::StringLiteral(lit), excluded_key)
+                }
+                PropertyKey::NumericLiteral(lit) => {
+                    let value = ctx.ast.atom(&lit.value.to_string());
+                    let excluded_key = ctx.ast.expression_string_literal(SPAN, value);
+                    (PropertyKey::NumericLiteral(lit), excluded_key)
+                }
+                key => {
+                    all_literal = false;
+                    let binding = self.create_temp(key.into_expression(), mode, items, ctx);
+                    let key = PropertyKey::from(binding.create_read_expression(ctx));
+                    (key, binding.create_read_expression(ctx))
+                }
+            };
+            excluded_keys.push(ArrayExpressionElement::from(excluded_key));
+
+            let member = create_member(source.take(ctx), key, ctx);
+            self.push(target, member, mode, items, ctx);
+        }
+
+        let object = source.take(ctx);
+        let value = if excluded_keys.is_empty() {
+            // `babelHelpers.extends({}, (babelHelpers.objectDestructuringEmpty(obj), obj))`
+            let callee = if self.use_built_ins {
+                let object = create_global_ident("Object", ctx);
+                Expression::from(ctx.ast.member_expression_static(
+                    SPAN,
+                    object,
+                    ctx.ast.identifier_name(SPAN, "assign"),
+                    false,
+                ))
+            } else {
+                self.ctx.helper_load(Helper::Extends, ctx)
+            };
+            let check = self.ctx.helper_call_expr(
+                Helper::ObjectDestructuringEmpty,
+                ctx.ast.vec1(Argument::from(object)),
+                ctx,
+            );
+            let sequence =
+                ctx.ast.expression_sequence(SPAN, ctx.ast.vec_from_iter([check, source.take(ctx)]));
+            let arguments = ctx.ast.vec_from_iter([
+                Argument::from(ctx.ast.expression_object(SPAN, ctx.ast.vec(), None)),
+                Argument::from(sequence),
+            ]);
+            ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+        } else {
+            // `["a", "b"]` or `[a, b].map(babelHelpers.toPropertyKey)`
+            let mut keys = ctx.ast.expression_array(SPAN, excluded_keys, None);
+            if !all_literal {
+                let callee = Expression::from(ctx.ast.member_expression_static(
+                    SPAN,
+                    keys,
+                    ctx.ast.identifier_name(SPAN, "map"),
+                    false,
+                ));
+                let to_property_key = self.ctx.helper_load(Helper::ToPropertyKey, ctx);
+                keys = ctx.ast.expression_call(
+                    SPAN,
+                    callee,
+                    NONE,
+                    ctx.ast.vec1(Argument::from(to_property_key)),
+                    false,
+                );
+            }
+            let helper = if self.object_rest_no_symbols {
+                Helper::ObjectWithoutPropertiesLoose
+            } else {
+                Helper::ObjectWithoutProperties
+            };
+            let arguments = ctx.ast.vec_from_iter([Argument::from(object), Argument::from(keys)]);
+            self.ctx.helper_call_expr(helper, arguments, ctx)
+        };
+        self.push(*rest, value, mode, items, ctx);
+    }
+
+    /// `[a, , b, ...c] = arr` ->
+    /// `_arr = babelHelpers.toArray(arr), a = _arr[0], b = _arr[2], c = _arr.slice(3)`
+    fn push_array(
+        &self,
+        elements: Vec<Option<Target<'a>>>,
+        rest: Option<Box<Target<'a>>>,
+        source: Source<'a>,
+        mode: Mode,
+        items: &mut Vec<Item<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let array_ref = match source {
+            Source::Ref(reference) if self.iterable_is_array => reference,
+            Source::Ref(reference) => {
+                let value = reference.create_read_expression(ctx);
+                self.create_array_ref(value, &elements, rest.is_some(), mode, items, ctx)
+            }
+            Source::Once(value) => {
+                self.create_array_ref(value.unwrap(), &elements, rest.is_some(), mode, items, ctx)
+            }
+        };
+
+        let len = elements.len();
+        for (index, target) in elements.into_iter().enumerate() {
+            let Some(target) = target else { continue };
+            let member = Expression::from(ctx.ast.member_expression_computed(
+                SPAN,
+                array_ref.create_read_expression(ctx),
+                create_number(index, ctx),
+                false,
+            ));
+            self.push(target, member, mode, items, ctx);
+        }
+
+        if let Some(rest) = rest {
+            // `_arr.slice(3)`
+            let callee = Expression::from(ctx.ast.member_expression_static(
+                SPAN,
+                array_ref.create_read_expression(ctx),
+                ctx.ast.identifier_name(SPAN, "slice"),
+                false,
+            ));
+            let arguments = ctx.ast.vec1(Argument::from(create_number(len, ctx)));
+            let value = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+            self.push(*rest, value, mode, items, ctx);
+        }
+    }
+
+    /// Create a reference to array `value` is converted to.
+    ///
+    /// `babelHelpers.slicedToArray(arr, 2)` if it has no rest element,
+    /// `babelHelpers.toArray(arr)` if it does.
+    fn create_array_ref(
+        &self,
+        value: Expression<'a>,
+        elements: &[Option<Target<'a>>],
+        has_rest: bool,
+        mode: Mode,
+        items: &mut Vec<Item<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Ref<'a> {
+        if self.iterable_is_array {
+            return self.create_ref(value, mode, items, ctx);
+        }
+
+        // Name temp var after the original value, not the helper call
+        let binding = self.create_temp_binding(&value, mode, ctx);
+        let kind = if has_rest {
+            ToArrayKind::Rest
+        } else {
+            ToArrayKind::Count(u32::try_from(elements.len()).unwrap())
+        };
+        let array = create_to_array(self.ctx, value, kind, ctx);
+        Self::push_temp(&binding, array, mode, items, ctx);
+        Ref::Binding(binding)
+    }
+
+    /// Create a reference to `value` which can be used multiple times.
+    ///
+    /// Identifiers which are never reassigned and `this` are used as is.
+    /// Otherwise, `value` is assigned to a temp var.
+    fn create_ref(
+        &self,
+        value: Expression<'a>,
+        mode: Mode,
+        items: &mut Vec<Item<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Ref<'a> {
+        match value {
+            Expression::ThisExpression(_) => Ref::This,
+            Expression::Identifier(ident) if ctx.is_static(&value) => {
+                let symbol_id =
+                    ctx.symbols().get_reference(ident.reference_id().unwrap()).symbol_id().unwrap();
+                ctx.delete_reference_for_identifier(&ident);
+                Ref::Binding(BoundIdentifier::new(ident.name.clone(), symbol_id))
+            }
+            value => Ref::Binding(self.create_temp(value, mode, items, ctx)),
+        }
+    }
+
+    /// Create temp var and assign `value` to it.
+    fn create_temp(
+        &self,
+        value: Expression<'a>,
+        mode: Mode,
+        items: &mut Vec<Item<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        let binding = self.create_temp_binding(&value, mode, ctx);
+        Self::push_temp(&binding, value, mode, items, ctx);
+        binding
+    }
+
+    /// Create temp var, with name based on `node`.
+    fn create_temp_binding(
+        &self,
+        node: &Expression<'a>,
+        mode: Mode,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        match mode {
+            Mode::Declaration { kind, scope_id } => {
+                let flags = match kind {
+                    VariableDeclarationKind::Var => SymbolFlags::FunctionScopedVariable,
+                    VariableDeclarationKind::Const => {
+                        SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable
+                    }
+                    _ => SymbolFlags::BlockScopedVariable,
+                };
+                ctx.generate_uid_based_on_node(node, scope_id, flags)
+            }
+            Mode::Assignment => {
+                let scope_id = current_hoist_scope_id(ctx);
+                let binding = ctx.generate_uid_based_on_node(
+                    node,
+                    scope_id,
+                    SymbolFlags::FunctionScopedVariable,
+                );
+                self.ctx.var_declarations.insert(&binding, None, ctx);
+                binding
+            }
+        }
+    }
+
+    /// Assign `value` to temp var.
+    fn push_temp(
+        binding: &BoundIdentifier<'a>,
+        value: Expression<'a>,
+        mode: Mode,
+        items: &mut Vec<Item<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let target = match mode {
+            Mode::Declaration { .. } => Target::Binding(binding.create_binding_pattern(ctx)),
+            Mode::Assignment => Target::Assign(binding.create_write_target(ctx)),
+        };
+        items.push(Item::Set(target, value));
+    }
+
+    /// Add an expression to be evaluated.
+    /// In a declaration, it is assigned to a temp var, as declarations can only contain declarators.
+    fn push_eval(
+        &self,
+        expr: Expression<'a>,
+        mode: Mode,
+        items: &mut Vec<Item<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        match mode {
+            Mode::Declaration { .. } => {
+                self.create_temp(expr, mode, items, ctx);
+            }
+            Mode::Assignment => items.push(Item::Eval(expr)),
+        }
+    }
+
+    /// Move destructuring in left side of `for-in` / `for-of` loop into the loop body.
+    ///
+    /// * `for (const { a } of arr) {}` -> `for (const _ref of arr) { const { a } = _ref; }`
+    /// * `for ({ a } of arr) {}` -> `for (var _ref of arr) { ({ a } = _ref); }`
+    ///
+    /// The new statement is transformed when it's visited.
+    fn transform_for_left(
+        left: &mut ForStatementLeft<'a>,
+        body: &mut Statement<'a>,
+        for_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        match left {
+            ForStatementLeft::VariableDeclaration(decl) => {
+                let kind = decl.kind;
+                let declarator = decl.declarations.first_mut().unwrap();
+                if declarator.id.kind.is_binding_identifier() {
+                    return;
+                }
+
+                let binding = if kind.is_var() {
+                    let scope_id = current_hoist_scope_id(ctx);
+                    ctx.generate_uid("ref", scope_id, SymbolFlags::FunctionScopedVariable)
+                } else {
+                    let flags = if kind == VariableDeclarationKind::Const {
+                        SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable
+                    } else {
+                        SymbolFlags::BlockScopedVariable
+                    };
+                    ctx.generate_uid("ref", for_scope_id, flags)
+                };
+                let pattern =
+                    std::mem::replace(&mut declarator.id, binding.create_binding_pattern(ctx));
+                let bindings = collect_bindings(&pattern);
+                let init = binding.create_read_expression(ctx);
+                let stmt = create_declaration(kind, pattern, init, ctx);
+                let body_scope_id =
+                    Self::insert_into_for_body(body, stmt, for_scope_id, &bindings, ctx);
+
+                // Lexical bindings are now declared in loop body
+                if kind.is_lexical() {
+                    for (name, symbol_id) in bindings {
+                        ctx.scopes_mut().move_binding(for_scope_id, body_scope_id, &name);
+                        ctx.symbols_mut().set_scope_id(symbol_id, body_scope_id);
+                    }
+                }
+            }
+            ForStatementLeft::ObjectAssignmentTarget(_)
+            | ForStatementLeft::ArrayAssignmentTarget(_) => {
+                let scope_id = current_hoist_scope_id(ctx);
+                let binding =
+                    ctx.generate_uid("ref", scope_id, SymbolFlags::FunctionScopedVariable);
+                let kind = VariableDeclarationKind::Var;
+                let declarator = ctx.ast.variable_declarator(
+                    SPAN,
+                    kind,
+                    binding.create_binding_pattern(ctx),
+                    None,
+                    false,
+                );
+                let new_left = ForStatementLeft::VariableDeclaration(
+                    ctx.ast.alloc_variable_declaration(SPAN, kind, ctx.ast.vec1(declarator), false),
+                );
+                let target = std::mem::replace(left, new_left).into_assignment_target();
+                let assignment = ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    target,
+                    binding.create_read_expression(ctx),
+                );
+                let stmt = ctx.ast.statement_expression(SPAN, assignment);
+                Self::insert_into_for_body(body, stmt, for_scope_id, &[], ctx);
+            }
+            _ => {}
+        }
+    }
+
+    /// Insert statement at top of loop body, wrapping body in a block if required.
+    /// Returns `ScopeId` of the block.
+    pub(crate) fn insert_into_for_body(
+        body: &mut Statement<'a>,
+        stmt: Statement<'a>,
+        for_scope_id: ScopeId,
+        bindings: &[(Atom<'a>, SymbolId)],
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ScopeId {
+        // Body block can be used, unless it declares bindings with same names as the new statement
+        if let Statement::BlockStatement(block) = body {
+            let block_scope_id = block.scope_id.get().unwrap();
+            if !bindings.iter().any(|(name, _)| ctx.scopes().has_binding(block_scope_id, name)) {
+                block.body.insert(0, stmt);
+                return block_scope_id;
+            }
+        }
+
+        let scope_id = ctx.insert_scope_below_statement(body, ScopeFlags::empty());
+        ctx.scopes_mut().change_parent_id(scope_id, Some(for_scope_id));
+        // Body's scopes were only removed from children of current scope, which may not be
+        // the loop's scope
+        if ctx.scopes().has_child_ids() {
+            let child_ids = ctx.scopes().get_child_ids(scope_id).to_vec();
+            ctx.scopes_mut()
+                .get_child_ids_mut(for_scope_id)
+                .retain(|scope_id| !child_ids.contains(scope_id));
+        }
+        let old_body = ctx.ast.move_statement(body);
+        *body = Statement::BlockStatement(ctx.ast.alloc_block_statement_with_scope_id(
+            SPAN,
+            ctx.ast.vec_from_iter([stmt, old_body]),
+            scope_id,
+        ));
+        scope_id
+    }
+
+    /// `export { a, b };`
+    pub(crate) fn create_export_specifiers(
+        decl: &VariableDeclaration<'a>,
+        span: Span,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let mut bindings = vec![];
+        for declarator in &decl.declarations {
+            bindings.extend(collect_bindings(&declarator.id));
+        }
+        let specifiers = ctx.ast.vec_from_iter(bindings.into_iter().map(|(name, symbol_id)| {
+            let binding = BoundIdentifier::new(name.clone(), symbol_id);
+            let local = ModuleExportName::IdentifierReference(binding.create_read_reference(ctx));
+            let exported = ModuleExportName::IdentifierName(ctx.ast.identifier_name(SPAN, name));
+            ctx.ast.export_specifier(SPAN, local, exported, ImportOrExportKind::Value)
+        }));
+        Statement::ExportNamedDeclaration(ctx.ast.alloc_export_named_declaration(
+            span,
+            None,
+            specifiers,
+            None,
+            ImportOrExportKind::Value,
+            NONE,
+        ))
+    }
+}
+
+/// Mode of destructuring.
+#[derive(Clone, Copy)]
+enum Mode {
+    /// Variable declaration. Temp vars are declared as declarators of same kind in `scope_id`.
+    Declaration { kind: VariableDeclarationKind, scope_id: ScopeId },
+    /// Assignment expression. Temp vars are declared with `var` at top of enclosing statement block.
+    Assignment,
+}
+
+/// Destructuring target.
+///
+/// Binding patterns and assignment targets are converted to this common form,
+/// so both can be handled by the same logic.
+enum Target<'a> {
+    /// Binding identifier (`BindingPattern` containing a `BindingIdentifier`)
+    Binding(BindingPattern<'a>),
+    /// Simple assignment target e.g. `a`, `a.b`
+    Assign(AssignmentTarget<'a>),
+    /// Target with default value
+    Default(Box<Target<'a>>, Expression<'a>),
+    /// Object pattern. Properties and rest.
+    Object(Vec<(PropertyKey<'a>, Target<'a>)>, Option<Box<Target<'a>>>),
+    /// Array pattern. Elements (`None` for holes) and rest.
+    Array(Vec<Option<Target<'a>>>, Option<Box<Target<'a>>>),
+}
+
+impl<'a> Target<'a> {
+    fn from_binding_pattern(pattern: BindingPattern<'a>) -> Self {
+        match pattern.kind {
+            BindingPatternKind::BindingIdentifier(_) => Self::Binding(pattern),
+            BindingPatternKind::AssignmentPattern(assign) => {
+                let AssignmentPattern { left, right, .. } = assign.unbox();
+                Self::Default(Box::new(Self::from_binding_pattern(left)), right)
+            }
+            BindingPatternKind::ObjectPattern(object) => {
+                let ObjectPattern { properties, rest, .. } = object.unbox();
+                let properties = properties
+                    .into_iter()
+                    .map(|prop| (prop.key, Self::from_binding_pattern(prop.value)))
+                    .collect();
+                let rest =
+                    rest.map(|rest| Box::new(Self::from_binding_pattern(rest.unbox().argument)));
+                Self::Object(properties, rest)
+            }
+            BindingPatternKind::ArrayPattern(array) => {
+                let ArrayPattern { elements, rest, .. } = array.unbox();
+                let elements = elements
+                    .into_iter()
+                    .map(|element| element.map(Self::from_binding_pattern))
+                    .collect();
+                let rest =
+                    rest.map(|rest| Box::new(Self::from_binding_pattern(rest.unbox().argument)));
+                Self::Array(elements, rest)
+            }
+        }
+    }
+
+    fn from_assignment_target(target: AssignmentTarget<'a>, ast: AstBuilder<'a>) -> Self {
+        match target {
+            AssignmentTarget::ObjectAssignmentTarget(object) => {
+                let ObjectAssignmentTarget { properties, rest, .. } = object.unbox();
+                let properties = properties
+                    .into_iter()
+                    .map(|prop| match prop {
+                        AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(prop) => {
+                            // `{ a = 1 } = obj`
+                            let AssignmentTargetPropertyIdentifier { binding, init, .. } =
+                                prop.unbox();
+                            let key = ast
+                                .property_key_identifier_name(binding.span, binding.name.clone());
+                            let target = Self::Assign(
+                                AssignmentTarget::AssignmentTargetIdentifier(ast.alloc(binding)),
+                            );
+                            let target = match init {
+                                Some(init) => Self::Default(Box::new(target), init),
+                                None => target,
+                            };
+                            (key, target)
+                        }
+                        AssignmentTargetProperty::AssignmentTargetPropertyProperty(prop) => {
+                            // `{ a: b } = obj`
+                            let AssignmentTargetPropertyProperty { name, binding, .. } =
+                                prop.unbox();
+                            (name, Self::from_assignment_target_maybe_default(binding, ast))
+                        }
+                    })
+                    .collect();
+                let rest =
+                    rest.map(|rest| Box::new(Self::from_assignment_target(rest.target, ast)));
+                Self::Object(properties, rest)
+            }
+            AssignmentTarget::ArrayAssignmentTarget(array) => {
+                let ArrayAssignmentTarget { elements, rest, .. } = array.unbox();
+                let elements = elements
+                    .into_iter()
+                    .map(|element| {
+                        element
+                            .map(|element| Self::from_assignment_target_maybe_default(element, ast))
+                    })
+                    .collect();
+                let rest =
+                    rest.map(|rest| Box::new(Self::from_assignment_target(rest.target, ast)));
+                Self::Array(elements, rest)
+            }
+            target => Self::Assign(target),
+        }
+    }
+
+    fn from_assignment_target_maybe_default(
+        target: AssignmentTargetMaybeDefault<'a>,
+        ast: AstBuilder<'a>,
+    ) -> Self {
+        match target {
+            AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(target) => {
+                let AssignmentTargetWithDefault { binding, init, .. } = target.unbox();
+                Self::Default(Box::new(Self::from_assignment_target(binding, ast)), init)
+            }
+            target => Self::from_assignment_target(target.into_assignment_target(), ast),
+        }
+    }
+}
+
+/// Output of destructuring.
+enum Item<'a> {
+    /// Declarator `target = value` (`Target::Binding`), or assignment `target = value` (`Target::Assign`)
+    Set(Target<'a>, Expression<'a>),
+    /// Expression to evaluate
+    Eval(Expression<'a>),
+}
+
+/// Reference to a value which can be used multiple times.
+#[derive(Clone)]
+enum Ref<'a> {
+    Binding(BoundIdentifier<'a>),
+    This,
+}
+
+impl<'a> Ref<'a> {
+    fn create_read_expression(&self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        match self {
+            Self::Binding(binding) => binding.create_read_expression(ctx),
+            Self::This => ctx.ast.expression_this(SPAN),
+        }
+    }
+}
+
+/// Source of value being destructured.
+/// Either a value which is only used once, or a [`Ref`] which can be used multiple times.
+enum Source<'a> {
+    Ref(Ref<'a>),
+    Once(Option<Expression<'a>>),
+}
+
+impl<'a> Source<'a> {
+    fn take(&mut self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        match self {
+            Self::Ref(reference) => reference.create_read_expression(ctx),
+            Self::Once(value) => value.take().unwrap(),
+        }
+    }
+}
+
+/// Add `ReferenceFlags::Read` to reference of an identifier assignment target.
+fn add_read_flag(target: &AssignmentTarget, ctx: &mut TraverseCtx) {
+    if let AssignmentTarget::AssignmentTargetIdentifier(ident) = target {
+        if let Some(reference_id) = ident.reference_id.get() {
+            ctx.symbols_mut()
+                .get_reference_mut(reference_id)
+                .flags_mut()
+                .insert(ReferenceFlags::Read);
+        }
+    }
+}
+
+/// Check if any declarator in declaration has a destructuring pattern.
+fn has_pattern(decl: &VariableDeclaration) -> bool {
+    decl.declarations.iter().any(|declarator| !declarator.id.kind.is_binding_identifier())
+}
+
+/// Get scope which `var` declarations are hoisted to.
+pub(crate) fn current_hoist_scope_id(ctx: &TraverseCtx) -> ScopeId {
+    ctx.ancestor_scopes().find(|&scope_id| ctx.scopes().get_flags(scope_id).is_var()).unwrap()
+}
+
+/// Collect names and `SymbolId`s of bindings in a pattern.
+pub(crate) fn collect_bindings<'a>(pattern: &BindingPattern<'a>) -> Vec<(Atom<'a>, SymbolId)> {
+    let mut bindings = vec![];
+    pattern.bound_names(&mut |ident| {
+        bindings.push((ident.name.clone(), ident.symbol_id.get().unwrap()));
+    });
+    bindings
+}
+
+/// Convert a binding pattern to an equivalent assignment target.
+///
+/// `{ a, b: [c = 1], ...d }` -> `{ a: a, b: [c = 1], ...d }`
+///
+/// `convert_ident` converts each binding identifier in the pattern to an assignment target.
+pub(crate) fn binding_pattern_to_assignment_target<'a>(
+    pattern: BindingPattern<'a>,
+    ast: AstBuilder<'a>,
+    convert_ident: &mut impl FnMut(&BindingIdentifier<'a>) -> AssignmentTarget<'a>,
+) -> AssignmentTarget<'a> {
+    match pattern.kind {
+        BindingPatternKind::BindingIdentifier(ident) => convert_ident(&ident),
+        BindingPatternKind::ObjectPattern(object) => {
+            let ObjectPattern { span, properties, rest } = object.unbox();
+            let properties = ast.vec_from_iter(properties.into_iter().map(|property| {
+                let binding = binding_pattern_to_maybe_default(property.value, ast, convert_ident);
+                ast.assignment_target_property_assignment_target_property_property(
+                    property.span,
+                    property.key,
+                    binding,
+                )
+            }));
+            let rest =
+                rest.map(|rest| binding_rest_to_assignment_rest(rest.unbox(), ast, convert_ident));
+            AssignmentTarget::ObjectAssignmentTarget(
+                ast.alloc_object_assignment_target(span, properties, rest),
+            )
+        }
+        BindingPatternKind::ArrayPattern(array) => {
+            let ArrayPattern { span, elements, rest } = array.unbox();
+            let elements = ast.vec_from_iter(elements.into_iter().map(|element| {
+                element.map(|element| binding_pattern_to_maybe_default(element, ast, convert_ident))
+            }));
+            let rest =
+                rest.map(|rest| binding_rest_to_assignment_rest(rest.unbox(), ast, convert_ident));
+            AssignmentTarget::ArrayAssignmentTarget(
+                ast.alloc_array_assignment_target(span, elements, rest, None),
+            )
+        }
+        BindingPatternKind::AssignmentPattern(_) => unreachable!(),
+    }
+}
+
+fn binding_pattern_to_maybe_default<'a>(
+    pattern: BindingPattern<'a>,
+    ast: AstBuilder<'a>,
+    convert_ident: &mut impl FnMut(&BindingIdentifier<'a>) -> AssignmentTarget<'a>,
+) -> AssignmentTargetMaybeDefault<'a> {
+    if let BindingPatternKind::AssignmentPattern(assign) = pattern.kind {
+        let AssignmentPattern { span, left, right } = assign.unbox();
+        let binding = binding_pattern_to_assignment_target(left, ast, convert_ident);
+        ast.assignment_target_maybe_default_assignment_target_with_default(span, binding, right)
+    } else {
+        AssignmentTargetMaybeDefault::from(binding_pattern_to_assignment_target(
+            pattern,
+            ast,
+            convert_ident,
+        ))
+    }
+}
+
+fn binding_rest_to_assignment_rest<'a>(
+    rest: BindingRestElement<'a>,
+    ast: AstBuilder<'a>,
+    convert_ident: &mut impl FnMut(&BindingIdentifier<'a>) -> AssignmentTarget<'a>,
+) -> AssignmentTargetRest<'a> {
+    let target = binding_pattern_to_assignment_target(rest.argument, ast, convert_ident);
+    ast.assignment_target_rest(rest.span, target)
+}
+
+/// Create `<kind> <pattern> = <init>;`.
+pub(super) fn create_declaration<'a>(
+    kind: VariableDeclarationKind,
+    pattern: BindingPattern<'a>,
+    init: Expression<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> Statement<'a> {
+    let declarator = ctx.ast.variable_declarator(SPAN, kind, pattern, Some(init), false);
+    Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+        SPAN,
+        kind,
+        ctx.ast.vec1(declarator),
+        false,
+    ))
+}
+
+/// `object.key` or `object[key]`
+fn create_member<'a>(
+    object: Expression<'a>,
+    key: PropertyKey<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    match key {
+        PropertyKey::StaticIdentifier(ident) => {
+            Expression::from(ctx.ast.member_expression_static(SPAN, object, ident.unbox(), false))
+        }
+        // Private identifiers are not valid in patterns
+        PropertyKey::PrivateIdentifier(_) => unreachable!(),
+        key => Expression::from(ctx.ast.member_expression_computed(
+            SPAN,
+            object,
+            key.into_expression(),
+            false,
+        )),
+    }
+}
+
+/// Create `IdentifierReference` for a global, or a local binding if it's shadowed.
+fn create_global_ident<'a>(name: &'static str, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+    let symbol_id = ctx.scopes().find_binding(ctx.current_scope_id(), name);
+    let ident = ctx.create_reference_id(SPAN, Atom::from(name), symbol_id, ReferenceFlags::Read);
+    ctx.ast.expression_from_identifier_reference(ident)
+}
+
+fn create_number<'a>(value: usize, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+    #[expect(clippy::cast_precision_loss)]
+    let number = value as f64;
+    ctx.ast.expression_numeric_literal(
+        SPAN,
+        number,
+        ctx.ast.str(&value.to_string()),
+        NumberBase::Decimal,
+    )
+}
@@ -0,0 +1,517 @@
+//! ES2015: For Of
+//!
+//! This plugin transforms `for...of` loops to `for` loops using an iterator.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! for (const x of arr) {
+//!   console.log(x);
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _iterator = babelHelpers.createForOfIteratorHelper(arr), _step;
+//! try {
+//!   for (_iterator.s(); !(_step = _iterator.n()).done;) {
+//!     const x = _step.value;
+//!     console.log(x);
+//!   }
+//! } catch (err) {
+//!   _iterator.e(err);
+//! } finally {
+//!   _iterator.f();
+//! }
+//! ```
+//!
+//! ## Options
+//!
+//! ### `loose`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Use a simpler iterator which does not close the iterator if the loop exits early
+//! (e.g. `break` or an error thrown in loop body).
+//! Also enabled by the `skipForOfIteratorClosing` assumption.
+//!
+//! Input:
+//! ```js
+//! for (const x of arr) {}
+//! ```
+//!
+//! Output:
+//! ```js
+//! for (var _iterator = babelHelpers.createForOfIteratorHelperLoose(arr), _step; !(_step = _iterator()).done;) {
+//!   const x = _step.value;
+//! }
+//! ```
+//!
+//! ### `assumeArray`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Assume that all iterables are arrays, and iterate over them with an index.
+//! Iterables which are array literals are always iterated over with an index.
+//! Also enabled by the `iterableIsArray` assumption.
+//!
+//! Input:
+//! ```js
+//! for (const x of arr) {}
+//! ```
+//!
+//! Output:
+//! ```js
+//! for (var _i = 0, _arr = arr; _i < _arr.length; _i++) {
+//!   const x = _arr[_i];
+//! }
+//! ```
+//!
+//! ### `allowArrayLike`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Allow iterating over array-like objects which are not iterable (e.g. `arguments`).
+//! Also enabled by the `arrayLikeIsIterable` assumption.
+//!
+//! ## Missing features
+//!
+//! Implementation is incomplete at present. Still TODO:
+//!
+//! * `for await...of` loops are not transformed (they are transformed by the ES2018 async generator
+//!   functions plugin).
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-for-of](https://babel.dev/docs/babel-plugin-transform-for-of).
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-for-of>
+//! * For-of statement in spec: <https://tc39.es/ecma262/#sec-for-in-and-for-of-statements>
+
+use serde::Deserialize;
+
+use oxc_allocator::{GetAddress, Vec as ArenaVec};
+use oxc_ast::{ast::*, NONE};
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::{
+    number::NumberBase,
+    operator::{AssignmentOperator, BinaryOperator, UnaryOperator, UpdateOperator},
+    scope::{ScopeFlags, ScopeId},
+    symbol::SymbolFlags,
+};
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse, TraverseCtx};
+
+use crate::{common::helper_loader::Helper, TransformCtx};
+
+use super::destructuring::{
+    collect_bindings, create_declaration, current_hoist_scope_id, Destructuring,
+};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct ForOfOptions {
+    pub loose: bool,
+    pub assume_array: bool,
+    pub allow_array_like: bool,
+}
+
+pub struct ForOf<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    options: ForOfOptions,
+}
+
+impl<'a, 'ctx> ForOf<'a, 'ctx> {
+    pub fn new(mut options: ForOfOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        options.loose |= ctx.assumptions.skip_for_of_iterator_closing;
+        options.assume_array |= ctx.assumptions.iterable_is_array;
+        options.allow_array_like |= ctx.assumptions.array_like_is_iterable;
+        Self { ctx, options }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for ForOf<'a, 'ctx> {
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        // Labelled loops are handled at the outermost label, so the labels can be kept on the loop
+        // if it's wrapped in a `try` statement
+        if matches!(ctx.parent(), Ancestor::LabeledStatementBody(_)) {
+            return;
+        }
+        let mut target = &mut *stmt;
+        while let Statement::LabeledStatement(labeled) = target {
+            target = &mut labeled.body;
+        }
+        let Statement::ForOfStatement(for_of) = target else { return };
+        if for_of.r#await {
+            return;
+        }
+
+        if self.options.assume_array || matches!(for_of.right, Expression::ArrayExpression(_)) {
+            *target = Self::transform_array(for_of, ctx);
+        } else if self.options.loose {
+            *target = self.transform_loose(for_of, ctx);
+        } else {
+            let for_scope_id = for_of.scope_id.get().unwrap();
+            let (loop_stmt, decl, iterator) = self.transform_spec(for_of, ctx);
+            *target = loop_stmt;
+            self.wrap_in_try(stmt, decl, &iterator, for_scope_id, ctx);
+        }
+    }
+}
+
+impl<'a, 'ctx> ForOf<'a, 'ctx> {
+    /// `for (const x of arr) {}` -> `for (var _i = 0, _arr = arr; _i < _arr.length; _i++) { const x = _arr[_i]; }`
+    fn transform_array(
+        for_of: &mut ForOfStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let scope_id = current_hoist_scope_id(ctx);
+        let index = ctx.generate_uid("i", scope_id, SymbolFlags::FunctionScopedVariable);
+        let array = ctx.generate_uid("arr", scope_id, SymbolFlags::FunctionScopedVariable);
+
+        // `var _i = 0, _arr = arr`
+        let right = ctx.ast.move_expression(&mut for_of.right);
+        let zero = ctx.ast.expression_numeric_literal(SPAN, 0.0, "0", NumberBase::Decimal);
+        let init = create_var_declaration(
+            ctx.ast.vec_from_iter([
+                create_declarator(&index, Some(zero), ctx),
+                create_declarator(&array, Some(right), ctx),
+            ]),
+            ctx,
+        );
+
+        // `_i < _arr.length`
+        let length = create_static_member(array.create_read_expression(ctx), "length", ctx);
+        let test = ctx.ast.expression_binary(
+            SPAN,
+            index.create_read_expression(ctx),
+            BinaryOperator::LessThan,
+            length,
+        );
+
+        // `_i++`
+        let argument = index.create_read_write_reference(ctx);
+        let update = ctx.ast.expression_update(
+            SPAN,
+            UpdateOperator::Increment,
+            false,
+            SimpleAssignmentTarget::AssignmentTargetIdentifier(ctx.alloc(argument)),
+        );
+
+        // `_arr[_i]`
+        let value = Expression::from(ctx.ast.member_expression_computed(
+            SPAN,
+            array.create_read_expression(ctx),
+            index.create_read_expression(ctx),
+            false,
+        ));
+
+        Self::create_for_statement(for_of, Some(init), test, Some(update), value, ctx)
+    }
+
+    /// `for (const x of arr) {}`
+    /// -> `for (var _iterator = babelHelpers.createForOfIteratorHelperLoose(arr), _step; !(_step = _iterator()).done;) { const x = _step.value; }`
+    fn transform_loose(
+        &self,
+        for_of: &mut ForOfStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let scope_id = current_hoist_scope_id(ctx);
+        let iterator = ctx.generate_uid("iterator", scope_id, SymbolFlags::FunctionScopedVariable);
+        let step = ctx.generate_uid("step", scope_id, SymbolFlags::FunctionScopedVariable);
+
+        // `var _iterator = babelHelpers.createForOfIteratorHelperLoose(arr), _step`
+        let helper = self.create_helper_call(Helper::CreateForOfIteratorHelperLoose, for_of, ctx);
+        let init = create_var_declaration(
+            ctx.ast.vec_from_iter([
+                create_declarator(&iterator, Some(helper), ctx),
+                create_declarator(&step, None, ctx),
+            ]),
+            ctx,
+        );
+
+        // `!(_step = _iterator()).done`
+        let next = ctx.ast.expression_call(
+            SPAN,
+            iterator.create_read_expression(ctx),
+            NONE,
+            ctx.ast.vec(),
+            false,
+        );
+        let test = create_step_test(&step, next, ctx);
+
+        let value = create_static_member(step.create_read_expression(ctx), "value", ctx);
+        Self::create_for_statement(for_of, Some(init), test, None, value, ctx)
+    }
+
+    /// `for (const x of arr) {}`
+    /// -> `for (_iterator.s(); !(_step = _iterator.n()).done;) { const x = _step.value; }`
+    ///
+    /// Returns the loop, declaration of `_iterator` and `_step`, and binding for `_iterator`.
+    fn transform_spec(
+        &self,
+        for_of: &mut ForOfStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Statement<'a>, Statement<'a>, BoundIdentifier<'a>) {
+        let scope_id = current_hoist_scope_id(ctx);
+        let iterator = ctx.generate_uid("iterator", scope_id, SymbolFlags::FunctionScopedVariable);
+        let step = ctx.generate_uid("step", scope_id, SymbolFlags::FunctionScopedVariable);
+
+        // `var _iterator = babelHelpers.createForOfIteratorHelper(arr), _step;`
+        let helper = self.create_helper_call(Helper::CreateForOfIteratorHelper, for_of, ctx);
+        let decl = Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            ctx.ast.vec_from_iter([
+                create_declarator(&iterator, Some(helper), ctx),
+                create_declarator(&step, None, ctx),
+            ]),
+            false,
+        ));
+
+        // `_iterator.s()`
+        let init = create_iterator_call(&iterator, "s", ctx.ast.vec(), ctx);
+        let init = ForStatementInit::from(init);
+
+        // `!(_step = _iterator.n()).done`
+        let next = create_iterator_call(&iterator, "n", ctx.ast.vec(), ctx);
+        let test = create_step_test(&step, next, ctx);
+
+        let value = create_static_member(step.create_read_expression(ctx), "value", ctx);
+        let loop_stmt = Self::create_for_statement(for_of, Some(init), test, None, value, ctx);
+        (loop_stmt, decl, iterator)
+    }
+
+    /// `babelHelpers.createForOfIteratorHelper(arr)`
+    fn create_helper_call(
+        &self,
+        helper: Helper,
+        for_of: &mut ForOfStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let right = ctx.ast.move_expression(&mut for_of.right);
+        let mut arguments = ctx.ast.vec1(Argument::from(right));
+        if self.options.allow_array_like {
+            arguments.push(Argument::from(ctx.ast.expression_boolean_literal(SPAN, true)));
+        }
+        self.ctx.helper_call_expr(helper, arguments, ctx)
+    }
+
+    /// Create `for` statement, with left of `for...of` statement assigned `value` at start of body.
+    pub(crate) fn create_for_statement(
+        for_of: &mut ForOfStatement<'a>,
+        init: Option<ForStatementInit<'a>>,
+        test: Expression<'a>,
+        update: Option<Expression<'a>>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let for_scope_id = for_of.scope_id.get().unwrap();
+        let mut body = ctx.ast.move_statement(&mut for_of.body);
+
+        match &mut for_of.left {
+            ForStatementLeft::VariableDeclaration(decl) => {
+                // `const x = _step.value;`
+                let kind = decl.kind;
+                let declarator = ctx.ast.move_vec(&mut decl.declarations).into_iter().next();
+                let pattern = declarator.unwrap().id;
+                let bindings = collect_bindings(&pattern);
+                let stmt = create_declaration(kind, pattern, value, ctx);
+                let body_scope_id = Destructuring::insert_into_for_body(
+                    &mut body,
+                    stmt,
+                    for_scope_id,
+                    &bindings,
+                    ctx,
+                );
+
+                // Lexical bindings are now declared in loop body
+                if kind.is_lexical() {
+                    for (name, symbol_id) in bindings {
+                        ctx.scopes_mut().move_binding(for_scope_id, body_scope_id, &name);
+                        ctx.symbols_mut().set_scope_id(symbol_id, body_scope_id);
+                    }
+                }
+            }
+            left => {
+                // `x = _step.value;`
+                let target = ctx.ast.move_assignment_target(left.to_assignment_target_mut());
+                let assignment =
+                    ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+                let stmt = ctx.ast.statement_expression(SPAN, assignment);
+                Destructuring::insert_into_for_body(&mut body, stmt, for_scope_id, &[], ctx);
+            }
+        }
+
+        Statement::ForStatement(ctx.ast.alloc_for_statement_with_scope_id(
+            for_of.span,
+            init,
+            Some(test),
+            update,
+            body,
+            for_scope_id,
+        ))
+    }
+
+    /// Wrap loop in `try` statement which closes the iterator.
+    ///
+    /// ```js
+    /// var _iterator = babelHelpers.createForOfIteratorHelper(arr), _step;
+    /// try {
+    ///   for (...) {}
+    /// } catch (err) {
+    ///   _iterator.e(err);
+    /// } finally {
+    ///   _iterator.f();
+    /// }
+    /// ```
+    fn wrap_in_try(
+        &self,
+        stmt: &mut Statement<'a>,
+        decl: Statement<'a>,
+        iterator: &BoundIdentifier<'a>,
+        for_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let in_statement_list = matches!(
+            ctx.parent(),
+            Ancestor::ProgramBody(_)
+                | Ancestor::BlockStatementBody(_)
+                | Ancestor::FunctionBodyStatements(_)
+                | Ancestor::SwitchCaseConsequent(_)
+                | Ancestor::StaticBlockBody(_)
+                | Ancestor::TSModuleBlockBody(_)
+        );
+
+        // If loop is not in a statement list, it's wrapped in a block with the declaration
+        let wrapper_scope_id = if in_statement_list {
+            None
+        } else {
+            Some(ctx.insert_scope_below_statement(stmt, ScopeFlags::empty()))
+        };
+        let parent_scope_id = wrapper_scope_id.unwrap_or_else(|| ctx.current_scope_id());
+
+        // `try { for (...) {} }`
+        let block_scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+        ctx.scopes_mut().change_parent_id(for_scope_id, Some(block_scope_id));
+        let loop_stmt = ctx.ast.move_statement(stmt);
+        let block = ctx.ast.alloc_block_statement_with_scope_id(
+            SPAN,
+            ctx.ast.vec1(loop_stmt),
+            block_scope_id,
+        );
+
+        // `catch (err) { _iterator.e(err); }`
+        // Catch param binding is in scope of catch body, same as in `SemanticBuilder`
+        let catch_scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::CatchClause);
+        let catch_body_scope_id = ctx.create_child_scope(catch_scope_id, ScopeFlags::empty());
+        let err = ctx.generate_binding(
+            Atom::from("err"),
+            catch_body_scope_id,
+            SymbolFlags::FunctionScopedVariable | SymbolFlags::CatchVariable,
+        );
+        let param = ctx.ast.catch_parameter(SPAN, err.create_binding_pattern(ctx));
+        let argument = Argument::from(err.create_read_expression(ctx));
+        let call = create_iterator_call(iterator, "e", ctx.ast.vec1(argument), ctx);
+        let catch_body = ctx.ast.alloc_block_statement_with_scope_id(
+            SPAN,
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, call)),
+            catch_body_scope_id,
+        );
+        let handler =
+            ctx.ast.alloc_catch_clause_with_scope_id(SPAN, Some(param), catch_body, catch_scope_id);
+
+        // `finally { _iterator.f(); }`
+        let call = create_iterator_call(iterator, "f", ctx.ast.vec(), ctx);
+        let finally_scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+        let finalizer = ctx.ast.alloc_block_statement_with_scope_id(
+            SPAN,
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, call)),
+            finally_scope_id,
+        );
+
+        let try_stmt = ctx.ast.statement_try(SPAN, block, Some(handler), Some(finalizer));
+        if let Some(wrapper_scope_id) = wrapper_scope_id {
+            *stmt = Statement::BlockStatement(ctx.ast.alloc_block_statement_with_scope_id(
+                SPAN,
+                ctx.ast.vec_from_iter([decl, try_stmt]),
+                wrapper_scope_id,
+            ));
+        } else {
+            *stmt = try_stmt;
+            self.ctx.statement_injector.insert_before(stmt.address(), decl);
+        }
+    }
+}
+
+/// `!(_step = next).done`
+pub(crate) fn create_step_test<'a>(
+    step: &BoundIdentifier<'a>,
+    next: Expression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let assignment = ctx.ast.expression_assignment(
+        SPAN,
+        AssignmentOperator::Assign,
+        step.create_read_write_target(ctx),
+        next,
+    );
+    let done =
+        create_static_member(ctx.ast.expression_parenthesized(SPAN, assignment), "done", ctx);
+    ctx.ast.expression_unary(SPAN, UnaryOperator::LogicalNot, done)
+}
+
+/// `_iterator.method(...arguments)`
+fn create_iterator_call<'a>(
+    iterator: &BoundIdentifier<'a>,
+    method: &'static str,
+    arguments: ArenaVec<'a, Argument<'a>>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let callee = create_static_member(iterator.create_read_expression(ctx), method, ctx);
+    ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+}
+
+/// `object.property`
+pub(crate) fn create_static_member<'a>(
+    object: Expression<'a>,
+    property: &'static str,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    Expression::from(ctx.ast.member_expression_static(
+        SPAN,
+        object,
+        ctx.ast.identifier_name(SPAN, property),
+        false,
+    ))
+}
+
+pub(crate) fn create_declarator<'a>(
+    binding: &BoundIdentifier<'a>,
+    init: Option<Expression<'a>>,
+    ctx: &TraverseCtx<'a>,
+) -> VariableDeclarator<'a> {
+    ctx.ast.variable_declarator(
+        SPAN,
+        VariableDeclarationKind::Var,
+        binding.create_binding_pattern(ctx),
+        init,
+        false,
+    )
+}
+
+/// `var <declarators>`
+fn create_var_declaration<'a>(
+    declarators: ArenaVec<'a, VariableDeclarator<'a>>,
+    ctx: &TraverseCtx<'a>,
+) -> ForStatementInit<'a> {
+    ForStatementInit::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+        SPAN,
+        VariableDeclarationKind::Var,
+        declarators,
+        false,
+    ))
+}
@@ -1,65 +1,304 @@
+use oxc_allocator::Vec as ArenaVec;
 use oxc_ast::ast::*;
 use oxc_traverse::{Traverse, TraverseCtx};
 
+use crate::TransformCtx;
+
 mod arrow_functions;
+mod block_scoping;
+mod classes;
+mod computed_properties;
+mod destructuring;
+mod for_of;
 mod options;
+mod parameters;
+mod regenerator;
+mod shorthand_properties;
+mod spread;
+mod template_literals;
 
 pub use arrow_functions::{ArrowFunctions, ArrowFunctionsOptions};
+pub use block_scoping::{BlockScoping, BlockScopingOptions};
+pub use classes::{Classes, ClassesOptions};
+pub use computed_properties::ComputedProperties;
+pub use destructuring::{Destructuring, DestructuringOptions};
+pub use for_of::{ForOf, ForOfOptions};
+
+pub(crate) use destructuring::{
+    binding_pattern_to_assignment_target, collect_bindings, current_hoist_scope_id,
+};
+pub(crate) use for_of::{create_declarator, create_static_member, create_step_test};
 pub use options::ES2015Options;
+pub use parameters::{Parameters, ParametersOptions};
+pub(crate) use regenerator::{create_apply_body, find_super_in_function, take_function_contents};
+pub use regenerator::{Regenerator, RegeneratorOptions};
+pub use shorthand_properties::ShorthandProperties;
+pub use spread::{Spread, SpreadOptions};
+pub use template_literals::{TemplateLiterals, TemplateLiteralsOptions};
 
-pub struct ES2015<'a> {
+pub struct ES2015<'a, 'ctx> {
     options: ES2015Options,
 
     // Plugins
     arrow_functions: ArrowFunctions<'a>,
+    classes: Classes<'a, 'ctx>,
+    shorthand_properties: ShorthandProperties,
+    computed_properties: ComputedProperties<'a, 'ctx>,
+    template_literals: TemplateLiterals<'a, 'ctx>,
+    spread: Spread<'a, 'ctx>,
+    parameters: Parameters,
+    destructuring: Destructuring<'a, 'ctx>,
+    block_scoping: BlockScoping<'a, 'ctx>,
+    for_of: ForOf<'a, 'ctx>,
+    regenerator: Regenerator<'a, 'ctx>,
 }
 
-impl<'a> ES2015<'a> {
-    pub fn new(options: ES2015Options) -> Self {
+impl<'a, 'ctx> ES2015<'a, 'ctx> {
+    pub fn new(options: ES2015Options, ctx: &'ctx TransformCtx<'a>) -> Self {
         Self {
             arrow_functions: ArrowFunctions::new(
                 options.arrow_function.clone().unwrap_or_default(),
             ),
+            classes: Classes::new(options.classes.unwrap_or_default(), options.spread, ctx),
+            shorthand_properties: ShorthandProperties::new(),
+            computed_properties: ComputedProperties::new(ctx),
+            template_literals: TemplateLiterals::new(
+                options.template_literals.unwrap_or_default(),
+                ctx,
+            ),
+            spread: Spread::new(options.spread.unwrap_or_default(), ctx),
+            parameters: Parameters::new(options.parameters.unwrap_or_default(), ctx),
+            destructuring: Destructuring::new(options.destructuring.unwrap_or_default(), ctx),
+            block_scoping: BlockScoping::new(
+                options.block_scoping.unwrap_or_default(),
+                options.classes.is_some(),
+                ctx,
+            ),
+            for_of: ForOf::new(options.for_of.unwrap_or_default(), ctx),
+            regenerator: Regenerator::new(options.regenerator.unwrap_or_default(), ctx),
             options,
         }
     }
 }
 
-impl<'a> Traverse<'a> for ES2015<'a> {
+impl<'a, 'ctx> Traverse<'a> for ES2015<'a, 'ctx> {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_program(program, ctx);
+        }
+    }
+
     fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.template_literals.is_some() {
+            self.template_literals.exit_program(program, ctx);
+        }
         if self.options.arrow_function.is_some() {
             self.arrow_functions.exit_program(program, ctx);
         }
     }
 
     fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.parameters.is_some() {
+            self.parameters.enter_function(func, ctx);
+        }
         if self.options.arrow_function.is_some() {
             self.arrow_functions.enter_function(func, ctx);
         }
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_function(func, ctx);
+        }
+    }
+
+    fn enter_arrow_function_expression(
+        &mut self,
+        arrow: &mut ArrowFunctionExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_arrow_function_expression(arrow, ctx);
+        }
     }
 
     fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
         if self.options.arrow_function.is_some() {
             self.arrow_functions.exit_function(func, ctx);
         }
+        if self.options.regenerator.is_some() {
+            self.regenerator.exit_function(func, ctx);
+        }
     }
 
     fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.shorthand_properties {
+            self.shorthand_properties.enter_expression(expr, ctx);
+        }
+        if self.options.destructuring.is_some() {
+            self.destructuring.enter_expression(expr, ctx);
+        }
         if self.options.arrow_function.is_some() {
+            // Parameters of arrow functions can only be transformed if arrow functions are
+            // converted to functions, as transformed parameters reference `arguments`
+            if self.options.parameters.is_some() {
+                self.parameters.enter_expression(expr, ctx);
+            }
             self.arrow_functions.enter_expression(expr, ctx);
         }
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_expression(expr, ctx);
+        }
     }
 
     fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.template_literals.is_some() {
+            self.template_literals.exit_expression(expr, ctx);
+        }
+        if self.options.computed_properties {
+            self.computed_properties.exit_expression(expr, ctx);
+        }
+        if self.options.spread.is_some() {
+            self.spread.exit_expression(expr, ctx);
+        }
+        if self.options.classes.is_some() {
+            self.classes.exit_expression(expr, ctx);
+        }
         if self.options.arrow_function.is_some() {
             self.arrow_functions.exit_expression(expr, ctx);
         }
+        if self.options.regenerator.is_some() {
+            self.regenerator.exit_expression(expr, ctx);
+        }
+    }
+
+    fn enter_statements(
+        &mut self,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.regenerator.is_some() {
+            self.regenerator.enter_statements(stmts, ctx);
+        }
+    }
+
+    fn exit_statements(
+        &mut self,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.exit_statements(stmts, ctx);
+        }
+        if self.options.regenerator.is_some() {
+            self.regenerator.exit_statements(stmts, ctx);
+        }
+    }
+
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.for_of.is_some() {
+            self.for_of.enter_statement(stmt, ctx);
+        }
+        if self.options.destructuring.is_some() {
+            self.destructuring.enter_statement(stmt, ctx);
+        }
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_for_statement(&mut self, stmt: &mut ForStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.destructuring.is_some() {
+            self.destructuring.enter_for_statement(stmt, ctx);
+        }
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_for_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.destructuring.is_some() {
+            self.destructuring.enter_for_in_statement(stmt, ctx);
+        }
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_for_in_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.destructuring.is_some() {
+            self.destructuring.enter_for_of_statement(stmt, ctx);
+        }
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_for_of_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_catch_clause(&mut self, clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.destructuring.is_some() {
+            self.destructuring.enter_catch_clause(clause, ctx);
+        }
+    }
+
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.classes.is_some() {
+            self.classes.exit_statement(stmt, ctx);
+        }
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.exit_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_block_statement(&mut self, block: &mut BlockStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_block_statement(block, ctx);
+        }
+    }
+
+    fn enter_switch_statement(
+        &mut self,
+        stmt: &mut SwitchStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_switch_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_variable_declaration(decl, ctx);
+        }
+    }
+
+    fn enter_binding_identifier(
+        &mut self,
+        ident: &mut BindingIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_binding_identifier(ident, ctx);
+        }
+    }
+
+    fn enter_identifier_reference(
+        &mut self,
+        ident: &mut IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_identifier_reference(ident, ctx);
+        }
     }
 
     fn enter_static_block(&mut self, block: &mut StaticBlock<'a>, ctx: &mut TraverseCtx<'a>) {
         if self.options.arrow_function.is_some() {
             self.arrow_functions.enter_static_block(block, ctx);
         }
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_static_block(block, ctx);
+        }
     }
 
     fn exit_static_block(&mut self, block: &mut StaticBlock<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -2,13 +2,46 @@ use serde::Deserialize;
 
 use crate::env::{can_enable_plugin, Versions};
 
-use super::ArrowFunctionsOptions;
+use super::{
+    ArrowFunctionsOptions, BlockScopingOptions, ClassesOptions, DestructuringOptions, ForOfOptions,
+    ParametersOptions, RegeneratorOptions, SpreadOptions, TemplateLiteralsOptions,
+};
 
 #[derive(Debug, Default, Clone, Deserialize)]
 #[serde(default, rename_all = "camelCase", deny_unknown_fields)]
 pub struct ES2015Options {
     #[serde(skip)]
     pub arrow_function: Option<ArrowFunctionsOptions>,
+
+    #[serde(skip)]
+    pub classes: Option<ClassesOptions>,
+
+    #[serde(skip)]
+    pub shorthand_properties: bool,
+
+    #[serde(skip)]
+    pub computed_properties: bool,
+
+    #[serde(skip)]
+    pub template_literals: Option<TemplateLiteralsOptions>,
+
+    #[serde(skip)]
+    pub spread: Option<SpreadOptions>,
+
+    #[serde(skip)]
+    pub parameters: Option<ParametersOptions>,
+
+    #[serde(skip)]
+    pub destructuring: Option<DestructuringOptions>,
+
+    #[serde(skip)]
+    pub block_scoping: Option<BlockScopingOptions>,
+
+    #[serde(skip)]
+    pub for_of: Option<ForOfOptions>,
+
+    #[serde(skip)]
+    pub regenerator: Option<RegeneratorOptions>,
 }
 
 impl ES2015Options {
@@ -20,11 +53,88 @@ impl ES2015Options {
         self
     }
 
+    pub fn with_classes(&mut self, classes: Option<ClassesOptions>) -> &mut Self {
+        self.classes = classes;
+        self
+    }
+
+    pub fn with_shorthand_properties(&mut self, enabled: bool) -> &mut Self {
+        self.shorthand_properties = enabled;
+        self
+    }
+
+    pub fn with_computed_properties(&mut self, enabled: bool) -> &mut Self {
+        self.computed_properties = enabled;
+        self
+    }
+
+    pub fn with_template_literals(
+        &mut self,
+        template_literals: Option<TemplateLiteralsOptions>,
+    ) -> &mut Self {
+        self.template_literals = template_literals;
+        self
+    }
+
+    pub fn with_spread(&mut self, spread: Option<SpreadOptions>) -> &mut Self {
+        self.spread = spread;
+        self
+    }
+
+    pub fn with_parameters(&mut self, parameters: Option<ParametersOptions>) -> &mut Self {
+        self.parameters = parameters;
+        self
+    }
+
+    pub fn with_destructuring(&mut self, destructuring: Option<DestructuringOptions>) -> &mut Self {
+        self.destructuring = destructuring;
+        self
+    }
+
+    pub fn with_block_scoping(&mut self, block_scoping: Option<BlockScopingOptions>) -> &mut Self {
+        self.block_scoping = block_scoping;
+        self
+    }
+
+    pub fn with_for_of(&mut self, for_of: Option<ForOfOptions>) -> &mut Self {
+        self.for_of = for_of;
+        self
+    }
+
+    pub fn with_regenerator(&mut self, regenerator: Option<RegeneratorOptions>) -> &mut Self {
+        self.regenerator = regenerator;
+        self
+    }
+
     #[must_use]
     pub fn from_targets_and_bugfixes(targets: Option<&Versions>, bugfixes: bool) -> Self {
         Self {
             arrow_function: can_enable_plugin("transform-arrow-functions", targets, bugfixes)
                 .then(Default::default),
+            classes: can_enable_plugin("transform-classes", targets, bugfixes)
+                .then(Default::default),
+            shorthand_properties: can_enable_plugin(
+                "transform-shorthand-properties",
+                targets,
+                bugfixes,
+            ),
+            computed_properties: can_enable_plugin(
+                "transform-computed-properties",
+                targets,
+                bugfixes,
+            ),
+            template_literals: can_enable_plugin("transform-template-literals", targets, bugfixes)
+                .then(Default::default),
+            spread: can_enable_plugin("transform-spread", targets, bugfixes).then(Default::default),
+            parameters: can_enable_plugin("transform-parameters", targets, bugfixes)
+                .then(Default::default),
+            destructuring: can_enable_plugin("transform-destructuring", targets, bugfixes)
+                .then(Default::default),
+            block_scoping: can_enable_plugin("transform-block-scoping", targets, bugfixes)
+                .then(Default::default),
+            for_of: can_enable_plugin("transform-for-of", targets, bugfixes).then(Default::default),
+            regenerator: can_enable_plugin("transform-regenerator", targets, bugfixes)
+                .then(Default::default),
         }
     }
 }
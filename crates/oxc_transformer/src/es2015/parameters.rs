@@ -0,0 +1,524 @@
+//! ES2015: Parameters
+//!
+//! This plugin transforms default parameters, destructuring parameters and rest parameters.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! function f(a, b = 1, c, ...d) {}
+//! function g({ x }) {}
+//! ```
+//!
+//! Output:
+//! ```js
+//! function f(a) {
+//!   let b = arguments.length > 1 && arguments[1] !== undefined ? arguments[1] : 1;
+//!   let c = arguments.length > 2 ? arguments[2] : undefined;
+//!   for (var _len = arguments.length, d = new Array(_len > 3 ? _len - 3 : 0), _key = 3; _key < _len; _key++) {
+//!     d[_key - 3] = arguments[_key];
+//!   }
+//! }
+//! function g(_ref) {
+//!   let { x } = _ref;
+//! }
+//! ```
+//!
+//! Parameters from the first one with a default value onwards are removed, so that the function's
+//! `length` property is unchanged. Destructuring patterns are left for destructuring transform.
+//!
+//! Arrow functions are only transformed when arrow functions transform is also enabled,
+//! as the output references `arguments`.
+//!
+//! ## Options
+//!
+//! ### `loose`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! When `true`, parameters with default values are retained, and defaults are applied with
+//! `if (b === void 0) { b = 1; }`. Function's `length` property is not preserved.
+//!
+//! ### Assumptions
+//!
+//! * `ignoreFunctionLength`: Same as `loose`.
+//!
+//! Setters are always transformed as if in loose mode, as their `length` is always 1.
+//!
+//! ## Missing features
+//!
+//! Implementation is incomplete at present. Still TODO:
+//!
+//! * Default values which reference bindings declared in the function body
+//!   (`function f(a = () => b) { var b; }`). Babel moves the function body into an inner function.
+//! * Optimizing rest parameters which are only used for `rest.length` or `rest[i]`.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-parameters](https://babel.dev/docs/babel-plugin-transform-parameters).
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-parameters>
+//! * Function definitions in spec: <https://tc39.es/ecma262/#sec-function-definitions>
+
+use serde::Deserialize;
+
+use oxc_ast::{ast::*, NONE};
+use oxc_ecmascript::BoundNames;
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::{
+    number::NumberBase,
+    operator::{AssignmentOperator, BinaryOperator, LogicalOperator, UpdateOperator},
+    reference::ReferenceFlags,
+    scope::{ScopeFlags, ScopeId},
+    symbol::SymbolFlags,
+};
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse, TraverseCtx};
+
+use crate::TransformCtx;
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct ParametersOptions {
+    pub loose: bool,
+}
+
+pub struct Parameters {
+    /// Retain parameters with default values, and apply defaults with `if` statements
+    loose: bool,
+}
+
+impl Parameters {
+    pub fn new(options: ParametersOptions, ctx: &TransformCtx) -> Self {
+        let loose = options.loose || ctx.assumptions.ignore_function_length;
+        Self { loose }
+    }
+}
+
+impl<'a> Traverse<'a> for Parameters {
+    fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Some(body) = func.body.as_mut() else { return };
+        let is_setter = match ctx.parent() {
+            Ancestor::MethodDefinitionValue(method) => *method.kind() == MethodDefinitionKind::Set,
+            Ancestor::ObjectPropertyValue(prop) => *prop.kind() == PropertyKind::Set,
+            _ => false,
+        };
+        let scope_id = func.scope_id.get().unwrap();
+        Self::transform_params(&mut func.params, body, scope_id, self.loose || is_setter, ctx);
+    }
+
+    /// Only called if arrow functions transform is enabled. See module docs.
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::ArrowFunctionExpression(arrow) = expr else { return };
+        let arrow = &mut **arrow;
+        let scope_id = arrow.scope_id.get().unwrap();
+        let transformed =
+            Self::transform_params(&mut arrow.params, &mut arrow.body, scope_id, self.loose, ctx);
+
+        // `(a = 1) => a` -> `(a) => { let a = ...; return a; }`
+        if transformed && arrow.expression {
+            let stmt = arrow.body.statements.last_mut().unwrap();
+            if let Statement::ExpressionStatement(expr_stmt) = stmt {
+                let expr = ctx.ast.move_expression(&mut expr_stmt.expression);
+                *stmt = ctx.ast.statement_return(SPAN, Some(expr));
+            }
+            arrow.expression = false;
+        }
+    }
+}
+
+impl<'a> Parameters {
+    /// Transform function parameters, and add statements to top of function body.
+    ///
+    /// Returns `true` if any changes were made.
+    fn transform_params(
+        params: &mut FormalParameters<'a>,
+        body: &mut FunctionBody<'a>,
+        scope_id: ScopeId,
+        loose: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> bool {
+        if params.rest.is_none()
+            && params.items.iter().all(|param| param.pattern.kind.is_binding_identifier())
+        {
+            return false;
+        }
+
+        let params_count = params.items.len();
+        let mut stmts = ctx.ast.vec();
+
+        // Parameters from first parameter with default onwards are removed
+        let first_optional_index = if loose {
+            None
+        } else {
+            params.items.iter().position(|param| param.pattern.kind.is_assignment_pattern())
+        };
+        let removed_params = match first_optional_index {
+            Some(index) => params.items.drain(index..).collect::<Vec<_>>(),
+            None => vec![],
+        };
+
+        for param in params.items.iter_mut() {
+            match &param.pattern.kind {
+                BindingPatternKind::BindingIdentifier(_) => {}
+                BindingPatternKind::AssignmentPattern(assign) => {
+                    // Loose mode only
+                    let is_ident = assign.left.kind.is_binding_identifier();
+                    let BindingPatternKind::AssignmentPattern(assign) = std::mem::replace(
+                        &mut param.pattern.kind,
+                        ctx.ast.binding_pattern_kind_binding_identifier(SPAN, ""),
+                    ) else {
+                        unreachable!()
+                    };
+                    let AssignmentPattern { left, right, .. } = assign.unbox();
+                    if is_ident {
+                        // `function f(a = 1) {}` -> `function f(a) { if (a === void 0) { a = 1; } }`
+                        let binding = BoundIdentifier::from_binding_ident(
+                            left.get_binding_identifier().unwrap(),
+                        );
+                        stmts.push(Self::create_loose_default(&binding, right, scope_id, ctx));
+                        param.pattern.kind = left.kind;
+                    } else {
+                        // `function f({ a } = {}) {}` ->
+                        // `function f(_temp) { let { a } = _temp === void 0 ? {} : _temp; }`
+                        let binding =
+                            ctx.generate_uid("temp", scope_id, SymbolFlags::FunctionScopedVariable);
+                        let test = ctx.ast.expression_binary(
+                            SPAN,
+                            binding.create_read_expression(ctx),
+                            BinaryOperator::StrictEquality,
+                            ctx.ast.void_0(SPAN),
+                        );
+                        let init = ctx.ast.expression_conditional(
+                            SPAN,
+                            test,
+                            right,
+                            binding.create_read_expression(ctx),
+                        );
+                        stmts.push(Self::create_let_declaration(left, init, ctx));
+                        param.pattern.kind = binding.create_binding_pattern(ctx).kind;
+                    }
+                }
+                BindingPatternKind::ObjectPattern(_) | BindingPatternKind::ArrayPattern(_) => {
+                    // `function f({ a }) {}` -> `function f(_ref) { let { a } = _ref; }`
+                    let binding =
+                        ctx.generate_uid("ref", scope_id, SymbolFlags::FunctionScopedVariable);
+                    let pattern =
+                        std::mem::replace(&mut param.pattern, binding.create_binding_pattern(ctx));
+                    let init = binding.create_read_expression(ctx);
+                    stmts.push(Self::create_let_declaration(pattern, init, ctx));
+                }
+            }
+        }
+
+        if let Some(first_optional_index) = first_optional_index {
+            for (index, param) in (first_optional_index..).zip(removed_params) {
+                let (pattern, init) = if let BindingPatternKind::AssignmentPattern(assign) =
+                    param.pattern.kind
+                {
+                    // `let b = arguments.length > 1 && arguments[1] !== undefined ? arguments[1] : 1;`
+                    let AssignmentPattern { left, right, .. } = assign.unbox();
+                    let has_arg = Self::create_has_argument(index, scope_id, ctx);
+                    let is_not_undefined = ctx.ast.expression_binary(
+                        SPAN,
+                        Self::create_argument(index, scope_id, ctx),
+                        BinaryOperator::StrictInequality,
+                        create_ident("undefined", scope_id, ctx),
+                    );
+                    let test = ctx.ast.expression_logical(
+                        SPAN,
+                        has_arg,
+                        LogicalOperator::And,
+                        is_not_undefined,
+                    );
+                    let argument = Self::create_argument(index, scope_id, ctx);
+                    (left, ctx.ast.expression_conditional(SPAN, test, argument, right))
+                } else {
+                    // `let c = arguments.length > 2 ? arguments[2] : undefined;`
+                    let test = Self::create_has_argument(index, scope_id, ctx);
+                    let argument = Self::create_argument(index, scope_id, ctx);
+                    let undefined = create_ident("undefined", scope_id, ctx);
+                    (param.pattern, ctx.ast.expression_conditional(SPAN, test, argument, undefined))
+                };
+                stmts.push(Self::create_let_declaration(pattern, init, ctx));
+            }
+        }
+
+        if let Some(rest) = params.rest.take() {
+            let rest_pattern = rest.unbox().argument;
+            if let BindingPatternKind::BindingIdentifier(ident) = &rest_pattern.kind {
+                let binding = BoundIdentifier::from_binding_ident(ident);
+                stmts.push(Self::create_rest_loop(
+                    rest_pattern,
+                    &binding,
+                    params_count,
+                    scope_id,
+                    ctx,
+                ));
+            } else {
+                // `function f(...[a]) {}` -> `function f() { for (...) {...} let [a] = _ref; }`
+                let binding =
+                    ctx.generate_uid("ref", scope_id, SymbolFlags::FunctionScopedVariable);
+                let pattern = binding.create_binding_pattern(ctx);
+                stmts.push(Self::create_rest_loop(pattern, &binding, params_count, scope_id, ctx));
+                let init = binding.create_read_expression(ctx);
+                stmts.push(Self::create_let_declaration(rest_pattern, init, ctx));
+            }
+        }
+
+        body.statements.splice(0..0, stmts);
+        true
+    }
+
+    /// Create `let <pattern> = <init>;`.
+    ///
+    /// Symbols of the pattern become block scoped.
+    fn create_let_declaration(
+        pattern: BindingPattern<'a>,
+        init: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let symbols = ctx.symbols_mut();
+        pattern.bound_names(&mut |ident| {
+            let flags = symbols.get_flags_mut(ident.symbol_id.get().unwrap());
+            *flags =
+                (*flags - SymbolFlags::FunctionScopedVariable) | SymbolFlags::BlockScopedVariable;
+        });
+
+        let kind = VariableDeclarationKind::Let;
+        let declarator = ctx.ast.variable_declarator(SPAN, kind, pattern, Some(init), false);
+        Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            kind,
+            ctx.ast.vec1(declarator),
+            false,
+        ))
+    }
+
+    /// `if (a === void 0) { a = 1; }`
+    fn create_loose_default(
+        binding: &BoundIdentifier<'a>,
+        default: Expression<'a>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let test = ctx.ast.expression_binary(
+            SPAN,
+            binding.create_read_expression(ctx),
+            BinaryOperator::StrictEquality,
+            ctx.ast.void_0(SPAN),
+        );
+        let assignment = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            binding.create_write_target(ctx),
+            default,
+        );
+        let block_scope_id = ctx.create_child_scope(scope_id, ScopeFlags::empty());
+        let consequent = Statement::BlockStatement(ctx.ast.alloc_block_statement_with_scope_id(
+            SPAN,
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, assignment)),
+            block_scope_id,
+        ));
+        ctx.ast.statement_if(SPAN, test, consequent, None)
+    }
+
+    /// Create loop to collect rest parameters into an array.
+    ///
+    /// ```js
+    /// for (var _len = arguments.length, d = new Array(_len > 3 ? _len - 3 : 0), _key = 3; _key < _len; _key++) {
+    ///   d[_key - 3] = arguments[_key];
+    /// }
+    /// ```
+    ///
+    /// If `start` is 0: `new Array(_len)` and `d[_key] = arguments[_key]`.
+    fn create_rest_loop(
+        rest_pattern: BindingPattern<'a>,
+        rest_binding: &BoundIdentifier<'a>,
+        start: usize,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let len_binding = ctx.generate_uid("len", scope_id, SymbolFlags::FunctionScopedVariable);
+        let key_binding = ctx.generate_uid("key", scope_id, SymbolFlags::FunctionScopedVariable);
+        let for_scope_id = ctx.create_child_scope(scope_id, ScopeFlags::empty());
+        let block_scope_id = ctx.create_child_scope(for_scope_id, ScopeFlags::empty());
+
+        // `_len = arguments.length`
+        let arguments_length = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            create_ident("arguments", scope_id, ctx),
+            ctx.ast.identifier_name(SPAN, "length"),
+            false,
+        ));
+        // `_len > 3 ? _len - 3 : 0` or `_len`
+        let array_len = if start == 0 {
+            len_binding.create_read_expression(ctx)
+        } else {
+            let test = ctx.ast.expression_binary(
+                SPAN,
+                len_binding.create_read_expression(ctx),
+                BinaryOperator::GreaterThan,
+                create_number(start, ctx),
+            );
+            let subtract = ctx.ast.expression_binary(
+                SPAN,
+                len_binding.create_read_expression(ctx),
+                BinaryOperator::Subtraction,
+                create_number(start, ctx),
+            );
+            ctx.ast.expression_conditional(SPAN, test, subtract, create_number(0, ctx))
+        };
+        // `new Array(...)`
+        let new_array = ctx.ast.expression_new(
+            SPAN,
+            create_ident("Array", scope_id, ctx),
+            ctx.ast.vec1(Argument::from(array_len)),
+            NONE,
+        );
+
+        let kind = VariableDeclarationKind::Var;
+        let declarations = ctx.ast.vec_from_iter([
+            ctx.ast.variable_declarator(
+                SPAN,
+                kind,
+                len_binding.create_binding_pattern(ctx),
+                Some(arguments_length),
+                false,
+            ),
+            ctx.ast.variable_declarator(SPAN, kind, rest_pattern, Some(new_array), false),
+            ctx.ast.variable_declarator(
+                SPAN,
+                kind,
+                key_binding.create_binding_pattern(ctx),
+                Some(create_number(start, ctx)),
+                false,
+            ),
+        ]);
+        let init = ForStatementInit::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            kind,
+            declarations,
+            false,
+        ));
+
+        // `_key < _len`
+        let test = ctx.ast.expression_binary(
+            SPAN,
+            key_binding.create_read_expression(ctx),
+            BinaryOperator::LessThan,
+            len_binding.create_read_expression(ctx),
+        );
+        // `_key++`
+        let update = ctx.ast.expression_update(
+            SPAN,
+            UpdateOperator::Increment,
+            false,
+            ctx.ast.simple_assignment_target_from_identifier_reference(
+                key_binding.create_read_write_reference(ctx),
+            ),
+        );
+
+        // `d[_key - 3] = arguments[_key];`
+        let index = if start == 0 {
+            key_binding.create_read_expression(ctx)
+        } else {
+            ctx.ast.expression_binary(
+                SPAN,
+                key_binding.create_read_expression(ctx),
+                BinaryOperator::Subtraction,
+                create_number(start, ctx),
+            )
+        };
+        let target = ctx.ast.member_expression_computed(
+            SPAN,
+            rest_binding.create_read_expression(ctx),
+            index,
+            false,
+        );
+        let value = Expression::from(ctx.ast.member_expression_computed(
+            SPAN,
+            create_ident("arguments", scope_id, ctx),
+            key_binding.create_read_expression(ctx),
+            false,
+        ));
+        let assignment = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            AssignmentTarget::from(target),
+            value,
+        );
+        let body = Statement::BlockStatement(ctx.ast.alloc_block_statement_with_scope_id(
+            SPAN,
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, assignment)),
+            block_scope_id,
+        ));
+
+        Statement::ForStatement(ctx.ast.alloc_for_statement_with_scope_id(
+            SPAN,
+            Some(init),
+            Some(test),
+            Some(update),
+            body,
+            for_scope_id,
+        ))
+    }
+
+    /// `arguments.length > 1`
+    fn create_has_argument(
+        index: usize,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let arguments_length = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            create_ident("arguments", scope_id, ctx),
+            ctx.ast.identifier_name(SPAN, "length"),
+            false,
+        ));
+        ctx.ast.expression_binary(
+            SPAN,
+            arguments_length,
+            BinaryOperator::GreaterThan,
+            create_number(index, ctx),
+        )
+    }
+
+    /// `arguments[1]`
+    fn create_argument(
+        index: usize,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        Expression::from(ctx.ast.member_expression_computed(
+            SPAN,
+            create_ident("arguments", scope_id, ctx),
+            create_number(index, ctx),
+            false,
+        ))
+    }
+}
+
+/// Create `IdentifierReference` for a global, or a local binding if it's shadowed.
+fn create_ident<'a>(
+    name: &'static str,
+    scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let symbol_id = ctx.scopes().find_binding(scope_id, name);
+    let ident = ctx.create_reference_id(SPAN, Atom::from(name), symbol_id, ReferenceFlags::Read);
+    ctx.ast.expression_from_identifier_reference(ident)
+}
+
+fn create_number<'a>(value: usize, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+    #[expect(clippy::cast_precision_loss)]
+    let number = value as f64;
+    ctx.ast.expression_numeric_literal(
+        SPAN,
+        number,
+        ctx.ast.str(&value.to_string()),
+        NumberBase::Decimal,
+    )
+}
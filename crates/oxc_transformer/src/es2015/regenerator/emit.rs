@@ -0,0 +1,1569 @@
+//! Compilation of a generator function body into a state machine.
+//!
+//! Statements are "exploded" into a flat listing of statements, with control flow replaced by
+//! assignments to `_context.next` and jumps to the dispatch loop.
+//! The listing is then split into `case`s of a `switch` statement at each location which
+//! can be jumped to.
+//!
+//! ```js
+//! while (1) switch (_context.prev = _context.next) {
+//!   case 0:
+//!     // ...
+//!   case "end":
+//!     return _context.stop();
+//! }
+//! ```
+//!
+//! Only statements and expressions which contain a "leap" (`yield`, `break`, `continue`, `return`
+//! or `throw`) are exploded. Others are emitted as they are.
+//!
+//! Based on `emit.js` and `leap.js` in regenerator-transform.
+
+use std::cell::Cell;
+
+use rustc_hash::FxHashSet;
+
+use oxc_allocator::CloneIn;
+use oxc_ast::{
+    ast::*,
+    visit::{walk, walk_mut},
+    Visit, VisitMut, NONE,
+};
+use oxc_span::{Atom, GetSpan, Span, SPAN};
+use oxc_syntax::{
+    number::NumberBase,
+    operator::{AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator},
+    reference::ReferenceFlags,
+    scope::{ScopeFlags, ScopeId},
+    symbol::SymbolId,
+};
+use oxc_traverse::{BoundIdentifier, TraverseCtx};
+
+use crate::{common::helper_loader::Helper, TransformCtx};
+
+use super::meta::{
+    arguments_contain_leap, assignment_target_contains_leap, contains_leap,
+    expression_contains_leap,
+};
+
+/// A location in the listing, which can be jumped to.
+///
+/// Index into [`Emitter::locs`].
+#[derive(Clone, Copy)]
+struct Loc(usize);
+
+/// A temporary variable stored on the context object (`_context.t0`).
+#[derive(Clone, Copy)]
+struct Temp(u32);
+
+/// An entry in the listing. Each entry becomes a single statement in the dispatch loop.
+///
+/// Statements which include locations are only created once the listing is complete,
+/// as locations may not be known at the time they are emitted.
+enum Op<'a> {
+    Statement(Statement<'a>),
+    /// `_context.next = 1;`
+    SetNext(Loc),
+    /// `_context.next = _context.t0 === a ? 1 : _context.t0 === b ? 2 : 3;`
+    SetNextSwitch(Vec<(Expression<'a>, Loc)>, Loc),
+    /// `_context.prev = 1;`
+    SetPrev(Loc),
+    /// `if (test) { _context.next = 1; break; }`
+    JumpIf(Expression<'a>, Loc),
+    /// `return _context.abrupt("break", 1);`
+    Abrupt(&'static str, Loc),
+    /// `return _context.delegateYield(iterable, "t0", 1);`
+    DelegateYield(Expression<'a>, Temp, Loc),
+    /// `_context.t0 = _context["catch"](1);`
+    Catch(Temp, Loc),
+    /// `return _context.finish(1);`
+    Finish(Loc),
+}
+
+/// A statement which can be the target of `break` or `continue`.
+enum Leap<'a> {
+    Loop { break_loc: Loc, continue_loc: Loc, label: Option<Atom<'a>> },
+    Switch { break_loc: Loc },
+    Labeled { break_loc: Loc, label: Atom<'a> },
+}
+
+/// Locations of a `try` statement.
+struct TryEntry {
+    first: Loc,
+    catch: Option<Loc>,
+    finally: Option<Loc>,
+    after: Loc,
+}
+
+pub(super) struct Emitter<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    /// `_context` parameter of inner function
+    context: BoundIdentifier<'a>,
+    /// Scope of `switch` statement in the dispatch loop
+    switch_scope_id: ScopeId,
+    listing: Vec<Op<'a>>,
+    /// Indexes in listing which are jumped to, and so start a new `case`
+    marked: FxHashSet<usize>,
+    /// Index in listing for each location. `None` if not yet marked.
+    locs: Vec<Option<usize>>,
+    next_temp_id: u32,
+    leaps: Vec<Leap<'a>>,
+    try_entries: Vec<TryEntry>,
+}
+
+impl<'a, 'ctx> Emitter<'a, 'ctx> {
+    pub fn new(
+        context: BoundIdentifier<'a>,
+        switch_scope_id: ScopeId,
+        ctx: &'ctx TransformCtx<'a>,
+    ) -> Self {
+        let mut marked = FxHashSet::default();
+        marked.insert(0);
+        Self {
+            ctx,
+            context,
+            switch_scope_id,
+            listing: vec![],
+            marked,
+            locs: vec![],
+            next_temp_id: 0,
+            leaps: vec![],
+            try_entries: vec![],
+        }
+    }
+
+    /// Create a new location, which is not yet marked.
+    fn loc(&mut self) -> Loc {
+        self.locs.push(None);
+        Loc(self.locs.len() - 1)
+    }
+
+    /// Create a location at current position in the listing, without marking it.
+    fn current_loc(&mut self) -> Loc {
+        self.locs.push(Some(self.listing.len()));
+        Loc(self.locs.len() - 1)
+    }
+
+    /// Set location to current position in the listing, and start a new `case` there.
+    fn mark(&mut self, loc: Loc) -> Loc {
+        let index = self.listing.len();
+        let value = self.locs[loc.0].get_or_insert(index);
+        debug_assert_eq!(*value, index);
+        self.marked.insert(index);
+        loc
+    }
+
+    fn loc_value(&self, loc: Loc) -> usize {
+        self.locs[loc.0].expect("location should be marked")
+    }
+
+    fn make_temp(&mut self) -> Temp {
+        let temp = Temp(self.next_temp_id);
+        self.next_temp_id += 1;
+        temp
+    }
+
+    fn emit(&mut self, stmt: Statement<'a>) {
+        self.listing.push(Op::Statement(stmt));
+    }
+
+    fn emit_expression(&mut self, expr: Expression<'a>, ctx: &TraverseCtx<'a>) {
+        self.emit(ctx.ast.statement_expression(expr.span(), expr));
+    }
+
+    fn emit_assign(
+        &mut self,
+        target: AssignmentTarget<'a>,
+        value: Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) {
+        let expr = ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+        self.emit_expression(expr, ctx);
+    }
+
+    fn emit_assign_temp(&mut self, temp: Temp, value: Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let target = self.temp_target(temp, ctx);
+        self.emit_assign(target, value, ctx);
+    }
+
+    /// `_context.next = loc; break;`
+    fn jump(&mut self, loc: Loc, ctx: &TraverseCtx<'a>) {
+        self.listing.push(Op::SetNext(loc));
+        self.emit(ctx.ast.statement_break(SPAN, None));
+    }
+
+    /// `if (test) { _context.next = loc; break; }`
+    fn jump_if(&mut self, test: Expression<'a>, loc: Loc) {
+        self.listing.push(Op::JumpIf(test, loc));
+    }
+
+    /// `if (!test) { _context.next = loc; break; }`
+    fn jump_if_not(&mut self, test: Expression<'a>, loc: Loc, ctx: &TraverseCtx<'a>) {
+        let test = match test {
+            Expression::UnaryExpression(unary) if unary.operator == UnaryOperator::LogicalNot => {
+                unary.unbox().argument
+            }
+            test => ctx.ast.expression_unary(SPAN, UnaryOperator::LogicalNot, test),
+        };
+        self.jump_if(test, loc);
+    }
+
+    /// `_context.prev = loc;`
+    fn update_context_prev_loc(&mut self, loc: Loc) {
+        let index = self.listing.len();
+        let value = self.locs[loc.0].get_or_insert(index);
+        debug_assert_eq!(*value, index);
+        self.listing.push(Op::SetPrev(loc));
+    }
+
+    /// Explode a statement into the listing.
+    pub fn explode_statement(
+        &mut self,
+        stmt: Statement<'a>,
+        label: Option<Atom<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Statement::BlockStatement(block) = stmt {
+            let block = block.unbox();
+            self.remove_scope(block.scope_id.get().unwrap(), ctx);
+            for stmt in block.body {
+                self.explode_statement(stmt, None, ctx);
+            }
+            return;
+        }
+
+        if !contains_leap(&stmt) {
+            self.emit(stmt);
+            return;
+        }
+
+        match stmt {
+            Statement::ExpressionStatement(stmt) => {
+                self.explode_expression(stmt.unbox().expression, true, ctx);
+            }
+            Statement::LabeledStatement(stmt) => {
+                let stmt = stmt.unbox();
+                let after = self.loc();
+                let label = stmt.label.name;
+                self.leaps.push(Leap::Labeled { break_loc: after, label: label.clone() });
+                self.explode_statement(stmt.body, Some(label), ctx);
+                self.leaps.pop();
+                self.mark(after);
+            }
+            Statement::WhileStatement(stmt) => {
+                let stmt = stmt.unbox();
+                let before = self.loc();
+                let after = self.loc();
+                self.mark(before);
+                let test = self.explode_expression(stmt.test, false, ctx).unwrap();
+                self.jump_if_not(test, after, ctx);
+                self.explode_loop_body(stmt.body, after, before, label, ctx);
+                self.jump(before, ctx);
+                self.mark(after);
+            }
+            Statement::DoWhileStatement(stmt) => {
+                let stmt = stmt.unbox();
+                let first = self.loc();
+                let test_loc = self.loc();
+                let after = self.loc();
+                self.mark(first);
+                self.explode_loop_body(stmt.body, after, test_loc, label, ctx);
+                self.mark(test_loc);
+                let test = self.explode_expression(stmt.test, false, ctx).unwrap();
+                self.jump_if(test, first);
+                self.mark(after);
+            }
+            Statement::ForStatement(stmt) => {
+                let stmt = stmt.unbox();
+                self.remove_scope(stmt.scope_id.get().unwrap(), ctx);
+                let head = self.loc();
+                let update = self.loc();
+                let after = self.loc();
+                if let Some(init) = stmt.init {
+                    // Declarations have been converted to assignments by hoisting
+                    self.explode_expression(init.into_expression(), true, ctx);
+                }
+                self.mark(head);
+                if let Some(test) = stmt.test {
+                    let test = self.explode_expression(test, false, ctx).unwrap();
+                    self.jump_if_not(test, after, ctx);
+                }
+                self.explode_loop_body(stmt.body, after, update, label, ctx);
+                self.mark(update);
+                if let Some(update) = stmt.update {
+                    self.explode_expression(update, true, ctx);
+                }
+                self.jump(head, ctx);
+                self.mark(after);
+            }
+            Statement::ForInStatement(stmt) => {
+                let stmt = stmt.unbox();
+                self.remove_scope(stmt.scope_id.get().unwrap(), ctx);
+                let head = self.loc();
+                let after = self.loc();
+
+                // `_context.t0 = babelHelpers.regeneratorRuntime().keys(object);`
+                let next_fn = self.make_temp();
+                let object = self.explode_expression(stmt.right, false, ctx).unwrap();
+                let keys = self.runtime_property("keys", ctx);
+                let keys = ctx.ast.expression_call(
+                    SPAN,
+                    keys,
+                    NONE,
+                    ctx.ast.vec1(Argument::from(object)),
+                    false,
+                );
+                self.emit_assign_temp(next_fn, keys, ctx);
+
+                // `if ((_context.t1 = _context.t0()).done) { _context.next = after; break; }`
+                self.mark(head);
+                let key_info = self.make_temp();
+                let next = self.temp_expression(next_fn, ctx);
+                let next = ctx.ast.expression_call(SPAN, next, NONE, ctx.ast.vec(), false);
+                let target = self.temp_target(key_info, ctx);
+                let next =
+                    ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, next);
+                let test = create_static_member(next, "done", ctx);
+                self.jump_if(test, after);
+
+                // `key = _context.t1.value;`
+                let value = self.temp_expression(key_info, ctx);
+                let value = create_static_member(value, "value", ctx);
+                self.emit_assign(stmt.left.into_assignment_target(), value, ctx);
+
+                self.explode_loop_body(stmt.body, after, head, label, ctx);
+                self.jump(head, ctx);
+                self.mark(after);
+            }
+            Statement::BreakStatement(stmt) => {
+                let label = stmt.label.as_ref().map(|label| &label.name);
+                let loc = self.find_leap_loc(label, false);
+                self.listing.push(Op::Abrupt("break", loc));
+            }
+            Statement::ContinueStatement(stmt) => {
+                let label = stmt.label.as_ref().map(|label| &label.name);
+                let loc = self.find_leap_loc(label, true);
+                self.listing.push(Op::Abrupt("continue", loc));
+            }
+            Statement::SwitchStatement(stmt) => self.explode_switch_statement(stmt.unbox(), ctx),
+            Statement::IfStatement(stmt) => {
+                let stmt = stmt.unbox();
+                let else_loc = stmt.alternate.is_some().then(|| self.loc());
+                let after = self.loc();
+                let test = self.explode_expression(stmt.test, false, ctx).unwrap();
+                self.jump_if_not(test, else_loc.unwrap_or(after), ctx);
+                self.explode_statement(stmt.consequent, None, ctx);
+                if let Some(else_loc) = else_loc {
+                    self.jump(after, ctx);
+                    self.mark(else_loc);
+                    self.explode_statement(stmt.alternate.unwrap(), None, ctx);
+                }
+                self.mark(after);
+            }
+            Statement::ReturnStatement(stmt) => {
+                let stmt = stmt.unbox();
+                let mut arguments =
+                    ctx.ast.vec1(Argument::from(ctx.ast.expression_string_literal(SPAN, "return")));
+                if let Some(argument) = stmt.argument {
+                    let argument = self.explode_expression(argument, false, ctx).unwrap();
+                    arguments.push(Argument::from(argument));
+                }
+                let abrupt = self.context_property("abrupt", ctx);
+                let call = ctx.ast.expression_call(SPAN, abrupt, NONE, arguments, false);
+                self.emit(ctx.ast.statement_return(stmt.span, Some(call)));
+            }
+            Statement::ThrowStatement(stmt) => {
+                let stmt = stmt.unbox();
+                let argument = self.explode_expression(stmt.argument, false, ctx).unwrap();
+                self.emit(ctx.ast.statement_throw(stmt.span, argument));
+            }
+            Statement::TryStatement(stmt) => self.explode_try_statement(stmt.unbox(), ctx),
+            _ => unreachable!(),
+        }
+    }
+
+    fn explode_loop_body(
+        &mut self,
+        body: Statement<'a>,
+        break_loc: Loc,
+        continue_loc: Loc,
+        label: Option<Atom<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.leaps.push(Leap::Loop { break_loc, continue_loc, label });
+        self.explode_statement(body, None, ctx);
+        self.leaps.pop();
+    }
+
+    /// Find location to jump to for `break` or `continue`.
+    fn find_leap_loc(&self, label: Option<&Atom<'a>>, is_continue: bool) -> Loc {
+        self.leaps
+            .iter()
+            .rev()
+            .find_map(|leap| match (leap, label) {
+                (Leap::Loop { break_loc, continue_loc, label: loop_label }, label) => {
+                    if label.is_none() || loop_label.as_ref() == label {
+                        Some(if is_continue { *continue_loc } else { *break_loc })
+                    } else {
+                        None
+                    }
+                }
+                (Leap::Switch { break_loc }, None) if !is_continue => Some(*break_loc),
+                (Leap::Labeled { break_loc, label: labeled_label }, Some(label))
+                    if !is_continue && labeled_label == label =>
+                {
+                    Some(*break_loc)
+                }
+                _ => None,
+            })
+            .unwrap()
+    }
+
+    fn explode_switch_statement(&mut self, stmt: SwitchStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.remove_scope(stmt.scope_id.get().unwrap(), ctx);
+
+        let discriminant_temp = self.make_temp();
+        let discriminant = self.explode_expression(stmt.discriminant, false, ctx).unwrap();
+        self.emit_assign_temp(discriminant_temp, discriminant, ctx);
+
+        let after = self.loc();
+        let default_loc = self.loc();
+        let mut tests = vec![];
+        let mut cases = vec![];
+        for case in stmt.cases {
+            let loc = match case.test {
+                Some(test) => {
+                    let loc = self.loc();
+                    let discriminant = self.temp_expression(discriminant_temp, ctx);
+                    let test = ctx.ast.expression_binary(
+                        SPAN,
+                        discriminant,
+                        BinaryOperator::StrictEquality,
+                        test,
+                    );
+                    tests.push((test, loc));
+                    loc
+                }
+                None => default_loc,
+            };
+            cases.push((loc, case.consequent));
+        }
+        if tests.iter().any(|(test, _)| expression_contains_leap(test)) {
+            // Tests are evaluated lazily, in order, so each is exploded and jumped on in turn.
+            // `_context.t1 = _context.t0 === a; if (...) ...`
+            for (test, loc) in tests {
+                let test = self.explode_expression(test, false, ctx).unwrap();
+                self.jump_if(test, loc);
+            }
+            self.jump(default_loc, ctx);
+        } else {
+            self.listing.push(Op::SetNextSwitch(tests, default_loc));
+            self.emit(ctx.ast.statement_break(SPAN, None));
+        }
+
+        self.leaps.push(Leap::Switch { break_loc: after });
+        for (loc, consequent) in cases {
+            self.mark(loc);
+            for stmt in consequent {
+                self.explode_statement(stmt, None, ctx);
+            }
+        }
+        self.leaps.pop();
+
+        self.mark(after);
+        if self.locs[default_loc.0].is_none() {
+            self.mark(default_loc);
+        }
+    }
+
+    fn explode_try_statement(&mut self, stmt: TryStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let after = self.loc();
+        let catch_loc = stmt.handler.is_some().then(|| self.loc());
+        let finally_loc = stmt.finalizer.is_some().then(|| self.loc());
+        let first_loc = self.current_loc();
+        self.try_entries.push(TryEntry {
+            first: first_loc,
+            catch: catch_loc,
+            finally: finally_loc,
+            after,
+        });
+
+        self.update_context_prev_loc(first_loc);
+        self.explode_statement(Statement::BlockStatement(stmt.block), None, ctx);
+
+        if let Some(catch_loc) = catch_loc {
+            self.jump(finally_loc.unwrap_or(after), ctx);
+            self.mark(catch_loc);
+            self.update_context_prev_loc(catch_loc);
+
+            let handler = stmt.handler.unwrap().unbox();
+            self.remove_scope(handler.scope_id.get().unwrap(), ctx);
+
+            // `_context.t0 = _context["catch"](first_loc);`
+            let safe_param = self.make_temp();
+            self.listing.push(Op::Catch(safe_param, first_loc));
+
+            let mut body = handler.body;
+            if let Some(param) = handler.param {
+                let BindingPatternKind::BindingIdentifier(ident) = &param.pattern.kind else {
+                    unreachable!()
+                };
+                let symbol_id = ident.symbol_id.get().unwrap();
+                let scope_id = ctx.symbols().get_scope_id(symbol_id);
+                ctx.scopes_mut().remove_binding(scope_id, &ident.name.as_str().into());
+                CatchParamReplacer { symbol_id, temp: safe_param, context: &self.context, ctx }
+                    .visit_block_statement(&mut body);
+            }
+            self.explode_statement(Statement::BlockStatement(body), None, ctx);
+        }
+
+        if let Some(finally_loc) = finally_loc {
+            self.mark(finally_loc);
+            self.update_context_prev_loc(finally_loc);
+            self.explode_statement(Statement::BlockStatement(stmt.finalizer.unwrap()), None, ctx);
+            self.listing.push(Op::Finish(finally_loc));
+        }
+
+        self.mark(after);
+    }
+
+    /// Explode an expression into the listing.
+    ///
+    /// Returns `None` if `ignore_result` is `true`.
+    fn explode_expression(
+        &mut self,
+        expr: Expression<'a>,
+        ignore_result: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        if !expression_contains_leap(&expr) {
+            return self.finish(expr, ignore_result, ctx);
+        }
+
+        match expr {
+            Expression::ParenthesizedExpression(expr) => {
+                self.explode_expression(expr.unbox().expression, ignore_result, ctx)
+            }
+            Expression::StaticMemberExpression(member) => {
+                let member = member.unbox();
+                let object = self.explode_expression(member.object, false, ctx).unwrap();
+                let expr = Expression::from(ctx.ast.member_expression_static(
+                    member.span,
+                    object,
+                    member.property,
+                    false,
+                ));
+                self.finish(expr, ignore_result, ctx)
+            }
+            Expression::ComputedMemberExpression(member) => {
+                let member = member.unbox();
+                let object = self.explode_expression(member.object, false, ctx).unwrap();
+                let property = self.explode_via_temp(None, member.expression, false, ctx).unwrap();
+                let expr = Expression::from(ctx.ast.member_expression_computed(
+                    member.span,
+                    object,
+                    property,
+                    false,
+                ));
+                self.finish(expr, ignore_result, ctx)
+            }
+            Expression::CallExpression(call) => {
+                let expr = self.explode_call_expression(call.unbox(), ctx);
+                self.finish(expr, ignore_result, ctx)
+            }
+            Expression::NewExpression(new_expr) => {
+                let new_expr = new_expr.unbox();
+                let callee = self.explode_via_temp(None, new_expr.callee, false, ctx).unwrap();
+                let arguments = self.explode_arguments(new_expr.arguments, ctx);
+                let expr = ctx.ast.expression_new(
+                    new_expr.span,
+                    callee,
+                    arguments,
+                    new_expr.type_parameters,
+                );
+                self.finish(expr, ignore_result, ctx)
+            }
+            Expression::ObjectExpression(object) => {
+                let object = object.unbox();
+                let mut properties = ctx.ast.vec_with_capacity(object.properties.len());
+                for property in object.properties {
+                    let property = match property {
+                        ObjectPropertyKind::ObjectProperty(mut property) => {
+                            if let Some(key) = property.key.as_expression_mut() {
+                                // `{ [yield]: 1 }` -> `_context.t0 = _context.sent; { [_context.t0]: 1 }`
+                                let key = ctx.ast.move_expression(key);
+                                let key = self.explode_via_temp(None, key, false, ctx).unwrap();
+                                property.key = PropertyKey::from(key);
+                            }
+                            if property.kind == PropertyKind::Init && !property.method {
+                                let value = ctx.ast.move_expression(&mut property.value);
+                                property.value =
+                                    self.explode_via_temp(None, value, false, ctx).unwrap();
+                                property.shorthand = false;
+                            }
+                            ObjectPropertyKind::ObjectProperty(property)
+                        }
+                        ObjectPropertyKind::SpreadProperty(spread) => {
+                            let spread = spread.unbox();
+                            let argument =
+                                self.explode_via_temp(None, spread.argument, false, ctx).unwrap();
+                            ctx.ast.object_property_kind_spread_element(spread.span, argument)
+                        }
+                    };
+                    properties.push(property);
+                }
+                let expr = ctx.ast.expression_object(object.span, properties, None);
+                self.finish(expr, ignore_result, ctx)
+            }
+            Expression::ArrayExpression(array) => {
+                let array = array.unbox();
+                let mut elements = ctx.ast.vec_with_capacity(array.elements.len());
+                for element in array.elements {
+                    let element = match element {
+                        ArrayExpressionElement::SpreadElement(spread) => {
+                            let spread = spread.unbox();
+                            let argument =
+                                self.explode_via_temp(None, spread.argument, false, ctx).unwrap();
+                            ctx.ast.array_expression_element_spread_element(spread.span, argument)
+                        }
+                        ArrayExpressionElement::Elision(_) => element,
+                        element => ArrayExpressionElement::from(
+                            self.explode_via_temp(None, element.into_expression(), false, ctx)
+                                .unwrap(),
+                        ),
+                    };
+                    elements.push(element);
+                }
+                let expr = ctx.ast.expression_array(array.span, elements, None);
+                self.finish(expr, ignore_result, ctx)
+            }
+            Expression::SequenceExpression(sequence) => {
+                let sequence = sequence.unbox();
+                let last_index = sequence.expressions.len() - 1;
+                let mut result = None;
+                for (index, expr) in sequence.expressions.into_iter().enumerate() {
+                    if index == last_index {
+                        result = self.explode_expression(expr, ignore_result, ctx);
+                    } else {
+                        self.explode_expression(expr, true, ctx);
+                    }
+                }
+                result
+            }
+            Expression::LogicalExpression(logical) => {
+                let logical = logical.unbox();
+                let after = self.loc();
+                let result = (!ignore_result).then(|| self.make_temp());
+                match logical.operator {
+                    LogicalOperator::And => {
+                        let left = self.explode_via_temp(result, logical.left, false, ctx).unwrap();
+                        self.jump_if_not(left, after, ctx);
+                    }
+                    LogicalOperator::Or => {
+                        let left = self.explode_via_temp(result, logical.left, false, ctx).unwrap();
+                        self.jump_if(left, after);
+                    }
+                    LogicalOperator::Coalesce => {
+                        // `_context.t0 = a; if (_context.t0 !== null && _context.t0 !== void 0) ...`
+                        let temp = result.unwrap_or_else(|| self.make_temp());
+                        self.explode_into_temp(Some(temp), logical.left, false, ctx);
+                        let test = self.create_not_nullish_test(temp, ctx);
+                        self.jump_if(test, after);
+                    }
+                }
+                self.explode_into_temp(result, logical.right, ignore_result, ctx);
+                self.mark(after);
+                result.map(|temp| self.temp_expression(temp, ctx))
+            }
+            Expression::ConditionalExpression(conditional) => {
+                let conditional = conditional.unbox();
+                let else_loc = self.loc();
+                let after = self.loc();
+                let test = self.explode_expression(conditional.test, false, ctx).unwrap();
+                self.jump_if_not(test, else_loc, ctx);
+                let result = (!ignore_result).then(|| self.make_temp());
+                self.explode_into_temp(result, conditional.consequent, ignore_result, ctx);
+                self.jump(after, ctx);
+                self.mark(else_loc);
+                self.explode_into_temp(result, conditional.alternate, ignore_result, ctx);
+                self.mark(after);
+                result.map(|temp| self.temp_expression(temp, ctx))
+            }
+            Expression::UnaryExpression(unary) => {
+                let unary = unary.unbox();
+                let argument = self.explode_expression(unary.argument, false, ctx).unwrap();
+                let expr = ctx.ast.expression_unary(unary.span, unary.operator, argument);
+                self.finish(expr, ignore_result, ctx)
+            }
+            Expression::BinaryExpression(binary) => {
+                let binary = binary.unbox();
+                let left = self.explode_via_temp(None, binary.left, false, ctx).unwrap();
+                let right = self.explode_via_temp(None, binary.right, false, ctx).unwrap();
+                let expr = ctx.ast.expression_binary(binary.span, left, binary.operator, right);
+                self.finish(expr, ignore_result, ctx)
+            }
+            Expression::AssignmentExpression(assign) => {
+                let assign = assign.unbox();
+                if let Some(operator) = assign.operator.to_logical_operator() {
+                    // `a.b ||= yield` -> `_context.t0 = a; _context.t0.b || (_context.t0.b = yield)`
+                    let (read, target) = self.explode_compound_target(assign.left, ctx);
+                    let right = ctx.ast.expression_assignment(
+                        SPAN,
+                        AssignmentOperator::Assign,
+                        target,
+                        assign.right,
+                    );
+                    let logical = ctx.ast.expression_logical(assign.span, read, operator, right);
+                    return self.explode_expression(logical, ignore_result, ctx);
+                }
+                let (target, right) = if let Some(operator) = assign.operator.to_binary_operator() {
+                    // `x += yield` -> `_context.t0 = x; x = _context.t0 + _context.sent`
+                    let (read, target) = self.explode_compound_target(assign.left, ctx);
+                    let temp = self.make_temp();
+                    self.emit_assign_temp(temp, read, ctx);
+                    let right = self.explode_expression(assign.right, false, ctx).unwrap();
+                    let left = self.temp_expression(temp, ctx);
+                    (target, ctx.ast.expression_binary(SPAN, left, operator, right))
+                } else {
+                    let target = self.explode_assignment_target(assign.left, ctx);
+                    (target, self.explode_expression(assign.right, false, ctx).unwrap())
+                };
+                let expr = ctx.ast.expression_assignment(
+                    assign.span,
+                    AssignmentOperator::Assign,
+                    target,
+                    right,
+                );
+                self.finish(expr, ignore_result, ctx)
+            }
+            Expression::UpdateExpression(update) => {
+                // `a[yield]++` -> `_context.t0 = a; _context.t0[_context.sent]++`
+                let update = update.unbox();
+                let argument = match update.argument {
+                    SimpleAssignmentTarget::AssignmentTargetIdentifier(_) => update.argument,
+                    argument => {
+                        let member = self.explode_member(argument.into_member_expression(), ctx);
+                        SimpleAssignmentTarget::from(self.create_exploded_member(&member, ctx))
+                    }
+                };
+                let expr = ctx.ast.expression_update(
+                    update.span,
+                    update.operator,
+                    update.prefix,
+                    argument,
+                );
+                self.finish(expr, ignore_result, ctx)
+            }
+            Expression::TemplateLiteral(template) => {
+                let mut template = template.unbox();
+                let expressions = ctx.ast.move_vec(&mut template.expressions);
+                for expr in expressions {
+                    let expr = self.explode_via_temp(None, expr, false, ctx).unwrap();
+                    template.expressions.push(expr);
+                }
+                self.finish(Expression::TemplateLiteral(ctx.alloc(template)), ignore_result, ctx)
+            }
+            Expression::PrivateInExpression(expr) => {
+                let mut expr = expr.unbox();
+                let right = ctx.ast.move_expression(&mut expr.right);
+                expr.right = self.explode_expression(right, false, ctx).unwrap();
+                self.finish(Expression::PrivateInExpression(ctx.alloc(expr)), ignore_result, ctx)
+            }
+            Expression::ImportExpression(import) => {
+                let import = import.unbox();
+                let source = self.explode_via_temp(None, import.source, false, ctx).unwrap();
+                let mut arguments = ctx.ast.vec_with_capacity(import.arguments.len());
+                for argument in import.arguments {
+                    arguments.push(self.explode_via_temp(None, argument, false, ctx).unwrap());
+                }
+                let expr = ctx.ast.expression_import(import.span, source, arguments);
+                self.finish(expr, ignore_result, ctx)
+            }
+            Expression::YieldExpression(yield_expr) => {
+                let yield_expr = yield_expr.unbox();
+                let after = self.loc();
+                let argument = yield_expr
+                    .argument
+                    .map(|argument| self.explode_expression(argument, false, ctx).unwrap());
+                if yield_expr.delegate {
+                    let result = self.make_temp();
+                    self.listing.push(Op::DelegateYield(argument.unwrap(), result, after));
+                    self.mark(after);
+                    return (!ignore_result).then(|| self.temp_expression(result, ctx));
+                }
+
+                self.listing.push(Op::SetNext(after));
+                self.emit(ctx.ast.statement_return(yield_expr.span, argument));
+                self.mark(after);
+                (!ignore_result).then(|| self.context_property("sent", ctx))
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    /// Explode an assignment target, so any leaps in it are evaluated before the value assigned.
+    fn explode_assignment_target(
+        &mut self,
+        target: AssignmentTarget<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> AssignmentTarget<'a> {
+        if !assignment_target_contains_leap(&target) {
+            return target;
+        }
+        let member = self.explode_member(target.into_member_expression(), ctx);
+        AssignmentTarget::from(self.create_exploded_member(&member, ctx))
+    }
+
+    /// Explode object and property of a member expression into temporary variables,
+    /// so it can be evaluated again without side effects.
+    ///
+    /// `a[b]` -> `_context.t0 = a; _context.t1 = b;` and returns `_context.t0[_context.t1]`.
+    /// `this` and literal properties are used as they are.
+    fn explode_member(
+        &mut self,
+        member: MemberExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ExplodedMember<'a> {
+        match member {
+            MemberExpression::StaticMemberExpression(member) => {
+                let member = member.unbox();
+                let object = self.explode_object(member.object, ctx);
+                ExplodedMember {
+                    span: member.span,
+                    object,
+                    property: ExplodedProperty::Static(member.property),
+                }
+            }
+            MemberExpression::ComputedMemberExpression(member) => {
+                let member = member.unbox();
+                let object = self.explode_object(member.object, ctx);
+                let property = self.explode_expression(member.expression, false, ctx).unwrap();
+                let property = if property.is_literal() {
+                    ExplodedProperty::Literal(property)
+                } else {
+                    let temp = self.make_temp();
+                    self.emit_assign_temp(temp, property, ctx);
+                    ExplodedProperty::Temp(temp)
+                };
+                ExplodedMember { span: member.span, object, property }
+            }
+            MemberExpression::PrivateFieldExpression(member) => {
+                let member = member.unbox();
+                let object = self.explode_object(member.object, ctx);
+                ExplodedMember {
+                    span: member.span,
+                    object,
+                    property: ExplodedProperty::Private(member.field),
+                }
+            }
+        }
+    }
+
+    /// Explode object of a member expression into a temporary variable, unless it's `this`.
+    fn explode_object(
+        &mut self,
+        object: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Temp> {
+        if matches!(object, Expression::ThisExpression(_)) {
+            return None;
+        }
+        let temp = self.make_temp();
+        self.explode_into_temp(Some(temp), object, false, ctx);
+        Some(temp)
+    }
+
+    /// Create member expression from exploded parts.
+    /// Can be called more than once, to both read and write the same member.
+    fn create_exploded_member(
+        &self,
+        member: &ExplodedMember<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> MemberExpression<'a> {
+        let object = match member.object {
+            Some(temp) => self.temp_expression(temp, ctx),
+            None => ctx.ast.expression_this(SPAN),
+        };
+        match &member.property {
+            ExplodedProperty::Static(property) => ctx.ast.member_expression_static(
+                member.span,
+                object,
+                property.clone_in(ctx.ast.allocator),
+                false,
+            ),
+            ExplodedProperty::Literal(property) => ctx.ast.member_expression_computed(
+                member.span,
+                object,
+                property.clone_in(ctx.ast.allocator),
+                false,
+            ),
+            ExplodedProperty::Temp(temp) => {
+                let property = self.temp_expression(*temp, ctx);
+                ctx.ast.member_expression_computed(member.span, object, property, false)
+            }
+            ExplodedProperty::Private(field) => ctx.ast.member_expression_private_field_expression(
+                member.span,
+                object,
+                field.clone_in(ctx.ast.allocator),
+                false,
+            ),
+        }
+    }
+
+    /// Explode target of a compound assignment, which is both read and written.
+    ///
+    /// Returns an expression to read the target, and the target to write to.
+    fn explode_compound_target(
+        &mut self,
+        target: AssignmentTarget<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Expression<'a>, AssignmentTarget<'a>) {
+        match target {
+            AssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                let read = ctx.clone_identifier_reference(&ident, ReferenceFlags::Read);
+                let read = Expression::Identifier(ctx.alloc(read));
+                (read, AssignmentTarget::AssignmentTargetIdentifier(ident))
+            }
+            target => {
+                let member = self.explode_member(target.into_member_expression(), ctx);
+                let read = Expression::from(self.create_exploded_member(&member, ctx));
+                let write = AssignmentTarget::from(self.create_exploded_member(&member, ctx));
+                (read, write)
+            }
+        }
+    }
+
+    /// `_context.t0 !== null && _context.t0 !== void 0`
+    fn create_not_nullish_test(&self, temp: Temp, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let left = self.temp_expression(temp, ctx);
+        let null = ctx.ast.expression_null_literal(SPAN);
+        let left = ctx.ast.expression_binary(SPAN, left, BinaryOperator::StrictInequality, null);
+        let right = self.temp_expression(temp, ctx);
+        let undefined = ctx.ast.void_0(SPAN);
+        let right =
+            ctx.ast.expression_binary(SPAN, right, BinaryOperator::StrictInequality, undefined);
+        ctx.ast.expression_logical(SPAN, left, LogicalOperator::And, right)
+    }
+
+    /// Emit expression as a statement if `ignore_result` is `true`, otherwise return it.
+    fn finish(
+        &mut self,
+        expr: Expression<'a>,
+        ignore_result: bool,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        if ignore_result {
+            self.emit_expression(expr, ctx);
+            None
+        } else {
+            Some(expr)
+        }
+    }
+
+    /// Explode an expression, and store the result in a temporary variable, so it's evaluated
+    /// in the correct order relative to leaps in its siblings.
+    ///
+    /// The result is only stored in a new temporary variable if it's not a literal.
+    fn explode_via_temp(
+        &mut self,
+        temp: Option<Temp>,
+        expr: Expression<'a>,
+        ignore_result: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let result = self.explode_expression(expr, ignore_result, ctx)?;
+        if temp.is_none()
+            && (result.is_literal() || matches!(result, Expression::TemplateLiteral(_)))
+        {
+            return Some(result);
+        }
+        let temp = temp.unwrap_or_else(|| self.make_temp());
+        self.emit_assign_temp(temp, result, ctx);
+        Some(self.temp_expression(temp, ctx))
+    }
+
+    /// Explode an expression, and store the result in `temp`, or emit it as a statement
+    /// if there's no `temp`.
+    fn explode_into_temp(
+        &mut self,
+        temp: Option<Temp>,
+        expr: Expression<'a>,
+        ignore_result: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(result) = self.explode_expression(expr, ignore_result, ctx) {
+            match temp {
+                Some(temp) => self.emit_assign_temp(temp, result, ctx),
+                None => self.emit_expression(result, ctx),
+            }
+        }
+    }
+
+    fn explode_call_expression(
+        &mut self,
+        call: CallExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let has_leaping_arguments = arguments_contain_leap(&call.arguments);
+
+        let mut this_argument = None;
+        let callee = match call.callee {
+            Expression::StaticMemberExpression(member) if has_leaping_arguments => {
+                // `a.b(yield)` -> `_context.t0 = a; ...; _context.t0.b.call(_context.t0, _context.t1)`
+                let member = member.unbox();
+                let temp = self.make_temp();
+                let object = self.explode_via_temp(Some(temp), member.object, false, ctx).unwrap();
+                this_argument = Some(temp);
+                let callee = Expression::from(ctx.ast.member_expression_static(
+                    member.span,
+                    object,
+                    member.property,
+                    false,
+                ));
+                create_static_member(callee, "call", ctx)
+            }
+            Expression::ComputedMemberExpression(member) if has_leaping_arguments => {
+                let member = member.unbox();
+                let temp = self.make_temp();
+                let object = self.explode_via_temp(Some(temp), member.object, false, ctx).unwrap();
+                this_argument = Some(temp);
+                let property = self.explode_via_temp(None, member.expression, false, ctx).unwrap();
+                let callee = Expression::from(ctx.ast.member_expression_computed(
+                    member.span,
+                    object,
+                    property,
+                    false,
+                ));
+                create_static_member(callee, "call", ctx)
+            }
+            Expression::PrivateFieldExpression(member) if has_leaping_arguments => {
+                let member = member.unbox();
+                let temp = self.make_temp();
+                let object = self.explode_via_temp(Some(temp), member.object, false, ctx).unwrap();
+                this_argument = Some(temp);
+                let callee = Expression::from(ctx.ast.member_expression_private_field_expression(
+                    member.span,
+                    object,
+                    member.field,
+                    false,
+                ));
+                create_static_member(callee, "call", ctx)
+            }
+            callee if callee.is_member_expression() => {
+                self.explode_expression(callee, false, ctx).unwrap()
+            }
+            callee => {
+                let callee = self.explode_via_temp(None, callee, false, ctx).unwrap();
+                if callee.is_member_expression() {
+                    // Call without a `this` value: `(0, _context.t0)()`
+                    let zero = create_number(0, ctx);
+                    ctx.ast.expression_sequence(SPAN, ctx.ast.vec_from_iter([zero, callee]))
+                } else {
+                    callee
+                }
+            }
+        };
+
+        let arguments = if has_leaping_arguments {
+            let mut arguments = self.explode_arguments(call.arguments, ctx);
+            if let Some(this_argument) = this_argument {
+                arguments.insert(0, Argument::from(self.temp_expression(this_argument, ctx)));
+            }
+            arguments
+        } else {
+            call.arguments
+        };
+
+        ctx.ast.expression_call(call.span, callee, call.type_parameters, arguments, false)
+    }
+
+    fn explode_arguments(
+        &mut self,
+        arguments: oxc_allocator::Vec<'a, Argument<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> oxc_allocator::Vec<'a, Argument<'a>> {
+        let mut exploded = ctx.ast.vec_with_capacity(arguments.len() + 1);
+        for argument in arguments {
+            let argument = match argument {
+                Argument::SpreadElement(spread) => {
+                    let spread = spread.unbox();
+                    let argument =
+                        self.explode_via_temp(None, spread.argument, false, ctx).unwrap();
+                    ctx.ast.argument_spread_element(spread.span, argument)
+                }
+                argument => Argument::from(
+                    self.explode_via_temp(None, argument.into_expression(), false, ctx).unwrap(),
+                ),
+            };
+            exploded.push(argument);
+        }
+        exploded
+    }
+
+    /// Remove scope of a statement which is flattened into the listing.
+    ///
+    /// Any bindings remaining in it (function declarations) are moved to scope of the `switch`
+    /// statement in the dispatch loop.
+    fn remove_scope(&self, scope_id: ScopeId, ctx: &mut TraverseCtx<'a>) {
+        let bindings = ctx
+            .scopes()
+            .get_bindings(scope_id)
+            .iter()
+            .map(|(name, &symbol_id)| (name.clone(), symbol_id))
+            .collect::<Vec<_>>();
+        for (name, symbol_id) in bindings {
+            ctx.scopes_mut().move_binding(scope_id, self.switch_scope_id, &name);
+            ctx.symbols_mut().set_scope_id(symbol_id, self.switch_scope_id);
+        }
+        ctx.scopes_mut().delete_scope(scope_id);
+    }
+
+    /// Create tries locations list to pass to `regeneratorRuntime().wrap()`.
+    ///
+    /// `[[first, catch, finally, after], ...]`. Omitted locations are left as holes.
+    pub fn get_try_locs_list(&self, ctx: &TraverseCtx<'a>) -> Option<Expression<'a>> {
+        if self.try_entries.is_empty() {
+            return None;
+        }
+
+        let entries = ctx.ast.vec_from_iter(self.try_entries.iter().map(|entry| {
+            let mut locs = ctx.ast.vec_with_capacity(4);
+            locs.push(ArrayExpressionElement::from(create_number(
+                self.loc_value(entry.first),
+                ctx,
+            )));
+            locs.push(match entry.catch {
+                Some(loc) => ArrayExpressionElement::from(create_number(self.loc_value(loc), ctx)),
+                None => ctx.ast.array_expression_element_elision(SPAN),
+            });
+            if let Some(finally_loc) = entry.finally {
+                for loc in [finally_loc, entry.after] {
+                    locs.push(ArrayExpressionElement::from(create_number(
+                        self.loc_value(loc),
+                        ctx,
+                    )));
+                }
+            }
+            ArrayExpressionElement::from(ctx.ast.expression_array(SPAN, locs, None))
+        }));
+        Some(ctx.ast.expression_array(SPAN, entries, None))
+    }
+
+    /// Create the dispatch loop from the listing.
+    ///
+    /// `while (1) switch (_context.prev = _context.next) { case 0: ... }`
+    pub fn into_dispatch_loop(mut self, ctx: &mut TraverseCtx<'a>) -> Statement<'a> {
+        let listing = std::mem::take(&mut self.listing);
+        let final_loc = listing.len();
+
+        let mut cases = ctx.ast.vec_with_capacity(self.marked.len() + 2);
+        let mut current: Option<(usize, oxc_allocator::Vec<'a, Statement<'a>>)> = None;
+        let mut already_ended = false;
+        for (index, op) in listing.into_iter().enumerate() {
+            if self.marked.contains(&index) {
+                if let Some((index, consequent)) = current.take() {
+                    cases.push(ctx.ast.switch_case(
+                        SPAN,
+                        Some(create_number(index, ctx)),
+                        consequent,
+                    ));
+                }
+                current = Some((index, ctx.ast.vec()));
+                already_ended = false;
+            }
+
+            let stmt = self.render(op, ctx);
+            // Statements after a completion statement in the same `case` are unreachable
+            if already_ended {
+                StatementRemover { depth: 0, ctx }.visit_statement(&stmt);
+                continue;
+            }
+            already_ended = matches!(
+                stmt,
+                Statement::BreakStatement(_)
+                    | Statement::ContinueStatement(_)
+                    | Statement::ReturnStatement(_)
+                    | Statement::ThrowStatement(_)
+            );
+            ScopeReparenter { parent_scope_id: self.switch_scope_id, depth: 0, ctx }
+                .visit_statement(&stmt);
+            FlagFixer::new(ctx).visit_statement(&stmt);
+            current.as_mut().unwrap().1.push(stmt);
+        }
+        if let Some((index, consequent)) = current {
+            cases.push(ctx.ast.switch_case(SPAN, Some(create_number(index, ctx)), consequent));
+        }
+
+        // `case 5: case "end": return _context.stop();`
+        cases.push(ctx.ast.switch_case(SPAN, Some(create_number(final_loc, ctx)), ctx.ast.vec()));
+        let stop = self.context_property("stop", ctx);
+        let stop = ctx.ast.expression_call(SPAN, stop, NONE, ctx.ast.vec(), false);
+        cases.push(ctx.ast.switch_case(
+            SPAN,
+            Some(ctx.ast.expression_string_literal(SPAN, "end")),
+            ctx.ast.vec1(ctx.ast.statement_return(SPAN, Some(stop))),
+        ));
+
+        // `_context.prev = _context.next`
+        let next = self.context_property("next", ctx);
+        let prev = self.context_target("prev", ctx);
+        let discriminant =
+            ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, prev, next);
+        let switch = Statement::SwitchStatement(ctx.ast.alloc_switch_statement_with_scope_id(
+            SPAN,
+            discriminant,
+            cases,
+            self.switch_scope_id,
+        ));
+        ctx.ast.statement_while(SPAN, create_number(1, ctx), switch)
+    }
+
+    /// Convert an entry in the listing to a statement.
+    fn render(&self, op: Op<'a>, ctx: &mut TraverseCtx<'a>) -> Statement<'a> {
+        match op {
+            Op::Statement(stmt) => stmt,
+            Op::SetNext(loc) => self.create_set_context_loc("next", loc, ctx),
+            Op::SetPrev(loc) => self.create_set_context_loc("prev", loc, ctx),
+            Op::SetNextSwitch(tests, default_loc) => {
+                let mut value = create_number(self.loc_value(default_loc), ctx);
+                for (test, loc) in tests.into_iter().rev() {
+                    let loc = create_number(self.loc_value(loc), ctx);
+                    value = ctx.ast.expression_conditional(SPAN, test, loc, value);
+                }
+                let target = self.context_target("next", ctx);
+                let expr =
+                    ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+                ctx.ast.statement_expression(SPAN, expr)
+            }
+            Op::JumpIf(test, loc) => {
+                let scope_id = ctx.create_child_scope(self.switch_scope_id, ScopeFlags::empty());
+                let body = ctx.ast.vec_from_iter([
+                    self.create_set_context_loc("next", loc, ctx),
+                    ctx.ast.statement_break(SPAN, None),
+                ]);
+                let block = Statement::BlockStatement(
+                    ctx.ast.alloc_block_statement_with_scope_id(SPAN, body, scope_id),
+                );
+                ctx.ast.statement_if(SPAN, test, block, None)
+            }
+            Op::Abrupt(kind, loc) => {
+                let arguments = ctx.ast.vec_from_iter([
+                    Argument::from(ctx.ast.expression_string_literal(SPAN, kind)),
+                    Argument::from(create_number(self.loc_value(loc), ctx)),
+                ]);
+                self.create_return_context_call("abrupt", arguments, ctx)
+            }
+            Op::DelegateYield(iterable, temp, loc) => {
+                let temp_name = ctx.ast.str(&temp_name(temp));
+                let arguments = ctx.ast.vec_from_iter([
+                    Argument::from(iterable),
+                    Argument::from(ctx.ast.expression_string_literal(SPAN, temp_name)),
+                    Argument::from(create_number(self.loc_value(loc), ctx)),
+                ]);
+                self.create_return_context_call("delegateYield", arguments, ctx)
+            }
+            Op::Catch(temp, loc) => {
+                // `_context["catch"](0)`
+                let callee = Expression::from(ctx.ast.member_expression_computed(
+                    SPAN,
+                    self.context.create_read_expression(ctx),
+                    ctx.ast.expression_string_literal(SPAN, "catch"),
+                    false,
+                ));
+                let arguments =
+                    ctx.ast.vec1(Argument::from(create_number(self.loc_value(loc), ctx)));
+                let value = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+                let target = self.temp_target(temp, ctx);
+                let expr =
+                    ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+                ctx.ast.statement_expression(SPAN, expr)
+            }
+            Op::Finish(loc) => {
+                let arguments =
+                    ctx.ast.vec1(Argument::from(create_number(self.loc_value(loc), ctx)));
+                self.create_return_context_call("finish", arguments, ctx)
+            }
+        }
+    }
+
+    /// `_context.next = 1;`
+    fn create_set_context_loc(
+        &self,
+        name: &'static str,
+        loc: Loc,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let target = self.context_target(name, ctx);
+        let value = create_number(self.loc_value(loc), ctx);
+        let expr = ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+        ctx.ast.statement_expression(SPAN, expr)
+    }
+
+    /// `return _context.abrupt(...);`
+    fn create_return_context_call(
+        &self,
+        name: &'static str,
+        arguments: oxc_allocator::Vec<'a, Argument<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let callee = self.context_property(name, ctx);
+        let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+        ctx.ast.statement_return(SPAN, Some(call))
+    }
+
+    /// `_context.name`
+    fn context_property(&self, name: &'a str, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        create_static_member(self.context.create_read_expression(ctx), name, ctx)
+    }
+
+    /// `_context.name` as an assignment target
+    fn context_target(&self, name: &'a str, ctx: &mut TraverseCtx<'a>) -> AssignmentTarget<'a> {
+        let object = self.context.create_read_expression(ctx);
+        AssignmentTarget::from(ctx.ast.member_expression_static(
+            SPAN,
+            object,
+            ctx.ast.identifier_name(SPAN, name),
+            false,
+        ))
+    }
+
+    /// `_context.t0`
+    fn temp_expression(&self, temp: Temp, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        create_temp_expression(&self.context, temp, ctx)
+    }
+
+    /// `_context.t0` as an assignment target
+    fn temp_target(&self, temp: Temp, ctx: &mut TraverseCtx<'a>) -> AssignmentTarget<'a> {
+        let name = ctx.ast.str(&temp_name(temp));
+        self.context_target(name, ctx)
+    }
+
+    /// `babelHelpers.regeneratorRuntime().name`
+    fn runtime_property(&self, name: &'a str, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let runtime = self.ctx.helper_call_expr(Helper::RegeneratorRuntime, ctx.ast.vec(), ctx);
+        create_static_member(runtime, name, ctx)
+    }
+}
+
+/// Member expression with object and property exploded into temporary variables.
+struct ExplodedMember<'a> {
+    span: Span,
+    /// `None` for `this`
+    object: Option<Temp>,
+    property: ExplodedProperty<'a>,
+}
+
+enum ExplodedProperty<'a> {
+    Static(IdentifierName<'a>),
+    Literal(Expression<'a>),
+    Temp(Temp),
+    Private(PrivateIdentifier<'a>),
+}
+
+fn temp_name(temp: Temp) -> String {
+    format!("t{}", temp.0)
+}
+
+fn create_temp_expression<'a>(
+    context: &BoundIdentifier<'a>,
+    temp: Temp,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let name = ctx.ast.str(&temp_name(temp));
+    create_static_member(context.create_read_expression(ctx), name, ctx)
+}
+
+fn create_static_member<'a>(
+    object: Expression<'a>,
+    name: &'a str,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    Expression::from(ctx.ast.member_expression_static(
+        SPAN,
+        object,
+        ctx.ast.identifier_name(SPAN, name),
+        false,
+    ))
+}
+
+fn create_number<'a>(value: usize, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+    #[expect(clippy::cast_precision_loss)]
+    let number = value as f64;
+    ctx.ast.expression_numeric_literal(
+        SPAN,
+        number,
+        ctx.ast.str(&value.to_string()),
+        NumberBase::Decimal,
+    )
+}
+
+/// Replace references to catch parameter with a temporary variable on the context object.
+///
+/// `catch (e) { console.log(e); }` -> `_context.t0 = _context["catch"](0); console.log(_context.t0);`
+struct CatchParamReplacer<'a, 'e, 'ctx> {
+    symbol_id: SymbolId,
+    temp: Temp,
+    context: &'e BoundIdentifier<'a>,
+    ctx: &'ctx mut TraverseCtx<'a>,
+}
+
+impl<'a, 'e, 'ctx> CatchParamReplacer<'a, 'e, 'ctx> {
+    fn is_param(&self, ident: &IdentifierReference<'a>) -> bool {
+        let reference_id = ident.reference_id().unwrap();
+        self.ctx.symbols().get_reference(reference_id).symbol_id() == Some(self.symbol_id)
+    }
+
+    fn create_member(&mut self) -> MemberExpression<'a> {
+        let object = self.context.create_read_expression(self.ctx);
+        let name = self.ctx.ast.str(&temp_name(self.temp));
+        self.ctx.ast.member_expression_static(
+            SPAN,
+            object,
+            self.ctx.ast.identifier_name(SPAN, name),
+            false,
+        )
+    }
+}
+
+impl<'a, 'e, 'ctx> VisitMut<'a> for CatchParamReplacer<'a, 'e, 'ctx> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if let Expression::Identifier(ident) = expr {
+            if self.is_param(ident) {
+                self.ctx.delete_reference_for_identifier(ident);
+                *expr = Expression::from(self.create_member());
+                return;
+            }
+        }
+        walk_mut::walk_expression(self, expr);
+    }
+
+    fn visit_simple_assignment_target(&mut self, target: &mut SimpleAssignmentTarget<'a>) {
+        if let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) = target {
+            if self.is_param(ident) {
+                self.ctx.delete_reference_for_identifier(ident);
+                *target = SimpleAssignmentTarget::from(self.create_member());
+                return;
+            }
+        }
+        walk_mut::walk_simple_assignment_target(self, target);
+    }
+}
+
+/// Remove an unreachable statement's references, bindings and scopes.
+struct StatementRemover<'a, 'ctx> {
+    depth: usize,
+    ctx: &'ctx mut TraverseCtx<'a>,
+}
+
+impl<'a, 'ctx> Visit<'a> for StatementRemover<'a, 'ctx> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        if self.depth == 0 {
+            if let Some(scope_id) = scope_id.get() {
+                self.ctx.scopes_mut().delete_scope(scope_id);
+            }
+        }
+        self.depth += 1;
+    }
+
+    fn leave_scope(&mut self) {
+        self.depth -= 1;
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        self.ctx.delete_reference_for_identifier(ident);
+    }
+
+    fn visit_binding_identifier(&mut self, ident: &BindingIdentifier<'a>) {
+        if let Some(symbol_id) = ident.symbol_id.get() {
+            let scope_id = self.ctx.symbols().get_scope_id(symbol_id);
+            self.ctx.scopes_mut().remove_binding(scope_id, &ident.name.to_compact_str());
+        }
+    }
+}
+
+/// Move top level scopes in a statement to be children of scope of `switch` statement
+/// in the dispatch loop.
+struct ScopeReparenter<'a, 'ctx> {
+    parent_scope_id: ScopeId,
+    depth: usize,
+    ctx: &'ctx mut TraverseCtx<'a>,
+}
+
+impl<'a, 'ctx> Visit<'a> for ScopeReparenter<'a, 'ctx> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        if self.depth == 0 {
+            if let Some(scope_id) = scope_id.get() {
+                self.ctx.scopes_mut().change_parent_id(scope_id, Some(self.parent_scope_id));
+            }
+        }
+        self.depth += 1;
+    }
+
+    fn leave_scope(&mut self) {
+        self.depth -= 1;
+    }
+}
+
+/// Update flags of references which are assigned to.
+///
+/// Exploding expressions can move assignments in and out of statement position, e.g.
+/// `for (i = 0; ...; i++)` -> `i = 0;` and `i++;`, which changes whether they're a read reference.
+struct FlagFixer<'a, 'ctx> {
+    statement_assignment: *const AssignmentExpression<'a>,
+    statement_update: *const UpdateExpression<'a>,
+    ctx: &'ctx mut TraverseCtx<'a>,
+}
+
+impl<'a, 'ctx> FlagFixer<'a, 'ctx> {
+    fn new(ctx: &'ctx mut TraverseCtx<'a>) -> Self {
+        Self { statement_assignment: std::ptr::null(), statement_update: std::ptr::null(), ctx }
+    }
+
+    fn set_flags(&mut self, ident: &IdentifierReference<'a>, flags: ReferenceFlags) {
+        let reference_id = ident.reference_id().unwrap();
+        *self.ctx.symbols_mut().get_reference_mut(reference_id).flags_mut() = flags;
+    }
+}
+
+impl<'a, 'ctx> Visit<'a> for FlagFixer<'a, 'ctx> {
+    fn visit_expression_statement(&mut self, stmt: &ExpressionStatement<'a>) {
+        match stmt.expression.without_parentheses() {
+            Expression::AssignmentExpression(assign) => self.statement_assignment = &**assign,
+            Expression::UpdateExpression(update) => self.statement_update = &**update,
+            _ => {}
+        }
+        walk::walk_expression_statement(self, stmt);
+    }
+
+    fn visit_assignment_expression(&mut self, expr: &AssignmentExpression<'a>) {
+        if let AssignmentTarget::AssignmentTargetIdentifier(ident) = &expr.left {
+            let flags = if expr.operator == AssignmentOperator::Assign
+                && std::ptr::eq(expr, self.statement_assignment)
+            {
+                ReferenceFlags::Write
+            } else {
+                ReferenceFlags::Read | ReferenceFlags::Write
+            };
+            self.set_flags(ident, flags);
+        }
+        walk::walk_assignment_expression(self, expr);
+    }
+
+    fn visit_update_expression(&mut self, expr: &UpdateExpression<'a>) {
+        if let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) = &expr.argument {
+            let flags = if std::ptr::eq(expr, self.statement_update) {
+                ReferenceFlags::Write
+            } else {
+                ReferenceFlags::Read | ReferenceFlags::Write
+            };
+            self.set_flags(ident, flags);
+        }
+        walk::walk_update_expression(self, expr);
+    }
+
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _arrow: &ArrowFunctionExpression<'a>) {}
+
+    fn visit_class(&mut self, _class: &Class<'a>) {}
+}
@@ -0,0 +1,435 @@
+//! Hoisting of declarations out of generator function bodies.
+//!
+//! The body of a generator function is moved into an inner function which is called again
+//! each time the generator resumes, so all variables declared in the body must be hoisted
+//! into the outer function, in order to preserve their values between calls.
+//!
+//! * `var`, `let` and `const` declarations are converted to assignments, and the bindings hoisted
+//!   to a single `var` declaration in the outer function.
+//!   Destructuring declarations are converted to destructuring assignments.
+//!   `let` and `const` bindings are renamed if hoisting them would shadow another binding.
+//! * Class declarations are converted to assignments of class expressions, and hoisted likewise.
+//! * Destructuring `catch` parameters are replaced with a plain parameter, which is destructured
+//!   at start of the `catch` block.
+//! * `arguments` is replaced with a reference to `_args` var declared in the outer function.
+//! * Usage of `this` is recorded, so it can be passed to inner function.
+//!
+//! Based on `hoist.js` and `argumentsThisVisitor` in regenerator-transform.
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_allocator::Box as ArenaBox;
+use oxc_ast::{
+    ast::*,
+    visit::{walk, walk_mut},
+    Visit, VisitMut,
+};
+use oxc_ecmascript::BoundNames;
+use oxc_span::{Atom, Span, SPAN};
+use oxc_syntax::{
+    operator::AssignmentOperator,
+    scope::{ScopeFlags, ScopeId},
+    symbol::{SymbolFlags, SymbolId},
+};
+use oxc_traverse::{BoundIdentifier, TraverseCtx};
+
+use crate::es2015::binding_pattern_to_assignment_target;
+
+/// Result of hoisting declarations from body of a generator function.
+pub(super) struct Hoisted<'a> {
+    /// Bindings to declare in outer function, with spans of their original declarations
+    pub vars: Vec<(BoundIdentifier<'a>, Span)>,
+    /// `_args` binding, if `arguments` is used in the function body
+    pub args: Option<BoundIdentifier<'a>>,
+    /// `true` if `this` is used in the function body
+    pub uses_this: bool,
+}
+
+/// Hoist declarations out of function body, and replace `arguments`.
+pub(super) fn hoist<'a>(
+    func: &mut Function<'a>,
+    function_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) -> Hoisted<'a> {
+    let body = func.body.as_mut().unwrap();
+
+    let mut collector = LexicalCollector { symbol_ids: vec![], depth: 0 };
+    collector.visit_function_body(body);
+    let renamed = hoist_lexical_bindings(&collector.symbol_ids, function_scope_id, ctx);
+
+    let mut params = FxHashSet::default();
+    for param in &func.params.items {
+        param.pattern.bound_names(&mut |ident| {
+            params.insert(ident.symbol_id.get().unwrap());
+        });
+    }
+    if let Some(rest) = &func.params.rest {
+        rest.argument.bound_names(&mut |ident| {
+            params.insert(ident.symbol_id.get().unwrap());
+        });
+    }
+
+    let mut hoister = Hoister {
+        function_scope_id,
+        renamed,
+        params,
+        seen: FxHashSet::default(),
+        vars: vec![],
+        args: None,
+        uses_this: false,
+        depth: 0,
+        function_depth: 0,
+        ctx,
+    };
+    hoister.visit_function_body(body);
+
+    Hoisted { vars: hoister.vars, args: hoister.args, uses_this: hoister.uses_this }
+}
+
+/// Move `let` and `const` bindings into function scope, and convert them to function-scoped
+/// variables. Rename any which would clash with another binding.
+///
+/// Returns map of renamed symbols.
+fn hoist_lexical_bindings<'a>(
+    symbol_ids: &[SymbolId],
+    function_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) -> FxHashMap<SymbolId, Atom<'a>> {
+    let mut renamed = FxHashMap::default();
+    for &symbol_id in symbol_ids {
+        *ctx.symbols_mut().get_flags_mut(symbol_id) = SymbolFlags::FunctionScopedVariable;
+
+        let scope_id = ctx.symbols().get_scope_id(symbol_id);
+        if scope_id == function_scope_id {
+            continue;
+        }
+
+        let name = ctx.symbols().get_name(symbol_id).to_string();
+        let clashes = ctx.scopes().find_binding(function_scope_id, &name).is_some()
+            || ctx.scopes().root_unresolved_references().contains_key(name.as_str());
+        let new_name = if clashes { ctx.generate_uid_name(&name) } else { name.as_str().into() };
+
+        ctx.scopes_mut().remove_binding(scope_id, &name.as_str().into());
+        ctx.scopes_mut().add_binding(function_scope_id, new_name.clone(), symbol_id);
+        ctx.symbols_mut().set_scope_id(symbol_id, function_scope_id);
+        if clashes {
+            ctx.symbols_mut().set_name(symbol_id, new_name.clone());
+            renamed.insert(symbol_id, ctx.ast.atom(&new_name));
+        }
+    }
+    renamed
+}
+
+/// Visitor which collects `let`, `const`, class and destructured `catch` parameter bindings
+/// which are not in nested functions.
+struct LexicalCollector {
+    symbol_ids: Vec<SymbolId>,
+    depth: u32,
+}
+
+impl<'a> Visit<'a> for LexicalCollector {
+    fn visit_variable_declaration(&mut self, decl: &VariableDeclaration<'a>) {
+        if self.depth == 0 && decl.kind.is_lexical() {
+            for declarator in &decl.declarations {
+                declarator.id.bound_names(&mut |ident| {
+                    self.symbol_ids.push(ident.symbol_id.get().unwrap());
+                });
+            }
+        }
+        walk::walk_variable_declaration(self, decl);
+    }
+
+    fn visit_function(&mut self, func: &Function<'a>, flags: ScopeFlags) {
+        self.depth += 1;
+        walk::walk_function(self, func, flags);
+        self.depth -= 1;
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &ArrowFunctionExpression<'a>) {
+        self.depth += 1;
+        walk::walk_arrow_function_expression(self, arrow);
+        self.depth -= 1;
+    }
+
+    fn visit_class(&mut self, class: &Class<'a>) {
+        if self.depth == 0 && class.is_declaration() {
+            if let Some(id) = &class.id {
+                self.symbol_ids.push(id.symbol_id.get().unwrap());
+            }
+        }
+        self.depth += 1;
+        walk::walk_class(self, class);
+        self.depth -= 1;
+    }
+
+    fn visit_catch_parameter(&mut self, param: &CatchParameter<'a>) {
+        if self.depth == 0 && !param.pattern.kind.is_binding_identifier() {
+            param.pattern.bound_names(&mut |ident| {
+                self.symbol_ids.push(ident.symbol_id.get().unwrap());
+            });
+        }
+        walk::walk_catch_parameter(self, param);
+    }
+}
+
+/// Visitor which converts declarations to assignments and replaces `arguments`.
+struct Hoister<'a, 'c> {
+    function_scope_id: ScopeId,
+    renamed: FxHashMap<SymbolId, Atom<'a>>,
+    /// Symbols for function's parameters, which must not be redeclared
+    params: FxHashSet<SymbolId>,
+    /// Symbols which have been added to `vars`
+    seen: FxHashSet<SymbolId>,
+    vars: Vec<(BoundIdentifier<'a>, Span)>,
+    args: Option<BoundIdentifier<'a>>,
+    uses_this: bool,
+    /// Depth of nested functions, arrow functions and classes
+    depth: u32,
+    /// Depth of nested functions and classes, which have their own `this` and `arguments`
+    function_depth: u32,
+    ctx: &'c mut TraverseCtx<'a>,
+}
+
+impl<'a, 'c> VisitMut<'a> for Hoister<'a, 'c> {
+    fn visit_statements(&mut self, stmts: &mut oxc_allocator::Vec<'a, Statement<'a>>) {
+        walk_mut::walk_statements(self, stmts);
+        if self.depth == 0 {
+            // Remove declarations which had no initializers
+            stmts.retain(
+                |stmt| !matches!(stmt, Statement::EmptyStatement(empty) if empty.span.is_unspanned()),
+            );
+        }
+    }
+
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        if self.depth == 0 {
+            match stmt {
+                Statement::VariableDeclaration(decl) => {
+                    let span = decl.span;
+                    *stmt = match self.convert_declaration(decl) {
+                        Some(expr) => self.ctx.ast.statement_expression(span, expr),
+                        None => self.ctx.ast.statement_empty(SPAN),
+                    };
+                }
+                Statement::ClassDeclaration(_) => {
+                    let Statement::ClassDeclaration(class) = self.ctx.ast.move_statement(stmt)
+                    else {
+                        unreachable!()
+                    };
+                    let span = class.span;
+                    let expr = self.convert_class(class);
+                    *stmt = self.ctx.ast.statement_expression(span, expr);
+                }
+                _ => {}
+            }
+        }
+        walk_mut::walk_statement(self, stmt);
+    }
+
+    fn visit_catch_clause(&mut self, clause: &mut CatchClause<'a>) {
+        if self.depth == 0 {
+            if let Some(param) = &mut clause.param {
+                if !param.pattern.kind.is_binding_identifier() {
+                    self.convert_catch_parameter(
+                        param,
+                        &mut clause.body,
+                        clause.scope_id.get().unwrap(),
+                    );
+                }
+            }
+        }
+        walk_mut::walk_catch_clause(self, clause);
+    }
+
+    fn visit_for_statement(&mut self, stmt: &mut ForStatement<'a>) {
+        if self.depth == 0 {
+            if let Some(ForStatementInit::VariableDeclaration(decl)) = &mut stmt.init {
+                stmt.init = self
+                    .convert_declaration(decl)
+                    .map(|expr| self.ctx.ast.for_statement_init_expression(expr));
+            }
+        }
+        walk_mut::walk_for_statement(self, stmt);
+    }
+
+    fn visit_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>) {
+        if self.depth == 0 {
+            self.convert_for_statement_left(&mut stmt.left);
+        }
+        walk_mut::walk_for_in_statement(self, stmt);
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>) {
+        if self.depth == 0 {
+            self.convert_for_statement_left(&mut stmt.left);
+        }
+        walk_mut::walk_for_of_statement(self, stmt);
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        let reference_id = ident.reference_id.get().unwrap();
+        let reference = self.ctx.symbols().get_reference(reference_id);
+        match reference.symbol_id() {
+            Some(symbol_id) => {
+                if let Some(name) = self.renamed.get(&symbol_id) {
+                    ident.name = name.clone();
+                }
+            }
+            None => {
+                if self.function_depth == 0 && ident.name == "arguments" {
+                    let flags = reference.flags();
+                    self.ctx.delete_reference_for_identifier(ident);
+                    let args = self.args.get_or_insert_with(|| {
+                        self.ctx.generate_uid(
+                            "args",
+                            self.function_scope_id,
+                            SymbolFlags::FunctionScopedVariable,
+                        )
+                    });
+                    *ident = args.create_spanned_reference(ident.span, flags, self.ctx);
+                }
+            }
+        }
+    }
+
+    fn visit_this_expression(&mut self, _it: &mut ThisExpression) {
+        if self.function_depth == 0 {
+            self.uses_this = true;
+        }
+    }
+
+    fn visit_function(&mut self, func: &mut Function<'a>, flags: ScopeFlags) {
+        self.depth += 1;
+        self.function_depth += 1;
+        walk_mut::walk_function(self, func, flags);
+        self.depth -= 1;
+        self.function_depth -= 1;
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &mut ArrowFunctionExpression<'a>) {
+        self.depth += 1;
+        walk_mut::walk_arrow_function_expression(self, arrow);
+        self.depth -= 1;
+    }
+
+    fn visit_class(&mut self, class: &mut Class<'a>) {
+        self.depth += 1;
+        self.function_depth += 1;
+        walk_mut::walk_class(self, class);
+        self.depth -= 1;
+        self.function_depth -= 1;
+    }
+}
+
+impl<'a, 'c> Hoister<'a, 'c> {
+    /// Convert `var a = 1, b, c = 2` to `a = 1, c = 2`, and hoist the bindings.
+    ///
+    /// `let` declarations without an initializer are assigned `void 0`, as they may be in a loop.
+    /// Destructuring declarations are converted to destructuring assignments.
+    /// `const { a, b: [c] } = obj` -> `({ a: a, b: [c] } = obj)`
+    ///
+    /// Returns `None` if there are no assignments.
+    fn convert_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+    ) -> Option<Expression<'a>> {
+        let is_lexical = decl.kind.is_lexical();
+        let mut assignments = self.ctx.ast.vec();
+        for declarator in self.ctx.ast.move_vec(&mut decl.declarations) {
+            let init = match declarator.init {
+                Some(init) => init,
+                None if is_lexical => self.ctx.ast.void_0(SPAN),
+                None => {
+                    // `var x;`. Only hoist the binding, don't create a reference to it.
+                    let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind else {
+                        unreachable!()
+                    };
+                    self.hoist_binding(ident);
+                    continue;
+                }
+            };
+            let target = self.convert_binding_pattern(declarator.id);
+            assignments.push(self.ctx.ast.expression_assignment(
+                declarator.span,
+                AssignmentOperator::Assign,
+                target,
+                init,
+            ));
+        }
+
+        match assignments.len() {
+            0 => None,
+            1 => assignments.pop(),
+            _ => Some(self.ctx.ast.expression_sequence(decl.span, assignments)),
+        }
+    }
+
+    /// Convert `for (var x in obj)` to `for (x in obj)`, and hoist the binding.
+    fn convert_for_statement_left(&mut self, left: &mut ForStatementLeft<'a>) {
+        let ForStatementLeft::VariableDeclaration(decl) = left else { return };
+        let declarator = self.ctx.ast.move_vec(&mut decl.declarations).into_iter().next().unwrap();
+        *left = ForStatementLeft::from(self.convert_binding_pattern(declarator.id));
+    }
+
+    /// Convert `class A {}` to `A = class {}`, and hoist the binding.
+    ///
+    /// The class expression is anonymous, so it is named `A` by the assignment, and references to
+    /// `A` inside the class resolve to the hoisted binding.
+    fn convert_class(&mut self, mut class: ArenaBox<'a, Class<'a>>) -> Expression<'a> {
+        let ident = class.id.take().unwrap();
+        let target = self.convert_binding_identifier(&ident);
+        class.r#type = ClassType::ClassExpression;
+        self.ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            target,
+            Expression::ClassExpression(class),
+        )
+    }
+
+    /// Convert `catch ({ a }) {}` to `catch (_ref) { ({ a: a } = _ref); }`, and hoist the bindings.
+    fn convert_catch_parameter(
+        &mut self,
+        param: &mut CatchParameter<'a>,
+        body: &mut ArenaBox<'a, BlockStatement<'a>>,
+        catch_scope_id: ScopeId,
+    ) {
+        let binding = self.ctx.generate_uid("ref", catch_scope_id, SymbolFlags::CatchVariable);
+        let pattern =
+            std::mem::replace(&mut param.pattern, binding.create_binding_pattern(self.ctx));
+        let target = self.convert_binding_pattern(pattern);
+        let value = binding.create_read_expression(self.ctx);
+        let expr =
+            self.ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+        body.body.insert(0, self.ctx.ast.statement_expression(SPAN, expr));
+    }
+
+    /// Convert a binding pattern to an assignment target, and hoist its bindings.
+    fn convert_binding_pattern(&mut self, pattern: BindingPattern<'a>) -> AssignmentTarget<'a> {
+        let ast = self.ctx.ast;
+        binding_pattern_to_assignment_target(pattern, ast, &mut |ident| {
+            self.convert_binding_identifier(ident)
+        })
+    }
+
+    /// Hoist binding, and return an assignment target referencing it.
+    fn convert_binding_identifier(
+        &mut self,
+        ident: &BindingIdentifier<'a>,
+    ) -> AssignmentTarget<'a> {
+        let span = ident.span;
+        let binding = self.hoist_binding(ident);
+        binding.create_spanned_write_target(span, self.ctx)
+    }
+
+    /// Add binding to list of vars to declare in outer function.
+    fn hoist_binding(&mut self, ident: &BindingIdentifier<'a>) -> BoundIdentifier<'a> {
+        let symbol_id = ident.symbol_id.get().unwrap();
+        let name = self.renamed.get(&symbol_id).cloned().unwrap_or_else(|| ident.name.clone());
+        let binding = BoundIdentifier::new(name, symbol_id);
+        if !self.params.contains(&symbol_id) && self.seen.insert(symbol_id) {
+            self.vars.push((binding.clone(), ident.span));
+        }
+        binding
+    }
+}
@@ -0,0 +1,390 @@
+//! Analysis of generator function bodies.
+//!
+//! * [`contains_leap`] / [`expression_contains_leap`] decide which statements and expressions need
+//!   exploding into the state machine. Based on `meta.js` in regenerator-transform.
+//! * [`find_super_in_function`] finds `super`, which cannot be moved into another function.
+//! * [`check_function_body`] finds constructs which the [`Emitter`] cannot compile, so the function
+//!   can be left untouched and an error reported, rather than producing broken output.
+//!
+//! [`Emitter`]: super::emit::Emitter
+
+use oxc_ast::{ast::*, Visit};
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::scope::ScopeFlags;
+
+/// Visitor which looks for "leaps" - `yield`, `break`, `continue`, `return` and `throw`.
+/// Does not enter nested functions or classes.
+#[derive(Default)]
+struct LeapFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for LeapFinder {
+    fn visit_yield_expression(&mut self, _it: &YieldExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_break_statement(&mut self, _it: &BreakStatement<'a>) {
+        self.found = true;
+    }
+
+    fn visit_continue_statement(&mut self, _it: &ContinueStatement<'a>) {
+        self.found = true;
+    }
+
+    fn visit_return_statement(&mut self, _it: &ReturnStatement<'a>) {
+        self.found = true;
+    }
+
+    fn visit_throw_statement(&mut self, _it: &ThrowStatement<'a>) {
+        self.found = true;
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'a>) {}
+
+    fn visit_class(&mut self, _it: &Class<'a>) {}
+}
+
+/// Returns `true` if statement contains a leap, and so needs to be exploded.
+pub(super) fn contains_leap(stmt: &Statement<'_>) -> bool {
+    let mut finder = LeapFinder::default();
+    finder.visit_statement(stmt);
+    finder.found
+}
+
+/// Returns `true` if expression contains a leap, and so needs to be exploded.
+pub(super) fn expression_contains_leap(expr: &Expression<'_>) -> bool {
+    let mut finder = LeapFinder::default();
+    finder.visit_expression(expr);
+    finder.found
+}
+
+/// Returns `true` if assignment target contains a leap, and so needs to be exploded.
+pub(super) fn assignment_target_contains_leap(target: &AssignmentTarget<'_>) -> bool {
+    let mut finder = LeapFinder::default();
+    finder.visit_assignment_target(target);
+    finder.found
+}
+
+/// Visitor which looks for `yield` inside classes.
+#[derive(Default)]
+struct YieldFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for YieldFinder {
+    fn visit_yield_expression(&mut self, _it: &YieldExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'a>) {}
+}
+
+/// Visitor which looks for `super`.
+/// Does not enter nested functions or classes, as `super` has a different meaning within them.
+#[derive(Default)]
+struct SuperFinder {
+    span: Option<Span>,
+}
+
+impl<'a> Visit<'a> for SuperFinder {
+    fn visit_super(&mut self, it: &Super) {
+        self.span.get_or_insert(it.span);
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_class(&mut self, _it: &Class<'a>) {}
+}
+
+/// Find `super` in params and body of a function, and return its span.
+pub(crate) fn find_super_in_function(func: &Function<'_>) -> Option<Span> {
+    let mut finder = SuperFinder::default();
+    finder.visit_formal_parameters(&func.params);
+    if let Some(body) = &func.body {
+        finder.visit_function_body(body);
+    }
+    finder.span
+}
+
+/// An unsupported construct in a generator function.
+pub(super) struct Unsupported {
+    pub span: Span,
+    pub message: &'static str,
+}
+
+type CheckResult = Result<(), Unsupported>;
+
+fn unsupported(span: Span, message: &'static str) -> CheckResult {
+    Err(Unsupported { span, message })
+}
+
+/// Check that body of a generator function can be compiled.
+pub(super) fn check_function_body(body: &FunctionBody<'_>) -> CheckResult {
+    let mut checker = DeclarationChecker { result: Ok(()) };
+    checker.visit_function_body(body);
+    checker.result?;
+    body.statements.iter().try_for_each(check_statement)
+}
+
+/// Visitor which checks declarations which need to be hoisted out of the state machine.
+struct DeclarationChecker {
+    result: CheckResult,
+}
+
+impl<'a> Visit<'a> for DeclarationChecker {
+    fn visit_binding_pattern(&mut self, it: &BindingPattern<'a>) {
+        if self.result.is_ok() && !it.kind.is_binding_identifier() {
+            let mut finder = LeapFinder::default();
+            finder.visit_binding_pattern(it);
+            if finder.found {
+                self.result = unsupported(
+                    it.span(),
+                    "`yield` in destructuring patterns is not supported yet.",
+                );
+            }
+        }
+    }
+
+    fn visit_class(&mut self, it: &Class<'a>) {
+        if self.result.is_ok() {
+            let mut finder = YieldFinder::default();
+            finder.visit_class(it);
+            if finder.found {
+                self.result = unsupported(it.span, "`yield` in classes is not supported yet.");
+            }
+        }
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'a>) {}
+}
+
+fn check_declarator_inits(decl: &VariableDeclaration<'_>) -> CheckResult {
+    decl.declarations
+        .iter()
+        .filter_map(|declarator| declarator.init.as_ref())
+        .try_for_each(check_expression)
+}
+
+fn check_statement(stmt: &Statement<'_>) -> CheckResult {
+    if let Statement::BlockStatement(block) = stmt {
+        return block.body.iter().try_for_each(check_statement);
+    }
+    if !contains_leap(stmt) {
+        return Ok(());
+    }
+
+    match stmt {
+        Statement::ExpressionStatement(stmt) => check_expression(&stmt.expression),
+        // Declarations are converted to assignments by hoisting
+        Statement::VariableDeclaration(decl) => check_declarator_inits(decl),
+        Statement::LabeledStatement(stmt) => check_statement(&stmt.body),
+        Statement::WhileStatement(stmt) => {
+            check_expression(&stmt.test)?;
+            check_statement(&stmt.body)
+        }
+        Statement::DoWhileStatement(stmt) => {
+            check_statement(&stmt.body)?;
+            check_expression(&stmt.test)
+        }
+        Statement::ForStatement(stmt) => {
+            if let Some(init) = &stmt.init {
+                if let Some(init) = init.as_expression() {
+                    check_expression(init)?;
+                } else if let ForStatementInit::VariableDeclaration(decl) = init {
+                    check_declarator_inits(decl)?;
+                }
+            }
+            if let Some(test) = &stmt.test {
+                check_expression(test)?;
+            }
+            if let Some(update) = &stmt.update {
+                check_expression(update)?;
+            }
+            check_statement(&stmt.body)
+        }
+        Statement::ForInStatement(stmt) => {
+            if let ForStatementLeft::VariableDeclaration(decl) = &stmt.left {
+                if decl.declarations.iter().any(|declarator| declarator.init.is_some()) {
+                    return unsupported(
+                        decl.span,
+                        "Initializers in `for...in` loops in generator functions are not supported.",
+                    );
+                }
+            }
+            check_expression(&stmt.right)?;
+            check_statement(&stmt.body)
+        }
+        Statement::SwitchStatement(stmt) => {
+            check_expression(&stmt.discriminant)?;
+            for case in &stmt.cases {
+                if let Some(test) = &case.test {
+                    check_expression(test)?;
+                }
+                case.consequent.iter().try_for_each(check_statement)?;
+            }
+            Ok(())
+        }
+        Statement::IfStatement(stmt) => {
+            check_expression(&stmt.test)?;
+            check_statement(&stmt.consequent)?;
+            if let Some(alternate) = &stmt.alternate {
+                check_statement(alternate)?;
+            }
+            Ok(())
+        }
+        Statement::ReturnStatement(stmt) => {
+            stmt.argument.as_ref().map_or(Ok(()), check_expression)
+        }
+        Statement::ThrowStatement(stmt) => check_expression(&stmt.argument),
+        Statement::TryStatement(stmt) => {
+            stmt.block.body.iter().try_for_each(check_statement)?;
+            if let Some(handler) = &stmt.handler {
+                handler.body.body.iter().try_for_each(check_statement)?;
+            }
+            if let Some(finalizer) = &stmt.finalizer {
+                finalizer.body.iter().try_for_each(check_statement)?;
+            }
+            Ok(())
+        }
+        Statement::BreakStatement(_) | Statement::ContinueStatement(_) => Ok(()),
+        Statement::ForOfStatement(stmt) => unsupported(
+            stmt.span,
+            "`for...of` loops in generator functions are only supported when `for...of` loops are transformed.",
+        ),
+        _ => unsupported(stmt.span(), "This statement is not supported in generator functions yet."),
+    }
+}
+
+fn check_expression(expr: &Expression<'_>) -> CheckResult {
+    if !expression_contains_leap(expr) {
+        return Ok(());
+    }
+
+    match expr {
+        Expression::ParenthesizedExpression(expr) => check_expression(&expr.expression),
+        Expression::YieldExpression(expr) => {
+            expr.argument.as_ref().map_or(Ok(()), check_expression)
+        }
+        Expression::StaticMemberExpression(expr) => check_expression(&expr.object),
+        Expression::ComputedMemberExpression(expr) => {
+            check_expression(&expr.object)?;
+            check_expression(&expr.expression)
+        }
+        Expression::CallExpression(call) => {
+            let has_leaping_arguments = arguments_contain_leap(&call.arguments);
+            if has_leaping_arguments {
+                check_arguments(&call.arguments)?;
+            }
+            match &call.callee {
+                Expression::StaticMemberExpression(member) if has_leaping_arguments => {
+                    check_expression(&member.object)
+                }
+                Expression::ComputedMemberExpression(member) if has_leaping_arguments => {
+                    check_expression(&member.object)?;
+                    check_expression(&member.expression)
+                }
+                Expression::PrivateFieldExpression(member) if has_leaping_arguments => {
+                    check_expression(&member.object)
+                }
+                Expression::Super(_) if has_leaping_arguments => {
+                    unsupported(call.span, "This call is not supported in generator functions yet.")
+                }
+                callee => check_expression(callee),
+            }
+        }
+        Expression::NewExpression(new_expr) => {
+            check_expression(&new_expr.callee)?;
+            check_arguments(&new_expr.arguments)
+        }
+        Expression::ObjectExpression(object) => {
+            object.properties.iter().try_for_each(|property| match property {
+                ObjectPropertyKind::ObjectProperty(property) => {
+                    if let Some(key) = property.key.as_expression() {
+                        check_expression(key)?;
+                    }
+                    check_expression(&property.value)
+                }
+                ObjectPropertyKind::SpreadProperty(spread) => check_expression(&spread.argument),
+            })
+        }
+        Expression::ArrayExpression(array) => {
+            array.elements.iter().try_for_each(|element| match element {
+                ArrayExpressionElement::SpreadElement(spread) => check_expression(&spread.argument),
+                ArrayExpressionElement::Elision(_) => Ok(()),
+                element => check_expression(element.to_expression()),
+            })
+        }
+        Expression::SequenceExpression(expr) => {
+            expr.expressions.iter().try_for_each(check_expression)
+        }
+        Expression::LogicalExpression(expr) => {
+            check_expression(&expr.left)?;
+            check_expression(&expr.right)
+        }
+        Expression::ConditionalExpression(expr) => {
+            check_expression(&expr.test)?;
+            check_expression(&expr.consequent)?;
+            check_expression(&expr.alternate)
+        }
+        Expression::UnaryExpression(expr) => check_expression(&expr.argument),
+        Expression::BinaryExpression(expr) => {
+            check_expression(&expr.left)?;
+            check_expression(&expr.right)
+        }
+        Expression::AssignmentExpression(expr) => {
+            if let Some(target) = expr.left.as_simple_assignment_target() {
+                check_simple_assignment_target(target)?;
+            } else if assignment_target_contains_leap(&expr.left) {
+                return unsupported(
+                    expr.left.span(),
+                    "`yield` in destructuring assignment targets is not supported yet.",
+                );
+            }
+            check_expression(&expr.right)
+        }
+        Expression::UpdateExpression(expr) => check_simple_assignment_target(&expr.argument),
+        Expression::TemplateLiteral(template) => {
+            template.expressions.iter().try_for_each(check_expression)
+        }
+        Expression::PrivateInExpression(expr) => check_expression(&expr.right),
+        Expression::ImportExpression(expr) => {
+            check_expression(&expr.source)?;
+            expr.arguments.iter().try_for_each(check_expression)
+        }
+        _ => unsupported(expr.span(), "`yield` in this expression is not supported yet."),
+    }
+}
+
+fn check_simple_assignment_target(target: &SimpleAssignmentTarget<'_>) -> CheckResult {
+    match target.as_member_expression() {
+        Some(MemberExpression::StaticMemberExpression(member)) => check_expression(&member.object),
+        Some(MemberExpression::ComputedMemberExpression(member)) => {
+            check_expression(&member.object)?;
+            check_expression(&member.expression)
+        }
+        Some(MemberExpression::PrivateFieldExpression(member)) => check_expression(&member.object),
+        None => Ok(()),
+    }
+}
+
+pub(super) fn arguments_contain_leap(arguments: &[Argument<'_>]) -> bool {
+    arguments.iter().any(|argument| {
+        let mut finder = LeapFinder::default();
+        finder.visit_argument(argument);
+        finder.found
+    })
+}
+
+fn check_arguments(arguments: &[Argument<'_>]) -> CheckResult {
+    arguments.iter().try_for_each(|argument| match argument {
+        Argument::SpreadElement(spread) => check_expression(&spread.argument),
+        argument => check_expression(argument.to_expression()),
+    })
+}
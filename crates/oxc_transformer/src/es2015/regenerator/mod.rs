@@ -0,0 +1,548 @@
+//! ES2015: Regenerator
+//!
+//! This plugin transforms generator functions to state machines which are run by the
+//! regenerator runtime.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! function* gen() {
+//!   const x = yield 1;
+//!   console.log(x);
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _marked = babelHelpers.regeneratorRuntime().mark(gen);
+//! function gen() {
+//!   var x;
+//!   return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+//!     while (1) switch (_context.prev = _context.next) {
+//!       case 0:
+//!         _context.next = 2;
+//!         return 1;
+//!       case 2:
+//!         x = _context.sent;
+//!         console.log(x);
+//!       case 4:
+//!       case "end":
+//!         return _context.stop();
+//!     }
+//!   }, _marked);
+//! }
+//! ```
+//!
+//! ## Options
+//!
+//! ### `asyncGenerators`
+//!
+//! `boolean`, defaults to `true`.
+//!
+//! Not supported yet. Async generator functions are not transformed.
+//!
+//! ### `generators`
+//!
+//! `boolean`, defaults to `true`.
+//!
+//! Transform generator functions.
+//!
+//! ### `async`
+//!
+//! `boolean`, defaults to `true`.
+//!
+//! Async functions are not transformed by this plugin alone. Functions output by async-to-generator
+//! plugin are transformed, if `generators` is enabled.
+//!
+//! ## Missing features
+//!
+//! Implementation is incomplete at present. Still TODO:
+//!
+//! * Async functions and async generator functions. Async functions are only transformed
+//!   when async-to-generator plugin is also enabled, which passes its output to this plugin.
+//! * `yield` in destructuring patterns, classes, tagged templates and `super` calls.
+//! * `for...of` loops containing `yield`, unless transformed by for-of plugin.
+//! * `super` in generator methods.
+//!
+//! These produce an error.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-regenerator](https://babel.dev/docs/babel-plugin-transform-regenerator),
+//! which uses [regenerator-transform](https://github.com/facebook/regenerator/tree/main/packages/transform).
+//!
+//! Unlike Babel, top level function declarations in the generator function body are moved to
+//! the outer function as they are, rather than being converted to assignments.
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-regenerator>
+//! * Regenerator: <https://github.com/facebook/regenerator>
+//! * Generator function definitions in spec: <https://tc39.es/ecma262/#sec-generator-function-definitions>
+
+mod emit;
+mod hoist;
+mod meta;
+
+use rustc_hash::FxHashSet;
+use serde::Deserialize;
+
+use oxc_allocator::{Box as ArenaBox, Vec as ArenaVec};
+use oxc_ast::{ast::*, visit::walk_mut, VisitMut, NONE};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::{
+    reference::ReferenceFlags,
+    scope::{ScopeFlags, ScopeId},
+    symbol::SymbolFlags,
+};
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse, TraverseCtx};
+
+use crate::{common::helper_loader::Helper, TransformCtx};
+
+use super::destructuring::current_hoist_scope_id;
+use emit::Emitter;
+use hoist::hoist;
+pub(crate) use meta::find_super_in_function;
+use meta::{check_function_body, Unsupported};
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct RegeneratorOptions {
+    pub async_generators: bool,
+    pub generators: bool,
+    pub r#async: bool,
+}
+
+impl Default for RegeneratorOptions {
+    fn default() -> Self {
+        Self { async_generators: true, generators: true, r#async: true }
+    }
+}
+
+pub struct Regenerator<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    options: RegeneratorOptions,
+    /// Stack of `_marked` declarators to insert at top of each statement list
+    marked: Vec<ArenaVec<'a, VariableDeclarator<'a>>>,
+    /// `true` if function expression which was just exited needs to be wrapped in
+    /// `regeneratorRuntime().mark()` in `exit_expression`
+    mark_function_expression: bool,
+    /// Scopes of generator functions which could not be transformed, and have been reported
+    unsupported: FxHashSet<ScopeId>,
+}
+
+impl<'a, 'ctx> Regenerator<'a, 'ctx> {
+    pub fn new(options: RegeneratorOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            ctx,
+            options,
+            marked: vec![],
+            mark_function_expression: false,
+            unsupported: FxHashSet::default(),
+        }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for Regenerator<'a, 'ctx> {
+    fn enter_statements(
+        &mut self,
+        _stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.marked.push(ctx.ast.vec());
+    }
+
+    fn exit_statements(
+        &mut self,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let declarations = self.marked.pop().unwrap();
+        if declarations.is_empty() {
+            return;
+        }
+        // `var _marked = babelHelpers.regeneratorRuntime().mark(gen);`
+        let declaration = Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            declarations,
+            false,
+        ));
+        stmts.insert(0, declaration);
+    }
+
+    fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !func.generator || func.r#async || !self.options.generators {
+            return;
+        }
+
+        let is_method = match ctx.parent() {
+            Ancestor::MethodDefinitionValue(_) => true,
+            Ancestor::ObjectPropertyValue(property) => *property.method(),
+            _ => false,
+        };
+        if is_method {
+            self.transform_method(func, ctx);
+            return;
+        }
+        if !self.check_function(func) {
+            return;
+        }
+        if func.is_declaration() && func.id.is_none() {
+            // `export default function* () {}` -> `export default function _callee() {}`
+            let scope_id = ctx.scopes().get_parent_id(func.scope_id.get().unwrap()).unwrap();
+            let flags = SymbolFlags::FunctionScopedVariable | SymbolFlags::Export;
+            let callee = ctx.generate_uid("callee", scope_id, flags);
+            func.id = Some(callee.create_binding_identifier(ctx));
+        }
+
+        self.mark_function_expression = self.transform_function(func, ctx);
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !self.mark_function_expression {
+            return;
+        }
+        self.mark_function_expression = false;
+
+        // `function* () {}` -> `babelHelpers.regeneratorRuntime().mark(function _callee() {})`
+        let func = ctx.ast.move_expression(expr);
+        *expr = self.create_mark_call(func, ctx);
+    }
+}
+
+impl<'a, 'ctx> Regenerator<'a, 'ctx> {
+    /// Transform a generator function expression which was created by another plugin,
+    /// and wrap it in `regeneratorRuntime().mark()`.
+    ///
+    /// `function* () {}` -> `babelHelpers.regeneratorRuntime().mark(function _callee() {})`
+    pub(crate) fn transform_generator_expression(
+        &mut self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::FunctionExpression(func) = expr else { return };
+        let Some(scope_id) = func.scope_id.get() else { return };
+        if !func.generator
+            || func.r#async
+            || self.unsupported.contains(&scope_id)
+            || !self.check_function(func)
+        {
+            return;
+        }
+        self.transform_function(func, ctx);
+        let func = ctx.ast.move_expression(expr);
+        *expr = self.create_mark_call(func, ctx);
+    }
+
+    /// Transform a generator method, by moving its body into a generator function expression
+    /// which is called with the method's `this` and `arguments`.
+    ///
+    /// `*gen(a) { yield a; }`
+    /// -> `gen() { return babelHelpers.regeneratorRuntime().mark(function _callee(a) { ... }).apply(this, arguments); }`
+    fn transform_method(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(span) = find_super_in_function(func) {
+            self.ctx.error(
+                OxcDiagnostic::error("`super` in generator methods is not supported yet.")
+                    .with_label(span),
+            );
+            return;
+        }
+        if !self.check_function(func) {
+            return;
+        }
+        let container = take_function_contents(func, ctx);
+        func.generator = false;
+        let mut container = Expression::FunctionExpression(container);
+        self.transform_generator_expression(&mut container, ctx);
+        func.body = Some(create_apply_body(container, ctx));
+    }
+
+    /// Check body of generator function can be transformed. Report an error if not.
+    fn check_function(&mut self, func: &Function<'a>) -> bool {
+        match check_function_body(func.body.as_ref().unwrap()) {
+            Ok(()) => true,
+            Err(Unsupported { span, message }) => {
+                self.ctx.error(OxcDiagnostic::error(message).with_label(span));
+                self.unsupported.insert(func.scope_id.get().unwrap());
+                false
+            }
+        }
+    }
+
+    /// Transform body of generator function into a state machine, and convert it to a normal function.
+    ///
+    /// Returns `true` if function is an expression which needs to be wrapped in
+    /// `regeneratorRuntime().mark()`.
+    fn transform_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) -> bool {
+        let function_scope_id = func.scope_id.get().unwrap();
+
+        // Generator functions can be created in the body by other plugins after they have been exited
+        // (e.g. `_loop` functions by block-scoping plugin). Transform them first.
+        CreatedGeneratorTransformer { regenerator: self, ctx }
+            .visit_function_body(func.body.as_mut().unwrap());
+
+        let hoisted = hoist(func, function_scope_id, ctx);
+
+        // Get the function used as `outerFn`, which has its prototype used for the generator object.
+        // Function declarations are marked with a separate `_marked` var, function expressions
+        // are marked in place, and given a name if they don't have one.
+        let outer_fn = if func.is_declaration() {
+            let id = func.id.as_ref().unwrap();
+            let marked = ctx.generate_uid(
+                "marked",
+                current_hoist_scope_id(ctx),
+                SymbolFlags::FunctionScopedVariable,
+            );
+            let func_ref = ctx.create_bound_reference_id(
+                SPAN,
+                id.name.clone(),
+                id.symbol_id.get().unwrap(),
+                ReferenceFlags::Read,
+            );
+            let mark_call = self.create_mark_call(Expression::Identifier(ctx.alloc(func_ref)), ctx);
+            self.marked.last_mut().unwrap().push(ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Var,
+                marked.create_binding_pattern(ctx),
+                Some(mark_call),
+                false,
+            ));
+            marked
+        } else if let Some(id) = &func.id {
+            BoundIdentifier::from_binding_ident(id)
+        } else {
+            let callee = ctx.generate_uid("callee", function_scope_id, SymbolFlags::Function);
+            func.id = Some(callee.create_binding_identifier(ctx));
+            callee
+        };
+
+        // Inner function `function gen$(_context) {}`
+        let inner_scope_id = ctx.create_child_scope(function_scope_id, ScopeFlags::Function);
+        let context =
+            ctx.generate_uid("context", inner_scope_id, SymbolFlags::FunctionScopedVariable);
+        let inner_name = ctx.ast.atom(&format!("{}$", func.id.as_ref().unwrap().name));
+        let inner_id = ctx.generate_binding(inner_name, inner_scope_id, SymbolFlags::Function);
+        let switch_scope_id = ctx.create_child_scope(inner_scope_id, ScopeFlags::empty());
+
+        // Explode body into the dispatch loop. Top level function declarations stay in outer function.
+        let body = func.body.as_mut().unwrap();
+        let mut outer_body = ctx.ast.vec();
+        let mut emitter = Emitter::new(context.clone(), switch_scope_id, self.ctx);
+        for stmt in ctx.ast.move_vec(&mut body.statements) {
+            if matches!(stmt, Statement::FunctionDeclaration(_)) {
+                outer_body.push(stmt);
+            } else {
+                emitter.explode_statement(stmt, None, ctx);
+            }
+        }
+        let try_locs = emitter.get_try_locs_list(ctx);
+        let dispatch_loop = emitter.into_dispatch_loop(ctx);
+
+        // `var x, y, _args = arguments;`
+        let mut declarators = ctx.ast.vec_from_iter(hoisted.vars.iter().map(|(binding, span)| {
+            let ident = ctx.ast.binding_identifier_with_symbol_id(
+                *span,
+                binding.name.clone(),
+                binding.symbol_id,
+            );
+            let pattern = ctx.ast.binding_pattern(
+                ctx.ast.binding_pattern_kind_from_binding_identifier(ident),
+                NONE,
+                false,
+            );
+            ctx.ast.variable_declarator(SPAN, VariableDeclarationKind::Var, pattern, None, false)
+        }));
+        if let Some(args) = &hoisted.args {
+            let arguments = ctx.create_unbound_reference_id(
+                SPAN,
+                Atom::from("arguments"),
+                ReferenceFlags::Read,
+            );
+            let arguments = Expression::Identifier(ctx.alloc(arguments));
+            declarators.push(ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Var,
+                args.create_binding_pattern(ctx),
+                Some(arguments),
+                false,
+            ));
+        }
+        if !declarators.is_empty() {
+            outer_body.insert(
+                0,
+                Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+                    SPAN,
+                    VariableDeclarationKind::Var,
+                    declarators,
+                    false,
+                )),
+            );
+        }
+
+        // `function gen$(_context) { while (1) switch (...) {} }`
+        let params = ctx.ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            ctx.ast.vec1(ctx.ast.formal_parameter(
+                SPAN,
+                ctx.ast.vec(),
+                context.create_binding_pattern(ctx),
+                None,
+                false,
+                false,
+            )),
+            NONE,
+        );
+        let inner_body = ctx.ast.function_body(SPAN, ctx.ast.vec(), ctx.ast.vec1(dispatch_loop));
+        let inner_fn = Expression::FunctionExpression(ctx.ast.alloc_function_with_scope_id(
+            FunctionType::FunctionExpression,
+            SPAN,
+            Some(inner_id.create_binding_identifier(ctx)),
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(inner_body),
+            inner_scope_id,
+        ));
+
+        // `return babelHelpers.regeneratorRuntime().wrap(gen$, _marked, this, tryLocs);`
+        let mut arguments = ctx.ast.vec_with_capacity(4);
+        arguments.push(Argument::from(inner_fn));
+        arguments.push(Argument::from(outer_fn.create_read_expression(ctx)));
+        if hoisted.uses_this {
+            arguments.push(Argument::from(ctx.ast.expression_this(SPAN)));
+        } else if try_locs.is_some() {
+            arguments.push(Argument::from(ctx.ast.expression_null_literal(SPAN)));
+        }
+        if let Some(try_locs) = try_locs {
+            arguments.push(Argument::from(try_locs));
+        }
+        let wrap = self.create_runtime_call("wrap", arguments, ctx);
+        outer_body.push(ctx.ast.statement_return(SPAN, Some(wrap)));
+
+        body.statements = outer_body;
+        func.generator = false;
+
+        !func.is_declaration()
+    }
+
+    /// `babelHelpers.regeneratorRuntime().mark(func)`
+    fn create_mark_call(&self, func: Expression<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        self.create_runtime_call("mark", ctx.ast.vec1(Argument::from(func)), ctx)
+    }
+
+    /// `babelHelpers.regeneratorRuntime().name(...arguments)`
+    fn create_runtime_call(
+        &self,
+        name: &'static str,
+        arguments: ArenaVec<'a, Argument<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let runtime = self.ctx.helper_call_expr(Helper::RegeneratorRuntime, ctx.ast.vec(), ctx);
+        let callee = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            runtime,
+            ctx.ast.identifier_name(SPAN, name),
+            false,
+        ));
+        ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+    }
+}
+
+/// Transform generator function expressions which were created by other plugins.
+struct CreatedGeneratorTransformer<'a, 'ctx, 'r, 'c> {
+    regenerator: &'r mut Regenerator<'a, 'ctx>,
+    ctx: &'c mut TraverseCtx<'a>,
+}
+
+impl<'a, 'ctx, 'r, 'c> VisitMut<'a> for CreatedGeneratorTransformer<'a, 'ctx, 'r, 'c> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if matches!(expr, Expression::FunctionExpression(_)) {
+            self.regenerator.transform_generator_expression(expr, self.ctx);
+        } else {
+            walk_mut::walk_expression(self, expr);
+        }
+    }
+
+    fn visit_function(&mut self, _func: &mut Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _arrow: &mut ArrowFunctionExpression<'a>) {}
+
+    fn visit_class(&mut self, _class: &mut Class<'a>) {}
+}
+
+/// Move params and body of `func` into a new generator function expression, which takes over
+/// the scope of `func`. `func` is given a new scope, and no params.
+///
+/// Used for generator methods, and by async-to-generator plugin, where the function itself
+/// cannot be replaced.
+pub(crate) fn take_function_contents<'a>(
+    func: &mut Function<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> ArenaBox<'a, Function<'a>> {
+    let scope_id = func.scope_id.get().unwrap();
+    let parent_scope_id = ctx.scopes().get_parent_id(scope_id).unwrap();
+    let flags = ctx.scopes().get_flags(scope_id);
+    let outer_scope_id = ctx.create_child_scope(parent_scope_id, flags);
+    ctx.scopes_mut().change_parent_id(scope_id, Some(outer_scope_id));
+
+    // Name of a function expression is bound in its own scope. It stays with the outer function.
+    if let Some(id) = &func.id {
+        let symbol_id = id.symbol_id.get().unwrap();
+        if ctx.symbols().get_scope_id(symbol_id) == scope_id {
+            ctx.scopes_mut().move_binding(scope_id, outer_scope_id, &id.name);
+            ctx.symbols_mut().set_scope_id(symbol_id, outer_scope_id);
+        }
+    }
+
+    let params = ctx.ast.alloc_formal_parameters(SPAN, func.params.kind, ctx.ast.vec(), NONE);
+    let params = std::mem::replace(&mut func.params, params);
+    func.scope_id.set(Some(outer_scope_id));
+    ctx.ast.alloc_function_with_scope_id(
+        FunctionType::FunctionExpression,
+        SPAN,
+        None,
+        true,
+        false,
+        false,
+        NONE,
+        NONE,
+        params,
+        NONE,
+        func.body.take(),
+        scope_id,
+    )
+}
+
+/// Create function body `{ return callee.apply(this, arguments); }`.
+pub(crate) fn create_apply_body<'a>(
+    callee: Expression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> ArenaBox<'a, FunctionBody<'a>> {
+    let callee = Expression::from(ctx.ast.member_expression_static(
+        SPAN,
+        callee,
+        ctx.ast.identifier_name(SPAN, "apply"),
+        false,
+    ));
+    let arguments =
+        ctx.create_unbound_reference_id(SPAN, Atom::from("arguments"), ReferenceFlags::Read);
+    let arguments = ctx.ast.vec_from_iter([
+        Argument::from(ctx.ast.expression_this(SPAN)),
+        Argument::from(Expression::Identifier(ctx.alloc(arguments))),
+    ]);
+    let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+    ctx.ast.alloc_function_body(
+        SPAN,
+        ctx.ast.vec(),
+        ctx.ast.vec1(ctx.ast.statement_return(SPAN, Some(call))),
+    )
+}
@@ -0,0 +1,111 @@
+//! ES2015: Shorthand Properties
+//!
+//! This plugin transforms shorthand properties and methods in object literals to normal properties.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! var o = { a, b, c() {}, __proto__ };
+//! ```
+//!
+//! Output:
+//! ```js
+//! var o = { a: a, b: b, c: function () {}, ["__proto__"]: __proto__ };
+//! ```
+//!
+//! A shorthand `__proto__` property is converted to a computed property, because
+//! `{ __proto__: __proto__ }` would set the object's prototype, whereas `{ __proto__ }` does not.
+//!
+//! ## Missing features
+//!
+//! Implementation is incomplete at present. Still TODO:
+//!
+//! * Methods which contain `super` are left as methods, as `super` is not valid in a function expression.
+//!   Babel relies on `transform-object-super` to transform them first.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-shorthand-properties](https://babel.dev/docs/babel-plugin-transform-shorthand-properties).
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-shorthand-properties>
+//! * Object initializer in spec: <https://tc39.es/ecma262/#sec-object-initializer>
+
+use oxc_ast::{ast::*, visit::walk, Visit};
+use oxc_span::GetSpan;
+use oxc_syntax::scope::ScopeFlags;
+use oxc_traverse::{Traverse, TraverseCtx};
+
+pub struct ShorthandProperties;
+
+impl ShorthandProperties {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<'a> Traverse<'a> for ShorthandProperties {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::ObjectExpression(obj_expr) = expr else { return };
+        for prop in obj_expr.properties.iter_mut() {
+            if let ObjectPropertyKind::ObjectProperty(prop) = prop {
+                Self::transform_property(prop, ctx);
+            }
+        }
+    }
+}
+
+impl ShorthandProperties {
+    fn transform_property<'a>(prop: &mut ObjectProperty<'a>, ctx: &TraverseCtx<'a>) {
+        if prop.shorthand {
+            // `{ a }` -> `{ a: a }`
+            prop.shorthand = false;
+        } else if prop.method {
+            // `{ a() {} }` -> `{ a: function () {} }`
+            let Expression::FunctionExpression(func) = &prop.value else { return };
+            if SuperFinder::contains_super(func) {
+                return;
+            }
+            prop.method = false;
+        } else {
+            return;
+        }
+
+        // `{ __proto__ }` -> `{ ["__proto__"]: __proto__ }`
+        if !prop.computed && prop.key.is_specific_static_name("__proto__") {
+            let span = prop.key.span();
+            prop.key = PropertyKey::from(ctx.ast.expression_string_literal(span, "__proto__"));
+            prop.computed = true;
+        }
+    }
+}
+
+/// Visitor which checks if a method contains `super`.
+/// Does not enter nested functions or classes, as `super` has a different meaning within them.
+struct SuperFinder {
+    found: bool,
+}
+
+impl SuperFinder {
+    fn contains_super(func: &Function<'_>) -> bool {
+        let mut finder = Self { found: false };
+        walk::walk_formal_parameters(&mut finder, &func.params);
+        if let Some(body) = &func.body {
+            finder.visit_function_body(body);
+        }
+        finder.found
+    }
+}
+
+impl<'a> Visit<'a> for SuperFinder {
+    fn visit_super(&mut self, _it: &Super) {
+        self.found = true;
+    }
+
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_class(&mut self, _class: &Class<'a>) {}
+}
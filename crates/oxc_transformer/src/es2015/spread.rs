@@ -0,0 +1,410 @@
+//! ES2015: Spread
+//!
+//! This plugin transforms spread elements in array literals, function calls and `new` expressions.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! var a = [b, ...c, d];
+//! f(...a);
+//! obj.method(x, ...a);
+//! new C(...a);
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _obj;
+//! var a = [b].concat(babelHelpers.toConsumableArray(c), [d]);
+//! f.apply(void 0, babelHelpers.toConsumableArray(a));
+//! (_obj = obj).method.apply(_obj, [x].concat(babelHelpers.toConsumableArray(a)));
+//! babelHelpers.construct(C, babelHelpers.toConsumableArray(a));
+//! ```
+//!
+//! ## Options
+//!
+//! ### `loose`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! When `true`, all iterables are assumed to be arrays, and are used directly.
+//!
+//! ### Assumptions
+//!
+//! * `iterableIsArray`: Same as `loose`.
+//! * `arrayLikeIsIterable`: Handled by `toConsumableArray` helper at runtime.
+//!
+//! ## Missing features
+//!
+//! Implementation is incomplete at present. Still TODO:
+//!
+//! * Spread in `super(...args)` call. Classes transform converts the arguments to an array,
+//!   which is not transformed further.
+//! * Spread in optional calls `f?.(...args)` is not transformed.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-spread](https://babel.dev/docs/babel-plugin-transform-spread).
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-spread>
+//! * Spread syntax on MDN: <https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Spread_syntax>
+
+use serde::Deserialize;
+
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::{ast::*, NONE};
+use oxc_span::SPAN;
+use oxc_syntax::{
+    number::NumberBase, operator::AssignmentOperator, reference::ReferenceFlags,
+    symbol::SymbolFlags,
+};
+use oxc_traverse::{Traverse, TraverseCtx};
+
+use crate::{common::helper_loader::Helper, TransformCtx};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct SpreadOptions {
+    pub loose: bool,
+}
+
+pub struct Spread<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    /// Use spread arguments directly, instead of converting them to arrays
+    loose: bool,
+}
+
+impl<'a, 'ctx> Spread<'a, 'ctx> {
+    pub fn new(options: SpreadOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        let loose = options.loose || ctx.assumptions.iterable_is_array;
+        Self { ctx, loose }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for Spread<'a, 'ctx> {
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            Expression::ArrayExpression(array) => {
+                if array
+                    .elements
+                    .iter()
+                    .any(|element| matches!(element, ArrayExpressionElement::SpreadElement(_)))
+                {
+                    *expr = self.transform_array(array, ctx);
+                }
+            }
+            Expression::CallExpression(call) => {
+                if call.arguments.iter().any(Argument::is_spread)
+                    && !call.optional
+                    && !matches!(call.callee, Expression::Super(_))
+                    && !call.callee.as_member_expression().is_some_and(MemberExpression::optional)
+                {
+                    self.transform_call(call, ctx);
+                }
+            }
+            Expression::NewExpression(new_expr) => {
+                if new_expr.arguments.iter().any(Argument::is_spread) {
+                    *expr = self.transform_new(new_expr, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a, 'ctx> Spread<'a, 'ctx> {
+    /// `[a, ...b, c]` -> `[a].concat(babelHelpers.toConsumableArray(b), [c])`
+    pub(super) fn transform_array(
+        &self,
+        array: &mut ArrayExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let span = array.span;
+        let elements = ctx.ast.move_vec(&mut array.elements);
+        let starts_with_spread = matches!(elements.first(), Some(ArrayExpressionElement::SpreadElement(spread)) if self.is_used_directly(&spread.argument));
+        let mut parts = self.build_parts(elements.into_iter(), ctx);
+
+        // `[...a]` -> `babelHelpers.toConsumableArray(a)`.
+        // But if `a` would be used directly, `[...a]` -> `[].concat(a)`, to create a new array.
+        if parts.len() == 1 && !starts_with_spread {
+            let mut expr = parts.pop().unwrap();
+            set_span(&mut expr, span);
+            return expr;
+        }
+
+        let first = if matches!(parts.first(), Some(Expression::ArrayExpression(_)))
+            && !starts_with_spread
+        {
+            parts.remove(0)
+        } else {
+            ctx.ast.expression_array(SPAN, ctx.ast.vec(), None)
+        };
+        Self::create_concat(first, parts, span, ctx)
+    }
+
+    /// `f(a, ...b)` -> `f.apply(void 0, [a].concat(babelHelpers.toConsumableArray(b)))`
+    /// `obj.f(...b)` -> `obj.f.apply(obj, babelHelpers.toConsumableArray(b))`
+    fn transform_call(&self, call: &mut CallExpression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let arguments = ctx.ast.move_vec(&mut call.arguments);
+
+        // `f(...arguments)` -> `f.apply(void 0, arguments)`
+        let args_array = match arguments.first() {
+            Some(Argument::SpreadElement(spread))
+                if arguments.len() == 1 && is_arguments_ident(&spread.argument) =>
+            {
+                let Some(Argument::SpreadElement(spread)) = arguments.into_iter().next() else {
+                    unreachable!()
+                };
+                spread.unbox().argument
+            }
+            _ => self.build_args_array(arguments, ctx),
+        };
+
+        // Get `this` value for call
+        let this = match &mut call.callee {
+            Expression::StaticMemberExpression(member) => {
+                self.memoize_object(&mut member.object, ctx)
+            }
+            Expression::ComputedMemberExpression(member) => {
+                self.memoize_object(&mut member.object, ctx)
+            }
+            Expression::PrivateFieldExpression(member) => {
+                self.memoize_object(&mut member.object, ctx)
+            }
+            _ => ctx.ast.void_0(SPAN),
+        };
+
+        let callee = ctx.ast.move_expression(&mut call.callee);
+        call.callee = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            callee,
+            ctx.ast.identifier_name(SPAN, "apply"),
+            false,
+        ));
+        call.arguments = ctx.ast.vec_from_iter([Argument::from(this), Argument::from(args_array)]);
+    }
+
+    /// `new C(a, ...b)` -> `babelHelpers.construct(C, [a].concat(babelHelpers.toConsumableArray(b)))`
+    fn transform_new(
+        &self,
+        new_expr: &mut NewExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let arguments = ctx.ast.move_vec(&mut new_expr.arguments);
+        let args_array = self.build_args_array(arguments, ctx);
+        let callee = ctx.ast.move_expression(&mut new_expr.callee);
+        let arguments = ctx.ast.vec_from_iter([Argument::from(callee), Argument::from(args_array)]);
+        let mut expr = self.ctx.helper_call_expr(Helper::Construct, arguments, ctx);
+        set_span(&mut expr, new_expr.span);
+        expr
+    }
+
+    /// Convert call arguments to a single array expression.
+    fn build_args_array(
+        &self,
+        arguments: ArenaVec<'a, Argument<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let elements = arguments.into_iter().map(|arg| match arg {
+            Argument::SpreadElement(spread) => ArrayExpressionElement::SpreadElement(spread),
+            arg => ArrayExpressionElement::from(arg.into_expression()),
+        });
+        let mut parts = self.build_parts(elements, ctx);
+        let first = parts.remove(0);
+        if parts.is_empty() {
+            first
+        } else {
+            Self::create_concat(first, parts, SPAN, ctx)
+        }
+    }
+
+    /// Split elements into parts. Runs of non-spread elements are grouped into array literals,
+    /// and spread arguments are converted to arrays.
+    ///
+    /// `a, b, ...c, d` -> `[a, b]`, `babelHelpers.toConsumableArray(c)`, `[d]`
+    fn build_parts(
+        &self,
+        elements: impl Iterator<Item = ArrayExpressionElement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Vec<Expression<'a>> {
+        let mut parts = vec![];
+        let mut pending = ctx.ast.vec();
+        for element in elements {
+            if let ArrayExpressionElement::SpreadElement(spread) = element {
+                if !pending.is_empty() {
+                    let elements = std::mem::replace(&mut pending, ctx.ast.vec());
+                    parts.push(ctx.ast.expression_array(SPAN, elements, None));
+                }
+                let argument = spread.unbox().argument;
+                parts.push(if self.is_used_directly(&argument) {
+                    argument
+                } else {
+                    create_to_array(self.ctx, argument, ToArrayKind::Spread, ctx)
+                });
+            } else {
+                pending.push(element);
+            }
+        }
+        if !pending.is_empty() {
+            parts.push(ctx.ast.expression_array(SPAN, pending, None));
+        }
+        parts
+    }
+
+    /// Check if spread argument can be used directly, without converting to an array.
+    fn is_used_directly(&self, argument: &Expression<'a>) -> bool {
+        self.loose && !is_arguments_ident(argument)
+    }
+
+    /// `first.concat(a, b)`
+    fn create_concat(
+        first: Expression<'a>,
+        parts: Vec<Expression<'a>>,
+        span: Span,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let callee = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            first,
+            ctx.ast.identifier_name(SPAN, "concat"),
+            false,
+        ));
+        let arguments = ctx.ast.vec_from_iter(parts.into_iter().map(Argument::from));
+        ctx.ast.expression_call(span, callee, NONE, arguments, false)
+    }
+
+    /// Memoize object of callee, if required, and return expression for `this`.
+    ///
+    /// `a.b.c(...d)` -> `(_a$b = a.b).c.apply(_a$b, ...)`
+    /// `a.c(...d)` -> `a.c.apply(a, ...)`
+    fn memoize_object(
+        &self,
+        object: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        match object {
+            Expression::Super(_) | Expression::ThisExpression(_) => ctx.ast.expression_this(SPAN),
+            Expression::Identifier(_) if ctx.is_static(object) => {
+                let Expression::Identifier(ident) = object else { unreachable!() };
+                Expression::Identifier(
+                    ctx.ast.alloc(ctx.clone_identifier_reference(ident, ReferenceFlags::Read)),
+                )
+            }
+            _ => {
+                let binding = ctx.generate_uid_in_current_scope_based_on_node(
+                    object,
+                    SymbolFlags::FunctionScopedVariable,
+                );
+                self.ctx.var_declarations.insert(&binding, None, ctx);
+                let value = ctx.ast.move_expression(object);
+                *object = ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    binding.create_read_write_target(ctx),
+                    value,
+                );
+                binding.create_read_expression(ctx)
+            }
+        }
+    }
+}
+
+/// Kind of array conversion required by [`create_to_array`].
+#[derive(Clone, Copy)]
+pub(super) enum ToArrayKind {
+    /// Whole iterable is spread
+    Spread,
+    /// Only a fixed number of elements are required
+    Count(u32),
+    /// Some elements are required, followed by rest of iterable
+    Rest,
+}
+
+/// Convert an iterable to an array.
+///
+/// * `[a, b]` -> `[a, b]`
+/// * `arguments` -> `Array.prototype.slice.call(arguments)`
+/// * [`ToArrayKind::Spread`]: `a` -> `babelHelpers.toConsumableArray(a)`
+/// * [`ToArrayKind::Count`]: `a` -> `babelHelpers.slicedToArray(a, 2)`
+/// * [`ToArrayKind::Rest`]: `a` -> `babelHelpers.toArray(a)`
+///
+/// Based on Babel's `scope.toArray`.
+pub(super) fn create_to_array<'a>(
+    transform_ctx: &TransformCtx<'a>,
+    expr: Expression<'a>,
+    kind: ToArrayKind,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    if matches!(expr, Expression::ArrayExpression(_)) {
+        return expr;
+    }
+
+    if is_arguments_ident(&expr) {
+        // `Array.prototype.slice.call(arguments)`
+        let array = create_global_ident("Array", ctx);
+        let prototype = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            array,
+            ctx.ast.identifier_name(SPAN, "prototype"),
+            false,
+        ));
+        let slice = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            prototype,
+            ctx.ast.identifier_name(SPAN, "slice"),
+            false,
+        ));
+        let callee = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            slice,
+            ctx.ast.identifier_name(SPAN, "call"),
+            false,
+        ));
+        return ctx.ast.expression_call(
+            SPAN,
+            callee,
+            NONE,
+            ctx.ast.vec1(Argument::from(expr)),
+            false,
+        );
+    }
+
+    let (helper, arguments) = match kind {
+        ToArrayKind::Spread => (Helper::ToConsumableArray, ctx.ast.vec1(Argument::from(expr))),
+        ToArrayKind::Count(count) => {
+            let count = ctx.ast.expression_numeric_literal(
+                SPAN,
+                f64::from(count),
+                ctx.ast.str(&count.to_string()),
+                NumberBase::Decimal,
+            );
+            (
+                Helper::SlicedToArray,
+                ctx.ast.vec_from_iter([Argument::from(expr), Argument::from(count)]),
+            )
+        }
+        ToArrayKind::Rest => (Helper::ToArray, ctx.ast.vec1(Argument::from(expr))),
+    };
+    transform_ctx.helper_call_expr(helper, arguments, ctx)
+}
+
+/// Check if expression is a reference to `arguments`.
+fn is_arguments_ident(expr: &Expression) -> bool {
+    matches!(expr, Expression::Identifier(ident) if ident.name == "arguments")
+}
+
+/// Create `IdentifierReference` for a global, or a local binding if it's shadowed.
+fn create_global_ident<'a>(name: &'static str, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+    let symbol_id = ctx.scopes().find_binding(ctx.current_scope_id(), name);
+    let ident = ctx.create_reference_id(SPAN, Atom::from(name), symbol_id, ReferenceFlags::Read);
+    ctx.ast.expression_from_identifier_reference(ident)
+}
+
+fn set_span(expr: &mut Expression, span: Span) {
+    if let Expression::CallExpression(call) = expr {
+        call.span = span;
+    }
+}
@@ -0,0 +1,299 @@
+//! ES2015: Template Literals
+//!
+//! This plugin transforms template literals and tagged templates.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! `foo${bar}baz${qux}`;
+//! tag`foo${bar}`;
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _templateObject;
+//! "foo".concat(bar, "baz").concat(qux);
+//! tag(_templateObject || (_templateObject = babelHelpers.taggedTemplateLiteral(["foo", ""])), bar);
+//! ```
+//!
+//! `String.prototype.concat` is used to preserve the order of `ToPrimitive` conversions of the
+//! embedded expressions.
+//!
+//! The strings array for each tagged template is created only once, and cached in a var
+//! at top level of the program, as the spec requires the same object each time a tagged template
+//! is evaluated.
+//!
+//! ## Options
+//!
+//! ### `loose`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! When `true`, template literals are transformed to `+` concatenation: `"foo" + bar + "baz" + qux`.
+//!
+//! ### Assumptions
+//!
+//! * `ignoreToPrimitiveHint`: Same as `loose`.
+//! * `mutableTemplateObject`: Use `taggedTemplateLiteralLoose` helper, which does not freeze
+//!   the strings array.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-template-literals](https://babel.dev/docs/babel-plugin-transform-template-literals).
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-template-literals>
+//! * Template literals in spec: <https://tc39.es/ecma262/#sec-template-literals>
+
+use serde::Deserialize;
+
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::{ast::*, NONE};
+use oxc_span::SPAN;
+use oxc_syntax::{
+    operator::{AssignmentOperator, BinaryOperator, LogicalOperator},
+    symbol::SymbolFlags,
+};
+use oxc_traverse::{BoundIdentifier, Traverse, TraverseCtx};
+
+use crate::{common::helper_loader::Helper, TransformCtx};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct TemplateLiteralsOptions {
+    pub loose: bool,
+}
+
+pub struct TemplateLiterals<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    /// Use `+` concatenation instead of `String.prototype.concat`
+    loose: bool,
+    /// Vars which cache template objects, to be declared at top level of program
+    template_objects: Vec<BoundIdentifier<'a>>,
+}
+
+impl<'a, 'ctx> TemplateLiterals<'a, 'ctx> {
+    pub fn new(options: TemplateLiteralsOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        let loose = options.loose || ctx.assumptions.ignore_to_primitive_hint;
+        Self { ctx, loose, template_objects: vec![] }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for TemplateLiterals<'a, 'ctx> {
+    fn exit_program(&mut self, _program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.template_objects.is_empty() {
+            return;
+        }
+        // `var _templateObject, _templateObject2;`
+        let kind = VariableDeclarationKind::Var;
+        let declarators = ctx.ast.vec_from_iter(self.template_objects.drain(..).map(|binding| {
+            ctx.ast.variable_declarator(
+                SPAN,
+                kind,
+                binding.create_binding_pattern(ctx),
+                None,
+                false,
+            )
+        }));
+        let stmt = Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            kind,
+            declarators,
+            false,
+        ));
+        self.ctx.top_level_statements.insert_statement(stmt);
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            Expression::TemplateLiteral(_) => {
+                let Expression::TemplateLiteral(lit) = ctx.ast.move_expression(expr) else {
+                    unreachable!()
+                };
+                *expr = self.transform_template_literal(lit.unbox(), ctx);
+            }
+            Expression::TaggedTemplateExpression(_) => {
+                let Expression::TaggedTemplateExpression(tagged) = ctx.ast.move_expression(expr)
+                else {
+                    unreachable!()
+                };
+                *expr = self.transform_tagged_template(tagged.unbox(), ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a, 'ctx> TemplateLiterals<'a, 'ctx> {
+    /// `` `foo${bar}baz` `` -> `"foo".concat(bar, "baz")`
+    fn transform_template_literal(
+        &self,
+        lit: TemplateLiteral<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let TemplateLiteral { span, quasis, expressions } = lit;
+
+        // Interleave strings and expressions, skipping empty strings
+        let mut nodes = vec![];
+        let mut expressions = expressions.into_iter();
+        for quasi in quasis {
+            if let Some(cooked) = quasi.value.cooked {
+                if !cooked.is_empty() {
+                    nodes.push(ctx.ast.expression_string_literal(quasi.span, cooked));
+                }
+            }
+            if let Some(expr) = expressions.next() {
+                nodes.push(expr);
+            }
+        }
+
+        // First node must be a string, to ensure result is a string.
+        // In loose mode, `a + "b"` is fine as is.
+        let starts_with_string = matches!(nodes.first(), Some(Expression::StringLiteral(_)))
+            || (self.loose && matches!(nodes.get(1), Some(Expression::StringLiteral(_))));
+        if !starts_with_string {
+            nodes.insert(0, ctx.ast.expression_string_literal(SPAN, ""));
+        }
+
+        let mut nodes = nodes.into_iter();
+        let first = nodes.next().unwrap();
+        let mut result = if self.loose {
+            // `"foo" + bar + "baz"`
+            nodes.fold(first, |left, right| {
+                ctx.ast.expression_binary(SPAN, left, BinaryOperator::Addition, right)
+            })
+        } else {
+            Self::build_concat_calls(first, nodes, ctx)
+        };
+        if let Some(span_mut) = result_span_mut(&mut result) {
+            *span_mut = span;
+        }
+        result
+    }
+
+    /// Build chain of `concat` calls.
+    ///
+    /// Literals can be added to an existing `concat` call, but only the first non-literal can,
+    /// so that `ToPrimitive` conversions of expressions occur in correct order relative to
+    /// evaluation of later expressions.
+    ///
+    /// `"foo", bar, "baz", qux, "x"` -> `"foo".concat(bar, "baz").concat(qux, "x")`
+    fn build_concat_calls(
+        first: Expression<'a>,
+        rest: impl Iterator<Item = Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut result = first;
+        let mut avail = true;
+        for node in rest {
+            let mut can_be_inserted = node.is_literal();
+            if !can_be_inserted && avail {
+                can_be_inserted = true;
+                avail = false;
+            }
+            if can_be_inserted {
+                if let Expression::CallExpression(call) = &mut result {
+                    call.arguments.push(Argument::from(node));
+                    continue;
+                }
+            }
+            let callee = Expression::from(ctx.ast.member_expression_static(
+                SPAN,
+                result,
+                ctx.ast.identifier_name(SPAN, "concat"),
+                false,
+            ));
+            result = ctx.ast.expression_call(
+                SPAN,
+                callee,
+                NONE,
+                ctx.ast.vec1(Argument::from(node)),
+                false,
+            );
+        }
+        result
+    }
+
+    /// `` tag`foo${bar}` `` ->
+    /// `tag(_templateObject || (_templateObject = babelHelpers.taggedTemplateLiteral(["foo", ""])), bar)`
+    fn transform_tagged_template(
+        &mut self,
+        tagged: TaggedTemplateExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let TaggedTemplateExpression { span, tag, quasi, .. } = tagged;
+        let TemplateLiteral { quasis, expressions, .. } = quasi;
+
+        let mut is_strings_raw_equal = true;
+        let mut strings = ctx.ast.vec_with_capacity(quasis.len());
+        let mut raws = ctx.ast.vec_with_capacity(quasis.len());
+        for quasi in &quasis {
+            let TemplateElementValue { raw, cooked } = &quasi.value;
+            let string = match cooked {
+                Some(cooked) => {
+                    if cooked != raw {
+                        is_strings_raw_equal = false;
+                    }
+                    ctx.ast.expression_string_literal(SPAN, cooked.clone())
+                }
+                None => {
+                    is_strings_raw_equal = false;
+                    ctx.ast.void_0(SPAN)
+                }
+            };
+            strings.push(ArrayExpressionElement::from(string));
+            raws.push(ArrayExpressionElement::from(
+                ctx.ast.expression_string_literal(SPAN, raw.clone()),
+            ));
+        }
+
+        let mut arguments =
+            ctx.ast.vec1(Argument::from(ctx.ast.expression_array(SPAN, strings, None)));
+        if !is_strings_raw_equal {
+            arguments.push(Argument::from(ctx.ast.expression_array(SPAN, raws, None)));
+        }
+        let helper = if self.ctx.assumptions.mutable_template_object {
+            Helper::TaggedTemplateLiteralLoose
+        } else {
+            Helper::TaggedTemplateLiteral
+        };
+        let template_object = self.ctx.helper_call_expr(helper, arguments, ctx);
+
+        // `_templateObject || (_templateObject = ...)`
+        let binding =
+            ctx.generate_uid_in_root_scope("templateObject", SymbolFlags::FunctionScopedVariable);
+        let assignment = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            binding.create_read_write_target(ctx),
+            template_object,
+        );
+        let cached = ctx.ast.expression_logical(
+            SPAN,
+            binding.create_read_expression(ctx),
+            LogicalOperator::Or,
+            assignment,
+        );
+        self.template_objects.push(binding);
+
+        let mut arguments: ArenaVec<'a, Argument<'a>> =
+            ctx.ast.vec_with_capacity(expressions.len() + 1);
+        arguments.push(Argument::from(cached));
+        arguments.extend(expressions.into_iter().map(Argument::from));
+        ctx.ast.expression_call(span, tag, NONE, arguments, false)
+    }
+}
+
+/// Get mutable reference to span of result of transforming a template literal,
+/// so it can be given the span of the original template literal.
+fn result_span_mut<'b>(expr: &'b mut Expression<'_>) -> Option<&'b mut Span> {
+    match expr {
+        Expression::StringLiteral(lit) => Some(&mut lit.span),
+        Expression::CallExpression(call) => Some(&mut call.span),
+        Expression::BinaryExpression(binary) => Some(&mut binary.span),
+        _ => None,
+    }
+}
@@ -1,34 +1,58 @@
-//! ES2017: Async / Await \[WIP\]
+//! ES2017: Async / Await
 //!
 //! This plugin transforms async functions to generator functions.
 //!
+//! > This plugin is included in `preset-env`, in ES2017
+//!
 //! ## Example
 //!
 //! Input:
 //! ```js
-//! async function foo() {
-//!   await bar();
+//! async function foo(x) {
+//!   await bar(x);
 //! }
 //! const foo2 = async () => {
 //!   await bar();
 //! };
-//! async () => {
-//!   await bar();
-//! }
 //! ```
 //!
-//! Output (Currently):
+//! Output:
 //! ```js
-//! function foo() {
-//!   return _asyncToGenerator(function* () {
-//!     yield bar();
-//!   })
+//! function foo(_x) {
+//!   return _foo.apply(this, arguments);
 //! }
-//! const foo2 = () => _asyncToGenerator(function* () {
-//!   yield bar();
+//! function _foo() {
+//!   _foo = babelHelpers.asyncToGenerator(function* (x) {
+//!     yield bar(x);
+//!   });
+//!   return _foo.apply(this, arguments);
 //! }
+//! const foo2 = babelHelpers.asyncToGenerator(function* () {
+//!   yield bar();
+//! });
 //! ```
 //!
+//! Body and params of async functions and methods are moved into a generator function, which is
+//! called with the original `this` and `arguments`. So function declarations stay hoisted,
+//! and methods stay methods. The generator function of a function declaration is created once,
+//! by a function declared after it. Params before the first default value or rest param are
+//! replaced with `_x`, `_x2`, ..., so the function keeps its `length`.
+//!
+//! If regenerator plugin is enabled, the generator functions created are transformed by it too.
+//!
+//! `super` in async class methods is only supported when classes plugin is also enabled,
+//! which transforms `super` in the generator functions created.
+//!
+//! ## Missing features
+//!
+//! Implementation is incomplete at present. Still TODO:
+//!
+//! * `length` of async arrow functions is not preserved.
+//! * `super` in async methods produces an error, unless classes plugin is enabled
+//!   and the method is a class method.
+//! * `this` and `arguments` in async arrow functions are only correct when arrow functions
+//!   plugin is also enabled.
+//!
 //! ## Implementation
 //!
 //! Implementation based on [@babel/plugin-transform-async-to-generator](https://babel.dev/docs/babel-plugin-transform-async-to-generator).
@@ -40,26 +64,49 @@
 //! * Babel helper implementation: <https://github.com/babel/babel/blob/main/packages/babel-helper-remap-async-to-generator>
 //! * Async / Await TC39 proposal: <https://github.com/tc39/proposal-async-await>
 
+use oxc_allocator::{GetAddress, Vec as ArenaVec};
 use oxc_ast::{
     ast::{
-        ArrowFunctionExpression, Expression, Function, FunctionType, Statement,
-        VariableDeclarationKind,
+        ArrowFunctionExpression, AssignmentOperator, Expression, FormalParameter,
+        FormalParameterKind, FormalParameters, Function, FunctionType, Statement,
     },
     NONE,
 };
-use oxc_semantic::ScopeFlags;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags};
 use oxc_span::{GetSpan, SPAN};
-use oxc_traverse::{Ancestor, Traverse, TraverseCtx};
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse, TraverseCtx};
 
-use crate::{common::helper_loader::Helper, TransformCtx};
+use crate::{
+    common::helper_loader::Helper,
+    es2015::{
+        create_apply_body, find_super_in_function, take_function_contents, Regenerator,
+        RegeneratorOptions,
+    },
+    TransformCtx,
+};
 
 pub struct AsyncToGenerator<'a, 'ctx> {
     ctx: &'ctx TransformCtx<'a>,
+    /// Regenerator plugin, if enabled, to transform the generator functions created
+    regenerator: Option<Regenerator<'a, 'ctx>>,
+    /// `true` if classes plugin is enabled, so `super` in class methods is transformed by it
+    transform_classes: bool,
+    /// `function _foo() {}` created for async function declarations, which are inserted after
+    /// the statement which declares the function
+    wrapper_declarations: Vec<Statement<'a>>,
 }
 
 impl<'a, 'ctx> AsyncToGenerator<'a, 'ctx> {
-    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
-        Self { ctx }
+    pub fn new(
+        regenerator: Option<RegeneratorOptions>,
+        transform_classes: bool,
+        ctx: &'ctx TransformCtx<'a>,
+    ) -> Self {
+        let regenerator = regenerator
+            .filter(|options| options.generators)
+            .map(|options| Regenerator::new(options, ctx));
+        Self { ctx, regenerator, transform_classes, wrapper_declarations: vec![] }
     }
 }
 
@@ -95,12 +142,6 @@ impl<'a, 'ctx> Traverse<'a> for AsyncToGenerator<'a, 'ctx> {
                     Some(ctx.ast.move_expression(&mut await_expr.argument)),
                 );
             }
-        } else if let Expression::FunctionExpression(func) = expr {
-            if !func.r#async || func.generator {
-                return;
-            }
-            let new_function = self.transform_function(func, ctx);
-            *expr = ctx.ast.expression_from_function(new_function);
         } else if let Expression::ArrowFunctionExpression(arrow) = expr {
             if !arrow.r#async {
                 return;
@@ -109,86 +150,158 @@ impl<'a, 'ctx> Traverse<'a> for AsyncToGenerator<'a, 'ctx> {
         }
     }
 
-    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
-        if let Statement::FunctionDeclaration(func) = stmt {
-            if !func.r#async || func.generator {
+    fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !func.r#async || func.generator || func.body.is_none() {
+            return;
+        }
+        self.transform_function(func, ctx);
+    }
+
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, _ctx: &mut TraverseCtx<'a>) {
+        if !self.wrapper_declarations.is_empty() {
+            self.ctx
+                .statement_injector
+                .insert_many_after(stmt.address(), std::mem::take(&mut self.wrapper_declarations));
+        }
+    }
+}
+
+impl<'a, 'ctx> AsyncToGenerator<'a, 'ctx> {
+    /// `async function foo(x) { await x; }`
+    /// -> `function foo(_x) { return _foo.apply(this, arguments); }`
+    ///    `function _foo() { _foo = babelHelpers.asyncToGenerator(function* (x) { yield x; }); return _foo.apply(this, arguments); }`
+    ///
+    /// `({ async foo(x) { await x; } })`
+    /// -> `({ foo(_x) { return babelHelpers.asyncToGenerator(function* (x) { yield x; }).apply(this, arguments); } })`
+    fn transform_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        let is_method = match ctx.parent() {
+            Ancestor::MethodDefinitionValue(_) => !self.transform_classes,
+            Ancestor::ObjectPropertyValue(property) => *property.method(),
+            _ => false,
+        };
+        if is_method {
+            if let Some(span) = find_super_in_function(func) {
+                self.ctx.error(
+                    OxcDiagnostic::error("`super` in async methods is not supported yet.")
+                        .with_label(span),
+                );
                 return;
             }
-            let new_function = self.transform_function(func, ctx);
-            if let Some(id) = func.id.take() {
-                *stmt = ctx.ast.statement_declaration(ctx.ast.declaration_variable(
-                    SPAN,
-                    VariableDeclarationKind::Const,
-                    ctx.ast.vec1(ctx.ast.variable_declarator(
-                        SPAN,
-                        VariableDeclarationKind::Const,
-                        ctx.ast.binding_pattern(
-                            ctx.ast.binding_pattern_kind_from_binding_identifier(id),
-                            NONE,
-                            false,
-                        ),
-                        Some(ctx.ast.expression_from_function(new_function)),
-                        false,
-                    )),
-                    false,
-                ));
-            } else {
-                *stmt =
-                    ctx.ast.statement_declaration(ctx.ast.declaration_from_function(new_function));
+        }
+
+        let container = take_function_contents(func, ctx);
+        func.r#async = false;
+        let outer_scope_id = func.scope_id.get().unwrap();
+        func.params.items = Self::create_length_params(&container.params, outer_scope_id, ctx);
+
+        if func.is_declaration() {
+            if let Some(id) = &func.id {
+                let name = id.name.clone();
+                // Declared in the same place as `foo`
+                let flags =
+                    ctx.symbols().get_flags(id.symbol_id.get().unwrap()) - SymbolFlags::Export;
+                let wrapper =
+                    self.create_wrapper_declaration(&name, flags, container, outer_scope_id, ctx);
+                func.body = Some(create_apply_body(wrapper.create_read_expression(ctx), ctx));
+                return;
             }
         }
+
+        let container = self.create_generator_expression(container, ctx);
+        let call = self.ctx.helper_call_expr(
+            Helper::AsyncToGenerator,
+            ctx.ast.vec1(ctx.ast.argument_expression(container)),
+            ctx,
+        );
+        func.body = Some(create_apply_body(call, ctx));
     }
-}
 
-impl<'a, 'ctx> AsyncToGenerator<'a, 'ctx> {
-    fn transform_function(
-        &self,
-        func: &mut Function<'a>,
+    /// Create `function _foo() { _foo = babelHelpers.asyncToGenerator(container); return _foo.apply(this, arguments); }`
+    /// to be inserted after the declaration of async function `foo`, and return its binding.
+    fn create_wrapper_declaration(
+        &mut self,
+        name: &str,
+        symbol_flags: SymbolFlags,
+        container: oxc_allocator::Box<'a, Function<'a>>,
+        outer_scope_id: ScopeId,
         ctx: &mut TraverseCtx<'a>,
-    ) -> Function<'a> {
-        let target = ctx.ast.function(
-            func.r#type,
+    ) -> BoundIdentifier<'a> {
+        let parent_scope_id = ctx.current_scope_id();
+        let binding = ctx.generate_uid(name, parent_scope_id, symbol_flags);
+        let flags = ctx.scopes().get_flags(outer_scope_id);
+        let wrapper_scope_id = ctx.create_child_scope(parent_scope_id, flags);
+        ctx.scopes_mut()
+            .change_parent_id(container.scope_id.get().unwrap(), Some(wrapper_scope_id));
+
+        let container = self.create_generator_expression(container, ctx);
+        let call = self.ctx.helper_call_expr(
+            Helper::AsyncToGenerator,
+            ctx.ast.vec1(ctx.ast.argument_expression(container)),
+            ctx,
+        );
+        let assignment = ctx.ast.expression_assignment(
             SPAN,
-            None,
-            true,
-            false,
-            false,
-            func.type_parameters.take(),
-            func.this_param.take(),
-            ctx.ast.alloc(ctx.ast.formal_parameters(
-                SPAN,
-                func.params.kind,
-                ctx.ast.move_vec(&mut func.params.items),
-                func.params.rest.take(),
-            )),
-            func.return_type.take(),
-            func.body.take(),
+            AssignmentOperator::Assign,
+            binding.create_write_target(ctx),
+            call,
         );
-        let parameters =
-            ctx.ast.vec1(ctx.ast.argument_expression(ctx.ast.expression_from_function(target)));
-        let call = self.ctx.helper_call_expr(Helper::AsyncToGenerator, parameters, ctx);
-        let returns = ctx.ast.return_statement(SPAN, Some(call));
-        let body = Statement::ReturnStatement(ctx.ast.alloc(returns));
-        let body = ctx.ast.function_body(SPAN, ctx.ast.vec(), ctx.ast.vec1(body));
-        let body = ctx.ast.alloc(body);
-        let params = ctx.ast.formal_parameters(SPAN, func.params.kind, ctx.ast.vec(), NONE);
-        ctx.ast.function(
-            FunctionType::FunctionExpression,
+        let mut body = create_apply_body(binding.create_read_expression(ctx), ctx);
+        body.statements.insert(0, ctx.ast.statement_expression(SPAN, assignment));
+
+        let params = ctx.ast.alloc_formal_parameters(
             SPAN,
-            None,
+            FormalParameterKind::FormalParameter,
+            ctx.ast.vec(),
+            NONE,
+        );
+        let wrapper = ctx.ast.alloc_function_with_scope_id(
+            FunctionType::FunctionDeclaration,
+            SPAN,
+            Some(binding.create_binding_identifier(ctx)),
             false,
             false,
             false,
-            func.type_parameters.take(),
-            func.this_param.take(),
+            NONE,
+            NONE,
             params,
-            func.return_type.take(),
+            NONE,
             Some(body),
-        )
+            wrapper_scope_id,
+        );
+        self.wrapper_declarations.push(Statement::FunctionDeclaration(wrapper));
+        binding
     }
 
+    /// `_x, _x2` for `params` before the first default value or rest param,
+    /// so the function which replaces `params` has the same `length`.
+    fn create_length_params(
+        params: &FormalParameters<'a>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ArenaVec<'a, FormalParameter<'a>> {
+        let len = params
+            .items
+            .iter()
+            .take_while(|param| !param.pattern.kind.is_assignment_pattern())
+            .count();
+        let mut items = ctx.ast.vec_with_capacity(len);
+        for _ in 0..len {
+            let binding = ctx.generate_uid("x", scope_id, SymbolFlags::FunctionScopedVariable);
+            items.push(ctx.ast.formal_parameter(
+                SPAN,
+                ctx.ast.vec(),
+                binding.create_binding_pattern(ctx),
+                None,
+                false,
+                false,
+            ));
+        }
+        items
+    }
+
+    /// `async (x) => { await x; }` -> `babelHelpers.asyncToGenerator(function* (x) { yield x; })`
     fn transform_arrow_function(
-        &self,
+        &mut self,
         arrow: &mut ArrowFunctionExpression<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) -> Expression<'a> {
@@ -219,13 +332,26 @@ impl<'a, 'ctx> AsyncToGenerator<'a, 'ctx> {
         let mut function = ctx
             .ast
             .function(r#type, SPAN, None, true, false, false, NONE, NONE, parameters, NONE, body);
-        function.scope_id = arrow.scope_id.clone();
+        function.scope_id.clone_from(&arrow.scope_id);
         if let Some(scope_id) = function.scope_id.get() {
             ctx.scopes_mut().get_flags_mut(scope_id).remove(ScopeFlags::Arrow);
         }
 
-        let arguments =
-            ctx.ast.vec1(ctx.ast.argument_expression(ctx.ast.expression_from_function(function)));
+        let function = self.create_generator_expression(ctx.ast.alloc(function), ctx);
+        let arguments = ctx.ast.vec1(ctx.ast.argument_expression(function));
         self.ctx.helper_call_expr(Helper::AsyncToGenerator, arguments, ctx)
     }
+
+    /// Create generator function expression, and transform it with regenerator plugin if it's enabled.
+    fn create_generator_expression(
+        &mut self,
+        func: oxc_allocator::Box<'a, Function<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut expr = Expression::FunctionExpression(func);
+        if let Some(regenerator) = &mut self.regenerator {
+            regenerator.transform_generator_expression(&mut expr, ctx);
+        }
+        expr
+    }
 }
@@ -1,7 +1,8 @@
-use oxc_ast::ast::{Expression, Statement};
+use oxc_ast::ast::{Expression, Function, Statement};
 use oxc_traverse::{Traverse, TraverseCtx};
 
 use crate::{
+    es2015::RegeneratorOptions,
     es2017::{async_to_generator::AsyncToGenerator, options::ES2017Options},
     TransformCtx,
 };
@@ -18,8 +19,16 @@ pub struct ES2017<'a, 'ctx> {
 }
 
 impl<'a, 'ctx> ES2017<'a, 'ctx> {
-    pub fn new(options: ES2017Options, ctx: &'ctx TransformCtx<'a>) -> ES2017<'a, 'ctx> {
-        ES2017 { async_to_generator: AsyncToGenerator::new(ctx), options }
+    pub fn new(
+        options: ES2017Options,
+        regenerator: Option<RegeneratorOptions>,
+        transform_classes: bool,
+        ctx: &'ctx TransformCtx<'a>,
+    ) -> ES2017<'a, 'ctx> {
+        ES2017 {
+            async_to_generator: AsyncToGenerator::new(regenerator, transform_classes, ctx),
+            options,
+        }
     }
 }
 
@@ -30,6 +39,12 @@ impl<'a, 'ctx> Traverse<'a> for ES2017<'a, 'ctx> {
         }
     }
 
+    fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.async_to_generator {
+            self.async_to_generator.exit_function(func, ctx);
+        }
+    }
+
     fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
         if self.options.async_to_generator {
             self.async_to_generator.exit_statement(stmt, ctx);
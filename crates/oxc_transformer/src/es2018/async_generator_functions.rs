@@ -0,0 +1,485 @@
+//! ES2018: Async Generator Functions
+//!
+//! This plugin transforms async generator functions to generator functions,
+//! and `for await...of` loops to `for` loops using an async iterator.
+//!
+//! > This plugin is included in `preset-env`, in ES2018
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! async function* agf(x) {
+//!   await x;
+//!   yield* other();
+//!   for await (const item of items) {
+//!     yield item;
+//!   }
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! function agf() {
+//!   return babelHelpers.wrapAsyncGenerator(function* (x) {
+//!     yield babelHelpers.awaitAsyncGenerator(x);
+//!     yield* babelHelpers.asyncGeneratorDelegate(babelHelpers.asyncIterator(other()));
+//!     var _iteratorAbruptCompletion = false;
+//!     var _didIteratorError = false;
+//!     var _iteratorError;
+//!     try {
+//!       for (var _iterator = babelHelpers.asyncIterator(items), _step; _iteratorAbruptCompletion = !(_step = yield babelHelpers.awaitAsyncGenerator(_iterator.next())).done; _iteratorAbruptCompletion = false) {
+//!         const item = _step.value;
+//!         yield item;
+//!       }
+//!     } catch (err) {
+//!       _didIteratorError = true;
+//!       _iteratorError = err;
+//!     } finally {
+//!       try {
+//!         if (_iteratorAbruptCompletion && _iterator.return != null) {
+//!           yield babelHelpers.awaitAsyncGenerator(_iterator.return());
+//!         }
+//!       } finally {
+//!         if (_didIteratorError) {
+//!           throw _iteratorError;
+//!         }
+//!       }
+//!     }
+//!   }).apply(this, arguments);
+//! }
+//! ```
+//!
+//! `for await...of` loops in async functions are transformed in the same way, but keep `await`
+//! (which is then transformed by the ES2017 async-to-generator plugin, if it's enabled).
+//!
+//! If regenerator plugin is enabled, the generator functions created are transformed by it too.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-async-generator-functions](https://babel.dev/docs/babel-plugin-transform-async-generator-functions).
+//!
+//! ## Missing features
+//!
+//! * Async generator functions are not wrapped in a hoisted helper function, so the transformed
+//!   function's `length` is always 0.
+//! * `super` in async generator methods is not supported.
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-async-generator-functions>
+//! * Async iteration TC39 proposal: <https://github.com/tc39/proposal-async-iteration>
+
+use oxc_allocator::GetAddress;
+use oxc_ast::{ast::*, NONE};
+use oxc_semantic::ReferenceFlags;
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::{
+    operator::{AssignmentOperator, BinaryOperator, LogicalOperator},
+    scope::{ScopeFlags, ScopeId},
+    symbol::SymbolFlags,
+};
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse, TraverseCtx};
+
+use crate::{
+    common::helper_loader::Helper,
+    es2015::{
+        create_declarator, create_static_member, create_step_test, current_hoist_scope_id, ForOf,
+        Regenerator, RegeneratorOptions,
+    },
+    TransformCtx,
+};
+
+pub struct AsyncGeneratorFunctions<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    /// Regenerator plugin, if enabled, to transform the generator functions created
+    regenerator: Option<Regenerator<'a, 'ctx>>,
+}
+
+impl<'a, 'ctx> AsyncGeneratorFunctions<'a, 'ctx> {
+    pub fn new(regenerator: Option<RegeneratorOptions>, ctx: &'ctx TransformCtx<'a>) -> Self {
+        let regenerator = regenerator
+            .filter(|options| options.generators)
+            .map(|options| Regenerator::new(options, ctx));
+        Self { ctx, regenerator }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for AsyncGeneratorFunctions<'a, 'ctx> {
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            // `await x` -> `yield babelHelpers.awaitAsyncGenerator(x)`
+            Expression::AwaitExpression(await_expr) if Self::is_in_async_generator(ctx) => {
+                let argument = ctx.ast.move_expression(&mut await_expr.argument);
+                let arguments = ctx.ast.vec1(Argument::from(argument));
+                let call = self.ctx.helper_call_expr(Helper::AwaitAsyncGenerator, arguments, ctx);
+                *expr = ctx.ast.expression_yield(await_expr.span, false, Some(call));
+            }
+            // `yield* x` -> `yield* babelHelpers.asyncGeneratorDelegate(babelHelpers.asyncIterator(x))`
+            Expression::YieldExpression(yield_expr)
+                if yield_expr.delegate && Self::is_in_async_generator(ctx) =>
+            {
+                let Some(argument) = yield_expr.argument.as_mut() else { return };
+                let argument = ctx.ast.move_expression(argument);
+                let arguments = ctx.ast.vec1(Argument::from(argument));
+                let iterator = self.ctx.helper_call_expr(Helper::AsyncIterator, arguments, ctx);
+                let arguments = ctx.ast.vec1(Argument::from(iterator));
+                let delegate =
+                    self.ctx.helper_call_expr(Helper::AsyncGeneratorDelegate, arguments, ctx);
+                yield_expr.argument = Some(delegate);
+            }
+            _ => {}
+        }
+    }
+
+    fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        if func.r#async && func.generator && func.body.is_some() {
+            self.transform_function(func, ctx);
+        }
+    }
+
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        // Labelled loops are handled at the outermost label, so the labels can be kept on the loop
+        // when it's wrapped in a `try` statement
+        if matches!(ctx.parent(), Ancestor::LabeledStatementBody(_)) {
+            return;
+        }
+        let mut target = &mut *stmt;
+        while let Statement::LabeledStatement(labeled) = target {
+            target = &mut labeled.body;
+        }
+        let Statement::ForOfStatement(for_of) = target else { return };
+        if !for_of.r#await {
+            return;
+        }
+
+        let for_scope_id = for_of.scope_id.get().unwrap();
+        let (loop_stmt, bindings) = self.transform_for_await(for_of, ctx);
+        *target = loop_stmt;
+        self.wrap_in_try(stmt, &bindings, for_scope_id, ctx);
+    }
+}
+
+/// Bindings for variables used by a transformed `for await...of` loop.
+struct ForAwaitBindings<'a> {
+    abrupt_completion: BoundIdentifier<'a>,
+    did_error: BoundIdentifier<'a>,
+    error: BoundIdentifier<'a>,
+    iterator: BoundIdentifier<'a>,
+}
+
+impl<'a, 'ctx> AsyncGeneratorFunctions<'a, 'ctx> {
+    /// Returns `true` if the closest function is an async generator function.
+    fn is_in_async_generator(ctx: &TraverseCtx<'a>) -> bool {
+        ctx.ancestors()
+            .find_map(|ancestor| match ancestor {
+                Ancestor::FunctionBody(body) => Some(*body.r#async() && *body.generator()),
+                Ancestor::ArrowFunctionExpressionBody(_) => Some(false),
+                _ => None,
+            })
+            .unwrap_or(false)
+    }
+
+    /// `async function* f(x) {}`
+    /// -> `function f() { return babelHelpers.wrapAsyncGenerator(function* (x) {}).apply(this, arguments); }`
+    ///
+    /// The original function's scope becomes the scope of the inner generator function,
+    /// and the outer function gets a new scope.
+    fn transform_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        let inner_scope_id = func.scope_id.get().unwrap();
+        let flags = ctx.scopes().get_flags(inner_scope_id);
+        let outer_scope_id = ctx.create_child_scope(ctx.current_scope_id(), flags);
+        ctx.scopes_mut().change_parent_id(inner_scope_id, Some(outer_scope_id));
+
+        // Name of a function expression is bound in the function's own scope
+        if let Some(id) = &func.id {
+            let symbol_id = id.symbol_id.get().unwrap();
+            if ctx.symbols().get_scope_id(symbol_id) == inner_scope_id {
+                ctx.scopes_mut().move_binding(inner_scope_id, outer_scope_id, &id.name);
+                ctx.symbols_mut().set_scope_id(symbol_id, outer_scope_id);
+            }
+        }
+
+        // `function* (x) {}`
+        let params = ctx.ast.formal_parameters(SPAN, func.params.kind, ctx.ast.vec(), NONE);
+        let params = std::mem::replace(&mut func.params, ctx.ast.alloc(params));
+        let inner = ctx.ast.alloc_function_with_scope_id(
+            FunctionType::FunctionExpression,
+            SPAN,
+            None,
+            true,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            func.body.take(),
+            inner_scope_id,
+        );
+
+        let mut inner = Expression::FunctionExpression(inner);
+        if let Some(regenerator) = &mut self.regenerator {
+            regenerator.transform_generator_expression(&mut inner, ctx);
+        }
+
+        // `babelHelpers.wrapAsyncGenerator(function* (x) {}).apply(this, arguments)`
+        let arguments = ctx.ast.vec1(Argument::from(inner));
+        let wrapped = self.ctx.helper_call_expr(Helper::WrapAsyncGenerator, arguments, ctx);
+        let apply = create_static_member(wrapped, "apply", ctx);
+        let symbol_id = ctx.scopes().find_binding(outer_scope_id, "arguments");
+        let arguments_ident =
+            ctx.create_reference_id(SPAN, Atom::from("arguments"), symbol_id, ReferenceFlags::Read);
+        let arguments = ctx.ast.vec_from_iter([
+            Argument::from(ctx.ast.expression_this(SPAN)),
+            Argument::from(ctx.ast.expression_from_identifier_reference(arguments_ident)),
+        ]);
+        let call = ctx.ast.expression_call(SPAN, apply, NONE, arguments, false);
+
+        let body = ctx.ast.vec1(ctx.ast.statement_return(SPAN, Some(call)));
+        func.body = Some(ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), body));
+        func.r#async = false;
+        func.generator = false;
+        func.scope_id.set(Some(outer_scope_id));
+    }
+
+    /// `for await (const x of y) {}`
+    /// -> `for (var _iterator = babelHelpers.asyncIterator(y), _step; _iteratorAbruptCompletion = !(_step = await _iterator.next()).done; _iteratorAbruptCompletion = false) { const x = _step.value; }`
+    fn transform_for_await(
+        &self,
+        for_of: &mut ForOfStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Statement<'a>, ForAwaitBindings<'a>) {
+        let scope_id = current_hoist_scope_id(ctx);
+        let flags = SymbolFlags::FunctionScopedVariable;
+        let abrupt_completion = ctx.generate_uid("iteratorAbruptCompletion", scope_id, flags);
+        let did_error = ctx.generate_uid("didIteratorError", scope_id, flags);
+        let error = ctx.generate_uid("iteratorError", scope_id, flags);
+        let iterator = ctx.generate_uid("iterator", scope_id, flags);
+        let step = ctx.generate_uid("step", scope_id, flags);
+
+        // `var _iterator = babelHelpers.asyncIterator(y), _step`
+        let right = ctx.ast.move_expression(&mut for_of.right);
+        let arguments = ctx.ast.vec1(Argument::from(right));
+        let helper = self.ctx.helper_call_expr(Helper::AsyncIterator, arguments, ctx);
+        let init = ForStatementInit::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            ctx.ast.vec_from_iter([
+                create_declarator(&iterator, Some(helper), ctx),
+                create_declarator(&step, None, ctx),
+            ]),
+            false,
+        ));
+
+        // `_iteratorAbruptCompletion = !(_step = await _iterator.next()).done`
+        let next = create_static_member(iterator.create_read_expression(ctx), "next", ctx);
+        let next = ctx.ast.expression_call(SPAN, next, NONE, ctx.ast.vec(), false);
+        let next = ctx.ast.expression_await(SPAN, next);
+        let test = create_step_test(&step, next, ctx);
+        let test = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            abrupt_completion.create_read_write_target(ctx),
+            test,
+        );
+
+        // `_iteratorAbruptCompletion = false`
+        let update = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            abrupt_completion.create_read_write_target(ctx),
+            ctx.ast.expression_boolean_literal(SPAN, false),
+        );
+
+        let value = create_static_member(step.create_read_expression(ctx), "value", ctx);
+        let loop_stmt =
+            ForOf::create_for_statement(for_of, Some(init), test, Some(update), value, ctx);
+        (loop_stmt, ForAwaitBindings { abrupt_completion, did_error, error, iterator })
+    }
+
+    /// Wrap loop in `try` statement which closes the iterator.
+    ///
+    /// ```js
+    /// var _iteratorAbruptCompletion = false;
+    /// var _didIteratorError = false;
+    /// var _iteratorError;
+    /// try {
+    ///   for (...) {}
+    /// } catch (err) {
+    ///   _didIteratorError = true;
+    ///   _iteratorError = err;
+    /// } finally {
+    ///   try {
+    ///     if (_iteratorAbruptCompletion && _iterator.return != null) {
+    ///       await _iterator.return();
+    ///     }
+    ///   } finally {
+    ///     if (_didIteratorError) {
+    ///       throw _iteratorError;
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    fn wrap_in_try(
+        &self,
+        stmt: &mut Statement<'a>,
+        bindings: &ForAwaitBindings<'a>,
+        for_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let ForAwaitBindings { abrupt_completion, did_error, error, iterator } = bindings;
+        let in_statement_list = matches!(
+            ctx.parent(),
+            Ancestor::ProgramBody(_)
+                | Ancestor::BlockStatementBody(_)
+                | Ancestor::FunctionBodyStatements(_)
+                | Ancestor::SwitchCaseConsequent(_)
+                | Ancestor::StaticBlockBody(_)
+                | Ancestor::TSModuleBlockBody(_)
+        );
+
+        // If loop is not in a statement list, it's wrapped in a block with the declarations
+        let wrapper_scope_id = if in_statement_list {
+            None
+        } else {
+            Some(ctx.insert_scope_below_statement(stmt, ScopeFlags::empty()))
+        };
+        let parent_scope_id = wrapper_scope_id.unwrap_or_else(|| ctx.current_scope_id());
+
+        // `var _iteratorAbruptCompletion = false; var _didIteratorError = false; var _iteratorError;`
+        let decls = [
+            (abrupt_completion, Some(ctx.ast.expression_boolean_literal(SPAN, false))),
+            (did_error, Some(ctx.ast.expression_boolean_literal(SPAN, false))),
+            (error, None),
+        ]
+        .into_iter()
+        .map(|(binding, init)| {
+            Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+                SPAN,
+                VariableDeclarationKind::Var,
+                ctx.ast.vec1(create_declarator(binding, init, ctx)),
+                false,
+            ))
+        })
+        .collect::<Vec<_>>();
+
+        // `try { for (...) {} }`
+        let block_scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+        ctx.scopes_mut().change_parent_id(for_scope_id, Some(block_scope_id));
+        let loop_stmt = ctx.ast.move_statement(stmt);
+        let block = ctx.ast.alloc_block_statement_with_scope_id(
+            SPAN,
+            ctx.ast.vec1(loop_stmt),
+            block_scope_id,
+        );
+
+        // `catch (err) { _didIteratorError = true; _iteratorError = err; }`
+        // Catch param binding is in scope of catch body, same as in `SemanticBuilder`
+        let catch_scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::CatchClause);
+        let catch_body_scope_id = ctx.create_child_scope(catch_scope_id, ScopeFlags::empty());
+        let err = ctx.generate_binding(
+            Atom::from("err"),
+            catch_body_scope_id,
+            SymbolFlags::FunctionScopedVariable | SymbolFlags::CatchVariable,
+        );
+        let param = ctx.ast.catch_parameter(SPAN, err.create_binding_pattern(ctx));
+        let catch_body = ctx.ast.vec_from_iter([
+            create_assignment(did_error, ctx.ast.expression_boolean_literal(SPAN, true), ctx),
+            create_assignment(error, err.create_read_expression(ctx), ctx),
+        ]);
+        let catch_body =
+            ctx.ast.alloc_block_statement_with_scope_id(SPAN, catch_body, catch_body_scope_id);
+        let handler =
+            ctx.ast.alloc_catch_clause_with_scope_id(SPAN, Some(param), catch_body, catch_scope_id);
+
+        // `finally { try { ... } finally { ... } }`
+        let finally_scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+
+        // `if (_iteratorAbruptCompletion && _iterator.return != null) { await _iterator.return(); }`
+        let return_method =
+            create_static_member(iterator.create_read_expression(ctx), "return", ctx);
+        let has_return = ctx.ast.expression_binary(
+            SPAN,
+            return_method,
+            BinaryOperator::Inequality,
+            ctx.ast.expression_null_literal(SPAN),
+        );
+        let test = ctx.ast.expression_logical(
+            SPAN,
+            abrupt_completion.create_read_expression(ctx),
+            LogicalOperator::And,
+            has_return,
+        );
+        let return_method =
+            create_static_member(iterator.create_read_expression(ctx), "return", ctx);
+        let call = ctx.ast.expression_call(SPAN, return_method, NONE, ctx.ast.vec(), false);
+        let call = ctx.ast.statement_expression(SPAN, ctx.ast.expression_await(SPAN, call));
+        let inner_try_scope_id = ctx.create_child_scope(finally_scope_id, ScopeFlags::empty());
+        let if_stmt = create_if(test, call, inner_try_scope_id, ctx);
+        let inner_block = ctx.ast.alloc_block_statement_with_scope_id(
+            SPAN,
+            ctx.ast.vec1(if_stmt),
+            inner_try_scope_id,
+        );
+
+        // `if (_didIteratorError) { throw _iteratorError; }`
+        let inner_finally_scope_id = ctx.create_child_scope(finally_scope_id, ScopeFlags::empty());
+        let throw = ctx.ast.statement_throw(SPAN, error.create_read_expression(ctx));
+        let if_stmt =
+            create_if(did_error.create_read_expression(ctx), throw, inner_finally_scope_id, ctx);
+        let inner_finalizer = ctx.ast.alloc_block_statement_with_scope_id(
+            SPAN,
+            ctx.ast.vec1(if_stmt),
+            inner_finally_scope_id,
+        );
+
+        let inner_try = ctx.ast.statement_try(SPAN, inner_block, NONE, Some(inner_finalizer));
+        let finalizer = ctx.ast.alloc_block_statement_with_scope_id(
+            SPAN,
+            ctx.ast.vec1(inner_try),
+            finally_scope_id,
+        );
+
+        let try_stmt = ctx.ast.statement_try(SPAN, block, Some(handler), Some(finalizer));
+        if let Some(wrapper_scope_id) = wrapper_scope_id {
+            let mut body = ctx.ast.vec_from_iter(decls);
+            body.push(try_stmt);
+            *stmt = Statement::BlockStatement(ctx.ast.alloc_block_statement_with_scope_id(
+                SPAN,
+                body,
+                wrapper_scope_id,
+            ));
+        } else {
+            *stmt = try_stmt;
+            self.ctx.statement_injector.insert_many_before(stmt.address(), decls);
+        }
+    }
+}
+
+/// `binding = value;`
+fn create_assignment<'a>(
+    binding: &BoundIdentifier<'a>,
+    value: Expression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Statement<'a> {
+    let assignment = ctx.ast.expression_assignment(
+        SPAN,
+        AssignmentOperator::Assign,
+        binding.create_write_target(ctx),
+        value,
+    );
+    ctx.ast.statement_expression(SPAN, assignment)
+}
+
+/// `if (test) { consequent }`, with the block in a new child scope of `parent_scope_id`.
+fn create_if<'a>(
+    test: Expression<'a>,
+    consequent: Statement<'a>,
+    parent_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) -> Statement<'a> {
+    let scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+    let block =
+        ctx.ast.alloc_block_statement_with_scope_id(SPAN, ctx.ast.vec1(consequent), scope_id);
+    ctx.ast.statement_if(SPAN, test, Statement::BlockStatement(block), None)
+}
@@ -1,11 +1,13 @@
 use oxc_ast::ast::*;
 use oxc_traverse::{Traverse, TraverseCtx};
 
-use crate::TransformCtx;
+use crate::{es2015::RegeneratorOptions, TransformCtx};
 
+mod async_generator_functions;
 mod object_rest_spread;
 mod options;
 
+pub use async_generator_functions::AsyncGeneratorFunctions;
 pub use object_rest_spread::{ObjectRestSpread, ObjectRestSpreadOptions};
 pub use options::ES2018Options;
 
@@ -14,15 +16,21 @@ pub struct ES2018<'a, 'ctx> {
 
     // Plugins
     object_rest_spread: ObjectRestSpread<'a, 'ctx>,
+    async_generator_functions: AsyncGeneratorFunctions<'a, 'ctx>,
 }
 
 impl<'a, 'ctx> ES2018<'a, 'ctx> {
-    pub fn new(options: ES2018Options, ctx: &'ctx TransformCtx<'a>) -> Self {
+    pub fn new(
+        options: ES2018Options,
+        regenerator: Option<RegeneratorOptions>,
+        ctx: &'ctx TransformCtx<'a>,
+    ) -> Self {
         Self {
             object_rest_spread: ObjectRestSpread::new(
                 options.object_rest_spread.unwrap_or_default(),
                 ctx,
             ),
+            async_generator_functions: AsyncGeneratorFunctions::new(regenerator, ctx),
             options,
         }
     }
@@ -34,4 +42,22 @@ impl<'a, 'ctx> Traverse<'a> for ES2018<'a, 'ctx> {
             self.object_rest_spread.enter_expression(expr, ctx);
         }
     }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.async_generator_functions {
+            self.async_generator_functions.exit_expression(expr, ctx);
+        }
+    }
+
+    fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.async_generator_functions {
+            self.async_generator_functions.exit_function(func, ctx);
+        }
+    }
+
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.async_generator_functions {
+            self.async_generator_functions.enter_statement(stmt, ctx);
+        }
+    }
 }
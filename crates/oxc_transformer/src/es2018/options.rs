@@ -9,6 +9,9 @@ use super::ObjectRestSpreadOptions;
 pub struct ES2018Options {
     #[serde(skip)]
     pub object_rest_spread: Option<ObjectRestSpreadOptions>,
+
+    #[serde(skip)]
+    pub async_generator_functions: bool,
 }
 
 impl ES2018Options {
@@ -20,6 +23,11 @@ impl ES2018Options {
         self
     }
 
+    pub fn with_async_generator_functions(&mut self, enable: bool) -> &mut Self {
+        self.async_generator_functions = enable;
+        self
+    }
+
     #[must_use]
     pub fn from_targets_and_bugfixes(targets: Option<&Versions>, bugfixes: bool) -> Self {
         Self {
@@ -29,6 +37,11 @@ impl ES2018Options {
                 bugfixes,
             )
             .then(Default::default),
+            async_generator_functions: can_enable_plugin(
+                "transform-async-generator-functions",
+                targets,
+                bugfixes,
+            ),
         }
     }
 }
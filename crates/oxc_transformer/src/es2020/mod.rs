@@ -4,9 +4,11 @@ use oxc_traverse::{Traverse, TraverseCtx};
 use crate::TransformCtx;
 
 mod nullish_coalescing_operator;
+mod optional_chaining;
 mod options;
 
 pub use nullish_coalescing_operator::NullishCoalescingOperator;
+pub use optional_chaining::OptionalChaining;
 pub use options::ES2020Options;
 
 pub struct ES2020<'a, 'ctx> {
@@ -14,11 +16,16 @@ pub struct ES2020<'a, 'ctx> {
 
     // Plugins
     nullish_coalescing_operator: NullishCoalescingOperator<'a, 'ctx>,
+    optional_chaining: OptionalChaining<'a, 'ctx>,
 }
 
 impl<'a, 'ctx> ES2020<'a, 'ctx> {
     pub fn new(options: ES2020Options, ctx: &'ctx TransformCtx<'a>) -> Self {
-        Self { nullish_coalescing_operator: NullishCoalescingOperator::new(ctx), options }
+        Self {
+            nullish_coalescing_operator: NullishCoalescingOperator::new(ctx),
+            optional_chaining: OptionalChaining::new(ctx),
+            options,
+        }
     }
 }
 
@@ -27,5 +34,9 @@ impl<'a, 'ctx> Traverse<'a> for ES2020<'a, 'ctx> {
         if self.options.nullish_coalescing_operator {
             self.nullish_coalescing_operator.enter_expression(expr, ctx);
         }
+
+        if self.options.optional_chaining {
+            self.optional_chaining.enter_expression(expr, ctx);
+        }
     }
 }
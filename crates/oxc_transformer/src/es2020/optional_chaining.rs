@@ -0,0 +1,448 @@
+//! ES2020: Optional Chaining
+//!
+//! This plugin transforms optional chains (`?.`) to a series of ternary expressions.
+//!
+//! > This plugin is included in `preset-env`, in ES2020
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! const baz = obj?.foo?.bar?.baz;
+//! a.b?.();
+//! delete a?.b;
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _obj, _obj$foo, _obj$foo$bar, _a$b, _a, _a2;
+//! const baz = (_obj = obj) === null || _obj === void 0
+//!   || (_obj$foo = _obj.foo) === null || _obj$foo === void 0
+//!   || (_obj$foo$bar = _obj$foo.bar) === null || _obj$foo$bar === void 0
+//!   ? void 0
+//!   : _obj$foo$bar.baz;
+//! (_a$b = (_a = a).b) === null || _a$b === void 0 ? void 0 : _a$b.call(_a);
+//! (_a2 = a) === null || _a2 === void 0 ? true : delete _a2.b;
+//! ```
+//!
+//! Objects are memoized in temporary variables unless they are static
+//! (`this`, or a binding which is never reassigned).
+//! Optional calls on a member expression (`a.b?.()`) are converted to `Function#call`,
+//! so `this` is preserved.
+//!
+//! Assumptions:
+//! * `noDocumentAll`: Compare with `== null` instead of `=== null || === void 0`.
+//! * `pureGetters`: Optional calls on a simple member expression (`a.b?.()`)
+//!   re-read the property instead of memoizing it, and avoid `Function#call`.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-optional-chaining](https://babeljs.io/docs/babel-plugin-transform-optional-chaining).
+//!
+//! ## Missing features
+//!
+//! * Calling a parenthesized optional chain (`(a?.b)()`) does not preserve `this`.
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-optional-chaining>
+//! * Optional chaining TC39 proposal: <https://github.com/tc39/proposal-optional-chaining>
+
+use std::mem;
+
+use oxc_allocator::{Box, CloneIn};
+use oxc_ast::{ast::*, NONE};
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator};
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse, TraverseCtx};
+
+use crate::TransformCtx;
+
+pub struct OptionalChaining<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> OptionalChaining<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for OptionalChaining<'a, 'ctx> {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            // `a?.b`
+            Expression::ChainExpression(_) => self.transform_chain_expression(expr, ctx),
+            // `delete a?.b`
+            Expression::UnaryExpression(unary)
+                if unary.operator == UnaryOperator::Delete
+                    && matches!(unary.argument, Expression::ChainExpression(_)) =>
+            {
+                self.transform_delete_chain_expression(expr, ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// A link in an optional chain, with its object / callee removed.
+enum ChainLink<'a> {
+    Member(MemberExpression<'a>),
+    Call(Box<'a, CallExpression<'a>>),
+}
+
+/// State for transforming a single optional chain.
+struct ChainState<'a> {
+    /// Scope to create temp vars in
+    scope_id: ScopeId,
+    /// Temp vars created
+    bindings: Vec<BoundIdentifier<'a>>,
+    /// Combined nullish checks `a === null || a === void 0 || ...`
+    test: Option<Expression<'a>>,
+}
+
+impl<'a, 'ctx> OptionalChaining<'a, 'ctx> {
+    /// `a?.b` -> `(_a = a) === null || _a === void 0 ? void 0 : _a.b`
+    fn transform_chain_expression(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::ChainExpression(chain) = ctx.ast.move_expression(expr) else {
+            unreachable!()
+        };
+        let is_parent_formal_parameter = Self::is_parent_formal_parameter(ctx);
+        let mut state = Self::create_state(is_parent_formal_parameter, ctx);
+        let value = self.transform_chain(chain.unbox().expression, &mut state, ctx);
+        let new_expr = Self::create_conditional(&mut state, ctx.ast.void_0(SPAN), value, ctx);
+        *expr = self.finish(new_expr, &state, is_parent_formal_parameter, ctx);
+    }
+
+    /// `delete a?.b` -> `(_a = a) === null || _a === void 0 ? true : delete _a.b`
+    fn transform_delete_chain_expression(
+        &self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::UnaryExpression(mut unary) = ctx.ast.move_expression(expr) else {
+            unreachable!()
+        };
+        let Expression::ChainExpression(chain) = ctx.ast.move_expression(&mut unary.argument)
+        else {
+            unreachable!()
+        };
+        let is_parent_formal_parameter = Self::is_parent_formal_parameter(ctx);
+        let mut state = Self::create_state(is_parent_formal_parameter, ctx);
+        unary.argument = self.transform_chain(chain.unbox().expression, &mut state, ctx);
+        let new_expr = Self::create_conditional(
+            &mut state,
+            ctx.ast.expression_boolean_literal(SPAN, true),
+            Expression::UnaryExpression(unary),
+            ctx,
+        );
+        *expr = self.finish(new_expr, &state, is_parent_formal_parameter, ctx);
+    }
+
+    fn is_parent_formal_parameter(ctx: &TraverseCtx<'a>) -> bool {
+        // ctx.ancestor(0) is AssignmentPattern
+        // ctx.ancestor(1) is BindingPattern
+        // ctx.ancestor(2) is FormalParameter
+        matches!(ctx.ancestor(2), Ancestor::FormalParameterPattern(_))
+    }
+
+    fn create_state(is_parent_formal_parameter: bool, ctx: &mut TraverseCtx<'a>) -> ChainState<'a> {
+        let scope_id = if is_parent_formal_parameter {
+            ctx.create_child_scope_of_current(ScopeFlags::Arrow | ScopeFlags::Function)
+        } else {
+            ctx.current_scope_id()
+        };
+        ChainState { scope_id, bindings: vec![], test: None }
+    }
+
+    /// Declare temp vars created while transforming the chain.
+    fn finish(
+        &self,
+        mut new_expr: Expression<'a>,
+        state: &ChainState<'a>,
+        is_parent_formal_parameter: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if is_parent_formal_parameter {
+            // Replace `function (a, x = a?.b) {}` with `function (a, x = ((_a) => ...)()) {}`
+            // so the temporary variables can be injected in correct scope
+            let params = ctx.ast.vec_from_iter(state.bindings.iter().map(|binding| {
+                let id = binding.create_binding_pattern(ctx);
+                ctx.ast.formal_parameter(SPAN, ctx.ast.vec(), id, None, false, false)
+            }));
+            let params = ctx.ast.formal_parameters(
+                SPAN,
+                FormalParameterKind::ArrowFormalParameters,
+                params,
+                NONE,
+            );
+            let body = ctx.ast.function_body(
+                SPAN,
+                ctx.ast.vec(),
+                ctx.ast.vec1(ctx.ast.statement_expression(SPAN, new_expr)),
+            );
+            let arrow_function =
+                ctx.ast.arrow_function_expression(SPAN, true, false, NONE, params, NONE, body);
+            arrow_function.scope_id.set(Some(state.scope_id));
+            let arrow_function = ctx.ast.expression_from_arrow_function(arrow_function);
+            // `(x) => x;` -> `((x) => x)();`
+            new_expr = ctx.ast.expression_call(SPAN, arrow_function, NONE, ctx.ast.vec(), false);
+        } else {
+            for binding in &state.bindings {
+                self.ctx.var_declarations.insert(binding, None, ctx);
+            }
+        }
+        new_expr
+    }
+
+    /// Transform chain into a plain expression, adding nullish checks to `state.test`.
+    ///
+    /// Returns the expression which is evaluated if none of the checks short-circuit.
+    fn transform_chain(
+        &self,
+        element: ChainElement<'a>,
+        state: &mut ChainState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut expr = match element {
+            ChainElement::CallExpression(call) => Expression::CallExpression(call),
+            element @ match_member_expression!(ChainElement) => {
+                Expression::from(element.into_member_expression())
+            }
+        };
+
+        // Split chain into links, from outermost to innermost.
+        // `a?.b.c()` -> `[Call(_()), Member(_.c), Member(_?.b)]`, with `a` left in `expr`.
+        let mut links = vec![];
+        loop {
+            match expr {
+                Expression::CallExpression(mut call) => {
+                    expr = ctx.ast.move_expression(&mut call.callee);
+                    links.push(ChainLink::Call(call));
+                }
+                match_member_expression!(Expression) => {
+                    let mut member = expr.into_member_expression();
+                    expr = ctx.ast.move_expression(Self::member_object_mut(&mut member));
+                    links.push(ChainLink::Member(member));
+                }
+                // `a?.b!.c`
+                Expression::TSNonNullExpression(non_null) => {
+                    expr = non_null.unbox().expression;
+                }
+                _ => break,
+            }
+        }
+
+        // Rebuild chain from innermost link outwards, inserting checks for optional links
+        for link in links.into_iter().rev() {
+            match link {
+                ChainLink::Member(mut member) => {
+                    if mem::replace(Self::member_optional_mut(&mut member), false) {
+                        expr = self.create_nullish_check(expr, false, state, ctx);
+                    }
+                    *Self::member_object_mut(&mut member) = expr;
+                    expr = Expression::from(member);
+                }
+                ChainLink::Call(mut call) => {
+                    if call.optional {
+                        call.optional = false;
+                        expr = self.transform_optional_callee(expr, &mut call, state, ctx);
+                    }
+                    call.callee = expr;
+                    expr = Expression::CallExpression(call);
+                }
+            }
+        }
+
+        expr
+    }
+
+    /// Add check for callee of an optional call, and return new callee.
+    ///
+    /// * `a?.()` -> `(_a = a) === null || _a === void 0 ? void 0 : _a()`
+    /// * `a.b?.()` -> `(_a$b = (_a = a).b) === null || _a$b === void 0 ? void 0 : _a$b.call(_a)`
+    fn transform_optional_callee(
+        &self,
+        callee: Expression<'a>,
+        call: &mut CallExpression<'a>,
+        state: &mut ChainState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        // `eval?.()` -> `eval === null || eval === void 0 ? void 0 : (0, eval)()`
+        // Otherwise it would be a direct `eval` call.
+        if matches!(&callee, Expression::Identifier(ident) if ident.name == "eval") {
+            let callee = self.create_nullish_check(callee, true, state, ctx);
+            let zero = ctx.ast.expression_numeric_literal(SPAN, 0.0, "0", NumberBase::Decimal);
+            return ctx.ast.expression_sequence(SPAN, ctx.ast.vec_from_iter([zero, callee]));
+        }
+
+        if !callee.is_member_expression() {
+            return self.create_nullish_check(callee, false, state, ctx);
+        }
+
+        // `a.b?.()` -> `a.b === null || a.b === void 0 ? void 0 : a.b()`
+        if self.ctx.assumptions.pure_getters && Self::is_simple_member_expression(&callee) {
+            return self.create_nullish_check(callee, true, state, ctx);
+        }
+
+        // Memoize object, and call callee with it as `this`
+        let mut member = callee.into_member_expression();
+        let object = Self::member_object_mut(&mut member);
+        let this_arg = if matches!(object, Expression::Super(_)) {
+            ctx.ast.expression_this(SPAN)
+        } else {
+            let (new_object, binding) =
+                Self::memoize(ctx.ast.move_expression(object), false, state, ctx);
+            let this_arg = match &binding {
+                Some(binding) => binding.create_read_expression(ctx),
+                None => Self::clone_expression(&new_object, ctx),
+            };
+            *object = new_object;
+            this_arg
+        };
+        call.arguments.insert(0, Argument::from(this_arg));
+
+        let callee = self.create_nullish_check(Expression::from(member), false, state, ctx);
+        let property = ctx.ast.identifier_name(SPAN, "call");
+        Expression::from(ctx.ast.member_expression_static(SPAN, callee, property, false))
+    }
+
+    /// Add nullish check for `expr` to `state.test`, and return expression to read its value.
+    ///
+    /// If `is_static`, `expr` is cloned rather than memoized in a temp var.
+    fn create_nullish_check(
+        &self,
+        expr: Expression<'a>,
+        is_static: bool,
+        state: &mut ChainState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let (check, binding) = Self::memoize(expr, is_static, state, ctx);
+        let read = |check: &Expression<'a>, ctx: &mut TraverseCtx<'a>| match &binding {
+            Some(binding) => binding.create_read_expression(ctx),
+            None => Self::clone_expression(check, ctx),
+        };
+
+        let value = read(&check, ctx);
+        if self.ctx.assumptions.no_document_all {
+            // `a == null`
+            let null = ctx.ast.expression_null_literal(SPAN);
+            let test = ctx.ast.expression_binary(SPAN, check, BinaryOperator::Equality, null);
+            Self::add_test(test, state, ctx);
+        } else {
+            // `a === null || a === void 0`
+            let reference = read(&check, ctx);
+            let op = BinaryOperator::StrictEquality;
+            let null = ctx.ast.expression_null_literal(SPAN);
+            let left = ctx.ast.expression_binary(SPAN, check, op, null);
+            let right = ctx.ast.expression_binary(SPAN, reference, op, ctx.ast.void_0(SPAN));
+            Self::add_test(left, state, ctx);
+            Self::add_test(right, state, ctx);
+        }
+
+        value
+    }
+
+    /// Append `test` to `state.test` with `||`.
+    fn add_test(test: Expression<'a>, state: &mut ChainState<'a>, ctx: &TraverseCtx<'a>) {
+        state.test = Some(match state.test.take() {
+            Some(prev) => ctx.ast.expression_logical(SPAN, prev, LogicalOperator::Or, test),
+            None => test,
+        });
+    }
+
+    /// Memoize `expr` in a temp var, unless it's static.
+    ///
+    /// Returns `(_a = a, Some(_a))` if a temp var was created, or `(a, None)` if not.
+    fn memoize(
+        expr: Expression<'a>,
+        is_static: bool,
+        state: &mut ChainState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Expression<'a>, Option<BoundIdentifier<'a>>) {
+        if is_static || ctx.is_static(&expr) || Self::is_temp_var(&expr, state, ctx) {
+            return (expr, None);
+        }
+
+        let binding = ctx.generate_uid_based_on_node(
+            &expr,
+            state.scope_id,
+            SymbolFlags::FunctionScopedVariable,
+        );
+        let assignment = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            binding.create_read_write_target(ctx),
+            expr,
+        );
+        state.bindings.push(binding.clone());
+        (assignment, Some(binding))
+    }
+
+    /// Check if `expr` is a reference to a temp var created earlier in this chain.
+    /// Temp vars are assigned only once before being read, so need no further memoization.
+    fn is_temp_var(expr: &Expression<'a>, state: &ChainState<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        let Expression::Identifier(ident) = expr else { return false };
+        let symbol_id = ctx.symbols().get_reference(ident.reference_id.get().unwrap()).symbol_id();
+        symbol_id.is_some_and(|symbol_id| {
+            state.bindings.iter().any(|binding| binding.symbol_id == symbol_id)
+        })
+    }
+
+    /// `test ? short_circuit : value`
+    fn create_conditional(
+        state: &mut ChainState<'a>,
+        short_circuit: Expression<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        match state.test.take() {
+            Some(test) => ctx.ast.expression_conditional(SPAN, test, short_circuit, value),
+            None => value,
+        }
+    }
+
+    /// Check if `expr` is `a.b`, `this.a.b`, `super.a` etc.
+    fn is_simple_member_expression(expr: &Expression<'a>) -> bool {
+        match expr {
+            Expression::Identifier(_) | Expression::ThisExpression(_) | Expression::Super(_) => {
+                true
+            }
+            Expression::StaticMemberExpression(member) => {
+                Self::is_simple_member_expression(&member.object)
+            }
+            _ => false,
+        }
+    }
+
+    /// Clone a static expression (identifier, `this`, or simple member expression).
+    fn clone_expression(expr: &Expression<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        match expr {
+            Expression::Identifier(ident) => ctx.ast.expression_from_identifier_reference(
+                ctx.clone_identifier_reference(ident, ReferenceFlags::Read),
+            ),
+            Expression::StaticMemberExpression(member) => {
+                let object = Self::clone_expression(&member.object, ctx);
+                let property = member.property.clone_in(ctx.ast.allocator);
+                Expression::from(ctx.ast.member_expression_static(SPAN, object, property, false))
+            }
+            _ => expr.clone_in(ctx.ast.allocator),
+        }
+    }
+
+    fn member_object_mut<'b>(member: &'b mut MemberExpression<'a>) -> &'b mut Expression<'a> {
+        match member {
+            MemberExpression::ComputedMemberExpression(member) => &mut member.object,
+            MemberExpression::StaticMemberExpression(member) => &mut member.object,
+            MemberExpression::PrivateFieldExpression(member) => &mut member.object,
+        }
+    }
+
+    fn member_optional_mut<'b>(member: &'b mut MemberExpression<'a>) -> &'b mut bool {
+        match member {
+            MemberExpression::ComputedMemberExpression(member) => &mut member.optional,
+            MemberExpression::StaticMemberExpression(member) => &mut member.optional,
+            MemberExpression::PrivateFieldExpression(member) => &mut member.optional,
+        }
+    }
+}
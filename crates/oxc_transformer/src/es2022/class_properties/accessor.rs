@@ -0,0 +1,273 @@
+//! ES2022: Class Properties
+//! Transform of `accessor` properties.
+//!
+//! `accessor` properties are converted to a private field, and a getter and setter for it.
+//! The private field is then transformed along with the rest of the class.
+//!
+//! ```js
+//! class C {
+//!   accessor x = 1;
+//! }
+//! ```
+//! ->
+//! ```js
+//! class C {
+//!   #A = 1;
+//!   get x() { return this.#A; }
+//!   set x(v) { this.#A = v; }
+//! }
+//! ```
+//!
+//! Based on handling of undecorated `accessor` properties in
+//! [@babel/plugin-proposal-decorators](https://babel.dev/docs/babel-plugin-proposal-decorators).
+
+use oxc_allocator::{Box, CloneIn};
+use oxc_ast::{ast::*, Visit, NONE};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::{
+    scope::{ScopeFlags, ScopeId},
+    symbol::SymbolFlags,
+};
+use oxc_traverse::TraverseCtx;
+use rustc_hash::FxHashSet;
+
+use super::ClassProperties;
+
+impl<'a, 'ctx> ClassProperties<'a, 'ctx> {
+    /// Convert `accessor` properties in class body to private fields with getters and setters.
+    pub(super) fn transform_accessor_properties(
+        &self,
+        class: &mut Class<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let has_accessor = class.body.body.iter().any(|element| {
+            matches!(element, ClassElement::AccessorProperty(prop) if prop.r#type == AccessorPropertyType::AccessorProperty)
+        });
+        if !has_accessor {
+            return;
+        }
+
+        let class_scope_id = class.scope_id.get().unwrap();
+        let mut private_names = PrivateNamesCollector::collect(class);
+
+        let elements = ctx.ast.move_vec(&mut class.body.body);
+        let mut new_elements = ctx.ast.vec_with_capacity(elements.len() + 2);
+        for element in elements {
+            let ClassElement::AccessorProperty(prop) = element else {
+                new_elements.push(element);
+                continue;
+            };
+            if prop.r#type != AccessorPropertyType::AccessorProperty {
+                new_elements.push(ClassElement::AccessorProperty(prop));
+                continue;
+            }
+
+            // Key is used for both getter and setter, so it must not have side effects
+            if prop.computed
+                && !matches!(
+                    prop.key,
+                    PropertyKey::StringLiteral(_) | PropertyKey::NumericLiteral(_)
+                )
+            {
+                self.ctx.error(
+                    OxcDiagnostic::error(
+                        "`accessor` properties with computed keys are not supported yet.",
+                    )
+                    .with_label(prop.span),
+                );
+                new_elements.push(ClassElement::AccessorProperty(prop));
+                continue;
+            }
+
+            let AccessorProperty { span, key, value, computed, r#static, .. } = prop.unbox();
+            let name = private_names.create_unique(ctx);
+
+            // `#A = 1;`
+            new_elements.push(ctx.ast.class_element_property_definition(
+                PropertyDefinitionType::PropertyDefinition,
+                span,
+                ctx.ast.vec(),
+                ctx.ast.property_key_private_identifier(SPAN, name.clone()),
+                value,
+                false,
+                r#static,
+                false,
+                false,
+                false,
+                false,
+                false,
+                NONE,
+                None,
+            ));
+
+            // `get x() { return this.#A; }`
+            let getter_key = key.clone_in(ctx.ast.allocator);
+            let getter = Self::create_accessor_getter(&name, class_scope_id, ctx);
+            new_elements.push(ctx.ast.class_element_method_definition(
+                MethodDefinitionType::MethodDefinition,
+                SPAN,
+                ctx.ast.vec(),
+                getter_key,
+                getter,
+                MethodDefinitionKind::Get,
+                computed,
+                r#static,
+                false,
+                false,
+                None,
+            ));
+
+            // `set x(v) { this.#A = v; }`
+            let setter = Self::create_accessor_setter(&name, class_scope_id, ctx);
+            new_elements.push(ctx.ast.class_element_method_definition(
+                MethodDefinitionType::MethodDefinition,
+                SPAN,
+                ctx.ast.vec(),
+                key,
+                setter,
+                MethodDefinitionKind::Set,
+                computed,
+                r#static,
+                false,
+                false,
+                None,
+            ));
+        }
+        class.body.body = new_elements;
+    }
+
+    /// `function() { return this.#A; }`
+    fn create_accessor_getter(
+        name: &Atom<'a>,
+        class_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Box<'a, Function<'a>> {
+        let scope_id = ctx.create_child_scope(
+            class_scope_id,
+            ScopeFlags::Function | ScopeFlags::GetAccessor | ScopeFlags::StrictMode,
+        );
+        let field = Self::create_this_private_field(name, ctx);
+        let stmt = ctx.ast.statement_return(SPAN, Some(field));
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::UniqueFormalParameters,
+            ctx.ast.vec(),
+            NONE,
+        );
+        Self::create_accessor_function(params, stmt, scope_id, ctx)
+    }
+
+    /// `function(v) { this.#A = v; }`
+    fn create_accessor_setter(
+        name: &Atom<'a>,
+        class_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Box<'a, Function<'a>> {
+        let scope_id = ctx.create_child_scope(
+            class_scope_id,
+            ScopeFlags::Function | ScopeFlags::SetAccessor | ScopeFlags::StrictMode,
+        );
+        let binding =
+            ctx.generate_binding(Atom::from("v"), scope_id, SymbolFlags::FunctionScopedVariable);
+        let param = ctx.ast.formal_parameter(
+            SPAN,
+            ctx.ast.vec(),
+            binding.create_binding_pattern(ctx),
+            None,
+            false,
+            false,
+        );
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::UniqueFormalParameters,
+            ctx.ast.vec1(param),
+            NONE,
+        );
+
+        let Expression::PrivateFieldExpression(field) = Self::create_this_private_field(name, ctx)
+        else {
+            unreachable!()
+        };
+        let assignment = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            AssignmentTarget::PrivateFieldExpression(field),
+            binding.create_read_expression(ctx),
+        );
+        let stmt = ctx.ast.statement_expression(SPAN, assignment);
+        Self::create_accessor_function(params, stmt, scope_id, ctx)
+    }
+
+    fn create_accessor_function(
+        params: Box<'a, FormalParameters<'a>>,
+        stmt: Statement<'a>,
+        scope_id: ScopeId,
+        ctx: &TraverseCtx<'a>,
+    ) -> Box<'a, Function<'a>> {
+        let body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), ctx.ast.vec1(stmt));
+        ctx.ast.alloc_function_with_scope_id(
+            FunctionType::FunctionExpression,
+            SPAN,
+            None,
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(body),
+            scope_id,
+        )
+    }
+
+    /// `this.#A`
+    fn create_this_private_field(name: &Atom<'a>, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+        let field = ctx.ast.private_identifier(SPAN, name.clone());
+        Expression::from(ctx.ast.member_expression_private_field_expression(
+            SPAN,
+            ctx.ast.expression_this(SPAN),
+            field,
+            false,
+        ))
+    }
+}
+
+/// Private names used within a class, used to create names for `accessor` properties' storage
+/// which do not clash with them.
+///
+/// Private names used in the class but declared in an enclosing class are included too,
+/// so they are not shadowed.
+struct PrivateNamesCollector<'a> {
+    names: FxHashSet<Atom<'a>>,
+    next: u32,
+}
+
+impl<'a> PrivateNamesCollector<'a> {
+    fn collect(class: &Class<'a>) -> Self {
+        let mut collector = Self { names: FxHashSet::default(), next: 0 };
+        collector.visit_class_body(&class.body);
+        collector
+    }
+
+    /// Create a private name which is not used in the class: `A`, `B`, ..., `Z`, `A1`, `B1`, ...
+    fn create_unique(&mut self, ctx: &TraverseCtx<'a>) -> Atom<'a> {
+        loop {
+            let letter = char::from(b'A' + u8::try_from(self.next % 26).unwrap());
+            let suffix = self.next / 26;
+            self.next += 1;
+            let name = if suffix == 0 { letter.to_string() } else { format!("{letter}{suffix}") };
+            let name = ctx.ast.atom(&name);
+            if self.names.insert(name.clone()) {
+                return name;
+            }
+        }
+    }
+}
+
+impl<'a> Visit<'a> for PrivateNamesCollector<'a> {
+    fn visit_private_identifier(&mut self, ident: &PrivateIdentifier<'a>) {
+        self.names.insert(ident.name.clone());
+    }
+}
@@ -0,0 +1,651 @@
+//! ES2022: Class Properties
+//! Transform of class itself.
+
+use oxc_allocator::GetAddress;
+use oxc_ast::{ast::*, NONE};
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::symbol::SymbolFlags;
+use oxc_traverse::{BoundIdentifier, TraverseCtx};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::common::helper_loader::Helper;
+
+use super::{
+    super_prop::{transform_super_in_private_method, transform_super_in_static_prop},
+    utils::{
+        create_global_ident, create_new_global, create_object_define_property,
+        create_var_statement, find_super_in_function, reparent_function_scope, reparent_scopes,
+        replace_class_name_in_function_with, replace_class_name_with, replace_this_with,
+    },
+    ClassDetails, ClassOutput, ClassProperties, PrivateMode, PrivateProp, PrivatePropKind,
+};
+
+/// A value which needs to be evaluated before or after the class.
+pub(super) struct Init<'a> {
+    /// Binding which value is assigned to. `None` if the value is only evaluated for its side effects.
+    pub binding: Option<BoundIdentifier<'a>>,
+    pub value: Expression<'a>,
+}
+
+impl<'a, 'ctx> ClassProperties<'a, 'ctx> {
+    /// Analyse class and create bindings for private members, before class body is traversed.
+    ///
+    /// Bindings are created at this point so that references to private members within class body
+    /// can be transformed during traversal of the class body.
+    pub(super) fn prepare_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.transform_accessor_properties(class, ctx);
+
+        let mut needs_transform = false;
+        let mut has_static = false;
+        let mut has_private_method = false;
+        let mut has_super_in_private_method = false;
+        for element in &class.body.body {
+            match element {
+                ClassElement::PropertyDefinition(prop) => {
+                    needs_transform = true;
+                    has_static |= prop.r#static;
+                }
+                ClassElement::MethodDefinition(method) if method.key.is_private_identifier() => {
+                    needs_transform = true;
+                    has_static |= method.r#static;
+                    has_private_method = true;
+                    has_super_in_private_method |= find_super_in_function(&method.value);
+                }
+                ClassElement::StaticBlock(_) => has_static = true,
+                _ => {}
+            }
+        }
+
+        if !needs_transform {
+            self.classes_stack.push(None);
+            return;
+        }
+
+        let is_declaration = class.is_declaration();
+
+        // Get binding for class.
+        // Class declarations use their own name. `export default class {}` is given a name.
+        // Class expressions are assigned to a temp var, but only if it's required:
+        // when class has static members, or private methods may refer to the class by its name
+        // or contain `super`.
+        let class_binding = if is_declaration {
+            if let Some(ident) = &class.id {
+                Some(BoundIdentifier::from_binding_ident(ident))
+            } else {
+                // Only `export default class {}` can be a class declaration without a name
+                let flags = SymbolFlags::Class | SymbolFlags::Export;
+                let binding = ctx.generate_uid_in_current_scope("Class", flags);
+                class.id = Some(binding.create_binding_identifier(ctx));
+                Some(binding)
+            }
+        } else if has_static
+            || has_super_in_private_method
+            || (has_private_method && Self::is_class_name_referenced(class, ctx))
+        {
+            let name = class.id.as_ref().map_or("Class", |id| id.name.as_str());
+            Some(ctx.generate_uid_in_current_scope(name, SymbolFlags::FunctionScopedVariable))
+        } else {
+            None
+        };
+
+        // Create bindings for private members
+        let mut before = vec![];
+        let mut private_props = FxHashMap::default();
+        let mut needs_brand = false;
+        for element in &class.body.body {
+            let (ident, is_static, kind) = match element {
+                ClassElement::PropertyDefinition(prop) => {
+                    let PropertyKey::PrivateIdentifier(ident) = &prop.key else { continue };
+                    (ident, prop.r#static, None)
+                }
+                ClassElement::MethodDefinition(method) => {
+                    let PropertyKey::PrivateIdentifier(ident) = &method.key else { continue };
+                    (ident, method.r#static, Some(method.kind))
+                }
+                _ => continue,
+            };
+            let name = ident.name.clone();
+
+            let Some(kind) = kind else {
+                // Private field
+                let binding = self.create_private_key(&name, is_static, &mut before, ctx);
+                private_props.insert(
+                    name,
+                    PrivateProp { binding: Some(binding), is_static, kind: PrivatePropKind::Field },
+                );
+                continue;
+            };
+
+            needs_brand |= !is_static;
+
+            // Private method or accessor
+            let prop = private_props.entry(name.clone()).or_insert_with(|| {
+                let binding = (self.private_mode != PrivateMode::Spec)
+                    .then(|| self.create_private_key(&name, is_static, &mut before, ctx));
+                let kind = if kind == MethodDefinitionKind::Method {
+                    PrivatePropKind::Method(Self::create_function_binding(&name, ctx))
+                } else {
+                    PrivatePropKind::Accessor { getter: None, setter: None }
+                };
+                PrivateProp { binding, is_static, kind }
+            });
+            if let PrivatePropKind::Accessor { getter, setter } = &mut prop.kind {
+                if kind == MethodDefinitionKind::Get {
+                    let fn_name = format!("get_{name}");
+                    *getter = Some(Self::create_function_binding(&fn_name, ctx));
+                } else {
+                    let fn_name = format!("set_{name}");
+                    *setter = Some(Self::create_function_binding(&fn_name, ctx));
+                }
+            }
+        }
+
+        // Create `WeakSet` which brands instances of class, if class has instance private methods
+        let brand = if needs_brand && self.private_mode == PrivateMode::Spec {
+            let class_name = class.id.as_ref().map_or("Class", |id| id.name.as_str());
+            let binding = ctx.generate_uid_in_current_scope(
+                &format!("{class_name}_brand"),
+                SymbolFlags::FunctionScopedVariable,
+            );
+            before.push(Init {
+                binding: Some(binding.clone()),
+                value: create_new_global("WeakSet", ctx),
+            });
+            Some(binding)
+        } else {
+            None
+        };
+
+        self.classes_stack.push(Some(ClassDetails { class_binding, brand, private_props, before }));
+    }
+
+    /// Check if name of a class expression is referenced anywhere.
+    fn is_class_name_referenced(class: &Class<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        class.id.as_ref().and_then(|id| id.symbol_id.get()).is_some_and(|symbol_id| {
+            !ctx.symbols().get_resolved_reference_ids(symbol_id).is_empty()
+        })
+    }
+
+    /// Create binding for key of private property, and push its initializer to `before`.
+    ///
+    /// * Spec mode: `var _x = new WeakMap();` for instance fields.
+    ///   Static fields are initialized after the class (`var _x = { _: value };`).
+    /// * Properties mode: `var _x = babelHelpers.classPrivateFieldLooseKey("x");`
+    /// * Symbols mode: `var _x = Symbol("x");`
+    fn create_private_key(
+        &self,
+        name: &str,
+        is_static: bool,
+        before: &mut Vec<Init<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        let binding = ctx.generate_uid_in_current_scope(name, SymbolFlags::FunctionScopedVariable);
+        let value = match self.private_mode {
+            PrivateMode::Spec if is_static => return binding,
+            PrivateMode::Spec => create_new_global("WeakMap", ctx),
+            PrivateMode::Properties => {
+                let arguments = ctx.ast.vec1(Argument::from(
+                    ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(name)),
+                ));
+                self.ctx.helper_call_expr(Helper::ClassPrivateFieldLooseKey, arguments, ctx)
+            }
+            PrivateMode::Symbols => {
+                let callee = create_global_ident("Symbol", ctx);
+                let arguments = ctx.ast.vec1(Argument::from(
+                    ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(name)),
+                ));
+                ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+            }
+        };
+        before.push(Init { binding: Some(binding.clone()), value });
+        binding
+    }
+
+    /// Create binding for function which a private method or accessor is converted to.
+    fn create_function_binding(name: &str, ctx: &mut TraverseCtx<'a>) -> BoundIdentifier<'a> {
+        ctx.generate_uid_in_current_scope(name, SymbolFlags::FunctionScopedVariable)
+    }
+
+    /// Transform class, after class body has been traversed.
+    ///
+    /// * Remove properties and private methods from class body.
+    /// * Move instance property initializers into constructor.
+    /// * Record code to be inserted before and after the class in `self.pending_output`.
+    pub(super) fn transform_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Some(Some(details)) = self.classes_stack.pop() else { return };
+        let ClassDetails { class_binding, brand, private_props, mut before } = details;
+
+        let outer_scope_id = ctx.current_scope_id();
+
+        // Name of a class expression is not in scope once code is moved out of class body,
+        // so references to it are replaced with the class's temp var
+        let class_name_symbol_id = if class.is_declaration() {
+            None
+        } else {
+            class.id.as_ref().and_then(|id| id.symbol_id.get())
+        };
+
+        let mut instance_inits = vec![];
+        let mut after = vec![];
+        let mut functions = vec![];
+        let mut defined_accessors = FxHashSet::default();
+
+        // Instances must be branded before any other initializers run
+        if let Some(brand) = &brand {
+            let arguments = ctx.ast.vec_from_iter([
+                Argument::from(ctx.ast.expression_this(SPAN)),
+                Argument::from(brand.create_read_expression(ctx)),
+            ]);
+            instance_inits.push(self.ctx.helper_call_expr(
+                Helper::ClassPrivateMethodInitSpec,
+                arguments,
+                ctx,
+            ));
+        }
+
+        let elements = ctx.ast.move_vec(&mut class.body.body);
+        let mut new_elements = ctx.ast.vec_with_capacity(elements.len());
+        for element in elements {
+            match element {
+                ClassElement::PropertyDefinition(mut prop) => {
+                    let mut value = prop.value.take().unwrap_or_else(|| ctx.ast.void_0(SPAN));
+                    if prop.r#static {
+                        reparent_scopes(&mut value, outer_scope_id, ctx);
+                        if let Some(class_binding) = &class_binding {
+                            // `super` is converted first, as it uses `this`, which is then replaced
+                            transform_super_in_static_prop(
+                                &mut value,
+                                class_binding,
+                                self.ctx,
+                                ctx,
+                            );
+                            replace_this_with(&mut value, class_binding, ctx);
+                            if let Some(symbol_id) = class_name_symbol_id {
+                                replace_class_name_with(&mut value, symbol_id, class_binding, ctx);
+                            }
+                        }
+                    }
+
+                    if let PropertyKey::PrivateIdentifier(ident) = &prop.key {
+                        let Some(private_prop) = private_props.get(&ident.name) else {
+                            // Private field created by transform of static block.
+                            // Nothing can refer to it, so just evaluate the initializer.
+                            after.push(Init { binding: None, value });
+                            continue;
+                        };
+                        let binding = private_prop.binding.as_ref().unwrap();
+                        if prop.r#static {
+                            let class_binding = class_binding.as_ref().unwrap();
+                            after.push(self.create_private_static_field_init(
+                                binding,
+                                class_binding,
+                                value,
+                                ctx,
+                            ));
+                        } else {
+                            instance_inits
+                                .push(self.create_private_instance_field_init(binding, value, ctx));
+                        }
+                    } else {
+                        let key = self.create_property_key(&mut prop.key, &mut before, ctx);
+                        if prop.r#static {
+                            let class_binding = class_binding.as_ref().unwrap();
+                            let object = class_binding.create_read_expression(ctx);
+                            let init = self.create_public_field_init(object, key, value, ctx);
+                            after.push(Init { binding: None, value: init });
+                        } else {
+                            let object = ctx.ast.expression_this(SPAN);
+                            instance_inits
+                                .push(self.create_public_field_init(object, key, value, ctx));
+                        }
+                    }
+                }
+                ClassElement::MethodDefinition(method) if method.key.is_private_identifier() => {
+                    let PropertyKey::PrivateIdentifier(ident) = &method.key else { unreachable!() };
+                    let name = ident.name.clone();
+                    let private_prop = &private_props[&name];
+
+                    let function_binding = match (&private_prop.kind, method.kind) {
+                        (PrivatePropKind::Method(binding), _) => binding.clone(),
+                        (PrivatePropKind::Accessor { getter, .. }, MethodDefinitionKind::Get) => {
+                            getter.clone().unwrap()
+                        }
+                        (PrivatePropKind::Accessor { setter, .. }, _) => setter.clone().unwrap(),
+                        (PrivatePropKind::Field, _) => unreachable!(),
+                    };
+
+                    // In properties / symbols mode, methods are defined as properties
+                    // on the instance or class
+                    if let Some(key_binding) = &private_prop.binding {
+                        let object = if method.r#static {
+                            class_binding.as_ref().unwrap().create_read_expression(ctx)
+                        } else {
+                            ctx.ast.expression_this(SPAN)
+                        };
+                        let key = key_binding.create_read_expression(ctx);
+                        let init = match &private_prop.kind {
+                            PrivatePropKind::Method(binding) => {
+                                let value = binding.create_read_expression(ctx);
+                                Some(create_object_define_property(
+                                    object,
+                                    key,
+                                    [("value", value)],
+                                    ctx,
+                                ))
+                            }
+                            PrivatePropKind::Accessor { getter, setter } => {
+                                defined_accessors.insert(name.clone()).then(|| {
+                                    let props = [("get", getter), ("set", setter)]
+                                        .into_iter()
+                                        .filter_map(|(prop_name, binding)| {
+                                            binding.as_ref().map(|binding| {
+                                                (prop_name, binding.create_read_expression(ctx))
+                                            })
+                                        })
+                                        .collect::<Vec<_>>();
+                                    create_object_define_property(object, key, props, ctx)
+                                })
+                            }
+                            PrivatePropKind::Field => unreachable!(),
+                        };
+                        if let Some(init) = init {
+                            if method.r#static {
+                                after.push(Init { binding: None, value: init });
+                            } else {
+                                instance_inits.push(init);
+                            }
+                        }
+                    }
+
+                    // Convert method to a function outside the class
+                    let is_static = method.r#static;
+                    let mut function = method.unbox().value;
+                    if let Some(class_binding) = &class_binding {
+                        transform_super_in_private_method(
+                            &mut function,
+                            class_binding,
+                            is_static,
+                            self.ctx,
+                            ctx,
+                        );
+                    }
+                    reparent_function_scope(&mut function, outer_scope_id, ctx);
+                    if let (Some(symbol_id), Some(class_binding)) =
+                        (class_name_symbol_id, &class_binding)
+                    {
+                        replace_class_name_in_function_with(
+                            &mut function,
+                            symbol_id,
+                            class_binding,
+                            ctx,
+                        );
+                    }
+                    function.r#type = FunctionType::FunctionDeclaration;
+                    functions.push((function_binding, function));
+                }
+                element => new_elements.push(element),
+            }
+        }
+        class.body.body = new_elements;
+
+        if !instance_inits.is_empty() {
+            Self::insert_instance_inits(class, instance_inits, ctx);
+        }
+
+        self.pending_output = Some(ClassOutput { class_binding, before, functions, after });
+    }
+
+    /// Get key for a public property.
+    ///
+    /// Computed keys which are not literals are evaluated before the class,
+    /// and stored in a temp var: `var _ref = babelHelpers.toPropertyKey(key);`.
+    fn create_property_key(
+        &self,
+        key: &mut PropertyKey<'a>,
+        before: &mut Vec<Init<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> PropertyKeyOrExpression<'a> {
+        match key {
+            PropertyKey::StaticIdentifier(ident) => {
+                PropertyKeyOrExpression::Name(ident.name.clone())
+            }
+            PropertyKey::PrivateIdentifier(_) => unreachable!(),
+            key => {
+                let expr = ctx.ast.move_expression(key.to_expression_mut());
+                if expr.is_literal() {
+                    return PropertyKeyOrExpression::Expression(expr);
+                }
+                let binding = ctx.generate_uid_in_current_scope_based_on_node(
+                    &expr,
+                    SymbolFlags::FunctionScopedVariable,
+                );
+                let value = self.ctx.helper_call_expr(
+                    Helper::ToPropertyKey,
+                    ctx.ast.vec1(Argument::from(expr)),
+                    ctx,
+                );
+                let key = binding.create_read_expression(ctx);
+                before.push(Init { binding: Some(binding), value });
+                PropertyKeyOrExpression::Expression(key)
+            }
+        }
+    }
+
+    /// Create initializer for a public property.
+    ///
+    /// * `babelHelpers.defineProperty(object, "key", value)`
+    /// * `object.key = value` (`setPublicClassFields` assumption)
+    fn create_public_field_init(
+        &self,
+        object: Expression<'a>,
+        key: PropertyKeyOrExpression<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if self.set_public_class_fields {
+            let target = match key {
+                PropertyKeyOrExpression::Name(name) => {
+                    let property = ctx.ast.identifier_name(SPAN, name);
+                    AssignmentTarget::from(
+                        ctx.ast.member_expression_static(SPAN, object, property, false),
+                    )
+                }
+                PropertyKeyOrExpression::Expression(key) => AssignmentTarget::from(
+                    ctx.ast.member_expression_computed(SPAN, object, key, false),
+                ),
+            };
+            ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value)
+        } else {
+            let key = match key {
+                PropertyKeyOrExpression::Name(name) => {
+                    ctx.ast.expression_string_literal(SPAN, name)
+                }
+                PropertyKeyOrExpression::Expression(key) => key,
+            };
+            let arguments = ctx.ast.vec_from_iter([
+                Argument::from(object),
+                Argument::from(key),
+                Argument::from(value),
+            ]);
+            self.ctx.helper_call_expr(Helper::DefineProperty, arguments, ctx)
+        }
+    }
+
+    /// Create initializer for a private instance field.
+    ///
+    /// * Spec mode: `babelHelpers.classPrivateFieldInitSpec(this, _x, value)`
+    /// * Properties / symbols mode: `Object.defineProperty(this, _x, { writable: true, value })`
+    fn create_private_instance_field_init(
+        &self,
+        binding: &BoundIdentifier<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let this = ctx.ast.expression_this(SPAN);
+        let key = binding.create_read_expression(ctx);
+        if self.private_mode == PrivateMode::Spec {
+            let arguments = ctx.ast.vec_from_iter([
+                Argument::from(this),
+                Argument::from(key),
+                Argument::from(value),
+            ]);
+            self.ctx.helper_call_expr(Helper::ClassPrivateFieldInitSpec, arguments, ctx)
+        } else {
+            let writable = ctx.ast.expression_boolean_literal(SPAN, true);
+            create_object_define_property(
+                this,
+                key,
+                [("writable", writable), ("value", value)],
+                ctx,
+            )
+        }
+    }
+
+    /// Create initializer for a private static field.
+    ///
+    /// * Spec mode: `var _x = { _: value };`
+    /// * Properties / symbols mode: `Object.defineProperty(C, _x, { writable: true, value });`
+    fn create_private_static_field_init(
+        &self,
+        binding: &BoundIdentifier<'a>,
+        class_binding: &BoundIdentifier<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Init<'a> {
+        if self.private_mode == PrivateMode::Spec {
+            let property = ctx.ast.object_property_kind_object_property(
+                SPAN,
+                PropertyKind::Init,
+                ctx.ast.property_key_identifier_name(SPAN, "_"),
+                value,
+                None,
+                false,
+                false,
+                false,
+            );
+            let value = ctx.ast.expression_object(SPAN, ctx.ast.vec1(property), None);
+            Init { binding: Some(binding.clone()), value }
+        } else {
+            let object = class_binding.create_read_expression(ctx);
+            let key = binding.create_read_expression(ctx);
+            let writable = ctx.ast.expression_boolean_literal(SPAN, true);
+            let value = create_object_define_property(
+                object,
+                key,
+                [("writable", writable), ("value", value)],
+                ctx,
+            );
+            Init { binding: None, value }
+        }
+    }
+
+    /// Insert code before and after a class declaration.
+    ///
+    /// ```js
+    /// var _x = new WeakMap();
+    /// class C {}
+    /// function _m() {}
+    /// babelHelpers.defineProperty(C, "y", 1);
+    /// ```
+    pub(super) fn insert_output_around_class_declaration(
+        &self,
+        stmt: &Statement<'a>,
+        output: ClassOutput<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let ClassOutput { before, functions, after, .. } = output;
+        let address = stmt.address();
+
+        if !before.is_empty() {
+            let stmts = before.into_iter().map(|init| Self::create_init_statement(init, ctx));
+            self.ctx.statement_injector.insert_many_before(address, stmts.collect());
+        }
+
+        if !functions.is_empty() || !after.is_empty() {
+            let function_stmts = functions.into_iter().map(|(binding, mut function)| {
+                function.id = Some(binding.create_binding_identifier(ctx));
+                Statement::FunctionDeclaration(function)
+            });
+            let mut stmts = function_stmts.collect::<Vec<_>>();
+            stmts.extend(after.into_iter().map(|init| Self::create_init_statement(init, ctx)));
+            self.ctx.statement_injector.insert_many_after(address, stmts);
+        }
+    }
+
+    /// Insert code before and after a class expression.
+    ///
+    /// `let C = class { static y = 1; }` ->
+    /// `let C = (_C = class {}, babelHelpers.defineProperty(_C, "y", 1), _C)`
+    pub(super) fn insert_output_around_class_expression(
+        &self,
+        expr: &mut Expression<'a>,
+        output: ClassOutput<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let ClassOutput { class_binding, before, functions, after } = output;
+        if before.is_empty() && functions.is_empty() && after.is_empty() {
+            return;
+        }
+
+        // Functions are hoisted to top of enclosing block, as function declarations would be.
+        for (binding, mut function) in functions {
+            function.r#type = FunctionType::FunctionExpression;
+            let function = ctx.ast.expression_from_function(function);
+            self.ctx.var_declarations.insert(&binding, Some(function), ctx);
+        }
+
+        let mut exprs = ctx.ast.vec();
+        exprs.extend(before.into_iter().map(|init| self.create_init_expression(init, ctx)));
+
+        let class_expr = ctx.ast.move_expression(expr);
+        if let Some(class_binding) = &class_binding {
+            self.ctx.var_declarations.insert(class_binding, None, ctx);
+            exprs.push(ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                class_binding.create_read_write_target(ctx),
+                class_expr,
+            ));
+            exprs.extend(after.into_iter().map(|init| self.create_init_expression(init, ctx)));
+            exprs.push(class_binding.create_read_expression(ctx));
+        } else {
+            exprs.push(class_expr);
+        }
+
+        *expr = ctx.ast.expression_sequence(SPAN, exprs);
+    }
+
+    /// Convert `Init` to a statement.
+    ///
+    /// `var binding = value;` or `value;`
+    fn create_init_statement(init: Init<'a>, ctx: &TraverseCtx<'a>) -> Statement<'a> {
+        match init.binding {
+            Some(binding) => create_var_statement(&binding, init.value, ctx),
+            None => ctx.ast.statement_expression(SPAN, init.value),
+        }
+    }
+
+    /// Convert `Init` to an expression.
+    ///
+    /// `binding = value` (with `var binding` inserted in enclosing block), or `value`.
+    fn create_init_expression(&self, init: Init<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        match init.binding {
+            Some(binding) => {
+                self.ctx.var_declarations.insert(&binding, None, ctx);
+                ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    binding.create_read_write_target(ctx),
+                    init.value,
+                )
+            }
+            None => init.value,
+        }
+    }
+}
+
+/// Key of a public property, either an identifier name or an expression.
+enum PropertyKeyOrExpression<'a> {
+    Name(Atom<'a>),
+    Expression(Expression<'a>),
+}
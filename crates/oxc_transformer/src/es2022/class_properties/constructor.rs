@@ -0,0 +1,263 @@
+//! ES2022: Class Properties
+//! Insertion of instance property initializers into class constructor.
+//!
+//! * Class has no constructor: create one.
+//!   `class C { x = 1; }` -> `class C { constructor() { this.x = 1; } }`
+//!   `class C extends S { x = 1; }` ->
+//!   `class C extends S { constructor(..._args) { super(..._args); this.x = 1; } }`
+//! * Class has constructor, and it is not a derived class: insert initializers at top of constructor.
+//! * Derived class with a single `super()` call as a top-level statement in constructor:
+//!   insert initializers after the `super()` call.
+//! * Derived class where `super()` is called elsewhere (e.g. in an `if` branch, or more than once):
+//!   Wrap `super()` in an arrow function which runs initializers, and replace `super()` calls with it.
+//!   ```js
+//!   class C extends S {
+//!     constructor() {
+//!       var _super = (..._args) => (super(..._args), this.x = 1, this);
+//!       if (cond) { _super(1); } else { _super(2); }
+//!     }
+//!   }
+//!   ```
+
+use oxc_allocator::Box;
+use oxc_ast::{ast::*, visit::walk_mut, VisitMut, NONE};
+use oxc_span::SPAN;
+use oxc_syntax::{
+    scope::{ScopeFlags, ScopeId},
+    symbol::SymbolFlags,
+};
+use oxc_traverse::{BoundIdentifier, TraverseCtx};
+
+use super::{
+    utils::{create_var_statement, reparent_scopes},
+    ClassProperties,
+};
+
+impl<'a, 'ctx> ClassProperties<'a, 'ctx> {
+    /// Insert instance property initializers into constructor, creating constructor if required.
+    pub(super) fn insert_instance_inits(
+        class: &mut Class<'a>,
+        mut inits: Vec<Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let is_derived = class.super_class.is_some();
+
+        let Some(constructor) = class.body.body.iter_mut().find_map(|element| match element {
+            ClassElement::MethodDefinition(method)
+                if method.kind == MethodDefinitionKind::Constructor =>
+            {
+                Some(&mut method.value)
+            }
+            _ => None,
+        }) else {
+            let class_scope_id = class.scope_id.get().unwrap();
+            let constructor = Self::create_constructor(class_scope_id, is_derived, inits, ctx);
+            class.body.body.insert(0, constructor);
+            return;
+        };
+
+        let constructor_scope_id = constructor.scope_id.get().unwrap();
+        let body = constructor.body.as_mut().unwrap();
+
+        if !is_derived {
+            for init in &mut inits {
+                reparent_scopes(init, constructor_scope_id, ctx);
+            }
+            let stmts = inits.into_iter().map(|init| ctx.ast.statement_expression(SPAN, init));
+            body.statements.splice(0..0, stmts.collect::<Vec<_>>());
+            return;
+        }
+
+        // Derived class. If constructor contains a single `super()` call as a top-level statement,
+        // insert initializers after it.
+        let mut counter = SuperCallCounter { count: 0 };
+        counter.visit_function_body(body);
+        if counter.count == 1 {
+            let index = body.statements.iter().position(|stmt| {
+                matches!(
+                    stmt,
+                    Statement::ExpressionStatement(expr_stmt)
+                        if matches!(&expr_stmt.expression, Expression::CallExpression(call) if matches!(call.callee, Expression::Super(_)))
+                )
+            });
+            if let Some(index) = index.map(|index| index + 1) {
+                for init in &mut inits {
+                    reparent_scopes(init, constructor_scope_id, ctx);
+                }
+                let stmts = inits.into_iter().map(|init| ctx.ast.statement_expression(SPAN, init));
+                body.statements.splice(index..index, stmts.collect::<Vec<_>>());
+                return;
+            }
+        }
+
+        // Otherwise wrap `super()` in a function which runs initializers
+        let super_binding =
+            ctx.generate_uid("super", constructor_scope_id, SymbolFlags::FunctionScopedVariable);
+        SuperCallReplacer { binding: &super_binding, ctx }.visit_function_body(body);
+
+        let arrow_scope_id = ctx.create_child_scope(
+            constructor_scope_id,
+            ScopeFlags::Function | ScopeFlags::Arrow | ScopeFlags::StrictMode,
+        );
+        for init in &mut inits {
+            reparent_scopes(init, arrow_scope_id, ctx);
+        }
+        let args_binding =
+            ctx.generate_uid("args", arrow_scope_id, SymbolFlags::FunctionScopedVariable);
+        let mut exprs = ctx.ast.vec_with_capacity(inits.len() + 2);
+        exprs.push(Self::create_super_call(&args_binding, ctx));
+        exprs.extend(inits);
+        exprs.push(ctx.ast.expression_this(SPAN));
+        let arrow_body = ctx.ast.function_body(
+            SPAN,
+            ctx.ast.vec(),
+            ctx.ast
+                .vec1(ctx.ast.statement_expression(SPAN, ctx.ast.expression_sequence(SPAN, exprs))),
+        );
+        let arrow = Expression::ArrowFunctionExpression(
+            ctx.ast.alloc_arrow_function_expression_with_scope_id(
+                SPAN,
+                true,
+                false,
+                NONE,
+                Self::create_rest_params(&args_binding, ctx),
+                NONE,
+                arrow_body,
+                arrow_scope_id,
+            ),
+        );
+        body.statements.insert(0, create_var_statement(&super_binding, arrow, ctx));
+    }
+
+    /// Create constructor containing initializers.
+    fn create_constructor(
+        class_scope_id: ScopeId,
+        is_derived: bool,
+        mut inits: Vec<Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ClassElement<'a> {
+        let scope_id = ctx.create_child_scope(
+            class_scope_id,
+            ScopeFlags::Function | ScopeFlags::Constructor | ScopeFlags::StrictMode,
+        );
+        for init in &mut inits {
+            reparent_scopes(init, scope_id, ctx);
+        }
+
+        let mut stmts = ctx.ast.vec_with_capacity(inits.len() + 1);
+        let params = if is_derived {
+            let args_binding =
+                ctx.generate_uid("args", scope_id, SymbolFlags::FunctionScopedVariable);
+            stmts.push(
+                ctx.ast.statement_expression(SPAN, Self::create_super_call(&args_binding, ctx)),
+            );
+            Self::create_rest_params(&args_binding, ctx)
+        } else {
+            ctx.ast.alloc_formal_parameters(
+                SPAN,
+                FormalParameterKind::FormalParameter,
+                ctx.ast.vec(),
+                NONE,
+            )
+        };
+        stmts.extend(inits.into_iter().map(|init| ctx.ast.statement_expression(SPAN, init)));
+
+        let body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), stmts);
+        let function = ctx.ast.alloc_function_with_scope_id(
+            FunctionType::FunctionExpression,
+            SPAN,
+            None,
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(body),
+            scope_id,
+        );
+        ctx.ast.class_element_method_definition(
+            MethodDefinitionType::MethodDefinition,
+            SPAN,
+            ctx.ast.vec(),
+            ctx.ast.property_key_identifier_name(SPAN, "constructor"),
+            function,
+            MethodDefinitionKind::Constructor,
+            false,
+            false,
+            false,
+            false,
+            None,
+        )
+    }
+
+    /// Create `super(..._args)`.
+    fn create_super_call(
+        args_binding: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let argument =
+            ctx.ast.argument_spread_element(SPAN, args_binding.create_read_expression(ctx));
+        ctx.ast.expression_call(
+            SPAN,
+            ctx.ast.expression_super(SPAN),
+            NONE,
+            ctx.ast.vec1(argument),
+            false,
+        )
+    }
+
+    /// Create `(..._args)` params.
+    fn create_rest_params(
+        args_binding: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Box<'a, FormalParameters<'a>> {
+        let rest =
+            ctx.ast.alloc_binding_rest_element(SPAN, args_binding.create_binding_pattern(ctx));
+        ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            ctx.ast.vec(),
+            Some(rest),
+        )
+    }
+}
+
+/// Visitor to count `super()` calls in constructor.
+/// Does not enter non-arrow functions or classes, as `super()` is not legal in them.
+struct SuperCallCounter {
+    count: usize,
+}
+
+impl<'a> VisitMut<'a> for SuperCallCounter {
+    fn visit_call_expression(&mut self, call: &mut CallExpression<'a>) {
+        if matches!(call.callee, Expression::Super(_)) {
+            self.count += 1;
+        }
+        walk_mut::walk_call_expression(self, call);
+    }
+
+    fn visit_function(&mut self, _func: &mut Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_class(&mut self, _class: &mut Class<'a>) {}
+}
+
+/// Visitor to replace `super(...)` with `_super(...)`.
+struct SuperCallReplacer<'a, 'b> {
+    binding: &'b BoundIdentifier<'a>,
+    ctx: &'b mut TraverseCtx<'a>,
+}
+
+impl<'a, 'b> VisitMut<'a> for SuperCallReplacer<'a, 'b> {
+    fn visit_call_expression(&mut self, call: &mut CallExpression<'a>) {
+        if matches!(call.callee, Expression::Super(_)) {
+            call.callee = self.binding.create_read_expression(self.ctx);
+        }
+        walk_mut::walk_call_expression(self, call);
+    }
+
+    fn visit_function(&mut self, _func: &mut Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_class(&mut self, _class: &mut Class<'a>) {}
+}
@@ -0,0 +1,293 @@
+//! ES2022: Class Properties
+//!
+//! This plugin transforms class properties (`class C { x = 1; static y = 2; }`),
+//! private properties / methods / accessors (`class C { #x = 1; #m() {} get #a() {} }`),
+//! and private brand checks (`#x in obj`).
+//!
+//! Transforms of class properties, private methods and private-property-in-object are implemented
+//! together, as they all require the same analysis of the class body.
+//!
+//! > This plugin is included in `preset-env`, in ES2022
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! class C {
+//!   x = 1;
+//!   #y = 2;
+//!   static z = 3;
+//!   #m() {}
+//!   method(obj) {
+//!     this.#m();
+//!     return #y in obj ? this.#y : this.x;
+//!   }
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _y = new WeakMap();
+//! var _C_brand = new WeakSet();
+//! class C {
+//!   constructor() {
+//!     babelHelpers.classPrivateMethodInitSpec(this, _C_brand);
+//!     babelHelpers.defineProperty(this, "x", 1);
+//!     babelHelpers.classPrivateFieldInitSpec(this, _y, 2);
+//!   }
+//!   method(obj) {
+//!     babelHelpers.assertClassBrand(_C_brand, this, _m).call(this);
+//!     return _y.has(babelHelpers.checkInRHS(obj))
+//!       ? babelHelpers.classPrivateFieldGet2(_y, this)
+//!       : this.x;
+//!   }
+//! }
+//! function _m() {}
+//! babelHelpers.defineProperty(C, "z", 3);
+//! ```
+//!
+//! ## Options
+//!
+//! ### `loose`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Equivalent to enabling both the `setPublicClassFields` and `privateFieldsAsProperties`
+//! compiler assumptions.
+//!
+//! ### Assumptions
+//!
+//! * `setPublicClassFields`: Use assignments (`this.x = 1`) instead of `defineProperty` for public fields.
+//! * `privateFieldsAsProperties`: Store private members as non-enumerable properties keyed by
+//!   a unique string, instead of in `WeakMap`s.
+//! * `privateFieldsAsSymbols`: Store private members as properties keyed by a `Symbol`.
+//!
+//! `accessor` properties (decorators proposal) are converted to a private field with a getter
+//! and setter. `super` in static property initializers and private methods is converted to
+//! `superPropGet` / `superPropSet` helper calls.
+//!
+//! ## Missing features
+//!
+//! These are not supported yet, and produce an error:
+//!
+//! * `accessor` properties with computed keys which are not literals.
+//! * Logical assignments, update expressions and destructuring assignments to `super` properties
+//!   in static property initializers and private methods.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-class-properties](https://babel.dev/docs/babel-plugin-transform-class-properties),
+//! [@babel/plugin-transform-private-methods](https://babel.dev/docs/babel-plugin-transform-private-methods) and
+//! [@babel/plugin-transform-private-property-in-object](https://babel.dev/docs/babel-plugin-transform-private-property-in-object).
+//!
+//! ## References:
+//! * Babel helper implementation: <https://github.com/babel/babel/tree/main/packages/babel-helper-create-class-features-plugin>
+//! * Class fields TC39 proposal: <https://github.com/tc39/proposal-class-fields>
+//! * Private methods TC39 proposal: <https://github.com/tc39/proposal-private-methods>
+//! * Ergonomic brand checks TC39 proposal: <https://github.com/tc39/proposal-private-fields-in-in>
+
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+
+use oxc_allocator::Box;
+use oxc_ast::ast::*;
+use oxc_span::Atom;
+use oxc_traverse::{BoundIdentifier, Traverse, TraverseCtx};
+
+use crate::TransformCtx;
+
+use class::Init;
+
+mod accessor;
+mod class;
+mod constructor;
+mod private;
+mod super_prop;
+mod utils;
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct ClassPropertiesOptions {
+    /// Enables both the `setPublicClassFields` and `privateFieldsAsProperties` assumptions.
+    pub loose: bool,
+}
+
+/// How private members are stored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PrivateMode {
+    /// `WeakMap`s, `WeakSet`s and brand checks, following the spec.
+    Spec,
+    /// Non-enumerable properties keyed by `classPrivateFieldLooseKey("name")`.
+    /// Enabled by `privateFieldsAsProperties` assumption.
+    Properties,
+    /// Properties keyed by `Symbol("name")`.
+    /// Enabled by `privateFieldsAsSymbols` assumption.
+    Symbols,
+}
+
+pub struct ClassProperties<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+
+    /// `true` if `setPublicClassFields` assumption or `loose` option is enabled
+    set_public_class_fields: bool,
+    private_mode: PrivateMode,
+
+    /// Stack of classes currently being traversed.
+    /// Entry is `None` for a class which does not need transforming.
+    classes_stack: Vec<Option<ClassDetails<'a>>>,
+    /// Output of the class which has just been exited, waiting for its parent
+    /// `Expression` or `Statement` to insert it.
+    pending_output: Option<ClassOutput<'a>>,
+}
+
+impl<'a, 'ctx> ClassProperties<'a, 'ctx> {
+    pub fn new(options: ClassPropertiesOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        let assumptions = &ctx.assumptions;
+        let set_public_class_fields = options.loose || assumptions.set_public_class_fields;
+        let private_mode = if options.loose || assumptions.private_fields_as_properties {
+            PrivateMode::Properties
+        } else if assumptions.private_fields_as_symbols {
+            PrivateMode::Symbols
+        } else {
+            PrivateMode::Spec
+        };
+
+        Self {
+            ctx,
+            set_public_class_fields,
+            private_mode,
+            classes_stack: vec![],
+            pending_output: None,
+        }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for ClassProperties<'a, 'ctx> {
+    fn enter_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.prepare_class(class, ctx);
+    }
+
+    fn exit_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.transform_class(class, ctx);
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            Expression::PrivateFieldExpression(_) => {
+                self.transform_private_field_expression(expr, ctx);
+            }
+            Expression::CallExpression(call_expr)
+                if matches!(call_expr.callee, Expression::PrivateFieldExpression(_)) =>
+            {
+                self.transform_call_expression(expr, ctx);
+            }
+            Expression::AssignmentExpression(assign_expr)
+                if matches!(assign_expr.left, AssignmentTarget::PrivateFieldExpression(_)) =>
+            {
+                self.transform_assignment_expression(expr, ctx);
+            }
+            Expression::UpdateExpression(update_expr)
+                if matches!(
+                    update_expr.argument,
+                    SimpleAssignmentTarget::PrivateFieldExpression(_)
+                ) =>
+            {
+                self.transform_update_expression(expr, ctx);
+            }
+            Expression::PrivateInExpression(_) => {
+                self.transform_private_in_expression(expr, ctx);
+            }
+            Expression::TaggedTemplateExpression(tagged_expr)
+                if matches!(tagged_expr.tag, Expression::PrivateFieldExpression(_)) =>
+            {
+                self.transform_tagged_template_expression(expr, ctx);
+            }
+            Expression::ChainExpression(chain_expr)
+                if self.chain_contains_private_field(chain_expr) =>
+            {
+                self.transform_chain_expression(expr, ctx);
+            }
+            _ => {}
+        }
+    }
+
+    fn enter_assignment_target(
+        &mut self,
+        target: &mut AssignmentTarget<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        // Plain assignments `obj.#prop = value` are transformed in `enter_expression`.
+        // Only destructuring targets and `for (obj.#prop of arr)` reach here.
+        if matches!(target, AssignmentTarget::PrivateFieldExpression(_)) {
+            self.transform_assignment_target(target, ctx);
+        }
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        // Class may have already been transformed to a function by ES2015 classes transform,
+        // so don't check that `expr` is still a `ClassExpression`.
+        // `pending_output` is only set when the class which has just been exited is an expression.
+        if let Some(output) = self.pending_output.take() {
+            self.insert_output_around_class_expression(expr, output, ctx);
+        }
+    }
+
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        // Class may have already been transformed by ES2015 classes transform.
+        // See comment in `exit_expression`.
+        if let Some(output) = self.pending_output.take() {
+            self.insert_output_around_class_declaration(stmt, output, ctx);
+        }
+    }
+}
+
+/// Details of a class which is being transformed.
+struct ClassDetails<'a> {
+    /// Binding used to refer to the class from outside its body.
+    /// For class declarations, it's the class's own name. For class expressions, a temp var.
+    /// Only created if required, i.e. when class has static members.
+    class_binding: Option<BoundIdentifier<'a>>,
+    /// Binding for the `WeakSet` which brands instances of the class,
+    /// used for instance private methods and accessors.
+    brand: Option<BoundIdentifier<'a>>,
+    /// Private members of the class, keyed by name (without `#`).
+    private_props: FxHashMap<Atom<'a>, PrivateProp<'a>>,
+    /// Initializers for private keys, to be evaluated before the class.
+    before: std::vec::Vec<Init<'a>>,
+}
+
+/// A private member of a class.
+#[derive(Clone)]
+struct PrivateProp<'a> {
+    /// Storage for the private member:
+    /// * Spec mode: `WeakMap` for instance fields, or `{ _: value }` object for static fields.
+    ///   `None` for methods and accessors.
+    /// * Properties / symbols modes: property key.
+    binding: Option<BoundIdentifier<'a>>,
+    is_static: bool,
+    kind: PrivatePropKind<'a>,
+}
+
+#[derive(Clone)]
+enum PrivatePropKind<'a> {
+    Field,
+    /// Method, with binding for the function it's converted to.
+    Method(BoundIdentifier<'a>),
+    /// Getter and/or setter, with bindings for the functions they're converted to.
+    Accessor {
+        getter: Option<BoundIdentifier<'a>>,
+        setter: Option<BoundIdentifier<'a>>,
+    },
+}
+
+/// Code which needs to be inserted around a class after it's been transformed.
+struct ClassOutput<'a> {
+    /// Binding for class, if static members need to refer to it.
+    class_binding: Option<BoundIdentifier<'a>>,
+    /// Values to be evaluated before the class (private keys and computed keys).
+    before: std::vec::Vec<Init<'a>>,
+    /// Functions created from private methods and accessors.
+    functions: std::vec::Vec<(BoundIdentifier<'a>, Box<'a, Function<'a>>)>,
+    /// Values to be evaluated after the class (static property initializers).
+    after: std::vec::Vec<Init<'a>>,
+}
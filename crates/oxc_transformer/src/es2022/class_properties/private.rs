@@ -0,0 +1,666 @@
+//! ES2022: Class Properties
+//! Transform of private property uses e.g. `this.#prop`.
+
+use oxc_ast::{ast::*, NONE};
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::{reference::ReferenceFlags, symbol::SymbolFlags};
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse, TraverseCtx};
+
+use crate::{common::helper_loader::Helper, es2020::OptionalChaining};
+
+use super::{
+    utils::{create_global_ident, create_member, create_private_name_string},
+    ClassProperties, PrivateMode, PrivateProp, PrivatePropKind,
+};
+
+/// Details of a private property, resolved from the class which declares it.
+struct ResolvedPrivateProp<'a> {
+    name: Atom<'a>,
+    prop: PrivateProp<'a>,
+    /// Binding for class. Present if property is static.
+    class_binding: Option<BoundIdentifier<'a>>,
+    /// Binding for `WeakSet` which brands class instances. Present in spec mode if class has
+    /// instance private methods or accessors.
+    brand: Option<BoundIdentifier<'a>>,
+}
+
+impl<'a, 'ctx> ClassProperties<'a, 'ctx> {
+    /// Transform private field read e.g. `obj.#prop`.
+    ///
+    /// * Spec mode:
+    ///   * Instance field: `babelHelpers.classPrivateFieldGet2(_prop, obj)`
+    ///   * Static field: `babelHelpers.assertClassBrand(Class, obj, _prop)._`
+    ///   * Method: `babelHelpers.assertClassBrand(_Class_brand, obj, _prop)`
+    ///   * Accessor: `babelHelpers.classPrivateGetter(_Class_brand, obj, _get_prop)`
+    /// * Properties mode: `babelHelpers.classPrivateFieldLooseBase(obj, _prop)[_prop]`
+    /// * Symbols mode: `obj[_prop]`
+    pub(super) fn transform_private_field_expression(
+        &self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::PrivateFieldExpression(field_expr) = expr else { unreachable!() };
+        if field_expr.optional {
+            return;
+        }
+        let Some(resolved) = self.resolve_private_prop(&field_expr.field.name) else { return };
+        let object = ctx.ast.move_expression(&mut field_expr.object);
+        *expr = self.create_private_get(&resolved, object, ctx);
+    }
+
+    /// Transform call of a private method or a function stored in a private field
+    /// e.g. `obj.#method(arg)`.
+    ///
+    /// * Spec mode: `babelHelpers.assertClassBrand(_Class_brand, obj, _method).call(obj, arg)`
+    /// * Properties / symbols mode: Member expression call preserves `this`, so transforming
+    ///   callee is sufficient. Callee is transformed when it's visited.
+    pub(super) fn transform_call_expression(
+        &self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.private_mode != PrivateMode::Spec {
+            return;
+        }
+
+        let Expression::CallExpression(call_expr) = expr else { unreachable!() };
+        if call_expr.optional {
+            return;
+        }
+        let Expression::PrivateFieldExpression(field_expr) = &mut call_expr.callee else {
+            unreachable!()
+        };
+        if field_expr.optional {
+            return;
+        }
+        let Some(resolved) = self.resolve_private_prop(&field_expr.field.name) else { return };
+
+        let object = ctx.ast.move_expression(&mut field_expr.object);
+        let (object1, object2) = self.duplicate_object(object, ctx);
+        let callee = self.create_private_get(&resolved, object1, ctx);
+        call_expr.callee = create_member(callee, "call", ctx);
+        call_expr.arguments.insert(0, Argument::from(object2));
+    }
+
+    /// Transform tagged template with a private method or field as tag e.g. ``obj.#method`foo` ``.
+    ///
+    /// * Spec mode: ``babelHelpers.assertClassBrand(_Class_brand, obj, _method).bind(obj)`foo` ``
+    /// * Properties / symbols mode: Member expression tag preserves `this`, so transforming
+    ///   tag is sufficient. Tag is transformed when it's visited.
+    pub(super) fn transform_tagged_template_expression(
+        &self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.private_mode != PrivateMode::Spec {
+            return;
+        }
+
+        let Expression::TaggedTemplateExpression(tagged_expr) = expr else { unreachable!() };
+        let Expression::PrivateFieldExpression(field_expr) = &mut tagged_expr.tag else {
+            unreachable!()
+        };
+        let Some(resolved) = self.resolve_private_prop(&field_expr.field.name) else { return };
+
+        let object = ctx.ast.move_expression(&mut field_expr.object);
+        let (object1, object2) = self.duplicate_object(object, ctx);
+        let get = self.create_private_get(&resolved, object1, ctx);
+        let callee = create_member(get, "bind", ctx);
+        tagged_expr.tag = ctx.ast.expression_call(
+            SPAN,
+            callee,
+            NONE,
+            ctx.ast.vec1(Argument::from(object2)),
+            false,
+        );
+    }
+
+    /// Transform optional chain containing a private field e.g. `obj?.#prop`, `obj?.foo.#method()`.
+    ///
+    /// The chain is lowered with the optional chaining transform, after which the private field
+    /// is no longer part of an optional chain, and is transformed as usual when it's visited.
+    ///
+    /// `obj?.#prop` -> `obj === null || obj === void 0 ? void 0 : obj.#prop`
+    pub(super) fn transform_chain_expression(
+        &self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        OptionalChaining::new(self.ctx).enter_expression(expr, ctx);
+    }
+
+    /// Check if an optional chain contains a private field of a class which is being transformed.
+    pub(super) fn chain_contains_private_field(&self, chain_expr: &ChainExpression<'a>) -> bool {
+        let mut expr = match &chain_expr.expression {
+            ChainElement::CallExpression(call_expr) => &call_expr.callee,
+            element => {
+                let member_expr = element.to_member_expression();
+                if let MemberExpression::PrivateFieldExpression(field_expr) = member_expr {
+                    if self.resolve_private_prop(&field_expr.field.name).is_some() {
+                        return true;
+                    }
+                }
+                member_expr.object()
+            }
+        };
+        loop {
+            expr = match expr {
+                Expression::PrivateFieldExpression(field_expr) => {
+                    if self.resolve_private_prop(&field_expr.field.name).is_some() {
+                        return true;
+                    }
+                    &field_expr.object
+                }
+                Expression::StaticMemberExpression(member_expr) => &member_expr.object,
+                Expression::ComputedMemberExpression(member_expr) => &member_expr.object,
+                Expression::CallExpression(call_expr) => &call_expr.callee,
+                Expression::TSNonNullExpression(non_null_expr) => &non_null_expr.expression,
+                _ => return false,
+            };
+        }
+    }
+
+    /// Transform assignment to private field e.g. `obj.#prop = value`, `obj.#prop += value`.
+    ///
+    /// * Spec mode:
+    ///   * `obj.#prop = value` -> `babelHelpers.classPrivateFieldSet2(_prop, obj, value)`
+    ///   * `obj.#prop += value` ->
+    ///     `babelHelpers.classPrivateFieldSet2(_prop, _obj = obj, babelHelpers.classPrivateFieldGet2(_prop, _obj) + value)`
+    ///   * `obj.#prop ||= value` ->
+    ///     `babelHelpers.classPrivateFieldGet2(_prop, _obj = obj) || babelHelpers.classPrivateFieldSet2(_prop, _obj, value)`
+    /// * Properties / symbols mode: Assignment target is replaced with a member expression,
+    ///   operator is unchanged.
+    pub(super) fn transform_assignment_expression(
+        &self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::AssignmentExpression(assign_expr) = expr else { unreachable!() };
+        let AssignmentTarget::PrivateFieldExpression(field_expr) = &mut assign_expr.left else {
+            unreachable!()
+        };
+        let Some(resolved) = self.resolve_private_prop(&field_expr.field.name) else { return };
+        let object = ctx.ast.move_expression(&mut field_expr.object);
+
+        if self.private_mode != PrivateMode::Spec {
+            let member = self.create_private_member(&resolved, object, ctx);
+            assign_expr.left = AssignmentTarget::from(member);
+            return;
+        }
+
+        let operator = assign_expr.operator;
+        let value = ctx.ast.move_expression(&mut assign_expr.right);
+        *expr = if operator == AssignmentOperator::Assign {
+            self.create_private_set(&resolved, object, value, ctx)
+        } else {
+            let (object1, object2) = self.duplicate_object(object, ctx);
+            if let Some(operator) = operator.to_logical_operator() {
+                let get = self.create_private_get(&resolved, object1, ctx);
+                let set = self.create_private_set(&resolved, object2, value, ctx);
+                ctx.ast.expression_logical(SPAN, get, operator, set)
+            } else {
+                let operator = operator.to_binary_operator().unwrap();
+                let get = self.create_private_get(&resolved, object2, ctx);
+                let value = ctx.ast.expression_binary(SPAN, get, operator, value);
+                self.create_private_set(&resolved, object1, value, ctx)
+            }
+        };
+    }
+
+    /// Transform private field used as a destructuring assignment target or `for in / of` left side
+    /// e.g. `[obj.#prop] = arr`, `({ x: obj.#prop } = o)`, `for (obj.#prop of arr) {}`.
+    ///
+    /// Target is replaced with a member expression, which sets the private property when
+    /// assigned to.
+    ///
+    /// * Spec mode:
+    ///   * Instance field:
+    ///     `babelHelpers.toSetter(babelHelpers.classPrivateFieldSet2, [_prop, obj])._`
+    ///   * Static field: `babelHelpers.assertClassBrand(Class, obj, _prop)._`
+    ///   * Accessor:
+    ///     `babelHelpers.toSetter(babelHelpers.classPrivateSetter, [_Class_brand, _set_prop, obj])._`
+    ///   * Method / accessor without setter:
+    ///     `(obj, babelHelpers.toSetter(babelHelpers.readOnlyError, ["#prop"]))._`
+    /// * Properties mode: `babelHelpers.classPrivateFieldLooseBase(obj, _prop)[_prop]`
+    /// * Symbols mode: `obj[_prop]`
+    pub(super) fn transform_assignment_target(
+        &self,
+        target: &mut AssignmentTarget<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let AssignmentTarget::PrivateFieldExpression(field_expr) = target else { unreachable!() };
+        let Some(resolved) = self.resolve_private_prop(&field_expr.field.name) else { return };
+        let object = ctx.ast.move_expression(&mut field_expr.object);
+
+        if self.private_mode != PrivateMode::Spec {
+            let member = self.create_private_member(&resolved, object, ctx);
+            *target = AssignmentTarget::from(member);
+            return;
+        }
+
+        let prop = &resolved.prop;
+        let setter_object = match &prop.kind {
+            PrivatePropKind::Field if prop.is_static => {
+                let key = prop.binding.as_ref().unwrap().create_read_expression(ctx);
+                self.create_assert_class_brand(&resolved, object, key, ctx)
+            }
+            PrivatePropKind::Field => {
+                let key = prop.binding.as_ref().unwrap().create_read_expression(ctx);
+                self.create_to_setter(Helper::ClassPrivateFieldSet2, [key, object], ctx)
+            }
+            PrivatePropKind::Accessor { setter: Some(setter), .. } => {
+                let brand = Self::get_brand(&resolved, ctx);
+                let setter = setter.create_read_expression(ctx);
+                self.create_to_setter(Helper::ClassPrivateSetter, [brand, setter, object], ctx)
+            }
+            PrivatePropKind::Method(_) | PrivatePropKind::Accessor { setter: None, .. } => {
+                let name = create_private_name_string(&resolved.name, ctx);
+                let setter = self.create_to_setter(Helper::ReadOnlyError, [name], ctx);
+                ctx.ast.expression_sequence(SPAN, ctx.ast.vec_from_iter([object, setter]))
+            }
+        };
+        let property = ctx.ast.identifier_name(SPAN, Atom::from("_"));
+        let member = ctx.ast.member_expression_static(SPAN, setter_object, property, false);
+        *target = AssignmentTarget::from(member);
+    }
+
+    /// Create `babelHelpers.toSetter(babelHelpers.<helper>, [<arguments>])`.
+    ///
+    /// Assigning to `_` property of the result calls the helper with `arguments` and the value.
+    fn create_to_setter(
+        &self,
+        helper: Helper,
+        arguments: impl IntoIterator<Item = Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let function = self.ctx.helper_load(helper, ctx);
+        let elements =
+            ctx.ast.vec_from_iter(arguments.into_iter().map(ArrayExpressionElement::from));
+        let arguments = ctx.ast.vec_from_iter([
+            Argument::from(function),
+            Argument::from(ctx.ast.expression_array(SPAN, elements, None)),
+        ]);
+        self.ctx.helper_call_expr(Helper::ToSetter, arguments, ctx)
+    }
+
+    /// Transform update expression on private field e.g. `obj.#prop++`.
+    ///
+    /// * Spec mode:
+    ///   * `++obj.#prop` ->
+    ///     `babelHelpers.classPrivateFieldSet2(_prop, _obj = obj, (_obj$prop = babelHelpers.classPrivateFieldGet2(_prop, _obj), ++_obj$prop))`
+    ///   * `obj.#prop++` ->
+    ///     `(babelHelpers.classPrivateFieldSet2(_prop, _obj = obj, (_obj$prop = babelHelpers.classPrivateFieldGet2(_prop, _obj), _obj$prop2 = _obj$prop++, _obj$prop)), _obj$prop2)`
+    ///
+    ///   Postfix update expressions whose value is unused are transformed as prefix.
+    /// * Properties / symbols mode: Argument is replaced with a member expression.
+    pub(super) fn transform_update_expression(
+        &self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let is_value_unused = matches!(ctx.parent(), Ancestor::ExpressionStatementExpression(_));
+
+        let Expression::UpdateExpression(update_expr) = expr else { unreachable!() };
+        let SimpleAssignmentTarget::PrivateFieldExpression(field_expr) = &mut update_expr.argument
+        else {
+            unreachable!()
+        };
+        let Some(resolved) = self.resolve_private_prop(&field_expr.field.name) else { return };
+        let object = ctx.ast.move_expression(&mut field_expr.object);
+
+        if self.private_mode != PrivateMode::Spec {
+            let member = self.create_private_member(&resolved, object, ctx);
+            update_expr.argument = SimpleAssignmentTarget::from(member);
+            return;
+        }
+
+        let operator = update_expr.operator;
+        let is_prefix = update_expr.prefix || is_value_unused;
+
+        let (object1, object2) = self.duplicate_object(object, ctx);
+        let get = self.create_private_get(&resolved, object2, ctx);
+
+        // `_obj$prop = get(obj)`
+        let temp_binding = ctx.generate_uid_in_current_scope(
+            &format!("{}${}", get_object_name(&object1), resolved.name),
+            SymbolFlags::FunctionScopedVariable,
+        );
+        self.ctx.var_declarations.insert(&temp_binding, None, ctx);
+        let assign_temp = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            temp_binding.create_read_write_target(ctx),
+            get,
+        );
+
+        let mut exprs = ctx.ast.vec_from_iter([assign_temp]);
+        let result_binding = if is_prefix {
+            // `++_obj$prop`
+            exprs.push(ctx.ast.expression_update(
+                SPAN,
+                operator,
+                true,
+                ctx.ast.simple_assignment_target_from_identifier_reference(
+                    temp_binding.create_read_write_reference(ctx),
+                ),
+            ));
+            None
+        } else {
+            // `_obj$prop2 = _obj$prop++, _obj$prop`
+            let result_binding = ctx.generate_uid_in_current_scope(
+                temp_binding.name.as_str(),
+                SymbolFlags::FunctionScopedVariable,
+            );
+            self.ctx.var_declarations.insert(&result_binding, None, ctx);
+            let update = ctx.ast.expression_update(
+                SPAN,
+                operator,
+                false,
+                ctx.ast.simple_assignment_target_from_identifier_reference(
+                    temp_binding.create_read_write_reference(ctx),
+                ),
+            );
+            exprs.push(ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                result_binding.create_read_write_target(ctx),
+                update,
+            ));
+            exprs.push(temp_binding.create_read_expression(ctx));
+            Some(result_binding)
+        };
+
+        let value = ctx.ast.expression_sequence(SPAN, exprs);
+        let set = self.create_private_set(&resolved, object1, value, ctx);
+        *expr = match result_binding {
+            Some(result_binding) => ctx.ast.expression_sequence(
+                SPAN,
+                ctx.ast.vec_from_iter([set, result_binding.create_read_expression(ctx)]),
+            ),
+            None => set,
+        };
+    }
+
+    /// Transform private brand check e.g. `#prop in obj`.
+    ///
+    /// * Spec mode:
+    ///   * Instance field: `_prop.has(babelHelpers.checkInRHS(obj))`
+    ///   * Instance method / accessor: `_Class_brand.has(babelHelpers.checkInRHS(obj))`
+    ///   * Static: `babelHelpers.checkInRHS(obj) === Class`
+    /// * Properties mode: `Object.prototype.hasOwnProperty.call(babelHelpers.checkInRHS(obj), _prop)`
+    /// * Symbols mode: `_prop in babelHelpers.checkInRHS(obj)`
+    pub(super) fn transform_private_in_expression(
+        &self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::PrivateInExpression(in_expr) = expr else { unreachable!() };
+        let Some(resolved) = self.resolve_private_prop(&in_expr.left.name) else { return };
+
+        let object = ctx.ast.move_expression(&mut in_expr.right);
+        let object = self.ctx.helper_call_expr(
+            Helper::CheckInRHS,
+            ctx.ast.vec1(Argument::from(object)),
+            ctx,
+        );
+
+        *expr = match self.private_mode {
+            PrivateMode::Spec if resolved.prop.is_static => {
+                let class = resolved.class_binding.as_ref().unwrap().create_read_expression(ctx);
+                ctx.ast.expression_binary(SPAN, object, BinaryOperator::StrictEquality, class)
+            }
+            PrivateMode::Spec => {
+                let set = match &resolved.prop.kind {
+                    PrivatePropKind::Field => resolved.prop.binding.as_ref().unwrap(),
+                    _ => resolved.brand.as_ref().unwrap(),
+                };
+                let callee = create_member(set.create_read_expression(ctx), "has", ctx);
+                ctx.ast.expression_call(
+                    SPAN,
+                    callee,
+                    NONE,
+                    ctx.ast.vec1(Argument::from(object)),
+                    false,
+                )
+            }
+            PrivateMode::Properties => {
+                let object_ident = create_global_ident("Object", ctx);
+                let prototype = create_member(object_ident, "prototype", ctx);
+                let has_own_property = create_member(prototype, "hasOwnProperty", ctx);
+                let callee = create_member(has_own_property, "call", ctx);
+                let key = resolved.prop.binding.as_ref().unwrap().create_read_expression(ctx);
+                let arguments =
+                    ctx.ast.vec_from_iter([Argument::from(object), Argument::from(key)]);
+                ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+            }
+            PrivateMode::Symbols => {
+                let key = resolved.prop.binding.as_ref().unwrap().create_read_expression(ctx);
+                ctx.ast.expression_binary(SPAN, key, BinaryOperator::In, object)
+            }
+        };
+    }
+
+    /// Find private property by name in the classes currently being traversed.
+    fn resolve_private_prop(&self, name: &Atom<'a>) -> Option<ResolvedPrivateProp<'a>> {
+        self.classes_stack.iter().rev().flatten().find_map(|class| {
+            class.private_props.get(name).map(|prop| ResolvedPrivateProp {
+                name: name.clone(),
+                prop: prop.clone(),
+                class_binding: class.class_binding.clone(),
+                brand: class.brand.clone(),
+            })
+        })
+    }
+
+    /// Create member expression used to access private property in properties / symbols mode.
+    ///
+    /// * Properties mode: `babelHelpers.classPrivateFieldLooseBase(obj, _prop)[_prop]`
+    /// * Symbols mode: `obj[_prop]`
+    fn create_private_member(
+        &self,
+        resolved: &ResolvedPrivateProp<'a>,
+        object: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> MemberExpression<'a> {
+        let key_binding = resolved.prop.binding.as_ref().unwrap();
+        let object = if self.private_mode == PrivateMode::Properties {
+            let key = key_binding.create_read_expression(ctx);
+            let arguments = ctx.ast.vec_from_iter([Argument::from(object), Argument::from(key)]);
+            self.ctx.helper_call_expr(Helper::ClassPrivateFieldLooseBase, arguments, ctx)
+        } else {
+            object
+        };
+        let key = key_binding.create_read_expression(ctx);
+        ctx.ast.member_expression_computed(SPAN, object, key, false)
+    }
+
+    /// Create expression to get value of private property.
+    fn create_private_get(
+        &self,
+        resolved: &ResolvedPrivateProp<'a>,
+        object: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if self.private_mode != PrivateMode::Spec {
+            return Expression::from(self.create_private_member(resolved, object, ctx));
+        }
+
+        let prop = &resolved.prop;
+        match &prop.kind {
+            PrivatePropKind::Field if prop.is_static => {
+                // `babelHelpers.assertClassBrand(Class, obj, _prop)._`
+                let key = prop.binding.as_ref().unwrap().create_read_expression(ctx);
+                let checked = self.create_assert_class_brand(resolved, object, key, ctx);
+                create_member(checked, "_", ctx)
+            }
+            PrivatePropKind::Field => {
+                // `babelHelpers.classPrivateFieldGet2(_prop, obj)`
+                let key = prop.binding.as_ref().unwrap().create_read_expression(ctx);
+                let arguments =
+                    ctx.ast.vec_from_iter([Argument::from(key), Argument::from(object)]);
+                self.ctx.helper_call_expr(Helper::ClassPrivateFieldGet2, arguments, ctx)
+            }
+            PrivatePropKind::Method(function_binding) => {
+                // `babelHelpers.assertClassBrand(_Class_brand, obj, _method)`
+                let function = function_binding.create_read_expression(ctx);
+                self.create_assert_class_brand(resolved, object, function, ctx)
+            }
+            PrivatePropKind::Accessor { getter: Some(getter), .. } => {
+                // `babelHelpers.classPrivateGetter(_Class_brand, obj, _get_prop)`
+                let brand = Self::get_brand(resolved, ctx);
+                let getter = getter.create_read_expression(ctx);
+                let arguments = ctx.ast.vec_from_iter([
+                    Argument::from(brand),
+                    Argument::from(object),
+                    Argument::from(getter),
+                ]);
+                self.ctx.helper_call_expr(Helper::ClassPrivateGetter, arguments, ctx)
+            }
+            PrivatePropKind::Accessor { getter: None, .. } => {
+                // `(obj, babelHelpers.writeOnlyError("#prop"))`
+                let name = create_private_name_string(&resolved.name, ctx);
+                let error = self.ctx.helper_call_expr(
+                    Helper::WriteOnlyError,
+                    ctx.ast.vec1(Argument::from(name)),
+                    ctx,
+                );
+                ctx.ast.expression_sequence(SPAN, ctx.ast.vec_from_iter([object, error]))
+            }
+        }
+    }
+
+    /// Create expression to set value of private property.
+    fn create_private_set(
+        &self,
+        resolved: &ResolvedPrivateProp<'a>,
+        object: Expression<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let prop = &resolved.prop;
+        match &prop.kind {
+            PrivatePropKind::Field if prop.is_static => {
+                // `_prop._ = babelHelpers.assertClassBrand(Class, obj, value)`
+                let checked = self.create_assert_class_brand(resolved, object, value, ctx);
+                let key = prop.binding.as_ref().unwrap().create_read_expression(ctx);
+                let property = ctx.ast.identifier_name(SPAN, Atom::from("_"));
+                let target = ctx.ast.member_expression_static(SPAN, key, property, false);
+                ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    AssignmentTarget::from(target),
+                    checked,
+                )
+            }
+            PrivatePropKind::Field => {
+                // `babelHelpers.classPrivateFieldSet2(_prop, obj, value)`
+                let key = prop.binding.as_ref().unwrap().create_read_expression(ctx);
+                let arguments = ctx.ast.vec_from_iter([
+                    Argument::from(key),
+                    Argument::from(object),
+                    Argument::from(value),
+                ]);
+                self.ctx.helper_call_expr(Helper::ClassPrivateFieldSet2, arguments, ctx)
+            }
+            PrivatePropKind::Accessor { setter: Some(setter), .. } => {
+                // `babelHelpers.classPrivateSetter(_Class_brand, _set_prop, obj, value)`
+                let brand = Self::get_brand(resolved, ctx);
+                let setter = setter.create_read_expression(ctx);
+                let arguments = ctx.ast.vec_from_iter([
+                    Argument::from(brand),
+                    Argument::from(setter),
+                    Argument::from(object),
+                    Argument::from(value),
+                ]);
+                self.ctx.helper_call_expr(Helper::ClassPrivateSetter, arguments, ctx)
+            }
+            PrivatePropKind::Method(_) | PrivatePropKind::Accessor { setter: None, .. } => {
+                // `(obj, value, babelHelpers.readOnlyError("#prop"))`
+                let name = create_private_name_string(&resolved.name, ctx);
+                let error = self.ctx.helper_call_expr(
+                    Helper::ReadOnlyError,
+                    ctx.ast.vec1(Argument::from(name)),
+                    ctx,
+                );
+                ctx.ast.expression_sequence(SPAN, ctx.ast.vec_from_iter([object, value, error]))
+            }
+        }
+    }
+
+    /// Create `babelHelpers.assertClassBrand(brand, obj, value)`.
+    fn create_assert_class_brand(
+        &self,
+        resolved: &ResolvedPrivateProp<'a>,
+        object: Expression<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let brand = Self::get_brand(resolved, ctx);
+        let arguments = ctx.ast.vec_from_iter([
+            Argument::from(brand),
+            Argument::from(object),
+            Argument::from(value),
+        ]);
+        self.ctx.helper_call_expr(Helper::AssertClassBrand, arguments, ctx)
+    }
+
+    /// Get brand for private property.
+    /// Class itself for static properties, `WeakSet` for instance methods and accessors.
+    fn get_brand(resolved: &ResolvedPrivateProp<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let brand = if resolved.prop.is_static {
+            resolved.class_binding.as_ref().unwrap()
+        } else {
+            resolved.brand.as_ref().unwrap()
+        };
+        brand.create_read_expression(ctx)
+    }
+
+    /// Duplicate object, so it can be used twice without being evaluated twice.
+    ///
+    /// If object is `this` or an identifier which is not mutated, it's cloned.
+    /// Otherwise assign it to a temp var: `(_obj = obj, _obj)`.
+    fn duplicate_object(
+        &self,
+        object: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Expression<'a>, Expression<'a>) {
+        match &object {
+            Expression::ThisExpression(this) => {
+                let span = this.span;
+                return (object, ctx.ast.expression_this(span));
+            }
+            Expression::Identifier(ident) if ctx.is_static(&object) => {
+                let ident = ctx.clone_identifier_reference(ident, ReferenceFlags::Read);
+                return (object, ctx.ast.expression_from_identifier_reference(ident));
+            }
+            _ => {}
+        }
+
+        let binding = ctx.generate_uid_in_current_scope_based_on_node(
+            &object,
+            SymbolFlags::FunctionScopedVariable,
+        );
+        self.ctx.var_declarations.insert(&binding, None, ctx);
+        let assignment = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            binding.create_read_write_target(ctx),
+            object,
+        );
+        (assignment, binding.create_read_expression(ctx))
+    }
+}
+
+/// Get name of object for use in temp var names.
+/// `this.#prop++` -> `_this$prop`, `obj.#prop++` -> `_obj$prop`.
+fn get_object_name<'a>(object: &Expression<'a>) -> &'a str {
+    match object {
+        Expression::Identifier(ident) => ident.name.as_str(),
+        Expression::AssignmentExpression(assign_expr) => match &assign_expr.left {
+            AssignmentTarget::AssignmentTargetIdentifier(ident) => ident.name.as_str(),
+            _ => "ref",
+        },
+        Expression::ThisExpression(_) => "this",
+        _ => "ref",
+    }
+}
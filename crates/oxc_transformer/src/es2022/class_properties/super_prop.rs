@@ -0,0 +1,238 @@
+//! ES2022: Class Properties
+//! Transform of `super` in static property initializers and private methods.
+//!
+//! Static property initializers and private methods are moved out of the class body,
+//! where `super` is not valid, so `super` is converted to helper calls:
+//!
+//! * `super.x` -> `babelHelpers.superPropGet(C, "x", this, 1)`
+//! * `super.x(1)` -> `babelHelpers.superPropGet(C, "x", this, 3)([1])`
+//! * `super.x = 1` -> `babelHelpers.superPropSet(C, "x", 1, this, 1, 1)`
+//!
+//! In static property initializers and static private methods, the flag indicating access is
+//! to the prototype is omitted. In static property initializers, `this` is later replaced with
+//! the class binding.
+//!
+//! Same as Babel's handling, and ES2015 classes transform's handling of `super` in methods.
+
+use oxc_ast::{ast::*, visit::walk_mut, VisitMut, NONE};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::{GetSpan, SPAN};
+use oxc_syntax::{number::NumberBase, scope::ScopeFlags};
+use oxc_traverse::{BoundIdentifier, TraverseCtx};
+
+use crate::{common::helper_loader::Helper, TransformCtx};
+
+/// Convert `super` in static property initializer `expr` to helper calls.
+pub(super) fn transform_super_in_static_prop<'a>(
+    expr: &mut Expression<'a>,
+    class_binding: &BoundIdentifier<'a>,
+    transform_ctx: &TransformCtx<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    SuperConverter { transform_ctx, class_binding, is_static: true, ctx }.visit_expression(expr);
+}
+
+/// Convert `super` in params and body of private method `func` to helper calls.
+pub(super) fn transform_super_in_private_method<'a>(
+    func: &mut Function<'a>,
+    class_binding: &BoundIdentifier<'a>,
+    is_static: bool,
+    transform_ctx: &TransformCtx<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    let mut converter = SuperConverter { transform_ctx, class_binding, is_static, ctx };
+    converter.visit_formal_parameters(&mut func.params);
+    if let Some(body) = &mut func.body {
+        converter.visit_function_body(body);
+    }
+}
+
+struct SuperConverter<'a, 'b> {
+    transform_ctx: &'b TransformCtx<'a>,
+    class_binding: &'b BoundIdentifier<'a>,
+    is_static: bool,
+    ctx: &'b mut TraverseCtx<'a>,
+}
+
+impl<'a, 'b> VisitMut<'a> for SuperConverter<'a, 'b> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            // `super.x(...)`
+            Expression::CallExpression(call) if is_super_member(&call.callee) => {
+                self.visit_super_member_key(&mut call.callee);
+                self.visit_arguments(&mut call.arguments);
+                *expr = self.transform_super_method_call(expr);
+            }
+            // `super.x = ...`
+            Expression::AssignmentExpression(assign)
+                if assign
+                    .left
+                    .as_member_expression()
+                    .is_some_and(|member| matches!(member.object(), Expression::Super(_))) =>
+            {
+                if let Some(MemberExpression::ComputedMemberExpression(member)) =
+                    assign.left.as_member_expression_mut()
+                {
+                    self.visit_expression(&mut member.expression);
+                }
+                self.visit_expression(&mut assign.right);
+                *expr = self.transform_super_assignment(expr);
+            }
+            // `super.x`
+            _ if is_super_member(expr) => {
+                self.visit_super_member_key(expr);
+                *expr = self.transform_super_member(expr);
+            }
+            _ => walk_mut::walk_expression(self, expr),
+        }
+    }
+
+    /// Any `super` which remains is in a position which is not supported,
+    /// e.g. `super.x++` or `[super.x] = arr`.
+    fn visit_super(&mut self, it: &mut Super) {
+        self.transform_ctx.error(
+            OxcDiagnostic::error("This use of `super` outside class body is not supported yet.")
+                .with_label(it.span),
+        );
+    }
+
+    fn visit_function(&mut self, _func: &mut Function<'a>, _flags: ScopeFlags) {}
+
+    // `super` has a different meaning in object methods and classes
+    fn visit_object_property(&mut self, prop: &mut ObjectProperty<'a>) {
+        if prop.method || prop.kind != PropertyKind::Init {
+            self.visit_property_key(&mut prop.key);
+        } else {
+            walk_mut::walk_object_property(self, prop);
+        }
+    }
+
+    fn visit_class(&mut self, _class: &mut Class<'a>) {}
+}
+
+impl<'a, 'b> SuperConverter<'a, 'b> {
+    /// Visit computed key of `super[key]`.
+    fn visit_super_member_key(&mut self, expr: &mut Expression<'a>) {
+        if let Expression::ComputedMemberExpression(member) = expr {
+            self.visit_expression(&mut member.expression);
+        }
+    }
+
+    /// `super.x(y)` -> `babelHelpers.superPropGet(C, "x", this, 3)([y])`
+    fn transform_super_method_call(&mut self, expr: &mut Expression<'a>) -> Expression<'a> {
+        let Expression::CallExpression(call) = self.ctx.ast.move_expression(expr) else {
+            unreachable!()
+        };
+        let CallExpression { span, mut callee, arguments, .. } = call.unbox();
+        let key = self.take_super_member_key(&mut callee);
+        let flags = if self.is_static { 2 } else { 3 };
+        let callee = self.create_super_prop_get(key, Some(flags));
+        let arguments = self.ctx.ast.vec1(Argument::from(self.create_array(arguments)));
+        self.ctx.ast.expression_call(span, callee, NONE, arguments, false)
+    }
+
+    /// `super.x` -> `babelHelpers.superPropGet(C, "x", this, 1)`
+    fn transform_super_member(&mut self, expr: &mut Expression<'a>) -> Expression<'a> {
+        let key = self.take_super_member_key(expr);
+        let flags = if self.is_static { None } else { Some(1) };
+        self.create_super_prop_get(key, flags)
+    }
+
+    /// `super.x = y` -> `babelHelpers.superPropSet(C, "x", y, this, 1, 1)`
+    /// `super.x += y` -> `babelHelpers.superPropSet(C, "x", babelHelpers.superPropGet(C, "x", this, 1) + y, this, 1, 1)`
+    fn transform_super_assignment(&mut self, expr: &mut Expression<'a>) -> Expression<'a> {
+        let Expression::AssignmentExpression(assign) = self.ctx.ast.move_expression(expr) else {
+            unreachable!()
+        };
+        let AssignmentExpression { span, operator, left, right } = assign.unbox();
+        let mut member = Expression::from(left.into_member_expression());
+        let key = self.take_super_member_key(&mut member);
+
+        let value = if operator == AssignmentOperator::Assign {
+            right
+        } else if let (Some(binary_operator), Expression::StringLiteral(lit)) =
+            (operator.to_binary_operator(), &key)
+        {
+            let get_key = self.ctx.ast.expression_string_literal(lit.span, lit.value.clone());
+            let flags = if self.is_static { None } else { Some(1) };
+            let current = self.create_super_prop_get(get_key, flags);
+            self.ctx.ast.expression_binary(SPAN, current, binary_operator, right)
+        } else {
+            let message = if operator.is_logical() {
+                "Logical assignments to `super` properties are not supported yet."
+            } else {
+                "Compound assignments to computed `super` properties are not supported yet."
+            };
+            self.transform_ctx.error(OxcDiagnostic::error(message).with_label(span));
+            right
+        };
+
+        let mut arguments = self.ctx.ast.vec_with_capacity(6);
+        arguments.push(Argument::from(self.class_binding.create_read_expression(self.ctx)));
+        arguments.push(Argument::from(key));
+        arguments.push(Argument::from(value));
+        arguments.push(Argument::from(self.ctx.ast.expression_this(SPAN)));
+        // `isStrict`. Class bodies are always strict mode.
+        arguments.push(Argument::from(self.create_number(1)));
+        if !self.is_static {
+            arguments.push(Argument::from(self.create_number(1)));
+        }
+        let set = self.transform_ctx.helper_call_expr(Helper::SuperPropSet, arguments, self.ctx);
+        let Expression::CallExpression(mut call) = set else { unreachable!() };
+        call.span = span;
+        Expression::CallExpression(call)
+    }
+
+    /// `babelHelpers.superPropGet(C, key, this, flags)`
+    fn create_super_prop_get(&mut self, key: Expression<'a>, flags: Option<u8>) -> Expression<'a> {
+        let mut arguments = self.ctx.ast.vec_with_capacity(4);
+        arguments.push(Argument::from(self.class_binding.create_read_expression(self.ctx)));
+        arguments.push(Argument::from(key));
+        arguments.push(Argument::from(self.ctx.ast.expression_this(SPAN)));
+        if let Some(flags) = flags {
+            arguments.push(Argument::from(self.create_number(flags)));
+        }
+        self.transform_ctx.helper_call_expr(Helper::SuperPropGet, arguments, self.ctx)
+    }
+
+    /// Get key of `super.x` / `super[x]` as an expression.
+    fn take_super_member_key(&mut self, expr: &mut Expression<'a>) -> Expression<'a> {
+        match self.ctx.ast.move_expression(expr) {
+            Expression::StaticMemberExpression(member) => {
+                let property = &member.property;
+                self.ctx.ast.expression_string_literal(property.span, property.name.clone())
+            }
+            Expression::ComputedMemberExpression(member) => member.unbox().expression,
+            _ => unreachable!(),
+        }
+    }
+
+    fn create_number(&self, value: u8) -> Expression<'a> {
+        let raw = self.ctx.ast.str(&value.to_string());
+        self.ctx.ast.expression_numeric_literal(SPAN, f64::from(value), raw, NumberBase::Decimal)
+    }
+
+    /// Convert call arguments to array `[a, ...b]`.
+    fn create_array(&self, arguments: oxc_allocator::Vec<'a, Argument<'a>>) -> Expression<'a> {
+        let elements =
+            self.ctx.ast.vec_from_iter(arguments.into_iter().map(|argument| match argument {
+                Argument::SpreadElement(spread) => ArrayExpressionElement::SpreadElement(spread),
+                argument => {
+                    self.ctx.ast.array_expression_element_expression(argument.into_expression())
+                }
+            }));
+        let span = elements.first().map_or(SPAN, GetSpan::span);
+        self.ctx.ast.expression_array(span, elements, None)
+    }
+}
+
+/// Check if expression is `super.x` or `super[x]`.
+fn is_super_member(expr: &Expression) -> bool {
+    match expr {
+        Expression::StaticMemberExpression(member) => matches!(member.object, Expression::Super(_)),
+        Expression::ComputedMemberExpression(member) => {
+            matches!(member.object, Expression::Super(_))
+        }
+        _ => false,
+    }
+}
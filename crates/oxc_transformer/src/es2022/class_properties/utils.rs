@@ -0,0 +1,269 @@
+//! ES2022: Class Properties
+//! Utility functions.
+
+use std::cell::Cell;
+
+use oxc_ast::{ast::*, visit::walk_mut, Visit, VisitMut, NONE};
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::{
+    reference::ReferenceFlags,
+    scope::{ScopeFlags, ScopeId},
+    symbol::SymbolId,
+};
+use oxc_traverse::{BoundIdentifier, TraverseCtx};
+
+/// Create `IdentifierReference` for a global var (e.g. `Object`, `WeakMap`) as an `Expression`.
+pub(super) fn create_global_ident<'a>(
+    name: &'static str,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let symbol_id = ctx.scopes().find_binding(ctx.current_scope_id(), name);
+    let ident = ctx.create_reference_id(SPAN, Atom::from(name), symbol_id, ReferenceFlags::Read);
+    ctx.ast.expression_from_identifier_reference(ident)
+}
+
+/// Create `new WeakMap()` / `new WeakSet()` etc.
+pub(super) fn create_new_global<'a>(
+    name: &'static str,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let callee = create_global_ident(name, ctx);
+    ctx.ast.expression_new(SPAN, callee, ctx.ast.vec(), NONE)
+}
+
+/// Create `object.property` member expression.
+pub(super) fn create_member<'a>(
+    object: Expression<'a>,
+    property: &'static str,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    let property = ctx.ast.identifier_name(SPAN, Atom::from(property));
+    Expression::from(ctx.ast.member_expression_static(SPAN, object, property, false))
+}
+
+/// Create `Object.defineProperty(object, key, { <props> })`.
+pub(super) fn create_object_define_property<'a>(
+    object: Expression<'a>,
+    key: Expression<'a>,
+    props: impl IntoIterator<Item = (&'static str, Expression<'a>)>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let object_ident = create_global_ident("Object", ctx);
+    let callee = create_member(object_ident, "defineProperty", ctx);
+    let properties = ctx.ast.vec_from_iter(props.into_iter().map(|(name, value)| {
+        ctx.ast.object_property_kind_object_property(
+            SPAN,
+            PropertyKind::Init,
+            ctx.ast.property_key_identifier_name(SPAN, name),
+            value,
+            None,
+            false,
+            false,
+            false,
+        )
+    }));
+    let descriptor = ctx.ast.expression_object(SPAN, properties, None);
+    let arguments = ctx.ast.vec_from_iter([
+        Argument::from(object),
+        Argument::from(key),
+        Argument::from(descriptor),
+    ]);
+    ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+}
+
+/// Create `"#name"` string literal, used in error messages.
+pub(super) fn create_private_name_string<'a>(
+    name: &Atom<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    let text = ctx.ast.atom(&format!("#{name}"));
+    ctx.ast.expression_string_literal(SPAN, text)
+}
+
+/// Create `var <binding> = <init>;` statement.
+pub(super) fn create_var_statement<'a>(
+    binding: &BoundIdentifier<'a>,
+    init: Expression<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> Statement<'a> {
+    let kind = VariableDeclarationKind::Var;
+    let declarator = ctx.ast.variable_declarator(
+        SPAN,
+        kind,
+        binding.create_binding_pattern(ctx),
+        Some(init),
+        false,
+    );
+    Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+        SPAN,
+        kind,
+        ctx.ast.vec1(declarator),
+        false,
+    ))
+}
+
+/// Set parent of all scopes which are direct children of `expr`'s enclosing scope
+/// (i.e. scopes of functions, classes etc. within `expr`) to `parent_scope_id`.
+///
+/// Used when moving an expression to a different scope, e.g. moving a property initializer
+/// from class body into the constructor.
+pub(super) fn reparent_scopes<'a>(
+    expr: &mut Expression<'a>,
+    parent_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    ScopeReparenter::new(parent_scope_id, ctx).visit_expression(expr);
+}
+
+/// Set parent of function's scope to `parent_scope_id`.
+///
+/// Used when moving a private method out of class body.
+/// Function is no longer a method, so method-only scope flags are removed.
+pub(super) fn reparent_function_scope<'a>(
+    func: &mut Function<'a>,
+    parent_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    let scope_id = func.scope_id.get().unwrap();
+    ctx.scopes_mut().get_flags_mut(scope_id).remove(ScopeFlags::Modifiers);
+    ScopeReparenter::new(parent_scope_id, ctx).visit_function(func, ScopeFlags::Function);
+}
+
+/// Visitor which reparents scopes.
+///
+/// Class bodies are always strict mode. If code is moved out of class body into non-strict code,
+/// `StrictMode` flag is also removed from all scopes within it.
+struct ScopeReparenter<'a, 'ctx> {
+    parent_scope_id: ScopeId,
+    is_parent_strict: bool,
+    depth: u32,
+    ctx: &'ctx mut TraverseCtx<'a>,
+}
+
+impl<'a, 'ctx> ScopeReparenter<'a, 'ctx> {
+    fn new(parent_scope_id: ScopeId, ctx: &'ctx mut TraverseCtx<'a>) -> Self {
+        let is_parent_strict = ctx.scopes().get_flags(parent_scope_id).is_strict_mode();
+        Self { parent_scope_id, is_parent_strict, depth: 0, ctx }
+    }
+}
+
+impl<'a, 'ctx> VisitMut<'a> for ScopeReparenter<'a, 'ctx> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        let scope_id = scope_id.get().unwrap();
+        if self.depth == 0 {
+            self.ctx.scopes_mut().change_parent_id(scope_id, Some(self.parent_scope_id));
+        }
+        if !self.is_parent_strict {
+            self.ctx.scopes_mut().get_flags_mut(scope_id).remove(ScopeFlags::StrictMode);
+        }
+        self.depth += 1;
+    }
+
+    fn leave_scope(&mut self) {
+        self.depth -= 1;
+    }
+}
+
+/// Replace `this` in `expr` with a reference to `binding`.
+///
+/// Does not enter functions or classes, where `this` has a different meaning.
+/// Arrow functions are entered, as they inherit `this`.
+pub(super) fn replace_this_with<'a>(
+    expr: &mut Expression<'a>,
+    binding: &BoundIdentifier<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    ThisReplacer { binding, ctx }.visit_expression(expr);
+}
+
+struct ThisReplacer<'a, 'b> {
+    binding: &'b BoundIdentifier<'a>,
+    ctx: &'b mut TraverseCtx<'a>,
+}
+
+impl<'a, 'b> VisitMut<'a> for ThisReplacer<'a, 'b> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if let Expression::ThisExpression(this) = expr {
+            *expr = self.binding.create_spanned_read_expression(this.span, self.ctx);
+            return;
+        }
+        walk_mut::walk_expression(self, expr);
+    }
+
+    fn visit_function(&mut self, _func: &mut Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_class(&mut self, _class: &mut Class<'a>) {}
+}
+
+/// Replace references to the name of a class expression in `expr` with references to `binding`.
+///
+/// Name of a class expression is only in scope within the class itself, so references to it
+/// must be replaced when moving a static property initializer out of class body.
+pub(super) fn replace_class_name_with<'a>(
+    expr: &mut Expression<'a>,
+    symbol_id: SymbolId,
+    binding: &BoundIdentifier<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    ClassNameReplacer { symbol_id, binding, ctx }.visit_expression(expr);
+}
+
+/// Replace references to the name of a class expression in `func` with references to `binding`.
+///
+/// Used when moving a private method out of class body.
+pub(super) fn replace_class_name_in_function_with<'a>(
+    func: &mut Function<'a>,
+    symbol_id: SymbolId,
+    binding: &BoundIdentifier<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    ClassNameReplacer { symbol_id, binding, ctx }.visit_function(func, ScopeFlags::Function);
+}
+
+struct ClassNameReplacer<'a, 'b> {
+    symbol_id: SymbolId,
+    binding: &'b BoundIdentifier<'a>,
+    ctx: &'b mut TraverseCtx<'a>,
+}
+
+impl<'a, 'b> VisitMut<'a> for ClassNameReplacer<'a, 'b> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if let Expression::Identifier(ident) = expr {
+            let reference_id = ident.reference_id().unwrap();
+            let reference = self.ctx.symbols().get_reference(reference_id);
+            if reference.symbol_id() == Some(self.symbol_id) {
+                self.ctx.delete_reference_for_identifier(ident);
+                *expr = self.binding.create_spanned_read_expression(ident.span, self.ctx);
+            }
+            return;
+        }
+        walk_mut::walk_expression(self, expr);
+    }
+}
+
+/// Check if params or body of `func` contain `super`.
+///
+/// Does not enter functions or classes, where `super` has a different meaning.
+/// Arrow functions are entered, as they inherit `super`.
+pub(super) fn find_super_in_function(func: &Function) -> bool {
+    let mut finder = SuperFinder { found: false };
+    finder.visit_formal_parameters(&func.params);
+    if let Some(body) = &func.body {
+        finder.visit_function_body(body);
+    }
+    finder.found
+}
+
+struct SuperFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for SuperFinder {
+    fn visit_super(&mut self, _it: &Super) {
+        self.found = true;
+    }
+
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_class(&mut self, _class: &Class<'a>) {}
+}
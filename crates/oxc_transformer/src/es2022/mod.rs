@@ -1,29 +1,80 @@
 use oxc_ast::ast::*;
 use oxc_traverse::{Traverse, TraverseCtx};
 
+use crate::TransformCtx;
+
+mod class_properties;
 mod class_static_block;
 mod options;
 
+use class_properties::ClassProperties;
 use class_static_block::ClassStaticBlock;
 
+pub use class_properties::ClassPropertiesOptions;
 pub use options::ES2022Options;
 
-pub struct ES2022 {
+pub struct ES2022<'a, 'ctx> {
     options: ES2022Options,
     // Plugins
     class_static_block: ClassStaticBlock,
+    class_properties: Option<ClassProperties<'a, 'ctx>>,
 }
 
-impl ES2022 {
-    pub fn new(options: ES2022Options) -> Self {
-        Self { options, class_static_block: ClassStaticBlock::new() }
+impl<'a, 'ctx> ES2022<'a, 'ctx> {
+    pub fn new(options: ES2022Options, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            class_static_block: ClassStaticBlock::new(),
+            class_properties: options
+                .class_properties
+                .map(|options| ClassProperties::new(options, ctx)),
+            options,
+        }
     }
 }
 
-impl<'a> Traverse<'a> for ES2022 {
-    fn enter_class_body(&mut self, body: &mut ClassBody<'a>, ctx: &mut TraverseCtx<'a>) {
+impl<'a, 'ctx> Traverse<'a> for ES2022<'a, 'ctx> {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(class_properties) = &mut self.class_properties {
+            class_properties.enter_expression(expr, ctx);
+        }
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(class_properties) = &mut self.class_properties {
+            class_properties.exit_expression(expr, ctx);
+        }
+    }
+
+    fn enter_assignment_target(
+        &mut self,
+        target: &mut AssignmentTarget<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(class_properties) = &mut self.class_properties {
+            class_properties.enter_assignment_target(target, ctx);
+        }
+    }
+
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(class_properties) = &mut self.class_properties {
+            class_properties.exit_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        // Static blocks are converted to private fields first, so class properties transform
+        // lowers them along with the rest of the class's static properties
         if self.options.class_static_block {
-            self.class_static_block.enter_class_body(body, ctx);
+            self.class_static_block.enter_class_body(&mut class.body, ctx);
+        }
+        if let Some(class_properties) = &mut self.class_properties {
+            class_properties.enter_class(class, ctx);
+        }
+    }
+
+    fn exit_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(class_properties) = &mut self.class_properties {
+            class_properties.exit_class(class, ctx);
         }
     }
 }
@@ -2,11 +2,16 @@ use serde::Deserialize;
 
 use crate::env::{can_enable_plugin, Versions};
 
+use super::ClassPropertiesOptions;
+
 #[derive(Debug, Default, Clone, Deserialize)]
 #[serde(default, rename_all = "camelCase", deny_unknown_fields)]
 pub struct ES2022Options {
     #[serde(skip)]
     pub class_static_block: bool,
+
+    #[serde(skip)]
+    pub class_properties: Option<ClassPropertiesOptions>,
 }
 
 impl ES2022Options {
@@ -15,6 +20,11 @@ impl ES2022Options {
         self
     }
 
+    pub fn with_class_properties(&mut self, option: Option<ClassPropertiesOptions>) -> &mut Self {
+        self.class_properties = option;
+        self
+    }
+
     #[must_use]
     pub fn from_targets_and_bugfixes(targets: Option<&Versions>, bugfixes: bool) -> Self {
         Self {
@@ -23,6 +33,14 @@ impl ES2022Options {
                 targets,
                 bugfixes,
             ),
+            class_properties: [
+                "transform-class-properties",
+                "transform-private-methods",
+                "transform-private-property-in-object",
+            ]
+            .into_iter()
+            .any(|plugin_name| can_enable_plugin(plugin_name, targets, bugfixes))
+            .then(Default::default),
         }
     }
 }
@@ -0,0 +1,630 @@
+//! Explicit Resource Management
+//!
+//! This plugin transforms `using` and `await using` declarations to `try` statements
+//! which dispose of the resources when the block they're declared in exits.
+//!
+//! > This plugin is not included in `preset-env`, as the proposal is not part of the spec yet.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! {
+//!   using x = getResource();
+//!   await using y = getAsyncResource();
+//!   use(x, y);
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! try {
+//!   var _usingCtx = babelHelpers.usingCtx();
+//!   const x = _usingCtx.u(getResource());
+//!   const y = _usingCtx.a(getAsyncResource());
+//!   use(x, y);
+//! } catch (_) {
+//!   _usingCtx.e = _;
+//! } finally {
+//!   await _usingCtx.d();
+//! }
+//! ```
+//!
+//! ## Implementation
+//!
+//! Blocks, function bodies and class static blocks which contain `using` declarations have their
+//! statements moved into a `try` statement. `switch` statements with `using` declarations in their
+//! cases are wrapped in a `try` statement as a whole. `for (using x of y)` loops are first
+//! converted to `for (const _x of y) { using x = _x; }`.
+//!
+//! At top level of a program, `import` statements, function declarations and exports stay outside
+//! the `try` statement. Top level `let`, `const` and `using` declarations are converted to `var`,
+//! so they remain visible to those function declarations and exports.
+//!
+//! Implementation based on [@babel/plugin-transform-explicit-resource-management](https://babel.dev/docs/babel-plugin-transform-explicit-resource-management).
+//!
+//! ## Missing features
+//!
+//! * Exporting classes from a module which contains top level `using` declarations is not supported.
+//!
+//! ## References:
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-explicit-resource-management>
+//! * Explicit Resource Management TC39 proposal: <https://github.com/tc39/proposal-explicit-resource-management>
+
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::{ast::*, NONE};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::{
+    operator::AssignmentOperator,
+    scope::{ScopeFlags, ScopeId},
+    symbol::{SymbolFlags, SymbolId},
+};
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse, TraverseCtx};
+
+use crate::{
+    common::helper_loader::Helper,
+    es2015::{collect_bindings, current_hoist_scope_id, Destructuring},
+    TransformCtx,
+};
+
+mod options;
+
+pub use options::ExplicitResourceManagementOptions;
+
+pub struct ExplicitResourceManagement<'a, 'ctx> {
+    options: Option<ExplicitResourceManagementOptions>,
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> ExplicitResourceManagement<'a, 'ctx> {
+    pub fn new(
+        options: Option<ExplicitResourceManagementOptions>,
+        ctx: &'ctx TransformCtx<'a>,
+    ) -> Self {
+        Self { options, ctx }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for ExplicitResourceManagement<'a, 'ctx> {
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.is_none() {
+            return;
+        }
+        match stmt {
+            // `{ using x = y; }` -> `try { ... } catch (_) { ... } finally { ... }`
+            // Block is replaced by the `try` statement, so its scope is reused for the `try` block
+            Statement::BlockStatement(block) if has_using_declaration(&block.body) => {
+                let block_scope_id = block.scope_id.get().unwrap();
+                let body = ctx.ast.move_vec(&mut block.body);
+                let parent_scope_id = ctx.current_scope_id();
+                *stmt = self.create_try(
+                    body,
+                    block_scope_id,
+                    parent_scope_id,
+                    VariableDeclarationKind::Const,
+                    ctx,
+                );
+            }
+            Statement::ForOfStatement(for_of) => Self::transform_for_of(for_of, ctx),
+            Statement::SwitchStatement(switch)
+                if switch.cases.iter().any(|case| has_using_declaration(&case.consequent)) =>
+            {
+                self.transform_switch(stmt, ctx);
+            }
+            _ => {}
+        }
+    }
+
+    fn enter_statements(
+        &mut self,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.is_none() || !has_using_declaration(stmts) {
+            return;
+        }
+        match ctx.parent() {
+            Ancestor::ProgramBody(_) => self.transform_program_body(stmts, ctx),
+            Ancestor::FunctionBodyStatements(_)
+            | Ancestor::StaticBlockBody(_)
+            | Ancestor::BlockStatementBody(_) => self.transform_body(stmts, ctx),
+            _ => {}
+        }
+    }
+}
+
+impl<'a, 'ctx> ExplicitResourceManagement<'a, 'ctx> {
+    /// `for (using x of y) {}` -> `for (const _x of y) { using x = _x; }`
+    ///
+    /// The `using` declaration in loop body is then transformed along with the body.
+    fn transform_for_of(for_of: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let ForStatementLeft::VariableDeclaration(decl) = &mut for_of.left else { return };
+        let kind = decl.kind;
+        if !is_using(kind) {
+            return;
+        }
+        let for_scope_id = for_of.scope_id.get().unwrap();
+        let declarator = decl.declarations.first_mut().unwrap();
+        let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind else { return };
+
+        let temp = ctx.generate_uid(
+            &ident.name,
+            for_scope_id,
+            SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable,
+        );
+        let id = std::mem::replace(&mut declarator.id, temp.create_binding_pattern(ctx));
+        declarator.kind = VariableDeclarationKind::Const;
+        decl.kind = VariableDeclarationKind::Const;
+
+        let bindings = collect_bindings(&id);
+        let declarator = ctx.ast.variable_declarator(
+            SPAN,
+            kind,
+            id,
+            Some(temp.create_read_expression(ctx)),
+            false,
+        );
+        let using_decl = Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            kind,
+            ctx.ast.vec1(declarator),
+            false,
+        ));
+        Destructuring::insert_into_for_body(
+            &mut for_of.body,
+            using_decl,
+            for_scope_id,
+            &bindings,
+            ctx,
+        );
+    }
+
+    /// `switch (x) { case 1: using y = z; }` -> `try { ... switch (x) { case 1: const y = ... } } ...`
+    ///
+    /// Resources declared in any case are disposed of when the `switch` statement exits,
+    /// so the whole `switch` statement is wrapped in the `try` statement.
+    fn transform_switch(&self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Statement::SwitchStatement(switch) = stmt else { unreachable!() };
+        let switch_scope_id = switch.scope_id.get().unwrap();
+        let using_ctx = create_using_ctx_binding(ctx);
+        let mut is_async = false;
+        for case in switch.cases.iter_mut() {
+            is_async |= transform_using_declarations(
+                &mut case.consequent,
+                &using_ctx,
+                VariableDeclarationKind::Const,
+                switch_scope_id,
+                SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable,
+                ctx,
+            );
+        }
+
+        let parent_scope_id = ctx.current_scope_id();
+        let switch = ctx.ast.move_statement(stmt);
+        let block_scope_id = ctx.insert_scope_below_statement(&switch, ScopeFlags::empty());
+        *stmt = self.wrap_in_try(
+            ctx.ast.vec1(switch),
+            &using_ctx,
+            is_async,
+            block_scope_id,
+            parent_scope_id,
+            ctx,
+        );
+    }
+
+    /// Move statements of a function body, class static block, or a block which is not
+    /// a statement (e.g. `try` block) into a `try` statement.
+    fn transform_body(&self, stmts: &mut ArenaVec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
+        let parent_scope_id = ctx.current_scope_id();
+        let body = ctx.ast.move_vec(stmts);
+        let block_scope_id = ctx.insert_scope_below_statements(&body, ScopeFlags::empty());
+
+        // Bindings which were declared directly in the body are now declared in the `try` block
+        for stmt in &body {
+            let bindings = match stmt {
+                Statement::VariableDeclaration(decl) if decl.kind.is_lexical() => {
+                    decl.declarations.iter().flat_map(|d| collect_bindings(&d.id)).collect()
+                }
+                Statement::ClassDeclaration(class) => binding_of(class.id.as_ref()),
+                Statement::FunctionDeclaration(func) => binding_of(func.id.as_ref()),
+                _ => vec![],
+            };
+            for (name, symbol_id) in bindings {
+                move_binding(&name, symbol_id, block_scope_id, None, ctx);
+            }
+        }
+
+        let try_stmt = self.create_try(
+            body,
+            block_scope_id,
+            parent_scope_id,
+            VariableDeclarationKind::Const,
+            ctx,
+        );
+        stmts.push(try_stmt);
+    }
+
+    /// Move top level statements of a program into a `try` statement.
+    ///
+    /// `import` statements, function declarations and exports remain at top level.
+    /// `let`, `const` and `using` declarations are converted to `var`.
+    fn transform_program_body(
+        &self,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let exports_class = stmts.iter().any(|stmt| match stmt {
+            Statement::ExportNamedDeclaration(export) => {
+                matches!(export.declaration, Some(Declaration::ClassDeclaration(_)))
+            }
+            Statement::ExportDefaultDeclaration(export) => {
+                matches!(export.declaration, ExportDefaultDeclarationKind::ClassDeclaration(_))
+            }
+            _ => false,
+        });
+        if exports_class {
+            self.ctx.error(OxcDiagnostic::error(
+                "Exporting classes from a module with top level `using` declarations is not supported yet",
+            ));
+            return;
+        }
+
+        let root_scope_id = ctx.current_scope_id();
+        let mut before = vec![];
+        let mut inside = ctx.ast.vec();
+        let mut after = vec![];
+        let mut default_exported = vec![];
+        for stmt in ctx.ast.move_vec(stmts) {
+            match stmt {
+                Statement::ImportDeclaration(_) | Statement::FunctionDeclaration(_) => {
+                    before.push(stmt);
+                }
+                Statement::ExportAllDeclaration(_) => after.push(stmt),
+                Statement::ExportNamedDeclaration(mut export) => match export.declaration.take() {
+                    // `export const x = 1;` -> `var x = 1;` + `export { x };`
+                    Some(Declaration::VariableDeclaration(decl)) => {
+                        after.push(Destructuring::create_export_specifiers(
+                            &decl,
+                            export.span,
+                            ctx,
+                        ));
+                        inside.push(Statement::VariableDeclaration(decl));
+                    }
+                    declaration => {
+                        export.declaration = declaration;
+                        let stmt = Statement::ExportNamedDeclaration(export);
+                        if export_is_hoisted(&stmt) {
+                            before.push(stmt);
+                        } else {
+                            after.push(stmt);
+                        }
+                    }
+                },
+                Statement::ExportDefaultDeclaration(mut export)
+                    if export.declaration.is_expression() =>
+                {
+                    // `export default x;` -> `var _default = x;` + `export { _default as default };`
+                    let binding = ctx.generate_uid(
+                        "default",
+                        root_scope_id,
+                        SymbolFlags::FunctionScopedVariable | SymbolFlags::Export,
+                    );
+                    let expr = ctx.ast.move_expression(export.declaration.to_expression_mut());
+                    if let Expression::Identifier(ident) = &expr {
+                        default_exported.push(ident.name.clone());
+                    }
+                    inside.push(create_var(&binding, expr, ctx));
+                    after.push(create_default_export(&binding, export.span, ctx));
+                }
+                Statement::ExportDefaultDeclaration(_) => before.push(stmt),
+                _ => inside.push(stmt),
+            }
+        }
+
+        // `export default x` marks `x` as exported, which it no longer is,
+        // unless it's also exported with `export { x }`
+        for name in default_exported {
+            let exported_elsewhere = after.iter().any(|stmt| {
+                matches!(stmt, Statement::ExportNamedDeclaration(export)
+                    if export.source.is_none()
+                        && export.specifiers.iter().any(|specifier| specifier.local.name() == name))
+            });
+            if exported_elsewhere {
+                continue;
+            }
+            if let Some(symbol_id) = ctx.scopes().get_binding(root_scope_id, &name) {
+                ctx.symbols_mut().get_flags_mut(symbol_id).remove(SymbolFlags::Export);
+            }
+        }
+
+        // Convert `let` and `const` to `var`. Bindings remain in root scope.
+        for stmt in inside.iter_mut() {
+            let Statement::VariableDeclaration(decl) = stmt else { continue };
+            if !decl.kind.is_lexical() {
+                continue;
+            }
+            decl.kind = VariableDeclarationKind::Var;
+            for declarator in decl.declarations.iter_mut() {
+                declarator.kind = VariableDeclarationKind::Var;
+                for (name, symbol_id) in collect_bindings(&declarator.id) {
+                    let flags = Some(SymbolFlags::FunctionScopedVariable);
+                    move_binding(&name, symbol_id, root_scope_id, flags, ctx);
+                }
+            }
+        }
+
+        // Classes are block-scoped, so are now declared in the `try` block
+        let block_scope_id = ctx.insert_scope_below_statements(&inside, ScopeFlags::empty());
+        for stmt in &inside {
+            if let Statement::ClassDeclaration(class) = stmt {
+                for (name, symbol_id) in binding_of(class.id.as_ref()) {
+                    move_binding(&name, symbol_id, block_scope_id, None, ctx);
+                }
+            }
+        }
+
+        let try_stmt = self.create_try(
+            inside,
+            block_scope_id,
+            root_scope_id,
+            VariableDeclarationKind::Var,
+            ctx,
+        );
+        stmts.extend(before);
+        stmts.push(try_stmt);
+        stmts.extend(after);
+    }
+
+    /// Convert `using` declarations in `body` to `kind` declarations, and wrap `body` in
+    /// a `try` statement which disposes of the resources.
+    ///
+    /// ```js
+    /// try {
+    ///   var _usingCtx = babelHelpers.usingCtx();
+    ///   const x = _usingCtx.u(y);
+    /// } catch (_) {
+    ///   _usingCtx.e = _;
+    /// } finally {
+    ///   _usingCtx.d();
+    /// }
+    /// ```
+    ///
+    /// `block_scope_id` is the scope for the `try` block. `parent_scope_id` is the scope
+    /// the `try` statement is in.
+    fn create_try(
+        &self,
+        mut body: ArenaVec<'a, Statement<'a>>,
+        block_scope_id: ScopeId,
+        parent_scope_id: ScopeId,
+        kind: VariableDeclarationKind,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let using_ctx = create_using_ctx_binding(ctx);
+        let (binding_scope_id, binding_flags) = if kind == VariableDeclarationKind::Var {
+            (current_hoist_scope_id(ctx), SymbolFlags::FunctionScopedVariable)
+        } else {
+            (block_scope_id, SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable)
+        };
+        let is_async = transform_using_declarations(
+            &mut body,
+            &using_ctx,
+            kind,
+            binding_scope_id,
+            binding_flags,
+            ctx,
+        );
+        self.wrap_in_try(body, &using_ctx, is_async, block_scope_id, parent_scope_id, ctx)
+    }
+
+    /// Wrap `body` in a `try` statement which disposes of the resources in `using_ctx`.
+    ///
+    /// `is_async` is `true` if any of the resources were declared with `await using`.
+    fn wrap_in_try(
+        &self,
+        mut body: ArenaVec<'a, Statement<'a>>,
+        using_ctx: &BoundIdentifier<'a>,
+        is_async: bool,
+        block_scope_id: ScopeId,
+        parent_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        // `var _usingCtx = babelHelpers.usingCtx();`
+        let helper = self.ctx.helper_call_expr(Helper::UsingCtx, ctx.ast.vec(), ctx);
+        body.insert(0, create_var(using_ctx, helper, ctx));
+        let block = ctx.ast.alloc_block_statement_with_scope_id(SPAN, body, block_scope_id);
+
+        // `catch (_) { _usingCtx.e = _; }`
+        // Catch param binding is in scope of catch body, same as in `SemanticBuilder`
+        let catch_scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::CatchClause);
+        let catch_body_scope_id = ctx.create_child_scope(catch_scope_id, ScopeFlags::empty());
+        let error = ctx.generate_uid(
+            "",
+            catch_body_scope_id,
+            SymbolFlags::FunctionScopedVariable | SymbolFlags::CatchVariable,
+        );
+        let param = ctx.ast.catch_parameter(SPAN, error.create_binding_pattern(ctx));
+        let target = SimpleAssignmentTarget::from(ctx.ast.member_expression_static(
+            SPAN,
+            using_ctx.create_read_expression(ctx),
+            ctx.ast.identifier_name(SPAN, "e"),
+            false,
+        ));
+        let assignment = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            AssignmentTarget::from(target),
+            error.create_read_expression(ctx),
+        );
+        let catch_body = ctx.ast.alloc_block_statement_with_scope_id(
+            SPAN,
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, assignment)),
+            catch_body_scope_id,
+        );
+        let handler =
+            ctx.ast.alloc_catch_clause_with_scope_id(SPAN, Some(param), catch_body, catch_scope_id);
+
+        // `finally { _usingCtx.d(); }` or `finally { await _usingCtx.d(); }`
+        let mut dispose = create_method_call(using_ctx, "d", ctx.ast.vec(), ctx);
+        if is_async {
+            dispose = ctx.ast.expression_await(SPAN, dispose);
+        }
+        let finally_scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+        let finalizer = ctx.ast.alloc_block_statement_with_scope_id(
+            SPAN,
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, dispose)),
+            finally_scope_id,
+        );
+
+        ctx.ast.statement_try(SPAN, block, Some(handler), Some(finalizer))
+    }
+}
+
+/// Create binding for the `_usingCtx` var, in current hoist scope.
+fn create_using_ctx_binding<'a>(ctx: &mut TraverseCtx<'a>) -> BoundIdentifier<'a> {
+    let hoist_scope_id = current_hoist_scope_id(ctx);
+    ctx.generate_uid("usingCtx", hoist_scope_id, SymbolFlags::FunctionScopedVariable)
+}
+
+/// Convert `using` declarations in `stmts` to `kind` declarations, with bindings declared
+/// in `binding_scope_id` with `binding_flags`.
+///
+/// `using x = y;` -> `const x = _usingCtx.u(y);`
+/// `await using x = y;` -> `const x = _usingCtx.a(y);`
+///
+/// Returns `true` if any of the declarations were `await using`.
+fn transform_using_declarations<'a>(
+    stmts: &mut [Statement<'a>],
+    using_ctx: &BoundIdentifier<'a>,
+    kind: VariableDeclarationKind,
+    binding_scope_id: ScopeId,
+    binding_flags: SymbolFlags,
+    ctx: &mut TraverseCtx<'a>,
+) -> bool {
+    let mut is_async = false;
+    for stmt in stmts {
+        let Statement::VariableDeclaration(decl) = stmt else { continue };
+        if !is_using(decl.kind) {
+            continue;
+        }
+        let method = if decl.kind == VariableDeclarationKind::AwaitUsing {
+            is_async = true;
+            "a"
+        } else {
+            "u"
+        };
+        decl.kind = kind;
+        for declarator in decl.declarations.iter_mut() {
+            declarator.kind = kind;
+            if let Some(init) = &mut declarator.init {
+                let value = ctx.ast.move_expression(init);
+                let arguments = ctx.ast.vec1(Argument::from(value));
+                *init = create_method_call(using_ctx, method, arguments, ctx);
+            }
+            for (name, symbol_id) in collect_bindings(&declarator.id) {
+                move_binding(&name, symbol_id, binding_scope_id, Some(binding_flags), ctx);
+            }
+        }
+    }
+    is_async
+}
+
+fn is_using(kind: VariableDeclarationKind) -> bool {
+    matches!(kind, VariableDeclarationKind::Using | VariableDeclarationKind::AwaitUsing)
+}
+
+fn has_using_declaration(stmts: &[Statement]) -> bool {
+    stmts
+        .iter()
+        .any(|stmt| matches!(stmt, Statement::VariableDeclaration(decl) if is_using(decl.kind)))
+}
+
+/// Returns `true` for exports which are hoisted: `export function f() {}`,
+/// `export default function() {}`, and TypeScript declarations.
+fn export_is_hoisted(stmt: &Statement) -> bool {
+    match stmt {
+        Statement::ExportNamedDeclaration(export) => export.declaration.is_some(),
+        Statement::ExportDefaultDeclaration(_) => true,
+        _ => false,
+    }
+}
+
+fn binding_of<'a>(id: Option<&BindingIdentifier<'a>>) -> Vec<(Atom<'a>, SymbolId)> {
+    id.map(|id| (id.name.clone(), id.symbol_id.get().unwrap())).into_iter().collect()
+}
+
+/// Move binding to `scope_id`, and update its flags if `flags` is provided.
+/// `Export` flag is preserved.
+fn move_binding<'a>(
+    name: &Atom<'a>,
+    symbol_id: SymbolId,
+    scope_id: ScopeId,
+    flags: Option<SymbolFlags>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    let current_scope_id = ctx.symbols().get_scope_id(symbol_id);
+    if current_scope_id != scope_id {
+        ctx.scopes_mut().move_binding(current_scope_id, scope_id, name);
+        ctx.symbols_mut().set_scope_id(symbol_id, scope_id);
+    }
+    if let Some(flags) = flags {
+        let symbol_flags = ctx.symbols_mut().get_flags_mut(symbol_id);
+        *symbol_flags = flags | (*symbol_flags & SymbolFlags::Export);
+    }
+}
+
+/// `_usingCtx.method(...arguments)`
+fn create_method_call<'a>(
+    using_ctx: &BoundIdentifier<'a>,
+    method: &'static str,
+    arguments: ArenaVec<'a, Argument<'a>>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let callee = Expression::from(ctx.ast.member_expression_static(
+        SPAN,
+        using_ctx.create_read_expression(ctx),
+        ctx.ast.identifier_name(SPAN, method),
+        false,
+    ));
+    ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+}
+
+/// `var binding = init;`
+fn create_var<'a>(
+    binding: &BoundIdentifier<'a>,
+    init: Expression<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> Statement<'a> {
+    let declarator = ctx.ast.variable_declarator(
+        SPAN,
+        VariableDeclarationKind::Var,
+        binding.create_binding_pattern(ctx),
+        Some(init),
+        false,
+    );
+    Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+        SPAN,
+        VariableDeclarationKind::Var,
+        ctx.ast.vec1(declarator),
+        false,
+    ))
+}
+
+/// `export { _default as default };`
+fn create_default_export<'a>(
+    binding: &BoundIdentifier<'a>,
+    span: Span,
+    ctx: &mut TraverseCtx<'a>,
+) -> Statement<'a> {
+    let local = ModuleExportName::IdentifierReference(binding.create_read_reference(ctx));
+    let exported = ModuleExportName::IdentifierName(ctx.ast.identifier_name(SPAN, "default"));
+    let specifier = ctx.ast.export_specifier(SPAN, local, exported, ImportOrExportKind::Value);
+    Statement::ExportNamedDeclaration(ctx.ast.alloc_export_named_declaration(
+        span,
+        None,
+        ctx.ast.vec1(specifier),
+        None,
+        ImportOrExportKind::Value,
+        NONE,
+    ))
+}
@@ -0,0 +1,10 @@
+use serde::Deserialize;
+
+/// Options for [`transform-explicit-resource-management`](https://babel.dev/docs/babel-plugin-transform-explicit-resource-management).
+///
+/// The plugin has no options at present.
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+// allow empty object for future compatibility
+#[allow(clippy::empty_structs_with_brackets)]
+pub struct ExplicitResourceManagementOptions {}
@@ -21,6 +21,9 @@ mod common;
 mod compiler_assumptions;
 mod context;
 mod options;
+// Proposals
+mod decorator;
+mod explicit_resource_management;
 // Presets: <https://babel.dev/docs/presets>
 mod env;
 mod es2015;
@@ -31,6 +34,8 @@ mod es2019;
 mod es2020;
 mod es2021;
 mod es2022;
+mod modules;
+mod polyfills;
 mod react;
 mod regexp;
 mod typescript;
@@ -39,6 +44,7 @@ mod plugins;
 
 use common::Common;
 use context::TransformCtx;
+use decorator::Decorator;
 use es2015::ES2015;
 use es2016::ES2016;
 use es2017::ES2017;
@@ -47,6 +53,9 @@ use es2019::ES2019;
 use es2020::ES2020;
 use es2021::ES2021;
 use es2022::ES2022;
+use explicit_resource_management::ExplicitResourceManagement;
+use modules::Modules;
+use polyfills::Polyfills;
 use react::React;
 use regexp::RegExp;
 use typescript::TypeScript;
@@ -54,12 +63,24 @@ use typescript::TypeScript;
 pub use crate::{
     common::helper_loader::HelperLoaderMode,
     compiler_assumptions::CompilerAssumptions,
+    decorator::{DecoratorOptions, DecoratorVersion},
     env::{EnvOptions, Targets},
-    es2015::{ArrowFunctionsOptions, ES2015Options},
+    es2015::{
+        ArrowFunctionsOptions, BlockScopingOptions, ClassesOptions, DestructuringOptions,
+        ES2015Options, ForOfOptions, ParametersOptions, RegeneratorOptions, SpreadOptions,
+        TemplateLiteralsOptions,
+    },
+    es2022::{ClassPropertiesOptions, ES2022Options},
+    explicit_resource_management::ExplicitResourceManagementOptions,
+    modules::{ImportInterop, ModuleFormat, ModulesOptions},
     options::{BabelOptions, TransformOptions},
     plugins::*,
+    polyfills::{CoreJsOptions, UseBuiltIns},
     react::{JsxOptions, JsxRuntime, ReactRefreshOptions},
-    typescript::{RewriteExtensionsMode, TypeScriptOptions},
+    typescript::{
+        ConstEnumMembers, ConstEnums, ConstantValue, ModuleConstEnums, RewriteExtensionsMode,
+        TypeScriptOptions,
+    },
 };
 
 pub struct TransformerReturn {
@@ -93,18 +114,46 @@ impl<'a> Transformer<'a> {
         self.ctx.source_text = program.source_text;
         react::update_options_with_comments(&program.comments, &mut self.options, &self.ctx);
 
+        let emotion_development = self.options.react.development;
         let mut transformer = TransformerImpl {
+            x0_tsconfig_paths: TsconfigPaths::new(
+                self.options.tsconfig_paths.clone(),
+                &self.options.cwd,
+                &self.ctx,
+            ),
             x0_typescript: TypeScript::new(&self.options.typescript, &self.ctx),
+            x0_decorator: Decorator::new(
+                self.options
+                    .decorator
+                    .or_else(|| DecoratorOptions::from_typescript(&self.options.typescript)),
+                &self.ctx,
+            ),
+            x0_explicit_resource_management: ExplicitResourceManagement::new(
+                self.options.explicit_resource_management,
+                &self.ctx,
+            ),
             x1_react: React::new(self.options.react, ast_builder, &self.ctx),
-            x2_es2022: ES2022::new(self.options.es2022),
+            x1_styled_components: StyledComponents::new(
+                self.options.styled_components.clone(),
+                &self.ctx,
+            ),
+            x1_emotion: Emotion::new(self.options.emotion.clone(), emotion_development, &self.ctx),
+            x2_polyfills: Polyfills::new(self.options.core_js.clone(), &self.ctx),
+            x2_es2022: ES2022::new(self.options.es2022, &self.ctx),
             x2_es2021: ES2021::new(self.options.es2021, &self.ctx),
             x2_es2020: ES2020::new(self.options.es2020, &self.ctx),
             x2_es2019: ES2019::new(self.options.es2019),
-            x2_es2018: ES2018::new(self.options.es2018, &self.ctx),
+            x2_es2018: ES2018::new(self.options.es2018, self.options.es2015.regenerator, &self.ctx),
             x2_es2016: ES2016::new(self.options.es2016, &self.ctx),
-            x2_es2017: ES2017::new(self.options.es2017, &self.ctx),
-            x3_es2015: ES2015::new(self.options.es2015),
+            x2_es2017: ES2017::new(
+                self.options.es2017,
+                self.options.es2015.regenerator,
+                self.options.es2015.classes.is_some(),
+                &self.ctx,
+            ),
+            x3_es2015: ES2015::new(self.options.es2015, &self.ctx),
             x4_regexp: RegExp::new(self.options.regexp, &self.ctx),
+            x5_modules: Modules::new(self.options.modules.clone(), &self.ctx),
             common: Common::new(&self.ctx),
         };
 
@@ -115,17 +164,24 @@ impl<'a> Transformer<'a> {
 
 struct TransformerImpl<'a, 'ctx> {
     // NOTE: all callbacks must run in order.
+    x0_tsconfig_paths: Option<TsconfigPaths>,
     x0_typescript: TypeScript<'a, 'ctx>,
+    x0_decorator: Decorator<'a, 'ctx>,
+    x0_explicit_resource_management: ExplicitResourceManagement<'a, 'ctx>,
     x1_react: React<'a, 'ctx>,
-    x2_es2022: ES2022,
+    x1_styled_components: StyledComponents<'a, 'ctx>,
+    x1_emotion: Emotion<'a, 'ctx>,
+    x2_polyfills: Polyfills<'a, 'ctx>,
+    x2_es2022: ES2022<'a, 'ctx>,
     x2_es2021: ES2021<'a, 'ctx>,
     x2_es2020: ES2020<'a, 'ctx>,
     x2_es2019: ES2019,
     x2_es2018: ES2018<'a, 'ctx>,
     x2_es2017: ES2017<'a, 'ctx>,
     x2_es2016: ES2016<'a, 'ctx>,
-    x3_es2015: ES2015<'a>,
+    x3_es2015: ES2015<'a, 'ctx>,
     x4_regexp: RegExp<'a, 'ctx>,
+    x5_modules: Modules<'a, 'ctx>,
     common: Common<'a, 'ctx>,
 }
 
@@ -133,13 +189,21 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
     fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
         self.x0_typescript.enter_program(program, ctx);
         self.x1_react.enter_program(program, ctx);
+        self.x1_styled_components.enter_program(program, ctx);
+        self.x1_emotion.enter_program(program, ctx);
+        self.x2_polyfills.enter_program(program, ctx);
+        self.x3_es2015.enter_program(program, ctx);
     }
 
     fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x0_decorator.exit_program(program, ctx);
         self.x1_react.exit_program(program, ctx);
         self.x0_typescript.exit_program(program, ctx);
         self.x3_es2015.exit_program(program, ctx);
+        self.x2_polyfills.exit_program(program, ctx);
         self.common.exit_program(program, ctx);
+        // Runs after common, as it transforms `import`s inserted by common transforms
+        self.x5_modules.exit_program(program, ctx);
     }
 
     // ALPHASORT
@@ -150,6 +214,8 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         self.x0_typescript.enter_arrow_function_expression(arrow, ctx);
+        self.x2_polyfills.enter_arrow_function_expression(arrow, ctx);
+        self.x3_es2015.enter_arrow_function_expression(arrow, ctx);
     }
 
     fn enter_variable_declarator(
@@ -160,22 +226,73 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         self.x0_typescript.enter_variable_declarator(decl, ctx);
     }
 
+    fn enter_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.x3_es2015.enter_variable_declaration(decl, ctx);
+    }
+
+    fn enter_binding_identifier(
+        &mut self,
+        ident: &mut BindingIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.x3_es2015.enter_binding_identifier(ident, ctx);
+    }
+
+    fn enter_identifier_reference(
+        &mut self,
+        ident: &mut IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.x3_es2015.enter_identifier_reference(ident, ctx);
+    }
+
+    fn enter_array_pattern(&mut self, pat: &mut ArrayPattern<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x2_polyfills.enter_array_pattern(pat, ctx);
+    }
+
+    fn enter_array_assignment_target(
+        &mut self,
+        target: &mut ArrayAssignmentTarget<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.x2_polyfills.enter_array_assignment_target(target, ctx);
+    }
+
+    fn enter_spread_element(&mut self, spread: &mut SpreadElement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x2_polyfills.enter_spread_element(spread, ctx);
+    }
+
     fn enter_binding_pattern(&mut self, pat: &mut BindingPattern<'a>, ctx: &mut TraverseCtx<'a>) {
         self.x0_typescript.enter_binding_pattern(pat, ctx);
     }
 
     fn enter_call_expression(&mut self, expr: &mut CallExpression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(tsconfig_paths) = &mut self.x0_tsconfig_paths {
+            tsconfig_paths.enter_call_expression(expr, ctx);
+        }
         self.x0_typescript.enter_call_expression(expr, ctx);
         self.x1_react.enter_call_expression(expr, ctx);
     }
 
     fn enter_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        // Decorators transform must run before TypeScript transform removes type annotations,
+        // which are used for decorator metadata
+        self.x0_decorator.enter_class(class, ctx);
         self.x0_typescript.enter_class(class, ctx);
+        self.x2_es2022.enter_class(class, ctx);
     }
 
     fn enter_class_body(&mut self, body: &mut ClassBody<'a>, ctx: &mut TraverseCtx<'a>) {
         self.x0_typescript.enter_class_body(body, ctx);
-        self.x2_es2022.enter_class_body(body, ctx);
+    }
+
+    fn exit_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x0_decorator.exit_class(class, ctx);
+        self.x2_es2022.exit_class(class, ctx);
     }
 
     fn enter_static_block(&mut self, block: &mut StaticBlock<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -197,6 +314,9 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
     #[inline]
     fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         self.x0_typescript.enter_expression(expr, ctx);
+        self.x0_decorator.enter_expression(expr, ctx);
+        self.x2_polyfills.enter_expression(expr, ctx);
+        self.x2_es2022.enter_expression(expr, ctx);
         self.x2_es2021.enter_expression(expr, ctx);
         self.x2_es2020.enter_expression(expr, ctx);
         self.x2_es2018.enter_expression(expr, ctx);
@@ -207,8 +327,14 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
 
     fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         self.x1_react.exit_expression(expr, ctx);
+        self.x1_styled_components.exit_expression(expr, ctx);
+        self.x1_emotion.exit_expression(expr, ctx);
+        self.x2_es2018.exit_expression(expr, ctx);
         self.x2_es2017.exit_expression(expr, ctx);
+        // ES2015 classes transform must run before ES2022 class properties transform wraps the class
+        // in a sequence expression, or the class would not be found
         self.x3_es2015.exit_expression(expr, ctx);
+        self.x2_es2022.exit_expression(expr, ctx);
     }
 
     fn enter_simple_assignment_target(
@@ -225,6 +351,7 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         self.x0_typescript.enter_assignment_target(node, ctx);
+        self.x2_es2022.enter_assignment_target(node, ctx);
     }
 
     fn enter_formal_parameter(
@@ -236,12 +363,14 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
     }
 
     fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x2_polyfills.enter_function(func, ctx);
         self.x3_es2015.enter_function(func, ctx);
     }
 
     fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
         self.x0_typescript.exit_function(func, ctx);
         self.x1_react.exit_function(func, ctx);
+        self.x2_es2018.exit_function(func, ctx);
         self.x2_es2017.exit_function(func, ctx);
         self.x3_es2015.exit_function(func, ctx);
     }
@@ -314,7 +443,9 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
     fn enter_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
         self.common.enter_statements(stmts, ctx);
         self.x0_typescript.enter_statements(stmts, ctx);
+        self.x0_explicit_resource_management.enter_statements(stmts, ctx);
         self.x1_react.enter_statements(stmts, ctx);
+        self.x3_es2015.enter_statements(stmts, ctx);
     }
 
     fn exit_arrow_function_expression(
@@ -344,12 +475,20 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
     fn exit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
         self.x0_typescript.exit_statements(stmts, ctx);
         self.x1_react.exit_statements(stmts, ctx);
+        self.x3_es2015.exit_statements(stmts, ctx);
         self.common.exit_statements(stmts, ctx);
     }
 
     fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
         self.x0_typescript.exit_statement(stmt, ctx);
+        // ES2015 classes transform must run before ES2022 class properties transform.
+        // See comment in `exit_expression`.
+        self.x3_es2015.exit_statement(stmt, ctx);
+        self.x2_es2022.exit_statement(stmt, ctx);
         self.x2_es2017.exit_statement(stmt, ctx);
+        // Decorations must be inserted after statements which ES2022 class properties transform
+        // inserts after the class, so static properties are defined before class is decorated
+        self.x0_decorator.exit_statement(stmt, ctx);
     }
 
     fn enter_tagged_template_expression(
@@ -362,12 +501,28 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
 
     fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
         self.x0_typescript.enter_statement(stmt, ctx);
+        self.x0_decorator.enter_statement(stmt, ctx);
+        self.x0_explicit_resource_management.enter_statement(stmt, ctx);
+        self.x2_es2018.enter_statement(stmt, ctx);
+        self.x3_es2015.enter_statement(stmt, ctx);
     }
 
     fn enter_declaration(&mut self, decl: &mut Declaration<'a>, ctx: &mut TraverseCtx<'a>) {
         self.x0_typescript.enter_declaration(decl, ctx);
     }
 
+    fn enter_block_statement(&mut self, block: &mut BlockStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x3_es2015.enter_block_statement(block, ctx);
+    }
+
+    fn enter_switch_statement(
+        &mut self,
+        stmt: &mut SwitchStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.x3_es2015.enter_switch_statement(stmt, ctx);
+    }
+
     fn enter_if_statement(&mut self, stmt: &mut IfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
         self.x0_typescript.enter_if_statement(stmt, ctx);
     }
@@ -386,18 +541,23 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
 
     fn enter_for_statement(&mut self, stmt: &mut ForStatement<'a>, ctx: &mut TraverseCtx<'a>) {
         self.x0_typescript.enter_for_statement(stmt, ctx);
+        self.x3_es2015.enter_for_statement(stmt, ctx);
     }
 
     fn enter_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
         self.x0_typescript.enter_for_of_statement(stmt, ctx);
+        self.x2_polyfills.enter_for_of_statement(stmt, ctx);
+        self.x3_es2015.enter_for_of_statement(stmt, ctx);
     }
 
     fn enter_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
         self.x0_typescript.enter_for_in_statement(stmt, ctx);
+        self.x3_es2015.enter_for_in_statement(stmt, ctx);
     }
 
     fn enter_catch_clause(&mut self, clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
         self.x2_es2019.enter_catch_clause(clause, ctx);
+        self.x3_es2015.enter_catch_clause(clause, ctx);
     }
 
     fn enter_import_declaration(
@@ -405,6 +565,9 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         node: &mut ImportDeclaration<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        if let Some(tsconfig_paths) = &mut self.x0_tsconfig_paths {
+            tsconfig_paths.enter_import_declaration(node, ctx);
+        }
         self.x0_typescript.enter_import_declaration(node, ctx);
     }
 
@@ -413,6 +576,9 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         node: &mut ExportAllDeclaration<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        if let Some(tsconfig_paths) = &mut self.x0_tsconfig_paths {
+            tsconfig_paths.enter_export_all_declaration(node, ctx);
+        }
         self.x0_typescript.enter_export_all_declaration(node, ctx);
     }
 
@@ -421,9 +587,22 @@ impl<'a, 'ctx> Traverse<'a> for TransformerImpl<'a, 'ctx> {
         node: &mut ExportNamedDeclaration<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        if let Some(tsconfig_paths) = &mut self.x0_tsconfig_paths {
+            tsconfig_paths.enter_export_named_declaration(node, ctx);
+        }
         self.x0_typescript.enter_export_named_declaration(node, ctx);
     }
 
+    fn enter_import_expression(
+        &mut self,
+        expr: &mut ImportExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(tsconfig_paths) = &mut self.x0_tsconfig_paths {
+            tsconfig_paths.enter_import_expression(expr, ctx);
+        }
+    }
+
     fn enter_ts_export_assignment(
         &mut self,
         export_assignment: &mut TSExportAssignment<'a>,
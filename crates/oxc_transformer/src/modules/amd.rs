@@ -0,0 +1,195 @@
+//! ES Modules to AMD
+//!
+//! This plugin transforms ES module syntax to [AMD](https://github.com/amdjs/amdjs-api) modules.
+//!
+//! > This plugin is included in `preset-env`, when `modules` option is `"amd"`.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! import foo from "foo";
+//! export const x = foo();
+//! ```
+//!
+//! Output:
+//! ```js
+//! define(["exports", "foo"], function (_exports, _foo) {
+//!   "use strict";
+//!
+//!   Object.defineProperty(_exports, "__esModule", { value: true });
+//!   _exports.x = void 0;
+//!   _foo = _interopRequireDefault(_foo);
+//!   const x = _exports.x = (0, _foo.default)();
+//! });
+//! ```
+//!
+//! ## Options
+//!
+//! Same as [CommonJS transform](super::commonjs), plus:
+//!
+//! * `moduleId`: Name of the module, passed as first argument to `define`.
+//!
+//! ## Implementation
+//!
+//! Module body is transformed the same as by the CommonJS transform,
+//! and then wrapped in a factory function, whose parameters are the dependencies.
+//!
+//! Implementation based on [@babel/plugin-transform-modules-amd](https://babel.dev/docs/babel-plugin-transform-modules-amd).
+//!
+//! ## Missing features
+//!
+//! * Dynamic `import()` is not transformed.
+//! * All missing features of the CommonJS transform.
+//!
+//! ## References
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-modules-amd>
+
+use std::cell::Cell;
+
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::{ast::*, Visit};
+use oxc_semantic::SymbolFlags;
+use oxc_span::{Atom, CompactStr, SPAN};
+use oxc_syntax::{scope::ScopeFlags, scope::ScopeId, symbol::SymbolId};
+use oxc_traverse::{Traverse, TraverseCtx};
+
+use crate::TransformCtx;
+
+use super::{
+    commonjs::{create_binding_pattern, ModuleTransform, TransformedModule},
+    options::ModulesOptions,
+    utils::{add_use_strict, create_call, create_function, create_function_scope, create_global},
+};
+
+pub struct Amd<'a, 'ctx> {
+    options: ModulesOptions,
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> Amd<'a, 'ctx> {
+    pub fn new(options: ModulesOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { options, ctx }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for Amd<'a, 'ctx> {
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Some(module) =
+            ModuleTransform::new(&self.options, true, self.ctx).transform(program, ctx)
+        else {
+            return;
+        };
+
+        let directives = ctx.ast.move_vec(&mut program.directives);
+        let factory = create_factory(module, directives, self.options.strict_mode, ctx);
+
+        // `define("id", ["exports", "foo"], function (_exports, _foo) { ... });`
+        let mut arguments = vec![];
+        if let Some(module_id) = &self.options.module_id {
+            arguments.push(ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(module_id)));
+        }
+        arguments.push(factory.create_dependencies_array(ctx));
+        arguments.push(factory.function);
+        let define = create_global("define", ctx);
+        let call = create_call(define, arguments, ctx);
+        program.body.push(ctx.ast.statement_expression(SPAN, call));
+    }
+}
+
+/// Module wrapped in a factory function.
+pub(super) struct Factory<'a> {
+    /// `function (_exports, _foo) { ... }`
+    pub function: Expression<'a>,
+    /// Specifiers of dependencies, in order of factory function's parameters.
+    /// Includes `"exports"`, if module has exports.
+    pub dependencies: Vec<Atom<'a>>,
+    /// `true` if module has exports, and first parameter is `_exports`.
+    pub has_exports: bool,
+}
+
+impl<'a> Factory<'a> {
+    /// Create `["exports", "foo"]` array of dependencies.
+    pub fn create_dependencies_array(&self, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+        let elements = ctx.ast.vec_from_iter(self.dependencies.iter().map(|specifier| {
+            ArrayExpressionElement::from(ctx.ast.expression_string_literal(SPAN, specifier.clone()))
+        }));
+        ctx.ast.expression_array(SPAN, elements, None)
+    }
+}
+
+/// Wrap transformed module body in a factory function `function (_exports, _foo) { ... }`.
+///
+/// All top level bindings and scopes are moved into the factory function's scope.
+pub(super) fn create_factory<'a>(
+    module: TransformedModule<'a>,
+    mut directives: ArenaVec<'a, Directive<'a>>,
+    strict_mode: bool,
+    ctx: &mut TraverseCtx<'a>,
+) -> Factory<'a> {
+    let root_scope_id = ctx.scopes().root_scope_id();
+    let scope_id = create_function_scope(root_scope_id, ctx);
+
+    let mut finder = TopLevelScopesFinder { depth: 0, scope_ids: vec![] };
+    finder.visit_statements(&module.body);
+    for child_scope_id in finder.scope_ids {
+        ctx.scopes_mut().change_parent_id(child_scope_id, Some(scope_id));
+    }
+
+    let bindings = ctx
+        .scopes()
+        .get_bindings(root_scope_id)
+        .iter()
+        .map(|(name, &symbol_id)| (name.clone(), symbol_id))
+        .collect::<Vec<(CompactStr, SymbolId)>>();
+    for (name, symbol_id) in bindings {
+        ctx.scopes_mut().move_binding(root_scope_id, scope_id, &name);
+        ctx.symbols_mut().set_scope_id(symbol_id, scope_id);
+        // Function declarations in a function are var-scoped
+        let flags = ctx.symbols_mut().get_flags_mut(symbol_id);
+        if flags.contains(SymbolFlags::Function | SymbolFlags::BlockScopedVariable) {
+            *flags = SymbolFlags::FunctionScopedVariable;
+        }
+    }
+
+    let mut dependencies = vec![];
+    let mut params = ctx.ast.vec();
+    if let Some(exports) = &module.exports {
+        dependencies.push(Atom::from("exports"));
+        params.push(ctx.ast.plain_formal_parameter(SPAN, exports.create_binding_pattern(ctx)));
+    }
+    for dependency in &module.dependencies {
+        let binding = dependency.binding.as_ref().unwrap();
+        dependencies.push(dependency.specifier.clone());
+        params.push(ctx.ast.plain_formal_parameter(SPAN, create_binding_pattern(binding, ctx)));
+    }
+
+    if strict_mode {
+        add_use_strict(&mut directives, ctx);
+    }
+    let function = create_function(scope_id, params, directives, module.body, ctx);
+    Factory { function, dependencies, has_exports: module.exports.is_some() }
+}
+
+/// Visitor to find scopes which are children of root scope.
+struct TopLevelScopesFinder {
+    depth: usize,
+    scope_ids: Vec<ScopeId>,
+}
+
+impl<'a> Visit<'a> for TopLevelScopesFinder {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        if self.depth == 0 {
+            // Inlined helpers have no scopes
+            if let Some(scope_id) = scope_id.get() {
+                self.scope_ids.push(scope_id);
+            }
+        }
+        self.depth += 1;
+    }
+
+    fn leave_scope(&mut self) {
+        self.depth -= 1;
+    }
+}
@@ -0,0 +1,1750 @@
+//! ES Modules to CommonJS
+//!
+//! This plugin transforms ES module syntax (`import` / `export`) to CommonJS (`require` / `exports`).
+//!
+//! It is also the base of the AMD and UMD transforms, which produce the same module body,
+//! wrapped in a factory function.
+//!
+//! > This plugin is included in `preset-env`, when `modules` option is `"commonjs"` or `"cjs"`.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! import foo, { bar } from "foo";
+//! import * as baz from "baz";
+//! export { qux } from "qux";
+//! export let x = foo(bar, baz);
+//! x++;
+//! ```
+//!
+//! Output:
+//! ```js
+//! "use strict";
+//!
+//! Object.defineProperty(exports, "__esModule", { value: true });
+//! Object.defineProperty(exports, "qux", {
+//!   enumerable: true,
+//!   get: function () {
+//!     return _qux.qux;
+//!   }
+//! });
+//! exports.x = void 0;
+//! var _foo = _interopRequireWildcard(require("foo"));
+//! var baz = _interopRequireWildcard(require("baz"));
+//! var _qux = require("qux");
+//! let x = exports.x = (0, _foo.default)(_foo.bar, baz);
+//! exports.x = ++x;
+//! ```
+//!
+//! ## Options
+//!
+//! * `importInterop` / `noInterop`: How to interop with CommonJS modules.
+//!   `"babel"` (default) uses `_interopRequireDefault` and `_interopRequireWildcard` helpers.
+//!   `"node"` treats `module.exports` as the default export.
+//!   `"none"` uses required modules as is.
+//! * `loose`: Set `exports.__esModule` and star re-exports by assignment,
+//!   instead of `Object.defineProperty`.
+//! * `strict`: Do not add `__esModule` marker.
+//! * `strictMode`: Add `"use strict"` directive. Default `true`.
+//! * `allowTopLevelThis`: Do not rewrite top level `this` to `void 0`.
+//!
+//! ## Implementation
+//!
+//! Runs on exit of `Program`, after all other transforms, including the common transforms
+//! which insert `import` statements for helpers and JSX runtime.
+//!
+//! A [`ModuleRecord`] is built for the transformed program. It determines which modules are
+//! required, and in what order, what interop each requires, and what bindings are exported.
+//!
+//! Then:
+//! * `import` declarations and re-exports are removed, and replaced with `require` calls
+//!   at top of the module.
+//! * `export` declarations are unwrapped.
+//! * References to imported bindings are replaced with member expressions on the required module
+//!   (e.g. `_foo.bar`). Calls are rewritten to `(0, _foo.bar)()` so `this` is not bound to module.
+//! * Assignments to exported bindings also update `exports`, so exports are live.
+//! * Re-exports of imported bindings are defined as getters on `exports`, so they're live too.
+//!
+//! Implementation based on [@babel/plugin-transform-modules-commonjs](https://babel.dev/docs/babel-plugin-transform-modules-commonjs).
+//!
+//! ## Missing features
+//!
+//! * `lazy` option.
+//! * Exported bindings assigned as target of `for in` / `for of` loops do not update `exports`.
+//! * Exported bindings declared with destructuring in nested blocks do not update `exports`.
+//! * Assignments to imported bindings are not rewritten to throw an error.
+//! * `import.meta`.
+//!
+//! ## References
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-modules-commonjs>
+//! * Babel helper implementation: <https://github.com/babel/babel/tree/main/packages/babel-helper-module-transforms>
+
+use std::{cell::Cell, mem, path::PathBuf};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::{
+    ast::*,
+    visit::{walk, walk_mut},
+    Visit, VisitMut, NONE,
+};
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ModuleRecordBuilder, ReferenceFlags, SymbolFlags, SymbolTable};
+use oxc_span::{Atom, CompactStr, SPAN};
+use oxc_syntax::{
+    module_record::{
+        ExportExportName, ExportImportName, ExportLocalName, ImportEntry, ImportImportName,
+        ModuleRecord, NameSpan,
+    },
+    number::NumberBase,
+    scope::{ScopeFlags, ScopeId},
+    symbol::SymbolId,
+};
+use oxc_traverse::{BoundIdentifier, Traverse, TraverseCtx};
+
+use crate::{common::helper_loader::Helper, es2015::Destructuring, TransformCtx};
+
+use super::{
+    options::{ImportInterop, ModulesOptions},
+    utils::{
+        add_use_strict, create_assignment, create_call, create_define_property, create_function,
+        create_function_scope, create_getter, create_global, create_member_expression,
+        create_member_target, create_object, module_binding_name,
+    },
+};
+
+pub struct CommonJs<'a, 'ctx> {
+    options: ModulesOptions,
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> CommonJs<'a, 'ctx> {
+    pub fn new(options: ModulesOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { options, ctx }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for CommonJs<'a, 'ctx> {
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Some(module) =
+            ModuleTransform::new(&self.options, false, self.ctx).transform(program, ctx)
+        else {
+            return;
+        };
+        program.body = module.body;
+        if self.options.strict_mode {
+            add_use_strict(&mut program.directives, ctx);
+        }
+    }
+}
+
+/// How a required module is wrapped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Interop {
+    /// `require("foo")`
+    None,
+    /// `_interopRequireDefault(require("foo"))`
+    Default,
+    /// `_interopRequireWildcard(require("foo"))`
+    Wildcard,
+    /// `_interopRequireWildcard(require("foo"), true)`
+    NodeWildcard,
+}
+
+/// A module which is imported or re-exported from.
+pub(super) struct Dependency<'a> {
+    /// Module specifier e.g. `"foo"`
+    pub specifier: Atom<'a>,
+    /// Binding holding the module's exports.
+    /// `None` for modules which are only imported for side effects, in CommonJS output.
+    pub binding: Option<BoundIdentifier<'a>>,
+    interop: Interop,
+    /// `export * from "foo"`
+    star_export: bool,
+    /// `export * as ns from "foo"`
+    namespace_exports: Vec<Atom<'a>>,
+}
+
+/// Analysis of a dependency, before bindings are created for it.
+struct DependencyInfo {
+    specifier: CompactStr,
+    interop: Interop,
+    /// Name of existing import binding to use as the dependency's binding
+    reuse_binding: Option<CompactStr>,
+    has_bindings: bool,
+    star_export: bool,
+    namespace_exports: Vec<CompactStr>,
+}
+
+/// Where an imported binding's value comes from: `_foo.bar`, or `_foo` if `property` is `None`.
+#[derive(Clone)]
+struct ImportTarget<'a> {
+    dependency: usize,
+    property: Option<Atom<'a>>,
+}
+
+/// Module after transform.
+pub(super) struct TransformedModule<'a> {
+    /// Statements of module body
+    pub body: ArenaVec<'a, Statement<'a>>,
+    /// Modules which are imported from, in order
+    pub dependencies: Vec<Dependency<'a>>,
+    /// Binding for `_exports` parameter of factory function.
+    /// `None` if the module has no exports, or output is CommonJS.
+    pub exports: Option<BoundIdentifier<'a>>,
+}
+
+/// Transform of module body shared by CommonJS, AMD and UMD transforms.
+pub(super) struct ModuleTransform<'a, 'o, 'ctx> {
+    options: &'o ModulesOptions,
+    ctx: &'ctx TransformCtx<'a>,
+    /// `true` if output will be wrapped in a factory function (AMD and UMD).
+    /// Dependencies are parameters of the factory function, instead of being `require`d.
+    wrapped: bool,
+    /// Binding for `_exports` parameter of factory function
+    exports: Option<BoundIdentifier<'a>>,
+    dependencies: Vec<Dependency<'a>>,
+    /// Imported bindings
+    imports: FxHashMap<SymbolId, ImportTarget<'a>>,
+    /// Exported local bindings, and the names they're exported as
+    exported: FxHashMap<SymbolId, Vec<Atom<'a>>>,
+    /// Re-exports of imported bindings, defined as getters on `exports`
+    getters: Vec<(Atom<'a>, ImportTarget<'a>)>,
+    /// All names exported, excluding `export * from`
+    export_names: Vec<Atom<'a>>,
+    /// Exports which are initialized as `void 0` at top of module
+    void_exports: Vec<Atom<'a>>,
+    /// Exported function declarations, which are assigned to `exports` at top of module
+    function_exports: Vec<(Atom<'a>, BoundIdentifier<'a>)>,
+    /// Temp vars created while rewriting references
+    temps: Vec<BoundIdentifier<'a>>,
+}
+
+impl<'a, 'o, 'ctx> ModuleTransform<'a, 'o, 'ctx> {
+    pub fn new(options: &'o ModulesOptions, wrapped: bool, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            options,
+            ctx,
+            wrapped,
+            exports: None,
+            dependencies: vec![],
+            imports: FxHashMap::default(),
+            exported: FxHashMap::default(),
+            getters: vec![],
+            export_names: vec![],
+            void_exports: vec![],
+            function_exports: vec![],
+            temps: vec![],
+        }
+    }
+
+    /// Transform module.
+    ///
+    /// Returns `None` if the program is a script.
+    pub fn transform(
+        mut self,
+        program: &mut Program<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<TransformedModule<'a>> {
+        if program.source_type.is_script() {
+            return None;
+        }
+
+        let mut record = Self::build_module_record(program);
+        if self.load_helpers(&record, program, ctx) {
+            record = Self::build_module_record(program);
+        }
+
+        let has_exports = program.body.iter().any(|stmt| {
+            matches!(
+                stmt,
+                Statement::ExportNamedDeclaration(_)
+                    | Statement::ExportDefaultDeclaration(_)
+                    | Statement::ExportAllDeclaration(_)
+            )
+        });
+        if has_exports && self.wrapped {
+            self.exports = Some(
+                ctx.generate_uid_in_root_scope("exports", SymbolFlags::FunctionScopedVariable),
+            );
+        }
+
+        self.collect_dependencies(&record, ctx);
+        self.collect_exports(&record, ctx);
+
+        let stmts = ctx.ast.move_vec(&mut program.body);
+        let mut body = self.transform_statements(stmts, ctx);
+
+        ModuleReferenceRewriter::new(&mut self, ctx).visit_statements(&mut body);
+
+        self.remove_import_bindings(ctx);
+        Self::remove_export_flags(ctx);
+
+        let mut stmts = self.create_header(has_exports, ctx);
+        stmts.extend(body);
+
+        Some(TransformedModule {
+            body: stmts,
+            dependencies: self.dependencies,
+            exports: self.exports,
+        })
+    }
+
+    fn build_module_record(program: &Program<'a>) -> ModuleRecord {
+        let mut builder = ModuleRecordBuilder::new(PathBuf::new());
+        builder.visit(program);
+        builder.build()
+    }
+
+    /// Load interop helpers which will be required.
+    ///
+    /// This transform runs after common transforms have inserted helpers, so insert them into
+    /// the program here, before transforming it. Runtime helpers are inserted as `import`s,
+    /// which are transformed along with the rest of the module.
+    ///
+    /// Returns `true` if any helpers were loaded.
+    fn load_helpers(
+        &self,
+        record: &ModuleRecord,
+        program: &mut Program<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> bool {
+        let interops = self.analyze_dependencies(record);
+        let mut helpers = vec![];
+        if interops.iter().any(|info| info.interop == Interop::Default) {
+            helpers.push(Helper::InteropRequireDefault);
+        }
+        let needs_wildcard = interops
+            .iter()
+            .any(|info| matches!(info.interop, Interop::Wildcard | Interop::NodeWildcard))
+            || (!self.wrapped
+                && self.options.import_interop() != ImportInterop::None
+                && DynamicImportFinder::has_dynamic_import(program));
+        if needs_wildcard {
+            helpers.push(Helper::InteropRequireWildcard);
+        }
+        if helpers.is_empty() {
+            return false;
+        }
+
+        for helper in helpers {
+            // Only loading the helper here. References to it are created where it's called.
+            let callee = self.ctx.helper_load(helper, ctx);
+            match &callee {
+                Expression::Identifier(ident) => ctx.delete_reference_for_identifier(ident),
+                Expression::StaticMemberExpression(member) => {
+                    if let Expression::Identifier(ident) = &member.object {
+                        ctx.delete_reference_for_identifier(ident);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        self.ctx.module_imports.insert_into_program(self.ctx, ctx);
+        self.ctx.top_level_statements.insert_into_program(program);
+        true
+    }
+
+    /// Determine modules which are depended on, in order, and what interop each requires.
+    fn analyze_dependencies(&self, record: &ModuleRecord) -> Vec<DependencyInfo> {
+        let mut specifiers = record
+            .requested_modules
+            .iter()
+            .filter(|(_, requests)| requests.iter().any(|request| !request.is_type()))
+            .map(|(specifier, requests)| (specifier, requests[0].span().start))
+            .collect::<Vec<_>>();
+        specifiers.sort_unstable_by_key(|(_, start)| *start);
+
+        let mut infos = specifiers
+            .into_iter()
+            .map(|(specifier, _)| DependencyInfo {
+                specifier: specifier.clone(),
+                interop: Interop::None,
+                reuse_binding: None,
+                has_bindings: false,
+                star_export: false,
+                namespace_exports: vec![],
+            })
+            .collect::<Vec<_>>();
+        let indexes = infos
+            .iter()
+            .enumerate()
+            .map(|(index, info)| (info.specifier.clone(), index))
+            .collect::<FxHashMap<_, _>>();
+
+        // `(has_namespace, has_default, has_named)` for each dependency
+        let mut usages = vec![(false, false, false); infos.len()];
+
+        for entry in record.import_entries.iter().filter(|entry| !entry.is_type) {
+            let Some(&index) = indexes.get(entry.module_request.name()) else { continue };
+            let info = &mut infos[index];
+            let usage = &mut usages[index];
+            info.has_bindings = true;
+            match &entry.import_name {
+                ImportImportName::NamespaceObject => {
+                    usage.0 = true;
+                    info.reuse_binding.get_or_insert_with(|| entry.local_name.name().clone());
+                }
+                ImportImportName::Default(_) => {
+                    if self.ctx.helper_loader.is_runtime_helper_source(&info.specifier) {
+                        // `import _defineProperty from "@babel/runtime/helpers/defineProperty"`.
+                        // Runtime helpers are CommonJS modules exporting the helper function.
+                        info.reuse_binding = Some(entry.local_name.name().clone());
+                    } else {
+                        usage.1 = true;
+                    }
+                }
+                ImportImportName::Name(name) => {
+                    if name.name() == "default" {
+                        usage.1 = true;
+                    } else {
+                        usage.2 = true;
+                    }
+                }
+            }
+        }
+
+        for entry in &record.indirect_export_entries {
+            let Some(request) = &entry.module_request else { continue };
+            let Some(&index) = indexes.get(request.name()) else { continue };
+            let info = &mut infos[index];
+            let usage = &mut usages[index];
+            info.has_bindings = true;
+            match &entry.import_name {
+                ExportImportName::Name(name) => {
+                    if find_import_entry(record, request, name).is_some() {
+                        // Re-export of an imported binding. Interop determined by the import.
+                    } else if name.name() == "default" {
+                        usage.1 = true;
+                    } else {
+                        usage.2 = true;
+                    }
+                }
+                ExportImportName::All => {
+                    usage.0 = true;
+                    if let ExportExportName::Name(name) = &entry.export_name {
+                        info.namespace_exports.push(name.name().clone());
+                    }
+                }
+                ExportImportName::AllButDefault | ExportImportName::Null => {}
+            }
+        }
+
+        for entry in &record.star_export_entries {
+            let Some(request) = &entry.module_request else { continue };
+            let Some(&index) = indexes.get(request.name()) else { continue };
+            infos[index].star_export = true;
+            infos[index].has_bindings = true;
+        }
+
+        let import_interop = self.options.import_interop();
+        for (info, (has_namespace, has_default, has_named)) in infos.iter_mut().zip(usages) {
+            info.interop = match import_interop {
+                ImportInterop::Babel => {
+                    if has_namespace || (has_default && has_named) {
+                        Interop::Wildcard
+                    } else if has_default {
+                        Interop::Default
+                    } else {
+                        Interop::None
+                    }
+                }
+                ImportInterop::Node if has_namespace => Interop::NodeWildcard,
+                ImportInterop::Node | ImportInterop::None => Interop::None,
+            };
+        }
+
+        infos
+    }
+
+    /// Create bindings for dependencies, and record imported bindings.
+    fn collect_dependencies(&mut self, record: &ModuleRecord, ctx: &mut TraverseCtx<'a>) {
+        let infos = self.analyze_dependencies(record);
+        let mut indexes = FxHashMap::default();
+        for (index, info) in infos.into_iter().enumerate() {
+            let binding = if let Some(name) = &info.reuse_binding {
+                // Convert `import * as ns from "foo"` binding to `var ns = require("foo")`
+                let symbol_id = ctx.scopes().get_root_binding(name).unwrap();
+                *ctx.symbols_mut().get_flags_mut(symbol_id) = SymbolFlags::FunctionScopedVariable;
+                Some(BoundIdentifier::new(ctx.ast.atom(name), symbol_id))
+            } else if info.has_bindings || self.wrapped {
+                let name = module_binding_name(&info.specifier);
+                Some(ctx.generate_uid_in_root_scope(&name, SymbolFlags::FunctionScopedVariable))
+            } else {
+                None
+            };
+            indexes.insert(info.specifier.clone(), index);
+            self.dependencies.push(Dependency {
+                specifier: ctx.ast.atom(&info.specifier),
+                binding,
+                interop: info.interop,
+                star_export: info.star_export,
+                namespace_exports: info
+                    .namespace_exports
+                    .iter()
+                    .map(|name| ctx.ast.atom(name))
+                    .collect(),
+            });
+        }
+
+        let node_interop = self.options.import_interop() == ImportInterop::Node;
+        for entry in record.import_entries.iter().filter(|entry| !entry.is_type) {
+            let Some(&dependency) = indexes.get(entry.module_request.name()) else { continue };
+            let Some(symbol_id) = ctx.scopes().get_root_binding(entry.local_name.name()) else {
+                continue;
+            };
+            let is_reused =
+                self.dependencies[dependency].binding.as_ref().unwrap().symbol_id == symbol_id;
+            let property = match &entry.import_name {
+                ImportImportName::NamespaceObject => None,
+                ImportImportName::Default(_) => {
+                    if node_interop || is_reused {
+                        None
+                    } else {
+                        Some(Atom::from("default"))
+                    }
+                }
+                ImportImportName::Name(name) => Some(ctx.ast.atom(name.name())),
+            };
+            self.imports.insert(symbol_id, ImportTarget { dependency, property });
+        }
+    }
+
+    /// Record exported bindings, and re-exports.
+    fn collect_exports(&mut self, record: &ModuleRecord, ctx: &TraverseCtx<'a>) {
+        for entry in &record.local_export_entries {
+            let export_name = match &entry.export_name {
+                ExportExportName::Name(name) => ctx.ast.atom(name.name()),
+                ExportExportName::Default(_) => Atom::from("default"),
+                ExportExportName::Null => continue,
+            };
+            self.export_names.push(export_name.clone());
+            // `export default expr` and anonymous default exports are handled in
+            // `transform_export_default`
+            let ExportLocalName::Name(local_name) = &entry.local_name else { continue };
+            let Some(symbol_id) = ctx.scopes().get_root_binding(local_name.name()) else {
+                continue;
+            };
+            if let Some(target) = self.imports.get(&symbol_id) {
+                // `import * as ns from "foo"; export { ns };`
+                self.getters.push((export_name, target.clone()));
+            } else {
+                self.exported.entry(symbol_id).or_default().push(export_name);
+            }
+        }
+
+        let node_interop = self.options.import_interop() == ImportInterop::Node;
+        for entry in &record.indirect_export_entries {
+            let Some(request) = &entry.module_request else { continue };
+            let ExportExportName::Name(export_name) = &entry.export_name else { continue };
+            let export_name = ctx.ast.atom(export_name.name());
+            self.export_names.push(export_name.clone());
+
+            let ExportImportName::Name(name) = &entry.import_name else { continue };
+            let target = if let Some(import_entry) = find_import_entry(record, request, name) {
+                // `import { foo } from "foo"; export { foo };`
+                let local_name = import_entry.local_name.name();
+                let Some(symbol_id) = ctx.scopes().get_root_binding(local_name) else { continue };
+                let Some(target) = self.imports.get(&symbol_id) else { continue };
+                target.clone()
+            } else {
+                // `export { foo } from "foo";`
+                let Some(dependency) = self
+                    .dependencies
+                    .iter()
+                    .position(|dependency| dependency.specifier == request.name().as_str())
+                else {
+                    continue;
+                };
+                let property = if node_interop && name.name() == "default" {
+                    None
+                } else {
+                    Some(ctx.ast.atom(name.name()))
+                };
+                ImportTarget { dependency, property }
+            };
+            self.getters.push((export_name, target));
+        }
+    }
+
+    /// Remove `import` and `export` declarations, and unwrap exported declarations.
+    fn transform_statements(
+        &mut self,
+        stmts: ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ArenaVec<'a, Statement<'a>> {
+        let mut body = ctx.ast.vec_with_capacity(stmts.len());
+        for stmt in stmts {
+            match stmt {
+                Statement::ImportDeclaration(_) | Statement::ExportAllDeclaration(_) => {}
+                Statement::ExportNamedDeclaration(decl) => {
+                    let decl = decl.unbox();
+                    if decl.source.is_none() {
+                        for specifier in &decl.specifiers {
+                            if let ModuleExportName::IdentifierReference(ident) = &specifier.local {
+                                ctx.delete_reference_for_identifier(ident);
+                            }
+                        }
+                    }
+                    if let Some(declaration) = decl.declaration {
+                        self.transform_declaration(Statement::from(declaration), &mut body, ctx);
+                    }
+                }
+                Statement::ExportDefaultDeclaration(decl) => {
+                    self.transform_export_default(decl.unbox().declaration, &mut body, ctx);
+                }
+                stmt => self.transform_declaration(stmt, &mut body, ctx),
+            }
+        }
+
+        // Exported bindings which are not declared at top level (`{ var x; } export { x };`)
+        let mut seen = self.void_exports.iter().cloned().collect::<FxHashSet<_>>();
+        seen.extend(self.function_exports.iter().map(|(name, _)| name.clone()));
+        for name in self.exported.values().flatten() {
+            if !seen.contains(name) {
+                self.void_exports.push(name.clone());
+            }
+        }
+
+        body
+    }
+
+    /// Record exports in a top level declaration, and push it to `body`.
+    fn transform_declaration(
+        &mut self,
+        stmt: Statement<'a>,
+        body: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        match &stmt {
+            Statement::FunctionDeclaration(func) => {
+                if let Some(id) = &func.id {
+                    let binding = BoundIdentifier::from_binding_ident(id);
+                    if let Some(names) = self.exported.get(&binding.symbol_id) {
+                        self.function_exports
+                            .extend(names.iter().map(|name| (name.clone(), binding.clone())));
+                    }
+                }
+                body.push(stmt);
+            }
+            Statement::ClassDeclaration(class) => {
+                // `export class C {}` -> `class C {} exports.C = C;`
+                let assignment = class.id.as_ref().and_then(|id| {
+                    let binding = BoundIdentifier::from_binding_ident(id);
+                    let names = self.exported.get(&binding.symbol_id)?.clone();
+                    self.void_exports.extend(names.iter().cloned());
+                    let value = binding.create_read_expression(ctx);
+                    let assignment = self.create_exports_assignments(&names, value, ctx);
+                    Some(ctx.ast.statement_expression(SPAN, assignment))
+                });
+                body.push(stmt);
+                if let Some(assignment) = assignment {
+                    body.push(assignment);
+                }
+            }
+            Statement::VariableDeclaration(decl) => {
+                // `export const x = 1` -> `const x = exports.x = 1` is handled in
+                // `ModuleReferenceRewriter`. Only destructuring needs handling here.
+                // `export const { x } = obj` -> `const { x } = obj; exports.x = x;`
+                let mut pattern_exports = vec![];
+                for declarator in &decl.declarations {
+                    let is_pattern = !declarator.id.kind.is_binding_identifier();
+                    declarator.id.bound_names(&mut |ident| {
+                        let binding = BoundIdentifier::from_binding_ident(ident);
+                        let Some(names) = self.exported.get(&binding.symbol_id) else { return };
+                        self.void_exports.extend(names.iter().cloned());
+                        if is_pattern {
+                            pattern_exports.push((names.clone(), binding));
+                        }
+                    });
+                }
+                body.push(stmt);
+                for (names, binding) in pattern_exports {
+                    let value = binding.create_read_expression(ctx);
+                    let assignment = self.create_exports_assignments(&names, value, ctx);
+                    body.push(ctx.ast.statement_expression(SPAN, assignment));
+                }
+            }
+            _ => body.push(stmt),
+        }
+    }
+
+    /// Transform `export default` declaration.
+    ///
+    /// * `export default function() {}` -> `function _default() {}` (assigned at top of module)
+    /// * `export default class {}` -> `class _default {} exports.default = _default;`
+    /// * `export default expr` -> `var _default = exports.default = expr;`
+    fn transform_export_default(
+        &mut self,
+        declaration: ExportDefaultDeclarationKind<'a>,
+        body: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        match declaration {
+            ExportDefaultDeclarationKind::FunctionDeclaration(mut func) => {
+                if func.id.is_none() {
+                    let binding = self.create_default_binding(
+                        SymbolFlags::Function | SymbolFlags::BlockScopedVariable,
+                        ctx,
+                    );
+                    func.id = Some(binding.create_binding_identifier(ctx));
+                }
+                self.transform_declaration(Statement::FunctionDeclaration(func), body, ctx);
+            }
+            ExportDefaultDeclarationKind::ClassDeclaration(mut class) => {
+                if class.id.is_none() {
+                    let binding = self.create_default_binding(SymbolFlags::Class, ctx);
+                    class.id = Some(binding.create_binding_identifier(ctx));
+                }
+                self.transform_declaration(Statement::ClassDeclaration(class), body, ctx);
+            }
+            ExportDefaultDeclarationKind::TSInterfaceDeclaration(_) => {}
+            declaration => {
+                let expr = declaration.into_expression();
+                let binding = self.create_default_binding(SymbolFlags::FunctionScopedVariable, ctx);
+                let declarator = ctx.ast.variable_declarator(
+                    SPAN,
+                    VariableDeclarationKind::Var,
+                    binding.create_binding_pattern(ctx),
+                    Some(expr),
+                    false,
+                );
+                let decl = ctx.ast.alloc_variable_declaration(
+                    SPAN,
+                    VariableDeclarationKind::Var,
+                    ctx.ast.vec1(declarator),
+                    false,
+                );
+                self.transform_declaration(Statement::VariableDeclaration(decl), body, ctx);
+            }
+        }
+    }
+
+    /// Create `_default` binding for default export, exported as `default`.
+    fn create_default_binding(
+        &mut self,
+        flags: SymbolFlags,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        let binding = ctx.generate_uid_in_root_scope("default", flags);
+        self.exported.insert(binding.symbol_id, vec![Atom::from("default")]);
+        binding
+    }
+
+    /// Remove bindings for imports from root scope. Their references have all been replaced.
+    fn remove_import_bindings(&self, ctx: &mut TraverseCtx<'a>) {
+        let root_scope_id = ctx.scopes().root_scope_id();
+        for (&symbol_id, target) in &self.imports {
+            let dependency = &self.dependencies[target.dependency];
+            if dependency.binding.as_ref().is_some_and(|binding| binding.symbol_id == symbol_id) {
+                continue;
+            }
+            let name = CompactStr::from(ctx.symbols().get_name(symbol_id));
+            ctx.scopes_mut().remove_binding(root_scope_id, &name);
+        }
+    }
+
+    /// Remove `Export` flag from top level bindings, as they're no longer exported by `export`.
+    fn remove_export_flags(ctx: &mut TraverseCtx<'a>) {
+        let root_scope_id = ctx.scopes().root_scope_id();
+        let symbol_ids =
+            ctx.scopes().get_bindings(root_scope_id).values().copied().collect::<Vec<_>>();
+        for symbol_id in symbol_ids {
+            ctx.symbols_mut().get_flags_mut(symbol_id).remove(SymbolFlags::Export);
+        }
+    }
+
+    /// Create statements at top of module:
+    ///
+    /// ```js
+    /// Object.defineProperty(exports, "__esModule", { value: true });
+    /// var _exportNames = { foo: true };
+    /// Object.defineProperty(exports, "foo", { enumerable: true, get: function () { return _foo.foo; } });
+    /// exports.f = f;
+    /// exports.x = void 0;
+    /// var _foo = require("foo");
+    /// Object.keys(_bar).forEach(function (key) { /* ... */ });
+    /// var _temp;
+    /// ```
+    fn create_header(
+        &mut self,
+        has_exports: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ArenaVec<'a, Statement<'a>> {
+        let mut stmts = ctx.ast.vec();
+
+        if has_exports && !self.options.strict {
+            stmts.push(self.create_es_module_marker(ctx));
+        }
+
+        let export_names_binding = if !self.export_names.is_empty()
+            && self.dependencies.iter().any(|dependency| dependency.star_export)
+        {
+            let binding =
+                ctx.generate_uid_in_root_scope("exportNames", SymbolFlags::FunctionScopedVariable);
+            let object = create_object(
+                self.export_names
+                    .iter()
+                    .map(|name| (name.clone(), ctx.ast.expression_boolean_literal(SPAN, true))),
+                ctx,
+            );
+            stmts.push(create_var_statement(&binding, Some(object), ctx));
+            Some(binding)
+        } else {
+            None
+        };
+
+        for (name, target) in mem::take(&mut self.getters) {
+            let getter = self.create_getter(&target, ctx);
+            let key = ctx.ast.expression_string_literal(SPAN, name);
+            let exports = self.create_exports_object(ctx);
+            let descriptor = create_object(
+                [
+                    (Atom::from("enumerable"), ctx.ast.expression_boolean_literal(SPAN, true)),
+                    (Atom::from("get"), getter),
+                ],
+                ctx,
+            );
+            let define_property = create_define_property(exports, key, descriptor, ctx);
+            stmts.push(ctx.ast.statement_expression(SPAN, define_property));
+        }
+
+        for (name, binding) in mem::take(&mut self.function_exports) {
+            let value = binding.create_read_expression(ctx);
+            let assignment = self.create_exports_assignments(&[name], value, ctx);
+            stmts.push(ctx.ast.statement_expression(SPAN, assignment));
+        }
+
+        let void_exports = mem::take(&mut self.void_exports);
+        if !void_exports.is_empty() {
+            let void_0 = ctx.ast.void_0(SPAN);
+            let assignment = self.create_exports_assignments(&void_exports, void_0, ctx);
+            stmts.push(ctx.ast.statement_expression(SPAN, assignment));
+        }
+
+        for index in 0..self.dependencies.len() {
+            self.create_dependency_statements(
+                index,
+                export_names_binding.as_ref(),
+                &mut stmts,
+                ctx,
+            );
+        }
+
+        if !self.temps.is_empty() {
+            let declarations = ctx.ast.vec_from_iter(self.temps.iter().map(|binding| {
+                ctx.ast.variable_declarator(
+                    SPAN,
+                    VariableDeclarationKind::Var,
+                    binding.create_binding_pattern(ctx),
+                    None,
+                    false,
+                )
+            }));
+            stmts.push(Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+                SPAN,
+                VariableDeclarationKind::Var,
+                declarations,
+                false,
+            )));
+        }
+
+        stmts
+    }
+
+    /// `Object.defineProperty(exports, "__esModule", { value: true });`
+    /// or `exports.__esModule = true;` in loose mode.
+    fn create_es_module_marker(&self, ctx: &mut TraverseCtx<'a>) -> Statement<'a> {
+        let exports = self.create_exports_object(ctx);
+        let value = ctx.ast.expression_boolean_literal(SPAN, true);
+        let expr = if self.options.loose {
+            let target = create_member_target(exports, Atom::from("__esModule"), ctx);
+            create_assignment(target, value, ctx)
+        } else {
+            let key = ctx.ast.expression_string_literal(SPAN, "__esModule");
+            let descriptor = create_object([(Atom::from("value"), value)], ctx);
+            create_define_property(exports, key, descriptor, ctx)
+        };
+        ctx.ast.statement_expression(SPAN, expr)
+    }
+
+    /// Create statements for a dependency.
+    ///
+    /// * `var _foo = _interopRequireDefault(require("foo"));` or
+    ///   `_foo = _interopRequireDefault(_foo);` in a factory function.
+    /// * Star re-exports.
+    /// * Namespace re-exports.
+    fn create_dependency_statements(
+        &self,
+        index: usize,
+        export_names_binding: Option<&BoundIdentifier<'a>>,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let dependency = &self.dependencies[index];
+        let Some(binding) = &dependency.binding else {
+            // `require("foo");`
+            let require = Self::create_require(dependency.specifier.clone(), ctx);
+            stmts.push(ctx.ast.statement_expression(SPAN, require));
+            return;
+        };
+
+        if self.wrapped {
+            if dependency.interop != Interop::None {
+                let value = binding.create_read_expression(ctx);
+                let value = self.create_interop(dependency.interop, value, ctx);
+                let target = binding.create_write_target(ctx);
+                let assignment = create_assignment(target, value, ctx);
+                stmts.push(ctx.ast.statement_expression(SPAN, assignment));
+            }
+        } else {
+            let value = Self::create_require(dependency.specifier.clone(), ctx);
+            let value = self.create_interop(dependency.interop, value, ctx);
+            stmts.push(create_var_statement(binding, Some(value), ctx));
+        }
+
+        if dependency.star_export {
+            let stmt = self.create_star_export(binding, export_names_binding, ctx);
+            stmts.push(stmt);
+        }
+
+        for name in &dependency.namespace_exports {
+            let value = binding.create_read_expression(ctx);
+            let assignment = self.create_exports_assignments(&[name.clone()], value, ctx);
+            stmts.push(ctx.ast.statement_expression(SPAN, assignment));
+        }
+    }
+
+    /// Create star re-export.
+    ///
+    /// ```js
+    /// Object.keys(_foo).forEach(function (key) {
+    ///   if (key === "default" || key === "__esModule") return;
+    ///   if (Object.prototype.hasOwnProperty.call(_exportNames, key)) return;
+    ///   if (key in exports && exports[key] === _foo[key]) return;
+    ///   Object.defineProperty(exports, key, {
+    ///     enumerable: true,
+    ///     get: function () {
+    ///       return _foo[key];
+    ///     }
+    ///   });
+    /// });
+    /// ```
+    ///
+    /// In loose mode, last statement is `exports[key] = _foo[key];`.
+    fn create_star_export(
+        &self,
+        binding: &BoundIdentifier<'a>,
+        export_names_binding: Option<&BoundIdentifier<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let root_scope_id = ctx.scopes().root_scope_id();
+        let scope_id = create_function_scope(root_scope_id, ctx);
+        let key =
+            ctx.generate_binding(Atom::from("key"), scope_id, SymbolFlags::FunctionScopedVariable);
+
+        let mut body = ctx.ast.vec();
+        let return_stmt = |ctx: &TraverseCtx<'a>| ctx.ast.statement_return(SPAN, None);
+
+        // `if (key === "default" || key === "__esModule") return;`
+        let is_default = create_strict_equality(
+            key.create_read_expression(ctx),
+            ctx.ast.expression_string_literal(SPAN, "default"),
+            ctx,
+        );
+        let is_es_module = create_strict_equality(
+            key.create_read_expression(ctx),
+            ctx.ast.expression_string_literal(SPAN, "__esModule"),
+            ctx,
+        );
+        let test = ctx.ast.expression_logical(SPAN, is_default, LogicalOperator::Or, is_es_module);
+        body.push(ctx.ast.statement_if(SPAN, test, return_stmt(ctx), None));
+
+        // `if (Object.prototype.hasOwnProperty.call(_exportNames, key)) return;`
+        if let Some(export_names_binding) = export_names_binding {
+            let callee = create_global("Object", ctx);
+            let callee = create_member_expression(callee, Atom::from("prototype"), ctx);
+            let callee = create_member_expression(callee, Atom::from("hasOwnProperty"), ctx);
+            let callee = create_member_expression(callee, Atom::from("call"), ctx);
+            let test = create_call(
+                callee,
+                [export_names_binding.create_read_expression(ctx), key.create_read_expression(ctx)],
+                ctx,
+            );
+            body.push(ctx.ast.statement_if(SPAN, test, return_stmt(ctx), None));
+        }
+
+        // `if (key in exports && exports[key] === _foo[key]) return;`
+        let in_exports = ctx.ast.expression_binary(
+            SPAN,
+            key.create_read_expression(ctx),
+            BinaryOperator::In,
+            self.create_exports_object(ctx),
+        );
+        let exports_value = Expression::from(ctx.ast.member_expression_computed(
+            SPAN,
+            self.create_exports_object(ctx),
+            key.create_read_expression(ctx),
+            false,
+        ));
+        let dependency_value = Expression::from(ctx.ast.member_expression_computed(
+            SPAN,
+            binding.create_read_expression(ctx),
+            key.create_read_expression(ctx),
+            false,
+        ));
+        let is_same = create_strict_equality(exports_value, dependency_value, ctx);
+        let test = ctx.ast.expression_logical(SPAN, in_exports, LogicalOperator::And, is_same);
+        body.push(ctx.ast.statement_if(SPAN, test, return_stmt(ctx), None));
+
+        let export = if self.options.loose {
+            // `exports[key] = _foo[key];`
+            let target = AssignmentTarget::from(ctx.ast.member_expression_computed(
+                SPAN,
+                self.create_exports_object(ctx),
+                key.create_read_expression(ctx),
+                false,
+            ));
+            let value = Expression::from(ctx.ast.member_expression_computed(
+                SPAN,
+                binding.create_read_expression(ctx),
+                key.create_read_expression(ctx),
+                false,
+            ));
+            create_assignment(target, value, ctx)
+        } else {
+            // `Object.defineProperty(exports, key, { enumerable: true, get: ... });`
+            let getter = create_getter(
+                scope_id,
+                |ctx| {
+                    Expression::from(ctx.ast.member_expression_computed(
+                        SPAN,
+                        binding.create_read_expression(ctx),
+                        key.create_read_expression(ctx),
+                        false,
+                    ))
+                },
+                ctx,
+            );
+            let descriptor = create_object(
+                [
+                    (Atom::from("enumerable"), ctx.ast.expression_boolean_literal(SPAN, true)),
+                    (Atom::from("get"), getter),
+                ],
+                ctx,
+            );
+            let exports = self.create_exports_object(ctx);
+            create_define_property(exports, key.create_read_expression(ctx), descriptor, ctx)
+        };
+        body.push(ctx.ast.statement_expression(SPAN, export));
+
+        let param = ctx.ast.plain_formal_parameter(SPAN, key.create_binding_pattern(ctx));
+        let callback = create_function(scope_id, ctx.ast.vec1(param), ctx.ast.vec(), body, ctx);
+
+        // `Object.keys(_foo).forEach(callback)`
+        let keys = create_global("Object", ctx);
+        let keys = create_member_expression(keys, Atom::from("keys"), ctx);
+        let keys = create_call(keys, [binding.create_read_expression(ctx)], ctx);
+        let for_each = create_member_expression(keys, Atom::from("forEach"), ctx);
+        let call = create_call(for_each, [callback], ctx);
+        ctx.ast.statement_expression(SPAN, call)
+    }
+
+    /// Create getter function `function () { return _foo.bar; }`.
+    fn create_getter(
+        &self,
+        target: &ImportTarget<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let root_scope_id = ctx.scopes().root_scope_id();
+        create_getter(root_scope_id, |ctx| self.create_import_target_expression(target, ctx), ctx)
+    }
+
+    /// Create `_foo.bar` expression for an import target.
+    fn create_import_target_expression(
+        &self,
+        target: &ImportTarget<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let binding = self.dependencies[target.dependency].binding.as_ref().unwrap();
+        let object = binding.create_read_expression(ctx);
+        match &target.property {
+            Some(property) => create_member_expression(object, property.clone(), ctx),
+            None => object,
+        }
+    }
+
+    /// Create `require("foo")`.
+    fn create_require(specifier: Atom<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let callee = create_global("require", ctx);
+        let argument = ctx.ast.expression_string_literal(SPAN, specifier);
+        create_call(callee, [argument], ctx)
+    }
+
+    /// Wrap `value` in interop helper call.
+    fn create_interop(
+        &self,
+        interop: Interop,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let (helper, arguments) = match interop {
+            Interop::None => return value,
+            Interop::Default => {
+                (Helper::InteropRequireDefault, ctx.ast.vec1(Argument::from(value)))
+            }
+            Interop::Wildcard => {
+                (Helper::InteropRequireWildcard, ctx.ast.vec1(Argument::from(value)))
+            }
+            Interop::NodeWildcard => {
+                let node = ctx.ast.expression_boolean_literal(SPAN, true);
+                (
+                    Helper::InteropRequireWildcard,
+                    ctx.ast.vec_from_iter([Argument::from(value), Argument::from(node)]),
+                )
+            }
+        };
+        self.ctx.helper_call_expr(helper, arguments, ctx)
+    }
+
+    /// Create reference to `exports` object (`_exports` in a factory function).
+    fn create_exports_object(&self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        match &self.exports {
+            Some(binding) => binding.create_read_expression(ctx),
+            None => create_global("exports", ctx),
+        }
+    }
+
+    /// Create `exports.a = exports.b = value`.
+    fn create_exports_assignments(
+        &self,
+        names: &[Atom<'a>],
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        names.iter().rev().fold(value, |value, name| {
+            let exports = self.create_exports_object(ctx);
+            let target = create_member_target(exports, name.clone(), ctx);
+            create_assignment(target, value, ctx)
+        })
+    }
+}
+
+/// Find import entry which an indirect export entry was created from.
+///
+/// `import { foo } from "foo"; export { foo };` produces an indirect export entry,
+/// with `ImportName` the span of either `foo` in import specifier's imported name,
+/// or local name of a default import.
+fn find_import_entry<'r>(
+    record: &'r ModuleRecord,
+    request: &NameSpan,
+    name: &NameSpan,
+) -> Option<&'r ImportEntry> {
+    record.import_entries.iter().find(|entry| {
+        entry.module_request.name() == request.name()
+            && (entry.local_name.span() == name.span()
+                || matches!(&entry.import_name, ImportImportName::Name(imported) if imported.span() == name.span()))
+    })
+}
+
+/// Create `var binding = init;`.
+fn create_var_statement<'a>(
+    binding: &BoundIdentifier<'a>,
+    init: Option<Expression<'a>>,
+    ctx: &TraverseCtx<'a>,
+) -> Statement<'a> {
+    let declarator = ctx.ast.variable_declarator(
+        SPAN,
+        VariableDeclarationKind::Var,
+        create_binding_pattern(binding, ctx),
+        init,
+        false,
+    );
+    Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+        SPAN,
+        VariableDeclarationKind::Var,
+        ctx.ast.vec1(declarator),
+        false,
+    ))
+}
+
+/// Create binding pattern for a binding, with the span of its original declaration.
+///
+/// Bindings of `import * as ns from "foo"` are reused for the required module.
+pub(super) fn create_binding_pattern<'a>(
+    binding: &BoundIdentifier<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> BindingPattern<'a> {
+    let span = ctx.symbols().get_span(binding.symbol_id);
+    let ident =
+        ctx.ast.binding_identifier_with_symbol_id(span, binding.name.clone(), binding.symbol_id);
+    ctx.ast.binding_pattern(
+        ctx.ast.binding_pattern_kind_from_binding_identifier(ident),
+        NONE,
+        false,
+    )
+}
+
+/// Create `left === right`.
+fn create_strict_equality<'a>(
+    left: Expression<'a>,
+    right: Expression<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    ctx.ast.expression_binary(SPAN, left, BinaryOperator::StrictEquality, right)
+}
+
+/// Visitor to find `import()` expressions.
+struct DynamicImportFinder {
+    found: bool,
+}
+
+impl DynamicImportFinder {
+    fn has_dynamic_import(program: &Program) -> bool {
+        let mut finder = Self { found: false };
+        finder.visit_program(program);
+        finder.found
+    }
+}
+
+impl<'a> Visit<'a> for DynamicImportFinder {
+    fn visit_import_expression(&mut self, _expr: &ImportExpression<'a>) {
+        self.found = true;
+    }
+}
+
+/// Visitor to find exported bindings which are assigned to in an assignment target.
+struct ExportedTargetsFinder<'a, 'b> {
+    exported: &'b FxHashMap<SymbolId, Vec<Atom<'a>>>,
+    symbols: &'b SymbolTable,
+    targets: Vec<(BoundIdentifier<'a>, Vec<Atom<'a>>)>,
+}
+
+impl<'a, 'b> Visit<'a> for ExportedTargetsFinder<'a, 'b> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        let Some(reference_id) = ident.reference_id.get() else { return };
+        let reference = self.symbols.get_reference(reference_id);
+        if !reference.flags().is_write() {
+            return;
+        }
+        let Some(symbol_id) = reference.symbol_id() else { return };
+        if let Some(names) = self.exported.get(&symbol_id) {
+            self.targets.push((BoundIdentifier::new(ident.name.clone(), symbol_id), names.clone()));
+        }
+    }
+
+    fn visit_expression(&mut self, expr: &Expression<'a>) {
+        // Default values in patterns cannot contain assignment targets of this pattern
+        if !matches!(expr, Expression::Identifier(_)) {
+            walk::walk_expression(self, expr);
+        }
+    }
+}
+
+/// Visitor to add `Read` flag to write references in an assignment target.
+///
+/// Semantic treats assignment targets as read too, when the assignment's value is used.
+/// Assignments which are wrapped in `exports.x = ...` or a sequence now have their value used.
+struct ReadFlagMarker<'b> {
+    symbols: &'b mut SymbolTable,
+}
+
+impl<'b> ReadFlagMarker<'b> {
+    fn mark_assignment_target(target: &AssignmentTarget<'_>, symbols: &'b mut SymbolTable) {
+        Self { symbols }.visit_assignment_target(target);
+    }
+
+    fn mark_simple_assignment_target(
+        target: &SimpleAssignmentTarget<'_>,
+        symbols: &'b mut SymbolTable,
+    ) {
+        Self { symbols }.visit_simple_assignment_target(target);
+    }
+}
+
+impl<'a, 'b> Visit<'a> for ReadFlagMarker<'b> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        let Some(reference_id) = ident.reference_id.get() else { return };
+        let flags = self.symbols.get_reference_mut(reference_id).flags_mut();
+        if flags.is_write() {
+            *flags |= ReferenceFlags::Read;
+        }
+    }
+
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _arrow: &ArrowFunctionExpression<'a>) {}
+}
+
+/// Visitor which rewrites references to imported and exported bindings.
+struct ModuleReferenceRewriter<'a, 'm, 'o, 'ctx> {
+    module: &'m mut ModuleTransform<'a, 'o, 'ctx>,
+    ctx: &'m mut TraverseCtx<'a>,
+    /// Scopes entered
+    scope_stack: Vec<ScopeId>,
+    /// Depth of functions, for tracking whether `this` is top level `this`
+    function_depth: u32,
+    /// `true` when visiting an expression whose value is unused (expression statement)
+    value_unused: bool,
+}
+
+impl<'a, 'm, 'o, 'ctx> ModuleReferenceRewriter<'a, 'm, 'o, 'ctx> {
+    fn new(module: &'m mut ModuleTransform<'a, 'o, 'ctx>, ctx: &'m mut TraverseCtx<'a>) -> Self {
+        let root_scope_id = ctx.scopes().root_scope_id();
+        Self {
+            module,
+            ctx,
+            scope_stack: vec![root_scope_id],
+            function_depth: 0,
+            value_unused: false,
+        }
+    }
+
+    fn current_scope_id(&self) -> ScopeId {
+        *self.scope_stack.last().unwrap()
+    }
+
+    /// Get import target for a reference to an imported binding, if the reference needs rewriting.
+    fn get_import_target(&self, ident: &IdentifierReference<'a>) -> Option<ImportTarget<'a>> {
+        let reference_id = ident.reference_id.get()?;
+        let symbol_id = self.ctx.symbols().get_reference(reference_id).symbol_id()?;
+        let target = self.module.imports.get(&symbol_id)?;
+        let binding = self.module.dependencies[target.dependency].binding.as_ref()?;
+        if binding.symbol_id == symbol_id {
+            // Binding is reused as the dependency's binding
+            return None;
+        }
+        Some(target.clone())
+    }
+
+    /// Replace reference to an imported binding with `_foo.bar`.
+    fn rewrite_import_reference(
+        &mut self,
+        ident: &IdentifierReference<'a>,
+    ) -> Option<Expression<'a>> {
+        let target = self.get_import_target(ident)?;
+        self.ctx.delete_reference_for_identifier(ident);
+        let expr = self.module.create_import_target_expression(&target, self.ctx);
+        Some(expr)
+    }
+
+    /// Get binding and export names for a reference to an exported binding.
+    fn get_exported(
+        &self,
+        ident: &IdentifierReference<'a>,
+    ) -> Option<(BoundIdentifier<'a>, Vec<Atom<'a>>)> {
+        let reference_id = ident.reference_id.get()?;
+        let symbol_id = self.ctx.symbols().get_reference(reference_id).symbol_id()?;
+        let names = self.module.exported.get(&symbol_id)?;
+        Some((BoundIdentifier::new(ident.name.clone(), symbol_id), names.clone()))
+    }
+
+    /// Create temp var, declared at top of module.
+    fn create_temp(&mut self, name: &str) -> BoundIdentifier<'a> {
+        let binding =
+            self.ctx.generate_uid_in_root_scope(name, SymbolFlags::FunctionScopedVariable);
+        self.module.temps.push(binding.clone());
+        binding
+    }
+
+    /// Transform assignment to exported binding.
+    ///
+    /// * `x = 1` -> `exports.x = x = 1`
+    /// * `[x, y] = arr;` -> `[x, y] = arr, exports.x = x, exports.y = y;`
+    /// * `f([x] = arr)` -> `f((_ref = [x] = arr, exports.x = x, _ref))`
+    fn transform_assignment(&mut self, expr: &mut Expression<'a>, value_unused: bool) {
+        let Expression::AssignmentExpression(assign) = expr else { unreachable!() };
+        if let AssignmentTarget::AssignmentTargetIdentifier(ident) = &assign.left {
+            let Some((_, names)) = self.get_exported(ident) else { return };
+            ReadFlagMarker::mark_assignment_target(&assign.left, self.ctx.symbols_mut());
+            let value = self.ctx.ast.move_expression(expr);
+            *expr = self.module.create_exports_assignments(&names, value, self.ctx);
+            return;
+        }
+
+        let targets = {
+            let mut finder = ExportedTargetsFinder {
+                exported: &self.module.exported,
+                symbols: self.ctx.symbols(),
+                targets: vec![],
+            };
+            finder.visit_assignment_target(&assign.left);
+            finder.targets
+        };
+        if targets.is_empty() {
+            return;
+        }
+        ReadFlagMarker::mark_assignment_target(&assign.left, self.ctx.symbols_mut());
+
+        let assignment = self.ctx.ast.move_expression(expr);
+        let mut expressions = self.ctx.ast.vec_with_capacity(targets.len() + 2);
+        let temp = if value_unused {
+            expressions.push(assignment);
+            None
+        } else {
+            let temp = self.create_temp("ref");
+            let target = temp.create_read_write_target(self.ctx);
+            expressions.push(create_assignment(target, assignment, self.ctx));
+            Some(temp)
+        };
+        for (binding, names) in targets {
+            let value = binding.create_read_expression(self.ctx);
+            expressions.push(self.module.create_exports_assignments(&names, value, self.ctx));
+        }
+        if let Some(temp) = temp {
+            expressions.push(temp.create_read_expression(self.ctx));
+        }
+        *expr = self.ctx.ast.expression_sequence(SPAN, expressions);
+    }
+
+    /// Transform update of exported binding.
+    ///
+    /// * `++x` -> `exports.x = ++x`
+    /// * `x++;` -> `exports.x = ++x;`
+    /// * `f(x++)` -> `f((_x = x++, exports.x = x, _x))`
+    fn transform_update(&mut self, expr: &mut Expression<'a>, value_unused: bool) {
+        let Expression::UpdateExpression(update) = expr else { unreachable!() };
+        let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) = &update.argument else {
+            return;
+        };
+        let Some((binding, names)) = self.get_exported(ident) else { return };
+        ReadFlagMarker::mark_simple_assignment_target(&update.argument, self.ctx.symbols_mut());
+
+        if update.prefix || value_unused {
+            update.prefix = true;
+            let value = self.ctx.ast.move_expression(expr);
+            *expr = self.module.create_exports_assignments(&names, value, self.ctx);
+            return;
+        }
+
+        let temp = self.create_temp(&binding.name);
+        let update = self.ctx.ast.move_expression(expr);
+        let target = temp.create_read_write_target(self.ctx);
+        let assign_temp = create_assignment(target, update, self.ctx);
+        let value = binding.create_read_expression(self.ctx);
+        let assign_exports = self.module.create_exports_assignments(&names, value, self.ctx);
+        let temp_value = temp.create_read_expression(self.ctx);
+        *expr = self.ctx.ast.expression_sequence(
+            SPAN,
+            self.ctx.ast.vec_from_iter([assign_temp, assign_exports, temp_value]),
+        );
+    }
+
+    /// Transform `for in` / `for of` loop which assigns to exported bindings in its head.
+    ///
+    /// * `for (x of list) {}` -> `for (let _x of list) { exports.x = x = _x; }`
+    /// * `for ([x, y] in obj) {}` -> `for (let _ref in obj) { [x, y] = _ref, exports.x = x, ... }`
+    ///
+    /// The assignment inserted into loop body is transformed when the body is visited.
+    fn transform_for_statement_left(
+        &mut self,
+        left: &mut ForStatementLeft<'a>,
+        body: &mut Statement<'a>,
+        for_scope_id: ScopeId,
+    ) {
+        let Some(target) = left.as_assignment_target() else { return };
+        let mut finder = ExportedTargetsFinder {
+            exported: &self.module.exported,
+            symbols: self.ctx.symbols(),
+            targets: vec![],
+        };
+        finder.visit_assignment_target(target);
+        if finder.targets.is_empty() {
+            return;
+        }
+        let bindings = finder
+            .targets
+            .into_iter()
+            .map(|(binding, _)| (binding.name, binding.symbol_id))
+            .collect::<Vec<_>>();
+
+        let name = match target {
+            AssignmentTarget::AssignmentTargetIdentifier(ident) => ident.name.as_str(),
+            _ => "ref",
+        };
+        let temp = self.ctx.generate_uid(name, for_scope_id, SymbolFlags::BlockScopedVariable);
+        let kind = VariableDeclarationKind::Let;
+        let declarator = self.ctx.ast.variable_declarator(
+            SPAN,
+            kind,
+            temp.create_binding_pattern(self.ctx),
+            None,
+            false,
+        );
+        let new_left =
+            ForStatementLeft::VariableDeclaration(self.ctx.ast.alloc_variable_declaration(
+                SPAN,
+                kind,
+                self.ctx.ast.vec1(declarator),
+                false,
+            ));
+        let target = mem::replace(left, new_left).into_assignment_target();
+        let value = temp.create_read_expression(self.ctx);
+        let assignment = create_assignment(target, value, self.ctx);
+        let stmt = self.ctx.ast.statement_expression(SPAN, assignment);
+        Destructuring::insert_into_for_body(body, stmt, for_scope_id, &bindings, self.ctx);
+    }
+
+    /// Transform dynamic import.
+    ///
+    /// * `import("foo")` -> `Promise.resolve().then(() => _interopRequireWildcard(require("foo")))`
+    /// * `import(foo)` -> `Promise.resolve(`${foo}`).then((_s) => _interopRequireWildcard(require(_s)))`
+    fn transform_dynamic_import(&mut self, expr: &mut Expression<'a>) {
+        let Expression::ImportExpression(import) = expr else { unreachable!() };
+        let source = self.ctx.ast.move_expression(&mut import.source);
+        let span = import.span;
+
+        let parent_scope_id = self.current_scope_id();
+        let scope_id = self.ctx.create_child_scope(
+            parent_scope_id,
+            ScopeFlags::Function | ScopeFlags::Arrow | ScopeFlags::StrictMode,
+        );
+
+        let (resolve_argument, param, specifier) = if let Expression::StringLiteral(literal) =
+            &source
+        {
+            let specifier = self.ctx.ast.expression_string_literal(SPAN, literal.value.clone());
+            (None, None, specifier)
+        } else {
+            // `${foo}`
+            let quasi = |tail, ctx: &TraverseCtx<'a>| {
+                let value =
+                    TemplateElementValue { raw: Atom::from(""), cooked: Some(Atom::from("")) };
+                ctx.ast.template_element(SPAN, tail, value)
+            };
+            let quasis =
+                self.ctx.ast.vec_from_iter([quasi(false, self.ctx), quasi(true, self.ctx)]);
+            let template =
+                self.ctx.ast.expression_template_literal(SPAN, quasis, self.ctx.ast.vec1(source));
+            let binding = self.ctx.generate_uid("s", scope_id, SymbolFlags::FunctionScopedVariable);
+            let param =
+                self.ctx.ast.plain_formal_parameter(SPAN, binding.create_binding_pattern(self.ctx));
+            let specifier = binding.create_read_expression(self.ctx);
+            (Some(template), Some(param), specifier)
+        };
+
+        let require = create_global("require", self.ctx);
+        let require = create_call(require, [specifier], self.ctx);
+        let interop = match self.module.options.import_interop() {
+            ImportInterop::Babel => Interop::Wildcard,
+            ImportInterop::Node => Interop::NodeWildcard,
+            ImportInterop::None => Interop::None,
+        };
+        let value = self.module.create_interop(interop, require, self.ctx);
+
+        let params = self.ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::ArrowFormalParameters,
+            self.ctx.ast.vec_from_iter(param),
+            NONE,
+        );
+        let body = self.ctx.ast.alloc_function_body(
+            SPAN,
+            self.ctx.ast.vec(),
+            self.ctx.ast.vec1(self.ctx.ast.statement_expression(SPAN, value)),
+        );
+        let arrow = Expression::ArrowFunctionExpression(
+            self.ctx.ast.alloc_arrow_function_expression_with_scope_id(
+                SPAN, true, false, NONE, params, NONE, body, scope_id,
+            ),
+        );
+
+        // `Promise.resolve(...).then(arrow)`
+        let promise = create_global("Promise", self.ctx);
+        let resolve = create_member_expression(promise, Atom::from("resolve"), self.ctx);
+        let resolve = create_call(resolve, resolve_argument, self.ctx);
+        let then = create_member_expression(resolve, Atom::from("then"), self.ctx);
+        let mut call = create_call(then, [arrow], self.ctx);
+        if let Expression::CallExpression(call) = &mut call {
+            call.span = span;
+        }
+        *expr = call;
+    }
+
+    /// Create `(0, _foo.bar)` callee, so `this` is not bound to the module object.
+    fn create_unbound_callee(&self, callee: Expression<'a>) -> Expression<'a> {
+        let zero = self.ctx.ast.expression_numeric_literal(SPAN, 0.0, "0", NumberBase::Decimal);
+        self.ctx.ast.expression_sequence(SPAN, self.ctx.ast.vec_from_iter([zero, callee]))
+    }
+
+    /// Create JSX element name / member expression object for an imported binding.
+    ///
+    /// Returns `None` if the import target cannot be represented in JSX,
+    /// i.e. import name is not a valid identifier.
+    fn create_jsx_import_target(
+        &mut self,
+        ident: &IdentifierReference<'a>,
+    ) -> Option<Result<JSXMemberExpression<'a>, IdentifierReference<'a>>> {
+        let target = self.get_import_target(ident)?;
+        if target.property.as_ref().is_some_and(|property| !is_jsx_identifier_name(property)) {
+            return None;
+        }
+        self.ctx.delete_reference_for_identifier(ident);
+        let binding = self.module.dependencies[target.dependency].binding.as_ref().unwrap();
+        let object = binding.create_spanned_read_reference(ident.span, self.ctx);
+        Some(match target.property {
+            Some(property) => {
+                let object =
+                    JSXMemberExpressionObject::IdentifierReference(self.ctx.ast.alloc(object));
+                let property = self.ctx.ast.jsx_identifier(SPAN, property);
+                Ok(self.ctx.ast.jsx_member_expression(ident.span, object, property))
+            }
+            None => Err(object),
+        })
+    }
+}
+
+fn is_jsx_identifier_name(name: &str) -> bool {
+    oxc_syntax::identifier::is_identifier_name(name)
+}
+
+impl<'a, 'm, 'o, 'ctx> VisitMut<'a> for ModuleReferenceRewriter<'a, 'm, 'o, 'ctx> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        // Inlined helpers have no scopes
+        let scope_id = scope_id.get().unwrap_or_else(|| self.current_scope_id());
+        self.scope_stack.push(scope_id);
+    }
+
+    fn leave_scope(&mut self) {
+        self.scope_stack.pop();
+    }
+
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        let value_unused = mem::take(&mut self.value_unused);
+        match expr {
+            Expression::Identifier(ident) => {
+                if let Some(new_expr) = self.rewrite_import_reference(ident) {
+                    *expr = new_expr;
+                }
+                return;
+            }
+            Expression::ThisExpression(this) => {
+                if self.function_depth == 0 && !self.module.options.allow_top_level_this {
+                    *expr = self.ctx.ast.void_0(this.span);
+                }
+                return;
+            }
+            _ => {}
+        }
+
+        walk_mut::walk_expression(self, expr);
+
+        match expr {
+            Expression::AssignmentExpression(_) => self.transform_assignment(expr, value_unused),
+            Expression::UpdateExpression(_) => self.transform_update(expr, value_unused),
+            Expression::ImportExpression(_) if !self.module.wrapped => {
+                self.transform_dynamic_import(expr);
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_expression_statement(&mut self, stmt: &mut ExpressionStatement<'a>) {
+        self.value_unused = true;
+        self.visit_expression(&mut stmt.expression);
+    }
+
+    fn visit_call_expression(&mut self, call: &mut CallExpression<'a>) {
+        let is_import_member = matches!(
+            &call.callee,
+            Expression::Identifier(ident)
+                if self.get_import_target(ident).is_some_and(|target| target.property.is_some())
+        );
+        walk_mut::walk_call_expression(self, call);
+        if is_import_member {
+            let callee = self.ctx.ast.move_expression(&mut call.callee);
+            call.callee = self.create_unbound_callee(callee);
+        }
+    }
+
+    fn visit_tagged_template_expression(&mut self, expr: &mut TaggedTemplateExpression<'a>) {
+        let is_import_member = matches!(
+            &expr.tag,
+            Expression::Identifier(ident)
+                if self.get_import_target(ident).is_some_and(|target| target.property.is_some())
+        );
+        walk_mut::walk_tagged_template_expression(self, expr);
+        if is_import_member {
+            let tag = self.ctx.ast.move_expression(&mut expr.tag);
+            expr.tag = self.create_unbound_callee(tag);
+        }
+    }
+
+    fn visit_object_property(&mut self, prop: &mut ObjectProperty<'a>) {
+        walk_mut::walk_object_property(self, prop);
+        // `{ foo }` -> `{ foo: _foo.foo }`
+        if prop.shorthand && !matches!(prop.value, Expression::Identifier(_)) {
+            prop.shorthand = false;
+        }
+    }
+
+    fn visit_variable_declarator(&mut self, declarator: &mut VariableDeclarator<'a>) {
+        walk_mut::walk_variable_declarator(self, declarator);
+        // `let x = 1` -> `let x = exports.x = 1`
+        let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind else { return };
+        let Some(init) = &mut declarator.init else { return };
+        let Some(names) = ident.symbol_id.get().and_then(|id| self.module.exported.get(&id)) else {
+            return;
+        };
+        let names = names.clone();
+        let value = self.ctx.ast.move_expression(init);
+        *init = self.module.create_exports_assignments(&names, value, self.ctx);
+    }
+
+    fn visit_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>) {
+        let for_scope_id = stmt.scope_id.get().unwrap();
+        self.transform_for_statement_left(&mut stmt.left, &mut stmt.body, for_scope_id);
+        walk_mut::walk_for_in_statement(self, stmt);
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>) {
+        let for_scope_id = stmt.scope_id.get().unwrap();
+        self.transform_for_statement_left(&mut stmt.left, &mut stmt.body, for_scope_id);
+        walk_mut::walk_for_of_statement(self, stmt);
+    }
+
+    fn visit_function(&mut self, func: &mut Function<'a>, flags: ScopeFlags) {
+        self.function_depth += 1;
+        walk_mut::walk_function(self, func, flags);
+        self.function_depth -= 1;
+    }
+
+    fn visit_class_body(&mut self, body: &mut ClassBody<'a>) {
+        self.function_depth += 1;
+        walk_mut::walk_class_body(self, body);
+        self.function_depth -= 1;
+    }
+
+    fn visit_jsx_element_name(&mut self, name: &mut JSXElementName<'a>) {
+        if let JSXElementName::IdentifierReference(ident) = name {
+            match self.create_jsx_import_target(ident) {
+                Some(Ok(member)) => {
+                    *name = JSXElementName::MemberExpression(self.ctx.ast.alloc(member));
+                }
+                Some(Err(new_ident)) => **ident = new_ident,
+                None => {}
+            }
+            return;
+        }
+        walk_mut::walk_jsx_element_name(self, name);
+    }
+
+    fn visit_jsx_member_expression_object(&mut self, object: &mut JSXMemberExpressionObject<'a>) {
+        if let JSXMemberExpressionObject::IdentifierReference(ident) = object {
+            match self.create_jsx_import_target(ident) {
+                Some(Ok(member)) => {
+                    *object =
+                        JSXMemberExpressionObject::MemberExpression(self.ctx.ast.alloc(member));
+                }
+                Some(Err(new_ident)) => **ident = new_ident,
+                None => {}
+            }
+            return;
+        }
+        walk_mut::walk_jsx_member_expression_object(self, object);
+    }
+}
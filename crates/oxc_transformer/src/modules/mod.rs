@@ -0,0 +1,83 @@
+//! Module transforms
+//!
+//! Transform ES modules (`import` / `export`) to other module formats:
+//!
+//! * CommonJS. See [`commonjs`] module.
+//! * AMD. See [`amd`] module.
+//! * UMD. See [`umd`] module.
+//!
+//! SystemJS format and lazy initialization of imports (`lazy` option) are not supported.
+//!
+//! Only one format can be enabled at a time. Format is determined by `modules` option of
+//! preset-env, or which of the `transform-modules-*` plugins is enabled.
+//!
+//! These transforms run last, after all other transforms, so the code they operate on
+//! includes `import`s of helpers inserted by other transforms.
+
+use serde_json::Value;
+
+use oxc_ast::ast::*;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_traverse::{Traverse, TraverseCtx};
+
+use crate::TransformCtx;
+
+mod amd;
+mod commonjs;
+mod options;
+mod umd;
+mod utils;
+
+use amd::Amd;
+use commonjs::CommonJs;
+use umd::Umd;
+
+pub use options::{ImportInterop, ModuleFormat, ModulesOptions};
+
+pub struct Modules<'a, 'ctx> {
+    // Plugins
+    commonjs: Option<CommonJs<'a, 'ctx>>,
+    amd: Option<Amd<'a, 'ctx>>,
+    umd: Option<Umd<'a, 'ctx>>,
+}
+
+impl<'a, 'ctx> Modules<'a, 'ctx> {
+    pub fn new(options: Option<ModulesOptions>, ctx: &'ctx TransformCtx<'a>) -> Self {
+        let format = options.as_ref().map(|options| options.format);
+        if options
+            .as_ref()
+            .and_then(|options| options.lazy.as_ref())
+            .is_some_and(|lazy| *lazy != Value::Bool(false))
+        {
+            ctx.error(OxcDiagnostic::error(
+                "The `lazy` option of module transforms is not supported",
+            ));
+        }
+        let commonjs = options
+            .clone()
+            .filter(|_| format == Some(ModuleFormat::CommonJS))
+            .map(|options| CommonJs::new(options, ctx));
+        let amd = options
+            .clone()
+            .filter(|_| format == Some(ModuleFormat::AMD))
+            .map(|options| Amd::new(options, ctx));
+        let umd = options
+            .filter(|_| format == Some(ModuleFormat::UMD))
+            .map(|options| Umd::new(options, ctx));
+        Self { commonjs, amd, umd }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for Modules<'a, 'ctx> {
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(commonjs) = &mut self.commonjs {
+            commonjs.exit_program(program, ctx);
+        }
+        if let Some(amd) = &mut self.amd {
+            amd.exit_program(program, ctx);
+        }
+        if let Some(umd) = &mut self.umd {
+            umd.exit_program(program, ctx);
+        }
+    }
+}
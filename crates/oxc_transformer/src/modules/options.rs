@@ -0,0 +1,147 @@
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Module format to transform ES modules to.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ModuleFormat {
+    /// `require` / `exports`.
+    #[default]
+    CommonJS,
+    /// `define([...], function (...) { ... })`.
+    AMD,
+    /// AMD, CommonJS and browser globals, detected at runtime.
+    UMD,
+}
+
+impl ModuleFormat {
+    /// Parse preset-env's `modules` option.
+    ///
+    /// `false` and `"auto"` disable the module transform.
+    /// `"auto"` is treated as `false`, as the caller is expected to know what its target supports.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is not one of the supported options.
+    /// `"systemjs"` is valid in Babel, but SystemJS format is not supported.
+    pub fn from_preset_env(value: Option<&Value>) -> Result<Option<Self>, String> {
+        let format = match value {
+            None | Some(Value::Null | Value::Bool(false)) => None,
+            Some(Value::String(s)) => match s.as_str() {
+                "auto" | "false" => None,
+                "commonjs" | "cjs" => Some(Self::CommonJS),
+                "amd" => Some(Self::AMD),
+                "umd" => Some(Self::UMD),
+                "systemjs" => {
+                    return Err("The 'systemjs' module format is not supported".to_string())
+                }
+                _ => return Err(Self::invalid_option_error()),
+            },
+            Some(_) => return Err(Self::invalid_option_error()),
+        };
+        Ok(format)
+    }
+
+    fn invalid_option_error() -> String {
+        "Invalid Option: The 'modules' option must be one of false, 'auto', 'commonjs', 'cjs', \
+        'amd' or 'umd'"
+            .to_string()
+    }
+}
+
+/// How to interop with CommonJS modules when importing them.
+///
+/// <https://babel.dev/docs/babel-plugin-transform-modules-commonjs#importinterop>
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ImportInterop {
+    /// Treat `exports.__esModule` modules as ES modules, and others as CommonJS modules
+    /// whose `module.exports` is the default export.
+    #[default]
+    Babel,
+    /// Same as Node.js: the default export is always `module.exports`.
+    Node,
+    /// Use the required module as is.
+    None,
+}
+
+/// Options for module transforms:
+/// * [transform-modules-commonjs](https://babel.dev/docs/babel-plugin-transform-modules-commonjs)
+/// * [transform-modules-amd](https://babel.dev/docs/babel-plugin-transform-modules-amd)
+/// * [transform-modules-umd](https://babel.dev/docs/babel-plugin-transform-modules-umd)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct ModulesOptions {
+    /// Module format to output.
+    ///
+    /// Set by which plugin is used, or preset-env's `modules` option.
+    #[serde(skip)]
+    pub format: ModuleFormat,
+
+    /// How to interop with CommonJS modules.
+    pub import_interop: Option<ImportInterop>,
+
+    /// Same as `importInterop: "none"`.
+    pub no_interop: bool,
+
+    /// Assign exports with `exports.foo = ...` instead of `Object.defineProperty`
+    /// where possible.
+    pub loose: bool,
+
+    /// Do not add the `__esModule` marker to exports.
+    pub strict: bool,
+
+    /// Add `"use strict"` directive. Default `true`.
+    pub strict_mode: bool,
+
+    /// Do not rewrite top level `this` to `undefined`.
+    pub allow_top_level_this: bool,
+
+    /// UMD only. Map of module specifiers to global variable names.
+    pub globals: FxHashMap<String, String>,
+
+    /// UMD only. Use the values of `globals` as is, including member expressions
+    /// such as `"Foo.Bar"`.
+    pub exact_globals: bool,
+
+    /// AMD and UMD only. Explicit module ID.
+    pub module_id: Option<String>,
+
+    /// Lazily initialize imports.
+    ///
+    /// Not supported. An error is reported if set to anything other than `false`.
+    pub lazy: Option<Value>,
+}
+
+impl Default for ModulesOptions {
+    fn default() -> Self {
+        Self {
+            format: ModuleFormat::default(),
+            import_interop: None,
+            no_interop: false,
+            loose: false,
+            strict: false,
+            strict_mode: true,
+            allow_top_level_this: false,
+            globals: FxHashMap::default(),
+            exact_globals: false,
+            module_id: None,
+            lazy: None,
+        }
+    }
+}
+
+impl ModulesOptions {
+    pub fn new(format: ModuleFormat) -> Self {
+        Self { format, ..Self::default() }
+    }
+
+    /// Get import interop, taking into account `noInterop` option.
+    pub fn import_interop(&self) -> ImportInterop {
+        if self.no_interop {
+            ImportInterop::None
+        } else {
+            self.import_interop.unwrap_or_default()
+        }
+    }
+}
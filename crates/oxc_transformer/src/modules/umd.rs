@@ -0,0 +1,371 @@
+//! ES Modules to UMD
+//!
+//! This plugin transforms ES module syntax to [UMD](https://github.com/umdjs/umd) modules,
+//! which work as AMD modules, CommonJS modules, or browser globals.
+//!
+//! > This plugin is included in `preset-env`, when `modules` option is `"umd"`.
+//!
+//! ## Example
+//!
+//! Input (`input.js`):
+//! ```js
+//! import foo from "foo";
+//! export const x = foo();
+//! ```
+//!
+//! Output:
+//! ```js
+//! (function (global, factory) {
+//!   if (typeof define === "function" && define.amd) {
+//!     define(["exports", "foo"], factory);
+//!   } else if (typeof exports !== "undefined") {
+//!     factory(exports, require("foo"));
+//!   } else {
+//!     var mod = { exports: {} };
+//!     factory(mod.exports, global.foo);
+//!     global.input = mod.exports;
+//!   }
+//! })(typeof globalThis !== "undefined" ? globalThis : typeof self !== "undefined" ? self : this, function (_exports, _foo) {
+//!   "use strict";
+//!
+//!   Object.defineProperty(_exports, "__esModule", { value: true });
+//!   _exports.x = void 0;
+//!   _foo = _interopRequireDefault(_foo);
+//!   const x = _exports.x = (0, _foo.default)();
+//! });
+//! ```
+//!
+//! ## Options
+//!
+//! Same as [CommonJS transform](super::commonjs), plus:
+//!
+//! * `globals`: Map of module names to global names, for browser globals.
+//!   By default, global name is the module's basename, converted to an identifier.
+//! * `exactGlobals`: Use `globals` names as is, matched against full module specifiers.
+//!   Global names can be member expressions e.g. `"Foo.Bar"`.
+//! * `moduleId`: Name of the module, passed as first argument to `define`.
+//!   Also used as name of the global, instead of file name.
+//!
+//! ## Implementation
+//!
+//! Module body is transformed the same as by the AMD transform, and the factory function is
+//! passed to a wrapper which detects the module system at runtime.
+//!
+//! Implementation based on [@babel/plugin-transform-modules-umd](https://babel.dev/docs/babel-plugin-transform-modules-umd).
+//!
+//! ## Missing features
+//!
+//! * Dynamic `import()` is not transformed.
+//! * All missing features of the CommonJS transform.
+//!
+//! ## References
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/main/packages/babel-plugin-transform-modules-umd>
+
+use oxc_ast::ast::*;
+use oxc_semantic::SymbolFlags;
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::scope::{ScopeFlags, ScopeId};
+use oxc_traverse::{BoundIdentifier, Traverse, TraverseCtx};
+
+use crate::TransformCtx;
+
+use super::{
+    amd::{create_factory, Factory},
+    commonjs::ModuleTransform,
+    options::ModulesOptions,
+    utils::{
+        create_assignment, create_call, create_function, create_function_scope, create_global,
+        create_member, create_member_expression, create_object, module_binding_name, to_identifier,
+    },
+};
+
+pub struct Umd<'a, 'ctx> {
+    options: ModulesOptions,
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> Umd<'a, 'ctx> {
+    pub fn new(options: ModulesOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { options, ctx }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for Umd<'a, 'ctx> {
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Some(module) =
+            ModuleTransform::new(&self.options, true, self.ctx).transform(program, ctx)
+        else {
+            return;
+        };
+
+        let directives = ctx.ast.move_vec(&mut program.directives);
+        let factory = create_factory(module, directives, self.options.strict_mode, ctx);
+        let wrapper = self.create_wrapper(&factory, ctx);
+
+        // `typeof globalThis !== "undefined" ? globalThis : typeof self !== "undefined" ? self : this`
+        let this = ctx.ast.expression_this(SPAN);
+        let global = ["self", "globalThis"].into_iter().fold(this, |alternate, name| {
+            let test = create_typeof_check(name, ctx);
+            let consequent = create_global(name, ctx);
+            ctx.ast.expression_conditional(SPAN, test, consequent, alternate)
+        });
+
+        let wrapper = ctx.ast.expression_parenthesized(SPAN, wrapper);
+        let call = create_call(wrapper, [global, factory.function], ctx);
+        program.body.push(ctx.ast.statement_expression(SPAN, call));
+    }
+}
+
+impl<'a, 'ctx> Umd<'a, 'ctx> {
+    /// Create wrapper function.
+    ///
+    /// ```js
+    /// function (global, factory) {
+    ///   if (typeof define === "function" && define.amd) {
+    ///     define(["exports", "foo"], factory);
+    ///   } else if (typeof exports !== "undefined") {
+    ///     factory(exports, require("foo"));
+    ///   } else {
+    ///     var mod = { exports: {} };
+    ///     factory(mod.exports, global.foo);
+    ///     global.input = mod.exports;
+    ///   }
+    /// }
+    /// ```
+    fn create_wrapper(&self, factory: &Factory<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let root_scope_id = ctx.scopes().root_scope_id();
+        let scope_id = create_function_scope(root_scope_id, ctx);
+        let global = ctx.generate_binding(
+            Atom::from("global"),
+            scope_id,
+            SymbolFlags::FunctionScopedVariable,
+        );
+        let factory_binding = ctx.generate_binding(
+            Atom::from("factory"),
+            scope_id,
+            SymbolFlags::FunctionScopedVariable,
+        );
+        let module =
+            ctx.generate_binding(Atom::from("mod"), scope_id, SymbolFlags::FunctionScopedVariable);
+
+        // AMD: `define("id", ["exports", "foo"], factory);`
+        let mut define_arguments = vec![];
+        if let Some(module_id) = &self.options.module_id {
+            define_arguments.push(ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(module_id)));
+        }
+        define_arguments.push(factory.create_dependencies_array(ctx));
+        define_arguments.push(factory_binding.create_read_expression(ctx));
+        let define = create_global("define", ctx);
+        let define = create_call(define, define_arguments, ctx);
+        let amd_block = create_block(scope_id, vec![define], ctx);
+
+        // CommonJS: `factory(exports, require("foo"));`
+        let cjs_arguments = factory
+            .dependencies
+            .iter()
+            .enumerate()
+            .map(|(index, specifier)| {
+                if index == 0 && factory.has_exports {
+                    create_global("exports", ctx)
+                } else {
+                    let require = create_global("require", ctx);
+                    let specifier = ctx.ast.expression_string_literal(SPAN, specifier.clone());
+                    create_call(require, [specifier], ctx)
+                }
+            })
+            .collect::<Vec<_>>();
+        let cjs_call = create_call(factory_binding.create_read_expression(ctx), cjs_arguments, ctx);
+        let cjs_block = create_block(scope_id, vec![cjs_call], ctx);
+
+        // Browser globals
+        let browser_block =
+            self.create_browser_block(scope_id, factory, &global, &factory_binding, &module, ctx);
+
+        // `typeof define === "function" && define.amd`
+        let is_define_function = {
+            let define = create_global("define", ctx);
+            let typeof_define = ctx.ast.expression_unary(SPAN, UnaryOperator::Typeof, define);
+            let function = ctx.ast.expression_string_literal(SPAN, "function");
+            ctx.ast.expression_binary(SPAN, typeof_define, BinaryOperator::StrictEquality, function)
+        };
+        let define_amd =
+            create_member_expression(create_global("define", ctx), Atom::from("amd"), ctx);
+        let is_amd =
+            ctx.ast.expression_logical(SPAN, is_define_function, LogicalOperator::And, define_amd);
+        let is_cjs = create_typeof_check("exports", ctx);
+
+        let cjs_if = ctx.ast.statement_if(SPAN, is_cjs, cjs_block, Some(browser_block));
+        let amd_if = ctx.ast.statement_if(SPAN, is_amd, amd_block, Some(cjs_if));
+
+        let params = ctx.ast.vec_from_iter([
+            ctx.ast.plain_formal_parameter(SPAN, global.create_binding_pattern(ctx)),
+            ctx.ast.plain_formal_parameter(SPAN, factory_binding.create_binding_pattern(ctx)),
+        ]);
+        create_function(scope_id, params, ctx.ast.vec(), ctx.ast.vec1(amd_if), ctx)
+    }
+
+    /// Create block for browser globals.
+    ///
+    /// ```js
+    /// {
+    ///   var mod = { exports: {} };
+    ///   factory(mod.exports, global.foo);
+    ///   global.input = mod.exports;
+    /// }
+    /// ```
+    fn create_browser_block(
+        &self,
+        scope_id: ScopeId,
+        factory: &Factory<'a>,
+        global: &BoundIdentifier<'a>,
+        factory_binding: &BoundIdentifier<'a>,
+        module: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let block_scope_id = ctx.create_child_scope(scope_id, ScopeFlags::empty());
+        let mut stmts = ctx.ast.vec();
+
+        // `var mod = { exports: {} };`
+        let exports_object = ctx.ast.expression_object(SPAN, ctx.ast.vec(), None);
+        let module_object = create_object([(Atom::from("exports"), exports_object)], ctx);
+        let declarator = ctx.ast.variable_declarator(
+            SPAN,
+            VariableDeclarationKind::Var,
+            module.create_binding_pattern(ctx),
+            Some(module_object),
+            false,
+        );
+        stmts.push(Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Var,
+            ctx.ast.vec1(declarator),
+            false,
+        )));
+
+        // `factory(mod.exports, global.foo);`
+        let arguments = factory
+            .dependencies
+            .iter()
+            .enumerate()
+            .map(|(index, specifier)| {
+                if index == 0 && factory.has_exports {
+                    create_member_expression(
+                        module.create_read_expression(ctx),
+                        Atom::from("exports"),
+                        ctx,
+                    )
+                } else {
+                    let path = self.get_global_path(specifier);
+                    create_global_path(global, &path, ctx)
+                }
+            })
+            .collect::<Vec<_>>();
+        let call = create_call(factory_binding.create_read_expression(ctx), arguments, ctx);
+        stmts.push(ctx.ast.statement_expression(SPAN, call));
+
+        // `global.input = mod.exports;`
+        // With exact globals, parents of nested globals are initialized:
+        // `global.Foo = global.Foo || {}; global.Foo.Bar = mod.exports;`
+        let path = self.get_module_global_path();
+        for len in 1..path.len() {
+            let target = create_global_path_target(global, &path[..len], ctx);
+            let current = create_global_path(global, &path[..len], ctx);
+            let empty = ctx.ast.expression_object(SPAN, ctx.ast.vec(), None);
+            let value = ctx.ast.expression_logical(SPAN, current, LogicalOperator::Or, empty);
+            let assignment = create_assignment(target, value, ctx);
+            stmts.push(ctx.ast.statement_expression(SPAN, assignment));
+        }
+        let target = create_global_path_target(global, &path, ctx);
+        let value = create_member_expression(
+            module.create_read_expression(ctx),
+            Atom::from("exports"),
+            ctx,
+        );
+        let assignment = create_assignment(target, value, ctx);
+        stmts.push(ctx.ast.statement_expression(SPAN, assignment));
+
+        Statement::BlockStatement(ctx.ast.alloc_block_statement_with_scope_id(
+            SPAN,
+            stmts,
+            block_scope_id,
+        ))
+    }
+
+    /// Get path of global for a dependency, e.g. `["Foo", "Bar"]` for `global.Foo.Bar`.
+    fn get_global_path(&self, specifier: &str) -> Vec<String> {
+        if self.options.exact_globals {
+            match self.options.globals.get(specifier) {
+                Some(name) => name.split('.').map(ToString::to_string).collect(),
+                None => vec![to_identifier(specifier)],
+            }
+        } else {
+            let name = module_binding_name(specifier);
+            let name = self.options.globals.get(&name).map_or(name, |name| to_identifier(name));
+            vec![name]
+        }
+    }
+
+    /// Get path of global which this module's exports are assigned to.
+    fn get_module_global_path(&self) -> Vec<String> {
+        let name = self.options.module_id.clone().unwrap_or_else(|| {
+            self.ctx
+                .source_path
+                .file_stem()
+                .map_or_else(|| "unknown".to_string(), |stem| stem.to_string_lossy().to_string())
+        });
+        if self.options.exact_globals {
+            if let Some(name) = self.options.globals.get(&name) {
+                return name.split('.').map(ToString::to_string).collect();
+            }
+        }
+        vec![to_identifier(&name)]
+    }
+}
+
+/// Create `typeof name !== "undefined"`.
+fn create_typeof_check<'a>(name: &'static str, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+    let ident = create_global(name, ctx);
+    let typeof_ident = ctx.ast.expression_unary(SPAN, UnaryOperator::Typeof, ident);
+    let undefined = ctx.ast.expression_string_literal(SPAN, "undefined");
+    ctx.ast.expression_binary(SPAN, typeof_ident, BinaryOperator::StrictInequality, undefined)
+}
+
+/// Create block statement `{ expr; }`, with a new scope.
+fn create_block<'a>(
+    parent_scope_id: ScopeId,
+    exprs: Vec<Expression<'a>>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Statement<'a> {
+    let scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+    let stmts = ctx
+        .ast
+        .vec_from_iter(exprs.into_iter().map(|expr| ctx.ast.statement_expression(SPAN, expr)));
+    Statement::BlockStatement(ctx.ast.alloc_block_statement_with_scope_id(SPAN, stmts, scope_id))
+}
+
+/// Create `global.Foo.Bar`.
+fn create_global_path<'a>(
+    global: &BoundIdentifier<'a>,
+    path: &[String],
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    path.iter().fold(global.create_read_expression(ctx), |object, name| {
+        create_member_expression(object, ctx.ast.atom(name), ctx)
+    })
+}
+
+/// Create `global.Foo.Bar` assignment target.
+fn create_global_path_target<'a>(
+    global: &BoundIdentifier<'a>,
+    path: &[String],
+    ctx: &mut TraverseCtx<'a>,
+) -> AssignmentTarget<'a> {
+    let (last, parents) = path.split_last().unwrap();
+    let object = create_global_path(global, parents, ctx);
+    AssignmentTarget::from(SimpleAssignmentTarget::from(create_member(
+        object,
+        ctx.ast.atom(last),
+        ctx,
+    )))
+}
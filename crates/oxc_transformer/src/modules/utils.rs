@@ -0,0 +1,218 @@
+//! Module transforms
+//! Utility functions.
+
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::{ast::*, NONE};
+use oxc_semantic::ReferenceFlags;
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::{
+    identifier::is_identifier_name,
+    keyword::is_reserved_keyword,
+    scope::{ScopeFlags, ScopeId},
+};
+use oxc_traverse::TraverseCtx;
+
+/// Create reference to a global, e.g. `Object`.
+///
+/// Reference is bound if there's a top level binding with that name.
+pub(super) fn create_global<'a>(name: &'static str, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+    let symbol_id = ctx.scopes().get_root_binding(name);
+    let ident = ctx.create_reference_id(SPAN, Atom::from(name), symbol_id, ReferenceFlags::Read);
+    ctx.ast.expression_from_identifier_reference(ident)
+}
+
+/// Create `object.property` or `object["property"]` member expression,
+/// depending on whether `property` is a valid identifier name.
+pub(super) fn create_member<'a>(
+    object: Expression<'a>,
+    property: Atom<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> MemberExpression<'a> {
+    if is_identifier_name(&property) {
+        let property = ctx.ast.identifier_name(SPAN, property);
+        ctx.ast.member_expression_static(SPAN, object, property, false)
+    } else {
+        let property = ctx.ast.expression_string_literal(SPAN, property);
+        ctx.ast.member_expression_computed(SPAN, object, property, false)
+    }
+}
+
+/// Create `object.property` member expression, as an `Expression`.
+pub(super) fn create_member_expression<'a>(
+    object: Expression<'a>,
+    property: Atom<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    Expression::from(create_member(object, property, ctx))
+}
+
+/// Create `object.property` member expression, as an `AssignmentTarget`.
+pub(super) fn create_member_target<'a>(
+    object: Expression<'a>,
+    property: Atom<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> AssignmentTarget<'a> {
+    AssignmentTarget::from(SimpleAssignmentTarget::from(create_member(object, property, ctx)))
+}
+
+/// Create `callee(...arguments)` call expression.
+pub(super) fn create_call<'a>(
+    callee: Expression<'a>,
+    arguments: impl IntoIterator<Item = Expression<'a>>,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    let arguments = ctx.ast.vec_from_iter(arguments.into_iter().map(Argument::from));
+    ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+}
+
+/// Create `target = value` assignment expression.
+pub(super) fn create_assignment<'a>(
+    target: AssignmentTarget<'a>,
+    value: Expression<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value)
+}
+
+/// Create `{ key: value, ... }` object expression.
+///
+/// Keys which are not valid identifier names are string literals (`{ "foo-bar": value }`).
+pub(super) fn create_object<'a>(
+    properties: impl IntoIterator<Item = (Atom<'a>, Expression<'a>)>,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    let properties = ctx.ast.vec_from_iter(properties.into_iter().map(|(key, value)| {
+        let key = if is_identifier_name(&key) {
+            ctx.ast.property_key_identifier_name(SPAN, key)
+        } else {
+            PropertyKey::from(ctx.ast.expression_string_literal(SPAN, key))
+        };
+        ctx.ast.object_property_kind_object_property(
+            SPAN,
+            PropertyKind::Init,
+            key,
+            value,
+            None,
+            false,
+            false,
+            false,
+        )
+    }));
+    ctx.ast.expression_object(SPAN, properties, None)
+}
+
+/// Create a scope for a function inserted into module code.
+pub(super) fn create_function_scope(parent_scope_id: ScopeId, ctx: &mut TraverseCtx) -> ScopeId {
+    ctx.create_child_scope(parent_scope_id, ScopeFlags::Function | ScopeFlags::StrictMode)
+}
+
+/// Create `function (params) { body }` function expression.
+pub(super) fn create_function<'a>(
+    scope_id: ScopeId,
+    params: ArenaVec<'a, FormalParameter<'a>>,
+    directives: ArenaVec<'a, Directive<'a>>,
+    body: ArenaVec<'a, Statement<'a>>,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    let params =
+        ctx.ast.alloc_formal_parameters(SPAN, FormalParameterKind::FormalParameter, params, NONE);
+    let body = ctx.ast.alloc_function_body(SPAN, directives, body);
+    Expression::FunctionExpression(ctx.ast.alloc_function_with_scope_id(
+        FunctionType::FunctionExpression,
+        SPAN,
+        None,
+        false,
+        false,
+        false,
+        NONE,
+        NONE,
+        params,
+        NONE,
+        Some(body),
+        scope_id,
+    ))
+}
+
+/// Create `function () { return value; }` getter function, as used in property descriptors.
+pub(super) fn create_getter<'a>(
+    parent_scope_id: ScopeId,
+    value: impl FnOnce(&mut TraverseCtx<'a>) -> Expression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let scope_id = create_function_scope(parent_scope_id, ctx);
+    let value = value(ctx);
+    let body = ctx.ast.vec1(ctx.ast.statement_return(SPAN, Some(value)));
+    create_function(scope_id, ctx.ast.vec(), ctx.ast.vec(), body, ctx)
+}
+
+/// Create `Object.defineProperty(object, key, descriptor)` call expression.
+pub(super) fn create_define_property<'a>(
+    object: Expression<'a>,
+    key: Expression<'a>,
+    descriptor: Expression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let callee = create_global("Object", ctx);
+    let callee = create_member_expression(callee, Atom::from("defineProperty"), ctx);
+    create_call(callee, [object, key, descriptor], ctx)
+}
+
+/// Create `"use strict"` directive.
+pub(super) fn create_use_strict<'a>(ctx: &TraverseCtx<'a>) -> Directive<'a> {
+    let literal = ctx.ast.string_literal(SPAN, "use strict");
+    ctx.ast.directive(SPAN, literal, "use strict")
+}
+
+/// Add `"use strict"` directive to `directives`, if it's not already present.
+pub(super) fn add_use_strict<'a>(
+    directives: &mut ArenaVec<'a, Directive<'a>>,
+    ctx: &TraverseCtx<'a>,
+) {
+    if !directives.iter().any(Directive::is_use_strict) {
+        directives.insert(0, create_use_strict(ctx));
+    }
+}
+
+/// Get name for binding of an imported module, based on the module specifier.
+///
+/// `"./foo/bar-baz.js"` -> `"barBaz"`.
+pub(super) fn module_binding_name(specifier: &str) -> String {
+    let basename = specifier.rsplit('/').next().unwrap_or(specifier);
+    let basename = match basename.rfind('.') {
+        Some(index) if index > 0 => &basename[..index],
+        _ => basename,
+    };
+    to_identifier(basename)
+}
+
+/// Convert a string to a valid identifier, by camel-casing it.
+///
+/// `"foo-bar"` -> `"fooBar"`, `"1foo"` -> `"foo"`.
+/// Same as Babel's `toIdentifier`.
+pub(super) fn to_identifier(name: &str) -> String {
+    let mut identifier = String::with_capacity(name.len());
+    let mut uppercase_next = false;
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() || c == '_' || c == '$' {
+            if identifier.is_empty() && c.is_ascii_digit() {
+                continue;
+            }
+            if uppercase_next && !identifier.is_empty() {
+                identifier.push(c.to_ascii_uppercase());
+            } else {
+                identifier.push(c);
+            }
+            uppercase_next = false;
+        } else {
+            uppercase_next = true;
+        }
+    }
+
+    if identifier.is_empty() {
+        return "_".to_string();
+    }
+    if !is_identifier_name(&identifier) || is_reserved_keyword(&identifier) {
+        identifier.insert(0, '_');
+    }
+    identifier
+}
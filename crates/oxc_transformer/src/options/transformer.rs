@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use serde_json::{from_value, json, Value};
 
@@ -7,16 +7,25 @@ use oxc_diagnostics::{Error, OxcDiagnostic};
 use crate::{
     common::helper_loader::{HelperLoaderMode, HelperLoaderOptions},
     compiler_assumptions::CompilerAssumptions,
+    decorator::DecoratorOptions,
     env::{can_enable_plugin, EnvOptions, Versions},
-    es2015::{ArrowFunctionsOptions, ES2015Options},
+    es2015::{
+        ArrowFunctionsOptions, BlockScopingOptions, ClassesOptions, DestructuringOptions,
+        ES2015Options, ForOfOptions, ParametersOptions, RegeneratorOptions, SpreadOptions,
+        TemplateLiteralsOptions,
+    },
     es2016::ES2016Options,
     es2017::options::ES2017Options,
     es2018::{ES2018Options, ObjectRestSpreadOptions},
     es2019::ES2019Options,
     es2020::ES2020Options,
     es2021::ES2021Options,
-    es2022::ES2022Options,
+    es2022::{ClassPropertiesOptions, ES2022Options},
+    explicit_resource_management::ExplicitResourceManagementOptions,
+    modules::{ModuleFormat, ModulesOptions},
     options::babel::BabelOptions,
+    plugins::{EmotionOptions, StyledComponentsOptions, TsconfigPathsOptions},
+    polyfills::CoreJsOptions,
     react::JsxOptions,
     regexp::RegExpOptions,
     typescript::TypeScriptOptions,
@@ -41,9 +50,29 @@ pub struct TransformOptions {
     /// [preset-typescript](https://babeljs.io/docs/babel-preset-typescript)
     pub typescript: TypeScriptOptions,
 
+    /// [proposal-decorators](https://babeljs.io/docs/babel-plugin-proposal-decorators)
+    ///
+    /// Enabled with legacy decorators if not set, and TypeScript's `experimental_decorators`
+    /// option is enabled.
+    pub decorator: Option<DecoratorOptions>,
+
+    /// [transform-explicit-resource-management](https://babel.dev/docs/babel-plugin-transform-explicit-resource-management)
+    pub explicit_resource_management: Option<ExplicitResourceManagementOptions>,
+
     /// [preset-react](https://babeljs.io/docs/babel-preset-react)
     pub react: JsxOptions,
 
+    /// [babel-plugin-styled-components](https://styled-components.com/docs/tooling#babel-plugin)
+    pub styled_components: Option<StyledComponentsOptions>,
+
+    /// [@emotion/babel-plugin](https://emotion.sh/docs/@emotion/babel-plugin)
+    ///
+    /// Labels are added in development mode if [`JsxOptions::development`] is enabled.
+    pub emotion: Option<EmotionOptions>,
+
+    /// Rewrite imports which use tsconfig `paths` and `baseUrl` aliases to relative paths.
+    pub tsconfig_paths: Option<TsconfigPathsOptions>,
+
     pub regexp: RegExpOptions,
 
     pub es2015: ES2015Options,
@@ -62,6 +91,19 @@ pub struct TransformOptions {
 
     pub es2022: ES2022Options,
 
+    /// Transform ES modules to another module format.
+    ///
+    /// * [transform-modules-commonjs](https://babel.dev/docs/babel-plugin-transform-modules-commonjs)
+    /// * [transform-modules-amd](https://babel.dev/docs/babel-plugin-transform-modules-amd)
+    /// * [transform-modules-umd](https://babel.dev/docs/babel-plugin-transform-modules-umd)
+    pub modules: Option<ModulesOptions>,
+
+    /// Add imports of core-js polyfills.
+    ///
+    /// Set by preset-env's [`useBuiltIns`](https://babel.dev/docs/babel-preset-env#usebuiltins)
+    /// and [`corejs`](https://babel.dev/docs/babel-preset-env#corejs) options.
+    pub core_js: Option<CoreJsOptions>,
+
     pub helper_loader: HelperLoaderOptions,
 }
 
@@ -72,11 +114,16 @@ impl TransformOptions {
             cwd: PathBuf::new(),
             assumptions: CompilerAssumptions::default(),
             typescript: TypeScriptOptions::default(),
+            decorator: Some(DecoratorOptions::default()),
+            explicit_resource_management: Some(ExplicitResourceManagementOptions::default()),
             react: JsxOptions {
                 development: true,
                 refresh: Some(ReactRefreshOptions::default()),
                 ..JsxOptions::default()
             },
+            styled_components: Some(StyledComponentsOptions::default()),
+            emotion: Some(EmotionOptions::default()),
+            tsconfig_paths: None,
             regexp: RegExpOptions {
                 sticky_flag: true,
                 unicode_flag: true,
@@ -90,17 +137,37 @@ impl TransformOptions {
             es2015: ES2015Options {
                 // Turned off because it is not ready.
                 arrow_function: None,
+                classes: Some(ClassesOptions::default()),
+                shorthand_properties: true,
+                computed_properties: true,
+                template_literals: Some(TemplateLiteralsOptions::default()),
+                spread: Some(SpreadOptions::default()),
+                parameters: Some(ParametersOptions::default()),
+                destructuring: Some(DestructuringOptions::default()),
+                block_scoping: Some(BlockScopingOptions::default()),
+                for_of: Some(ForOfOptions::default()),
+                regenerator: Some(RegeneratorOptions::default()),
             },
             es2016: ES2016Options { exponentiation_operator: true },
-            es2018: ES2018Options { object_rest_spread: Some(ObjectRestSpreadOptions::default()) },
+            es2018: ES2018Options {
+                object_rest_spread: Some(ObjectRestSpreadOptions::default()),
+                async_generator_functions: true,
+            },
             es2017: ES2017Options {
                 // Turned off because it is not ready.
                 async_to_generator: false,
             },
             es2019: ES2019Options { optional_catch_binding: true },
-            es2020: ES2020Options { nullish_coalescing_operator: true },
+            es2020: ES2020Options { nullish_coalescing_operator: true, optional_chaining: true },
             es2021: ES2021Options { logical_assignment_operators: true },
-            es2022: ES2022Options { class_static_block: true },
+            es2022: ES2022Options {
+                class_static_block: true,
+                class_properties: Some(ClassPropertiesOptions::default()),
+            },
+            // Turned off because it changes the module system of the output.
+            modules: None,
+            // Turned off because it depends on targets.
+            core_js: None,
             helper_loader: HelperLoaderOptions {
                 mode: HelperLoaderMode::Runtime,
                 ..Default::default()
@@ -123,13 +190,16 @@ impl TransformOptions {
         }
     }
 
+    /// `cwd` is set as [`TransformOptions::cwd`], and browserslist config is searched for
+    /// relative to it.
+    ///
     /// # Errors
     ///
     /// If there are any errors in the `options.targets``, they will be returned as a list of errors.
-    pub fn from_preset_env(env_options: &EnvOptions) -> Result<Self, Vec<Error>> {
+    pub fn from_preset_env(env_options: &EnvOptions, cwd: &Path) -> Result<Self, Vec<Error>> {
         let mut errors = Vec::<Error>::new();
 
-        let targets = match env_options.get_targets() {
+        let targets = match env_options.get_targets(cwd) {
             Ok(t) => Some(t),
             Err(err) => {
                 errors.push(OxcDiagnostic::error(err.to_string()).into());
@@ -137,7 +207,23 @@ impl TransformOptions {
             }
         };
         let bugfixes = env_options.bugfixes;
-        Ok(Self::from_targets_and_bugfixes(targets.as_ref(), bugfixes))
+        let mut options = Self::from_targets_and_bugfixes(targets.as_ref(), bugfixes);
+        options.cwd = cwd.to_path_buf();
+        match ModuleFormat::from_preset_env(env_options.modules.as_ref()) {
+            Ok(format) => options.modules = format.map(ModulesOptions::new),
+            Err(err) => {
+                errors.push(OxcDiagnostic::error(err).into());
+                return Err(errors);
+            }
+        }
+        match CoreJsOptions::from_preset_env(env_options, targets.as_ref()) {
+            Ok(core_js) => options.core_js = core_js,
+            Err(err) => {
+                errors.push(OxcDiagnostic::error(err).into());
+                return Err(errors);
+            }
+        }
+        Ok(options)
     }
 
     /// # Errors
@@ -159,7 +245,8 @@ impl TransformOptions {
             })
         };
 
-        let targets = env_options.as_ref().and_then(|env| match env.get_targets() {
+        let cwd = options.cwd.clone().unwrap_or_default();
+        let targets = env_options.as_ref().and_then(|env| match env.get_targets(&cwd) {
             Ok(res) => Some(res),
             Err(err) => {
                 errors.push(OxcDiagnostic::error(err.to_string()).into());
@@ -222,6 +309,112 @@ impl TransformOptions {
             )
         });
 
+        transformer_options.es2015.with_classes({
+            let plugin_name = "transform-classes";
+            get_enabled_plugin_options(plugin_name, options, targets.as_ref(), bugfixes).map(
+                |options| {
+                    from_value::<ClassesOptions>(options).unwrap_or_else(|err| {
+                        report_error(plugin_name, &err, false, &mut errors);
+                        ClassesOptions::default()
+                    })
+                },
+            )
+        });
+
+        transformer_options.es2015.with_shorthand_properties({
+            let plugin_name = "transform-shorthand-properties";
+            get_enabled_plugin_options(plugin_name, options, targets.as_ref(), bugfixes).is_some()
+        });
+
+        transformer_options.es2015.with_computed_properties({
+            let plugin_name = "transform-computed-properties";
+            get_enabled_plugin_options(plugin_name, options, targets.as_ref(), bugfixes).is_some()
+        });
+
+        transformer_options.es2015.with_template_literals({
+            let plugin_name = "transform-template-literals";
+            get_enabled_plugin_options(plugin_name, options, targets.as_ref(), bugfixes).map(
+                |options| {
+                    from_value::<TemplateLiteralsOptions>(options).unwrap_or_else(|err| {
+                        report_error(plugin_name, &err, false, &mut errors);
+                        TemplateLiteralsOptions::default()
+                    })
+                },
+            )
+        });
+
+        transformer_options.es2015.with_spread({
+            let plugin_name = "transform-spread";
+            get_enabled_plugin_options(plugin_name, options, targets.as_ref(), bugfixes).map(
+                |options| {
+                    from_value::<SpreadOptions>(options).unwrap_or_else(|err| {
+                        report_error(plugin_name, &err, false, &mut errors);
+                        SpreadOptions::default()
+                    })
+                },
+            )
+        });
+
+        transformer_options.es2015.with_parameters({
+            let plugin_name = "transform-parameters";
+            get_enabled_plugin_options(plugin_name, options, targets.as_ref(), bugfixes).map(
+                |options| {
+                    from_value::<ParametersOptions>(options).unwrap_or_else(|err| {
+                        report_error(plugin_name, &err, false, &mut errors);
+                        ParametersOptions::default()
+                    })
+                },
+            )
+        });
+
+        transformer_options.es2015.with_destructuring({
+            let plugin_name = "transform-destructuring";
+            get_enabled_plugin_options(plugin_name, options, targets.as_ref(), bugfixes).map(
+                |options| {
+                    from_value::<DestructuringOptions>(options).unwrap_or_else(|err| {
+                        report_error(plugin_name, &err, false, &mut errors);
+                        DestructuringOptions::default()
+                    })
+                },
+            )
+        });
+
+        transformer_options.es2015.with_block_scoping({
+            let plugin_name = "transform-block-scoping";
+            get_enabled_plugin_options(plugin_name, options, targets.as_ref(), bugfixes).map(
+                |options| {
+                    from_value::<BlockScopingOptions>(options).unwrap_or_else(|err| {
+                        report_error(plugin_name, &err, false, &mut errors);
+                        BlockScopingOptions::default()
+                    })
+                },
+            )
+        });
+
+        transformer_options.es2015.with_for_of({
+            let plugin_name = "transform-for-of";
+            get_enabled_plugin_options(plugin_name, options, targets.as_ref(), bugfixes).map(
+                |options| {
+                    from_value::<ForOfOptions>(options).unwrap_or_else(|err| {
+                        report_error(plugin_name, &err, false, &mut errors);
+                        ForOfOptions::default()
+                    })
+                },
+            )
+        });
+
+        transformer_options.es2015.with_regenerator({
+            let plugin_name = "transform-regenerator";
+            get_enabled_plugin_options(plugin_name, options, targets.as_ref(), bugfixes).map(
+                |options| {
+                    from_value::<RegeneratorOptions>(options).unwrap_or_else(|err| {
+                        report_error(plugin_name, &err, false, &mut errors);
+                        RegeneratorOptions::default()
+                    })
+                },
+            )
+        });
+
         transformer_options.es2016.with_exponentiation_operator({
             let plugin_name = "transform-exponentiation-operator";
             get_enabled_plugin_options(plugin_name, options, targets.as_ref(), bugfixes).is_some()
@@ -232,6 +425,11 @@ impl TransformOptions {
             get_enabled_plugin_options(plugin_name, options, targets.as_ref(), bugfixes).is_some()
         });
 
+        transformer_options.es2018.with_async_generator_functions({
+            let plugin_name = "transform-async-generator-functions";
+            get_enabled_plugin_options(plugin_name, options, targets.as_ref(), bugfixes).is_some()
+        });
+
         transformer_options.es2018.with_object_rest_spread({
             let plugin_name = "transform-object-rest-spread";
             get_enabled_plugin_options(plugin_name, options, targets.as_ref(), bugfixes).map(
@@ -254,6 +452,11 @@ impl TransformOptions {
             get_enabled_plugin_options(plugin_name, options, targets.as_ref(), bugfixes).is_some()
         });
 
+        transformer_options.es2020.with_optional_chaining({
+            let plugin_name = "transform-optional-chaining";
+            get_enabled_plugin_options(plugin_name, options, targets.as_ref(), bugfixes).is_some()
+        });
+
         transformer_options.es2021.with_logical_assignment_operators({
             let plugin_name = "transform-logical-assignment-operators";
             get_enabled_plugin_options(plugin_name, options, targets.as_ref(), bugfixes).is_some()
@@ -264,6 +467,28 @@ impl TransformOptions {
             get_enabled_plugin_options(plugin_name, options, targets.as_ref(), bugfixes).is_some()
         });
 
+        transformer_options.es2022.with_class_properties({
+            // Class properties, private methods and private-property-in-object
+            // are all implemented by the same transform
+            [
+                "transform-class-properties",
+                "transform-private-methods",
+                "transform-private-property-in-object",
+            ]
+            .into_iter()
+            .filter_map(|plugin_name| {
+                get_enabled_plugin_options(plugin_name, options, targets.as_ref(), bugfixes).map(
+                    |options| {
+                        from_value::<ClassPropertiesOptions>(options).unwrap_or_else(|err| {
+                            report_error(plugin_name, &err, false, &mut errors);
+                            ClassPropertiesOptions::default()
+                        })
+                    },
+                )
+            })
+            .reduce(|a, b| ClassPropertiesOptions { loose: a.loose || b.loose })
+        });
+
         transformer_options.typescript = {
             let preset_name = "typescript";
             if options.has_preset("typescript") {
@@ -284,6 +509,106 @@ impl TransformOptions {
             }
         };
 
+        transformer_options.decorator = {
+            let plugin_name = "proposal-decorators";
+            options.get_plugin(plugin_name).map(|options| {
+                from_value::<DecoratorOptions>(options.unwrap_or_else(|| json!({}))).unwrap_or_else(
+                    |err| {
+                        report_error(plugin_name, &err, false, &mut errors);
+                        DecoratorOptions::default()
+                    },
+                )
+            })
+        };
+
+        transformer_options.styled_components = {
+            ["styled-components", "babel-plugin-styled-components"].into_iter().find_map(
+                |plugin_name| {
+                    options.get_plugin(plugin_name).map(|options| {
+                        from_value::<StyledComponentsOptions>(options.unwrap_or_else(|| json!({})))
+                            .unwrap_or_else(|err| {
+                                report_error(plugin_name, &err, false, &mut errors);
+                                StyledComponentsOptions::default()
+                            })
+                    })
+                },
+            )
+        };
+
+        transformer_options.emotion = {
+            ["@emotion", "@emotion/babel-plugin"].into_iter().find_map(|plugin_name| {
+                options.get_plugin(plugin_name).map(|options| {
+                    from_value::<EmotionOptions>(options.unwrap_or_else(|| json!({})))
+                        .unwrap_or_else(|err| {
+                            report_error(plugin_name, &err, false, &mut errors);
+                            EmotionOptions::default()
+                        })
+                })
+            })
+        };
+
+        transformer_options.tsconfig_paths = {
+            let plugin_name = "tsconfig-paths";
+            options.get_plugin(plugin_name).map(|options| {
+                from_value::<TsconfigPathsOptions>(options.unwrap_or_else(|| json!({})))
+                    .unwrap_or_else(|err| {
+                        report_error(plugin_name, &err, false, &mut errors);
+                        TsconfigPathsOptions::default()
+                    })
+            })
+        };
+
+        transformer_options.explicit_resource_management = {
+            ["transform-explicit-resource-management", "proposal-explicit-resource-management"]
+                .into_iter()
+                .find_map(|plugin_name| {
+                    options.get_plugin(plugin_name).map(|options| {
+                        from_value::<ExplicitResourceManagementOptions>(
+                            options.unwrap_or_else(|| json!({})),
+                        )
+                        .unwrap_or_else(|err| {
+                            report_error(plugin_name, &err, false, &mut errors);
+                            ExplicitResourceManagementOptions::default()
+                        })
+                    })
+                })
+        };
+
+        transformer_options.modules = {
+            let mut modules = env_options.as_ref().and_then(|env| {
+                ModuleFormat::from_preset_env(env.modules.as_ref())
+                    .unwrap_or_else(|err| {
+                        errors.push(OxcDiagnostic::error(format!("preset-env: {err}")).into());
+                        None
+                    })
+                    .map(ModulesOptions::new)
+            });
+            for (plugin_name, format) in [
+                ("transform-modules-commonjs", ModuleFormat::CommonJS),
+                ("transform-modules-amd", ModuleFormat::AMD),
+                ("transform-modules-umd", ModuleFormat::UMD),
+            ] {
+                if let Some(plugin_options) = options.get_plugin(plugin_name) {
+                    let plugin_options = plugin_options.unwrap_or_else(|| json!({}));
+                    let mut plugin_options = from_value::<ModulesOptions>(plugin_options)
+                        .unwrap_or_else(|err| {
+                            report_error(plugin_name, &err, false, &mut errors);
+                            ModulesOptions::default()
+                        });
+                    plugin_options.format = format;
+                    modules = Some(plugin_options);
+                }
+            }
+            modules
+        };
+
+        transformer_options.core_js = env_options.as_ref().and_then(|env| {
+            CoreJsOptions::from_preset_env(env, targets.as_ref()).unwrap_or_else(|err| {
+                errors.push(OxcDiagnostic::error(format!("preset-env: {err}")).into());
+                None
+            })
+        });
+
         let regexp = transformer_options.regexp;
         if !regexp.sticky_flag {
             transformer_options.regexp.sticky_flag = options.has_plugin("transform-sticky-regex");
@@ -323,7 +648,7 @@ impl TransformOptions {
             transformer_options.helper_loader.mode = HelperLoaderMode::External;
         }
 
-        transformer_options.cwd = options.cwd.clone().unwrap_or_default();
+        transformer_options.cwd = cwd;
 
         if !errors.is_empty() {
             return Err(errors);
@@ -0,0 +1,256 @@
+//! Utilities shared by the CSS-in-JS plugins.
+
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::ast::*;
+use oxc_syntax::symbol::SymbolId;
+use oxc_traverse::{Ancestor, TraverseCtx};
+
+/// Stands in for interpolations while minifying the quasis of a template literal.
+/// A private use character, so it can't be confused with CSS syntax.
+const PLACEHOLDER: char = '\u{E000}';
+
+/// Bindings imported from CSS-in-JS packages, keyed by symbol.
+/// Values are the imported names, with `default` for default imports.
+#[derive(Default)]
+pub(crate) struct CssImports<'a> {
+    imports: FxHashMap<SymbolId, Atom<'a>>,
+}
+
+impl<'a> CssImports<'a> {
+    /// Collect bindings imported from any of `sources`.
+    pub(crate) fn collect(program: &Program<'a>, sources: &[&str]) -> Self {
+        let mut imports = FxHashMap::default();
+        for stmt in &program.body {
+            let Statement::ImportDeclaration(decl) = stmt else { continue };
+            if decl.import_kind.is_type() || !sources.contains(&decl.source.value.as_str()) {
+                continue;
+            }
+            let Some(specifiers) = &decl.specifiers else { continue };
+            for specifier in specifiers {
+                let imported = match specifier {
+                    ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                        if specifier.import_kind.is_type() {
+                            continue;
+                        }
+                        specifier.imported.name()
+                    }
+                    ImportDeclarationSpecifier::ImportDefaultSpecifier(_) => Atom::from("default"),
+                    ImportDeclarationSpecifier::ImportNamespaceSpecifier(_) => continue,
+                };
+                imports.insert(specifier.local().symbol_id.get().unwrap(), imported);
+            }
+        }
+        Self { imports }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.imports.is_empty()
+    }
+
+    /// Get the imported name, if `expr` is a reference to an imported binding.
+    pub(crate) fn imported_name(
+        &self,
+        expr: &Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<&Atom<'a>> {
+        let Expression::Identifier(ident) = expr else { return None };
+        let reference_id = ident.reference_id.get()?;
+        let symbol_id = ctx.symbols().get_reference(reference_id).symbol_id()?;
+        self.imports.get(&symbol_id)
+    }
+}
+
+/// Get the name of the binding or property a CSS-in-JS expression is assigned to.
+///
+/// * ``const Button = styled.div`...` `` -> `Button`
+/// * ``Button = styled.div`...` `` or ``x.Button = styled.div`...` `` -> `Button`
+/// * ``{ Button: styled.div`...` }`` -> `Button`
+pub(crate) fn binding_name<'a>(ctx: &TraverseCtx<'a>) -> Option<Atom<'a>> {
+    for ancestor in ctx.ancestors() {
+        match ancestor {
+            Ancestor::VariableDeclaratorInit(declarator) => {
+                return match &declarator.id().kind {
+                    BindingPatternKind::BindingIdentifier(ident) => Some(ident.name.clone()),
+                    _ => None,
+                };
+            }
+            Ancestor::AssignmentExpressionRight(assign_expr) => {
+                return match assign_expr.left() {
+                    AssignmentTarget::AssignmentTargetIdentifier(ident) => Some(ident.name.clone()),
+                    AssignmentTarget::StaticMemberExpression(expr) => {
+                        Some(expr.property.name.clone())
+                    }
+                    _ => None,
+                };
+            }
+            Ancestor::ObjectPropertyValue(prop) => {
+                return prop.key().static_name().map(|name| ctx.ast.atom(&name));
+            }
+            // Stop crawling up when hit a statement
+            _ if ancestor.is_via_statement() => return None,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Minify the CSS in the quasis of a tagged template.
+///
+/// Quasis containing escapes are left as is, as minifying them would require
+/// keeping `raw` and `cooked` in sync.
+pub(crate) fn minify_quasis<'a>(
+    quasis: &mut ArenaVec<'a, TemplateElement<'a>>,
+    ctx: &TraverseCtx<'a>,
+) {
+    if quasis.iter().any(|quasi| quasi.value.raw.contains(['\\', PLACEHOLDER])) {
+        return;
+    }
+    let css = quasis.iter().map(|quasi| quasi.value.raw.as_str()).collect::<Vec<_>>();
+    let css = minify_css(&css.join(PLACEHOLDER.encode_utf8(&mut [0; 4])));
+    let parts = css.split(PLACEHOLDER).collect::<Vec<_>>();
+    // An interpolation was inside a comment, which has been removed
+    if parts.len() != quasis.len() {
+        return;
+    }
+    for (quasi, part) in quasis.iter_mut().zip(parts) {
+        let value = ctx.ast.atom(part);
+        quasi.value.raw = value.clone();
+        quasi.value.cooked = Some(value);
+    }
+}
+
+/// Remove comments and collapse whitespace in CSS.
+///
+/// Whitespace is removed entirely next to `{`, `}`, `;` and `,`, and after `:`.
+/// Strings and line comments inside parentheses (e.g. `url(http://...)`) are preserved.
+pub(crate) fn minify_css(css: &str) -> String {
+    fn is_symbol(c: char) -> bool {
+        matches!(c, '{' | '}' | ';' | ',')
+    }
+
+    let mut minified = String::with_capacity(css.len());
+    let mut chars = css.chars().peekable();
+    let mut quote = None;
+    let mut paren_depth = 0u32;
+    let mut pending_space = false;
+    while let Some(c) = chars.next() {
+        if let Some(q) = quote {
+            minified.push(c);
+            if c == q {
+                quote = None;
+            }
+            continue;
+        }
+        match c {
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut prev = '\0';
+                for c in chars.by_ref() {
+                    if prev == '*' && c == '/' {
+                        break;
+                    }
+                    prev = c;
+                }
+                pending_space = true;
+            }
+            '/' if chars.peek() == Some(&'/') && paren_depth == 0 => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+                pending_space = true;
+            }
+            c if c.is_whitespace() => pending_space = true,
+            _ => {
+                if pending_space
+                    && !is_symbol(c)
+                    && minified.chars().last().is_some_and(|last| !is_symbol(last) && last != ':')
+                {
+                    minified.push(' ');
+                }
+                pending_space = false;
+                match c {
+                    '"' | '\'' => quote = Some(c),
+                    '(' => paren_depth += 1,
+                    ')' => paren_depth = paren_depth.saturating_sub(1),
+                    _ => {}
+                }
+                minified.push(c);
+            }
+        }
+    }
+    minified
+}
+
+/// MurmurHash2 of `s` in base 36, as used by `@emotion/hash` and `styled-components`.
+pub(crate) fn hash(s: &str) -> String {
+    const M: u32 = 0x5bd1_e995;
+
+    let mut h = 0u32;
+    let mut chunks = s.as_bytes().chunks_exact(4);
+    for chunk in &mut chunks {
+        let mut k = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]).wrapping_mul(M);
+        k ^= k >> 24;
+        h = k.wrapping_mul(M) ^ h.wrapping_mul(M);
+    }
+    let rest = chunks.remainder();
+    if rest.len() >= 3 {
+        h ^= u32::from(rest[2]) << 16;
+    }
+    if rest.len() >= 2 {
+        h ^= u32::from(rest[1]) << 8;
+    }
+    if let Some(&first) = rest.first() {
+        h ^= u32::from(first);
+        h = h.wrapping_mul(M);
+    }
+    h ^= h >> 13;
+    h = h.wrapping_mul(M);
+    h ^= h >> 15;
+
+    to_base36(h)
+}
+
+fn to_base36(mut n: u32) -> String {
+    const DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+    let mut digits = vec![];
+    loop {
+        digits.push(DIGITS[(n % 36) as usize]);
+        n /= 36;
+        if n == 0 {
+            break;
+        }
+    }
+    digits.reverse();
+    String::from_utf8(digits).unwrap()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{hash, minify_css};
+
+    #[test]
+    fn minify() {
+        assert_eq!(
+            minify_css("\n  color: red;\n  /* comment */\n  &:hover {\n    color: blue;\n  }\n"),
+            "color:red;&:hover{color:blue;}"
+        );
+        assert_eq!(minify_css("a , b { margin : 0  auto }"), "a,b{margin :0 auto}");
+        assert_eq!(
+            minify_css("background: url(http://x.com/a.png); // comment\ncontent: ' a  b '"),
+            "background:url(http://x.com/a.png);content:' a  b '"
+        );
+    }
+
+    #[test]
+    fn murmur_hash() {
+        // Values from `@emotion/hash`
+        assert_eq!(hash(""), "0");
+        assert_eq!(hash("abc"), "1pgwlfu");
+        assert_eq!(hash("abcd"), "rsjkja");
+        assert_eq!(hash("hello world"), "6opb3n");
+    }
+}
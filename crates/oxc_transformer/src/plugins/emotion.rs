@@ -0,0 +1,282 @@
+use cow_utils::CowUtils;
+use serde::Deserialize;
+
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::{ast::*, NONE};
+use oxc_span::SPAN;
+use oxc_traverse::{Traverse, TraverseCtx};
+
+use super::css::{binding_name, hash, minify_quasis, CssImports};
+use crate::TransformCtx;
+
+/// Options for [`@emotion/babel-plugin`](https://emotion.sh/docs/@emotion/babel-plugin).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct EmotionOptions {
+    /// When to add a label to styles, for easier debugging.
+    ///
+    /// Default: `dev-only`
+    pub auto_label: EmotionAutoLabel,
+
+    /// Format of labels. `[local]` is replaced with the name of the variable the styles are
+    /// assigned to, `[filename]` with the name of the file, and `[dirname]` with the name of
+    /// the directory containing the file.
+    ///
+    /// Default: `[local]`
+    pub label_format: String,
+}
+
+impl Default for EmotionOptions {
+    fn default() -> Self {
+        Self { auto_label: EmotionAutoLabel::default(), label_format: String::from("[local]") }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum EmotionAutoLabel {
+    Never,
+    /// Only when [`crate::JsxOptions::development`] is enabled.
+    #[default]
+    DevOnly,
+    Always,
+}
+
+/// Port of [@emotion/babel-plugin](https://github.com/emotion-js/emotion/tree/main/packages/babel-plugin).
+///
+/// Tagged templates are converted to calls with minified CSS, styled components are given
+/// a stable `target` class name, and labels are added to styles:
+///
+/// ```js
+/// import { css } from "@emotion/react";
+/// import styled from "@emotion/styled";
+/// const container = css`
+///   color: ${color};
+/// `;
+/// const Button = styled.button`
+///   padding: 0;
+/// `;
+/// ```
+///
+/// is transformed to:
+///
+/// ```js
+/// import { css } from "@emotion/react";
+/// import styled from "@emotion/styled";
+/// const container = css("color:", color, ";label:container;");
+/// const Button = styled("button", {
+///   target: "e1pgwlfu0",
+///   label: "Button"
+/// })("padding:0;");
+/// ```
+pub(crate) struct Emotion<'a, 'ctx> {
+    options: Option<EmotionOptions>,
+    ctx: &'ctx TransformCtx<'a>,
+    add_labels: bool,
+
+    // States
+    imports: CssImports<'a>,
+    /// Hash of the source text, used to make `target` class names unique to this file.
+    file_hash: String,
+    /// Number of `target` class names generated so far.
+    target_count: usize,
+}
+
+impl<'a, 'ctx> Emotion<'a, 'ctx> {
+    pub(crate) fn new(
+        options: Option<EmotionOptions>,
+        development: bool,
+        ctx: &'ctx TransformCtx<'a>,
+    ) -> Self {
+        let add_labels = options.as_ref().is_some_and(|options| match options.auto_label {
+            EmotionAutoLabel::Never => false,
+            EmotionAutoLabel::DevOnly => development,
+            EmotionAutoLabel::Always => true,
+        });
+        Self {
+            options,
+            ctx,
+            add_labels,
+            imports: CssImports::default(),
+            file_hash: String::new(),
+            target_count: 0,
+        }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for Emotion<'a, 'ctx> {
+    fn enter_program(&mut self, program: &mut Program<'a>, _ctx: &mut TraverseCtx<'a>) {
+        if self.options.is_none() {
+            return;
+        }
+        self.imports =
+            CssImports::collect(program, &["@emotion/react", "@emotion/css", "@emotion/styled"]);
+        self.file_hash = hash(self.ctx.source_text);
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.is_none() || self.imports.is_empty() {
+            return;
+        }
+        match expr {
+            // `css`...``
+            Expression::TaggedTemplateExpression(tagged) => {
+                if let Some(labelled) = self.css_helper(&tagged.tag, ctx) {
+                    if let Some(mut arguments) = Self::template_to_arguments(&mut tagged.quasi, ctx)
+                    {
+                        if labelled {
+                            self.add_label(&mut arguments, ctx);
+                        }
+                        let callee = ctx.ast.move_expression(&mut tagged.tag);
+                        *expr = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+                    }
+                } else if self.is_styled(&tagged.tag, ctx) {
+                    if let Some(arguments) = Self::template_to_arguments(&mut tagged.quasi, ctx) {
+                        let callee = self.create_styled(&mut tagged.tag, ctx);
+                        *expr = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+                    }
+                }
+            }
+            // `css({ ... })`, `styled.div({ ... })`
+            Expression::CallExpression(call) => {
+                if self.css_helper(&call.callee, ctx) == Some(true) {
+                    self.add_label(&mut call.arguments, ctx);
+                } else if self.is_styled(&call.callee, ctx) {
+                    call.callee = self.create_styled(&mut call.callee, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a, 'ctx> Emotion<'a, 'ctx> {
+    /// `css`, `keyframes` or `injectGlobal`.
+    /// Returns whether a label is added to the styles.
+    fn css_helper(&self, expr: &Expression<'a>, ctx: &TraverseCtx<'a>) -> Option<bool> {
+        match self.imports.imported_name(expr, ctx)?.as_str() {
+            "css" | "keyframes" => Some(true),
+            "injectGlobal" => Some(false),
+            _ => None,
+        }
+    }
+
+    /// `styled.div` or `styled(Component)`
+    fn is_styled(&self, expr: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        let is_styled_import =
+            |expr| self.imports.imported_name(expr, ctx).is_some_and(|name| name == "default");
+        match expr {
+            Expression::StaticMemberExpression(member) => is_styled_import(&member.object),
+            Expression::CallExpression(call) => {
+                call.arguments.len() == 1 && is_styled_import(&call.callee)
+            }
+            _ => false,
+        }
+    }
+
+    /// `css`color: ${color};`` -> `css("color:", color, ";")`
+    ///
+    /// Returns `None` if the template contains invalid escapes.
+    fn template_to_arguments(
+        quasi: &mut TemplateLiteral<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<ArenaVec<'a, Argument<'a>>> {
+        if quasi.quasis.iter().any(|quasi| quasi.value.cooked.is_none()) {
+            return None;
+        }
+        minify_quasis(&mut quasi.quasis, ctx);
+        let mut expressions = ctx.ast.move_vec(&mut quasi.expressions).into_iter();
+        let mut arguments = ctx.ast.vec();
+        for quasi in &quasi.quasis {
+            let value = quasi.value.cooked.clone().unwrap();
+            if !value.is_empty() {
+                arguments.push(Argument::from(ctx.ast.expression_string_literal(SPAN, value)));
+            }
+            if let Some(expr) = expressions.next() {
+                arguments.push(Argument::from(expr));
+            }
+        }
+        Some(arguments)
+    }
+
+    /// `styled.div` -> `styled("div", { target: "e1abc0", label: "Button" })`
+    /// `styled(Component)` -> `styled(Component, { target: "e1abc0", label: "Button" })`
+    fn create_styled(
+        &mut self,
+        expr: &mut Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut properties = ctx.ast.vec();
+        let target = format!("e{}{}", self.file_hash, self.target_count);
+        self.target_count += 1;
+        properties.push(Self::create_property("target", &target, ctx));
+        if let Some(label) = self.label(ctx) {
+            properties.push(Self::create_property("label", &label, ctx));
+        }
+        let options = Argument::from(ctx.ast.expression_object(SPAN, properties, None));
+
+        match ctx.ast.move_expression(expr) {
+            Expression::StaticMemberExpression(member) => {
+                let member = member.unbox();
+                let tag = ctx.ast.expression_string_literal(SPAN, member.property.name);
+                let arguments = ctx.ast.vec_from_iter([Argument::from(tag), options]);
+                ctx.ast.expression_call(SPAN, member.object, NONE, arguments, false)
+            }
+            Expression::CallExpression(mut call) => {
+                call.arguments.push(options);
+                Expression::CallExpression(call)
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    /// Append `label:name;` to styles.
+    fn add_label(&self, arguments: &mut ArenaVec<'a, Argument<'a>>, ctx: &TraverseCtx<'a>) {
+        let Some(label) = self.label(ctx) else { return };
+        if let Some(Argument::StringLiteral(last)) = arguments.last_mut() {
+            last.value = ctx.ast.atom(&format!("{}label:{label};", last.value));
+        } else {
+            let label = ctx.ast.atom(&format!("label:{label};"));
+            arguments.push(Argument::from(ctx.ast.expression_string_literal(SPAN, label)));
+        }
+    }
+
+    /// Label for styles, from [`EmotionOptions::label_format`].
+    ///
+    /// Returns `None` if labels are disabled, or the styles are not assigned to a variable or property.
+    fn label(&self, ctx: &TraverseCtx<'a>) -> Option<String> {
+        if !self.add_labels {
+            return None;
+        }
+        let local = binding_name(ctx)?;
+        let options = self.options.as_ref().unwrap();
+        let dirname = self
+            .ctx
+            .source_path
+            .parent()
+            .and_then(|dir| dir.file_name())
+            .map(|dir| dir.to_string_lossy())
+            .unwrap_or_default();
+        let label = options
+            .label_format
+            .cow_replace("[local]", local.as_str())
+            .cow_replace("[filename]", &self.ctx.filename)
+            .cow_replace("[dirname]", &dirname)
+            .into_owned();
+        // Class names can only contain word characters and `-`
+        Some(label.chars().map(|c| if c.is_alphanumeric() || c == '_' { c } else { '-' }).collect())
+    }
+
+    fn create_property(key: &str, value: &str, ctx: &TraverseCtx<'a>) -> ObjectPropertyKind<'a> {
+        ctx.ast.object_property_kind_object_property(
+            SPAN,
+            PropertyKind::Init,
+            ctx.ast.property_key_identifier_name(SPAN, ctx.ast.atom(key)),
+            ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(value)),
+            None,
+            false,
+            false,
+            false,
+        )
+    }
+}
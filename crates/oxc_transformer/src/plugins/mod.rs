@@ -1,5 +1,12 @@
+mod css;
+mod emotion;
 mod inject_global_variables;
 mod replace_global_defines;
+mod styled_components;
+mod tsconfig_paths;
 
+pub use emotion::*;
 pub use inject_global_variables::*;
 pub use replace_global_defines::*;
+pub use styled_components::*;
+pub use tsconfig_paths::*;
@@ -1,13 +1,15 @@
 use std::{cmp::Ordering, sync::Arc};
 
+use cow_utils::CowUtils;
+
 use oxc_allocator::Allocator;
 use oxc_ast::ast::*;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_parser::Parser;
 use oxc_semantic::{IsGlobalReference, ScopeTree, SymbolTable};
-use oxc_span::{CompactStr, SourceType};
-use oxc_syntax::identifier::is_identifier_name;
-use oxc_traverse::{traverse_mut, Traverse, TraverseCtx};
+use oxc_span::{CompactStr, SourceType, SPAN};
+use oxc_syntax::{identifier::is_identifier_name, operator::UnaryOperator, symbol::SymbolFlags};
+use oxc_traverse::{traverse_mut, BoundIdentifier, Traverse, TraverseCtx};
 
 /// Configuration for [ReplaceGlobalDefines].
 ///
@@ -15,6 +17,18 @@ use oxc_traverse::{traverse_mut, Traverse, TraverseCtx};
 /// and does not save the constructed expression.
 ///
 /// The data is stored in an `Arc` so this can be shared across threads.
+///
+/// Keys can be:
+///
+/// * an identifier, e.g. `DEBUG`
+/// * a member expression, e.g. `process.env.NODE_ENV`. `?.` is the same as `.`, so
+///   `process?.env?.NODE_ENV` is also accepted, and both keys match `process.env.NODE_ENV`
+///   and `process?.env?.NODE_ENV`.
+/// * `import.meta`, optionally followed by properties and a postfix wildcard, e.g. `import.meta.env.*`
+/// * `typeof` followed by an identifier or member expression, e.g. `typeof window`
+///
+/// Values which are object or array literals, e.g. `{ "NODE_ENV": "production" }`, are declared
+/// once at the top of the program and referenced, instead of being duplicated at each site.
 #[derive(Debug, Clone)]
 pub struct ReplaceGlobalDefinesConfig(Arc<ReplaceGlobalDefinesConfigImpl>);
 
@@ -23,6 +37,8 @@ struct ReplaceGlobalDefinesConfigImpl {
     identifier: Vec<(/* key */ CompactStr, /* value */ CompactStr)>,
     dot: Vec<DotDefine>,
     meta_property: Vec<MetaPropertyDefine>,
+    /// `typeof` keys, with the parts of the identifier or member expression after `typeof`
+    typeof_defines: Vec<DotDefine>,
     /// extra field to avoid linear scan `meta_property` to check if it has `import.meta` every
     /// time
     /// Some(replacement): import.meta -> replacement
@@ -64,12 +80,15 @@ enum IdentifierType {
     ImportMetaWithParts { parts: Vec<CompactStr>, postfix_wildcard: bool },
     // import.meta or import.meta.*
     ImportMeta(bool),
+    // typeof a or typeof a.b
+    Typeof { parts: Vec<CompactStr> },
 }
 
 impl ReplaceGlobalDefinesConfig {
     /// # Errors
     ///
     /// * key is not an identifier
+    /// * key is `typeof import.meta`
     /// * value has a syntax error
     pub fn new<S: AsRef<str>>(defines: &[(S, S)]) -> Result<Self, Vec<OxcDiagnostic>> {
         let allocator = Allocator::default();
@@ -77,6 +96,7 @@ impl ReplaceGlobalDefinesConfig {
         let mut dot_defines = vec![];
         let mut meta_properties_defines = vec![];
         let mut import_meta = None;
+        let mut typeof_defines = vec![];
         for (key, value) in defines {
             let key = key.as_ref();
 
@@ -108,6 +128,9 @@ impl ReplaceGlobalDefinesConfig {
                         import_meta = Some(CompactStr::new(value));
                     }
                 }
+                IdentifierType::Typeof { parts } => {
+                    typeof_defines.push(DotDefine::new(parts, CompactStr::new(value)));
+                }
             }
         }
         // Always move specific meta define before wildcard dot define
@@ -127,10 +150,25 @@ impl ReplaceGlobalDefinesConfig {
             dot: dot_defines,
             meta_property: meta_properties_defines,
             import_meta,
+            typeof_defines,
         })))
     }
 
     fn check_key(key: &str) -> Result<IdentifierType, Vec<OxcDiagnostic>> {
+        if let Some(argument) = key.strip_prefix("typeof ") {
+            return match Self::check_key(argument.trim_start())? {
+                IdentifierType::Identifier => {
+                    Ok(IdentifierType::Typeof { parts: vec![CompactStr::new(argument.trim())] })
+                }
+                IdentifierType::DotDefines { parts } => Ok(IdentifierType::Typeof { parts }),
+                _ => Err(vec![OxcDiagnostic::error(format!(
+                    "`{key}` is not supported, `typeof` can only be followed by an identifier or member expression."
+                ))]),
+            };
+        }
+
+        // `a?.b` is treated the same as `a.b`
+        let key = key.cow_replace("?.", ".");
         let parts: Vec<&str> = key.split('.').collect();
 
         assert!(!parts.is_empty());
@@ -202,10 +240,29 @@ pub struct ReplaceGlobalDefinesReturn {
 pub struct ReplaceGlobalDefines<'a> {
     allocator: &'a Allocator,
     config: ReplaceGlobalDefinesConfig,
+
+    /// Object and array values which have been used, to be declared at the top of the program.
+    hoisted_values: Vec<(/* value */ CompactStr, BoundIdentifier<'a>, Expression<'a>)>,
 }
 
 impl<'a> Traverse<'a> for ReplaceGlobalDefines<'a> {
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.hoisted_values.is_empty() {
+            return;
+        }
+        // `const _process_env = { NODE_ENV: "production" };`
+        let kind = VariableDeclarationKind::Const;
+        let declarations =
+            ctx.ast.vec_from_iter(self.hoisted_values.drain(..).map(|(_, binding, value)| {
+                let id = binding.create_binding_pattern(ctx);
+                ctx.ast.variable_declarator(SPAN, kind, id, Some(value), false)
+            }));
+        let declaration = ctx.ast.declaration_variable(SPAN, kind, declarations, false);
+        program.body.insert(0, Statement::from(declaration));
+    }
+
     fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.replace_typeof_defines(expr, ctx);
         self.replace_identifier_defines(expr, ctx);
         self.replace_dot_defines(expr, ctx);
     }
@@ -213,7 +270,7 @@ impl<'a> Traverse<'a> for ReplaceGlobalDefines<'a> {
 
 impl<'a> ReplaceGlobalDefines<'a> {
     pub fn new(allocator: &'a Allocator, config: ReplaceGlobalDefinesConfig) -> Self {
-        Self { allocator, config }
+        Self { allocator, config, hoisted_values: vec![] }
     }
 
     pub fn build(
@@ -234,48 +291,104 @@ impl<'a> ReplaceGlobalDefines<'a> {
         Parser::new(self.allocator, source_text, SourceType::default()).parse_expression().unwrap()
     }
 
-    fn replace_identifier_defines(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+    /// Create the replacement for a define.
+    ///
+    /// Object and array values are declared once at the top of the program (in [`Self::exit_program`]),
+    /// and replaced with a reference to the declaration. `name` is used to name the declaration.
+    fn create_value(
+        &mut self,
+        name: &str,
+        value: &CompactStr,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if let Some((_, binding, _)) = self.hoisted_values.iter().find(|(v, _, _)| v == value) {
+            return binding.create_read_expression(ctx);
+        }
+        let expr = self.parse_value(value);
+        if !matches!(
+            expr.without_parentheses(),
+            Expression::ObjectExpression(_) | Expression::ArrayExpression(_)
+        ) {
+            return expr;
+        }
+        let binding = ctx.generate_uid_in_root_scope(
+            name,
+            SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable,
+        );
+        let reference = binding.create_read_expression(ctx);
+        self.hoisted_values.push((value.clone(), binding, expr));
+        reference
+    }
+
+    /// `typeof window` -> `"object"`
+    fn replace_typeof_defines(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::UnaryExpression(unary) = expr else { return };
+        if unary.operator != UnaryOperator::Typeof {
+            return;
+        }
+        let config = Arc::clone(&self.config.0);
+        let matched = config.typeof_defines.iter().find(|define| match &unary.argument {
+            Expression::Identifier(ident) => {
+                define.parts.len() == 1
+                    && ident.name == define.parts[0]
+                    && ident.is_global_reference(ctx.symbols())
+            }
+            Expression::StaticMemberExpression(member) => {
+                define.parts.len() > 1 && Self::is_dot_define(ctx.symbols(), define, member)
+            }
+            _ => false,
+        });
+        if let Some(define) = matched {
+            *expr = self.create_value(&define.parts.join("_"), &define.value, ctx);
+        }
+    }
+
+    fn replace_identifier_defines(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         let Expression::Identifier(ident) = expr else { return };
         if !ident.is_global_reference(ctx.symbols()) {
             return;
         }
-        for (key, value) in &self.config.0.identifier {
+        let config = Arc::clone(&self.config.0);
+        for (key, value) in &config.identifier {
             if ident.name.as_str() == key {
-                let value = self.parse_value(value);
-                *expr = value;
+                *expr = self.create_value(key, value, ctx);
                 break;
             }
         }
     }
 
     fn replace_dot_defines(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
-        match expr {
-            Expression::StaticMemberExpression(member) => {
-                for dot_define in &self.config.0.dot {
-                    if Self::is_dot_define(ctx.symbols(), dot_define, member) {
-                        let value = self.parse_value(&dot_define.value);
-                        *expr = value;
-                        return;
-                    }
-                }
-                for meta_proeperty_define in &self.config.0.meta_property {
-                    if Self::is_meta_property_define(meta_proeperty_define, member) {
-                        let value = self.parse_value(&meta_proeperty_define.value);
-                        *expr = value;
-                        return;
-                    }
-                }
-            }
+        let config = Arc::clone(&self.config.0);
+        let member = match expr {
+            Expression::StaticMemberExpression(member) => member,
+            // `process?.env?.NODE_ENV`
+            Expression::ChainExpression(chain) => match &chain.expression {
+                ChainElement::StaticMemberExpression(member) => member,
+                _ => return,
+            },
             Expression::MetaProperty(meta_property) => {
-                if let Some(ref replacement) = self.config.0.import_meta {
+                if let Some(ref replacement) = config.import_meta {
                     if meta_property.meta.name == "import" && meta_property.property.name == "meta"
                     {
-                        let value = self.parse_value(replacement);
-                        *expr = value;
+                        *expr = self.create_value("import_meta", replacement, ctx);
                     }
                 }
+                return;
+            }
+            _ => return,
+        };
+        for dot_define in &config.dot {
+            if Self::is_dot_define(ctx.symbols(), dot_define, member) {
+                *expr = self.create_value(&dot_define.parts.join("_"), &dot_define.value, ctx);
+                return;
+            }
+        }
+        for meta_proeperty_define in &config.meta_property {
+            if Self::is_meta_property_define(meta_proeperty_define, member) {
+                let name = format!("import_meta_{}", meta_proeperty_define.parts.join("_"));
+                *expr = self.create_value(&name, &meta_proeperty_define.value, ctx);
+                return;
             }
-            _ => {}
         }
     }
 
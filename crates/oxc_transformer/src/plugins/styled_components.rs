@@ -0,0 +1,264 @@
+use serde::Deserialize;
+
+use oxc_ast::{ast::*, NONE};
+use oxc_span::SPAN;
+use oxc_traverse::{Traverse, TraverseCtx};
+
+use super::css::{binding_name, hash, minify_quasis, CssImports};
+use crate::TransformCtx;
+
+/// Options for [`babel-plugin-styled-components`](https://styled-components.com/docs/tooling#babel-plugin).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct StyledComponentsOptions {
+    /// Add a `displayName` to styled components, for easier debugging.
+    ///
+    /// Default: `true`
+    pub display_name: bool,
+
+    /// Add a `componentId` to styled components, so class names are consistent between
+    /// server and client.
+    ///
+    /// Default: `true`
+    pub ssr: bool,
+
+    /// Prefix `displayName` with the name of the file.
+    ///
+    /// Default: `true`
+    pub file_name: bool,
+
+    /// Remove comments and whitespace from CSS in tagged templates.
+    ///
+    /// Default: `true`
+    pub minify: bool,
+
+    /// Prefix for `componentId`, to avoid collisions between libraries.
+    pub namespace: Option<String>,
+}
+
+impl Default for StyledComponentsOptions {
+    fn default() -> Self {
+        Self { display_name: true, ssr: true, file_name: true, minify: true, namespace: None }
+    }
+}
+
+/// Port of [babel-plugin-styled-components](https://github.com/styled-components/babel-plugin-styled-components).
+///
+/// Styled components are given a `displayName` and `componentId`:
+///
+/// ```js
+/// import styled from "styled-components";
+/// const Button = styled.div`
+///   color: red;
+/// `;
+/// ```
+///
+/// is transformed to:
+///
+/// ```js
+/// import styled from "styled-components";
+/// const Button = styled.div.withConfig({
+///   displayName: "App__Button",
+///   componentId: "sc-1pgwlfu-0"
+/// })`color:red;`;
+/// ```
+///
+/// CSS in `styled`, `css`, `createGlobalStyle`, `keyframes` and `injectGlobal` tagged templates
+/// is minified.
+pub(crate) struct StyledComponents<'a, 'ctx> {
+    options: Option<StyledComponentsOptions>,
+    ctx: &'ctx TransformCtx<'a>,
+
+    // States
+    imports: CssImports<'a>,
+    /// Hash of the source text, used to make `componentId`s unique to this file.
+    file_hash: String,
+    /// Number of `componentId`s generated so far.
+    component_count: usize,
+}
+
+impl<'a, 'ctx> StyledComponents<'a, 'ctx> {
+    pub(crate) fn new(
+        options: Option<StyledComponentsOptions>,
+        ctx: &'ctx TransformCtx<'a>,
+    ) -> Self {
+        Self {
+            options,
+            ctx,
+            imports: CssImports::default(),
+            file_hash: String::new(),
+            component_count: 0,
+        }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for StyledComponents<'a, 'ctx> {
+    fn enter_program(&mut self, program: &mut Program<'a>, _ctx: &mut TraverseCtx<'a>) {
+        if self.options.is_none() {
+            return;
+        }
+        self.imports = CssImports::collect(
+            program,
+            &["styled-components", "styled-components/native", "styled-components/primitives"],
+        );
+        self.file_hash = hash(self.ctx.source_text);
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Some(options) = &self.options else { return };
+        if self.imports.is_empty() {
+            return;
+        }
+
+        // `styled.div(...)`, `styled(Component)(...)`
+        let (styled, is_css) = match expr {
+            Expression::TaggedTemplateExpression(tagged) => {
+                let is_css = self.is_css_helper(&tagged.tag, ctx);
+                (self.is_styled(&tagged.tag, ctx), is_css)
+            }
+            Expression::CallExpression(call) => (self.is_styled(&call.callee, ctx), false),
+            _ => return,
+        };
+        if !styled && !is_css {
+            return;
+        }
+
+        if options.minify {
+            if let Expression::TaggedTemplateExpression(tagged) = expr {
+                minify_quasis(&mut tagged.quasi.quasis, ctx);
+            }
+        }
+
+        if styled {
+            let config = self.create_config(ctx);
+            let root = match expr {
+                Expression::TaggedTemplateExpression(tagged) => &mut tagged.tag,
+                Expression::CallExpression(call) => &mut call.callee,
+                _ => unreachable!(),
+            };
+            if let Some(config) = config {
+                Self::add_config(Self::styled_root(root), config, ctx);
+            }
+        }
+    }
+}
+
+impl<'a, 'ctx> StyledComponents<'a, 'ctx> {
+    /// `css`, `createGlobalStyle`, `keyframes` or `injectGlobal`
+    fn is_css_helper(&self, expr: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        self.imports.imported_name(expr, ctx).is_some_and(|name| {
+            matches!(name.as_str(), "css" | "createGlobalStyle" | "keyframes" | "injectGlobal")
+        })
+    }
+
+    /// The default export, which creates styled components
+    fn is_styled_import(&self, expr: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        self.imports
+            .imported_name(expr, ctx)
+            .is_some_and(|name| matches!(name.as_str(), "default" | "styled"))
+    }
+
+    /// `styled.div`, `styled(Component)`, or either followed by `.attrs(...)`
+    fn is_styled(&self, expr: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        match expr {
+            Expression::StaticMemberExpression(member) => {
+                self.is_styled_import(&member.object, ctx)
+            }
+            Expression::CallExpression(call) => match &call.callee {
+                Expression::StaticMemberExpression(member) if member.property.name == "attrs" => {
+                    self.is_styled(&member.object, ctx)
+                }
+                callee => self.is_styled_import(callee, ctx),
+            },
+            _ => false,
+        }
+    }
+
+    /// Get `styled.div` or `styled(Component)` from an expression which [`Self::is_styled`].
+    fn styled_root<'b>(expr: &'b mut Expression<'a>) -> &'b mut Expression<'a> {
+        let is_attrs = matches!(expr, Expression::CallExpression(call)
+            if matches!(&call.callee, Expression::StaticMemberExpression(member) if member.property.name == "attrs"));
+        if !is_attrs {
+            return expr;
+        }
+        let Expression::CallExpression(call) = expr else { unreachable!() };
+        let Expression::StaticMemberExpression(member) = &mut call.callee else { unreachable!() };
+        Self::styled_root(&mut member.object)
+    }
+
+    /// `{ displayName: "File__Name", componentId: "sc-hash-0" }`
+    fn create_config(&mut self, ctx: &TraverseCtx<'a>) -> Option<Expression<'a>> {
+        let options = self.options.as_ref().unwrap();
+        let mut properties = ctx.ast.vec();
+        if options.display_name {
+            if let Some(display_name) = self.display_name(ctx) {
+                properties.push(Self::create_property("displayName", &display_name, ctx));
+            }
+        }
+        if options.ssr {
+            let namespace =
+                options.namespace.as_ref().map(|ns| format!("{ns}__")).unwrap_or_default();
+            let component_id = format!("{namespace}sc-{}-{}", self.file_hash, self.component_count);
+            self.component_count += 1;
+            properties.push(Self::create_property("componentId", &component_id, ctx));
+        }
+        if properties.is_empty() {
+            return None;
+        }
+        Some(ctx.ast.expression_object(SPAN, properties, None))
+    }
+
+    fn display_name(&self, ctx: &TraverseCtx<'a>) -> Option<String> {
+        let options = self.options.as_ref().unwrap();
+        let name = binding_name(ctx);
+        if !options.file_name {
+            return name.map(|name| name.to_string());
+        }
+        // Name of the directory is used for `index` files
+        let block_name = if self.ctx.filename == "index" {
+            self.ctx
+                .source_path
+                .parent()
+                .and_then(|dir| dir.file_name())
+                .map_or_else(|| self.ctx.filename.clone(), |dir| dir.to_string_lossy().to_string())
+        } else {
+            self.ctx.filename.clone()
+        };
+        match name {
+            Some(name) if name != block_name.as_str() => Some(format!("{block_name}__{name}")),
+            Some(name) => Some(name.to_string()),
+            None => Some(block_name),
+        }
+    }
+
+    fn create_property(key: &str, value: &str, ctx: &TraverseCtx<'a>) -> ObjectPropertyKind<'a> {
+        ctx.ast.object_property_kind_object_property(
+            SPAN,
+            PropertyKind::Init,
+            ctx.ast.property_key_identifier_name(SPAN, ctx.ast.atom(key)),
+            ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(value)),
+            None,
+            false,
+            false,
+            false,
+        )
+    }
+
+    /// `styled.div` -> `styled.div.withConfig({ ... })`
+    fn add_config(root: &mut Expression<'a>, config: Expression<'a>, ctx: &TraverseCtx<'a>) {
+        let object = ctx.ast.move_expression(root);
+        let callee = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            object,
+            ctx.ast.identifier_name(SPAN, "withConfig"),
+            false,
+        ));
+        *root = ctx.ast.expression_call(
+            SPAN,
+            callee,
+            NONE,
+            ctx.ast.vec1(Argument::from(config)),
+            false,
+        );
+    }
+}
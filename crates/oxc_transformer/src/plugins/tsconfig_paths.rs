@@ -0,0 +1,291 @@
+use std::path::{Component, Path, PathBuf};
+
+use cow_utils::CowUtils;
+use indexmap::IndexMap;
+use serde::Deserialize;
+
+use oxc_ast::ast::*;
+use oxc_traverse::{Traverse, TraverseCtx};
+
+use crate::TransformCtx;
+
+/// Extensions tried when checking if a specifier resolves to a file, in TypeScript's order.
+const EXTENSIONS: &[&str] = &["ts", "tsx", "d.ts", "mts", "cts", "js", "jsx", "mjs", "cjs", "json"];
+
+/// Options for rewriting imports which use tsconfig
+/// [`paths`](https://www.typescriptlang.org/tsconfig/#paths) and
+/// [`baseUrl`](https://www.typescriptlang.org/tsconfig/#baseUrl) aliases.
+///
+/// Usually created from a tsconfig with [`TsconfigPathsOptions::from_tsconfig`].
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct TsconfigPathsOptions {
+    /// `compilerOptions.baseUrl`.
+    ///
+    /// Non-relative specifiers which resolve to a file in this directory are rewritten,
+    /// e.g. `import "utils/log"` to `import "../utils/log"`.
+    /// Relative to [`TsconfigPathsOptions::config_dir`].
+    pub base_url: Option<PathBuf>,
+
+    /// `compilerOptions.paths`, e.g. `{ "@/*": ["./src/*"] }`.
+    ///
+    /// Relative to [`TsconfigPathsOptions::base_url`] if it is set,
+    /// otherwise [`TsconfigPathsOptions::config_dir`].
+    pub paths: IndexMap<String, Vec<String>>,
+
+    /// Directory containing the tsconfig.
+    ///
+    /// Relative to [`TransformOptions::cwd`](crate::TransformOptions::cwd),
+    /// which is also the default.
+    pub config_dir: Option<PathBuf>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Tsconfig {
+    #[serde(default)]
+    compiler_options: TsconfigCompilerOptions,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TsconfigCompilerOptions {
+    base_url: Option<PathBuf>,
+    #[serde(default)]
+    paths: IndexMap<String, Vec<String>>,
+}
+
+impl TsconfigPathsOptions {
+    /// Read `compilerOptions.baseUrl` and `compilerOptions.paths` from the source text of the
+    /// tsconfig at `tsconfig_path`. Comments and trailing commas are allowed.
+    ///
+    /// `extends` is not followed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the tsconfig is not valid JSON.
+    pub fn from_tsconfig(source_text: &str, tsconfig_path: &Path) -> Result<Self, String> {
+        let mut json = source_text.to_string();
+        json_strip_comments::strip(&mut json).map_err(|err| err.to_string())?;
+        let tsconfig: Tsconfig = serde_json::from_str(&json).map_err(|err| err.to_string())?;
+        let TsconfigCompilerOptions { base_url, paths } = tsconfig.compiler_options;
+        let config_dir = tsconfig_path.parent().map(Path::to_path_buf);
+        Ok(Self { base_url, paths, config_dir })
+    }
+}
+
+/// Rewrite tsconfig `paths` and `baseUrl` aliases to relative paths.
+///
+/// With `{ "baseUrl": ".", "paths": { "@/*": ["src/*"] } }`:
+///
+/// ```ts
+/// // src/components/button.ts
+/// import { log } from "@/utils/log";
+/// export * from "@/theme";
+/// const lazy = import("@/lazy");
+/// ```
+///
+/// is transformed to:
+///
+/// ```js
+/// import { log } from "../utils/log";
+/// export * from "../theme";
+/// const lazy = import("../lazy");
+/// ```
+///
+/// Specifiers of `import` and `export` declarations, `import()`, and `require()` with
+/// a string literal argument are rewritten.
+///
+/// Patterns are matched as in TypeScript: an exact match is preferred, otherwise the pattern
+/// with the longest prefix before `*`. If a pattern has multiple targets, the first which
+/// resolves to an existing file is used. If none of them exist, the specifier is resolved
+/// against `baseUrl`.
+///
+/// Specifiers are only rewritten if they resolve to an existing file, so imports of packages
+/// are kept, even with a catch-all pattern like `"*": ["src/*"]`.
+///
+/// Based on [tsc-alias](https://github.com/justkey007/tsc-alias).
+pub(crate) struct TsconfigPaths {
+    /// Absolute directory which `paths` targets are relative to
+    paths_dir: PathBuf,
+    /// Absolute `baseUrl`
+    base_url: Option<PathBuf>,
+    paths: IndexMap<String, Vec<String>>,
+    /// Absolute directory of the file being transformed
+    source_dir: PathBuf,
+}
+
+impl TsconfigPaths {
+    pub(crate) fn new(
+        options: Option<TsconfigPathsOptions>,
+        cwd: &Path,
+        ctx: &TransformCtx,
+    ) -> Option<Self> {
+        let options = options?;
+        let config_dir = options.config_dir.map_or_else(|| cwd.to_path_buf(), |dir| cwd.join(dir));
+        let base_url = options.base_url.map(|base_url| normalize(&config_dir.join(base_url)));
+        let paths_dir = base_url.clone().unwrap_or_else(|| normalize(&config_dir));
+        let source_dir =
+            ctx.source_path.parent().map_or_else(|| cwd.to_path_buf(), |dir| cwd.join(dir));
+        Some(Self { paths_dir, base_url, paths: options.paths, source_dir: normalize(&source_dir) })
+    }
+}
+
+impl<'a> Traverse<'a> for TsconfigPaths {
+    fn enter_import_declaration(
+        &mut self,
+        decl: &mut ImportDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.rewrite(&mut decl.source, ctx);
+    }
+
+    fn enter_export_named_declaration(
+        &mut self,
+        decl: &mut ExportNamedDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(source) = &mut decl.source {
+            self.rewrite(source, ctx);
+        }
+    }
+
+    fn enter_export_all_declaration(
+        &mut self,
+        decl: &mut ExportAllDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.rewrite(&mut decl.source, ctx);
+    }
+
+    fn enter_import_expression(
+        &mut self,
+        expr: &mut ImportExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Expression::StringLiteral(source) = &mut expr.source {
+            self.rewrite(source, ctx);
+        }
+    }
+
+    fn enter_call_expression(&mut self, call: &mut CallExpression<'a>, ctx: &mut TraverseCtx<'a>) {
+        // `require("@/utils")`, where `require` is not shadowed
+        let Expression::Identifier(ident) = &call.callee else { return };
+        if ident.name != "require" || call.arguments.len() != 1 {
+            return;
+        }
+        let is_global = ident.reference_id.get().map_or(true, |reference_id| {
+            ctx.symbols().get_reference(reference_id).symbol_id().is_none()
+        });
+        if !is_global {
+            return;
+        }
+        if let Some(Argument::StringLiteral(source)) = call.arguments.first_mut() {
+            self.rewrite(source, ctx);
+        }
+    }
+}
+
+impl TsconfigPaths {
+    fn rewrite<'a>(&self, source: &mut StringLiteral<'a>, ctx: &TraverseCtx<'a>) {
+        if let Some(target) = self.resolve(&source.value) {
+            let specifier = relative_specifier(&self.source_dir, &target);
+            source.value = ctx.ast.atom(&specifier);
+        }
+    }
+
+    /// Get the absolute path which `specifier` is an alias of.
+    fn resolve(&self, specifier: &str) -> Option<PathBuf> {
+        if specifier.starts_with('.') || Path::new(specifier).is_absolute() {
+            return None;
+        }
+
+        if let Some((targets, matched)) = self.match_pattern(specifier) {
+            let target = targets
+                .iter()
+                .map(|target| {
+                    normalize(&self.paths_dir.join(target.cow_replacen('*', matched, 1).as_ref()))
+                })
+                .find(|candidate| exists(candidate));
+            if target.is_some() {
+                return target;
+            }
+        }
+
+        // Only rewrite `baseUrl` imports of files which exist, as it would be a package otherwise
+        let base_url = self.base_url.as_ref()?;
+        let target = normalize(&base_url.join(specifier));
+        exists(&target).then_some(target)
+    }
+
+    /// Find the targets of the pattern in `paths` which matches `specifier`,
+    /// and the part of `specifier` matched by `*`.
+    fn match_pattern<'s>(&self, specifier: &'s str) -> Option<(&Vec<String>, &'s str)> {
+        if let Some(targets) = self.paths.get(specifier) {
+            return Some((targets, ""));
+        }
+        // Reversed, so the first pattern is used if prefixes have the same length
+        self.paths
+            .iter()
+            .rev()
+            .filter_map(|(pattern, targets)| {
+                let (prefix, suffix) = pattern.split_once('*')?;
+                let matched = specifier.strip_prefix(prefix)?.strip_suffix(suffix)?;
+                Some((prefix.len(), targets, matched))
+            })
+            .max_by_key(|(prefix_len, _, _)| *prefix_len)
+            .map(|(_, targets, matched)| (targets, matched))
+    }
+}
+
+/// Whether `path` resolves to a file, with TypeScript's extension and `index` file lookup.
+fn exists(path: &Path) -> bool {
+    if path.is_file() {
+        return true;
+    }
+    let with_extension = |path: &Path, extension: &str| {
+        let mut path = path.as_os_str().to_os_string();
+        path.push(".");
+        path.push(extension);
+        PathBuf::from(path)
+    };
+    EXTENSIONS.iter().any(|extension| {
+        with_extension(path, extension).is_file()
+            || with_extension(&path.join("index"), extension).is_file()
+    })
+}
+
+/// Remove `.` and `..` components from `path`, without accessing the file system.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    normalized.push("..");
+                }
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+/// Specifier to import `to` from a file in directory `from`, e.g. `./utils` or `../lib/utils`.
+/// Both paths must be normalized.
+fn relative_specifier(from: &Path, to: &Path) -> String {
+    let from = from.components().collect::<Vec<_>>();
+    let to = to.components().collect::<Vec<_>>();
+    let common = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+
+    let mut parts = vec![];
+    if common == from.len() {
+        parts.push(".".to_string());
+    }
+    parts.extend((common..from.len()).map(|_| "..".to_string()));
+    parts.extend(
+        to[common..].iter().map(|component| component.as_os_str().to_string_lossy().into_owned()),
+    );
+    parts.join("/")
+}
@@ -0,0 +1,407 @@
+//! Definitions of which core-js modules polyfill each built-in.
+//!
+//! Based on `babel-plugin-polyfill-corejs3`'s built-in definitions, limited to stable features.
+//! <https://github.com/babel/babel-polyfills/blob/main/packages/babel-plugin-polyfill-corejs3/src/built-in-definitions.ts>
+
+/// Iterators of strings, arrays and DOM collections.
+/// Used by `for (... of ...)`, spread and array destructuring.
+pub const COMMON_ITERATORS: &[&str] =
+    &["es.string.iterator", "es.array.iterator", "web.dom-collections.iterator"];
+
+/// Used by async functions and `import()`.
+pub const PROMISE_DEPENDENCIES: &[&str] = &["es.promise", "es.object.to-string"];
+
+const PROMISE_DEPENDENCIES_WITH_ITERATORS: &[&str] = &[
+    "es.promise",
+    "es.object.to-string",
+    "es.string.iterator",
+    "es.array.iterator",
+    "web.dom-collections.iterator",
+];
+
+const ARRAY_NATURE_ITERATORS_WITH_TAG: &[&str] =
+    &["es.object.to-string", "es.array.iterator", "web.dom-collections.iterator"];
+
+const ERROR_DEPENDENCIES: &[&str] = &["es.error.cause", "es.error.to-string"];
+
+const SYMBOL_DEPENDENCIES: &[&str] = &["es.symbol", "es.symbol.description", "es.object.to-string"];
+
+const DOM_EXCEPTION_DEPENDENCIES: &[&str] = &[
+    "web.dom-exception.constructor",
+    "web.dom-exception.stack",
+    "web.dom-exception.to-string-tag",
+    "es.error.to-string",
+];
+
+const URL_SEARCH_PARAMS_DEPENDENCIES: &[&str] = &[
+    "web.url-search-params",
+    "web.url-search-params.delete",
+    "web.url-search-params.has",
+    "web.url-search-params.size",
+    "es.object.to-string",
+    "es.string.iterator",
+    "es.array.iterator",
+    "web.dom-collections.iterator",
+];
+
+/// Get core-js modules required by global `name`, e.g. `Promise`.
+pub fn built_in(name: &str) -> Option<&'static [&'static str]> {
+    let modules: &[&str] = match name {
+        "AggregateError" => &[
+            "es.aggregate-error",
+            "es.error.cause",
+            "es.error.to-string",
+            "es.object.to-string",
+            "es.string.iterator",
+            "es.array.iterator",
+            "web.dom-collections.iterator",
+            "es.aggregate-error.cause",
+        ],
+        "ArrayBuffer" => {
+            &["es.array-buffer.constructor", "es.array-buffer.slice", "es.object.to-string"]
+        }
+        "DataView" => &["es.data-view", "es.array-buffer.slice", "es.object.to-string"],
+        "Date" => &["es.date.to-string"],
+        "DOMException" => DOM_EXCEPTION_DEPENDENCIES,
+        "Error" | "EvalError" | "RangeError" | "ReferenceError" | "SyntaxError" | "TypeError"
+        | "URIError" => ERROR_DEPENDENCIES,
+        "Float32Array" => &["es.typed-array.float32-array", "es.object.to-string"],
+        "Float64Array" => &["es.typed-array.float64-array", "es.object.to-string"],
+        "Int8Array" => &["es.typed-array.int8-array", "es.object.to-string"],
+        "Int16Array" => &["es.typed-array.int16-array", "es.object.to-string"],
+        "Int32Array" => &["es.typed-array.int32-array", "es.object.to-string"],
+        "Uint8Array" => &["es.typed-array.uint8-array", "es.object.to-string"],
+        "Uint8ClampedArray" => &["es.typed-array.uint8-clamped-array", "es.object.to-string"],
+        "Uint16Array" => &["es.typed-array.uint16-array", "es.object.to-string"],
+        "Uint32Array" => &["es.typed-array.uint32-array", "es.object.to-string"],
+        "Map" => &[
+            "es.map",
+            "es.object.to-string",
+            "es.string.iterator",
+            "es.array.iterator",
+            "web.dom-collections.iterator",
+        ],
+        "Number" => &["es.number.constructor"],
+        "Promise" | "fetch" => PROMISE_DEPENDENCIES,
+        "Reflect" => &["es.reflect.to-string-tag", "es.object.to-string"],
+        "RegExp" => &[
+            "es.regexp.constructor",
+            "es.regexp.dot-all",
+            "es.regexp.exec",
+            "es.regexp.sticky",
+            "es.regexp.to-string",
+        ],
+        "Set" => &[
+            "es.set",
+            "es.object.to-string",
+            "es.string.iterator",
+            "es.array.iterator",
+            "web.dom-collections.iterator",
+        ],
+        "Symbol" => SYMBOL_DEPENDENCIES,
+        "URL" => &[
+            "web.url",
+            "web.url.to-json",
+            "web.url-search-params",
+            "web.url-search-params.delete",
+            "web.url-search-params.has",
+            "web.url-search-params.size",
+            "es.object.to-string",
+            "es.string.iterator",
+            "es.array.iterator",
+            "web.dom-collections.iterator",
+        ],
+        "URLSearchParams" => URL_SEARCH_PARAMS_DEPENDENCIES,
+        "WeakMap" => &[
+            "es.weak-map",
+            "es.object.to-string",
+            "es.array.iterator",
+            "web.dom-collections.iterator",
+        ],
+        "WeakSet" => &[
+            "es.weak-set",
+            "es.object.to-string",
+            "es.array.iterator",
+            "web.dom-collections.iterator",
+        ],
+        "atob" => &[
+            "web.atob",
+            "web.dom-exception.constructor",
+            "web.dom-exception.stack",
+            "web.dom-exception.to-string-tag",
+            "es.error.to-string",
+        ],
+        "btoa" => &[
+            "web.btoa",
+            "web.dom-exception.constructor",
+            "web.dom-exception.stack",
+            "web.dom-exception.to-string-tag",
+            "es.error.to-string",
+        ],
+        "clearImmediate" | "setImmediate" => &["web.immediate"],
+        "escape" => &["es.escape"],
+        "globalThis" => &["es.global-this"],
+        "parseFloat" => &["es.parse-float"],
+        "parseInt" => &["es.parse-int"],
+        "queueMicrotask" => &["web.queue-microtask"],
+        "self" => &["web.self"],
+        "setInterval" | "setTimeout" => &["web.timers"],
+        "structuredClone" => &[
+            "web.structured-clone",
+            "web.dom-exception.constructor",
+            "web.dom-exception.stack",
+            "web.dom-exception.to-string-tag",
+            "es.error.to-string",
+            "es.array.iterator",
+            "es.object.keys",
+            "es.object.to-string",
+            "es.map",
+            "es.set",
+        ],
+        "unescape" => &["es.unescape"],
+        _ => return None,
+    };
+    Some(modules)
+}
+
+/// Get core-js modules required by static property `key` of global `object`, e.g. `Array.from`.
+pub fn static_property(object: &str, key: &str) -> Option<&'static [&'static str]> {
+    let modules: &[&str] = match (object, key) {
+        ("Array", "from") => &["es.array.from", "es.string.iterator"],
+        ("Array", "isArray") => &["es.array.is-array"],
+        ("Array", "of") => &["es.array.of"],
+        ("ArrayBuffer", "isView") => &["es.array-buffer.is-view"],
+        ("Date", "now") => &["es.date.now"],
+        ("JSON", "stringify") => &["es.json.stringify"],
+        ("Math", "acosh") => &["es.math.acosh"],
+        ("Math", "asinh") => &["es.math.asinh"],
+        ("Math", "atanh") => &["es.math.atanh"],
+        ("Math", "cbrt") => &["es.math.cbrt"],
+        ("Math", "clz32") => &["es.math.clz32"],
+        ("Math", "cosh") => &["es.math.cosh"],
+        ("Math", "expm1") => &["es.math.expm1"],
+        ("Math", "fround") => &["es.math.fround"],
+        ("Math", "hypot") => &["es.math.hypot"],
+        ("Math", "imul") => &["es.math.imul"],
+        ("Math", "log10") => &["es.math.log10"],
+        ("Math", "log1p") => &["es.math.log1p"],
+        ("Math", "log2") => &["es.math.log2"],
+        ("Math", "sign") => &["es.math.sign"],
+        ("Math", "sinh") => &["es.math.sinh"],
+        ("Math", "tanh") => &["es.math.tanh"],
+        ("Math", "trunc") => &["es.math.trunc"],
+        ("Number", "EPSILON") => &["es.number.epsilon"],
+        ("Number", "MIN_SAFE_INTEGER") => &["es.number.min-safe-integer"],
+        ("Number", "MAX_SAFE_INTEGER") => &["es.number.max-safe-integer"],
+        ("Number", "isFinite") => &["es.number.is-finite"],
+        ("Number", "isInteger") => &["es.number.is-integer"],
+        ("Number", "isSafeInteger") => &["es.number.is-safe-integer"],
+        ("Number", "isNaN") => &["es.number.is-nan"],
+        ("Number", "parseFloat") => &["es.number.parse-float"],
+        ("Number", "parseInt") => &["es.number.parse-int"],
+        ("Object", "assign") => &["es.object.assign"],
+        ("Object", "create") => &["es.object.create"],
+        ("Object", "defineProperties") => &["es.object.define-properties"],
+        ("Object", "defineProperty") => &["es.object.define-property"],
+        ("Object", "entries") => &["es.object.entries"],
+        ("Object", "freeze") => &["es.object.freeze"],
+        ("Object", "fromEntries") => &["es.object.from-entries", "es.array.iterator"],
+        ("Object", "getOwnPropertyDescriptor") => &["es.object.get-own-property-descriptor"],
+        ("Object", "getOwnPropertyDescriptors") => &["es.object.get-own-property-descriptors"],
+        ("Object", "getOwnPropertyNames") => &["es.object.get-own-property-names"],
+        ("Object", "getOwnPropertySymbols") => &["es.symbol"],
+        ("Object", "getPrototypeOf") => &["es.object.get-prototype-of"],
+        ("Object", "hasOwn") => &["es.object.has-own"],
+        ("Object", "is") => &["es.object.is"],
+        ("Object", "isExtensible") => &["es.object.is-extensible"],
+        ("Object", "isFrozen") => &["es.object.is-frozen"],
+        ("Object", "isSealed") => &["es.object.is-sealed"],
+        ("Object", "keys") => &["es.object.keys"],
+        ("Object", "preventExtensions") => &["es.object.prevent-extensions"],
+        ("Object", "seal") => &["es.object.seal"],
+        ("Object", "setPrototypeOf") => &["es.object.set-prototype-of"],
+        ("Object", "values") => &["es.object.values"],
+        ("Promise", "all" | "race") => PROMISE_DEPENDENCIES_WITH_ITERATORS,
+        ("Promise", "allSettled") => &[
+            "es.promise.all-settled",
+            "es.promise",
+            "es.object.to-string",
+            "es.string.iterator",
+            "es.array.iterator",
+            "web.dom-collections.iterator",
+        ],
+        ("Promise", "any") => &[
+            "es.promise.any",
+            "es.aggregate-error",
+            "es.promise",
+            "es.object.to-string",
+            "es.string.iterator",
+            "es.array.iterator",
+            "web.dom-collections.iterator",
+        ],
+        ("Reflect", "apply") => &["es.reflect.apply"],
+        ("Reflect", "construct") => &["es.reflect.construct"],
+        ("Reflect", "defineProperty") => &["es.reflect.define-property"],
+        ("Reflect", "deleteProperty") => &["es.reflect.delete-property"],
+        ("Reflect", "get") => &["es.reflect.get"],
+        ("Reflect", "getOwnPropertyDescriptor") => &["es.reflect.get-own-property-descriptor"],
+        ("Reflect", "getPrototypeOf") => &["es.reflect.get-prototype-of"],
+        ("Reflect", "has") => &["es.reflect.has"],
+        ("Reflect", "isExtensible") => &["es.reflect.is-extensible"],
+        ("Reflect", "ownKeys") => &["es.reflect.own-keys"],
+        ("Reflect", "preventExtensions") => &["es.reflect.prevent-extensions"],
+        ("Reflect", "set") => &["es.reflect.set"],
+        ("Reflect", "setPrototypeOf") => &["es.reflect.set-prototype-of"],
+        ("String", "fromCodePoint") => &["es.string.from-code-point"],
+        ("String", "raw") => &["es.string.raw"],
+        ("Symbol", "asyncIterator") => &["es.symbol.async-iterator"],
+        ("Symbol", "hasInstance") => &["es.symbol.has-instance", "es.function.has-instance"],
+        ("Symbol", "isConcatSpreadable") => &["es.symbol.is-concat-spreadable", "es.array.concat"],
+        ("Symbol", "iterator") => &[
+            "es.symbol.iterator",
+            "es.object.to-string",
+            "es.string.iterator",
+            "es.array.iterator",
+            "web.dom-collections.iterator",
+        ],
+        ("Symbol", "match") => &["es.symbol.match", "es.string.match"],
+        ("Symbol", "matchAll") => &["es.symbol.match-all", "es.string.match-all"],
+        ("Symbol", "replace") => &["es.symbol.replace", "es.string.replace"],
+        ("Symbol", "search") => &["es.symbol.search", "es.string.search"],
+        ("Symbol", "species") => &["es.symbol.species", "es.array.species"],
+        ("Symbol", "split") => &["es.symbol.split", "es.string.split"],
+        ("Symbol", "toPrimitive") => &["es.symbol.to-primitive", "es.date.to-primitive"],
+        ("Symbol", "toStringTag") => &[
+            "es.symbol.to-string-tag",
+            "es.object.to-string",
+            "es.math.to-string-tag",
+            "es.json.to-string-tag",
+        ],
+        ("Symbol", "unscopables") => &["es.symbol.unscopables"],
+        ("URL", "canParse") => &["web.url.can-parse", "web.url"],
+        _ => return None,
+    };
+    Some(modules)
+}
+
+/// Get core-js modules which may be required by instance property `key`, e.g. `x.includes`.
+///
+/// Type of the object is not known, so this includes modules for all built-ins
+/// which have a property with that name.
+pub fn instance_property(key: &str) -> Option<&'static [&'static str]> {
+    let modules: &[&str] = match key {
+        "at" => &["es.string.at-alternative", "es.array.at", "es.typed-array.at"],
+        "anchor" => &["es.string.anchor"],
+        "big" => &["es.string.big"],
+        "bind" => &["es.function.bind"],
+        "blink" => &["es.string.blink"],
+        "bold" => &["es.string.bold"],
+        "codePointAt" => &["es.string.code-point-at"],
+        "concat" => &["es.array.concat"],
+        "copyWithin" => &["es.array.copy-within"],
+        "description" => &["es.symbol", "es.symbol.description"],
+        "dotAll" => &["es.regexp.dot-all"],
+        "endsWith" => &["es.string.ends-with"],
+        "entries" | "keys" | "values" => ARRAY_NATURE_ITERATORS_WITH_TAG,
+        "every" => &["es.array.every"],
+        "exec" => &["es.regexp.exec"],
+        "fill" => &["es.array.fill"],
+        "filter" => &["es.array.filter"],
+        "finally" => &["es.promise.finally", "es.promise", "es.object.to-string"],
+        "find" => &["es.array.find"],
+        "findIndex" => &["es.array.find-index"],
+        "findLast" => &["es.array.find-last"],
+        "findLastIndex" => &["es.array.find-last-index"],
+        "fixed" => &["es.string.fixed"],
+        "flags" => &["es.regexp.flags"],
+        "flat" => &["es.array.flat", "es.array.unscopables.flat"],
+        "flatMap" => &["es.array.flat-map", "es.array.unscopables.flat-map"],
+        "fontcolor" => &["es.string.fontcolor"],
+        "fontsize" => &["es.string.fontsize"],
+        "forEach" => &["es.array.for-each", "web.dom-collections.for-each"],
+        "includes" => &["es.array.includes", "es.string.includes"],
+        "indexOf" => &["es.array.index-of"],
+        "isWellFormed" => &["es.string.is-well-formed"],
+        "italics" => &["es.string.italics"],
+        "join" => &["es.array.join"],
+        "lastIndexOf" => &["es.array.last-index-of"],
+        "link" => &["es.string.link"],
+        "map" => &["es.array.map"],
+        "match" => &["es.string.match", "es.regexp.exec"],
+        "matchAll" => &["es.string.match-all", "es.regexp.exec"],
+        "name" => &["es.function.name"],
+        "padEnd" => &["es.string.pad-end"],
+        "padStart" => &["es.string.pad-start"],
+        "push" => &["es.array.push"],
+        "reduce" => &["es.array.reduce"],
+        "reduceRight" => &["es.array.reduce-right"],
+        "repeat" => &["es.string.repeat"],
+        "replace" => &["es.string.replace", "es.regexp.exec"],
+        "replaceAll" => &["es.string.replace-all", "es.string.replace", "es.regexp.exec"],
+        "reverse" => &["es.array.reverse"],
+        "search" => &["es.string.search", "es.regexp.exec"],
+        "slice" => &["es.array.slice"],
+        "small" => &["es.string.small"],
+        "some" => &["es.array.some"],
+        "sort" => &["es.array.sort"],
+        "splice" => &["es.array.splice"],
+        "split" => &["es.string.split", "es.regexp.exec"],
+        "startsWith" => &["es.string.starts-with"],
+        "sticky" => &["es.regexp.sticky"],
+        "strike" => &["es.string.strike"],
+        "sub" => &["es.string.sub"],
+        "substr" => &["es.string.substr"],
+        "sup" => &["es.string.sup"],
+        "test" => &["es.regexp.test", "es.regexp.exec"],
+        "toFixed" => &["es.number.to-fixed"],
+        "toISOString" => &["es.date.to-iso-string"],
+        "toJSON" => &["es.date.to-json", "web.url.to-json"],
+        "toPrecision" => &["es.number.to-precision"],
+        "toReversed" => &["es.array.to-reversed"],
+        "toSorted" => &["es.array.to-sorted", "es.array.sort"],
+        "toSpliced" => &["es.array.to-spliced"],
+        "toString" => &[
+            "es.object.to-string",
+            "es.error.to-string",
+            "es.date.to-string",
+            "es.regexp.to-string",
+        ],
+        "toWellFormed" => &["es.string.to-well-formed"],
+        "trim" => &["es.string.trim"],
+        "trimEnd" | "trimRight" => &["es.string.trim-end"],
+        "trimStart" | "trimLeft" => &["es.string.trim-start"],
+        "unshift" => &["es.array.unshift"],
+        "with" => &["es.array.with"],
+        "__defineGetter__" => &["es.object.define-getter"],
+        "__defineSetter__" => &["es.object.define-setter"],
+        "__lookupGetter__" => &["es.object.lookup-getter"],
+        "__lookupSetter__" => &["es.object.lookup-setter"],
+        "__proto__" => &["es.object.proto"],
+        _ => return None,
+    };
+    Some(modules)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::env::{core_js_available_modules, Version};
+
+    #[test]
+    fn modules_exist() {
+        let available = core_js_available_modules(Version { major: 3, minor: 33, patch: 0 });
+        let mut modules = vec![COMMON_ITERATORS, PROMISE_DEPENDENCIES];
+        for name in ["AggregateError", "Map", "Promise", "structuredClone", "URL", "WeakSet"] {
+            modules.push(built_in(name).unwrap());
+        }
+        for (object, key) in [("Array", "from"), ("Object", "fromEntries"), ("Promise", "any")] {
+            modules.push(static_property(object, key).unwrap());
+        }
+        for key in ["at", "entries", "includes", "toString", "__proto__"] {
+            modules.push(instance_property(key).unwrap());
+        }
+        for module in modules.into_iter().flatten() {
+            assert!(available.contains(module), "{module}");
+        }
+    }
+}
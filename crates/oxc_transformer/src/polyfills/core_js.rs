@@ -0,0 +1,326 @@
+//! core-js polyfills
+//!
+//! Adds imports of [core-js](https://github.com/zloirock/core-js) modules
+//! which polyfill features not supported by the targets.
+//!
+//! > This plugin is included in `preset-env`, when `useBuiltIns` option is `"entry"` or `"usage"`.
+//!
+//! ## Example
+//!
+//! Targets: `{ "ie": "11" }`
+//!
+//! ### `useBuiltIns: "entry"`
+//!
+//! Input:
+//! ```js
+//! import "core-js/stable";
+//! ```
+//!
+//! Output:
+//! ```js
+//! import "core-js/modules/es.symbol.js";
+//! import "core-js/modules/es.symbol.description.js";
+//! // ... every other module of `core-js/stable` which IE 11 needs
+//! ```
+//!
+//! ### `useBuiltIns: "usage"`
+//!
+//! Input:
+//! ```js
+//! const p = Promise.resolve([1, 2].includes(1));
+//! ```
+//!
+//! Output:
+//! ```js
+//! import "core-js/modules/es.promise.js";
+//! import "core-js/modules/es.object.to-string.js";
+//! import "core-js/modules/es.array.includes.js";
+//! import "core-js/modules/es.string.includes.js";
+//! const p = Promise.resolve([1, 2].includes(1));
+//! ```
+//!
+//! ## Implementation
+//!
+//! In `entry` mode, `import "core-js/..."` and `require("core-js/...")` statements at top level
+//! are replaced with imports of the modules which that entry point loads, and the targets need.
+//!
+//! In `usage` mode, references to global built-ins (`Promise`), their static properties
+//! (`Array.from`), and instance properties (`x.includes`) are collected, along with syntax which
+//! relies on built-ins (async functions and `import()` use `Promise`, `for ... of`, spread and
+//! array destructuring use iterators). Imports of the required modules are added to top of the file.
+//!
+//! In both modes, imports are only added for modules which exist in the configured core-js
+//! version, and are needed by at least one of the targets.
+//!
+//! In scripts, `require("core-js/modules/...")` statements are added instead of `import`s.
+//!
+//! Implementation based on [babel-plugin-polyfill-corejs3](https://github.com/babel/babel-polyfills/tree/main/packages/babel-plugin-polyfill-corejs3).
+//!
+//! ## Missing features
+//!
+//! * core-js 2 is not supported.
+//! * Polyfills for proposals (`corejs.proposals` and `shippedProposals` options) are not supported yet.
+//! * preset-env's `include` and `exclude` options are not supported yet.
+//! * Types of objects are not inferred, so `"abc".includes(x)` adds polyfills for both
+//!   `Array.prototype.includes` and `String.prototype.includes`.
+//! * Properties accessed with destructuring (`const { from } = Array`) are not detected.
+//!
+//! ## References
+//!
+//! * Babel docs: <https://babel.dev/docs/babel-preset-env#usebuiltins>
+//! * core-js compat data: <https://github.com/zloirock/core-js/tree/master/packages/core-js-compat>
+
+use indexmap::IndexSet;
+use rustc_hash::FxHashSet;
+
+use oxc_ast::{ast::*, NONE};
+use oxc_semantic::ReferenceFlags;
+use oxc_span::{Atom, SPAN};
+use oxc_traverse::{Traverse, TraverseCtx};
+
+use crate::{
+    env::{core_js_available_modules, core_js_entry_modules, is_core_js_module_required},
+    TransformCtx,
+};
+
+use super::{
+    built_in_definitions::{
+        built_in, instance_property, static_property, COMMON_ITERATORS, PROMISE_DEPENDENCIES,
+    },
+    options::{CoreJsOptions, UseBuiltIns},
+};
+
+pub struct CoreJs<'a, 'ctx> {
+    options: CoreJsOptions,
+    ctx: &'ctx TransformCtx<'a>,
+    /// Modules which exist in the configured version of core-js
+    available_modules: FxHashSet<&'static str>,
+    /// Modules to import, in order they were first found to be used
+    used_modules: IndexSet<&'static str>,
+}
+
+impl<'a, 'ctx> CoreJs<'a, 'ctx> {
+    pub fn new(options: CoreJsOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        let available_modules = core_js_available_modules(options.version);
+        Self { options, ctx, available_modules, used_modules: IndexSet::new() }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for CoreJs<'a, 'ctx> {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.use_built_ins == UseBuiltIns::Entry {
+            self.replace_entry_imports(program, ctx);
+        }
+    }
+
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.used_modules.is_empty() {
+            return;
+        }
+        let modules = std::mem::take(&mut self.used_modules);
+        let stmts =
+            modules.into_iter().map(|name| self.create_import(name, ctx)).collect::<Vec<_>>();
+        program.body.splice(0..0, stmts);
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.use_built_ins != UseBuiltIns::Usage {
+            return;
+        }
+        match expr {
+            Expression::Identifier(ident) => {
+                if Self::is_global_reference(ident, ctx) {
+                    if let Some(modules) = built_in(&ident.name) {
+                        self.add_modules(modules);
+                    }
+                }
+            }
+            Expression::StaticMemberExpression(member) => {
+                self.add_property_modules(&member.object, &member.property.name, ctx);
+            }
+            Expression::ComputedMemberExpression(member) => {
+                if let Expression::StringLiteral(key) = &member.expression {
+                    self.add_property_modules(&member.object, &key.value, ctx);
+                }
+            }
+            Expression::ImportExpression(_) => self.add_modules(PROMISE_DEPENDENCIES),
+            Expression::YieldExpression(expr) if expr.delegate => {
+                self.add_modules(COMMON_ITERATORS);
+            }
+            _ => {}
+        }
+    }
+
+    fn enter_function(&mut self, func: &mut Function<'a>, _ctx: &mut TraverseCtx<'a>) {
+        if self.options.use_built_ins == UseBuiltIns::Usage && func.r#async {
+            self.add_modules(PROMISE_DEPENDENCIES);
+        }
+    }
+
+    fn enter_arrow_function_expression(
+        &mut self,
+        arrow: &mut ArrowFunctionExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.use_built_ins == UseBuiltIns::Usage && arrow.r#async {
+            self.add_modules(PROMISE_DEPENDENCIES);
+        }
+    }
+
+    fn enter_for_of_statement(
+        &mut self,
+        _stmt: &mut ForOfStatement<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.use_built_ins == UseBuiltIns::Usage {
+            self.add_modules(COMMON_ITERATORS);
+        }
+    }
+
+    fn enter_array_pattern(&mut self, _pat: &mut ArrayPattern<'a>, _ctx: &mut TraverseCtx<'a>) {
+        if self.options.use_built_ins == UseBuiltIns::Usage {
+            self.add_modules(COMMON_ITERATORS);
+        }
+    }
+
+    fn enter_array_assignment_target(
+        &mut self,
+        _target: &mut ArrayAssignmentTarget<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.use_built_ins == UseBuiltIns::Usage {
+            self.add_modules(COMMON_ITERATORS);
+        }
+    }
+
+    fn enter_spread_element(&mut self, _spread: &mut SpreadElement<'a>, ctx: &mut TraverseCtx<'a>) {
+        // Object spread does not use iterators
+        if self.options.use_built_ins == UseBuiltIns::Usage && !ctx.parent().is_object_expression()
+        {
+            self.add_modules(COMMON_ITERATORS);
+        }
+    }
+}
+
+impl<'a, 'ctx> CoreJs<'a, 'ctx> {
+    /// Replace `import "core-js/stable";` with imports of individual modules.
+    fn replace_entry_imports(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        let has_entry = program.body.iter().any(|stmt| Self::get_entry_source(stmt).is_some());
+        if !has_entry {
+            return;
+        }
+
+        let mut body = ctx.ast.vec_with_capacity(program.body.len());
+        for stmt in program.body.drain(..) {
+            let Some(modules) = Self::get_entry_source(&stmt).and_then(core_js_entry_modules)
+            else {
+                body.push(stmt);
+                continue;
+            };
+            if let Statement::ExpressionStatement(stmt) = &stmt {
+                if let Expression::CallExpression(call) = &stmt.expression {
+                    if let Expression::Identifier(callee) = &call.callee {
+                        ctx.delete_reference_for_identifier(callee);
+                    }
+                }
+            }
+            let modules = modules.iter().filter_map(|name| {
+                let name = self.available_modules.get(name.as_str())?;
+                is_core_js_module_required(name, &self.options.targets).then_some(*name)
+            });
+            for name in modules.collect::<Vec<_>>() {
+                body.push(self.create_import(name, ctx));
+            }
+        }
+        program.body = body;
+    }
+
+    /// Get source of `import "source";` or `require("source");` statement.
+    fn get_entry_source<'s>(stmt: &'s Statement<'a>) -> Option<&'s str> {
+        match stmt {
+            Statement::ImportDeclaration(decl)
+                if decl.specifiers.as_ref().map_or(true, |specifiers| specifiers.is_empty()) =>
+            {
+                Some(decl.source.value.as_str())
+            }
+            Statement::ExpressionStatement(stmt) => {
+                let Expression::CallExpression(call) = &stmt.expression else { return None };
+                if !call.callee.is_specific_id("require") || call.arguments.len() != 1 {
+                    return None;
+                }
+                match &call.arguments[0] {
+                    Argument::StringLiteral(source) => Some(source.value.as_str()),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Add modules for `object.key` or `object["key"]`.
+    ///
+    /// If `object` is a global built-in with a static property `key` (e.g. `Array.from`),
+    /// add modules for that static property. Otherwise, add modules for any built-in instance
+    /// property `key`.
+    fn add_property_modules(&mut self, object: &Expression<'a>, key: &str, ctx: &TraverseCtx<'a>) {
+        if let Expression::Identifier(ident) = object {
+            if Self::is_global_reference(ident, ctx) {
+                if let Some(modules) = static_property(&ident.name, key) {
+                    self.add_modules(modules);
+                    return;
+                }
+            }
+        }
+        if let Some(modules) = instance_property(key) {
+            self.add_modules(modules);
+        }
+    }
+
+    fn add_modules(&mut self, modules: &'static [&'static str]) {
+        for &name in modules {
+            if self.should_inject(name) {
+                self.used_modules.insert(name);
+            }
+        }
+    }
+
+    /// Returns `true` if module `name` exists in the configured core-js version,
+    /// and is required by the targets.
+    fn should_inject(&self, name: &str) -> bool {
+        self.available_modules.contains(name)
+            && is_core_js_module_required(name, &self.options.targets)
+    }
+
+    fn is_global_reference(ident: &IdentifierReference<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        ident.reference_id.get().is_some_and(|reference_id| {
+            ctx.symbols().get_reference(reference_id).symbol_id().is_none()
+        })
+    }
+
+    /// Create `import "core-js/modules/name.js";` or `require("core-js/modules/name.js");`.
+    fn create_import(&self, name: &str, ctx: &mut TraverseCtx<'a>) -> Statement<'a> {
+        let source = ctx.ast.atom(&format!("core-js/modules/{name}.js"));
+        if self.ctx.source_type.is_script() {
+            let symbol_id = ctx.scopes().get_root_binding("require");
+            let ident = ctx.create_reference_id(
+                SPAN,
+                Atom::from("require"),
+                symbol_id,
+                ReferenceFlags::Read,
+            );
+            let callee = ctx.ast.expression_from_identifier_reference(ident);
+            let argument = Argument::from(ctx.ast.expression_string_literal(SPAN, source));
+            let call = ctx.ast.expression_call(SPAN, callee, NONE, ctx.ast.vec1(argument), false);
+            ctx.ast.statement_expression(SPAN, call)
+        } else {
+            let import = ctx.ast.module_declaration_import_declaration(
+                SPAN,
+                None,
+                ctx.ast.string_literal(SPAN, source),
+                NONE,
+                ImportOrExportKind::Value,
+            );
+            ctx.ast.statement_module_declaration(import)
+        }
+    }
+}
@@ -0,0 +1,93 @@
+//! Polyfills
+//!
+//! Add imports of polyfills for built-ins which are not supported by the targets.
+//!
+//! * core-js 3. See [`core_js`] module.
+
+use oxc_ast::ast::*;
+use oxc_traverse::{Traverse, TraverseCtx};
+
+use crate::TransformCtx;
+
+mod built_in_definitions;
+mod core_js;
+mod options;
+
+use core_js::CoreJs;
+
+pub use options::{CoreJsOptions, UseBuiltIns};
+
+pub struct Polyfills<'a, 'ctx> {
+    // Plugins
+    core_js: Option<CoreJs<'a, 'ctx>>,
+}
+
+impl<'a, 'ctx> Polyfills<'a, 'ctx> {
+    pub fn new(options: Option<CoreJsOptions>, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { core_js: options.map(|options| CoreJs::new(options, ctx)) }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for Polyfills<'a, 'ctx> {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(core_js) = &mut self.core_js {
+            core_js.enter_program(program, ctx);
+        }
+    }
+
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(core_js) = &mut self.core_js {
+            core_js.exit_program(program, ctx);
+        }
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(core_js) = &mut self.core_js {
+            core_js.enter_expression(expr, ctx);
+        }
+    }
+
+    fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(core_js) = &mut self.core_js {
+            core_js.enter_function(func, ctx);
+        }
+    }
+
+    fn enter_arrow_function_expression(
+        &mut self,
+        arrow: &mut ArrowFunctionExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(core_js) = &mut self.core_js {
+            core_js.enter_arrow_function_expression(arrow, ctx);
+        }
+    }
+
+    fn enter_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(core_js) = &mut self.core_js {
+            core_js.enter_for_of_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_array_pattern(&mut self, pat: &mut ArrayPattern<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(core_js) = &mut self.core_js {
+            core_js.enter_array_pattern(pat, ctx);
+        }
+    }
+
+    fn enter_array_assignment_target(
+        &mut self,
+        target: &mut ArrayAssignmentTarget<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(core_js) = &mut self.core_js {
+            core_js.enter_array_assignment_target(target, ctx);
+        }
+    }
+
+    fn enter_spread_element(&mut self, spread: &mut SpreadElement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(core_js) = &mut self.core_js {
+            core_js.enter_spread_element(spread, ctx);
+        }
+    }
+}
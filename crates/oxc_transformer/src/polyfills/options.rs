@@ -0,0 +1,115 @@
+use serde_json::Value;
+
+use crate::env::{EnvOptions, Version, Versions};
+
+/// How polyfills are added.
+///
+/// <https://babel.dev/docs/babel-preset-env#usebuiltins>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UseBuiltIns {
+    /// Replace `import "core-js"` (or other core-js entry points) with imports of the individual
+    /// core-js modules required by the targets.
+    Entry,
+    /// Add imports of core-js modules for the features used in each file,
+    /// if they're required by the targets.
+    Usage,
+}
+
+/// Options for injecting [core-js](https://github.com/zloirock/core-js) polyfills.
+///
+/// Set by preset-env's `useBuiltIns` and `corejs` options.
+#[derive(Debug, Clone)]
+pub struct CoreJsOptions {
+    /// How polyfills are added.
+    pub use_built_ins: UseBuiltIns,
+
+    /// Version of core-js in use, e.g. `3.33`.
+    ///
+    /// Only modules which exist in this version of core-js are imported.
+    /// Only core-js 3 is supported.
+    pub version: Version,
+
+    /// Add polyfills for proposals in `usage` mode.
+    ///
+    /// Not supported yet.
+    pub proposals: bool,
+
+    /// Targets to add polyfills for.
+    ///
+    /// If empty, polyfills for all features are added.
+    pub targets: Versions,
+}
+
+impl CoreJsOptions {
+    /// Parse preset-env's `useBuiltIns` and `corejs` options.
+    ///
+    /// Returns `None` if `useBuiltIns` is `false` or not set.
+    /// If `corejs` is not set, core-js 3.0 is assumed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either option has an invalid value, or core-js version is not 3.
+    pub fn from_preset_env(
+        env_options: &EnvOptions,
+        targets: Option<&Versions>,
+    ) -> Result<Option<Self>, String> {
+        let use_built_ins = match &env_options.use_built_ins {
+            None | Some(Value::Null | Value::Bool(false)) => return Ok(None),
+            Some(Value::String(s)) if s == "entry" => UseBuiltIns::Entry,
+            Some(Value::String(s)) if s == "usage" => UseBuiltIns::Usage,
+            Some(_) => {
+                return Err("Invalid Option: The 'useBuiltIns' option must be either \
+                    false, 'entry' or 'usage'"
+                    .to_string())
+            }
+        };
+
+        let (version, proposals) = match &env_options.corejs {
+            None | Some(Value::Null) => (None, false),
+            Some(Value::Object(corejs)) => {
+                let proposals = match corejs.get("proposals") {
+                    None | Some(Value::Null) => false,
+                    Some(Value::Bool(proposals)) => *proposals,
+                    Some(_) => {
+                        return Err(
+                            "Invalid Option: 'corejs.proposals' must be a boolean".to_string()
+                        )
+                    }
+                };
+                (corejs.get("version"), proposals)
+            }
+            Some(version) => (Some(version), false),
+        };
+        let version = match version {
+            None => Version { major: 3, minor: 0, patch: 0 },
+            Some(version) => Self::parse_version(version)?,
+        };
+
+        Ok(Some(Self {
+            use_built_ins,
+            version,
+            proposals,
+            targets: targets.cloned().unwrap_or_default(),
+        }))
+    }
+
+    fn parse_version(value: &Value) -> Result<Version, String> {
+        let version = match value {
+            Value::String(s) => s.clone(),
+            Value::Number(n) => n.to_string(),
+            _ => String::new(),
+        };
+        // `Version::from_str` panics on malformed minor or patch versions
+        let is_valid = version.split('.').count() <= 3
+            && version.split('.').all(|part| part.parse::<u32>().is_ok());
+        if !is_valid {
+            return Err(format!("Invalid Option: {value} is not a valid core-js version"));
+        }
+        match version.parse::<Version>() {
+            Ok(parsed) if parsed.major == 3 => Ok(parsed),
+            _ => Err(format!(
+                "Invalid Option: core-js {version} is not supported. Only core-js 3 is supported"
+            )),
+        }
+    }
+}
@@ -48,6 +48,7 @@ fn update_options_with_comment(
             options.react.runtime = match remainder {
                 "classic" => JsxRuntime::Classic,
                 "automatic" => JsxRuntime::Automatic,
+                "dom" => JsxRuntime::Dom,
                 _ => return,
             };
         }
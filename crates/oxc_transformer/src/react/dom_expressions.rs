@@ -0,0 +1,1153 @@
+//! DOM Expressions
+//!
+//! This plugin compiles JSX to DOM template cloning and fine-grained reactive updates,
+//! as used by [Solid](https://www.solidjs.com).
+//!
+//! Enabled with [`JsxRuntime::Dom`](super::JsxRuntime::Dom).
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! const view = (
+//!   <div class="card" id={id()} onClick={select}>
+//!     Hello {name()}!
+//!     <Badge count={count()} />
+//!   </div>
+//! );
+//! ```
+//!
+//! Output:
+//! ```js
+//! import { template as _template, insert as _insert, createComponent as _createComponent,
+//!   effect as _effect, setAttribute as _setAttribute } from "solid-js/web";
+//! var _tmpl$ = _template("<div class=\"card\">Hello <!>!</div>");
+//! const view = (() => {
+//!   var _el$ = _tmpl$(), _el$2 = _el$.firstChild, _el$3 = _el$2.nextSibling;
+//!   _effect(() => _setAttribute(_el$, "id", id()));
+//!   _el$.addEventListener("click", select);
+//!   _insert(_el$, () => name(), _el$3);
+//!   _insert(_el$, _createComponent(Badge, { get count() { return count(); } }));
+//!   return _el$;
+//! })();
+//! ```
+//!
+//! Static markup of native elements is hoisted into a template, which is created once and cloned
+//! each time the JSX is evaluated. Nodes which have dynamic attributes or children are reached by
+//! walking the clone with `firstChild` and `nextSibling`.
+//!
+//! Attributes and children are considered dynamic if they contain a call or a member expression
+//! outside of a function. Dynamic attributes are updated in an `effect`, dynamic children are
+//! inserted as functions, and dynamic props of components become getters, so they are tracked
+//! by the reactive system.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [babel-plugin-jsx-dom-expressions](https://github.com/ryansolid/dom-expressions/tree/main/packages/babel-plugin-jsx-dom-expressions),
+//! with `generate: "dom"`. Not supported: SVG templates, event delegation, `classList`,
+//! `use:` directives, and server-side rendering / hydration output.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/ryansolid/dom-expressions/tree/main/packages/babel-plugin-jsx-dom-expressions>
+//! * Runtime: <https://github.com/ryansolid/dom-expressions/blob/main/packages/dom-expressions/src/client.js>
+
+use std::cell::Cell;
+
+use cow_utils::CowUtils;
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::{Box as ArenaBox, Vec as ArenaVec};
+use oxc_ast::{ast::*, visit::walk, AstBuilder, Visit, VisitMut, NONE};
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::{
+    number::ToJsString,
+    operator::{AssignmentOperator, BinaryOperator, UnaryOperator},
+    reference::ReferenceFlags,
+    scope::{ScopeFlags, ScopeId},
+    symbol::SymbolFlags,
+};
+use oxc_traverse::{BoundIdentifier, Traverse, TraverseCtx};
+
+use crate::TransformCtx;
+
+use super::{diagnostics, jsx::ReactJsx, JsxOptions};
+
+/// Elements which cannot have children, and have no closing tag.
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "keygen", "link", "meta", "param",
+    "source", "track", "wbr",
+];
+
+/// Attributes which are set as properties of the element, instead of with `setAttribute`.
+const PROPERTIES: &[&str] =
+    &["value", "checked", "selected", "muted", "innerHTML", "textContent", "innerText"];
+
+pub struct DomExpressions<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    module_name: Atom<'a>,
+
+    // States
+    /// Functions imported from `module_name`
+    imports: FxHashMap<&'static str, BoundIdentifier<'a>>,
+    /// `var _domExpressions = require("solid-js/web")`, used instead of `imports` in scripts
+    require_binding: Option<BoundIdentifier<'a>>,
+    /// Templates created so far, keyed by their HTML, so identical templates are shared
+    templates: FxHashMap<String, BoundIdentifier<'a>>,
+    /// `var _tmpl$ = _template("...")` statements, inserted at top of program
+    template_declarations: Vec<Statement<'a>>,
+}
+
+/// Template for a native element, and code to make it dynamic.
+struct Template<'a> {
+    html: String,
+    /// Declarators for references to nodes in the template, in the order they are walked
+    declarators: Vec<VariableDeclarator<'a>>,
+    statements: Vec<Statement<'a>>,
+    /// Scope of the function which clones the template
+    scope_id: ScopeId,
+}
+
+/// Child of a native element, after JSX text has been trimmed.
+enum Child<'a> {
+    Text(String),
+    Element(ArenaBox<'a, JSXElement<'a>>),
+    /// An expression written by the user, which may need wrapping to be reactive
+    Expression(Expression<'a>),
+    /// A component or fragment which has already been transformed
+    Transformed(Expression<'a>),
+}
+
+/// Node in the template of a native element.
+enum Node<'a> {
+    Text(String),
+    Element(ArenaBox<'a, JSXElement<'a>>),
+    /// `<!>`, marks where dynamic children are inserted before text
+    Marker,
+}
+
+/// Dynamic child of a native element, inserted with `insert`.
+struct Insert<'a> {
+    value: Child<'a>,
+    /// Index of the node which the child is inserted before. `None` to append.
+    marker: Option<usize>,
+}
+
+impl<'a> Template<'a> {
+    fn new(scope_id: ScopeId) -> Self {
+        Self { html: String::new(), declarators: vec![], statements: vec![], scope_id }
+    }
+}
+
+impl<'a, 'ctx> DomExpressions<'a, 'ctx> {
+    pub fn new(options: &JsxOptions, ast: AstBuilder<'a>, ctx: &'ctx TransformCtx<'a>) -> Self {
+        if options.pragma.is_some() || options.pragma_frag.is_some() {
+            ctx.error(diagnostics::pragma_and_pragma_frag_cannot_be_set());
+        }
+        let module_name = match options.import_source.as_deref() {
+            Some("") => {
+                ctx.error(diagnostics::invalid_import_source());
+                Atom::from("solid-js/web")
+            }
+            Some(import_source) => ast.atom(import_source),
+            None => Atom::from("solid-js/web"),
+        };
+        Self {
+            ctx,
+            module_name,
+            imports: FxHashMap::default(),
+            require_binding: None,
+            templates: FxHashMap::default(),
+            template_declarations: vec![],
+        }
+    }
+}
+
+impl<'a, 'ctx> Traverse<'a> for DomExpressions<'a, 'ctx> {
+    fn exit_program(&mut self, program: &mut Program<'a>, _ctx: &mut TraverseCtx<'a>) {
+        // Insert templates after imports. Not inserted with `TopLevelStatements`, so imports of
+        // helpers, which are inserted later, are placed before the templates.
+        let index = program
+            .body
+            .iter()
+            .rposition(|stmt| matches!(stmt, Statement::ImportDeclaration(_)))
+            .map_or(0, |i| i + 1);
+        program.body.splice(index..index, self.template_declarations.drain(..));
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !matches!(expr, Expression::JSXElement(_) | Expression::JSXFragment(_)) {
+            return;
+        }
+        let scope_id = ctx.current_scope_id();
+        *expr = match ctx.ast.move_expression(expr) {
+            Expression::JSXElement(element) => self.transform_element(element, scope_id, ctx),
+            Expression::JSXFragment(fragment) => self.transform_fragment(fragment, scope_id, ctx),
+            _ => unreachable!(),
+        };
+    }
+}
+
+// Elements
+impl<'a, 'ctx> DomExpressions<'a, 'ctx> {
+    /// Transform an element which will be placed in `scope_id`.
+    fn transform_element(
+        &mut self,
+        element: ArenaBox<'a, JSXElement<'a>>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if is_component(&element.opening_element.name) {
+            self.transform_component(element, scope_id, ctx)
+        } else {
+            self.transform_native_element(element, scope_id, ctx)
+        }
+    }
+
+    /// `<div>{a()}</div>` -> `(() => { var _el$ = _tmpl$(); _insert(_el$, () => a()); return _el$; })()`
+    fn transform_native_element(
+        &mut self,
+        mut element: ArenaBox<'a, JSXElement<'a>>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if !needs_reference(&element) {
+            // Fully static, just clone the template
+            let mut template = Template::new(scope_id);
+            self.transform_template_element(&mut element, None, &mut template, ctx);
+            return self.clone_template(template.html, ctx);
+        }
+
+        let scope_id = ctx.create_child_scope(scope_id, ScopeFlags::Function | ScopeFlags::Arrow);
+        let el = ctx.generate_uid("el$", scope_id, SymbolFlags::FunctionScopedVariable);
+        let mut template = Template::new(scope_id);
+        self.transform_template_element(&mut element, Some(&el), &mut template, ctx);
+
+        // var _el$ = _tmpl$(), _el$2 = _el$.firstChild;
+        let Template { html, declarators, statements, .. } = template;
+        let init = self.clone_template(html, ctx);
+        let mut all_declarators = ctx.ast.vec_with_capacity(declarators.len() + 1);
+        all_declarators.push(Self::create_declarator(&el, init, ctx));
+        all_declarators.extend(declarators);
+        let mut body = ctx.ast.vec_with_capacity(statements.len() + 2);
+        body.push(Statement::from(ctx.ast.declaration_variable(
+            SPAN,
+            VariableDeclarationKind::Var,
+            all_declarators,
+            false,
+        )));
+        body.extend(statements);
+        // return _el$;
+        body.push(ctx.ast.statement_return(SPAN, Some(el.create_read_expression(ctx))));
+
+        // (() => { ... })()
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::ArrowFormalParameters,
+            ctx.ast.vec(),
+            NONE,
+        );
+        let body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), body);
+        let arrow = Expression::ArrowFunctionExpression(
+            ctx.ast.alloc_arrow_function_expression_with_scope_id(
+                SPAN, false, false, NONE, params, NONE, body, scope_id,
+            ),
+        );
+        ctx.ast.expression_call(SPAN, arrow, NONE, ctx.ast.vec(), false)
+    }
+
+    /// Add `element` to the template, and generate code for its dynamic attributes and children.
+    ///
+    /// `el` is a reference to the element, which is `None` if it has nothing dynamic.
+    fn transform_template_element(
+        &mut self,
+        element: &mut JSXElement<'a>,
+        el: Option<&BoundIdentifier<'a>>,
+        template: &mut Template<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let tag = element.opening_element.name.to_string();
+        let has_children = !element.children.is_empty();
+
+        template.html.push('<');
+        template.html.push_str(&tag);
+        for attribute in ctx.ast.move_vec(&mut element.opening_element.attributes) {
+            match attribute {
+                JSXAttributeItem::Attribute(attribute) => {
+                    self.transform_attribute(attribute.unbox(), el, template, ctx);
+                }
+                // _spread(_el$, props, false, true)
+                JSXAttributeItem::SpreadAttribute(spread) => {
+                    let mut argument = spread.unbox().argument;
+                    reparent_scopes(&mut argument, template.scope_id, ctx);
+                    let arguments = ctx.ast.vec_from_iter([
+                        Argument::from(el.unwrap().create_read_expression(ctx)),
+                        Argument::from(argument),
+                        Argument::from(ctx.ast.expression_boolean_literal(SPAN, false)),
+                        Argument::from(ctx.ast.expression_boolean_literal(SPAN, has_children)),
+                    ]);
+                    let call = self.call_import("spread", arguments, ctx);
+                    template.statements.push(ctx.ast.statement_expression(SPAN, call));
+                }
+            }
+        }
+        template.html.push('>');
+        if VOID_ELEMENTS.contains(&tag.as_str()) {
+            return;
+        }
+        self.transform_template_children(&mut element.children, el, template, ctx);
+        template.html.push_str("</");
+        template.html.push_str(&tag);
+        template.html.push('>');
+    }
+
+    fn transform_attribute(
+        &mut self,
+        attribute: JSXAttribute<'a>,
+        el: Option<&BoundIdentifier<'a>>,
+        template: &mut Template<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let name = attribute_name(&attribute.name);
+        if let Some(value) = static_attribute_value(&name, attribute.value.as_ref()) {
+            let name = match name.as_str() {
+                "className" => "class",
+                "htmlFor" => "for",
+                name => name,
+            };
+            template.html.push(' ');
+            template.html.push_str(name);
+            if let StaticValue::String(value) = value {
+                template.html.push_str("=\"");
+                escape_html(&value, true, &mut template.html);
+                template.html.push('"');
+            }
+            return;
+        }
+
+        let el = el.unwrap();
+        let mut value = match attribute.value {
+            Some(JSXAttributeValue::ExpressionContainer(container)) => {
+                match container.unbox().expression {
+                    JSXExpression::EmptyExpression(_) => return,
+                    expression => expression.into_expression(),
+                }
+            }
+            Some(JSXAttributeValue::Element(element)) => {
+                self.transform_element(element, template.scope_id, ctx)
+            }
+            Some(JSXAttributeValue::Fragment(fragment)) => {
+                self.transform_fragment(fragment, template.scope_id, ctx)
+            }
+            // Static values have been added to the template
+            Some(JSXAttributeValue::StringLiteral(_)) | None => unreachable!(),
+        };
+
+        // _el$.addEventListener("click", handler)
+        if let Some(event) = event_name(&name) {
+            reparent_scopes(&mut value, template.scope_id, ctx);
+            let callee = Expression::from(ctx.ast.member_expression_static(
+                SPAN,
+                el.create_read_expression(ctx),
+                ctx.ast.identifier_name(SPAN, "addEventListener"),
+                false,
+            ));
+            let event = ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(&event));
+            let arguments = ctx.ast.vec_from_iter([Argument::from(event), Argument::from(value)]);
+            let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+            template.statements.push(ctx.ast.statement_expression(SPAN, call));
+            return;
+        }
+
+        if name == "ref" {
+            reparent_scopes(&mut value, template.scope_id, ctx);
+            let stmt = self.create_ref(value, el, ctx);
+            template.statements.push(stmt);
+            return;
+        }
+
+        // _effect(() => _setAttribute(_el$, "id", id()))
+        let is_dynamic = is_dynamic(&value);
+        let scope_id = if is_dynamic {
+            ctx.create_child_scope(template.scope_id, ScopeFlags::Function | ScopeFlags::Arrow)
+        } else {
+            template.scope_id
+        };
+        reparent_scopes(&mut value, scope_id, ctx);
+        let mut update = self.create_attribute_update(&name, value, el, ctx);
+        if is_dynamic {
+            let arrow = Self::create_arrow(update, scope_id, ctx);
+            update = self.call_import("effect", ctx.ast.vec1(Argument::from(arrow)), ctx);
+        }
+        template.statements.push(ctx.ast.statement_expression(SPAN, update));
+    }
+
+    /// * `_el$.value = value`
+    /// * `_className(_el$, value)`
+    /// * `_style(_el$, value)`
+    /// * `_setAttribute(_el$, "name", value)`
+    fn create_attribute_update(
+        &mut self,
+        name: &str,
+        value: Expression<'a>,
+        el: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let el_expr = el.create_read_expression(ctx);
+        match name {
+            name if PROPERTIES.contains(&name) => {
+                let target = ctx.ast.member_expression_static(
+                    SPAN,
+                    el_expr,
+                    ctx.ast.identifier_name(SPAN, ctx.ast.atom(name)),
+                    false,
+                );
+                let target = AssignmentTarget::from(
+                    ctx.ast.simple_assignment_target_member_expression(target),
+                );
+                ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value)
+            }
+            "class" | "className" | "style" => {
+                let function = if name == "style" { "style" } else { "className" };
+                let arguments = ctx.ast.vec_from_iter([el_expr, value].map(Argument::from));
+                self.call_import(function, arguments, ctx)
+            }
+            name => {
+                let name = ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(name));
+                let arguments = ctx.ast.vec_from_iter([el_expr, name, value].map(Argument::from));
+                self.call_import("setAttribute", arguments, ctx)
+            }
+        }
+    }
+
+    /// * `ref={el}` -> `typeof el === "function" ? _use(el, _el$) : el = _el$`
+    /// * `ref={fn}` -> `_use(fn, _el$)`
+    fn create_ref(
+        &mut self,
+        value: Expression<'a>,
+        el: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let Expression::Identifier(ident) = value else {
+            let arguments =
+                ctx.ast.vec_from_iter([value, el.create_read_expression(ctx)].map(Argument::from));
+            let call = self.call_import("use", arguments, ctx);
+            return ctx.ast.statement_expression(SPAN, call);
+        };
+
+        let typeof_ident = ctx.ast.expression_unary(
+            SPAN,
+            UnaryOperator::Typeof,
+            Expression::Identifier(
+                ctx.ast.alloc(ctx.clone_identifier_reference(&ident, ReferenceFlags::Read)),
+            ),
+        );
+        let test = ctx.ast.expression_binary(
+            SPAN,
+            typeof_ident,
+            BinaryOperator::StrictEquality,
+            ctx.ast.expression_string_literal(SPAN, "function"),
+        );
+        let target = SimpleAssignmentTarget::AssignmentTargetIdentifier(ctx.ast.alloc(
+            // Value of the assignment is used as result of the conditional
+            ctx.clone_identifier_reference(&ident, ReferenceFlags::Read | ReferenceFlags::Write),
+        ));
+        let assignment = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            AssignmentTarget::from(target),
+            el.create_read_expression(ctx),
+        );
+        let arguments = ctx.ast.vec_from_iter(
+            [Expression::Identifier(ident), el.create_read_expression(ctx)].map(Argument::from),
+        );
+        let call = self.call_import("use", arguments, ctx);
+        let conditional = ctx.ast.expression_conditional(SPAN, test, call, assignment);
+        ctx.ast.statement_expression(SPAN, conditional)
+    }
+
+    /// Add children of a native element to the template, and insert dynamic children.
+    fn transform_template_children(
+        &mut self,
+        children: &mut ArenaVec<'a, JSXChild<'a>>,
+        el: Option<&BoundIdentifier<'a>>,
+        template: &mut Template<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let children = self.collect_children(children, template.scope_id, ctx);
+
+        let mut nodes = vec![];
+        let mut inserts: Vec<Insert<'a>> = vec![];
+        // Number of inserts at the end of `inserts` which have no marker yet
+        let mut pending = 0;
+        for child in children {
+            match child {
+                Child::Text(_) | Child::Element(_) => {
+                    if pending > 0 {
+                        let len = inserts.len();
+                        for insert in &mut inserts[len - pending..] {
+                            insert.marker = Some(nodes.len());
+                        }
+                        pending = 0;
+                        // Text can't be inserted before, as it would be merged with other text
+                        // in the template
+                        if matches!(child, Child::Text(_)) {
+                            nodes.push(Node::Marker);
+                        }
+                    }
+                    nodes.push(match child {
+                        Child::Text(text) => Node::Text(text),
+                        Child::Element(element) => Node::Element(element),
+                        _ => unreachable!(),
+                    });
+                }
+                Child::Expression(_) | Child::Transformed(_) => {
+                    inserts.push(Insert { value: child, marker: None });
+                    pending += 1;
+                }
+            }
+        }
+
+        // var _el$2 = _el$.firstChild, _el$3 = _el$2.nextSibling;
+        let last_referenced = nodes.iter().enumerate().rev().find_map(|(i, node)| {
+            let referenced = match node {
+                Node::Element(element) => needs_reference(element),
+                Node::Marker => true,
+                Node::Text(_) => false,
+            } || inserts.iter().any(|insert| insert.marker == Some(i));
+            referenced.then_some(i)
+        });
+        let mut node_refs = vec![];
+        if let Some(last_referenced) = last_referenced {
+            let el = el.unwrap();
+            for i in 0..=last_referenced {
+                let (object, property) = match node_refs.last() {
+                    None => (el, "firstChild"),
+                    Some(prev) => (prev, "nextSibling"),
+                };
+                let init = Expression::from(ctx.ast.member_expression_static(
+                    SPAN,
+                    object.create_read_expression(ctx),
+                    ctx.ast.identifier_name(SPAN, property),
+                    false,
+                ));
+                let binding =
+                    ctx.generate_uid("el$", template.scope_id, SymbolFlags::FunctionScopedVariable);
+                template.declarators.push(Self::create_declarator(&binding, init, ctx));
+                debug_assert_eq!(node_refs.len(), i);
+                node_refs.push(binding);
+            }
+        }
+
+        for (i, node) in nodes.into_iter().enumerate() {
+            match node {
+                Node::Text(text) => escape_html(&text, false, &mut template.html),
+                Node::Marker => template.html.push_str("<!>"),
+                Node::Element(mut element) => {
+                    self.transform_template_element(&mut element, node_refs.get(i), template, ctx);
+                }
+            }
+        }
+
+        // _insert(_el$, () => a(), _el$3)
+        for Insert { value, marker } in inserts {
+            let value = match value {
+                Child::Expression(mut expr) => {
+                    if is_dynamic(&expr) {
+                        let scope_id = ctx.create_child_scope(
+                            template.scope_id,
+                            ScopeFlags::Function | ScopeFlags::Arrow,
+                        );
+                        reparent_scopes(&mut expr, scope_id, ctx);
+                        Self::create_arrow(expr, scope_id, ctx)
+                    } else {
+                        reparent_scopes(&mut expr, template.scope_id, ctx);
+                        expr
+                    }
+                }
+                Child::Transformed(expr) => expr,
+                Child::Text(_) | Child::Element(_) => unreachable!(),
+            };
+            let mut arguments = ctx.ast.vec_with_capacity(3);
+            arguments.push(Argument::from(el.unwrap().create_read_expression(ctx)));
+            arguments.push(Argument::from(value));
+            if let Some(marker) = marker {
+                arguments.push(Argument::from(node_refs[marker].create_read_expression(ctx)));
+            }
+            let call = self.call_import("insert", arguments, ctx);
+            template.statements.push(ctx.ast.statement_expression(SPAN, call));
+        }
+    }
+
+    /// Trim text and transform components and fragments in `children`, which will be placed
+    /// in `scope_id`. Native elements are left to be added to a template.
+    fn collect_children(
+        &mut self,
+        children: &mut ArenaVec<'a, JSXChild<'a>>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Vec<Child<'a>> {
+        let mut collected = vec![];
+        for child in ctx.ast.move_vec(children) {
+            match child {
+                JSXChild::Text(text) => {
+                    let Some(text) = ReactJsx::fixup_whitespace_and_decode_entities(&text.value)
+                    else {
+                        continue;
+                    };
+                    if let Some(Child::Text(prev)) = collected.last_mut() {
+                        prev.push_str(&text);
+                    } else {
+                        collected.push(Child::Text(text));
+                    }
+                }
+                JSXChild::Element(element) => {
+                    if is_component(&element.opening_element.name) {
+                        let expr = self.transform_component(element, scope_id, ctx);
+                        collected.push(Child::Transformed(expr));
+                    } else {
+                        collected.push(Child::Element(element));
+                    }
+                }
+                JSXChild::Fragment(fragment) => {
+                    let expr = self.transform_fragment(fragment, scope_id, ctx);
+                    collected.push(Child::Transformed(expr));
+                }
+                JSXChild::ExpressionContainer(container) => match container.unbox().expression {
+                    JSXExpression::EmptyExpression(_) => {}
+                    expr => collected.push(Child::Expression(expr.into_expression())),
+                },
+                JSXChild::Spread(spread) => {
+                    self.ctx.error(diagnostics::spread_children_are_not_supported(spread.span));
+                }
+            }
+        }
+        collected
+    }
+}
+
+// Components and fragments
+impl<'a, 'ctx> DomExpressions<'a, 'ctx> {
+    /// `<Comp a={b()}>c</Comp>` -> `_createComponent(Comp, { get a() { return b(); }, children: "c" })`
+    fn transform_component(
+        &mut self,
+        element: ArenaBox<'a, JSXElement<'a>>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let JSXElement { opening_element, closing_element, mut children, .. } = element.unbox();
+        if let Some(closing_element) = &closing_element {
+            if let Some(ident) = closing_element.name.get_identifier() {
+                ctx.delete_reference_for_identifier(ident);
+            }
+        }
+        let JSXOpeningElement { name, attributes, .. } = opening_element.unbox();
+        let callee = match name {
+            JSXElementName::IdentifierReference(ident) => Expression::Identifier(ident),
+            JSXElementName::MemberExpression(member_expr) => {
+                ReactJsx::transform_jsx_member_expression(&member_expr, ctx)
+            }
+            JSXElementName::ThisExpression(this) => ctx.ast.expression_this(this.span),
+            JSXElementName::Identifier(_) | JSXElementName::NamespacedName(_) => unreachable!(),
+        };
+
+        // Objects and spread props, to be merged with `mergeProps`
+        let mut sources = vec![];
+        let mut properties = ctx.ast.vec();
+        for attribute in attributes {
+            match attribute {
+                JSXAttributeItem::Attribute(attribute) => {
+                    let JSXAttribute { name, value, .. } = attribute.unbox();
+                    let key = ReactJsx::get_attribute_name(&name, ctx);
+                    let property = match value {
+                        None => Self::create_property(
+                            key,
+                            ctx.ast.expression_boolean_literal(SPAN, true),
+                            ctx,
+                        ),
+                        Some(JSXAttributeValue::StringLiteral(lit)) => {
+                            let value = ReactJsx::decode_entities(&lit.value);
+                            let value = ctx.ast.expression_string_literal(lit.span, value);
+                            Self::create_property(key, value, ctx)
+                        }
+                        Some(JSXAttributeValue::ExpressionContainer(container)) => {
+                            match container.unbox().expression {
+                                JSXExpression::EmptyExpression(_) => continue,
+                                expr => {
+                                    Self::create_prop(key, expr.into_expression(), scope_id, ctx)
+                                }
+                            }
+                        }
+                        Some(JSXAttributeValue::Element(element)) => {
+                            let getter_scope_id = Self::create_getter_scope(scope_id, ctx);
+                            let value = self.transform_element(element, getter_scope_id, ctx);
+                            Self::create_getter(key, value, getter_scope_id, ctx)
+                        }
+                        Some(JSXAttributeValue::Fragment(fragment)) => {
+                            let getter_scope_id = Self::create_getter_scope(scope_id, ctx);
+                            let value = self.transform_fragment(fragment, getter_scope_id, ctx);
+                            Self::create_getter(key, value, getter_scope_id, ctx)
+                        }
+                    };
+                    properties.push(property);
+                }
+                JSXAttributeItem::SpreadAttribute(spread) => {
+                    if !properties.is_empty() {
+                        let properties = ctx.ast.move_vec(&mut properties);
+                        sources.push(ctx.ast.expression_object(SPAN, properties, None));
+                    }
+                    let mut argument = spread.unbox().argument;
+                    reparent_scopes(&mut argument, scope_id, ctx);
+                    sources.push(argument);
+                }
+            }
+        }
+
+        // children: "c"
+        if let Some(property) = self.create_children_prop(&mut children, scope_id, ctx) {
+            properties.push(property);
+        }
+
+        let props = if sources.is_empty() {
+            ctx.ast.expression_object(SPAN, properties, None)
+        } else {
+            if !properties.is_empty() {
+                sources.push(ctx.ast.expression_object(SPAN, properties, None));
+            }
+            let arguments = ctx.ast.vec_from_iter(sources.into_iter().map(Argument::from));
+            self.call_import("mergeProps", arguments, ctx)
+        };
+        let arguments = ctx.ast.vec_from_iter([callee, props].map(Argument::from));
+        self.call_import("createComponent", arguments, ctx)
+    }
+
+    /// `a: b` or `get a() { return b(); }` if `b()` is dynamic
+    fn create_prop(
+        key: PropertyKey<'a>,
+        mut value: Expression<'a>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ObjectPropertyKind<'a> {
+        if is_dynamic(&value) {
+            let getter_scope_id = Self::create_getter_scope(scope_id, ctx);
+            reparent_scopes(&mut value, getter_scope_id, ctx);
+            Self::create_getter(key, value, getter_scope_id, ctx)
+        } else {
+            reparent_scopes(&mut value, scope_id, ctx);
+            Self::create_property(key, value, ctx)
+        }
+    }
+
+    /// Children of a component. A getter if any children are elements or dynamic.
+    fn create_children_prop(
+        &mut self,
+        children: &mut ArenaVec<'a, JSXChild<'a>>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<ObjectPropertyKind<'a>> {
+        let needs_getter = children.iter().any(|child| match child {
+            JSXChild::Element(_) | JSXChild::Fragment(_) => true,
+            JSXChild::ExpressionContainer(container) => {
+                container.expression.as_expression().is_some_and(is_dynamic)
+            }
+            JSXChild::Text(_) | JSXChild::Spread(_) => false,
+        });
+        let value_scope_id =
+            if needs_getter { Self::create_getter_scope(scope_id, ctx) } else { scope_id };
+        let value = self.transform_children(children, value_scope_id, false, ctx)?;
+        let key = ctx.ast.property_key_identifier_name(SPAN, "children");
+        Some(if needs_getter {
+            Self::create_getter(key, value, value_scope_id, ctx)
+        } else {
+            Self::create_property(key, value, ctx)
+        })
+    }
+
+    /// `<>a{b()}</>` -> `["a", () => b()]`
+    fn transform_fragment(
+        &mut self,
+        mut fragment: ArenaBox<'a, JSXFragment<'a>>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        self.transform_children(&mut fragment.children, scope_id, true, ctx)
+            .unwrap_or_else(|| ctx.ast.expression_array(SPAN, ctx.ast.vec(), None))
+    }
+
+    /// Transform children of a component or fragment to a single value, or an array of values.
+    ///
+    /// If `wrap_dynamic` is `true`, dynamic expressions are wrapped in functions.
+    /// Returns `None` if there are no children.
+    fn transform_children(
+        &mut self,
+        children: &mut ArenaVec<'a, JSXChild<'a>>,
+        scope_id: ScopeId,
+        wrap_dynamic: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let children = self.collect_children(children, scope_id, ctx);
+        let mut values = ctx.ast.vec_with_capacity(children.len());
+        for child in children {
+            let value = match child {
+                Child::Text(text) => ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(&text)),
+                Child::Element(element) => self.transform_native_element(element, scope_id, ctx),
+                Child::Expression(mut expr) => {
+                    if wrap_dynamic && is_dynamic(&expr) {
+                        let arrow_scope_id = ctx
+                            .create_child_scope(scope_id, ScopeFlags::Function | ScopeFlags::Arrow);
+                        reparent_scopes(&mut expr, arrow_scope_id, ctx);
+                        Self::create_arrow(expr, arrow_scope_id, ctx)
+                    } else {
+                        reparent_scopes(&mut expr, scope_id, ctx);
+                        expr
+                    }
+                }
+                Child::Transformed(expr) => expr,
+            };
+            values.push(ArrayExpressionElement::from(value));
+        }
+        match values.len() {
+            0 => None,
+            1 => match values.pop().unwrap() {
+                ArrayExpressionElement::SpreadElement(_) | ArrayExpressionElement::Elision(_) => {
+                    unreachable!()
+                }
+                value => Some(value.into_expression()),
+            },
+            _ => Some(ctx.ast.expression_array(SPAN, values, None)),
+        }
+    }
+}
+
+// Utilities
+impl<'a, 'ctx> DomExpressions<'a, 'ctx> {
+    /// `_tmpl$()`, creating the template if it doesn't exist yet.
+    ///
+    /// `var _tmpl$ = _template("<div></div>");` is inserted at top of program in `exit_program`.
+    fn clone_template(&mut self, html: String, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let template = if let Some(template) = self.templates.get(&html) {
+            template.clone()
+        } else {
+            let template =
+                ctx.generate_uid_in_root_scope("tmpl$", SymbolFlags::FunctionScopedVariable);
+            let html_expr = ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(&html));
+            let init = self.call_import("template", ctx.ast.vec1(Argument::from(html_expr)), ctx);
+            let declarator = Self::create_declarator(&template, init, ctx);
+            let stmt = Statement::from(ctx.ast.declaration_variable(
+                SPAN,
+                VariableDeclarationKind::Var,
+                ctx.ast.vec1(declarator),
+                false,
+            ));
+            self.template_declarations.push(stmt);
+            self.templates.insert(html, template.clone());
+            template
+        };
+        ctx.ast.expression_call(
+            SPAN,
+            template.create_read_expression(ctx),
+            NONE,
+            ctx.ast.vec(),
+            false,
+        )
+    }
+
+    /// Call a function imported from the runtime module.
+    ///
+    /// `_name(...)` in modules, or `_domExpressions.name(...)` in scripts.
+    fn call_import(
+        &mut self,
+        name: &'static str,
+        arguments: ArenaVec<'a, Argument<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if self.ctx.source_type.is_script() {
+            // `_domExpressions.name(...)`
+            let binding = self.require_binding.get_or_insert_with(|| {
+                let binding = ctx.generate_uid_in_root_scope(
+                    "domExpressions",
+                    SymbolFlags::FunctionScopedVariable,
+                );
+                self.ctx.module_imports.add_default_import(
+                    self.module_name.clone(),
+                    binding.clone(),
+                    false,
+                );
+                binding
+            });
+            let callee = Expression::from(ctx.ast.member_expression_static(
+                SPAN,
+                binding.create_read_expression(ctx),
+                ctx.ast.identifier_name(SPAN, name),
+                false,
+            ));
+            return ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+        }
+
+        let binding = self.imports.entry(name).or_insert_with(|| {
+            let binding = ctx.generate_uid_in_root_scope(name, SymbolFlags::Import);
+            self.ctx.module_imports.add_named_import(
+                self.module_name.clone(),
+                Atom::from(name),
+                binding.clone(),
+                false,
+            );
+            binding
+        });
+        let callee = binding.create_read_expression(ctx);
+        ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+    }
+
+    fn create_declarator(
+        binding: &BoundIdentifier<'a>,
+        init: Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> VariableDeclarator<'a> {
+        let id = binding.create_binding_pattern(ctx);
+        ctx.ast.variable_declarator(SPAN, VariableDeclarationKind::Var, id, Some(init), false)
+    }
+
+    /// `() => expr`
+    fn create_arrow(
+        expr: Expression<'a>,
+        scope_id: ScopeId,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::ArrowFormalParameters,
+            ctx.ast.vec(),
+            NONE,
+        );
+        let body = ctx.ast.alloc_function_body(
+            SPAN,
+            ctx.ast.vec(),
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, expr)),
+        );
+        Expression::ArrowFunctionExpression(ctx.ast.alloc_arrow_function_expression_with_scope_id(
+            SPAN, true, false, NONE, params, NONE, body, scope_id,
+        ))
+    }
+
+    fn create_getter_scope(scope_id: ScopeId, ctx: &mut TraverseCtx<'a>) -> ScopeId {
+        ctx.create_child_scope(scope_id, ScopeFlags::Function | ScopeFlags::GetAccessor)
+    }
+
+    /// `get key() { return value; }`
+    fn create_getter(
+        key: PropertyKey<'a>,
+        value: Expression<'a>,
+        scope_id: ScopeId,
+        ctx: &TraverseCtx<'a>,
+    ) -> ObjectPropertyKind<'a> {
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::UniqueFormalParameters,
+            ctx.ast.vec(),
+            NONE,
+        );
+        let body = ctx.ast.alloc_function_body(
+            SPAN,
+            ctx.ast.vec(),
+            ctx.ast.vec1(ctx.ast.statement_return(SPAN, Some(value))),
+        );
+        let function = ctx.ast.alloc_function_with_scope_id(
+            FunctionType::FunctionExpression,
+            SPAN,
+            None,
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(body),
+            scope_id,
+        );
+        ctx.ast.object_property_kind_object_property(
+            SPAN,
+            PropertyKind::Get,
+            key,
+            Expression::FunctionExpression(function),
+            None,
+            false,
+            false,
+            false,
+        )
+    }
+
+    fn create_property(
+        key: PropertyKey<'a>,
+        value: Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> ObjectPropertyKind<'a> {
+        ctx.ast.object_property_kind_object_property(
+            SPAN,
+            PropertyKind::Init,
+            key,
+            value,
+            None,
+            false,
+            false,
+            false,
+        )
+    }
+}
+
+/// Components are elements named by a reference, e.g. `<Comp>`, `<a.b>` or `<this>`.
+fn is_component(name: &JSXElementName) -> bool {
+    matches!(
+        name,
+        JSXElementName::IdentifierReference(_)
+            | JSXElementName::MemberExpression(_)
+            | JSXElementName::ThisExpression(_)
+    )
+}
+
+/// Whether a native element, or any of its descendants, has dynamic attributes or children,
+/// so it needs to be referenced when its template is cloned.
+fn needs_reference(element: &JSXElement) -> bool {
+    let has_dynamic_attribute =
+        element.opening_element.attributes.iter().any(|attribute| match attribute {
+            JSXAttributeItem::Attribute(attribute) => {
+                static_attribute_value(&attribute_name(&attribute.name), attribute.value.as_ref())
+                    .is_none()
+            }
+            JSXAttributeItem::SpreadAttribute(_) => true,
+        });
+    has_dynamic_attribute
+        || element.children.iter().any(|child| match child {
+            JSXChild::Text(_) | JSXChild::Spread(_) => false,
+            JSXChild::Element(element) => {
+                is_component(&element.opening_element.name) || needs_reference(element)
+            }
+            JSXChild::Fragment(_) => true,
+            JSXChild::ExpressionContainer(container) => {
+                !matches!(container.expression, JSXExpression::EmptyExpression(_))
+            }
+        })
+}
+
+/// Value of an attribute which can be added to a template.
+enum StaticValue {
+    /// `<input disabled />`
+    Boolean,
+    String(String),
+}
+
+/// Get the value of an attribute, if it can be added to a template.
+fn static_attribute_value(name: &str, value: Option<&JSXAttributeValue>) -> Option<StaticValue> {
+    if name == "ref" || event_name(name).is_some() {
+        return None;
+    }
+    match value {
+        None => Some(StaticValue::Boolean),
+        Some(JSXAttributeValue::StringLiteral(lit)) => {
+            Some(StaticValue::String(ReactJsx::decode_entities(&lit.value)))
+        }
+        Some(JSXAttributeValue::ExpressionContainer(container)) => match &container.expression {
+            JSXExpression::StringLiteral(lit) => Some(StaticValue::String(lit.value.to_string())),
+            JSXExpression::NumericLiteral(lit) => {
+                Some(StaticValue::String(lit.value.to_js_string()))
+            }
+            _ => None,
+        },
+        Some(JSXAttributeValue::Element(_) | JSXAttributeValue::Fragment(_)) => None,
+    }
+}
+
+/// `class` or `xlink:href`
+fn attribute_name(name: &JSXAttributeName) -> String {
+    match name {
+        JSXAttributeName::Identifier(ident) => ident.name.to_string(),
+        JSXAttributeName::NamespacedName(name) => name.to_string(),
+    }
+}
+
+/// `onClick` -> `click`
+fn event_name(name: &str) -> Option<String> {
+    let event = name.strip_prefix("on")?;
+    if !event.starts_with(|c: char| c.is_ascii_uppercase()) {
+        return None;
+    }
+    Some(event.cow_to_ascii_lowercase().into_owned())
+}
+
+fn escape_html(s: &str, is_attribute: bool, out: &mut String) {
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' if !is_attribute => out.push_str("&lt;"),
+            '"' if is_attribute => out.push_str("&quot;"),
+            c => out.push(c),
+        }
+    }
+}
+
+/// Whether an expression can change when re-evaluated, so needs to be tracked.
+///
+/// Expressions containing calls or member accesses outside of functions are dynamic.
+fn is_dynamic(expr: &Expression) -> bool {
+    let mut finder = DynamicFinder { found: false };
+    finder.visit_expression(expr);
+    finder.found
+}
+
+struct DynamicFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for DynamicFinder {
+    fn visit_expression(&mut self, expr: &Expression<'a>) {
+        if self.found {
+            return;
+        }
+        match expr {
+            Expression::CallExpression(_)
+            | Expression::NewExpression(_)
+            | Expression::TaggedTemplateExpression(_)
+            | Expression::StaticMemberExpression(_)
+            | Expression::ComputedMemberExpression(_)
+            | Expression::PrivateFieldExpression(_)
+            | Expression::ChainExpression(_) => self.found = true,
+            // Functions are not called when evaluated
+            Expression::FunctionExpression(_)
+            | Expression::ArrowFunctionExpression(_)
+            | Expression::ClassExpression(_) => {}
+            _ => walk::walk_expression(self, expr),
+        }
+    }
+}
+
+/// Set parent of all scopes which are direct children of `expr`'s enclosing scope
+/// (i.e. scopes of functions, classes etc. within `expr`) to `parent_scope_id`.
+///
+/// Used when moving expressions from JSX into functions.
+fn reparent_scopes<'a>(
+    expr: &mut Expression<'a>,
+    parent_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    ScopeReparenter { parent_scope_id, depth: 0, ctx }.visit_expression(expr);
+}
+
+struct ScopeReparenter<'a, 'ctx> {
+    parent_scope_id: ScopeId,
+    depth: u32,
+    ctx: &'ctx mut TraverseCtx<'a>,
+}
+
+impl<'a, 'ctx> VisitMut<'a> for ScopeReparenter<'a, 'ctx> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        if self.depth == 0 {
+            let scope_id = scope_id.get().unwrap();
+            self.ctx.scopes_mut().change_parent_id(scope_id, Some(self.parent_scope_id));
+        }
+        self.depth += 1;
+    }
+
+    fn leave_scope(&mut self) {
+        self.depth -= 1;
+    }
+}
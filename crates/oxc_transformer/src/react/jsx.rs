@@ -378,6 +378,12 @@ impl<'a, 'ctx> ReactJsx<'a, 'ctx> {
                 let pragma_frag = Pragma::parse(options.pragma_frag.as_ref(), "Fragment", ast, ctx);
                 Bindings::Classic(ClassicBindings { pragma, pragma_frag })
             }
+            // Unused, JSX is transformed by `DomExpressions`
+            JsxRuntime::Dom => {
+                let pragma = Pragma::parse(None, "createElement", ast, ctx);
+                let pragma_frag = Pragma::parse(None, "Fragment", ast, ctx);
+                Bindings::Classic(ClassicBindings { pragma, pragma_frag })
+            }
             JsxRuntime::Automatic => {
                 if options.pragma.is_some() || options.pragma_frag.is_some() {
                     ctx.error(diagnostics::pragma_and_pragma_frag_cannot_be_set());
@@ -787,7 +793,7 @@ impl<'a, 'ctx> ReactJsx<'a, 'ctx> {
         }
     }
 
-    fn transform_jsx_member_expression(
+    pub(super) fn transform_jsx_member_expression(
         expr: &JSXMemberExpression<'a>,
         ctx: &TraverseCtx<'a>,
     ) -> Expression<'a> {
@@ -860,7 +866,10 @@ impl<'a, 'ctx> ReactJsx<'a, 'ctx> {
         }
     }
 
-    fn get_attribute_name(name: &JSXAttributeName<'a>, ctx: &TraverseCtx<'a>) -> PropertyKey<'a> {
+    pub(super) fn get_attribute_name(
+        name: &JSXAttributeName<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> PropertyKey<'a> {
         match name {
             JSXAttributeName::Identifier(ident) => {
                 let name = ident.name.clone();
@@ -899,7 +908,7 @@ impl<'a, 'ctx> ReactJsx<'a, 'ctx> {
     /// - Remove empty lines and join the rest with " ".
     ///
     /// <https://github.com/microsoft/TypeScript/blob/f0374ce2a9c465e27a15b7fa4a347e2bd9079450/src/compiler/transformers/jsx.ts#L557-L608>
-    fn fixup_whitespace_and_decode_entities(text: &str) -> Option<String> {
+    pub(super) fn fixup_whitespace_and_decode_entities(text: &str) -> Option<String> {
         let mut acc: Option<String> = None;
         let mut first_non_whitespace: Option<usize> = Some(0);
         let mut last_non_whitespace: Option<usize> = None;
@@ -935,7 +944,7 @@ impl<'a, 'ctx> ReactJsx<'a, 'ctx> {
     /// Replace entities like "&nbsp;", "&#123;", and "&#xDEADBEEF;" with the characters they encode.
     /// * See <https://en.wikipedia.org/wiki/List_of_XML_and_HTML_character_entity_references>
     /// Code adapted from <https://github.com/microsoft/TypeScript/blob/514f7e639a2a8466c075c766ee9857a30ed4e196/src/compiler/transformers/jsx.ts#L617C1-L635>
-    fn decode_entities(s: &str) -> String {
+    pub(super) fn decode_entities(s: &str) -> String {
         let mut buffer = String::new();
         let mut chars = s.char_indices();
         let mut prev = 0;
@@ -7,12 +7,14 @@ use crate::TransformCtx;
 mod comments;
 mod diagnostics;
 mod display_name;
+mod dom_expressions;
 mod jsx;
 mod jsx_self;
 mod jsx_source;
 mod options;
 mod refresh;
 mod utils;
+use dom_expressions::DomExpressions;
 use refresh::ReactRefresh;
 
 pub use display_name::ReactDisplayName;
@@ -31,6 +33,8 @@ pub(crate) use comments::update_options_with_comments;
 /// * [plugin-transform-react-display-name](https://babeljs.io/docs/babel-plugin-transform-react-display-name)
 pub struct React<'a, 'ctx> {
     jsx: ReactJsx<'a, 'ctx>,
+    /// Replaces `jsx` when the runtime is [`JsxRuntime::Dom`]
+    dom_expressions: Option<DomExpressions<'a, 'ctx>>,
     display_name: ReactDisplayName<'a, 'ctx>,
     refresh: ReactRefresh<'a, 'ctx>,
     jsx_plugin: bool,
@@ -50,7 +54,10 @@ impl<'a, 'ctx> React<'a, 'ctx> {
             jsx_plugin, display_name_plugin, jsx_self_plugin, jsx_source_plugin, ..
         } = options;
         let refresh = options.refresh.clone();
+        let dom_expressions = (jsx_plugin && options.runtime.is_dom())
+            .then(|| DomExpressions::new(&options, ast, ctx));
         Self {
+            dom_expressions,
             jsx: ReactJsx::new(options, ast, ctx),
             display_name: ReactDisplayName::new(ctx),
             jsx_plugin,
@@ -77,7 +84,9 @@ impl<'a, 'ctx> Traverse<'a> for React<'a, 'ctx> {
         if self.refresh_plugin {
             self.refresh.exit_program(program, ctx);
         }
-        if self.jsx_plugin {
+        if let Some(dom_expressions) = &mut self.dom_expressions {
+            dom_expressions.exit_program(program, ctx);
+        } else if self.jsx_plugin {
             self.jsx.exit_program(program, ctx);
         } else if self.jsx_source_plugin {
             self.jsx.jsx_source.exit_program(program, ctx);
@@ -126,7 +135,9 @@ impl<'a, 'ctx> Traverse<'a> for React<'a, 'ctx> {
     }
 
     fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
-        if self.jsx_plugin {
+        if let Some(dom_expressions) = &mut self.dom_expressions {
+            dom_expressions.exit_expression(expr, ctx);
+        } else if self.jsx_plugin {
             self.jsx.exit_expression(expr, ctx);
         }
         if self.refresh_plugin {
@@ -9,6 +9,7 @@ fn default_as_true() -> bool {
 ///
 /// Auto imports the functions that JSX transpiles to.
 /// classic does not automatic import anything.
+/// dom compiles JSX to DOM template cloning, and imports its helpers from `import_source`.
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum JsxRuntime {
@@ -16,6 +17,10 @@ pub enum JsxRuntime {
     /// The default runtime is switched to automatic in Babel 8.
     #[default]
     Automatic,
+    /// Compile JSX to DOM expressions, as used by [Solid](https://www.solidjs.com).
+    ///
+    /// Helpers are imported from `import_source`, which defaults to `solid-js/web`.
+    Dom,
 }
 
 impl JsxRuntime {
@@ -26,6 +31,10 @@ impl JsxRuntime {
     pub fn is_automatic(self) -> bool {
         self == Self::Automatic
     }
+
+    pub fn is_dom(self) -> bool {
+        self == Self::Dom
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
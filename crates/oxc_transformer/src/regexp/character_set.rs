@@ -0,0 +1,284 @@
+//! Sets of code points, used to expand Unicode property escapes and set notation
+//! into explicit character classes.
+//!
+//! Unicode property data comes from `regex-syntax`'s Unicode tables.
+
+use regex_syntax::hir::{Class, HirKind};
+
+use oxc_regular_expression::ast::CharacterClassEscapeKind;
+
+const MAX_CODE_POINT: u32 = 0x10_FFFF;
+
+/// Lone surrogates are code points in JS regular expressions, but not in Rust's `char`.
+const SURROGATES: (u32, u32) = (0xD800, 0xDFFF);
+
+/// Set of code points, stored as sorted, non-overlapping and non-adjacent inclusive ranges.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CharacterSet {
+    ranges: Vec<(u32, u32)>,
+}
+
+impl CharacterSet {
+    pub fn from_ranges<I: IntoIterator<Item = (u32, u32)>>(ranges: I) -> Self {
+        let mut ranges = ranges.into_iter().collect::<Vec<_>>();
+        ranges.sort_unstable();
+        let mut merged: Vec<(u32, u32)> = Vec::with_capacity(ranges.len());
+        for (min, max) in ranges {
+            match merged.last_mut() {
+                Some(last) if min <= last.1.saturating_add(1) => last.1 = last.1.max(max),
+                _ => merged.push((min, max)),
+            }
+        }
+        Self { ranges: merged }
+    }
+
+    pub fn from_code_point(cp: u32) -> Self {
+        Self { ranges: vec![(cp, cp)] }
+    }
+
+    pub fn ranges(&self) -> &[(u32, u32)] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self::from_ranges(self.ranges.iter().chain(&other.ranges).copied())
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a_min, a_max) = self.ranges[i];
+            let (b_min, b_max) = other.ranges[j];
+            let min = a_min.max(b_min);
+            let max = a_max.min(b_max);
+            if min <= max {
+                ranges.push((min, max));
+            }
+            if a_max < b_max {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { ranges }
+    }
+
+    pub fn subtraction(&self, other: &Self) -> Self {
+        self.intersection(&other.complement())
+    }
+
+    pub fn complement(&self) -> Self {
+        let mut ranges = vec![];
+        let mut next = 0;
+        for &(min, max) in &self.ranges {
+            if min > next {
+                ranges.push((next, min - 1));
+            }
+            next = max + 1;
+        }
+        if next <= MAX_CODE_POINT {
+            ranges.push((next, MAX_CODE_POINT));
+        }
+        Self { ranges }
+    }
+
+    /// Split astral code points (above U+FFFF) in the set into the UTF-16 surrogate pairs
+    /// which encode them.
+    ///
+    /// Returns a list of sets of lead surrogates, each with the set of trail surrogates which
+    /// may follow any of them. Consecutive lead surrogates with the same trail surrogates are
+    /// grouped together. Code points below U+10000 are ignored.
+    pub fn surrogate_pairs(&self) -> Vec<(Self, Self)> {
+        // Trail surrogate ranges for each lead surrogate
+        let mut leads: Vec<(u32, Vec<(u32, u32)>)> = vec![];
+        for &(min, max) in &self.ranges {
+            let mut start = min.max(0x1_0000);
+            while start <= max {
+                let (lead, trail_start) = to_surrogate_pair(start);
+                let end = max.min(from_surrogate_pair(lead, 0xDFFF));
+                let (_, trail_end) = to_surrogate_pair(end);
+                match leads.last_mut() {
+                    Some((last_lead, trails)) if *last_lead == lead => {
+                        trails.push((trail_start, trail_end));
+                    }
+                    _ => leads.push((lead, vec![(trail_start, trail_end)])),
+                }
+                start = end + 1;
+            }
+        }
+
+        let mut pairs: Vec<(Self, Self)> = vec![];
+        for (lead, trails) in leads {
+            match pairs.last_mut() {
+                Some((last_leads, last_trails))
+                    if last_leads.ranges[0].1 + 1 == lead && last_trails.ranges == trails =>
+                {
+                    last_leads.ranges[0].1 = lead;
+                }
+                _ => pairs.push((Self { ranges: vec![(lead, lead)] }, Self { ranges: trails })),
+            }
+        }
+        pairs
+    }
+
+    /// Code points matched by `\d`, `\s`, `\w` and their negations.
+    ///
+    /// Does not include the extra characters `\w` matches with `i` and `u` flags.
+    pub fn from_class_escape(kind: CharacterClassEscapeKind) -> Self {
+        let set = match kind {
+            CharacterClassEscapeKind::D | CharacterClassEscapeKind::NegativeD => {
+                Self::from_ranges([(0x30, 0x39)])
+            }
+            CharacterClassEscapeKind::S | CharacterClassEscapeKind::NegativeS => {
+                Self::from_ranges([
+                    (0x09, 0x0D),
+                    (0x20, 0x20),
+                    (0xA0, 0xA0),
+                    (0x1680, 0x1680),
+                    (0x2000, 0x200A),
+                    (0x2028, 0x2029),
+                    (0x202F, 0x202F),
+                    (0x205F, 0x205F),
+                    (0x3000, 0x3000),
+                    (0xFEFF, 0xFEFF),
+                ])
+            }
+            CharacterClassEscapeKind::W | CharacterClassEscapeKind::NegativeW => {
+                Self::from_ranges([(0x30, 0x39), (0x41, 0x5A), (0x5F, 0x5F), (0x61, 0x7A)])
+            }
+        };
+        match kind {
+            CharacterClassEscapeKind::NegativeD
+            | CharacterClassEscapeKind::NegativeS
+            | CharacterClassEscapeKind::NegativeW => set.complement(),
+            _ => set,
+        }
+    }
+
+    /// Code points matched by `\p{name=value}` or `\p{name}`.
+    ///
+    /// Returns `None` if the property is not known.
+    pub fn from_property(name: &str, value: Option<&str>) -> Option<Self> {
+        let mut set =
+            if matches!((name, value), ("General_Category" | "gc", Some("Cs" | "Surrogate"))) {
+                // `regex-syntax` has no data for this category, as surrogates are not valid `char`s
+                Self::default()
+            } else {
+                let query = match value {
+                    Some(value) => format!(r"\p{{{name}={value}}}"),
+                    None => format!(r"\p{{{name}}}"),
+                };
+                let hir = regex_syntax::parse(&query).ok()?;
+                match hir.kind() {
+                    HirKind::Class(Class::Unicode(class)) => Self::from_ranges(
+                        class
+                            .iter()
+                            .map(|range| (u32::from(range.start()), u32::from(range.end()))),
+                    ),
+                    HirKind::Literal(literal) => {
+                        let ch = std::str::from_utf8(&literal.0).ok()?.chars().next()?;
+                        Self::from_code_point(u32::from(ch))
+                    }
+                    _ => return None,
+                }
+            };
+        if property_includes_surrogates(name, value) {
+            set = set.union(&Self::from_ranges([SURROGATES]));
+        }
+        Some(set)
+    }
+}
+
+fn to_surrogate_pair(cp: u32) -> (u32, u32) {
+    let offset = cp - 0x1_0000;
+    (0xD800 + (offset >> 10), 0xDC00 + (offset & 0x3FF))
+}
+
+fn from_surrogate_pair(lead: u32, trail: u32) -> u32 {
+    0x1_0000 + ((lead - 0xD800) << 10) + (trail - 0xDC00)
+}
+
+/// `regex-syntax` excludes surrogates from all properties, as they're not valid `char`s.
+fn property_includes_surrogates(name: &str, value: Option<&str>) -> bool {
+    match (name, value) {
+        ("Any" | "Assigned", None) => true,
+        ("General_Category" | "gc", Some(value)) => {
+            matches!(value, "Cs" | "Surrogate" | "C" | "Other")
+        }
+        ("Script" | "sc" | "Script_Extensions" | "scx", Some(value)) => {
+            matches!(value, "Zzzz" | "Unknown")
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::CharacterSet;
+
+    #[test]
+    fn set_operations() {
+        let a = CharacterSet::from_ranges([(0x61, 0x7A), (0x30, 0x39)]);
+        let b = CharacterSet::from_ranges([(0x35, 0x65)]);
+        assert_eq!(a.union(&b).ranges(), &[(0x30, 0x7A)]);
+        assert_eq!(a.intersection(&b).ranges(), &[(0x35, 0x39), (0x61, 0x65)]);
+        assert_eq!(a.subtraction(&b).ranges(), &[(0x30, 0x34), (0x66, 0x7A)]);
+        assert_eq!(b.complement().ranges(), &[(0, 0x34), (0x66, 0x10_FFFF)]);
+    }
+
+    #[test]
+    fn properties() {
+        let ascii = CharacterSet::from_property("ASCII", None).unwrap();
+        assert_eq!(ascii.ranges(), &[(0, 0x7F)]);
+        let greek = CharacterSet::from_property("Script", Some("Greek")).unwrap();
+        assert_eq!(greek, CharacterSet::from_property("sc", Some("Grek")).unwrap());
+        let lu = CharacterSet::from_property("General_Category", Some("Lu")).unwrap();
+        assert_eq!(lu.ranges()[0], (0x41, 0x5A));
+        let any = CharacterSet::from_property("Any", None).unwrap();
+        assert_eq!(any.ranges(), &[(0, 0x10_FFFF)]);
+        let surrogates = CharacterSet::from_property("General_Category", Some("Cs")).unwrap();
+        assert_eq!(surrogates.ranges(), &[(0xD800, 0xDFFF)]);
+        assert!(CharacterSet::from_property("Unknown_Property", None).is_none());
+    }
+
+    #[test]
+    fn surrogate_pairs() {
+        let pairs = |ranges: &[(u32, u32)]| {
+            CharacterSet::from_ranges(ranges.iter().copied())
+                .surrogate_pairs()
+                .into_iter()
+                .map(|(leads, trails)| (leads.ranges().to_vec(), trails.ranges().to_vec()))
+                .collect::<Vec<_>>()
+        };
+        // BMP code points are ignored
+        assert_eq!(pairs(&[(0x61, 0x7A)]), vec![]);
+        // U+1F600
+        assert_eq!(
+            pairs(&[(0x1F600, 0x1F600)]),
+            vec![(vec![(0xD83D, 0xD83D)], vec![(0xDE00, 0xDE00)])]
+        );
+        // Split at lead surrogate boundaries, and whole blocks grouped
+        assert_eq!(
+            pairs(&[
+                (0x1_0000, 0x1_0005),
+                (0x1_0010, 0x1_0020),
+                (0x1_03FF, 0x1_1000),
+                (0x10_FFFF, 0x10_FFFF)
+            ]),
+            vec![
+                (
+                    vec![(0xD800, 0xD800)],
+                    vec![(0xDC00, 0xDC05), (0xDC10, 0xDC20), (0xDFFF, 0xDFFF)]
+                ),
+                (vec![(0xD801, 0xD803)], vec![(0xDC00, 0xDFFF)]),
+                (vec![(0xD804, 0xD804)], vec![(0xDC00, 0xDC00)]),
+                (vec![(0xDBFF, 0xDBFF)], vec![(0xDFFF, 0xDFFF)]),
+            ]
+        );
+    }
+}
@@ -1,73 +1,92 @@
 //! RegExp Transformer
 //!
 //! This module supports various RegExp plugins to handle unsupported RegExp literal features.
-//! When an unsupported feature is detected, these plugins convert the RegExp literal into
+//!
+//! Where possible, the pattern is rewritten to an equivalent pattern which does not use the feature
+//! (see `rewrite` module), like Babel does with [regexpu-core](https://github.com/mathiasbynens/regexpu-core).
+//! When an unsupported feature cannot be rewritten, these plugins convert the RegExp literal into
 //! a `new RegExp()` constructor call to avoid syntax errors.
 //!
-//! Note: You will need to include a polyfill for the `RegExp` constructor in your code to have the correct runtime behavior.
+//! Note: For RegExps converted to `new RegExp()`, you will need to include a polyfill for the `RegExp`
+//! constructor in your code to have the correct runtime behavior.
 //!
 //! ### ES2015
 //!
 //! #### Sticky flag (`y`)
 //! - @babel/plugin-transform-sticky-regex: <https://babeljs.io/docs/en/babel-plugin-transform-sticky-regex>
+//! - Converted to `new RegExp()`.
 //!
 //! #### Unicode flag (`u`)
 //! - @babel/plugin-transform-unicode-regex: <https://babeljs.io/docs/en/babel-plugin-transform-unicode-regex>
+//! - Implementation: Same as regexpu-core's handling
+//! - `/😀+/u` -> `/(?:\uD83D\uDE00)+/`
+//! - Character classes, `.`, `\D`, `\S`, `\W` and `\p{...}` are expanded to match surrogate pairs.
+//! - Case-insensitive patterns (`i` flag) are not supported yet, and are converted to `new RegExp()`.
 //!
 //! ### ES2018
 //!
 //! #### DotAll flag (`s`)
 //! - @babel/plugin-transform-dotall-regex: <https://babeljs.io/docs/en/babel-plugin-transform-dotall-regex>
 //! - Spec: ECMAScript 2018: <https://262.ecma-international.org/9.0/#sec-get-regexp.prototype.dotAll>
+//! - `/a.b/s` -> `/a[\s\S]b/`
 //!
 //! #### Lookbehind assertions (`/(?<=x)/` and `/(?<!x)/`)
 //! - Implementation: Same as esbuild's handling
+//! - Converted to `new RegExp()`.
 //!
 //! #### Named capture groups (`(?<name>x)`)
 //! - @babel/plugin-transform-named-capturing-groups-regex: <https://babeljs.io/docs/en/babel-plugin-transform-named-capturing-groups-regex>
+//! - `/(?<name>x)\k<name>/` -> `babelHelpers.wrapRegExp(/(x)\1/, { name: 1 })`
 //!
 //! #### Unicode property escapes (`\p{...}` and `\P{...}`)
 //! - @babel/plugin-transform-unicode-property-regex: <https://babeljs.io/docs/en/babel-plugin-proposal-unicode-property-regex>
+//! - `/\p{ASCII_Hex_Digit}/u` -> `/[0-9A-Fa-f]/u`
+//! - Unicode data is from `regex-syntax` crate, so may be for a different version of Unicode
+//!   than the target engine supports.
 //!
 //! ### ES2022
 //!
 //! #### Match indices flag (`d`)
 //! - Implementation: Same as esbuild's handling
+//! - Converted to `new RegExp()`.
 //!
 //! ### ES2024
 //!
 //! #### Set notation + properties of strings (`v`)
 //! - @babel/plugin-transform-unicode-sets-regex: <https://babeljs.io/docs/en/babel-plugin-proposal-unicode-sets-regex>
 //! - TC39 Proposal: <https://github.com/tc39/proposal-regexp-set-notation>
+//! - `/[\p{ASCII_Hex_Digit}--[a-f]]/v` -> `/[0-9A-F]/u`
+//! - Properties of strings (`\p{RGI_Emoji}`), strings in `\q{...}`, and set notation with `i` flag
+//!   are not supported yet, and are converted to `new RegExp()`.
 //!
 //! TODO(improve-on-babel): We could convert to plain `RegExp(...)` instead of `new RegExp(...)`.
 //! TODO(improve-on-babel): When flags is empty, we could output `RegExp("(?<=x)")` instead of `RegExp("(?<=x)", "")`.
 //! (actually these would be improvements on ESBuild, not Babel)
 
-use std::borrow::Cow;
-
 use oxc_ast::{ast::*, NONE};
 use oxc_diagnostics::Result;
-use oxc_regular_expression::ast::{
-    CharacterClass, CharacterClassContents, LookAroundAssertionKind, Pattern, Term,
-};
+use oxc_regular_expression::ast::Pattern;
 use oxc_semantic::ReferenceFlags;
-use oxc_span::{Atom, SPAN};
+use oxc_span::{Atom, GetSpan, SPAN};
+use oxc_syntax::number::NumberBase;
 use oxc_traverse::{Traverse, TraverseCtx};
 
-use crate::TransformCtx;
+use crate::{common::helper_loader::Helper, TransformCtx};
 
+mod character_set;
 mod options;
+mod rewrite;
 
 pub use options::RegExpOptions;
+use rewrite::PatternRewriter;
 
 pub struct RegExp<'a, 'ctx> {
     ctx: &'ctx TransformCtx<'a>,
+    options: RegExpOptions,
     unsupported_flags: RegExpFlags,
+    /// Unsupported flags which cannot be rewritten in the pattern
+    constructor_flags: RegExpFlags,
     some_unsupported_patterns: bool,
-    look_behind_assertions: bool,
-    named_capture_groups: bool,
-    unicode_property_escapes: bool,
 }
 
 impl<'a, 'ctx> RegExp<'a, 'ctx> {
@@ -90,25 +109,16 @@ impl<'a, 'ctx> RegExp<'a, 'ctx> {
             unsupported_flags |= RegExpFlags::V;
         }
 
-        // Get if some unsupported patterns
-        let RegExpOptions {
-            look_behind_assertions,
-            named_capture_groups,
-            unicode_property_escapes,
-            ..
-        } = options;
+        // `s` flag is rewritten by replacing `.` with `[\s\S]`, `u` flag by rewriting to
+        // surrogate pairs, and `v` flag by converting to `u` flag. Other flags cannot be rewritten.
+        let constructor_flags = unsupported_flags & (RegExpFlags::Y | RegExpFlags::D);
 
-        let some_unsupported_patterns =
-            look_behind_assertions || named_capture_groups || unicode_property_escapes;
+        // Get if some unsupported patterns
+        let some_unsupported_patterns = options.look_behind_assertions
+            || options.named_capture_groups
+            || options.unicode_property_escapes;
 
-        Self {
-            ctx,
-            unsupported_flags,
-            some_unsupported_patterns,
-            look_behind_assertions,
-            named_capture_groups,
-            unicode_property_escapes,
-        }
+        Self { ctx, options, unsupported_flags, constructor_flags, some_unsupported_patterns }
     }
 }
 
@@ -125,13 +135,21 @@ impl<'a, 'ctx> Traverse<'a> for RegExp<'a, 'ctx> {
 
         let flags = regexp.regex.flags;
         let has_unsupported_flags = flags.intersects(self.unsupported_flags);
-        if !has_unsupported_flags {
-            if !self.some_unsupported_patterns {
-                // This RegExp has no unsupported flags, and there are no patterns which may need transforming,
-                // so there's nothing to do
-                return;
-            }
+        if !has_unsupported_flags && !self.some_unsupported_patterns {
+            // This RegExp has no unsupported flags, and there are no patterns which may need transforming,
+            // so there's nothing to do
+            return;
+        }
 
+        let needs_constructor = flags.intersects(self.constructor_flags);
+
+        let pattern_source = if needs_constructor {
+            match &regexp.regex.pattern {
+                RegExpPattern::Raw(raw) => Atom::from(*raw),
+                RegExpPattern::Pattern(p) => ctx.ast.atom(&p.to_string()),
+                RegExpPattern::Invalid(_) => return,
+            }
+        } else {
             let literal_span = regexp.span;
             let pattern = match &mut regexp.regex.pattern {
                 RegExpPattern::Raw(raw) => {
@@ -151,7 +169,7 @@ impl<'a, 'ctx> Traverse<'a> for RegExp<'a, 'ctx> {
                     ) {
                         Ok(pattern) => {
                             regexp.regex.pattern = RegExpPattern::Pattern(ctx.alloc(pattern));
-                            let RegExpPattern::Pattern(pattern) = &regexp.regex.pattern else {
+                            let RegExpPattern::Pattern(pattern) = &mut regexp.regex.pattern else {
                                 unreachable!()
                             };
                             pattern
@@ -164,18 +182,29 @@ impl<'a, 'ctx> Traverse<'a> for RegExp<'a, 'ctx> {
                     }
                 }
                 RegExpPattern::Invalid(_) => return,
-                RegExpPattern::Pattern(pattern) => &**pattern,
+                RegExpPattern::Pattern(pattern) => pattern,
             };
 
-            if !self.has_unsupported_regular_expression_pattern(pattern) {
-                return;
-            }
-        }
+            // Source of pattern before rewriting, in case it has to be passed to `new RegExp()`
+            let pattern_source = if pattern.span.is_unspanned() {
+                ctx.ast.atom(&pattern.to_string())
+            } else {
+                Atom::from(pattern.span.source_text(self.ctx.source_text))
+            };
 
-        let pattern_source: Cow<'_, str> = match &regexp.regex.pattern {
-            RegExpPattern::Raw(raw) => Cow::Borrowed(raw),
-            RegExpPattern::Pattern(p) => Cow::Owned(p.to_string()),
-            RegExpPattern::Invalid(_) => return,
+            let rewriter = PatternRewriter::new(self.options, flags, ctx.ast.allocator);
+            match rewriter.rewrite(pattern) {
+                Some(rewritten) => {
+                    if rewritten.changed {
+                        regexp.regex.flags = rewritten.flags;
+                        if !rewritten.group_names.is_empty() {
+                            self.wrap_named_groups(expr, &rewritten.group_names, ctx);
+                        }
+                    }
+                    return;
+                }
+                None => pattern_source,
+            }
         };
 
         let callee = {
@@ -199,55 +228,48 @@ impl<'a, 'ctx> Traverse<'a> for RegExp<'a, 'ctx> {
             ctx.ast.argument_expression(ctx.ast.expression_string_literal(SPAN, flags_str));
         arguments.push(flags_str);
 
-        *expr = ctx.ast.expression_new(regexp.span, callee, arguments, NONE);
+        *expr = ctx.ast.expression_new(expr.span(), callee, arguments, NONE);
     }
 }
 
 impl<'a, 'ctx> RegExp<'a, 'ctx> {
-    /// Check if the regular expression contains any unsupported syntax.
+    /// Wrap RegExp literal which had named capture groups removed with `wrapRegExp` helper,
+    /// which adds the named groups to results of `exec` and `replace`.
     ///
-    /// Based on parsed regular expression pattern.
-    fn has_unsupported_regular_expression_pattern(&self, pattern: &Pattern<'a>) -> bool {
-        pattern.body.body.iter().any(|alternative| {
-            alternative.body.iter().any(|term| self.term_contains_unsupported(term))
-        })
-    }
+    /// `/(b)/` -> `babelHelpers.wrapRegExp(/(b)/, { a: 1 })`
+    fn wrap_named_groups(
+        &self,
+        expr: &mut Expression<'a>,
+        group_names: &[(Atom<'a>, u32)],
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let properties = ctx.ast.vec_from_iter(group_names.iter().map(|(name, index)| {
+            let key = ctx.ast.property_key_identifier_name(SPAN, name.clone());
+            let value = ctx.ast.expression_numeric_literal(
+                SPAN,
+                f64::from(*index),
+                index.to_string(),
+                NumberBase::Decimal,
+            );
+            ctx.ast.object_property_kind_object_property(
+                SPAN,
+                PropertyKind::Init,
+                key,
+                value,
+                None,
+                false,
+                false,
+                false,
+            )
+        }));
+        let groups = ctx.ast.expression_object(SPAN, properties, None);
 
-    fn term_contains_unsupported(&self, mut term: &Term) -> bool {
-        // Loop because `Term::Quantifier` contains a nested `Term`
-        loop {
-            match term {
-                Term::CapturingGroup(_) => return self.named_capture_groups,
-                Term::UnicodePropertyEscape(_) => return self.unicode_property_escapes,
-                Term::CharacterClass(character_class) => {
-                    return self.unicode_property_escapes
-                        && character_class_has_unicode_property_escape(character_class)
-                }
-                Term::LookAroundAssertion(assertion) => {
-                    return self.look_behind_assertions
-                        && matches!(
-                            assertion.kind,
-                            LookAroundAssertionKind::Lookbehind
-                                | LookAroundAssertionKind::NegativeLookbehind
-                        )
-                }
-                Term::Quantifier(quantifier) => term = &quantifier.body,
-                _ => return false,
-            }
-        }
+        let regexp = ctx.ast.move_expression(expr);
+        let arguments = ctx.ast.vec_from_iter([Argument::from(regexp), Argument::from(groups)]);
+        *expr = self.ctx.helper_call_expr(Helper::WrapRegExp, arguments, ctx);
     }
 }
 
-fn character_class_has_unicode_property_escape(character_class: &CharacterClass) -> bool {
-    character_class.body.iter().any(|element| match element {
-        CharacterClassContents::UnicodePropertyEscape(_) => true,
-        CharacterClassContents::NestedCharacterClass(character_class) => {
-            character_class_has_unicode_property_escape(character_class)
-        }
-        _ => false,
-    })
-}
-
 fn try_parse_pattern<'a>(
     raw: &'a str,
     pattern_span_offset: u32,
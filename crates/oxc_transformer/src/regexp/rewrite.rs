@@ -0,0 +1,578 @@
+//! Rewrite RegExp patterns in place, to remove features which are not supported by the targets.
+//!
+//! * dotAll flag (`s`): `.` is replaced with `[\s\S]`, and `s` flag is removed.
+//! * Named capture groups: `(?<name>x)` is replaced with `(x)`, and `\k<name>` with `\1`.
+//!   Names of the groups are returned, so the RegExp can be wrapped with `wrapRegExp` helper.
+//! * Unicode property escapes: `\p{...}` and `\P{...}` are expanded into character classes.
+//! * Set notation (`v` flag): character classes are expanded into plain character classes,
+//!   and `v` flag is replaced with `u`.
+//! * Unicode flag (`u`): astral characters are replaced with surrogate pairs, and character
+//!   classes, `.`, `\D`, `\S`, `\W` and `\p{...}` with alternatives which match the BMP characters,
+//!   surrogate pairs, and lone surrogates separately. `u` flag is removed.
+//!   Case-insensitive patterns are not supported, as case folding differs without `u` flag.
+//!
+//! Based on [regexpu-core](https://github.com/mathiasbynens/regexpu-core).
+
+use oxc_allocator::{Allocator, Box, Vec};
+use oxc_ast::ast::RegExpFlags;
+use oxc_regular_expression::ast::{
+    Alternative, BoundaryAssertion, BoundaryAssertionKind, Character, CharacterClass,
+    CharacterClassContents, CharacterClassContentsKind, CharacterClassEscape,
+    CharacterClassEscapeKind, CharacterClassRange, CharacterKind, Disjunction, IgnoreGroup,
+    IndexedReference, LookAroundAssertion, LookAroundAssertionKind, Pattern, Term,
+    UnicodePropertyEscape,
+};
+use oxc_span::{Atom, SPAN};
+
+use super::{character_set::CharacterSet, options::RegExpOptions};
+
+/// Result of rewriting a pattern.
+pub struct RewrittenPattern<'a> {
+    /// Flags after rewriting.
+    pub flags: RegExpFlags,
+    /// `true` if pattern or flags were changed.
+    pub changed: bool,
+    /// Names of removed capture groups, and their indexes.
+    pub group_names: std::vec::Vec<(Atom<'a>, u32)>,
+}
+
+pub struct PatternRewriter<'a> {
+    allocator: &'a Allocator,
+    options: RegExpOptions,
+    flags: RegExpFlags,
+    /// `true` if pattern has `v` flag, which is being converted to `u` flag
+    unicode_sets: bool,
+    /// `true` if pattern has `u` (or `v`) flag, which is being removed
+    unicode: bool,
+    /// `true` if `.` matches line terminators in the current group
+    dot_all: bool,
+    group_count: u32,
+    group_names: std::vec::Vec<(Atom<'a>, u32)>,
+    changed: bool,
+}
+
+impl<'a> PatternRewriter<'a> {
+    pub fn new(options: RegExpOptions, flags: RegExpFlags, allocator: &'a Allocator) -> Self {
+        let unicode_sets = options.set_notation && flags.contains(RegExpFlags::V);
+        Self {
+            allocator,
+            options,
+            flags,
+            unicode_sets,
+            unicode: options.unicode_flag && (unicode_sets || flags.contains(RegExpFlags::U)),
+            dot_all: flags.contains(RegExpFlags::S),
+            group_count: 0,
+            group_names: vec![],
+            changed: false,
+        }
+    }
+
+    /// Rewrite `pattern` in place.
+    ///
+    /// Returns `None` if pattern contains features which cannot be rewritten.
+    /// `pattern` may have been partially rewritten in that case.
+    pub fn rewrite(mut self, pattern: &mut Pattern<'a>) -> Option<RewrittenPattern<'a>> {
+        // Case-insensitive set operations and case-insensitive patterns without `u` flag
+        // are not supported yet
+        if (self.unicode_sets || self.unicode) && self.flags.contains(RegExpFlags::I) {
+            return None;
+        }
+
+        if self.options.named_capture_groups {
+            self.collect_group_names(&pattern.body);
+        }
+        self.rewrite_disjunction(&mut pattern.body)?;
+
+        let mut flags = self.flags;
+        if self.options.dot_all_flag && flags.contains(RegExpFlags::S) {
+            flags.remove(RegExpFlags::S);
+            self.changed = true;
+        }
+        if self.unicode_sets {
+            flags.remove(RegExpFlags::V);
+            flags.insert(RegExpFlags::U);
+            self.changed = true;
+        }
+        if self.unicode {
+            flags.remove(RegExpFlags::U);
+            self.changed = true;
+        }
+        if self.changed {
+            // Pattern is printed from the AST, instead of source text
+            pattern.span = SPAN;
+        }
+
+        Some(RewrittenPattern { flags, changed: self.changed, group_names: self.group_names })
+    }
+
+    /// Collect names of capture groups, and their indexes.
+    fn collect_group_names(&mut self, disjunction: &Disjunction<'a>) {
+        for alternative in &disjunction.body {
+            for term in &alternative.body {
+                self.collect_group_names_in_term(term);
+            }
+        }
+    }
+
+    fn collect_group_names_in_term(&mut self, term: &Term<'a>) {
+        match term {
+            Term::CapturingGroup(group) => {
+                self.group_count += 1;
+                if let Some(name) = &group.name {
+                    self.group_names.push((name.clone(), self.group_count));
+                }
+                self.collect_group_names(&group.body);
+            }
+            Term::IgnoreGroup(group) => self.collect_group_names(&group.body),
+            Term::LookAroundAssertion(assertion) => self.collect_group_names(&assertion.body),
+            Term::Quantifier(quantifier) => self.collect_group_names_in_term(&quantifier.body),
+            _ => {}
+        }
+    }
+
+    fn rewrite_disjunction(&mut self, disjunction: &mut Disjunction<'a>) -> Option<()> {
+        for alternative in disjunction.body.iter_mut() {
+            for term in alternative.body.iter_mut() {
+                self.rewrite_term(term)?;
+            }
+            if self.unicode {
+                self.split_astral_characters(&mut alternative.body);
+            }
+        }
+        Some(())
+    }
+
+    /// Replace astral characters with their surrogate pairs.
+    /// `😀` -> `\uD83D\uDE00`
+    fn split_astral_characters(&self, terms: &mut Vec<'a, Term<'a>>) {
+        if !terms.iter().any(|term| matches!(term, Term::Character(ch) if ch.value > 0xFFFF)) {
+            return;
+        }
+        let mut new_terms = Vec::with_capacity_in(terms.len() + 1, self.allocator);
+        for term in terms.drain(..) {
+            match term {
+                Term::Character(ch) if ch.value > 0xFFFF => {
+                    let set = CharacterSet::from_code_point(ch.value);
+                    for (lead, trail) in set.surrogate_pairs() {
+                        new_terms.push(self.create_code_unit_term(&lead));
+                        new_terms.push(self.create_code_unit_term(&trail));
+                    }
+                }
+                term => new_terms.push(term),
+            }
+        }
+        *terms = new_terms;
+    }
+
+    fn rewrite_term(&mut self, term: &mut Term<'a>) -> Option<()> {
+        match term {
+            Term::Dot(_) if self.unicode => {
+                // `.` -> `(?:[...]|[\uD800-\uDBFF][\uDC00-\uDFFF]|...)`
+                let set = if self.dot_all {
+                    CharacterSet::from_ranges([(0, 0x10_FFFF)])
+                } else {
+                    CharacterSet::from_ranges([(0x0A, 0x0A), (0x0D, 0x0D), (0x2028, 0x2029)])
+                        .complement()
+                };
+                *term = self.create_unicode_term(&set);
+                self.changed = true;
+            }
+            Term::Dot(_) if self.dot_all && self.options.dot_all_flag => {
+                // `.` -> `[\s\S]`
+                let body = Vec::from_iter_in(
+                    [CharacterClassEscapeKind::S, CharacterClassEscapeKind::NegativeS].map(
+                        |kind| {
+                            CharacterClassContents::CharacterClassEscape(Box::new_in(
+                                CharacterClassEscape { span: SPAN, kind },
+                                self.allocator,
+                            ))
+                        },
+                    ),
+                    self.allocator,
+                );
+                *term = Term::CharacterClass(self.create_character_class(false, body));
+                self.changed = true;
+            }
+            Term::CapturingGroup(group) => {
+                if self.options.named_capture_groups && group.name.take().is_some() {
+                    self.changed = true;
+                }
+                self.rewrite_disjunction(&mut group.body)?;
+            }
+            Term::NamedReference(reference) if self.options.named_capture_groups => {
+                // `\k<name>` -> `\1`
+                let span = reference.span;
+                let (_, index) =
+                    self.group_names.iter().find(|(name, _)| *name == reference.name)?;
+                let index = *index;
+                *term = Term::IndexedReference(Box::new_in(
+                    IndexedReference { span, index },
+                    self.allocator,
+                ));
+                self.changed = true;
+            }
+            Term::IgnoreGroup(group) => {
+                let dot_all = self.dot_all;
+                if let Some(modifiers) = &group.modifiers {
+                    // `sticky` is the `s` modifier
+                    if modifiers.enabling.as_ref().is_some_and(|modifier| modifier.sticky) {
+                        self.dot_all = true;
+                    }
+                    if self.unicode
+                        && modifiers.enabling.as_ref().is_some_and(|modifier| modifier.ignore_case)
+                    {
+                        return None;
+                    }
+                    if modifiers.disabling.as_ref().is_some_and(|modifier| modifier.sticky) {
+                        self.dot_all = false;
+                    }
+                }
+                let result = self.rewrite_disjunction(&mut group.body);
+                self.dot_all = dot_all;
+                result?;
+            }
+            Term::LookAroundAssertion(assertion) => {
+                if self.options.look_behind_assertions
+                    && matches!(
+                        assertion.kind,
+                        LookAroundAssertionKind::Lookbehind
+                            | LookAroundAssertionKind::NegativeLookbehind
+                    )
+                {
+                    return None;
+                }
+                self.rewrite_disjunction(&mut assertion.body)?;
+            }
+            Term::Quantifier(quantifier) => {
+                self.rewrite_term(&mut quantifier.body)?;
+                // `😀+` -> `(?:\uD83D\uDE00)+`
+                if let Term::Character(ch) = &quantifier.body {
+                    if self.unicode && ch.value > 0xFFFF {
+                        let set = CharacterSet::from_code_point(ch.value);
+                        quantifier.body = self.create_unicode_term(&set);
+                    }
+                }
+            }
+            Term::Character(ch) if self.unicode && (0xD800..=0xDFFF).contains(&ch.value) => {
+                // Lone surrogate only matches if it's not part of a surrogate pair
+                let set = CharacterSet::from_code_point(ch.value);
+                *term = self.create_unicode_term(&set);
+                self.changed = true;
+            }
+            Term::CharacterClassEscape(escape)
+                if self.unicode
+                    && matches!(
+                        escape.kind,
+                        CharacterClassEscapeKind::NegativeD
+                            | CharacterClassEscapeKind::NegativeS
+                            | CharacterClassEscapeKind::NegativeW
+                    ) =>
+            {
+                // `\D`, `\S` and `\W` match astral characters too
+                let set = CharacterSet::from_class_escape(escape.kind);
+                *term = self.create_unicode_term(&set);
+                self.changed = true;
+            }
+            Term::UnicodePropertyEscape(escape) if self.unicode => {
+                // `\p{...}` -> `(?:[...]|...)`
+                let set = Self::property_escape_set(escape)?;
+                *term = self.create_unicode_term(&set);
+                self.changed = true;
+            }
+            Term::UnicodePropertyEscape(escape)
+                if self.options.unicode_property_escapes
+                    || (self.unicode_sets && escape.strings) =>
+            {
+                // `\p{...}` -> `[...]`
+                let set = Self::property_escape_set(escape)?;
+                let body = self.create_class_contents(&set);
+                *term = Term::CharacterClass(self.create_character_class(false, body));
+                self.changed = true;
+            }
+            Term::CharacterClass(class) => {
+                if self.unicode {
+                    // `[^a]` -> `(?:[...]|[\uD800-\uDBFF][\uDC00-\uDFFF]|...)`
+                    let set = self.character_class_set(class)?;
+                    let set = if class.negative { set.complement() } else { set };
+                    if !Self::is_bmp_class(class, &set) {
+                        *term = self.create_unicode_term(&set);
+                        self.changed = true;
+                    }
+                } else if self.unicode_sets {
+                    // `[\p{...}&&[a-z]]` -> `[...]`
+                    let negative = class.negative;
+                    let set = self.character_class_set(class)?;
+                    let body = self.create_class_contents(&set);
+                    *term = Term::CharacterClass(self.create_character_class(negative, body));
+                    self.changed = true;
+                } else if self.options.unicode_property_escapes {
+                    self.expand_property_escapes(class)?;
+                }
+            }
+            _ => {}
+        }
+        Some(())
+    }
+
+    /// Replace `\p{...}` in a character class with the characters it matches.
+    fn expand_property_escapes(&mut self, class: &mut CharacterClass<'a>) -> Option<()> {
+        if !class
+            .body
+            .iter()
+            .any(|contents| matches!(contents, CharacterClassContents::UnicodePropertyEscape(_)))
+        {
+            return Some(());
+        }
+        let mut body = Vec::with_capacity_in(class.body.len(), self.allocator);
+        for contents in class.body.drain(..) {
+            match contents {
+                CharacterClassContents::UnicodePropertyEscape(escape) => {
+                    let set = Self::property_escape_set(&escape)?;
+                    body.extend(self.create_class_contents(&set));
+                }
+                contents => body.push(contents),
+            }
+        }
+        class.body = body;
+        self.changed = true;
+        Some(())
+    }
+
+    /// Get characters matched by a character class, ignoring negation of the class itself.
+    ///
+    /// Returns `None` if class contains strings.
+    fn character_class_set(&self, class: &CharacterClass<'a>) -> Option<CharacterSet> {
+        let mut sets = class.body.iter().map(|contents| self.class_contents_set(contents));
+        let first = match sets.next() {
+            Some(set) => set?,
+            None => CharacterSet::default(),
+        };
+        sets.try_fold(first, |acc, set| {
+            let set = set?;
+            Some(match class.kind {
+                CharacterClassContentsKind::Union => acc.union(&set),
+                CharacterClassContentsKind::Intersection => acc.intersection(&set),
+                CharacterClassContentsKind::Subtraction => acc.subtraction(&set),
+            })
+        })
+    }
+
+    fn class_contents_set(&self, contents: &CharacterClassContents<'a>) -> Option<CharacterSet> {
+        match contents {
+            CharacterClassContents::CharacterClassRange(range) => {
+                Some(CharacterSet::from_ranges([(range.min.value, range.max.value)]))
+            }
+            CharacterClassContents::CharacterClassEscape(escape) => {
+                Some(CharacterSet::from_class_escape(escape.kind))
+            }
+            CharacterClassContents::UnicodePropertyEscape(escape) => {
+                Self::property_escape_set(escape)
+            }
+            CharacterClassContents::Character(character) => {
+                Some(CharacterSet::from_code_point(character.value))
+            }
+            CharacterClassContents::NestedCharacterClass(class) => {
+                let set = self.character_class_set(class)?;
+                Some(if class.negative { set.complement() } else { set })
+            }
+            CharacterClassContents::ClassStringDisjunction(disjunction) => {
+                // `\q{a|b}` is a set of characters, but `\q{ab}` is not supported yet
+                if disjunction.strings {
+                    return None;
+                }
+                Some(CharacterSet::from_ranges(
+                    disjunction
+                        .body
+                        .iter()
+                        .filter_map(|string| string.body.first())
+                        .map(|character| (character.value, character.value)),
+                ))
+            }
+        }
+    }
+
+    /// Returns `true` if `class` matches the same characters with or without `u` flag:
+    /// it contains only characters, ranges and `\d`, `\s`, `\w` escapes, all in the BMP
+    /// and not surrogates.
+    fn is_bmp_class(class: &CharacterClass<'a>, set: &CharacterSet) -> bool {
+        !class.negative
+            && class.kind == CharacterClassContentsKind::Union
+            && class.body.iter().all(|contents| match contents {
+                CharacterClassContents::Character(_)
+                | CharacterClassContents::CharacterClassRange(_) => true,
+                CharacterClassContents::CharacterClassEscape(escape) => matches!(
+                    escape.kind,
+                    CharacterClassEscapeKind::D
+                        | CharacterClassEscapeKind::S
+                        | CharacterClassEscapeKind::W
+                ),
+                _ => false,
+            })
+            && set
+                .ranges()
+                .iter()
+                .all(|&(min, max)| max < 0xD800 || (min > 0xDFFF && max <= 0xFFFF))
+    }
+
+    /// Create a term which matches characters in `set` without `u` flag.
+    ///
+    /// Based on regexpu-core's handling of `u` flag:
+    /// `(?:[BMP]|[lead][trail]|[lone lead](?![\uDC00-\uDFFF])|(?:[^\uD800-\uDBFF]|^)[lone trail])`
+    ///
+    /// Like regexpu-core, a lone trail surrogate consumes the character before it.
+    fn create_unicode_term(&self, set: &CharacterSet) -> Term<'a> {
+        let allocator = self.allocator;
+        let bmp = set.intersection(&CharacterSet::from_ranges([(0, 0xD7FF), (0xE000, 0xFFFF)]));
+        let lead_surrogates = CharacterSet::from_ranges([(0xD800, 0xDBFF)]);
+        let trail_surrogates = CharacterSet::from_ranges([(0xDC00, 0xDFFF)]);
+        let lone_leads = set.intersection(&lead_surrogates);
+        let lone_trails = set.intersection(&trail_surrogates);
+
+        let mut alternatives: std::vec::Vec<std::vec::Vec<Term<'a>>> = vec![];
+        if !bmp.is_empty() {
+            alternatives.push(vec![self.create_code_unit_term(&bmp)]);
+        }
+        for (leads, trails) in set.surrogate_pairs() {
+            alternatives.push(vec![
+                self.create_code_unit_term(&leads),
+                self.create_code_unit_term(&trails),
+            ]);
+        }
+        if !lone_leads.is_empty() {
+            // `[\uD800-\uDBFF](?![\uDC00-\uDFFF])`
+            let lookahead = Term::LookAroundAssertion(Box::new_in(
+                LookAroundAssertion {
+                    span: SPAN,
+                    kind: LookAroundAssertionKind::NegativeLookahead,
+                    body: self
+                        .create_disjunction([vec![self.create_code_unit_term(&trail_surrogates)]]),
+                },
+                allocator,
+            ));
+            alternatives.push(vec![self.create_code_unit_term(&lone_leads), lookahead]);
+        }
+        if !lone_trails.is_empty() {
+            // `(?:[^\uD800-\uDBFF]|^)[\uDC00-\uDFFF]`
+            let not_lead = Term::CharacterClass(
+                self.create_character_class(true, self.create_class_contents(&lead_surrogates)),
+            );
+            let start = Term::BoundaryAssertion(Box::new_in(
+                BoundaryAssertion { span: SPAN, kind: BoundaryAssertionKind::Start },
+                allocator,
+            ));
+            let before = self.create_ignore_group([vec![not_lead], vec![start]]);
+            alternatives.push(vec![before, self.create_code_unit_term(&lone_trails)]);
+        }
+
+        if alternatives.len() == 1 && alternatives[0].len() == 1 {
+            return alternatives.pop().unwrap().pop().unwrap();
+        }
+        if alternatives.is_empty() {
+            // Matches nothing
+            return Term::CharacterClass(
+                self.create_character_class(false, Vec::new_in(allocator)),
+            );
+        }
+        self.create_ignore_group(alternatives)
+    }
+
+    /// Create a single character if `set` contains one code unit, otherwise a character class.
+    fn create_code_unit_term(&self, set: &CharacterSet) -> Term<'a> {
+        match set.ranges() {
+            &[(min, max)] if min == max => {
+                Term::Character(Box::new_in(Self::create_character(min), self.allocator))
+            }
+            _ => Term::CharacterClass(
+                self.create_character_class(false, self.create_class_contents(set)),
+            ),
+        }
+    }
+
+    /// `(?:a|b)`
+    fn create_ignore_group(
+        &self,
+        alternatives: impl IntoIterator<Item = std::vec::Vec<Term<'a>>>,
+    ) -> Term<'a> {
+        Term::IgnoreGroup(Box::new_in(
+            IgnoreGroup {
+                span: SPAN,
+                modifiers: None,
+                body: self.create_disjunction(alternatives),
+            },
+            self.allocator,
+        ))
+    }
+
+    fn create_disjunction(
+        &self,
+        alternatives: impl IntoIterator<Item = std::vec::Vec<Term<'a>>>,
+    ) -> Disjunction<'a> {
+        let allocator = self.allocator;
+        let body = Vec::from_iter_in(
+            alternatives
+                .into_iter()
+                .map(|terms| Alternative { span: SPAN, body: Vec::from_iter_in(terms, allocator) }),
+            allocator,
+        );
+        Disjunction { span: SPAN, body }
+    }
+
+    /// Get characters matched by `\p{...}` or `\P{...}`.
+    ///
+    /// Returns `None` for properties of strings, and properties for which there is no data.
+    fn property_escape_set(escape: &UnicodePropertyEscape<'a>) -> Option<CharacterSet> {
+        if escape.strings {
+            return None;
+        }
+        let set = CharacterSet::from_property(&escape.name, escape.value.as_deref())?;
+        Some(if escape.negative { set.complement() } else { set })
+    }
+
+    fn create_character_class(
+        &self,
+        negative: bool,
+        body: Vec<'a, CharacterClassContents<'a>>,
+    ) -> Box<'a, CharacterClass<'a>> {
+        Box::new_in(
+            CharacterClass {
+                span: SPAN,
+                negative,
+                strings: false,
+                kind: CharacterClassContentsKind::Union,
+                body,
+            },
+            self.allocator,
+        )
+    }
+
+    fn create_class_contents(&self, set: &CharacterSet) -> Vec<'a, CharacterClassContents<'a>> {
+        let allocator = self.allocator;
+        Vec::from_iter_in(
+            set.ranges().iter().map(|&(min, max)| {
+                if min == max {
+                    CharacterClassContents::Character(Box::new_in(
+                        Self::create_character(min),
+                        allocator,
+                    ))
+                } else {
+                    CharacterClassContents::CharacterClassRange(Box::new_in(
+                        CharacterClassRange {
+                            span: SPAN,
+                            min: Self::create_character(min),
+                            max: Self::create_character(max),
+                        },
+                        allocator,
+                    ))
+                }
+            }),
+            allocator,
+        )
+    }
+
+    fn create_character(value: u32) -> Character {
+        let is_alphanumeric = char::from_u32(value).is_some_and(|c| c.is_ascii_alphanumeric());
+        let kind =
+            if is_alphanumeric { CharacterKind::Symbol } else { CharacterKind::UnicodeEscape };
+        Character { span: SPAN, kind, value }
+    }
+}
@@ -7,7 +7,7 @@ use rustc_hash::FxHashSet;
 use oxc_allocator::{GetAddress, Vec as ArenaVec};
 use oxc_ast::ast::*;
 use oxc_diagnostics::OxcDiagnostic;
-use oxc_semantic::SymbolFlags;
+use oxc_semantic::{SymbolFlags, SymbolTable};
 use oxc_span::{Atom, GetSpan, Span, SPAN};
 use oxc_syntax::{
     operator::AssignmentOperator,
@@ -68,6 +68,7 @@ impl<'a, 'ctx> Traverse<'a> for TypeScriptAnnotations<'a, 'ctx> {
     fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
         let mut no_modules_remaining = true;
         let mut some_modules_deleted = false;
+        let mut removed_import_symbols = vec![];
 
         program.body.retain_mut(|stmt| {
             let need_retain = match stmt {
@@ -100,6 +101,11 @@ impl<'a, 'ctx> Traverse<'a> for TypeScriptAnnotations<'a, 'ctx> {
                 Statement::ExportDefaultDeclaration(decl) => !decl.is_typescript_syntax(),
                 Statement::ImportDeclaration(decl) => {
                     if decl.import_kind.is_type() {
+                        if let Some(specifiers) = &decl.specifiers {
+                            removed_import_symbols.extend(
+                                specifiers.iter().filter_map(|s| s.local().symbol_id.get()),
+                            );
+                        }
                         false
                     } else if self.only_remove_type_imports {
                         true
@@ -110,21 +116,16 @@ impl<'a, 'ctx> Traverse<'a> for TypeScriptAnnotations<'a, 'ctx> {
                             true
                         } else {
                             specifiers.retain(|specifier| {
-                                let id = match specifier {
-                                    ImportDeclarationSpecifier::ImportSpecifier(s) => {
-                                        if s.import_kind.is_type() {
-                                            return false;
-                                        }
-                                        &s.local
-                                    }
-                                    ImportDeclarationSpecifier::ImportDefaultSpecifier(s) => {
-                                        &s.local
-                                    }
-                                    ImportDeclarationSpecifier::ImportNamespaceSpecifier(s) => {
-                                        &s.local
-                                    }
-                                };
-                                self.has_value_reference(&id.name, ctx)
+                                let id = specifier.local();
+                                let retain = !matches!(
+                                    specifier,
+                                    ImportDeclarationSpecifier::ImportSpecifier(s)
+                                        if s.import_kind.is_type()
+                                ) && self.has_value_reference(&id.name, ctx);
+                                if !retain {
+                                    removed_import_symbols.extend(id.symbol_id.get());
+                                }
+                                retain
                             });
                             !specifiers.is_empty()
                         }
@@ -156,6 +157,19 @@ impl<'a, 'ctx> Traverse<'a> for TypeScriptAnnotations<'a, 'ctx> {
             );
             program.body.push(ctx.ast.statement_module_declaration(export_decl));
         }
+
+        // Remove bindings of removed imports
+        let root_scope_id = ctx.scopes().root_scope_id();
+        for symbol_id in removed_import_symbols {
+            // Binding is shared with a value redeclaration `import A from 'mod'; const A = 1;`
+            if (ctx.symbols().get_flags(symbol_id) - SymbolFlags::Import).is_value() {
+                continue;
+            }
+            let name = ctx.symbols().names[symbol_id].clone();
+            ctx.scopes_mut().remove_binding(root_scope_id, &name);
+        }
+
+        Self::delete_type_references(ctx);
     }
 
     fn enter_arrow_function_expression(
@@ -607,6 +621,33 @@ impl<'a, 'ctx> TypeScriptAnnotations<'a, 'ctx> {
 
         self.is_jsx_imports(name)
     }
+
+    /// Delete type-only references, as all types have been removed from the AST.
+    ///
+    /// `let x: Foo` -> `let x`
+    fn delete_type_references(ctx: &mut TraverseCtx<'a>) {
+        let SymbolTable { references, resolved_references, .. } = ctx.symbols_mut();
+        for reference_ids in resolved_references.iter_mut() {
+            reference_ids.retain(|&reference_id| !references[reference_id].flags().is_type_only());
+        }
+
+        let unresolved_type_references = ctx
+            .scopes()
+            .root_unresolved_references()
+            .iter()
+            .flat_map(|(name, reference_ids)| {
+                reference_ids
+                    .iter()
+                    .filter(|&&reference_id| {
+                        ctx.symbols().get_reference(reference_id).flags().is_type_only()
+                    })
+                    .map(move |&reference_id| (name.clone(), reference_id))
+            })
+            .collect::<Vec<_>>();
+        for (name, reference_id) in unresolved_type_references {
+            ctx.scopes_mut().delete_root_unresolved_reference(&name, reference_id);
+        }
+    }
 }
 
 struct Assignment<'a> {
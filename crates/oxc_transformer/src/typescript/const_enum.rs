@@ -0,0 +1,145 @@
+use std::{borrow::Cow, fmt, path::Path, sync::Arc};
+
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::*;
+use oxc_parser::Parser;
+use oxc_span::{Atom, SourceType};
+
+use super::r#enum::{ConstantValue, TypeScriptEnum};
+
+/// Values of the members of a const enum, keyed by member name.
+pub type ConstEnumMembers = FxHashMap<String, ConstantValue>;
+
+/// Const enums exported from a module, keyed by enum name.
+pub type ModuleConstEnums = FxHashMap<String, ConstEnumMembers>;
+
+type ConstEnumResolver = dyn Fn(&str, &Path) -> Option<ModuleConstEnums> + Send + Sync;
+
+/// Const enums exported from other modules.
+///
+/// Members of const enums imported from these modules are inlined, and the imports are
+/// removed if they are no longer used:
+///
+/// ```ts
+/// import { Direction } from "./enums";
+/// move(Direction.Up);
+/// ```
+///
+/// is transformed to:
+///
+/// ```js
+/// move(0);
+/// ```
+///
+/// Members accessed through a namespace import (`import * as E from "./enums"; E.Direction.Up`)
+/// are inlined too.
+#[derive(Clone, Default)]
+pub struct ConstEnums {
+    /// Keyed by import specifier.
+    modules: FxHashMap<String, ModuleConstEnums>,
+    resolver: Option<Arc<ConstEnumResolver>>,
+}
+
+impl fmt::Debug for ConstEnums {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConstEnums")
+            .field("modules", &self.modules)
+            .field("resolver", &self.resolver.as_ref().map(|_| "Fn"))
+            .finish()
+    }
+}
+
+impl ConstEnums {
+    /// Add the const enums exported from the module imported as `specifier`.
+    #[must_use]
+    pub fn with_module<S: Into<String>>(mut self, specifier: S, enums: ModuleConstEnums) -> Self {
+        self.modules.insert(specifier.into(), enums);
+        self
+    }
+
+    /// Resolve the const enums exported from imported modules which were not added with
+    /// [`ConstEnums::with_module`].
+    ///
+    /// `resolver` is called with the import specifier and the path of the file being transformed.
+    /// It would usually read the imported file and pass it to [`ConstEnums::collect_exports`].
+    #[must_use]
+    pub fn with_resolver<F>(mut self, resolver: F) -> Self
+    where
+        F: Fn(&str, &Path) -> Option<ModuleConstEnums> + Send + Sync + 'static,
+    {
+        self.resolver = Some(Arc::new(resolver));
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.modules.is_empty() && self.resolver.is_none()
+    }
+
+    /// Get the const enums exported from `specifier`, imported by the file at `importer`.
+    pub fn resolve(&self, specifier: &str, importer: &Path) -> Option<Cow<'_, ModuleConstEnums>> {
+        if let Some(enums) = self.modules.get(specifier) {
+            return Some(Cow::Borrowed(enums));
+        }
+        let resolver = self.resolver.as_ref()?;
+        resolver(specifier, importer).map(Cow::Owned)
+    }
+
+    /// Collect the exported const enums in the source text of a module.
+    ///
+    /// Both `export const enum E {}` and `const enum E {}; export { E }` are collected.
+    /// Members which cannot be evaluated at compile time are omitted.
+    pub fn collect_exports(source_text: &str, source_type: SourceType) -> ModuleConstEnums {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source_text, source_type).parse().program;
+
+        let mut evaluator = TypeScriptEnum::default();
+        let mut exports = ModuleConstEnums::default();
+        let mut local_enums = ModuleConstEnums::default();
+        // `export { E as F }`, as (local name, exported name)
+        let mut export_specifiers = vec![];
+        for stmt in &program.body {
+            match stmt {
+                // Exported enums may refer to members of enums which are not exported
+                Statement::TSEnumDeclaration(decl) => {
+                    let members = evaluator.evaluate_members(decl);
+                    if decl.r#const {
+                        local_enums.insert(decl.id.name.to_string(), Self::to_members(members));
+                    }
+                }
+                Statement::ExportNamedDeclaration(export_decl) => match &export_decl.declaration {
+                    Some(Declaration::TSEnumDeclaration(decl)) => {
+                        let members = evaluator.evaluate_members(decl);
+                        if decl.r#const {
+                            exports.insert(decl.id.name.to_string(), Self::to_members(members));
+                        }
+                    }
+                    None if export_decl.source.is_none() && !export_decl.export_kind.is_type() => {
+                        export_specifiers.extend(
+                            export_decl
+                                .specifiers
+                                .iter()
+                                .filter(|specifier| !specifier.export_kind.is_type())
+                                .map(|specifier| {
+                                    (specifier.local.name(), specifier.exported.name())
+                                }),
+                        );
+                    }
+                    _ => {}
+                },
+                _ => {}
+            }
+        }
+        for (local, exported) in export_specifiers {
+            if let Some(members) = local_enums.get(local.as_str()) {
+                exports.insert(exported.to_string(), members.clone());
+            }
+        }
+        exports
+    }
+
+    fn to_members(members: FxHashMap<Atom<'_>, ConstantValue>) -> ConstEnumMembers {
+        members.into_iter().map(|(name, value)| (name.to_string(), value)).collect()
+    }
+}
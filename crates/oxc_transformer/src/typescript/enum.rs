@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use rustc_hash::FxHashMap;
 
 use oxc_allocator::Vec;
@@ -8,21 +10,103 @@ use oxc_syntax::{
     number::{NumberBase, ToJsString},
     operator::{AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator},
     reference::ReferenceFlags,
-    symbol::SymbolFlags,
+    scope::ScopeFlags,
+    symbol::{SymbolFlags, SymbolId},
 };
 use oxc_traverse::{Traverse, TraverseCtx};
 
+use super::{
+    const_enum::{ConstEnumMembers, ConstEnums, ModuleConstEnums},
+    TypeScriptOptions,
+};
+use crate::TransformCtx;
+
+#[derive(Default)]
 pub struct TypeScriptEnum<'a> {
     enums: FxHashMap<Atom<'a>, FxHashMap<Atom<'a>, ConstantValue>>,
+
+    const_enums: ConstEnums,
+    source_path: PathBuf,
+    /// Members of const enums imported from other modules, keyed by the symbol of the import.
+    imported_const_enums: FxHashMap<SymbolId, ConstEnumMembers>,
+    /// Const enums of modules imported as namespaces (`import * as E`),
+    /// keyed by the symbol of the import.
+    imported_namespaces: FxHashMap<SymbolId, ModuleConstEnums>,
+    /// Flags of variables which enums are converted to.
+    /// Set on exit of program, as decorator metadata relies on enum symbols having enum flags.
+    enum_symbol_flags: std::vec::Vec<(SymbolId, SymbolFlags)>,
 }
 
 impl<'a> TypeScriptEnum<'a> {
-    pub fn new() -> Self {
-        Self { enums: FxHashMap::default() }
+    pub fn new(options: &TypeScriptOptions, ctx: &TransformCtx<'a>) -> Self {
+        Self {
+            const_enums: options.const_enums.clone(),
+            source_path: ctx.source_path.clone(),
+            ..Self::default()
+        }
     }
 }
 
 impl<'a> Traverse<'a> for TypeScriptEnum<'a> {
+    fn enter_program(&mut self, program: &mut Program<'a>, _ctx: &mut TraverseCtx<'a>) {
+        if self.const_enums.is_empty() {
+            return;
+        }
+        for stmt in &program.body {
+            let Statement::ImportDeclaration(decl) = stmt else { continue };
+            let Some(specifiers) = &decl.specifiers else { continue };
+            let Some(enums) = self.const_enums.resolve(&decl.source.value, &self.source_path)
+            else {
+                continue;
+            };
+            for specifier in specifiers {
+                match specifier {
+                    ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                        let Some(symbol_id) = specifier.local.symbol_id.get() else { continue };
+                        if let Some(members) = enums.get(specifier.imported.name().as_str()) {
+                            self.imported_const_enums.insert(symbol_id, members.clone());
+                        }
+                    }
+                    ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+                        let Some(symbol_id) = specifier.local.symbol_id.get() else { continue };
+                        self.imported_namespaces.insert(symbol_id, enums.clone().into_owned());
+                    }
+                    ImportDeclarationSpecifier::ImportDefaultSpecifier(_) => {}
+                }
+            }
+        }
+    }
+
+    fn exit_program(&mut self, _program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        for &(symbol_id, flags) in &self.enum_symbol_flags {
+            *ctx.symbols_mut().get_flags_mut(symbol_id) = flags;
+        }
+    }
+
+    /// Inline members of imported const enums.
+    ///
+    /// `Direction.Up` -> `0`
+    /// `E.Direction.Up` -> `0` (`import * as E`)
+    ///
+    /// References to the import are removed, so the import is dropped if it's no longer used.
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.imported_const_enums.is_empty() && self.imported_namespaces.is_empty() {
+            return;
+        }
+        let Some(member_expr) = expr.as_member_expression() else { return };
+        let Some((ident, value)) = self.resolve_imported_member(member_expr, ctx) else {
+            return;
+        };
+        let value = match value {
+            ConstantValue::Number(value) => Self::get_initializer_expr(*value, ctx),
+            ConstantValue::String(value) => {
+                ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(value))
+            }
+        };
+        ctx.delete_reference_for_identifier(ident);
+        *expr = value;
+    }
+
     fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
         let new_stmt = match stmt {
             Statement::TSEnumDeclaration(ts_enum_decl) => {
@@ -46,6 +130,32 @@ impl<'a> Traverse<'a> for TypeScriptEnum<'a> {
 }
 
 impl<'a> TypeScriptEnum<'a> {
+    /// Get value of imported const enum member `Direction.Up` or `E.Direction.Up`,
+    /// and the identifier referring to the import.
+    fn resolve_imported_member<'b>(
+        &'b self,
+        member_expr: &'b MemberExpression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<(&'b IdentifierReference<'a>, &'b ConstantValue)> {
+        let property = member_expr.static_property_name()?;
+        let symbol_id = |ident: &IdentifierReference<'a>| {
+            ctx.symbols().get_reference(ident.reference_id.get()?).symbol_id()
+        };
+        match member_expr.object() {
+            Expression::Identifier(ident) => {
+                let members = self.imported_const_enums.get(&symbol_id(ident)?)?;
+                Some((ident, members.get(property)?))
+            }
+            object => {
+                let object = object.as_member_expression()?;
+                let Expression::Identifier(ident) = object.object() else { return None };
+                let enums = self.imported_namespaces.get(&symbol_id(ident)?)?;
+                let members = enums.get(object.static_property_name()?)?;
+                Some((ident, members.get(property)?))
+            }
+        }
+    }
+
     /// ```TypeScript
     /// enum Foo {
     ///   X = 1,
@@ -76,6 +186,16 @@ impl<'a> TypeScriptEnum<'a> {
 
         let enum_name = decl.id.name.clone();
         let func_scope_id = decl.scope_id.get().unwrap();
+
+        // Enum scope becomes a function scope, and members are no longer bindings
+        let strict_mode = ctx.current_scope_flags() & ScopeFlags::StrictMode;
+        *ctx.scopes_mut().get_flags_mut(func_scope_id) = ScopeFlags::Function | strict_mode;
+        let member_names =
+            ctx.scopes().get_bindings(func_scope_id).keys().cloned().collect::<std::vec::Vec<_>>();
+        for name in &member_names {
+            ctx.scopes_mut().remove_binding(func_scope_id, name);
+        }
+
         let param_ident = ctx.generate_binding(
             enum_name.clone(),
             func_scope_id,
@@ -152,11 +272,14 @@ impl<'a> TypeScriptEnum<'a> {
             return Some(ast.statement_expression(decl.span, expr));
         }
 
-        let kind = if is_export || is_not_top_scope {
-            VariableDeclarationKind::Let
+        let (kind, symbol_flags) = if is_export || is_not_top_scope {
+            (VariableDeclarationKind::Let, SymbolFlags::BlockScopedVariable)
         } else {
-            VariableDeclarationKind::Var
+            (VariableDeclarationKind::Var, SymbolFlags::FunctionScopedVariable)
         };
+        let symbol_flags =
+            if is_export { symbol_flags | SymbolFlags::Export } else { symbol_flags };
+        self.enum_symbol_flags.push((var_symbol_id, symbol_flags));
         let decls = {
             let binding_identifier = decl.id.clone();
             let binding_pattern_kind =
@@ -204,20 +327,7 @@ impl<'a> TypeScriptEnum<'a> {
         let mut prev_member_name: Option<Atom<'a>> = None;
 
         for member in members.iter_mut() {
-            let member_name: &Atom<'_> = match &member.id {
-                TSEnumMemberName::StaticIdentifier(id) => &id.name,
-                TSEnumMemberName::StaticStringLiteral(str)
-                | TSEnumMemberName::StringLiteral(str) => &str.value,
-                TSEnumMemberName::StaticTemplateLiteral(template)
-                | TSEnumMemberName::TemplateLiteral(template) => {
-                    &template.quasi().expect("Template enum members cannot have substitutions.")
-                }
-                // parse error, but better than a panic
-                TSEnumMemberName::StaticNumericLiteral(n) => &Atom::from(n.raw),
-                match_expression!(TSEnumMemberName) => {
-                    unreachable!()
-                }
-            };
+            let member_name = &Self::member_name(&member.id);
 
             let init = if let Some(initializer) = &mut member.initializer {
                 let constant_value =
@@ -328,6 +438,52 @@ impl<'a> TypeScriptEnum<'a> {
         statements
     }
 
+    fn member_name(id: &TSEnumMemberName<'a>) -> Atom<'a> {
+        match id {
+            TSEnumMemberName::StaticIdentifier(id) => id.name.clone(),
+            TSEnumMemberName::StaticStringLiteral(str) | TSEnumMemberName::StringLiteral(str) => {
+                str.value.clone()
+            }
+            TSEnumMemberName::StaticTemplateLiteral(template)
+            | TSEnumMemberName::TemplateLiteral(template) => {
+                template.quasi().expect("Template enum members cannot have substitutions.")
+            }
+            // parse error, but better than a panic
+            TSEnumMemberName::StaticNumericLiteral(n) => Atom::from(n.raw),
+            match_expression!(TSEnumMemberName) => {
+                unreachable!()
+            }
+        }
+    }
+
+    /// Evaluate the members of an enum which have constant values, without transforming it.
+    ///
+    /// Values are remembered, so members of later enums can refer to them.
+    pub(super) fn evaluate_members(
+        &mut self,
+        decl: &TSEnumDeclaration<'a>,
+    ) -> FxHashMap<Atom<'a>, ConstantValue> {
+        let mut members = self.enums.get(&decl.id.name).cloned().unwrap_or_default();
+        let mut prev_value = Some(-1.0);
+        for member in &decl.members {
+            let member_name = Self::member_name(&member.id);
+            let value = if let Some(initializer) = &member.initializer {
+                self.computed_constant_value(initializer, &members)
+            } else {
+                prev_value.map(|value| ConstantValue::Number(value + 1.0))
+            };
+            prev_value = match &value {
+                Some(ConstantValue::Number(value)) => Some(*value),
+                _ => None,
+            };
+            if let Some(value) = value {
+                members.insert(member_name, value);
+            }
+        }
+        self.enums.insert(decl.id.name.clone(), members.clone());
+        members
+    }
+
     fn get_number_literal_expression(value: f64, ctx: &TraverseCtx<'a>) -> Expression<'a> {
         ctx.ast.expression_numeric_literal(SPAN, value, value.to_string(), NumberBase::Decimal)
     }
@@ -351,8 +507,9 @@ impl<'a> TypeScriptEnum<'a> {
     }
 }
 
-#[derive(Debug, Clone)]
-enum ConstantValue {
+/// Value of an enum member which can be evaluated at compile time.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstantValue {
     Number(f64),
     String(String),
 }
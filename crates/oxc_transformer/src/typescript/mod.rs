@@ -5,6 +5,7 @@ use oxc_traverse::{Traverse, TraverseCtx};
 use crate::TransformCtx;
 
 mod annotations;
+mod const_enum;
 mod diagnostics;
 mod r#enum;
 mod module;
@@ -18,7 +19,9 @@ use namespace::TypeScriptNamespace;
 use r#enum::TypeScriptEnum;
 use rewrite_extensions::TypeScriptRewriteExtensions;
 
+pub use const_enum::{ConstEnumMembers, ConstEnums, ModuleConstEnums};
 pub use options::{RewriteExtensionsMode, TypeScriptOptions};
+pub use r#enum::ConstantValue;
 
 /// [Preset TypeScript](https://babeljs.io/docs/babel-preset-typescript)
 ///
@@ -56,7 +59,7 @@ impl<'a, 'ctx> TypeScript<'a, 'ctx> {
         Self {
             ctx,
             annotations: TypeScriptAnnotations::new(options, ctx),
-            r#enum: TypeScriptEnum::new(),
+            r#enum: TypeScriptEnum::new(options, ctx),
             namespace: TypeScriptNamespace::new(options, ctx),
             module: TypeScriptModule::new(ctx),
             rewrite_extensions: TypeScriptRewriteExtensions::new(options),
@@ -74,11 +77,13 @@ impl<'a, 'ctx> Traverse<'a> for TypeScript<'a, 'ctx> {
         } else {
             program.source_type = program.source_type.with_javascript(true);
             self.namespace.enter_program(program, ctx);
+            self.r#enum.enter_program(program, ctx);
         }
     }
 
     fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
         self.annotations.exit_program(program, ctx);
+        self.r#enum.exit_program(program, ctx);
     }
 
     fn enter_arrow_function_expression(
@@ -123,6 +128,7 @@ impl<'a, 'ctx> Traverse<'a> for TypeScript<'a, 'ctx> {
 
     fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         self.annotations.enter_expression(expr, ctx);
+        self.r#enum.enter_expression(expr, ctx);
     }
 
     fn enter_simple_assignment_target(
@@ -5,6 +5,8 @@ use serde::{
     Deserialize, Deserializer,
 };
 
+use super::ConstEnums;
+
 fn default_for_jsx_pragma() -> Cow<'static, str> {
     Cow::Borrowed("React.createElement")
 }
@@ -47,6 +49,15 @@ pub struct TypeScriptOptions {
     /// Unused.
     pub optimize_const_enums: bool,
 
+    /// Enables legacy decorators, equivalent to TypeScript's
+    /// [`experimentalDecorators`](https://www.typescriptlang.org/tsconfig#experimentalDecorators) option.
+    pub experimental_decorators: bool,
+
+    /// Emit design-time type metadata for decorated declarations, equivalent to TypeScript's
+    /// [`emitDecoratorMetadata`](https://www.typescriptlang.org/tsconfig#emitDecoratorMetadata) option.
+    /// Only takes effect when `experimental_decorators` is enabled.
+    pub emit_decorator_metadata: bool,
+
     // Preset options
     /// Modifies extensions in import and export declarations.
     ///
@@ -56,6 +67,11 @@ pub struct TypeScriptOptions {
     /// When set to `true`, same as [`RewriteExtensionsMode::Rewrite`]. Defaults to `false` (do nothing).
     #[serde(deserialize_with = "deserialize_rewrite_import_extensions")]
     pub rewrite_import_extensions: Option<RewriteExtensionsMode>,
+
+    /// Const enums exported from other modules, so their members can be inlined
+    /// and the imports removed.
+    #[serde(skip)]
+    pub const_enums: ConstEnums,
 }
 
 impl Default for TypeScriptOptions {
@@ -67,7 +83,10 @@ impl Default for TypeScriptOptions {
             allow_namespaces: default_as_true(),
             allow_declare_fields: default_as_true(),
             optimize_const_enums: false,
+            experimental_decorators: false,
+            emit_decorator_metadata: false,
             rewrite_import_extensions: None,
+            const_enums: ConstEnums::default(),
         }
     }
 }
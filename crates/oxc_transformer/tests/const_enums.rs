@@ -0,0 +1,170 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{CodeGenerator, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{ConstEnums, ConstantValue, TransformOptions, Transformer};
+
+const ENUMS: &str = "
+enum Internal { A = 10 }
+export const enum Direction { Up, Down, Left = Internal.A, Right }
+export const enum Color { Red = 'RED', Blue = `BLUE` }
+export enum Runtime { X }
+export const enum Computed { A = 'a'.length, B = 1 << 3 }
+";
+
+fn transform(source_text: &str, const_enums: ConstEnums) -> String {
+    let source_type = SourceType::ts();
+    let allocator = Allocator::default();
+    let mut program = Parser::new(&allocator, source_text, source_type).parse().program;
+    let (symbols, scopes) =
+        SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+    let mut options = TransformOptions::default();
+    options.typescript.const_enums = const_enums;
+    let ret = Transformer::new(&allocator, Path::new("src/main.ts"), options)
+        .build_with_symbols_and_scopes(symbols, scopes, &mut program);
+    assert!(ret.errors.is_empty());
+    CodeGenerator::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(&program)
+        .code
+}
+
+fn codegen(source_text: &str) -> String {
+    let allocator = Allocator::default();
+    let program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+    CodeGenerator::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(&program)
+        .code
+}
+
+fn test(source_text: &str, expected: &str, const_enums: ConstEnums) {
+    assert_eq!(transform(source_text, const_enums), codegen(expected), "for source {source_text}");
+}
+
+#[test]
+fn collect_exports() {
+    let enums = ConstEnums::collect_exports(ENUMS, SourceType::ts());
+    assert_eq!(enums.len(), 3);
+    let direction = &enums["Direction"];
+    assert_eq!(direction["Up"], ConstantValue::Number(0.0));
+    assert_eq!(direction["Down"], ConstantValue::Number(1.0));
+    assert_eq!(direction["Left"], ConstantValue::Number(10.0));
+    assert_eq!(direction["Right"], ConstantValue::Number(11.0));
+    assert_eq!(enums["Color"]["Blue"], ConstantValue::String("BLUE".to_string()));
+    // `'a'.length` cannot be evaluated
+    assert!(!enums["Computed"].contains_key("A"));
+    assert_eq!(enums["Computed"]["B"], ConstantValue::Number(8.0));
+}
+
+#[test]
+fn collect_exports_export_specifiers() {
+    let enums = ConstEnums::collect_exports(
+        "
+        export { Local, Other as Renamed };
+        const enum Local { A = 1 }
+        const enum Other { B = 'b' }
+        const enum Type { C }
+        enum Runtime { D }
+        export type { Type };
+        export { Runtime };
+        ",
+        SourceType::ts(),
+    );
+    assert_eq!(enums.len(), 2);
+    assert_eq!(enums["Local"]["A"], ConstantValue::Number(1.0));
+    assert_eq!(enums["Renamed"]["B"], ConstantValue::String("b".to_string()));
+}
+
+#[test]
+fn with_module() {
+    let const_enums = ConstEnums::default()
+        .with_module("./enums", ConstEnums::collect_exports(ENUMS, SourceType::ts()));
+    test(
+        "
+        import { Direction, Color as C } from './enums';
+        move(Direction.Up, Direction['Right']);
+        paint(C.Red);
+        ",
+        "
+        move(0, 11);
+        paint('RED');
+        export {};
+        ",
+        const_enums.clone(),
+    );
+    // Imports still referenced are kept
+    test(
+        "
+        import { Direction, Runtime } from './enums';
+        import { Other } from './other';
+        move(Direction.Up, Runtime.X, Other.Up);
+        log(Direction);
+        ",
+        "
+        import { Direction, Runtime } from './enums';
+        import { Other } from './other';
+        move(0, Runtime.X, Other.Up);
+        log(Direction);
+        ",
+        const_enums.clone(),
+    );
+    // Namespace imports
+    test(
+        "
+        import * as E from './enums';
+        move(E.Direction.Up, E['Color'].Red, E.Direction['Left']);
+        ",
+        "
+        move(0, 'RED', 10);
+        export {};
+        ",
+        const_enums.clone(),
+    );
+    test(
+        "
+        import * as E from './enums';
+        move(E.Direction.Down, E.Runtime.X, E.Direction);
+        ",
+        "
+        import * as E from './enums';
+        move(1, E.Runtime.X, E.Direction);
+        ",
+        const_enums.clone(),
+    );
+    // Shadowed bindings are not inlined
+    test(
+        "
+        import { Direction } from './enums';
+        function f(Direction) { return Direction.Up; }
+        ",
+        "
+        function f(Direction) { return Direction.Up; }
+        export {};
+        ",
+        const_enums,
+    );
+}
+
+#[test]
+fn with_resolver() {
+    let const_enums = ConstEnums::default().with_resolver(|specifier, importer| {
+        assert!(importer.ends_with("src/main.ts"));
+        (specifier == "./enums").then(|| ConstEnums::collect_exports(ENUMS, SourceType::ts()))
+    });
+    test(
+        "
+        import { Direction } from './enums';
+        import { Direction as D } from './other';
+        move(Direction.Down, D.Down);
+        ",
+        "
+        import { Direction as D } from './other';
+        move(1, D.Down);
+        ",
+        const_enums,
+    );
+}
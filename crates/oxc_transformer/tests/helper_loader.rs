@@ -0,0 +1,72 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_codegen::CodeGenerator;
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{HelperLoaderMode, TransformOptions, Transformer};
+
+fn transform(source_text: &str) -> String {
+    let source_type = SourceType::mjs();
+    let allocator = Allocator::default();
+    let mut program = Parser::new(&allocator, source_text, source_type).parse().program;
+    let (symbols, scopes) =
+        SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+    let mut options = TransformOptions::enable_all();
+    options.helper_loader.mode = HelperLoaderMode::Inline;
+    let ret = Transformer::new(&allocator, Path::new("test.js"), options)
+        .build_with_symbols_and_scopes(symbols, scopes, &mut program);
+    assert!(ret.errors.is_empty());
+    CodeGenerator::new().build(&program).code
+}
+
+#[test]
+fn inline_once() {
+    let code = transform("const a = { ...x }; const b = { ...y };");
+    assert_eq!(code.matches("function _objectSpread(").count(), 1);
+    assert_eq!(code.matches("_objectSpread({}, ").count(), 2);
+}
+
+#[test]
+fn inline_dependencies() {
+    let code = transform("const a = { ...x };");
+    // Dependencies are inserted before helpers which use them
+    let order = [
+        "function _typeof(",
+        "function _toPrimitive(",
+        "function _toPropertyKey(",
+        "function _defineProperty(",
+        "function _objectSpread(",
+    ];
+    let positions =
+        order.map(|s| code.find(s).unwrap_or_else(|| panic!("{s} not found in:\n{code}")));
+    assert!(positions.windows(2).all(|w| w[0] < w[1]), "{code}");
+}
+
+#[test]
+fn inline_renamed() {
+    let code = transform("let _typeof = 1, _ownKeys = 2; const a = { ...x };");
+    assert!(code.contains("function _typeof2("), "{code}");
+    assert!(code.contains("\"object\" != _typeof2(t)"), "{code}");
+    assert!(code.contains("function _ownKeys2("), "{code}");
+    assert!(code.contains("_ownKeys2(Object(t), true)"), "{code}");
+    assert!(code.contains("var _typeof = 1, _ownKeys = 2;"), "{code}");
+}
+
+#[test]
+fn inline_wrap_reg_exp() {
+    let code = transform("const re = /(?<year>\\d{4})/;");
+    assert!(code.contains("function _inherits("), "{code}");
+    assert!(code.contains("function _wrapRegExp("), "{code}");
+    assert!(code.contains("_wrapRegExp(/(\\d{4})/, { year: 1 })"), "{code}");
+}
+
+#[test]
+fn inline_wrap_async_generator() {
+    let code = transform("async function* f() { yield await x; }");
+    assert_eq!(code.matches("function _OverloadYield(").count(), 1, "{code}");
+    assert!(code.contains("function _wrapAsyncGenerator("), "{code}");
+    assert!(code.contains("AsyncGenerator.prototype.next = function"), "{code}");
+    assert!(code.contains("return _awaitAsyncGenerator(x);"), "{code}");
+}
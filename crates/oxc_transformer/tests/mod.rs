@@ -1 +1,7 @@
+mod const_enums;
+mod helper_loader;
+mod modules;
 mod plugins;
+mod polyfills;
+mod preset_env;
+mod tsconfig_paths;
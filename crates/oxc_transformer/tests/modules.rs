@@ -0,0 +1,92 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_codegen::CodeGenerator;
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{
+    EnvOptions, HelperLoaderMode, ModuleFormat, ModulesOptions, TransformOptions, Transformer,
+};
+
+fn transform(source_text: &str, mode: HelperLoaderMode) -> String {
+    let source_type = SourceType::mjs();
+    let allocator = Allocator::default();
+    let mut program = Parser::new(&allocator, source_text, source_type).parse().program;
+    let (symbols, scopes) =
+        SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+    let mut options = TransformOptions::enable_all();
+    options.helper_loader.mode = mode;
+    options.modules = Some(ModulesOptions::new(ModuleFormat::CommonJS));
+    let ret = Transformer::new(&allocator, Path::new("test.js"), options)
+        .build_with_symbols_and_scopes(symbols, scopes, &mut program);
+    assert!(ret.errors.is_empty());
+    CodeGenerator::new().build(&program).code
+}
+
+#[test]
+fn runtime_helpers_required() {
+    let code = transform("import foo from 'foo'; const a = { ...foo };", HelperLoaderMode::Runtime);
+    assert!(!code.contains("import "), "{code}");
+    // Runtime helpers are required without interop
+    assert!(
+        code.contains("var _objectSpread = require(\"@babel/runtime/helpers/objectSpread2\");"),
+        "{code}"
+    );
+    assert!(
+        code.contains(
+            "var _interopRequireDefault = require(\"@babel/runtime/helpers/interopRequireDefault\");"
+        ),
+        "{code}"
+    );
+    assert!(code.contains("var _foo = _interopRequireDefault(require(\"foo\"));"), "{code}");
+    assert!(code.contains("_objectSpread({}, _foo.default)"), "{code}");
+}
+
+#[test]
+fn inline_helpers() {
+    let code = transform("import foo from 'foo'; export default foo;", HelperLoaderMode::Inline);
+    assert!(code.contains("function _interopRequireDefault("), "{code}");
+    assert!(code.contains("var _foo = _interopRequireDefault(require(\"foo\"));"), "{code}");
+    assert!(code.contains("var _default = exports.default = _foo.default;"), "{code}");
+}
+
+#[test]
+fn preset_env_modules() {
+    let env = |modules: serde_json::Value| {
+        let env_options = serde_json::from_value::<EnvOptions>(serde_json::json!({
+            "modules": modules
+        }))
+        .unwrap();
+        TransformOptions::from_preset_env(&env_options, Path::new(""))
+            .map(|options| options.modules.map(|modules| modules.format))
+    };
+    assert_eq!(env(serde_json::json!("commonjs")).unwrap(), Some(ModuleFormat::CommonJS));
+    assert_eq!(env(serde_json::json!("cjs")).unwrap(), Some(ModuleFormat::CommonJS));
+    assert_eq!(env(serde_json::json!("umd")).unwrap(), Some(ModuleFormat::UMD));
+    assert_eq!(env(serde_json::json!(false)).unwrap(), None);
+    assert_eq!(env(serde_json::json!("auto")).unwrap(), None);
+    assert!(env(serde_json::json!("esm")).is_err());
+    assert!(env(serde_json::json!("systemjs")).is_err());
+}
+
+#[test]
+fn lazy_is_not_supported() {
+    let errors = |lazy: serde_json::Value| {
+        let allocator = Allocator::default();
+        let mut program =
+            Parser::new(&allocator, "import foo from 'foo';", SourceType::mjs()).parse().program;
+        let (symbols, scopes) =
+            SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+        let mut modules = ModulesOptions::new(ModuleFormat::CommonJS);
+        modules.lazy = Some(lazy);
+        let options = TransformOptions { modules: Some(modules), ..TransformOptions::default() };
+        Transformer::new(&allocator, Path::new("test.js"), options)
+            .build_with_symbols_and_scopes(symbols, scopes, &mut program)
+            .errors
+            .len()
+    };
+    assert_eq!(errors(serde_json::json!(true)), 1);
+    assert_eq!(errors(serde_json::json!(["foo"])), 1);
+    assert_eq!(errors(serde_json::json!(false)), 0);
+}
@@ -84,3 +84,59 @@ fn dot_with_postfix_mixed() {
     test("import.meta.somethingelse", "metaProperty", config.clone());
     test("import.meta", "1", config);
 }
+
+#[test]
+fn typeof_define() {
+    let config = ReplaceGlobalDefinesConfig::new(&[
+        ("typeof window", "'undefined'"),
+        ("typeof process.env", "'object'"),
+    ])
+    .unwrap();
+    test("typeof window === 'undefined'", "'undefined' === 'undefined'", config.clone());
+    test("typeof process.env", "'object'", config.clone());
+    test("typeof process", "typeof process", config.clone());
+    test("window; typeof document", "window; typeof document", config.clone());
+    test_same("(function (window) { let x = typeof window })()", config);
+
+    assert!(ReplaceGlobalDefinesConfig::new(&[("typeof import.meta", "'object'")]).is_err());
+    assert!(ReplaceGlobalDefinesConfig::new(&[("typeof 1", "'number'")]).is_err());
+}
+
+#[test]
+fn optional_chain() {
+    let config =
+        ReplaceGlobalDefinesConfig::new(&[("process?.env?.NODE_ENV", "'production'")]).unwrap();
+    test("process.env.NODE_ENV", "'production'", config.clone());
+    test("process?.env?.NODE_ENV", "'production'", config.clone());
+    test("process?.env.NODE_ENV", "'production'", config.clone());
+    test("process?.env", "process?.env", config);
+
+    let config =
+        ReplaceGlobalDefinesConfig::new(&[("process.env.NODE_ENV", "'production'")]).unwrap();
+    test("process?.env?.NODE_ENV", "'production'", config);
+}
+
+#[test]
+fn hoisted_object_value() {
+    let config = ReplaceGlobalDefinesConfig::new(&[
+        ("process.env", "{ \"NODE_ENV\": \"production\", \"DEBUG\": false }"),
+        ("import.meta.env", "{ \"MODE\": \"production\" }"),
+        ("FEATURES", "[\"a\", \"b\"]"),
+    ])
+    .unwrap();
+    test(
+        "import a from 'a'; console.log(process.env, process.env.NODE_ENV); f(process.env)",
+        "const _process_env = { 'NODE_ENV': 'production', 'DEBUG': false };
+        import a from 'a';
+        console.log(_process_env, _process_env.NODE_ENV);
+        f(_process_env);",
+        config.clone(),
+    );
+    test(
+        "let _FEATURES; import.meta.env.MODE, FEATURES, FEATURES[0]",
+        "const _import_meta_env = { 'MODE': 'production' }, _FEATURES2 = ['a', 'b'];
+        let _FEATURES;
+        _import_meta_env.MODE, _FEATURES2, _FEATURES2[0];",
+        config,
+    );
+}
@@ -0,0 +1,43 @@
+use std::path::Path;
+
+use serde_json::json;
+
+use oxc_transformer::{EnvOptions, TransformOptions, UseBuiltIns};
+
+fn core_js(options: serde_json::Value) -> Result<Option<(UseBuiltIns, String)>, String> {
+    let env_options = serde_json::from_value::<EnvOptions>(options).unwrap();
+    TransformOptions::from_preset_env(&env_options, Path::new(""))
+        .map(|options| {
+            options.core_js.map(|core_js| {
+                let version = core_js.version;
+                (core_js.use_built_ins, format!("{}.{}", version.major, version.minor))
+            })
+        })
+        .map_err(|errors| errors.iter().map(ToString::to_string).collect::<Vec<_>>().join("\n"))
+}
+
+#[test]
+fn preset_env_core_js() {
+    assert_eq!(core_js(json!({})).unwrap(), None);
+    assert_eq!(core_js(json!({ "useBuiltIns": false, "corejs": 3 })).unwrap(), None);
+    assert_eq!(
+        core_js(json!({ "useBuiltIns": "usage" })).unwrap(),
+        Some((UseBuiltIns::Usage, "3.0".to_string()))
+    );
+    assert_eq!(
+        core_js(json!({ "useBuiltIns": "entry", "corejs": "3.33" })).unwrap(),
+        Some((UseBuiltIns::Entry, "3.33".to_string()))
+    );
+    assert_eq!(
+        core_js(json!({ "useBuiltIns": "usage", "corejs": { "version": 3.8, "proposals": true } }))
+            .unwrap(),
+        Some((UseBuiltIns::Usage, "3.8".to_string()))
+    );
+    assert!(core_js(json!({ "useBuiltIns": "always" })).is_err());
+    assert!(core_js(json!({ "useBuiltIns": "usage", "corejs": 2 }))
+        .unwrap_err()
+        .contains("Only core-js 3 is supported"));
+    assert!(core_js(json!({ "useBuiltIns": "usage", "corejs": "3.x" }))
+        .unwrap_err()
+        .contains("is not a valid core-js version"));
+}
@@ -0,0 +1,217 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_ast::{ast::*, visit::walk, Visit};
+use oxc_codegen::CodeGenerator;
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::{GetSpan, SourceType};
+use oxc_syntax::scope::ScopeFlags;
+use oxc_transformer::{EnvOptions, TransformOptions, Transformer};
+
+fn transform_with_env(source_text: &str, env: serde_json::Value) -> String {
+    let source_type = SourceType::mjs();
+    let allocator = Allocator::default();
+    let mut program = Parser::new(&allocator, source_text, source_type).parse().program;
+    let (symbols, scopes) =
+        SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+    let env_options = serde_json::from_value::<EnvOptions>(env).unwrap();
+    let options = TransformOptions::from_preset_env(&env_options, Path::new("")).unwrap();
+    let ret = Transformer::new(&allocator, Path::new("test.js"), options)
+        .build_with_symbols_and_scopes(symbols, scopes, &mut program);
+    assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+    CodeGenerator::new().build(&program).code
+}
+
+/// Find syntax which is not valid in ES5.
+#[derive(Default)]
+struct Es2015SyntaxFinder {
+    found: Vec<String>,
+}
+
+impl Es2015SyntaxFinder {
+    fn find(code: &str) -> Vec<String> {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, code, SourceType::cjs()).parse();
+        assert!(ret.errors.is_empty(), "{:?}\n{code}", ret.errors);
+        let mut finder = Self::default();
+        finder.visit_program(&ret.program);
+        finder.found
+    }
+
+    fn report(&mut self, syntax: &str, node: &impl GetSpan) {
+        let span = node.span();
+        self.found.push(format!("{syntax} at {}..{}", span.start, span.end));
+    }
+}
+
+impl<'a> Visit<'a> for Es2015SyntaxFinder {
+    fn visit_variable_declaration(&mut self, decl: &VariableDeclaration<'a>) {
+        if decl.kind != VariableDeclarationKind::Var {
+            self.report(decl.kind.as_str(), decl);
+        }
+        walk::walk_variable_declaration(self, decl);
+    }
+
+    fn visit_binding_pattern(&mut self, pattern: &BindingPattern<'a>) {
+        if !pattern.kind.is_binding_identifier() {
+            self.report("destructuring pattern", pattern);
+        }
+        walk::walk_binding_pattern(self, pattern);
+    }
+
+    fn visit_assignment_target(&mut self, target: &AssignmentTarget<'a>) {
+        if matches!(
+            target,
+            AssignmentTarget::ArrayAssignmentTarget(_)
+                | AssignmentTarget::ObjectAssignmentTarget(_)
+        ) {
+            self.report("destructuring assignment", target);
+        }
+        walk::walk_assignment_target(self, target);
+    }
+
+    fn visit_function(&mut self, func: &Function<'a>, flags: ScopeFlags) {
+        if func.generator {
+            self.report("generator function", func);
+        }
+        if func.r#async {
+            self.report("async function", func);
+        }
+        if func.params.rest.is_some() {
+            self.report("rest parameter", func);
+        }
+        walk::walk_function(self, func, flags);
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &ArrowFunctionExpression<'a>) {
+        self.report("arrow function", arrow);
+        walk::walk_arrow_function_expression(self, arrow);
+    }
+
+    fn visit_class(&mut self, class: &Class<'a>) {
+        self.report("class", class);
+        walk::walk_class(self, class);
+    }
+
+    fn visit_object_property(&mut self, prop: &ObjectProperty<'a>) {
+        if prop.shorthand || prop.method || prop.computed {
+            self.report("shorthand, method or computed property", prop);
+        }
+        walk::walk_object_property(self, prop);
+    }
+
+    fn visit_spread_element(&mut self, spread: &SpreadElement<'a>) {
+        self.report("spread", spread);
+        walk::walk_spread_element(self, spread);
+    }
+
+    fn visit_template_literal(&mut self, lit: &TemplateLiteral<'a>) {
+        self.report("template literal", lit);
+        walk::walk_template_literal(self, lit);
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &ForOfStatement<'a>) {
+        self.report("for-of", stmt);
+        walk::walk_for_of_statement(self, stmt);
+    }
+
+    fn visit_super(&mut self, sup: &Super) {
+        self.report("super", sup);
+    }
+
+    fn visit_yield_expression(&mut self, expr: &YieldExpression<'a>) {
+        self.report("yield", expr);
+        walk::walk_yield_expression(self, expr);
+    }
+
+    fn visit_await_expression(&mut self, expr: &AwaitExpression<'a>) {
+        self.report("await", expr);
+        walk::walk_await_expression(self, expr);
+    }
+
+    fn visit_meta_property(&mut self, meta: &MetaProperty<'a>) {
+        self.report("meta property", meta);
+    }
+
+    fn visit_module_declaration(&mut self, decl: &ModuleDeclaration<'a>) {
+        self.report("module declaration", decl);
+        walk::walk_module_declaration(self, decl);
+    }
+}
+
+#[test]
+fn preset_env_es5_class_and_async() {
+    let source = r#"
+        import { Base } from "./base";
+
+        export default class Foo extends Base {
+            #count = 0;
+            static instances = [];
+            x = 1;
+
+            constructor(...args) {
+                super(...args);
+                Foo.instances.push(this);
+            }
+
+            get count() {
+                return this.#count;
+            }
+
+            async load(url, { retries = 3 } = {}) {
+                for (const attempt of range(retries)) {
+                    try {
+                        const { data, ...rest } = await fetch(`${url}?attempt=${attempt}`);
+                        this.#count++;
+                        return [data, { ...rest }];
+                    } catch {
+                        await sleep(attempt * 100);
+                    }
+                }
+                return super.load?.(url);
+            }
+
+            async *stream(items) {
+                for (let i = 0; i < items.length; i++) {
+                    yield await items[i];
+                }
+            }
+        }
+
+        export const run = async () => {
+            const foo = new Foo(1, 2);
+            const handlers = [];
+            for (let i = 0; i < 3; i++) {
+                handlers.push(() => foo.load(i));
+            }
+            return Promise.all(handlers.map(async (h) => await h()));
+        };
+    "#;
+
+    let code = transform_with_env(
+        source,
+        serde_json::json!({ "targets": "ie 11", "modules": "commonjs" }),
+    );
+    let found = Es2015SyntaxFinder::find(&code);
+    assert!(found.is_empty(), "ES2015+ syntax found: {found:#?}\n{code}");
+    assert!(code.contains("regeneratorRuntime"), "{code}");
+}
+
+#[test]
+fn preset_env_browserslist_config_from_cwd() {
+    let cwd =
+        std::env::temp_dir().join(format!("oxc_transformer_preset_env_{}", std::process::id()));
+    std::fs::create_dir_all(&cwd).unwrap();
+    std::fs::write(cwd.join(".browserslistrc"), "ie 11").unwrap();
+
+    let options = TransformOptions::from_preset_env(&EnvOptions::default(), &cwd).unwrap();
+    assert_eq!(options.cwd, cwd);
+    assert!(options.es2015.arrow_function.is_some());
+
+    std::fs::write(cwd.join(".browserslistrc"), "chrome 120").unwrap();
+    let options = TransformOptions::from_preset_env(&EnvOptions::default(), &cwd).unwrap();
+    assert!(options.es2015.arrow_function.is_none());
+
+    std::fs::remove_dir_all(cwd).unwrap();
+}
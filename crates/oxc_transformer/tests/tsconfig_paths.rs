@@ -0,0 +1,187 @@
+use std::path::{Path, PathBuf};
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{CodeGenerator, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{TransformOptions, Transformer, TsconfigPathsOptions};
+
+fn fixture_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/tsconfig_paths")
+}
+
+fn tsconfig() -> TsconfigPathsOptions {
+    let tsconfig_path = fixture_dir().join("tsconfig.json");
+    let source_text = std::fs::read_to_string(&tsconfig_path).unwrap();
+    TsconfigPathsOptions::from_tsconfig(&source_text, &tsconfig_path).unwrap()
+}
+
+fn transform(
+    source_text: &str,
+    source_path: &Path,
+    tsconfig_paths: TsconfigPathsOptions,
+) -> String {
+    let source_type = SourceType::from_path(source_path).unwrap();
+    let allocator = Allocator::default();
+    let mut program = Parser::new(&allocator, source_text, source_type).parse().program;
+    let (symbols, scopes) =
+        SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+    let options =
+        TransformOptions { tsconfig_paths: Some(tsconfig_paths), ..TransformOptions::default() };
+    let ret = Transformer::new(&allocator, source_path, options).build_with_symbols_and_scopes(
+        symbols,
+        scopes,
+        &mut program,
+    );
+    assert!(ret.errors.is_empty());
+    CodeGenerator::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(&program)
+        .code
+}
+
+fn codegen(source_text: &str) -> String {
+    let allocator = Allocator::default();
+    let program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+    CodeGenerator::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(&program)
+        .code
+}
+
+fn test(source_text: &str, expected: &str) {
+    let source_path = fixture_dir().join("src/components/button.js");
+    assert_eq!(
+        transform(source_text, &source_path, tsconfig()),
+        codegen(expected),
+        "for source {source_text}"
+    );
+}
+
+#[test]
+fn from_tsconfig() {
+    let options = tsconfig();
+    assert_eq!(options.base_url, Some(PathBuf::from(".")));
+    assert_eq!(options.config_dir, Some(fixture_dir()));
+    assert_eq!(
+        options.paths.keys().collect::<Vec<_>>(),
+        ["@/*", "@utils/*", "shared", "@fallback/*"]
+    );
+    assert!(TsconfigPathsOptions::from_tsconfig("{", Path::new("tsconfig.json")).is_err());
+}
+
+#[test]
+fn paths() {
+    test(
+        "
+        import { log } from '@/utils/log';
+        import { color } from '@utils/../theme';
+        export { shared } from 'shared';
+        export * from '@/theme';
+        const lazy = import('@/lazy');
+        log(color);
+        ",
+        "
+        import { log } from '../utils/log';
+        import { color } from '../theme';
+        export { shared } from '../../lib/shared';
+        export * from '../theme';
+        const lazy = import('../lazy');
+        log(color);
+        ",
+    );
+    // The longest prefix wins
+    test("import '@utils/log';", "import '../utils/log';");
+    // Relative imports, packages, and unmatched aliases are kept
+    test(
+        "import './local'; import 'react'; import '@scope/pkg'; import '../utils/log';",
+        "import './local'; import 'react'; import '@scope/pkg'; import '../utils/log';",
+    );
+}
+
+#[test]
+fn fallback_targets() {
+    // `missing/shared` does not exist, so `lib/shared` is used
+    test("import '@fallback/shared';", "import '../../lib/shared';");
+    // Neither exists, so the specifier is kept
+    test("import '@fallback/other';", "import '@fallback/other';");
+}
+
+#[test]
+fn catch_all_pattern() {
+    let options = TsconfigPathsOptions {
+        base_url: Some(PathBuf::from(".")),
+        paths: [("*".to_string(), vec!["src/*".to_string()])].into_iter().collect(),
+        config_dir: Some(fixture_dir()),
+    };
+    let source_path = fixture_dir().join("src/components/button.js");
+    assert_eq!(
+        transform(
+            "import 'utils/log'; import 'theme'; import 'react'; import 'react-dom/client';",
+            &source_path,
+            options.clone(),
+        ),
+        codegen(
+            "import '../utils/log'; import '../theme'; import 'react'; import 'react-dom/client';"
+        ),
+    );
+    // `src/lib/shared` does not exist, so the specifier is resolved against `baseUrl`
+    assert_eq!(
+        transform("import 'lib/shared';", &source_path, options),
+        codegen("import '../../lib/shared';"),
+    );
+}
+
+#[test]
+fn base_url() {
+    // Files which exist in `baseUrl` are rewritten, others are assumed to be packages
+    test(
+        "import 'src/theme'; import 'lib/shared'; import 'lodash';",
+        "import '../theme'; import '../../lib/shared'; import 'lodash';",
+    );
+}
+
+#[test]
+fn require() {
+    let source_path = fixture_dir().join("src/index.cjs");
+    assert_eq!(
+        transform(
+            "
+            const log = require('@/utils/log');
+            function f(require) { return require('@/utils/log'); }
+            ",
+            &source_path,
+            tsconfig(),
+        ),
+        codegen(
+            "
+            const log = require('./utils/log');
+            function f(require) { return require('@/utils/log'); }
+            "
+        ),
+    );
+}
+
+#[test]
+fn options() {
+    // `paths` are relative to `configDir` without `baseUrl`, which is relative to `cwd`
+    let options = TsconfigPathsOptions {
+        base_url: None,
+        paths: [("~/*".to_string(), vec!["./utils/*".to_string()])].into_iter().collect(),
+        config_dir: Some(PathBuf::from("src")),
+    };
+    let allocator = Allocator::default();
+    let source_type = SourceType::mjs();
+    let mut program = Parser::new(&allocator, "import '~/log';", source_type).parse().program;
+    let (symbols, scopes) =
+        SemanticBuilder::new().build(&program).semantic.into_symbol_table_and_scope_tree();
+    let options = TransformOptions {
+        cwd: fixture_dir(),
+        tsconfig_paths: Some(options),
+        ..TransformOptions::default()
+    };
+    Transformer::new(&allocator, Path::new("src/components/index.js"), options)
+        .build_with_symbols_and_scopes(symbols, scopes, &mut program);
+    assert_eq!(CodeGenerator::new().build(&program).code, "import \"../utils/log\";\n");
+}
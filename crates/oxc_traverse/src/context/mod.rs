@@ -266,6 +266,24 @@ impl<'a> TraverseCtx<'a> {
         self.scoping.insert_scope_below_statement(stmt, flags)
     }
 
+    /// Insert a scope into scope tree below a list of statements.
+    ///
+    /// Statements must be in current scope.
+    /// New scope is created as child of current scope.
+    /// All child scopes of the statements are reassigned to be children of the new scope.
+    ///
+    /// `flags` provided are amended to inherit from parent scope's flags.
+    ///
+    /// This is a shortcut for `ctx.scoping.insert_scope_below_statements`.
+    #[inline]
+    pub fn insert_scope_below_statements(
+        &mut self,
+        stmts: &[Statement],
+        flags: ScopeFlags,
+    ) -> ScopeId {
+        self.scoping.insert_scope_below_statements(stmts, flags)
+    }
+
     /// Insert a scope into scope tree below an expression.
     ///
     /// Expression must be in current scope.
@@ -14,7 +14,7 @@ use oxc::{
     ast::{ast::Program, Comment as OxcComment, CommentKind, Visit},
     codegen::{CodeGenerator, CodegenOptions},
     diagnostics::Error,
-    minifier::{CompressOptions, Minifier, MinifierOptions},
+    minifier::{CompressOptions, MangleOptions, Minifier, MinifierOptions},
     parser::{ParseOptions, Parser, ParserReturn},
     semantic::{
         dot::{DebugDot, DebugDotContext},
@@ -236,10 +236,13 @@ impl Oxc {
         }
 
         if run_options.transform.unwrap_or_default() {
-            if let Ok(options) = TransformOptions::from_preset_env(&EnvOptions {
-                targets: Targets::from_query("chrome 51"),
-                ..EnvOptions::default()
-            }) {
+            if let Ok(options) = TransformOptions::from_preset_env(
+                &EnvOptions {
+                    targets: Some(Targets::from_query("chrome 51")),
+                    ..EnvOptions::default()
+                },
+                Path::new(""),
+            ) {
                 let result = Transformer::new(&allocator, &path, options)
                     .build_with_symbols_and_scopes(symbols, scopes, &mut program);
                 if !result.errors.is_empty() {
@@ -255,7 +258,7 @@ impl Oxc {
         {
             let compress_options = minifier_options.compress_options.unwrap_or_default();
             let options = MinifierOptions {
-                mangle: minifier_options.mangle.unwrap_or_default(),
+                mangle: minifier_options.mangle.unwrap_or_default().then(MangleOptions::default),
                 compress: if minifier_options.compress.unwrap_or_default() {
                     CompressOptions {
                         booleans: compress_options.booleans,
@@ -265,6 +268,10 @@ impl Oxc {
                         join_vars: compress_options.join_vars,
                         loops: compress_options.loops,
                         typeofs: compress_options.typeofs,
+                        unused: compress_options.unused,
+                        toplevel: compress_options.toplevel,
+                        keep_fnames: compress_options.keep_fnames,
+                        keep_classnames: compress_options.keep_classnames,
                         ..CompressOptions::default()
                     }
                 } else {
@@ -116,6 +116,10 @@ pub struct OxcCompressOptions {
     pub join_vars: bool,
     pub loops: bool,
     pub typeofs: bool,
+    pub unused: bool,
+    pub toplevel: bool,
+    pub keep_fnames: bool,
+    pub keep_classnames: bool,
 }
 
 // keep same with `oxc_minifier::options::CompressOptions`
@@ -129,6 +133,10 @@ impl Default for OxcCompressOptions {
             join_vars: true,
             loops: true,
             typeofs: true,
+            unused: true,
+            toplevel: false,
+            keep_fnames: false,
+            keep_classnames: false,
         }
     }
 }
@@ -12,9 +12,111 @@ export interface ArrowFunctionsOptions {
   spec?: boolean
 }
 
+export interface BlockScopingOptions {
+  /**
+   * Throw an error if a closure would be required to transform a loop.
+   *
+   * @default false
+   */
+  throwIfClosureRequired?: boolean
+  /**
+   * Not supported yet.
+   *
+   * @default false
+   */
+  tdz?: boolean
+}
+
+export interface ClassesOptions {
+  /**
+   * Not supported yet.
+   *
+   * @default false
+   */
+  loose?: boolean
+}
+
+export interface DestructuringOptions {
+  /**
+   * Assume all destructured iterables are arrays, and objects have no symbol properties.
+   *
+   * @default false
+   */
+  loose?: boolean
+  /**
+   * Use `Object.assign` instead of `extends` helper.
+   *
+   * @default false
+   */
+  useBuiltIns?: boolean
+}
+
+/**
+ * Configure the Emotion plugin.
+ *
+ * @see {@link https://emotion.sh/docs/@emotion/babel-plugin}
+ */
+export interface EmotionOptions {
+  /**
+   * When to add a label to styles.
+   *
+   * `dev-only` adds labels when {@link JsxOptions#development} is enabled.
+   *
+   * @default 'dev-only'
+   */
+  autoLabel?: 'never' | 'dev-only' | 'always'
+  /**
+   * Format of labels. Supports `[local]`, `[filename]` and `[dirname]`.
+   *
+   * @default '[local]'
+   */
+  labelFormat?: string
+}
+
 export interface Es2015Options {
   /** Transform arrow functions into function expressions. */
   arrowFunction?: ArrowFunctionsOptions
+  /** Transform classes into constructor functions. */
+  classes?: ClassesOptions
+  /** Transform shorthand properties and methods in object literals. */
+  shorthandProperties?: boolean
+  /** Transform computed keys in object literals. */
+  computedProperties?: boolean
+  /** Transform template literals and tagged templates. */
+  templateLiterals?: TemplateLiteralsOptions
+  /** Transform spread in array literals, calls and `new` expressions. */
+  spread?: SpreadOptions
+  /** Transform default, destructured and rest parameters. */
+  parameters?: ParametersOptions
+  /** Transform destructuring. */
+  destructuring?: DestructuringOptions
+  /** Transform `let` and `const` declarations to `var`. */
+  blockScoping?: BlockScopingOptions
+  /** Transform `for...of` loops. */
+  forOf?: ForOfOptions
+  /** Transform generator functions. */
+  regenerator?: RegeneratorOptions
+}
+
+export interface ForOfOptions {
+  /**
+   * Use a simpler iterator which does not close the iterator if the loop exits early.
+   *
+   * @default false
+   */
+  loose?: boolean
+  /**
+   * Assume that all iterables are arrays.
+   *
+   * @default false
+   */
+  assumeArray?: boolean
+  /**
+   * Allow iterating over array-like objects which are not iterable.
+   *
+   * @default false
+   */
+  allowArrayLike?: boolean
 }
 
 /** TypeScript Isolated Declarations for Standalone DTS Emit */
@@ -50,10 +152,11 @@ export interface JsxOptions {
    *
    * - 'automatic' - auto-import the correct JSX factories
    * - 'classic' - no auto-import
+   * - 'dom' - compile to DOM expressions, as used by Solid
    *
    * @default 'automatic'
    */
-  runtime?: 'classic' | 'automatic'
+  runtime?: 'classic' | 'automatic' | 'dom'
   /**
    * Emit development-specific information, such as `__source` and `__self`.
    *
@@ -134,6 +237,15 @@ export interface JsxOptions {
   refresh?: boolean | ReactRefreshOptions
 }
 
+export interface ParametersOptions {
+  /**
+   * Transform default parameters without preserving function's `length`.
+   *
+   * @default false
+   */
+  loose?: boolean
+}
+
 export interface ReactRefreshOptions {
   /**
    * Specify the identifier of the refresh registration variable.
@@ -150,6 +262,27 @@ export interface ReactRefreshOptions {
   emitFullSignatures?: boolean
 }
 
+export interface RegeneratorOptions {
+  /**
+   * Not supported yet.
+   *
+   * @default true
+   */
+  asyncGenerators?: boolean
+  /**
+   * Transform generator functions.
+   *
+   * @default true
+   */
+  generators?: boolean
+  /**
+   * Not supported yet.
+   *
+   * @default true
+   */
+  async?: boolean
+}
+
 export interface SourceMap {
   file?: string
   mappings: string
@@ -161,6 +294,59 @@ export interface SourceMap {
   x_google_ignoreList?: Array<number>
 }
 
+export interface SpreadOptions {
+  /**
+   * Assume all spread iterables are arrays.
+   *
+   * @default false
+   */
+  loose?: boolean
+}
+
+/**
+ * Configure the styled-components plugin.
+ *
+ * @see {@link https://styled-components.com/docs/tooling#babel-plugin}
+ */
+export interface StyledComponentsOptions {
+  /**
+   * Add a `displayName` to styled components.
+   *
+   * @default true
+   */
+  displayName?: boolean
+  /**
+   * Add a `componentId` to styled components, so class names are consistent
+   * between server and client.
+   *
+   * @default true
+   */
+  ssr?: boolean
+  /**
+   * Prefix `displayName` with the name of the file.
+   *
+   * @default true
+   */
+  fileName?: boolean
+  /**
+   * Remove comments and whitespace from CSS in tagged templates.
+   *
+   * @default true
+   */
+  minify?: boolean
+  /** Prefix for `componentId`. */
+  namespace?: string
+}
+
+export interface TemplateLiteralsOptions {
+  /**
+   * Transform template literals to `+` concatenation, instead of `String.prototype.concat` calls.
+   *
+   * @default false
+   */
+  loose?: boolean
+}
+
 /**
  * Transpile a JavaScript or TypeScript into a target ECMAScript version.
  *
@@ -203,6 +389,12 @@ export interface TransformOptions {
   typescript?: TypeScriptOptions
   /** Configure how TSX and JSX are transformed. */
   jsx?: JsxOptions
+  /** Enable the styled-components plugin. */
+  styledComponents?: StyledComponentsOptions
+  /** Enable the Emotion plugin. */
+  emotion?: EmotionOptions
+  /** Rewrite imports which use tsconfig `paths` and `baseUrl` aliases to relative paths. */
+  tsconfigPaths?: TsconfigPathsOptions
   /** Enable ES2015 transformations. */
   es2015?: Es2015Options
   /** Define Plugin */
@@ -251,12 +443,46 @@ export interface TransformResult {
   errors: Array<string>
 }
 
+/**
+ * Configure rewriting of tsconfig `paths` and `baseUrl` aliases.
+ *
+ * @see {@link https://www.typescriptlang.org/tsconfig/#paths}
+ */
+export interface TsconfigPathsOptions {
+  /** `compilerOptions.baseUrl`. Relative to {@link configDir}. */
+  baseUrl?: string
+  /**
+   * `compilerOptions.paths`. Relative to {@link baseUrl} if it is set,
+   * otherwise {@link configDir}.
+   */
+  paths?: Record<string, string[]>
+  /**
+   * Directory containing the tsconfig. Relative to {@link TransformOptions#cwd}.
+   *
+   * @default cwd
+   */
+  configDir?: string
+}
+
 export interface TypeScriptOptions {
   jsxPragma?: string
   jsxPragmaFrag?: string
   onlyRemoveTypeImports?: boolean
   allowNamespaces?: boolean
   allowDeclareFields?: boolean
+  /**
+   * Enable legacy decorators, like TypeScript's `experimentalDecorators` option.
+   *
+   * @default false
+   */
+  experimentalDecorators?: boolean
+  /**
+   * Emit design-time type metadata for decorated declarations, like TypeScript's
+   * `emitDecoratorMetadata` option. Only takes effect with `experimentalDecorators`.
+   *
+   * @default false
+   */
+  emitDecoratorMetadata?: boolean
   /**
    * Also generate a `.d.ts` declaration file for TypeScript files.
    *
@@ -81,3 +81,33 @@ describe('inject plugin', () => {
     assert.equal(ret.code, 'import $inject_Object_assign from "foo";\nlet _ = $inject_Object_assign;\n');
   });
 });
+
+describe('styled-components plugin', () => {
+  const code = 'import styled from "styled-components";\nconst Button = styled.div`\n  color: red;\n`;';
+
+  it('matches output', () => {
+    const ret = oxc.transform('test.js', code, {
+      styledComponents: { ssr: false },
+    });
+    assert.equal(
+      ret.code,
+      'import styled from "styled-components";\n' +
+        'const Button = styled.div.withConfig({ displayName: "test__Button" })`color:red;`;\n',
+    );
+  });
+});
+
+describe('emotion plugin', () => {
+  const code = 'import { css } from "@emotion/react";\nconst container = css`\n  color: red;\n`;';
+
+  it('matches output', () => {
+    const ret = oxc.transform('test.js', code, {
+      emotion: { autoLabel: 'always' },
+    });
+    assert.equal(
+      ret.code,
+      'import { css } from "@emotion/react";\n' +
+        'const container = css("color:red;label:container;");\n',
+    );
+  });
+});
@@ -49,7 +49,10 @@ fn get_result(
 fn get_default_transformer_options() -> TransformOptions {
     TransformOptions {
         typescript: TypeScriptOptions::default(),
-        es2015: ES2015Options { arrow_function: Some(ArrowFunctionsOptions::default()) },
+        es2015: ES2015Options {
+            arrow_function: Some(ArrowFunctionsOptions::default()),
+            ..ES2015Options::default()
+        },
         react: JsxOptions {
             jsx_plugin: true,
             jsx_self_plugin: true,
@@ -355,9 +355,11 @@ impl<'a, 's> PostTransformChecker<'a, 's> {
                 }
             }
 
-            // Check flags match
-            let flags =
-                self.get_pair(scope_ids, |scoping, scope_id| scoping.scopes.get_flags(scope_id));
+            // Check flags match.
+            // `DirectEval` is not maintained by transforms.
+            let flags = self.get_pair(scope_ids, |scoping, scope_id| {
+                scoping.scopes.get_flags(scope_id) - ScopeFlags::DirectEval
+            });
             if flags.is_mismatch() {
                 self.errors.push_mismatch("Scope flags mismatch", scope_ids, flags);
             }
@@ -3,10 +3,10 @@ pub(crate) const PLUGINS: &[&str] = &[
     // // ES2024
     // "babel-plugin-transform-unicode-sets-regex",
     // // ES2022
-    // "babel-plugin-transform-class-properties",
+    "babel-plugin-transform-class-properties",
     "babel-plugin-transform-class-static-block",
-    // "babel-plugin-transform-private-methods",
-    // "babel-plugin-transform-private-property-in-object",
+    "babel-plugin-transform-private-methods",
+    "babel-plugin-transform-private-property-in-object",
     // // [Syntax] "babel-plugin-transform-syntax-top-level-await",
     // ES2021
     "babel-plugin-transform-logical-assignment-operators",
@@ -15,7 +15,7 @@ pub(crate) const PLUGINS: &[&str] = &[
     // "babel-plugin-transform-export-namespace-from",
     // "babel-plugin-transform-dynamic-import",
     "babel-plugin-transform-nullish-coalescing-operator",
-    // "babel-plugin-transform-optional-chaining",
+    "babel-plugin-transform-optional-chaining",
     // // [Syntax] "babel-plugin-transform-syntax-bigint",
     // // [Syntax] "babel-plugin-transform-syntax-dynamic-import",
     // // [Syntax] "babel-plugin-transform-syntax-import-meta",
@@ -23,7 +23,7 @@ pub(crate) const PLUGINS: &[&str] = &[
     "babel-plugin-transform-optional-catch-binding",
     // "babel-plugin-transform-json-strings",
     // // ES2018
-    // "babel-plugin-transform-async-generator-functions",
+    "babel-plugin-transform-async-generator-functions",
     "babel-plugin-transform-object-rest-spread",
     // // [Regex] "babel-plugin-transform-unicode-property-regex",
     // "babel-plugin-transform-dotall-regex",
@@ -34,16 +34,28 @@ pub(crate) const PLUGINS: &[&str] = &[
     "babel-plugin-transform-exponentiation-operator",
     // ES2015
     "babel-plugin-transform-arrow-functions",
+    "babel-plugin-transform-classes",
     // "babel-plugin-transform-function-name",
-    // "babel-plugin-transform-shorthand-properties",
+    "babel-plugin-transform-shorthand-properties",
+    "babel-plugin-transform-computed-properties",
+    "babel-plugin-transform-spread",
+    "babel-plugin-transform-parameters",
+    "babel-plugin-transform-destructuring",
+    "babel-plugin-transform-block-scoping",
+    "babel-plugin-transform-for-of",
+    "babel-plugin-transform-regenerator",
     // "babel-plugin-transform-sticky-regex",
     // "babel-plugin-transform-unicode-regex",
-    // "babel-plugin-transform-template-literals",
+    "babel-plugin-transform-template-literals",
     // "babel-plugin-transform-duplicate-keys",
     // "babel-plugin-transform-instanceof",
     // "babel-plugin-transform-new-target",
     // // ES3
     // "babel-plugin-transform-property-literals",
+    // Modules
+    "babel-plugin-transform-modules-commonjs",
+    "babel-plugin-transform-modules-amd",
+    "babel-plugin-transform-modules-umd",
     // TypeScript
     "babel-preset-typescript",
     "babel-plugin-transform-typescript",
@@ -54,25 +66,20 @@ pub(crate) const PLUGINS: &[&str] = &[
     "babel-plugin-transform-react-jsx-self",
     "babel-plugin-transform-react-jsx-source",
     "babel-plugin-transform-react-jsx-development",
-    // // Proposal
-    // "babel-plugin-proposal-decorators",
-
+    // Proposal
+    "babel-plugin-proposal-decorators",
+    "babel-plugin-transform-explicit-resource-management",
+    // CSS-in-JS
+    "styled-components",
+    "emotion",
+    // JSX to DOM expressions, as used by Solid
+    "dom-expressions",
     // RegExp tests ported from esbuild + a few additions
     "regexp",
 ];
 
-pub(crate) const PLUGINS_NOT_SUPPORTED_YET: &[&str] = &[
-    "proposal-decorators",
-    "transform-class-properties",
-    "transform-classes",
-    "transform-destructuring",
-    "transform-modules-commonjs",
-    "transform-optional-chaining",
-    "transform-parameters",
-    "transform-private-methods",
-    "transform-property-literals",
-    "transform-react-constant-elements",
-];
+pub(crate) const PLUGINS_NOT_SUPPORTED_YET: &[&str] =
+    &["transform-property-literals", "transform-react-constant-elements"];
 
 pub(crate) const SKIP_TESTS: &[&str] = &[
     // Shouldn't report in transformer
@@ -0,0 +1,3 @@
+class Foo {
+  @dec accessor x = 1;
+}
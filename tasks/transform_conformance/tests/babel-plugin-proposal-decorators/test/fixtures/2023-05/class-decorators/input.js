@@ -0,0 +1,9 @@
+@dec
+class Foo {
+  @dec method() {}
+  static create() {
+    return new Foo();
+  }
+}
+
+new Foo();
@@ -0,0 +1,21 @@
+var _initProto, _Foo, _initClass;
+class Foo {
+	static {
+		({e: [_initProto], c: [_Foo, _initClass]} = babelHelpers.applyDecs2305(this, [[
+			dec,
+			2,
+			"method"
+		]], [dec]));
+	}
+	constructor() {
+		_initProto(this);
+	}
+	method() {}
+	static create() {
+		return new _Foo();
+	}
+	static {
+		_initClass();
+	}
+}
+new _Foo();
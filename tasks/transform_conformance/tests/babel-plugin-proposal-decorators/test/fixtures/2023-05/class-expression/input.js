@@ -0,0 +1,3 @@
+const Foo = @dec class {
+  @dec() method() {}
+};
@@ -0,0 +1,17 @@
+var _dec, _initProto, _Class, _initClass;
+const Foo = (_dec = dec(), class {
+	static {
+		({e: [_initProto], c: [_Class, _initClass]} = babelHelpers.applyDecs2305(this, [[
+			_dec,
+			2,
+			"method"
+		]], [dec]));
+	}
+	constructor() {
+		_initProto(this);
+	}
+	method() {}
+	static {
+		_initClass();
+	}
+}, _Class);
@@ -0,0 +1,8 @@
+class Foo {
+  @dec() a;
+  @obj.dec b;
+  @this.dec c;
+  @getObj().dec d;
+  @dec [computed()]() {}
+  @(() => dec) e;
+}
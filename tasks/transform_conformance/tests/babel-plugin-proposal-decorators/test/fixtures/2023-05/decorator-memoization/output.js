@@ -0,0 +1,48 @@
+var _dec, _init_a, _init_b, _this, _init_c, _getObj, _init_d, _computedKey, _dec2, _init_e, _initProto;
+_dec = dec();
+_this = this;
+_getObj = getObj();
+_computedKey = computed();
+_dec2 = () => dec;
+class Foo {
+	static {
+		[_init_a, _init_b, _init_c, _init_d, _init_e, _initProto] = babelHelpers.applyDecs2305(this, [
+			[
+				_dec,
+				0,
+				"a"
+			],
+			[
+				[obj, obj.dec],
+				16,
+				"b"
+			],
+			[
+				[_this, _this.dec],
+				16,
+				"c"
+			],
+			[
+				[_getObj, _getObj.dec],
+				16,
+				"d"
+			],
+			[
+				dec,
+				2,
+				_computedKey
+			],
+			[
+				_dec2,
+				0,
+				"e"
+			]
+		], []).e;
+	}
+	a = (_initProto(this), _init_a(this));
+	b = _init_b(this);
+	c = _init_c(this);
+	d = _init_d(this);
+	[_computedKey]() {}
+	e = _init_e(this);
+}
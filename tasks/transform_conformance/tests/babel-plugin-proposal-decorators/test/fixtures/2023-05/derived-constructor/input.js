@@ -0,0 +1,21 @@
+class Foo extends Bar {
+  @dec method() {}
+}
+
+class Baz extends Bar {
+  constructor() {
+    if (cond) {
+      super(1);
+    } else {
+      super(2);
+    }
+  }
+  @dec method() {}
+}
+
+class Qux {
+  constructor() {
+    this.x = 1;
+  }
+  @dec method() {}
+}
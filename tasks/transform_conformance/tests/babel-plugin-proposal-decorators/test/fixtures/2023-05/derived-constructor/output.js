@@ -0,0 +1,45 @@
+var _initProto, _initProto2, _initProto3;
+class Foo extends Bar {
+	static {
+		[_initProto] = babelHelpers.applyDecs2305(this, [[
+			dec,
+			2,
+			"method"
+		]], []).e;
+	}
+	constructor(..._args) {
+		_initProto(super(..._args));
+	}
+	method() {}
+}
+class Baz extends Bar {
+	static {
+		[_initProto2] = babelHelpers.applyDecs2305(this, [[
+			dec,
+			2,
+			"method"
+		]], []).e;
+	}
+	constructor() {
+		if (cond) {
+			_initProto2(super(1));
+		} else {
+			_initProto2(super(2));
+		}
+	}
+	method() {}
+}
+class Qux {
+	static {
+		[_initProto3] = babelHelpers.applyDecs2305(this, [[
+			dec,
+			2,
+			"method"
+		]], []).e;
+	}
+	constructor() {
+		_initProto3(this);
+		this.x = 1;
+	}
+	method() {}
+}
@@ -0,0 +1,5 @@
+@dec
+export class Foo {}
+
+@dec
+export default class {}
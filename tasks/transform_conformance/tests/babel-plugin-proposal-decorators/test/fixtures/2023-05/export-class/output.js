@@ -0,0 +1,19 @@
+var _Foo, _initClass, _default2, _initClass2;
+class Foo {
+	static {
+		[_Foo, _initClass] = babelHelpers.applyDecs2305(this, [], [dec]).c;
+	}
+	static {
+		_initClass();
+	}
+}
+export { _Foo as Foo };
+class _default {
+	static {
+		[_default2, _initClass2] = babelHelpers.applyDecs2305(this, [], [dec]).c;
+	}
+	static {
+		_initClass2();
+	}
+}
+export { _default2 as default };
@@ -0,0 +1,32 @@
+var _init_a, _init_b, _init_d, _init_computedKey;
+class Foo {
+	static {
+		[_init_a, _init_b, _init_d, _init_computedKey] = babelHelpers.applyDecs2305(this, [
+			[
+				dec,
+				0,
+				"a"
+			],
+			[
+				dec,
+				0,
+				"b"
+			],
+			[
+				dec,
+				8,
+				"d"
+			],
+			[
+				dec,
+				0,
+				"e-f"
+			]
+		], []).e;
+	}
+	a = _init_a(this);
+	b = _init_b(this, 1);
+	c = 2;
+	static d = _init_d(this, 3);
+	"e-f" = _init_computedKey(this, 4);
+}
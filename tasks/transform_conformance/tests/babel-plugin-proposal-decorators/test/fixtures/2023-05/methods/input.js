@@ -0,0 +1,6 @@
+class Foo {
+  @dec method() {}
+  @dec get getter() {}
+  @dec set setter(v) {}
+  @dec static staticMethod() {}
+}
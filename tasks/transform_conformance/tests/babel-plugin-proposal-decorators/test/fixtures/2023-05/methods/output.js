@@ -0,0 +1,35 @@
+var _initProto, _initStatic;
+class Foo {
+	static {
+		[_initProto, _initStatic] = babelHelpers.applyDecs2305(this, [
+			[
+				dec,
+				2,
+				"method"
+			],
+			[
+				dec,
+				3,
+				"getter"
+			],
+			[
+				dec,
+				4,
+				"setter"
+			],
+			[
+				dec,
+				10,
+				"staticMethod"
+			]
+		], []).e;
+		_initStatic(this);
+	}
+	constructor() {
+		_initProto(this);
+	}
+	method() {}
+	get getter() {}
+	set setter(v) {}
+	static staticMethod() {}
+}
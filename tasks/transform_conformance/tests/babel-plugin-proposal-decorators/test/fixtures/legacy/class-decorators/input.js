@@ -0,0 +1,5 @@
+@dec
+@dec2()
+class Foo {}
+
+new Foo();
@@ -0,0 +1,3 @@
+let Foo = class {};
+Foo = babelHelpers.decorate([dec, dec2()], Foo);
+new Foo();
@@ -0,0 +1 @@
+const Foo = @dec class {};
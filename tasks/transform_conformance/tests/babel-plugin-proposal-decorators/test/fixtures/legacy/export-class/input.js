@@ -0,0 +1,7 @@
+@dec
+export class Foo {}
+
+@dec
+export default class {
+  @dec method() {}
+}
@@ -0,0 +1,8 @@
+export let Foo = class {};
+Foo = babelHelpers.decorate([dec], Foo);
+let _default = class {
+	method() {}
+};
+babelHelpers.decorate([dec], _default.prototype, "method", null);
+_default = babelHelpers.decorate([dec], _default);
+export default _default;
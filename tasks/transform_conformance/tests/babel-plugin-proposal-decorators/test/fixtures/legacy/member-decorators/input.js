@@ -0,0 +1,10 @@
+class Foo {
+  @dec prop = 1;
+  @dec method() {}
+  @dec get getter() {}
+  @dec static staticMethod() {}
+  @dec static staticProp;
+  @dec ["computed" + key]() {}
+  @dec "string"() {}
+  @dec 1() {}
+}
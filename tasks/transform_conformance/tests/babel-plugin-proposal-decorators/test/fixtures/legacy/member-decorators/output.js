@@ -0,0 +1,19 @@
+var _ref;
+class Foo {
+	prop = 1;
+	method() {}
+	get getter() {}
+	static staticMethod() {}
+	static staticProp;
+	[_ref = "computed" + key]() {}
+	"string"() {}
+	1() {}
+}
+babelHelpers.decorate([dec], Foo.prototype, "prop", void 0);
+babelHelpers.decorate([dec], Foo.prototype, "method", null);
+babelHelpers.decorate([dec], Foo.prototype, "getter", null);
+babelHelpers.decorate([dec], Foo.prototype, _ref, null);
+babelHelpers.decorate([dec], Foo.prototype, "string", null);
+babelHelpers.decorate([dec], Foo.prototype, 1, null);
+babelHelpers.decorate([dec], Foo, "staticMethod", null);
+babelHelpers.decorate([dec], Foo, "staticProp", void 0);
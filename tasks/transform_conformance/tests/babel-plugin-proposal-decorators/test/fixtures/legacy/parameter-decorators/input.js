@@ -0,0 +1,4 @@
+class Foo {
+  constructor(@inject a, b, @inject @optional c) {}
+  method(@param x, y) {}
+}
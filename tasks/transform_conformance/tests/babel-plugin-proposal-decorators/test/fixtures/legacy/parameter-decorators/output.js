@@ -0,0 +1,10 @@
+let Foo = class {
+	constructor(a, b, c) {}
+	method(x, y) {}
+};
+babelHelpers.decorate([babelHelpers.decorateParam(0, param)], Foo.prototype, "method", null);
+Foo = babelHelpers.decorate([
+	babelHelpers.decorateParam(0, inject),
+	babelHelpers.decorateParam(2, inject),
+	babelHelpers.decorateParam(2, optional)
+], Foo);
@@ -0,0 +1,7 @@
+var _Foo;
+let Foo = _Foo = class Foo {
+	static create() {
+		return new _Foo();
+	}
+};
+Foo = _Foo = babelHelpers.decorate([dec], Foo);
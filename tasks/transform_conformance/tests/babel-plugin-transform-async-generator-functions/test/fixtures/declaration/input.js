@@ -0,0 +1,6 @@
+async function* agf(a, b = 1) {
+  this;
+  arguments;
+  await a;
+  yield b;
+}
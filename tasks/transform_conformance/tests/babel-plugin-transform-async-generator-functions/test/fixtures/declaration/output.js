@@ -0,0 +1,8 @@
+function agf() {
+  return babelHelpers.wrapAsyncGenerator(function* (a, b = 1) {
+    this;
+    arguments;
+    yield babelHelpers.awaitAsyncGenerator(a);
+    yield b;
+  }).apply(this, arguments);
+}
@@ -0,0 +1,10 @@
+const a = async function* named() {
+  yield await named;
+};
+const b = async function* () {
+  const inner = async () => await 1;
+  function* gen() {
+    yield 2;
+  }
+  yield await inner();
+};
@@ -0,0 +1,14 @@
+const a = function named() {
+  return babelHelpers.wrapAsyncGenerator(function* () {
+    yield yield babelHelpers.awaitAsyncGenerator(named);
+  }).apply(this, arguments);
+};
+const b = function() {
+  return babelHelpers.wrapAsyncGenerator(function* () {
+    const inner = async () => await 1;
+    function* gen() {
+      yield 2;
+    }
+    yield yield babelHelpers.awaitAsyncGenerator(inner());
+  }).apply(this, arguments);
+};
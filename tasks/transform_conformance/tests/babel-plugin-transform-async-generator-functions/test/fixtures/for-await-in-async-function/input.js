@@ -0,0 +1,5 @@
+async function f(items) {
+  for await (let item of items) {
+    console.log(item);
+  }
+}
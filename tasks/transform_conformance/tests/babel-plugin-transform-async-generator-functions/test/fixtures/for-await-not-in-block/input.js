@@ -0,0 +1,3 @@
+async function f(items) {
+  if (items) for await (const item of items) console.log(item);
+}
@@ -0,0 +1,8 @@
+async function* f(items) {
+  for await (const item of items) {
+    yield item;
+  }
+  outer: for await ([a, b] of items) {
+    continue outer;
+  }
+}
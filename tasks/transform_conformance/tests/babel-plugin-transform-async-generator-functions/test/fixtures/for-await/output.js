@@ -0,0 +1,48 @@
+function f() {
+  return babelHelpers.wrapAsyncGenerator(function* (items) {
+    var _iteratorAbruptCompletion = false;
+    var _didIteratorError = false;
+    var _iteratorError;
+    try {
+      for (var _iterator = babelHelpers.asyncIterator(items), _step; _iteratorAbruptCompletion = !(_step = yield babelHelpers.awaitAsyncGenerator(_iterator.next())).done; _iteratorAbruptCompletion = false) {
+        const item = _step.value;
+        yield item;
+      }
+    } catch (err) {
+      _didIteratorError = true;
+      _iteratorError = err;
+    } finally {
+      try {
+        if (_iteratorAbruptCompletion && _iterator.return != null) {
+          yield babelHelpers.awaitAsyncGenerator(_iterator.return());
+        }
+      } finally {
+        if (_didIteratorError) {
+          throw _iteratorError;
+        }
+      }
+    }
+    var _iteratorAbruptCompletion2 = false;
+    var _didIteratorError2 = false;
+    var _iteratorError2;
+    try {
+      outer: for (var _iterator2 = babelHelpers.asyncIterator(items), _step2; _iteratorAbruptCompletion2 = !(_step2 = yield babelHelpers.awaitAsyncGenerator(_iterator2.next())).done; _iteratorAbruptCompletion2 = false) {
+        [a, b] = _step2.value;
+        continue outer;
+      }
+    } catch (err) {
+      _didIteratorError2 = true;
+      _iteratorError2 = err;
+    } finally {
+      try {
+        if (_iteratorAbruptCompletion2 && _iterator2.return != null) {
+          yield babelHelpers.awaitAsyncGenerator(_iterator2.return());
+        }
+      } finally {
+        if (_didIteratorError2) {
+          throw _iteratorError2;
+        }
+      }
+    }
+  }).apply(this, arguments);
+}
@@ -0,0 +1,11 @@
+const obj = {
+  async *method(x) {
+    yield await x;
+  },
+};
+class C {
+  async *method(x) {
+    yield await x;
+  }
+  static async *[Symbol.asyncIterator]() {}
+}
@@ -0,0 +1,17 @@
+const obj = {
+  method() {
+    return babelHelpers.wrapAsyncGenerator(function* (x) {
+      yield yield babelHelpers.awaitAsyncGenerator(x);
+    }).apply(this, arguments);
+  },
+};
+class C {
+  method() {
+    return babelHelpers.wrapAsyncGenerator(function* (x) {
+      yield yield babelHelpers.awaitAsyncGenerator(x);
+    }).apply(this, arguments);
+  }
+  static [Symbol.asyncIterator]() {
+    return babelHelpers.wrapAsyncGenerator(function* () {}).apply(this, arguments);
+  }
+}
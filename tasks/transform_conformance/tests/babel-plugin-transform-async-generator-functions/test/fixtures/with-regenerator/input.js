@@ -0,0 +1,6 @@
+async function* gen(xs) {
+  let i = 0;
+  while (i < xs.length) {
+    yield await xs[i++];
+  }
+}
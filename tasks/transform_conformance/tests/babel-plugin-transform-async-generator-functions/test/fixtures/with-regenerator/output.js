@@ -0,0 +1,25 @@
+function gen() {
+	return babelHelpers.wrapAsyncGenerator(babelHelpers.regeneratorRuntime().mark(function _callee(xs) {
+		var i;
+		return babelHelpers.regeneratorRuntime().wrap(function _callee$(_context) {
+			while (1) switch (_context.prev = _context.next) {
+				case 0: i = 0;
+				case 1:
+					if (!(i < xs.length)) {
+						_context.next = 8;
+						break;
+					}
+					_context.next = 4;
+					return babelHelpers.awaitAsyncGenerator(xs[i++]);
+				case 4:
+					_context.next = 6;
+					return _context.sent;
+				case 6:
+					_context.next = 1;
+					break;
+				case 8:
+				case "end": return _context.stop();
+			}
+		}, _callee);
+	})).apply(this, arguments);
+}
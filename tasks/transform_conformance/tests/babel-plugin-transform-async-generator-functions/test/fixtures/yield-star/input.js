@@ -0,0 +1,6 @@
+async function* f(other) {
+  yield* other;
+  function* g() {
+    yield* other;
+  }
+}
@@ -0,0 +1,8 @@
+function f() {
+  return babelHelpers.wrapAsyncGenerator(function* (other) {
+    yield* babelHelpers.asyncGeneratorDelegate(babelHelpers.asyncIterator(other));
+    function* g() {
+      yield* other;
+    }
+  }).apply(this, arguments);
+}
@@ -0,0 +1,18 @@
+async function foo(a) {
+  await a;
+  return arguments.length;
+}
+const bar = async function bar() {
+  return await bar;
+};
+class Foo {
+  async method(x) {
+    await this.x;
+  }
+}
+const obj = {
+  async method() {
+    await 1;
+  },
+};
+const arrow = async (x) => await x;
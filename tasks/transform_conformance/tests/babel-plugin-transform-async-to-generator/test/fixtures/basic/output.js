@@ -0,0 +1,30 @@
+function foo(_x) {
+	return _foo.apply(this, arguments);
+}
+function _foo() {
+	_foo = babelHelpers.asyncToGenerator(function* (a) {
+		yield a;
+		return arguments.length;
+	});
+	return _foo.apply(this, arguments);
+}
+const bar = function bar() {
+	return babelHelpers.asyncToGenerator(function* () {
+		return yield bar;
+	}).apply(this, arguments);
+};
+class Foo {
+	method(_x2) {
+		return babelHelpers.asyncToGenerator(function* (x) {
+			yield this.x;
+		}).apply(this, arguments);
+	}
+}
+const obj = { method() {
+	return babelHelpers.asyncToGenerator(function* () {
+		yield 1;
+	}).apply(this, arguments);
+} };
+const arrow = babelHelpers.asyncToGenerator(function* (x) {
+	return yield x;
+});
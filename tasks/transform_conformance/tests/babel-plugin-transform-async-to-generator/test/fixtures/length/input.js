@@ -0,0 +1,11 @@
+async function a(x, y) {}
+async function b(x, y = 1, z) {}
+async function c(x, ...rest) {}
+export async function d(x) {}
+export default async function (x) {}
+async function outer(x) {
+  async function inner(y) {
+    await y;
+  }
+  return inner;
+}
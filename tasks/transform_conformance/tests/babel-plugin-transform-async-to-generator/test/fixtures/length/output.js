@@ -0,0 +1,49 @@
+function a(_x, _x2) {
+	return _a.apply(this, arguments);
+}
+function _a() {
+	_a = babelHelpers.asyncToGenerator(function* (x, y) {});
+	return _a.apply(this, arguments);
+}
+function b(_x3) {
+	return _b.apply(this, arguments);
+}
+function _b() {
+	_b = babelHelpers.asyncToGenerator(function* (x, y = 1, z) {});
+	return _b.apply(this, arguments);
+}
+function c(_x4) {
+	return _c.apply(this, arguments);
+}
+function _c() {
+	_c = babelHelpers.asyncToGenerator(function* (x, ...rest) {});
+	return _c.apply(this, arguments);
+}
+export function d(_x5) {
+	return _d.apply(this, arguments);
+}
+function _d() {
+	_d = babelHelpers.asyncToGenerator(function* (x) {});
+	return _d.apply(this, arguments);
+}
+export default function(_x6) {
+	return babelHelpers.asyncToGenerator(function* (x) {}).apply(this, arguments);
+}
+function outer(_x8) {
+	return _outer.apply(this, arguments);
+}
+function _outer() {
+	_outer = babelHelpers.asyncToGenerator(function* (x) {
+		function inner(_x7) {
+			return _inner.apply(this, arguments);
+		}
+		function _inner() {
+			_inner = babelHelpers.asyncToGenerator(function* (y) {
+				yield y;
+			});
+			return _inner.apply(this, arguments);
+		}
+		return inner;
+	});
+	return _outer.apply(this, arguments);
+}
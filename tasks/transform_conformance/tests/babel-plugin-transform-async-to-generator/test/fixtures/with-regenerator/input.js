@@ -0,0 +1,9 @@
+async function foo(a) {
+  let x = await a;
+  return x;
+}
+class Foo {
+  async method(x) {
+    await x;
+  }
+}
@@ -0,0 +1,36 @@
+function foo(_x) {
+	return _foo.apply(this, arguments);
+}
+function _foo() {
+	_foo = babelHelpers.asyncToGenerator(babelHelpers.regeneratorRuntime().mark(function _callee(a) {
+		var x;
+		return babelHelpers.regeneratorRuntime().wrap(function _callee$(_context) {
+			while (1) switch (_context.prev = _context.next) {
+				case 0:
+					_context.next = 2;
+					return a;
+				case 2:
+					x = _context.sent;
+					return _context.abrupt("return", x);
+				case 4:
+				case "end": return _context.stop();
+			}
+		}, _callee);
+	}));
+	return _foo.apply(this, arguments);
+}
+class Foo {
+	method(_x2) {
+		return babelHelpers.asyncToGenerator(babelHelpers.regeneratorRuntime().mark(function _callee2(x) {
+			return babelHelpers.regeneratorRuntime().wrap(function _callee2$(_context2) {
+				while (1) switch (_context2.prev = _context2.next) {
+					case 0:
+						_context2.next = 2;
+						return x;
+					case 2:
+					case "end": return _context2.stop();
+				}
+			}, _callee2);
+		})).apply(this, arguments);
+	}
+}
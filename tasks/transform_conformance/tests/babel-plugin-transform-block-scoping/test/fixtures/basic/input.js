@@ -0,0 +1,24 @@
+let a = 1;
+const b = 2;
+{
+  let a = 3;
+  const c = a + b;
+  console.log(a, c);
+}
+function f() {
+  let x = 1;
+  if (x) {
+    let x = 2;
+    let y;
+    return x + y;
+  }
+  for (let i = 0; i < 3; i++) {
+    let z;
+    z = i;
+  }
+}
+switch (a) {
+  case 1:
+    let undefined = 1;
+    console.log(undefined);
+}
@@ -0,0 +1,24 @@
+var a = 1;
+var b = 2;
+{
+	var _a = 3;
+	var c = _a + b;
+	console.log(_a, c);
+}
+function f() {
+	var x = 1;
+	if (x) {
+		var _x = 2;
+		var y;
+		return _x + y;
+	}
+	for (var i = 0; i < 3; i++) {
+		var z = void 0;
+		z = i;
+	}
+}
+switch (a) {
+	case 1:
+		var undefined = 1;
+		console.log(undefined);
+}
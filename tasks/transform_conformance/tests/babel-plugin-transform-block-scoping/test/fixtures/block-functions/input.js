@@ -0,0 +1,11 @@
+"use strict";
+function f() { return 0; }
+{
+  g();
+  function f() { return 1; }
+  function g() { return f(); }
+}
+for (let i = 0; i < 3; i++) {
+  function h() { return i; }
+  fns.push(h);
+}
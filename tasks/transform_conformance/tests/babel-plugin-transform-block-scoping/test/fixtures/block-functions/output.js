@@ -0,0 +1,22 @@
+"use strict";
+function f() {
+	return 0;
+}
+{
+	var _f = function() {
+		return 1;
+	};
+	var g = function() {
+		return _f();
+	};
+	g();
+}
+var _loop = function(i) {
+	var h = function() {
+		return i;
+	};
+	fns.push(h);
+};
+for (var i = 0; i < 3; i++) {
+	_loop(i);
+}
@@ -0,0 +1,6 @@
+var a = 1;
+babelHelpers.readOnlyError("a"), 2;
+babelHelpers.readOnlyError("a"), a + 3;
+a || (babelHelpers.readOnlyError("a"), 4);
+babelHelpers.readOnlyError("a"), +a;
+foo((babelHelpers.readOnlyError("a"), 5));
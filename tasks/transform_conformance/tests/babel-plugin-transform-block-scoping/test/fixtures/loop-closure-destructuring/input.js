@@ -0,0 +1,5 @@
+for (let i = 0; i < 3; i++) {
+  var { a, b: [c = 1] } = obj[i], d;
+  for (var [k, v] in entries) fns.push(() => i + k + v);
+  fns.push(() => i + a + c + d);
+}
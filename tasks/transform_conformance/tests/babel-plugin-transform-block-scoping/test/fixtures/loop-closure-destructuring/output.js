@@ -0,0 +1,8 @@
+var _loop = function(i) {
+	({a: a, b: [c = 1]} = obj[i]);
+	for ([k, v] in entries) fns.push(() => i + k + v);
+	fns.push(() => i + a + c + d);
+}, a, c, d, k, v;
+for (var i = 0; i < 3; i++) {
+	_loop(i);
+}
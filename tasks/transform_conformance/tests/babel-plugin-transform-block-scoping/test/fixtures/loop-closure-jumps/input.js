@@ -0,0 +1,15 @@
+function f() {
+  for (let i = 0; i < 3; i++) {
+    fns.push(() => i);
+    if (i === 1) break;
+  }
+
+  outer: for (let i = 0; i < 3; i++) {
+    for (let j = 0; j < 3; j++) {
+      fns.push(() => i + j);
+      if (j === 1) continue;
+      if (j === 2) continue outer;
+      if (i === 2) return i;
+    }
+  }
+}
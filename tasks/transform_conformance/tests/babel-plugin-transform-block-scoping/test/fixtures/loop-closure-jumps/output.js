@@ -0,0 +1,28 @@
+function f() {
+	var _loop = function(i) {
+		fns.push(() => i);
+		if (i === 1) return 1;
+	};
+	for (var i = 0; i < 3; i++) {
+		if (_loop(i)) break;
+	}
+	var _loop3 = function(_i) {
+		var _loop2 = function(j) {
+			fns.push(() => _i + j);
+			if (j === 1) return 0;
+			if (j === 2) return 1;
+			if (_i === 2) return { v: _i };
+		}, _ret;
+		for (var j = 0; j < 3; j++) {
+			_ret = _loop2(j);
+			if (_ret === 0) continue;
+			if (_ret === 1) return 0;
+			if (_ret) return { v: _ret.v };
+		}
+	}, _ret2;
+	outer: for (var _i = 0; _i < 3; _i++) {
+		_ret2 = _loop3(_i);
+		if (_ret2 === 0) continue outer;
+		if (_ret2) return _ret2.v;
+	}
+}
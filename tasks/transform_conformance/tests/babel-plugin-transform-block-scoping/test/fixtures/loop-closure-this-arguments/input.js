@@ -0,0 +1,5 @@
+function f() {
+  for (let i = 0; i < 3; i++) {
+    fns.push(() => this[i] + arguments[i]);
+  }
+}
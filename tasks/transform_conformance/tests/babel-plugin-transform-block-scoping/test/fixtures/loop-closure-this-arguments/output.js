@@ -0,0 +1,8 @@
+function f() {
+	var _this = this, _arguments = arguments, _loop = function(i) {
+		fns.push(() => _this[i] + _arguments[i]);
+	};
+	for (var i = 0; i < 3; i++) {
+		_loop(i);
+	}
+}
@@ -0,0 +1,21 @@
+for (let i = 0; i < 3; i++) {
+  fns.push(() => i);
+}
+
+for (let i = 0; i < 3; i++) {
+  let j = i * 2;
+  i++;
+  fns.push(function () {
+    return i + j;
+  });
+}
+
+for (const key in obj) {
+  var value = obj[key];
+  fns.push(() => key + value);
+}
+
+while (cond) {
+  const x = next();
+  fns.push(() => x);
+}
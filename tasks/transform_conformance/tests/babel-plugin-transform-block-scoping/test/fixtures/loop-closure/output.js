@@ -0,0 +1,31 @@
+var _loop = function(i) {
+	fns.push(() => i);
+};
+for (var i = 0; i < 3; i++) {
+	_loop(i);
+}
+var _loop2 = function(_i2) {
+	var j = _i2 * 2;
+	_i2++;
+	fns.push(function() {
+		return _i2 + j;
+	});
+	_i = _i2;
+};
+for (var _i = 0; _i < 3; _i++) {
+	_loop2(_i);
+}
+var _loop3 = function(key) {
+	value = obj[key];
+	fns.push(() => key + value);
+}, value;
+for (var key in obj) {
+	_loop3(key);
+}
+var _loop4 = function() {
+	var x = next();
+	fns.push(() => x);
+};
+while (cond) {
+	_loop4();
+}
@@ -0,0 +1,9 @@
+export default class {
+  x = 1;
+}
+function f() {
+  const a = class {
+    static y = 2;
+  };
+  return a;
+}
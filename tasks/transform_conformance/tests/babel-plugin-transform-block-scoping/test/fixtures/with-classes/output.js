@@ -0,0 +1,18 @@
+var _Class = function() {
+	function _Class() {
+		babelHelpers.classCallCheck(this, _Class);
+		babelHelpers.defineProperty(this, "x", 1);
+	}
+	return babelHelpers.createClass(_Class);
+}();
+export { _Class as default };
+function f() {
+	var _Class2;
+	var a = (_Class2 = function() {
+		function a() {
+			babelHelpers.classCallCheck(this, a);
+		}
+		return babelHelpers.createClass(a);
+	}(), babelHelpers.defineProperty(_Class2, "y", 2), _Class2);
+	return a;
+}
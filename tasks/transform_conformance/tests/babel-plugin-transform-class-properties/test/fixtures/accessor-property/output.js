@@ -0,0 +1,12 @@
+var _A = new WeakMap();
+class A {
+  constructor() {
+    babelHelpers.classPrivateFieldInitSpec(this, _A, 1);
+  }
+  get x() {
+    return babelHelpers.classPrivateFieldGet2(_A, this);
+  }
+  set x(v) {
+    babelHelpers.classPrivateFieldSet2(_A, this, v);
+  }
+}
@@ -0,0 +1,9 @@
+const A = class B {
+  static x = 1;
+  static y = B.x;
+  static z = () => B;
+
+  method() {
+    return B;
+  }
+};
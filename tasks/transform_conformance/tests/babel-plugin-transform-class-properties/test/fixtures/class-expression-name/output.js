@@ -0,0 +1,6 @@
+var _B;
+const A = (_B = class B {
+	method() {
+		return B;
+	}
+}, babelHelpers.defineProperty(_B, "x", 1), babelHelpers.defineProperty(_B, "y", _B.x), babelHelpers.defineProperty(_B, "z", () => _B), _B);
@@ -0,0 +1,12 @@
+const A = class {
+  static x = 1;
+  #y = 2;
+
+  static getY(obj) {
+    return obj.#y;
+  }
+};
+
+export default class {
+  static x = 1;
+}
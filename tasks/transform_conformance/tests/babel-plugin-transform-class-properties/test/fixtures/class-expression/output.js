@@ -0,0 +1,11 @@
+var _y, _Class;
+const A = (_y = new WeakMap(), _Class = class {
+	constructor() {
+		babelHelpers.classPrivateFieldInitSpec(this, _y, 2);
+	}
+	static getY(obj) {
+		return babelHelpers.classPrivateFieldGet2(_y, obj);
+	}
+}, babelHelpers.defineProperty(_Class, "x", 1), _Class);
+export default class _Class2 {}
+babelHelpers.defineProperty(_Class2, "x", 1);
@@ -0,0 +1,24 @@
+class A extends B {
+  x = 1;
+}
+
+class C extends B {
+  x = 1;
+
+  constructor() {
+    super();
+    foo();
+  }
+}
+
+class D extends B {
+  x = () => this;
+
+  constructor(cond) {
+    if (cond) {
+      super(1);
+    } else {
+      super(2);
+    }
+  }
+}
@@ -0,0 +1,23 @@
+class A extends B {
+	constructor(..._args) {
+		super(..._args);
+		babelHelpers.defineProperty(this, "x", 1);
+	}
+}
+class C extends B {
+	constructor() {
+		super();
+		babelHelpers.defineProperty(this, "x", 1);
+		foo();
+	}
+}
+class D extends B {
+	constructor(cond) {
+		var _super = (..._args2) => (super(..._args2), babelHelpers.defineProperty(this, "x", () => this), this);
+		if (cond) {
+			_super(1);
+		} else {
+			_super(2);
+		}
+	}
+}
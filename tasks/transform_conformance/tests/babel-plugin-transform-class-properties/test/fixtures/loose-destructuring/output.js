@@ -0,0 +1,20 @@
+var _x = babelHelpers.classPrivateFieldLooseKey("x");
+var _y = babelHelpers.classPrivateFieldLooseKey("y");
+class A {
+	constructor() {
+		Object.defineProperty(this, _x, {
+			writable: true,
+			value: 1
+		});
+	}
+	method(obj, arr) {
+		[babelHelpers.classPrivateFieldLooseBase(this, _x)[_x], babelHelpers.classPrivateFieldLooseBase(A, _y)[_y]] = arr;
+		({a: babelHelpers.classPrivateFieldLooseBase(obj, _x)[_x], b: babelHelpers.classPrivateFieldLooseBase(this, _x)[_x] = 3,...babelHelpers.classPrivateFieldLooseBase(A, _y)[_y]} = obj);
+		for (babelHelpers.classPrivateFieldLooseBase(this, _x)[_x] of arr) {}
+		for (babelHelpers.classPrivateFieldLooseBase(this, _x)[_x] in obj) {}
+	}
+}
+Object.defineProperty(A, _y, {
+	writable: true,
+	value: 2
+});
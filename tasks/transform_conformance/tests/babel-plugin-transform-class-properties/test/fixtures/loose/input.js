@@ -0,0 +1,9 @@
+class C {
+  a = 1;
+  #b = 2;
+  static c = 3;
+
+  method() {
+    return this.#b + C.c;
+  }
+}
@@ -0,0 +1,14 @@
+var _b = babelHelpers.classPrivateFieldLooseKey("b");
+class C {
+	constructor() {
+		this.a = 1;
+		Object.defineProperty(this, _b, {
+			writable: true,
+			value: 2
+		});
+	}
+	method() {
+		return babelHelpers.classPrivateFieldLooseBase(this, _b)[_b] + C.c;
+	}
+}
+C.c = 3;
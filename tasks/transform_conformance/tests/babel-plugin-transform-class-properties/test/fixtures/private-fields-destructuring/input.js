@@ -0,0 +1,11 @@
+class A {
+  #x = 1;
+  static #y = 2;
+
+  method(obj, arr) {
+    [this.#x, A.#y] = arr;
+    ({ a: obj.#x, b: this.#x = 3, ...A.#y } = obj);
+    for (this.#x of arr) {}
+    for (this.#x in obj) {}
+  }
+}
@@ -0,0 +1,13 @@
+var _x = new WeakMap();
+class A {
+	constructor() {
+		babelHelpers.classPrivateFieldInitSpec(this, _x, 1);
+	}
+	method(obj, arr) {
+		[babelHelpers.toSetter(babelHelpers.classPrivateFieldSet2, [_x, this])._, babelHelpers.assertClassBrand(A, A, _y)._] = arr;
+		({a: babelHelpers.toSetter(babelHelpers.classPrivateFieldSet2, [_x, obj])._, b: babelHelpers.toSetter(babelHelpers.classPrivateFieldSet2, [_x, this])._ = 3,...babelHelpers.assertClassBrand(A, A, _y)._} = obj);
+		for (babelHelpers.toSetter(babelHelpers.classPrivateFieldSet2, [_x, this])._ of arr) {}
+		for (babelHelpers.toSetter(babelHelpers.classPrivateFieldSet2, [_x, this])._ in obj) {}
+	}
+}
+var _y = { _: 2 };
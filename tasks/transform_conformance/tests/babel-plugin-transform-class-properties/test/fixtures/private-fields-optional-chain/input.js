@@ -0,0 +1,11 @@
+class A {
+  #x = 1;
+
+  method(obj) {
+    obj?.#x;
+    obj?.a.#x;
+    obj?.#x.a;
+    this.#x?.a;
+    obj?.a(this.#x);
+  }
+}
@@ -0,0 +1,14 @@
+var _x = new WeakMap();
+class A {
+	constructor() {
+		babelHelpers.classPrivateFieldInitSpec(this, _x, 1);
+	}
+	method(obj) {
+		var _this$x;
+		obj === null || obj === void 0 ? void 0 : babelHelpers.classPrivateFieldGet2(_x, obj);
+		obj === null || obj === void 0 ? void 0 : babelHelpers.classPrivateFieldGet2(_x, obj.a);
+		obj === null || obj === void 0 ? void 0 : babelHelpers.classPrivateFieldGet2(_x, obj).a;
+		(_this$x = babelHelpers.classPrivateFieldGet2(_x, this)) === null || _this$x === void 0 ? void 0 : _this$x.a;
+		obj?.a(babelHelpers.classPrivateFieldGet2(_x, this));
+	}
+}
@@ -0,0 +1,8 @@
+class A {
+  #tag = () => {};
+
+  method(obj) {
+    this.#tag`foo`;
+    obj.a.#tag`bar${1}`;
+  }
+}
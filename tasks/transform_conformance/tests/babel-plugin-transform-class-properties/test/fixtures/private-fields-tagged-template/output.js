@@ -0,0 +1,11 @@
+var _tag = new WeakMap();
+class A {
+	constructor() {
+		babelHelpers.classPrivateFieldInitSpec(this, _tag, () => {});
+	}
+	method(obj) {
+		var _obj$a;
+		babelHelpers.classPrivateFieldGet2(_tag, this).bind(this)`foo`;
+		babelHelpers.classPrivateFieldGet2(_tag, _obj$a = obj.a).bind(_obj$a)`bar${1}`;
+	}
+}
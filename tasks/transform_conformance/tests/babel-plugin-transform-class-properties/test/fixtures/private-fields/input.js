@@ -0,0 +1,13 @@
+class C {
+  #a = 1;
+  #b;
+
+  method(obj) {
+    this.#a = 2;
+    obj.#b += this.#a;
+    this.#a++;
+    const old = obj.#b--;
+    this.#a ||= 3;
+    return this.#b(1);
+  }
+}
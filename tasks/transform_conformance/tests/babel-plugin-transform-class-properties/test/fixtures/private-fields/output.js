@@ -0,0 +1,17 @@
+var _a = new WeakMap();
+var _b = new WeakMap();
+class C {
+	constructor() {
+		babelHelpers.classPrivateFieldInitSpec(this, _a, 1);
+		babelHelpers.classPrivateFieldInitSpec(this, _b, void 0);
+	}
+	method(obj) {
+		var _this$a, _obj$b, _obj$b2;
+		babelHelpers.classPrivateFieldSet2(_a, this, 2);
+		babelHelpers.classPrivateFieldSet2(_b, obj, babelHelpers.classPrivateFieldGet2(_b, obj) + babelHelpers.classPrivateFieldGet2(_a, this));
+		babelHelpers.classPrivateFieldSet2(_a, this, (_this$a = babelHelpers.classPrivateFieldGet2(_a, this), ++_this$a));
+		const old = (babelHelpers.classPrivateFieldSet2(_b, obj, (_obj$b = babelHelpers.classPrivateFieldGet2(_b, obj), _obj$b2 = _obj$b--, _obj$b)), _obj$b2);
+		babelHelpers.classPrivateFieldGet2(_a, this) || babelHelpers.classPrivateFieldSet2(_a, this, 3);
+		return babelHelpers.classPrivateFieldGet2(_b, this).call(this, 1);
+	}
+}
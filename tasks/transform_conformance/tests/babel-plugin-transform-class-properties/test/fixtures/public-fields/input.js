@@ -0,0 +1,11 @@
+class C {
+  a;
+  b = 1;
+  "c" = 2;
+  [d()] = 3;
+  e = () => this.b;
+
+  constructor(x) {
+    this.x = x;
+  }
+}
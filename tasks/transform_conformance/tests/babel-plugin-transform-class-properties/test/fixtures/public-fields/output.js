@@ -0,0 +1,11 @@
+var _d = babelHelpers.toPropertyKey(d());
+class C {
+	constructor(x) {
+		babelHelpers.defineProperty(this, "a", void 0);
+		babelHelpers.defineProperty(this, "b", 1);
+		babelHelpers.defineProperty(this, "c", 2);
+		babelHelpers.defineProperty(this, _d, 3);
+		babelHelpers.defineProperty(this, "e", () => this.b);
+		this.x = x;
+	}
+}
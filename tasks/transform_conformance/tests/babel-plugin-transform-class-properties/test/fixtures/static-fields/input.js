@@ -0,0 +1,9 @@
+class C {
+  static a = 1;
+  static b = this.a + 1;
+  static #c = () => this;
+
+  static method() {
+    return C.#c;
+  }
+}
@@ -0,0 +1,8 @@
+class C {
+	static method() {
+		return babelHelpers.assertClassBrand(C, C, _c)._;
+	}
+}
+babelHelpers.defineProperty(C, "a", 1);
+babelHelpers.defineProperty(C, "b", C.a + 1);
+var _c = { _: () => C };
@@ -0,0 +1,3 @@
+class A extends B {
+  static x = super.y;
+}
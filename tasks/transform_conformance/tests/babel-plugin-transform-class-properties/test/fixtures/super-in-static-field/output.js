@@ -0,0 +1,2 @@
+class A extends B {}
+babelHelpers.defineProperty(A, "x", babelHelpers.superPropGet(A, "y", A));
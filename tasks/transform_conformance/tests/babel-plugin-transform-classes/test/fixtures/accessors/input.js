@@ -0,0 +1,15 @@
+class A {
+  get foo() {
+    return 1;
+  }
+
+  set foo(v) {}
+
+  static get bar() {
+    return 2;
+  }
+
+  [computed]() {}
+
+  "string-key"() {}
+}
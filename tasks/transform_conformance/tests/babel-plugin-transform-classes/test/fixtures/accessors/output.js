@@ -0,0 +1,27 @@
+let A = function() {
+	function A() {
+		babelHelpers.classCallCheck(this, A);
+	}
+	return babelHelpers.createClass(A, [
+		{
+			key: "foo",
+			get: function() {
+				return 1;
+			},
+			set: function(v) {}
+		},
+		{
+			key: computed,
+			value: function() {}
+		},
+		{
+			key: "string-key",
+			value: function() {}
+		}
+	], [{
+		key: "bar",
+		get: function() {
+			return 2;
+		}
+	}]);
+}();
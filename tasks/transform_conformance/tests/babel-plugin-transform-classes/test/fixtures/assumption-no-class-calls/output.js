@@ -0,0 +1,13 @@
+let A = function() {
+	function A() {}
+	return babelHelpers.createClass(A);
+}();
+let B = function(_A) {
+	function B() {
+		var _this;
+		_this = babelHelpers.callSuper(this, B);
+		return _this;
+	}
+	babelHelpers.inherits(B, _A);
+	return babelHelpers.createClass(B);
+}(A);
@@ -0,0 +1,14 @@
+class A extends B {
+  constructor() {
+    super();
+    this.f = () => this.x;
+  }
+}
+
+class C extends D {
+  constructor(x) {
+    const g = () => this;
+    super(x);
+    this.g = g;
+  }
+}
@@ -0,0 +1,27 @@
+let A = function(_B) {
+	function A() {
+		var _this;
+		babelHelpers.classCallCheck(this, A);
+		_this = babelHelpers.callSuper(this, A);
+		_this.f = function() {
+			return _this.x;
+		};
+		return _this;
+	}
+	babelHelpers.inherits(A, _B);
+	return babelHelpers.createClass(A);
+}(B);
+let C = function(_D) {
+	function C(x) {
+		var _this2;
+		babelHelpers.classCallCheck(this, C);
+		const g = function() {
+			return _this2;
+		};
+		_this2 = babelHelpers.callSuper(this, C, [x]);
+		_this2.g = g;
+		return _this2;
+	}
+	babelHelpers.inherits(C, _D);
+	return babelHelpers.createClass(C);
+}(D);
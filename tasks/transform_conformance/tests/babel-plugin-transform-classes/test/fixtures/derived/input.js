@@ -0,0 +1,26 @@
+class A extends B {
+  constructor(x) {
+    super(x, ...rest);
+    this.x = x;
+  }
+}
+
+class C extends D {}
+
+class E extends F {
+  constructor() {
+    if (cond) {
+      super();
+    }
+  }
+}
+
+class G extends H {
+  constructor() {
+    super();
+    if (cond) {
+      return;
+    }
+    return {};
+  }
+}
@@ -0,0 +1,44 @@
+let A = function(_B) {
+	function A(x) {
+		var _this;
+		babelHelpers.classCallCheck(this, A);
+		_this = babelHelpers.callSuper(this, A, [x, ...rest]);
+		_this.x = x;
+		return _this;
+	}
+	babelHelpers.inherits(A, _B);
+	return babelHelpers.createClass(A);
+}(B);
+let C = function(_D) {
+	function C() {
+		babelHelpers.classCallCheck(this, C);
+		return babelHelpers.callSuper(this, C, arguments);
+	}
+	babelHelpers.inherits(C, _D);
+	return babelHelpers.createClass(C);
+}(D);
+let E = function(_F) {
+	function E() {
+		var _this2;
+		babelHelpers.classCallCheck(this, E);
+		if (cond) {
+			_this2 = babelHelpers.callSuper(this, E);
+		}
+		return babelHelpers.assertThisInitialized(_this2);
+	}
+	babelHelpers.inherits(E, _F);
+	return babelHelpers.createClass(E);
+}(F);
+let G = function(_H) {
+	function G() {
+		var _this3;
+		babelHelpers.classCallCheck(this, G);
+		_this3 = babelHelpers.callSuper(this, G);
+		if (cond) {
+			return _this3;
+		}
+		return babelHelpers.possibleConstructorReturn(_this3, {});
+	}
+	babelHelpers.inherits(G, _H);
+	return babelHelpers.createClass(G);
+}(H);
@@ -0,0 +1,6 @@
+export default (function() {
+	function _default() {
+		babelHelpers.classCallCheck(this, _default);
+	}
+	return babelHelpers.createClass(_default);
+})();
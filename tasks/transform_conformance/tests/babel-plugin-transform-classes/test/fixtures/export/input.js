@@ -0,0 +1,2 @@
+export class A {}
+export default class B extends A {}
@@ -0,0 +1,15 @@
+export let A = function() {
+	function A() {
+		babelHelpers.classCallCheck(this, A);
+	}
+	return babelHelpers.createClass(A);
+}();
+let B = function(_A) {
+	function B() {
+		babelHelpers.classCallCheck(this, B);
+		return babelHelpers.callSuper(this, B, arguments);
+	}
+	babelHelpers.inherits(B, _A);
+	return babelHelpers.createClass(B);
+}(A);
+export { B as default };
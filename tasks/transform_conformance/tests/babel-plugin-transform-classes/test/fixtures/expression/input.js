@@ -0,0 +1,7 @@
+let A = class {};
+let B = class C extends D {
+  foo() {
+    return C;
+  }
+};
+foo(class {});
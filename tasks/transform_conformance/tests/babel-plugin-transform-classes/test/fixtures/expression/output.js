@@ -0,0 +1,25 @@
+let A = function() {
+	function A() {
+		babelHelpers.classCallCheck(this, A);
+	}
+	return babelHelpers.createClass(A);
+}();
+let B = function(_D) {
+	function C() {
+		babelHelpers.classCallCheck(this, C);
+		return babelHelpers.callSuper(this, C, arguments);
+	}
+	babelHelpers.inherits(C, _D);
+	return babelHelpers.createClass(C, [{
+		key: "foo",
+		value: function foo() {
+			return C;
+		}
+	}]);
+}(D);
+foo(function() {
+	function _Class() {
+		babelHelpers.classCallCheck(this, _Class);
+	}
+	return babelHelpers.createClass(_Class);
+}());
@@ -0,0 +1,9 @@
+class A {
+  constructor() {
+    this.x = new.target;
+  }
+
+  foo() {
+    return new.target;
+  }
+}
@@ -0,0 +1,12 @@
+let A = function() {
+	function A() {
+		babelHelpers.classCallCheck(this, A);
+		this.x = this.constructor;
+	}
+	return babelHelpers.createClass(A, [{
+		key: "foo",
+		value: function foo() {
+			return void 0;
+		}
+	}]);
+}();
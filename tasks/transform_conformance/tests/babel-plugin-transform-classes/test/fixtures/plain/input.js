@@ -0,0 +1,15 @@
+class A {
+  constructor(x) {
+    this.x = x;
+  }
+
+  foo() {
+    return this.x;
+  }
+
+  static bar() {
+    return A;
+  }
+}
+
+class B {}
@@ -0,0 +1,23 @@
+let A = function() {
+	function A(x) {
+		babelHelpers.classCallCheck(this, A);
+		this.x = x;
+	}
+	return babelHelpers.createClass(A, [{
+		key: "foo",
+		value: function foo() {
+			return this.x;
+		}
+	}], [{
+		key: "bar",
+		value: function bar() {
+			return A;
+		}
+	}]);
+}();
+let B = function() {
+	function B() {
+		babelHelpers.classCallCheck(this, B);
+	}
+	return babelHelpers.createClass(B);
+}();
@@ -0,0 +1,17 @@
+class A extends B {
+  constructor() {
+    super();
+    fns.push(() => super.m());
+  }
+  m() {
+    fns.push(() => super.m(1));
+    for (let i = 0; i < 3; i++) {
+      fns.push(() => i);
+      super.x = i;
+      this.y = super.z;
+    }
+  }
+  static s() {
+    return () => super.s();
+  }
+}
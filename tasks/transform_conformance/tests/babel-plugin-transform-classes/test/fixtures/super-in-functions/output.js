@@ -0,0 +1,39 @@
+var A = function(_B) {
+	function A() {
+		var _this2;
+		babelHelpers.classCallCheck(this, A);
+		_this2 = babelHelpers.callSuper(this, A);
+		fns.push(function() {
+			return babelHelpers.superPropGet(A, "m", _this2, 3)([]);
+		});
+		return _this2;
+	}
+	babelHelpers.inherits(A, _B);
+	return babelHelpers.createClass(A, [{
+		key: "m",
+		value: function m() {
+			var _this3 = this;
+			fns.push(function() {
+				return babelHelpers.superPropGet(A, "m", _this3, 3)([1]);
+			});
+			var _this = this, _loop = function(i) {
+				fns.push(function() {
+					return i;
+				});
+				babelHelpers.superPropSet(A, "x", i, _this3, 1, 1);
+				_this.y = babelHelpers.superPropGet(A, "z", _this3, 1);
+			};
+			for (var i = 0; i < 3; i++) {
+				_loop(i);
+			}
+		}
+	}], [{
+		key: "s",
+		value: function s() {
+			var _this4 = this;
+			return function() {
+				return babelHelpers.superPropGet(A, "s", _this4, 2)([]);
+			};
+		}
+	}]);
+}(B);
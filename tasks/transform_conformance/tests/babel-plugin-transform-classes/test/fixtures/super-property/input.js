@@ -0,0 +1,15 @@
+class A extends B {
+  foo() {
+    super.foo;
+    super["bar"];
+    super.foo(1, 2);
+    super.foo = 1;
+    super[key] += 2;
+  }
+
+  static bar() {
+    super.bar;
+    super.bar();
+    super.bar = 1;
+  }
+}
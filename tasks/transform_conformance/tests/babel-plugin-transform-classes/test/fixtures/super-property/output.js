@@ -0,0 +1,25 @@
+let A = function(_B) {
+	function A() {
+		babelHelpers.classCallCheck(this, A);
+		return babelHelpers.callSuper(this, A, arguments);
+	}
+	babelHelpers.inherits(A, _B);
+	return babelHelpers.createClass(A, [{
+		key: "foo",
+		value: function foo() {
+			var _key;
+			babelHelpers.superPropGet(A, "foo", this, 1);
+			babelHelpers.superPropGet(A, "bar", this, 1);
+			babelHelpers.superPropGet(A, "foo", this, 3)([1, 2]);
+			babelHelpers.superPropSet(A, "foo", 1, this, 1, 1);
+			babelHelpers.superPropSet(A, _key = key, babelHelpers.superPropGet(A, _key, this, 1) + 2, this, 1, 1);
+		}
+	}], [{
+		key: "bar",
+		value: function bar() {
+			babelHelpers.superPropGet(A, "bar", this);
+			babelHelpers.superPropGet(A, "bar", this, 2)([]);
+			babelHelpers.superPropSet(A, "bar", 1, this, 1);
+		}
+	}]);
+}(B);
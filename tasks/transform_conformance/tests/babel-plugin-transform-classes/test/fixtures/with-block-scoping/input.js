@@ -0,0 +1,9 @@
+class A {}
+
+let B = 1;
+{
+  class B {}
+  new B();
+}
+
+export class C {}
@@ -0,0 +1,22 @@
+var A = function() {
+	function A() {
+		babelHelpers.classCallCheck(this, A);
+	}
+	return babelHelpers.createClass(A);
+}();
+var B = 1;
+{
+	var _B = function() {
+		function _B() {
+			babelHelpers.classCallCheck(this, _B);
+		}
+		return babelHelpers.createClass(_B);
+	}();
+	new _B();
+}
+export var C = function() {
+	function C() {
+		babelHelpers.classCallCheck(this, C);
+	}
+	return babelHelpers.createClass(C);
+}();
@@ -0,0 +1,6 @@
+class A extends B {
+  x = 1;
+  static y = A;
+  foo() { return () => super.foo(); }
+}
+let C = class { static z = 2; };
@@ -0,0 +1,24 @@
+var _Class;
+let A = function(_B) {
+	function A(..._args) {
+		var _this;
+		babelHelpers.classCallCheck(this, A);
+		_this = babelHelpers.callSuper(this, A, [..._args]);
+		babelHelpers.defineProperty(_this, "x", 1);
+		return _this;
+	}
+	babelHelpers.inherits(A, _B);
+	return babelHelpers.createClass(A, [{
+		key: "foo",
+		value: function foo() {
+			return () => babelHelpers.superPropGet(A, "foo", this, 3)([]);
+		}
+	}]);
+}(B);
+babelHelpers.defineProperty(A, "y", A);
+let C = (_Class = function() {
+	function C() {
+		babelHelpers.classCallCheck(this, C);
+	}
+	return babelHelpers.createClass(C);
+}(), babelHelpers.defineProperty(_Class, "z", 2), _Class);
@@ -0,0 +1,5 @@
+class A {
+  static x = 1;
+  static { init(this.x); }
+  static { foo(); bar(); }
+}
@@ -0,0 +1,12 @@
+let A = function() {
+	function A() {
+		babelHelpers.classCallCheck(this, A);
+	}
+	return babelHelpers.createClass(A);
+}();
+babelHelpers.defineProperty(A, "x", 1);
+var _ = { _: init(A.x) };
+var _2 = { _: (() => {
+	foo();
+	bar();
+})() };
@@ -0,0 +1,8 @@
+var obj = {
+  a: 1,
+  [b]: 2,
+  c: 3,
+  get [d]() {},
+  set [d](v) {},
+  ["e" + f]() {},
+};
@@ -0,0 +1,2 @@
+var _obj;
+var obj = (_obj = { a: 1 }, babelHelpers.defineProperty(_obj, b, 2), babelHelpers.defineProperty(_obj, "c", 3), babelHelpers.defineAccessor("get", _obj, d, function() {}), babelHelpers.defineAccessor("set", _obj, d, function(v) {}), babelHelpers.defineProperty(_obj, "e" + f, function() {}), _obj);
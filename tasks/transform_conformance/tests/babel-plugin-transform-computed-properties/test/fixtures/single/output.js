@@ -0,0 +1 @@
+var obj = babelHelpers.defineProperty({ a: 1 }, b, 2);
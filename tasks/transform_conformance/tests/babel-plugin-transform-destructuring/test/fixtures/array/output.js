@@ -0,0 +1,2 @@
+var _arr = babelHelpers.toArray(arr), a = _arr[0], b = _arr[2], c = _arr.slice(3);
+const _arr2 = babelHelpers.slicedToArray(arr, 1), d = _arr2[0];
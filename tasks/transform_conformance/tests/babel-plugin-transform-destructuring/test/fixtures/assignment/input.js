@@ -0,0 +1,3 @@
+({ a, b } = obj);
+[c, d] = arr;
+x = { e } = obj;
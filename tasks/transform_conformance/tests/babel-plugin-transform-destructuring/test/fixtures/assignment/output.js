@@ -0,0 +1,4 @@
+var _obj, _arr, _obj2;
+_obj = obj, a = _obj.a, b = _obj.b;
+_arr = babelHelpers.slicedToArray(arr, 2), c = _arr[0], d = _arr[1];
+x = (_obj2 = obj, e = _obj2.e, _obj2);
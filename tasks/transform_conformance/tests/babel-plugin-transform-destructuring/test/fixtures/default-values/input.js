@@ -0,0 +1,2 @@
+var [a = 1, [b] = []] = arr;
+({ c = 2, d: { e } = {} } = obj);
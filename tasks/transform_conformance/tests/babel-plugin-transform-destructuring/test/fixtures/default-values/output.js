@@ -0,0 +1,3 @@
+var _obj, _obj$c, _obj$d;
+var _arr = babelHelpers.slicedToArray(arr, 2), _arr$ = _arr[0], a = _arr$ === void 0 ? 1 : _arr$, _arr$2 = _arr[1], _ref = babelHelpers.slicedToArray(_arr$2 === void 0 ? [] : _arr$2, 1), b = _ref[0];
+_obj = obj, _obj$c = _obj.c, c = _obj$c === void 0 ? 2 : _obj$c, _obj$d = _obj.d, e = (_obj$d === void 0 ? {} : _obj$d).e;
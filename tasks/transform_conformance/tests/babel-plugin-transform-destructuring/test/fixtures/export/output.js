@@ -0,0 +1,2 @@
+var _obj = obj, a = _obj.a, b = _obj.b;
+export { a, b };
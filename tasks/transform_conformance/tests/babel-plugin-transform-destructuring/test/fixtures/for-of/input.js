@@ -0,0 +1,4 @@
+for (const { a, b } of arr) {
+  console.log(a, b);
+}
+for ([c, d] of arr);
@@ -0,0 +1,9 @@
+for (const _ref of arr) {
+	const a = _ref.a, b = _ref.b;
+	console.log(a, b);
+}
+for (var _ref2 of arr) {
+	var _ref3;
+	_ref3 = babelHelpers.slicedToArray(_ref2, 2), c = _ref3[0], d = _ref3[1];
+	;
+}
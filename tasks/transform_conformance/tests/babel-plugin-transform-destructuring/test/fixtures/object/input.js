@@ -0,0 +1,3 @@
+var { a, b: c, d = 1 } = obj;
+var { e } = obj;
+let { f: { g } } = obj;
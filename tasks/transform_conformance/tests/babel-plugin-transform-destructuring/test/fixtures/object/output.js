@@ -0,0 +1,3 @@
+var _obj = obj, a = _obj.a, c = _obj.b, _obj$d = _obj.d, d = _obj$d === void 0 ? 1 : _obj$d;
+var e = obj.e;
+let g = obj.f.g;
@@ -0,0 +1,3 @@
+var { a, ...b } = obj;
+var { [c]: d, ...e } = obj;
+var { ...f } = obj;
@@ -0,0 +1,3 @@
+var _obj = obj, a = _obj.a, b = babelHelpers.objectWithoutProperties(_obj, ["a"]);
+var _obj2 = obj, _c = c, d = _obj2[_c], e = babelHelpers.objectWithoutProperties(_obj2, [_c].map(babelHelpers.toPropertyKey));
+var _obj3 = obj, f = babelHelpers.extends({}, (babelHelpers.objectDestructuringEmpty(_obj3), _obj3));
@@ -0,0 +1,7 @@
+async function f() {
+  {
+    await using x = getAsyncResource();
+    using y = getResource();
+    use(x, y);
+  }
+}
@@ -0,0 +1,12 @@
+async function f() {
+  try {
+    var _usingCtx = babelHelpers.usingCtx();
+    const x = _usingCtx.a(getAsyncResource());
+    const y = _usingCtx.u(getResource());
+    use(x, y);
+  } catch (_) {
+    _usingCtx.e = _;
+  } finally {
+    await _usingCtx.d();
+  }
+}
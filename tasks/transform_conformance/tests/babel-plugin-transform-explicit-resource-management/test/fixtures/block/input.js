@@ -0,0 +1,4 @@
+{
+  using x = getResource();
+  use(x);
+}
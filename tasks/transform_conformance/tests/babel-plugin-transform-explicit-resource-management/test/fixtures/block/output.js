@@ -0,0 +1,9 @@
+try {
+  var _usingCtx = babelHelpers.usingCtx();
+  const x = _usingCtx.u(getResource());
+  use(x);
+} catch (_) {
+  _usingCtx.e = _;
+} finally {
+  _usingCtx.d();
+}
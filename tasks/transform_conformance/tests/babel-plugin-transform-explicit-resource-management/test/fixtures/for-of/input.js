@@ -0,0 +1,3 @@
+for (using x of getResources()) {
+  use(x);
+}
@@ -0,0 +1,9 @@
+for (const _x of getResources()) try {
+  var _usingCtx = babelHelpers.usingCtx();
+  const x = _usingCtx.u(_x);
+  use(x);
+} catch (_) {
+  _usingCtx.e = _;
+} finally {
+  _usingCtx.d();
+}
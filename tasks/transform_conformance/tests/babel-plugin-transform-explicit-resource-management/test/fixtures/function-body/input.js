@@ -0,0 +1,5 @@
+function f() {
+  using x = getResource();
+  let y = x.value;
+  return y;
+}
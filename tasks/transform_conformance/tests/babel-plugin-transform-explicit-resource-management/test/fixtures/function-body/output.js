@@ -0,0 +1,12 @@
+function f() {
+  try {
+    var _usingCtx = babelHelpers.usingCtx();
+    const x = _usingCtx.u(getResource());
+    let y = x.value;
+    return y;
+  } catch (_) {
+    _usingCtx.e = _;
+  } finally {
+    _usingCtx.d();
+  }
+}
@@ -0,0 +1,8 @@
+import { getResource } from "resources";
+using x = getResource();
+const y = x.value;
+export const z = y + 1;
+export function f() {
+  return z;
+}
+export default y;
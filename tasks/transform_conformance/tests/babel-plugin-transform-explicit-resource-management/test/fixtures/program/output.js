@@ -0,0 +1,17 @@
+import { getResource } from "resources";
+export function f() {
+  return z;
+}
+try {
+  var _usingCtx = babelHelpers.usingCtx();
+  var x = _usingCtx.u(getResource());
+  var y = x.value;
+  var z = y + 1;
+  var _default = y;
+} catch (_) {
+  _usingCtx.e = _;
+} finally {
+  _usingCtx.d();
+}
+export { z };
+export { _default as default };
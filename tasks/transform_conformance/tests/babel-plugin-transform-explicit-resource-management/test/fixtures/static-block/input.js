@@ -0,0 +1,6 @@
+class C {
+  static {
+    using x = getResource();
+    use(x);
+  }
+}
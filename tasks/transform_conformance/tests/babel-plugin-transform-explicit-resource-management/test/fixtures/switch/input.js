@@ -0,0 +1,21 @@
+function f(x) {
+  switch (x) {
+    case 1:
+      using a = g();
+      break;
+    case 2: {
+      using b = g();
+      break;
+    }
+    default:
+      log();
+  }
+}
+
+async function h(x) {
+  switch (x) {
+    case 1:
+      await using c = g();
+      return c;
+  }
+}
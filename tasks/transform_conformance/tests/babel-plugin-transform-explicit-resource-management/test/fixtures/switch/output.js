@@ -0,0 +1,38 @@
+function f(x) {
+  try {
+    var _usingCtx = babelHelpers.usingCtx();
+    switch (x) {
+      case 1:
+        const a = _usingCtx.u(g());
+        break;
+      case 2: try {
+        var _usingCtx2 = babelHelpers.usingCtx();
+        const b = _usingCtx2.u(g());
+        break;
+      } catch (_2) {
+        _usingCtx2.e = _2;
+      } finally {
+        _usingCtx2.d();
+      }
+      default: log();
+    }
+  } catch (_) {
+    _usingCtx.e = _;
+  } finally {
+    _usingCtx.d();
+  }
+}
+async function h(x) {
+  try {
+    var _usingCtx3 = babelHelpers.usingCtx();
+    switch (x) {
+      case 1:
+        const c = _usingCtx3.a(g());
+        return c;
+    }
+  } catch (_3) {
+    _usingCtx3.e = _3;
+  } finally {
+    await _usingCtx3.d();
+  }
+}
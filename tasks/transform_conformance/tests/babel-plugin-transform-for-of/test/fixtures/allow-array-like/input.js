@@ -0,0 +1,5 @@
+function f() {
+  for (const x of arguments) {
+    console.log(x);
+  }
+}
@@ -0,0 +1,3 @@
+let x;
+for (x of arr) {}
+for (obj.prop of arr) {}
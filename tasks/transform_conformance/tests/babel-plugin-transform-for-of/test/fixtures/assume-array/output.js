@@ -0,0 +1,4 @@
+for (var _i = 0, _arr = arr; _i < _arr.length; _i++) {
+	const x = _arr[_i];
+	console.log(x);
+}
@@ -0,0 +1,3 @@
+for (const x of arr) {
+  console.log(x);
+}
@@ -0,0 +1,4 @@
+for (var _iterator = babelHelpers.createForOfIteratorHelperLoose(arr), _step; !(_step = _iterator()).done;) {
+	const x = _step.value;
+	console.log(x);
+}
@@ -0,0 +1,5 @@
+outer: for (const x of xs) {
+  for (const y of ys) {
+    if (x === y) continue outer;
+  }
+}
@@ -0,0 +1,21 @@
+var _iterator = babelHelpers.createForOfIteratorHelper(xs), _step;
+try {
+	outer: for (_iterator.s(); !(_step = _iterator.n()).done;) {
+		const x = _step.value;
+		var _iterator2 = babelHelpers.createForOfIteratorHelper(ys), _step2;
+		try {
+			for (_iterator2.s(); !(_step2 = _iterator2.n()).done;) {
+				const y = _step2.value;
+				if (x === y) continue outer;
+			}
+		} catch (err) {
+			_iterator2.e(err);
+		} finally {
+			_iterator2.f();
+		}
+	}
+} catch (err) {
+	_iterator.e(err);
+} finally {
+	_iterator.f();
+}
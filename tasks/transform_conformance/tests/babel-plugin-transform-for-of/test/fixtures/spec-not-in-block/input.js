@@ -0,0 +1 @@
+if (cond) for (const x of arr) console.log(x);
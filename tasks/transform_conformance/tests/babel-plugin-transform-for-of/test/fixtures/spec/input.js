@@ -0,0 +1,5 @@
+for (const x of arr) {
+  console.log(x);
+}
+
+for (let [a, b] of entries) foo(a, b);
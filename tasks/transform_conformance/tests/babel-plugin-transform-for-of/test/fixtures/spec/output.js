@@ -0,0 +1,22 @@
+var _iterator = babelHelpers.createForOfIteratorHelper(arr), _step;
+try {
+	for (_iterator.s(); !(_step = _iterator.n()).done;) {
+		const x = _step.value;
+		console.log(x);
+	}
+} catch (err) {
+	_iterator.e(err);
+} finally {
+	_iterator.f();
+}
+var _iterator2 = babelHelpers.createForOfIteratorHelper(entries), _step2;
+try {
+	for (_iterator2.s(); !(_step2 = _iterator2.n()).done;) {
+		let [a, b] = _step2.value;
+		foo(a, b);
+	}
+} catch (err) {
+	_iterator2.e(err);
+} finally {
+	_iterator2.f();
+}
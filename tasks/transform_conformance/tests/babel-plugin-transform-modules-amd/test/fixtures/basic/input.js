@@ -0,0 +1,6 @@
+import foo from "foo";
+import { bar } from "bar";
+import "side-effect";
+export * from "star";
+export const x = foo(bar);
+export default function () {}
@@ -0,0 +1,30 @@
+define([
+  "exports",
+  "foo",
+  "bar",
+  "side-effect",
+  "star"
+], function(_exports, _foo, _bar, _sideEffect, _star) {
+  "use strict";
+  Object.defineProperty(_exports, "__esModule", { value: true });
+  var _exportNames = {
+    x: true,
+    default: true
+  };
+  _exports.default = _default;
+  _exports.x = void 0;
+  _foo = babelHelpers.interopRequireDefault(_foo);
+  Object.keys(_star).forEach(function(key) {
+    if (key === "default" || key === "__esModule") return;
+    if (Object.prototype.hasOwnProperty.call(_exportNames, key)) return;
+    if (key in _exports && _exports[key] === _star[key]) return;
+    Object.defineProperty(_exports, key, {
+      enumerable: true,
+      get: function() {
+        return _star[key];
+      }
+    });
+  });
+  const x = _exports.x = (0, _foo.default)(_bar.bar);
+  function _default() {}
+});
@@ -0,0 +1,6 @@
+define("my-module", ["exports"], function(_exports) {
+  "use strict";
+  Object.defineProperty(_exports, "__esModule", { value: true });
+  _exports.x = void 0;
+  const x = _exports.x = 1;
+});
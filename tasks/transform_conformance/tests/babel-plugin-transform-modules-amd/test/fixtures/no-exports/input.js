@@ -0,0 +1,3 @@
+"use client";
+import * as ns from "ns";
+ns.run();
@@ -0,0 +1,6 @@
+define(["ns"], function(ns) {
+  "use strict";
+  "use client";
+  ns = babelHelpers.interopRequireWildcard(ns);
+  ns.run();
+});
@@ -0,0 +1,3 @@
+export function load(name) {
+  return Promise.all([import("foo"), import(name)]);
+}
@@ -0,0 +1,6 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.load = load;
+function load(name) {
+  return Promise.all([Promise.resolve().then(() => babelHelpers.interopRequireWildcard(require("foo"))), Promise.resolve(`${name}`).then((_s) => babelHelpers.interopRequireWildcard(require(_s)))]);
+}
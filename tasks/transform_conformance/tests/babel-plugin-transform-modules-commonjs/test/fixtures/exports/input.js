@@ -0,0 +1,9 @@
+export var a = 1, b;
+export let c = 2;
+export const d = 3;
+export function f() {}
+export class C {}
+export { a as e, c as default };
+export const { g, h: [i] } = obj;
+var j;
+export { j };
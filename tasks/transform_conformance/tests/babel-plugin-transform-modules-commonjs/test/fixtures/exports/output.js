@@ -0,0 +1,14 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.f = f;
+exports.a = exports.e = exports.b = exports.c = exports.default = exports.d = exports.C = exports.g = exports.i = exports.j = void 0;
+var a = exports.a = exports.e = 1, b;
+let c = exports.c = exports.default = 2;
+const d = exports.d = 3;
+function f() {}
+class C {}
+exports.C = C;
+const { g, h: [i] } = obj;
+exports.g = g;
+exports.i = i;
+var j;
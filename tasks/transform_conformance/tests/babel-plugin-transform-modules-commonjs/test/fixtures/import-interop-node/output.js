@@ -0,0 +1,5 @@
+"use strict";
+var _foo = require("foo");
+var _bar = require("bar");
+var ns = babelHelpers.interopRequireWildcard(require("ns"), true);
+_foo(_bar.bar, ns);
@@ -0,0 +1,14 @@
+import "side-effect";
+import foo from "foo";
+import { bar, baz as qux } from "bar";
+import * as ns from "ns";
+import def, { named } from "mixed";
+
+foo();
+bar(qux);
+ns.x();
+console.log(def, named, `${qux}`, { bar, qux });
+qux`tagged`;
+function f() {
+  return bar;
+}
@@ -0,0 +1,17 @@
+"use strict";
+require("side-effect");
+var _foo = babelHelpers.interopRequireDefault(require("foo"));
+var _bar = require("bar");
+var ns = babelHelpers.interopRequireWildcard(require("ns"));
+var _mixed = babelHelpers.interopRequireWildcard(require("mixed"));
+(0, _foo.default)();
+(0, _bar.bar)(_bar.baz);
+ns.x();
+console.log(_mixed.default, _mixed.named, `${_bar.baz}`, {
+  bar: _bar.bar,
+  qux: _bar.baz
+});
+(0, _bar.baz)`tagged`;
+function f() {
+  return _bar.bar;
+}
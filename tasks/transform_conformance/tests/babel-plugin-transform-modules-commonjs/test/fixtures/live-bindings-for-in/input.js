@@ -0,0 +1,6 @@
+export let key;
+for (key in obj) {
+  log(key);
+}
+for (key in obj);
+for ({ key } in obj) {}
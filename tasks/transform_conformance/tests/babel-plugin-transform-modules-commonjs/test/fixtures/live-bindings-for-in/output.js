@@ -0,0 +1,15 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.key = void 0;
+let key;
+for (let _key in obj) {
+  exports.key = key = _key;
+  log(key);
+}
+for (let _key2 in obj) {
+  exports.key = key = _key2;
+  ;
+}
+for (let _ref in obj) {
+  ({key} = _ref), exports.key = key;
+}
@@ -0,0 +1,11 @@
+export let x, y;
+let z;
+for (x of list) {
+  log(x);
+}
+for (x of list) log(x);
+for ([x, z] of pairs) {}
+for (y of list) {
+  let y = 1;
+}
+for (z of list) {}
@@ -0,0 +1,23 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.x = exports.y = void 0;
+let x, y;
+let z;
+for (let _x of list) {
+  exports.x = x = _x;
+  log(x);
+}
+for (let _x2 of list) {
+  exports.x = x = _x2;
+  log(x);
+}
+for (let _ref of pairs) {
+  [x, z] = _ref, exports.x = x;
+}
+for (let _y of list) {
+  exports.y = y = _y;
+  {
+    let y = 1;
+  }
+}
+for (z of list) {}
@@ -0,0 +1,12 @@
+export let count = 0;
+export let a, b;
+count = 1;
+count += 2;
+count++;
+++count;
+const old = count--;
+[a, b] = [1, 2];
+const pair = ({ a, b } = obj);
+function reset() {
+  count = 0;
+}
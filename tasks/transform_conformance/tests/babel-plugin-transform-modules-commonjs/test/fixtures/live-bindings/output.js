@@ -0,0 +1,16 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.count = exports.a = exports.b = void 0;
+var _count, _ref;
+let count = exports.count = 0;
+let a, b;
+exports.count = count = 1;
+exports.count = count += 2;
+exports.count = ++count;
+exports.count = ++count;
+const old = (_count = count--, exports.count = count, _count);
+[a, b] = [1, 2], exports.a = a, exports.b = b;
+const pair = (_ref = {a, b} = obj, exports.a = a, exports.b = b, _ref);
+function reset() {
+  exports.count = count = 0;
+}
@@ -0,0 +1,2 @@
+export * from "foo";
+export const x = 1;
@@ -0,0 +1,12 @@
+"use strict";
+exports.__esModule = true;
+var _exportNames = { x: true };
+exports.x = void 0;
+var _foo = require("foo");
+Object.keys(_foo).forEach(function(key) {
+  if (key === "default" || key === "__esModule") return;
+  if (Object.prototype.hasOwnProperty.call(_exportNames, key)) return;
+  if (key in exports && exports[key] === _foo[key]) return;
+  exports[key] = _foo[key];
+});
+const x = exports.x = 1;
@@ -0,0 +1,4 @@
+import foo from "foo";
+import { bar } from "bar";
+import * as ns from "ns";
+foo(bar, ns);
@@ -0,0 +1,6 @@
+export { a, b as c } from "foo";
+export * from "bar";
+export * as ns from "baz";
+import { x } from "qux";
+export { x };
+export const y = 1;
@@ -0,0 +1,45 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+var _exportNames = {
+  y: true,
+  a: true,
+  c: true,
+  ns: true,
+  x: true
+};
+Object.defineProperty(exports, "a", {
+  enumerable: true,
+  get: function() {
+    return _foo.a;
+  }
+});
+Object.defineProperty(exports, "c", {
+  enumerable: true,
+  get: function() {
+    return _foo.b;
+  }
+});
+Object.defineProperty(exports, "x", {
+  enumerable: true,
+  get: function() {
+    return _qux.x;
+  }
+});
+exports.y = void 0;
+var _foo = require("foo");
+var _bar = require("bar");
+Object.keys(_bar).forEach(function(key) {
+  if (key === "default" || key === "__esModule") return;
+  if (Object.prototype.hasOwnProperty.call(_exportNames, key)) return;
+  if (key in exports && exports[key] === _bar[key]) return;
+  Object.defineProperty(exports, key, {
+    enumerable: true,
+    get: function() {
+      return _bar[key];
+    }
+  });
+});
+var _baz = babelHelpers.interopRequireWildcard(require("baz"));
+exports.ns = _baz;
+var _qux = require("qux");
+const y = exports.y = 1;
@@ -0,0 +1,2 @@
+exports.x = void 0;
+const x = exports.x = 1;
@@ -0,0 +1,7 @@
+export const self = this;
+function f() {
+  return this;
+}
+class C {
+  x = this;
+}
@@ -0,0 +1,10 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.self = void 0;
+const self = exports.self = void 0;
+function f() {
+  return this;
+}
+class C {
+  x = this;
+}
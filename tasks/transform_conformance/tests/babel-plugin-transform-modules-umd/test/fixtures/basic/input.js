@@ -0,0 +1,3 @@
+import foo from "foo";
+import { bar } from "./bar-baz.js";
+export const x = foo(bar);
@@ -0,0 +1,21 @@
+(function(global, factory) {
+  if (typeof define === "function" && define.amd) {
+    define([
+      "exports",
+      "foo",
+      "./bar-baz.js"
+    ], factory);
+  } else if (typeof exports !== "undefined") {
+    factory(exports, require("foo"), require("./bar-baz.js"));
+  } else {
+    var mod = { exports: {} };
+    factory(mod.exports, global.foo, global.barBaz);
+    global.input = mod.exports;
+  }
+})(typeof globalThis !== "undefined" ? globalThis : typeof self !== "undefined" ? self : this, function(_exports, _foo, _barBaz) {
+  "use strict";
+  Object.defineProperty(_exports, "__esModule", { value: true });
+  _exports.x = void 0;
+  _foo = babelHelpers.interopRequireDefault(_foo);
+  const x = _exports.x = (0, _foo.default)(_barBaz.bar);
+});
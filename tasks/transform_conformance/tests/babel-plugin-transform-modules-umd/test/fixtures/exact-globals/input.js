@@ -0,0 +1,2 @@
+import foo from "foo/bar";
+export default foo;
@@ -0,0 +1,18 @@
+(function(global, factory) {
+  if (typeof define === "function" && define.amd) {
+    define(["exports", "foo/bar"], factory);
+  } else if (typeof exports !== "undefined") {
+    factory(exports, require("foo/bar"));
+  } else {
+    var mod = { exports: {} };
+    factory(mod.exports, global.Foo.Bar);
+    global.My = global.My || {};
+    global.My.Lib = mod.exports;
+  }
+})(typeof globalThis !== "undefined" ? globalThis : typeof self !== "undefined" ? self : this, function(_exports, _bar) {
+  "use strict";
+  Object.defineProperty(_exports, "__esModule", { value: true });
+  _exports.default = void 0;
+  _bar = babelHelpers.interopRequireDefault(_bar);
+  var _default = _exports.default = _bar.default;
+});
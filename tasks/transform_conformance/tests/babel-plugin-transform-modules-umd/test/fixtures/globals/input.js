@@ -0,0 +1,3 @@
+import foo from "foo";
+import bar from "bar";
+export default foo(bar);
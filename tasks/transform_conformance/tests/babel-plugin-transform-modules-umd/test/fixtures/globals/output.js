@@ -0,0 +1,22 @@
+(function(global, factory) {
+  if (typeof define === "function" && define.amd) {
+    define([
+      "exports",
+      "foo",
+      "bar"
+    ], factory);
+  } else if (typeof exports !== "undefined") {
+    factory(exports, require("foo"), require("bar"));
+  } else {
+    var mod = { exports: {} };
+    factory(mod.exports, global.Foo, global.bar);
+    global.input = mod.exports;
+  }
+})(typeof globalThis !== "undefined" ? globalThis : typeof self !== "undefined" ? self : this, function(_exports, _foo, _bar) {
+  "use strict";
+  Object.defineProperty(_exports, "__esModule", { value: true });
+  _exports.default = void 0;
+  _foo = babelHelpers.interopRequireDefault(_foo);
+  _bar = babelHelpers.interopRequireDefault(_bar);
+  var _default = _exports.default = (0, _foo.default)(_bar.default);
+});
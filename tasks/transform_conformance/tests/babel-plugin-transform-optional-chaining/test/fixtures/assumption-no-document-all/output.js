@@ -0,0 +1,5 @@
+var _obj, _obj$foo, _obj$a, _obj$a$b, _obj2, _obj2$key, _obj3;
+const a = (_obj = obj) == null || (_obj$foo = _obj.foo) == null ? void 0 : _obj$foo.bar;
+(_obj$a$b = (_obj$a = obj.a).b) == null ? void 0 : _obj$a$b.call(_obj$a);
+(_obj2 = obj) == null || (_obj2$key = _obj2[key]) == null ? void 0 : _obj2$key.call(_obj2);
+(_obj3 = obj) == null ? true : delete _obj3.foo;
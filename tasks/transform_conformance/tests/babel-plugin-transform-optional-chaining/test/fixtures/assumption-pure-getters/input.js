@@ -0,0 +1,4 @@
+const a = obj?.foo?.bar;
+obj.a.b?.();
+obj?.[key]?.();
+delete obj?.foo;
@@ -0,0 +1,5 @@
+var _obj, _obj$foo, _obj2, _obj2$key, _obj3;
+const a = (_obj = obj) === null || _obj === void 0 || (_obj$foo = _obj.foo) === null || _obj$foo === void 0 ? void 0 : _obj$foo.bar;
+obj.a.b === null || obj.a.b === void 0 ? void 0 : obj.a.b();
+(_obj2 = obj) === null || _obj2 === void 0 || (_obj2$key = _obj2[key]) === null || _obj2$key === void 0 ? void 0 : _obj2$key.call(_obj2);
+(_obj3 = obj) === null || _obj3 === void 0 ? true : delete _obj3.foo;
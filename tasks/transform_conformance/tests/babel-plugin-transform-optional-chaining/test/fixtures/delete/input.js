@@ -0,0 +1,3 @@
+delete obj?.a;
+delete obj?.a.b;
+delete obj.a?.b?.c;
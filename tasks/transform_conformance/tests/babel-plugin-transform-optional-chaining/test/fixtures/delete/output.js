@@ -0,0 +1,4 @@
+var _obj, _obj2, _obj$a, _obj$a$b;
+(_obj = obj) === null || _obj === void 0 ? true : delete _obj.a;
+(_obj2 = obj) === null || _obj2 === void 0 ? true : delete _obj2.a.b;
+(_obj$a = obj.a) === null || _obj$a === void 0 || (_obj$a$b = _obj$a.b) === null || _obj$a$b === void 0 ? true : delete _obj$a$b.c;
@@ -0,0 +1,3 @@
+function f(a, b = a?.b) {}
+function g(b = obj?.b?.c) {}
+const h = (c = obj?.c()) => c;
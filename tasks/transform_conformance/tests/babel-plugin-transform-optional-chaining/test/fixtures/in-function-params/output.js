@@ -0,0 +1,3 @@
+function f(a, b = (() => a === null || a === void 0 ? void 0 : a.b)()) {}
+function g(b = ((_obj, _obj$b) => (_obj = obj) === null || _obj === void 0 || (_obj$b = _obj.b) === null || _obj$b === void 0 ? void 0 : _obj$b.c)()) {}
+const h = (c = ((_obj2) => (_obj2 = obj) === null || _obj2 === void 0 ? void 0 : _obj2.c())()) => c;
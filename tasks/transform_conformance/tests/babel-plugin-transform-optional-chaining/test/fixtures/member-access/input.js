@@ -0,0 +1,9 @@
+const a = obj?.foo;
+const b = obj?.foo?.bar?.baz;
+const c = obj?.[key]?.bar;
+const d = obj?.foo.bar.baz;
+const e = (obj?.foo).bar;
+
+function f(x) {
+  return x?.y?.z;
+}
@@ -0,0 +1,10 @@
+var _obj, _obj2, _obj2$foo, _obj2$foo$bar, _obj3, _obj3$key, _obj4, _obj5;
+const a = (_obj = obj) === null || _obj === void 0 ? void 0 : _obj.foo;
+const b = (_obj2 = obj) === null || _obj2 === void 0 || (_obj2$foo = _obj2.foo) === null || _obj2$foo === void 0 || (_obj2$foo$bar = _obj2$foo.bar) === null || _obj2$foo$bar === void 0 ? void 0 : _obj2$foo$bar.baz;
+const c = (_obj3 = obj) === null || _obj3 === void 0 || (_obj3$key = _obj3[key]) === null || _obj3$key === void 0 ? void 0 : _obj3$key.bar;
+const d = (_obj4 = obj) === null || _obj4 === void 0 ? void 0 : _obj4.foo.bar.baz;
+const e = ((_obj5 = obj) === null || _obj5 === void 0 ? void 0 : _obj5.foo).bar;
+function f(x) {
+	var _x$y;
+	return x === null || x === void 0 || (_x$y = x.y) === null || _x$y === void 0 ? void 0 : _x$y.z;
+}
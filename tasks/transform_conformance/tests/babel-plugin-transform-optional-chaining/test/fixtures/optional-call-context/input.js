@@ -0,0 +1,14 @@
+fn?.(1);
+obj.method?.(1, 2);
+obj?.method(1);
+obj?.a.method?.();
+obj[key]?.();
+
+class C extends S {
+  m() {
+    this.method?.();
+    super.method?.();
+  }
+}
+
+eval?.("foo");
@@ -0,0 +1,14 @@
+var _fn, _obj, _obj$method, _obj2, _obj3, _obj3$a, _obj3$a$method, _obj4, _obj4$key;
+(_fn = fn) === null || _fn === void 0 ? void 0 : _fn(1);
+(_obj$method = (_obj = obj).method) === null || _obj$method === void 0 ? void 0 : _obj$method.call(_obj, 1, 2);
+(_obj2 = obj) === null || _obj2 === void 0 ? void 0 : _obj2.method(1);
+(_obj3 = obj) === null || _obj3 === void 0 || (_obj3$a$method = (_obj3$a = _obj3.a).method) === null || _obj3$a$method === void 0 ? void 0 : _obj3$a$method.call(_obj3$a);
+(_obj4$key = (_obj4 = obj)[key]) === null || _obj4$key === void 0 ? void 0 : _obj4$key.call(_obj4);
+class C extends S {
+	m() {
+		var _this$method, _super$method;
+		(_this$method = this.method) === null || _this$method === void 0 ? void 0 : _this$method.call(this);
+		(_super$method = super.method) === null || _super$method === void 0 ? void 0 : _super$method.call(this);
+	}
+}
+eval === null || eval === void 0 ? void 0 : (0, eval)("foo");
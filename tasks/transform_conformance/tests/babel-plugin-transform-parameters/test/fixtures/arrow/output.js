@@ -0,0 +1,4 @@
+var f = function() {
+	let a = arguments.length > 0 && arguments[0] !== undefined ? arguments[0] : 1;
+	return a;
+};
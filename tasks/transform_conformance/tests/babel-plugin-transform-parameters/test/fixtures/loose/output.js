@@ -0,0 +1,7 @@
+function f(a, b, _temp) {
+	if (b === void 0) {
+		b = 1;
+	}
+	let { c } = _temp === void 0 ? {} : _temp;
+	return a + b + c;
+}
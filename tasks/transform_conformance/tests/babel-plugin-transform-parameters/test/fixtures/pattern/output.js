@@ -0,0 +1,5 @@
+function f(_ref, _ref2) {
+	let { a, b } = _ref;
+	let [c] = _ref2;
+	return a + b + c;
+}
@@ -0,0 +1,6 @@
+function f(a) {
+	for (var _len = arguments.length, rest = new Array(_len > 1 ? _len - 1 : 0), _key = 1; _key < _len; _key++) {
+		rest[_key - 1] = arguments[_key];
+	}
+	return rest;
+}
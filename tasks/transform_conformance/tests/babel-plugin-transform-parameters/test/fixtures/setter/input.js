@@ -0,0 +1,5 @@
+var obj = {
+  set x(v = 1) {
+    this._x = v;
+  },
+};
@@ -0,0 +1,6 @@
+var obj = { set x(v) {
+	if (v === void 0) {
+		v = 1;
+	}
+	this._x = v;
+} };
@@ -0,0 +1,20 @@
+class C {
+  #value = 0;
+
+  get #a() {
+    return this.#value;
+  }
+
+  set #a(v) {
+    this.#value = v;
+  }
+
+  get #readOnly() {
+    return 1;
+  }
+
+  run() {
+    this.#a = this.#a + 1;
+    this.#readOnly = 2;
+  }
+}
@@ -0,0 +1,21 @@
+var _value = new WeakMap();
+var _C_brand = new WeakSet();
+class C {
+	constructor() {
+		babelHelpers.classPrivateMethodInitSpec(this, _C_brand);
+		babelHelpers.classPrivateFieldInitSpec(this, _value, 0);
+	}
+	run() {
+		babelHelpers.classPrivateSetter(_C_brand, _set_a, this, babelHelpers.classPrivateGetter(_C_brand, this, _get_a) + 1);
+		this, 2, babelHelpers.readOnlyError("#readOnly");
+	}
+}
+function _get_a() {
+	return babelHelpers.classPrivateFieldGet2(_value, this);
+}
+function _set_a(v) {
+	babelHelpers.classPrivateFieldSet2(_value, this, v);
+}
+function _get_readOnly() {
+	return 1;
+}
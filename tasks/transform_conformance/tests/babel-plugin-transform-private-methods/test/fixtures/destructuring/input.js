@@ -0,0 +1,15 @@
+class A {
+  #m() {}
+  set #s(v) {}
+  get #g() {}
+
+  method(arr) {
+    [this.#m, this.#s, this.#g] = arr;
+  }
+}
+
+const C = class D {
+  #m() {
+    return D;
+  }
+};
@@ -0,0 +1,24 @@
+var _m2 = function() {
+	return _D;
+}, _D_brand, _D;
+var _A_brand = new WeakSet();
+class A {
+	constructor() {
+		babelHelpers.classPrivateMethodInitSpec(this, _A_brand);
+	}
+	method(arr) {
+		[(this, babelHelpers.toSetter(babelHelpers.readOnlyError, ["#m"]))._, babelHelpers.toSetter(babelHelpers.classPrivateSetter, [
+			_A_brand,
+			_set_s,
+			this
+		])._, (this, babelHelpers.toSetter(babelHelpers.readOnlyError, ["#g"]))._] = arr;
+	}
+}
+function _m() {}
+function _set_s(v) {}
+function _get_g() {}
+const C = (_D_brand = new WeakSet(), _D = class D {
+	constructor() {
+		babelHelpers.classPrivateMethodInitSpec(this, _D_brand);
+	}
+}, _D);
@@ -0,0 +1,12 @@
+class C {
+  #method() {}
+
+  get #a() {
+    return 1;
+  }
+
+  run() {
+    this.#method();
+    return this.#a;
+  }
+}
@@ -0,0 +1,16 @@
+var _method = babelHelpers.classPrivateFieldLooseKey("method");
+var _a = babelHelpers.classPrivateFieldLooseKey("a");
+class C {
+	constructor() {
+		Object.defineProperty(this, _method, { value: _method2 });
+		Object.defineProperty(this, _a, { get: _get_a });
+	}
+	run() {
+		babelHelpers.classPrivateFieldLooseBase(this, _method)[_method]();
+		return babelHelpers.classPrivateFieldLooseBase(this, _a)[_a];
+	}
+}
+function _method2() {}
+function _get_a() {
+	return 1;
+}
@@ -0,0 +1,10 @@
+class C {
+  #method(x) {
+    return x;
+  }
+
+  run(obj) {
+    this.#method(1);
+    return obj.#method;
+  }
+}
@@ -0,0 +1,13 @@
+var _C_brand = new WeakSet();
+class C {
+	constructor() {
+		babelHelpers.classPrivateMethodInitSpec(this, _C_brand);
+	}
+	run(obj) {
+		babelHelpers.assertClassBrand(_C_brand, this, _method).call(this, 1);
+		return babelHelpers.assertClassBrand(_C_brand, obj, _method);
+	}
+}
+function _method(x) {
+	return x;
+}
@@ -0,0 +1,9 @@
+class C {
+  static #method() {
+    return this;
+  }
+
+  static run() {
+    return C.#method();
+  }
+}
@@ -0,0 +1,8 @@
+class C {
+	static run() {
+		return babelHelpers.assertClassBrand(C, C, _method).call(C);
+	}
+}
+function _method() {
+	return this;
+}
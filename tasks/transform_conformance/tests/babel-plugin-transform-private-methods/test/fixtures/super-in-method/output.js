@@ -0,0 +1,10 @@
+var _A_brand = new WeakSet();
+class A extends B {
+  constructor(..._args) {
+    super(..._args);
+    babelHelpers.classPrivateMethodInitSpec(this, _A_brand);
+  }
+}
+function _m() {
+  return babelHelpers.superPropGet(A, "m", this, 3)([]);
+}
@@ -0,0 +1,9 @@
+class C {
+  #field;
+  #method() {}
+  static #staticField;
+
+  test(obj) {
+    return [#field in obj, #method in obj, #staticField in obj];
+  }
+}
@@ -0,0 +1,17 @@
+var _field = new WeakMap();
+var _C_brand = new WeakSet();
+class C {
+	constructor() {
+		babelHelpers.classPrivateMethodInitSpec(this, _C_brand);
+		babelHelpers.classPrivateFieldInitSpec(this, _field, void 0);
+	}
+	test(obj) {
+		return [
+			_field.has(babelHelpers.checkInRHS(obj)),
+			_C_brand.has(babelHelpers.checkInRHS(obj)),
+			babelHelpers.checkInRHS(obj) === C
+		];
+	}
+}
+function _method() {}
+var _staticField = { _: void 0 };
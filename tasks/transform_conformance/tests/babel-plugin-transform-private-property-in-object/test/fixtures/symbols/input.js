@@ -0,0 +1,7 @@
+class C {
+  #field = 1;
+
+  test(obj) {
+    return #field in obj && obj.#field;
+  }
+}
@@ -0,0 +1,12 @@
+var _field = Symbol("field");
+class C {
+	constructor() {
+		Object.defineProperty(this, _field, {
+			writable: true,
+			value: 1
+		});
+	}
+	test(obj) {
+		return _field in babelHelpers.checkInRHS(obj) && obj[_field];
+	}
+}
@@ -0,0 +1,8 @@
+function* gen() {
+  yield this.value;
+  yield arguments.length;
+  const fn = function () {
+    return arguments;
+  };
+  const arrow = () => arguments[0];
+}
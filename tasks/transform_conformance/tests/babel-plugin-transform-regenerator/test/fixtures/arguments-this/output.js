@@ -0,0 +1,21 @@
+var _marked = babelHelpers.regeneratorRuntime().mark(gen);
+function gen() {
+	var fn, arrow, _args = arguments;
+	return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+		while (1) switch (_context.prev = _context.next) {
+			case 0:
+				_context.next = 2;
+				return this.value;
+			case 2:
+				_context.next = 4;
+				return _args.length;
+			case 4:
+				fn = function() {
+					return arguments;
+				};
+				arrow = () => _args[0];
+			case 6:
+			case "end": return _context.stop();
+		}
+	}, _marked, this);
+}
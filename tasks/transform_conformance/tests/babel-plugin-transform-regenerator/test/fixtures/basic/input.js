@@ -0,0 +1,5 @@
+function* gen() {
+  const x = yield 1;
+  console.log(x);
+  return x + 1;
+}
@@ -0,0 +1,25 @@
+function* gen(obj) {
+  const { a, b: [c, d = 2], ...rest } = obj;
+  let [x, , y = 4] = [yield a];
+  for (const [k, v] in rest) {
+    yield k + v;
+  }
+  for (var key in { p: 1 }) {
+    yield key;
+  }
+  class Point {
+    constructor(n) {
+      this.n = n;
+    }
+    get double() {
+      return new Point(this.n * 2);
+    }
+  }
+  const p = new Point(yield x);
+  try {
+    throw { message: "m", code: 3 };
+  } catch ({ message, code }) {
+    yield message + code;
+  }
+  return [a, c, d, y, p.double.n, Point.name];
+}
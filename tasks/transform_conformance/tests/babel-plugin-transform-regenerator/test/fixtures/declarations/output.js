@@ -0,0 +1,75 @@
+var _marked = babelHelpers.regeneratorRuntime().mark(gen);
+function gen(obj) {
+	var a, c, d, rest, x, y, k, v, key, Point, p, message, code;
+	return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+		while (1) switch (_context.prev = _context.next) {
+			case 0:
+				({a: a, b: [c, d = 2],...rest} = obj);
+				_context.next = 3;
+				return a;
+			case 3:
+				_context.t0 = _context.sent;
+				[x, , y = 4] = [_context.t0];
+				_context.t1 = babelHelpers.regeneratorRuntime().keys(rest);
+			case 6:
+				if ((_context.t2 = _context.t1()).done) {
+					_context.next = 12;
+					break;
+				}
+				[k, v] = _context.t2.value;
+				_context.next = 10;
+				return k + v;
+			case 10:
+				_context.next = 6;
+				break;
+			case 12: _context.t3 = babelHelpers.regeneratorRuntime().keys({ p: 1 });
+			case 13:
+				if ((_context.t4 = _context.t3()).done) {
+					_context.next = 19;
+					break;
+				}
+				key = _context.t4.value;
+				_context.next = 17;
+				return key;
+			case 17:
+				_context.next = 13;
+				break;
+			case 19:
+				Point = class {
+					constructor(n) {
+						this.n = n;
+					}
+					get double() {
+						return new Point(this.n * 2);
+					}
+				};
+				_context.t5 = Point;
+				_context.next = 23;
+				return x;
+			case 23:
+				_context.t6 = _context.sent;
+				p = new _context.t5(_context.t6);
+				_context.prev = 25;
+				throw {
+					message: "m",
+					code: 3
+				};
+			case 29:
+				_context.prev = 29;
+				_context.t7 = _context["catch"](25);
+				({message: message, code: code} = _context.t7);
+				_context.next = 34;
+				return message + code;
+			case 34: return _context.abrupt("return", [
+				a,
+				c,
+				d,
+				y,
+				p.double.n,
+				Point.name
+			]);
+			case 35:
+			case "end": return _context.stop();
+		}
+	}, _marked, null, [[25, 29]]);
+}
@@ -0,0 +1,8 @@
+function* inner() {
+  yield 1;
+}
+function* outer() {
+  const result = yield* inner();
+  yield* [2, 3];
+  return result;
+}
@@ -0,0 +1,3 @@
+export default function* () {
+  yield 1;
+}
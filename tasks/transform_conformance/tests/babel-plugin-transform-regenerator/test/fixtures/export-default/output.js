@@ -0,0 +1,12 @@
+var _marked = babelHelpers.regeneratorRuntime().mark(_callee);
+export default function _callee() {
+	return babelHelpers.regeneratorRuntime().wrap(function _callee$(_context) {
+		while (1) switch (_context.prev = _context.next) {
+			case 0:
+				_context.next = 2;
+				return 1;
+			case 2:
+			case "end": return _context.stop();
+		}
+	}, _marked);
+}
@@ -0,0 +1,25 @@
+class A {
+  #p = 1;
+  *gen(a, o) {
+    switch (a) {
+      case yield 1:
+        x();
+        break;
+      case 2:
+        y();
+      default:
+        z();
+    }
+    const n = (yield 2) ?? 3;
+    o.p += yield 3;
+    o[a] ||= yield 4;
+    o[yield 5]++;
+    const t = `a${yield 6}b`;
+    const i = #p in (yield 7);
+    const m = import(yield 8);
+    g(...(yield 9), 1);
+    this.#p(a, yield 10);
+    const obj = { [yield 11]: 1, ...(yield 12) };
+    o[yield 13] = 1;
+  }
+}
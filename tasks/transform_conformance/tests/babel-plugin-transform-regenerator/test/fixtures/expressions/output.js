@@ -0,0 +1,116 @@
+class A {
+	#p = 1;
+	gen() {
+		return babelHelpers.regeneratorRuntime().mark(function _callee(a, o) {
+			var n, t, i, m, obj;
+			return babelHelpers.regeneratorRuntime().wrap(function _callee$(_context) {
+				while (1) switch (_context.prev = _context.next) {
+					case 0:
+						_context.t0 = a;
+						_context.t1 = _context.t0;
+						_context.next = 4;
+						return 1;
+					case 4:
+						_context.t2 = _context.sent;
+						if (_context.t1 === _context.t2) {
+							_context.next = 9;
+							break;
+						}
+						if (_context.t0 === 2) {
+							_context.next = 11;
+							break;
+						}
+						_context.next = 12;
+						break;
+					case 9:
+						x();
+						return _context.abrupt("break", 13);
+					case 11: y();
+					case 12: z();
+					case 13:
+						_context.next = 15;
+						return 2;
+					case 15:
+						_context.t3 = _context.sent;
+						if (_context.t3 !== null && _context.t3 !== void 0) {
+							_context.next = 18;
+							break;
+						}
+						_context.t3 = 3;
+					case 18:
+						n = _context.t3;
+						_context.t4 = o;
+						_context.t5 = _context.t4.p;
+						_context.next = 23;
+						return 3;
+					case 23:
+						_context.t4.p = _context.t5 + _context.sent;
+						_context.t6 = o;
+						_context.t7 = a;
+						_context.t8 = _context.t6[_context.t7];
+						if (_context.t8) {
+							_context.next = 31;
+							break;
+						}
+						_context.next = 30;
+						return 4;
+					case 30: _context.t6[_context.t7] = _context.sent;
+					case 31:
+						_context.t9 = o;
+						_context.next = 34;
+						return 5;
+					case 34:
+						_context.t10 = _context.sent;
+						_context.t9[_context.t10]++;
+						_context.next = 38;
+						return 6;
+					case 38:
+						_context.t11 = _context.sent;
+						t = `a${_context.t11}b`;
+						_context.next = 42;
+						return 7;
+					case 42:
+						i = #p in _context.sent;
+						_context.next = 45;
+						return 8;
+					case 45:
+						_context.t12 = _context.sent;
+						m = import(_context.t12);
+						_context.t13 = g;
+						_context.next = 50;
+						return 9;
+					case 50:
+						_context.t14 = _context.sent;
+						(0, _context.t13)(..._context.t14, 1);
+						_context.t15 = this;
+						_context.t16 = a;
+						_context.next = 56;
+						return 10;
+					case 56:
+						_context.t17 = _context.sent;
+						_context.t15.#p.call(_context.t15, _context.t16, _context.t17);
+						_context.next = 60;
+						return 11;
+					case 60:
+						_context.t18 = _context.sent;
+						_context.next = 63;
+						return 12;
+					case 63:
+						_context.t19 = _context.sent;
+						obj = {
+							[_context.t18]: 1,
+							..._context.t19
+						};
+						_context.t20 = o;
+						_context.next = 68;
+						return 13;
+					case 68:
+						_context.t21 = _context.sent;
+						_context.t20[_context.t21] = 1;
+					case 70:
+					case "end": return _context.stop();
+				}
+			}, _callee, this);
+		}).apply(this, arguments);
+	}
+}
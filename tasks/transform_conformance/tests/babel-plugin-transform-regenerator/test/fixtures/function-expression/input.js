@@ -0,0 +1,6 @@
+const anonymous = function* () {
+  yield 1;
+};
+const named = function* named() {
+  yield named;
+};
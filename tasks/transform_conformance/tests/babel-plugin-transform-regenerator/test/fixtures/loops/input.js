@@ -0,0 +1,17 @@
+function* gen(n) {
+  for (let i = 0; i < n; i++) {
+    if (i % 2) continue;
+    yield i;
+  }
+  let j = 0;
+  while (j < n) {
+    if (j > 5) break;
+    yield j++;
+  }
+  do {
+    yield j;
+  } while (j--);
+  for (const key in obj) {
+    yield key;
+  }
+}
@@ -0,0 +1,64 @@
+var _marked = babelHelpers.regeneratorRuntime().mark(gen);
+function gen(n) {
+	var i, j, key;
+	return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+		while (1) switch (_context.prev = _context.next) {
+			case 0: i = 0;
+			case 1:
+				if (!(i < n)) {
+					_context.next = 9;
+					break;
+				}
+				if (!(i % 2)) {
+					_context.next = 4;
+					break;
+				}
+				return _context.abrupt("continue", 6);
+			case 4:
+				_context.next = 6;
+				return i;
+			case 6:
+				i++;
+				_context.next = 1;
+				break;
+			case 9: j = 0;
+			case 10:
+				if (!(j < n)) {
+					_context.next = 17;
+					break;
+				}
+				if (!(j > 5)) {
+					_context.next = 13;
+					break;
+				}
+				return _context.abrupt("break", 17);
+			case 13:
+				_context.next = 15;
+				return j++;
+			case 15:
+				_context.next = 10;
+				break;
+			case 17:
+				_context.next = 19;
+				return j;
+			case 19: if (j--) {
+				_context.next = 17;
+				break;
+			}
+			case 20: _context.t0 = babelHelpers.regeneratorRuntime().keys(obj);
+			case 21:
+				if ((_context.t1 = _context.t0()).done) {
+					_context.next = 27;
+					break;
+				}
+				key = _context.t1.value;
+				_context.next = 25;
+				return key;
+			case 25:
+				_context.next = 21;
+				break;
+			case 27:
+			case "end": return _context.stop();
+		}
+	}, _marked);
+}
@@ -0,0 +1,14 @@
+class Foo {
+  *gen(a) {
+    yield a;
+    yield this.b;
+  }
+  static *all() {
+    yield* arguments;
+  }
+}
+const obj = {
+  *gen() {
+    yield 1;
+  },
+};
@@ -0,0 +1,43 @@
+class Foo {
+	gen() {
+		return babelHelpers.regeneratorRuntime().mark(function _callee(a) {
+			return babelHelpers.regeneratorRuntime().wrap(function _callee$(_context) {
+				while (1) switch (_context.prev = _context.next) {
+					case 0:
+						_context.next = 2;
+						return a;
+					case 2:
+						_context.next = 4;
+						return this.b;
+					case 4:
+					case "end": return _context.stop();
+				}
+			}, _callee, this);
+		}).apply(this, arguments);
+	}
+	static all() {
+		return babelHelpers.regeneratorRuntime().mark(function _callee2() {
+			var _args = arguments;
+			return babelHelpers.regeneratorRuntime().wrap(function _callee2$(_context2) {
+				while (1) switch (_context2.prev = _context2.next) {
+					case 0: return _context2.delegateYield(_args, "t0", 1);
+					case 1:
+					case "end": return _context2.stop();
+				}
+			}, _callee2);
+		}).apply(this, arguments);
+	}
+}
+const obj = { gen() {
+	return babelHelpers.regeneratorRuntime().mark(function _callee3() {
+		return babelHelpers.regeneratorRuntime().wrap(function _callee3$(_context3) {
+			while (1) switch (_context3.prev = _context3.next) {
+				case 0:
+					_context3.next = 2;
+					return 1;
+				case 2:
+				case "end": return _context3.stop();
+			}
+		}, _callee3);
+	}).apply(this, arguments);
+} };
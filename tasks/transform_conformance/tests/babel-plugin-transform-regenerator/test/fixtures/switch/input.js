@@ -0,0 +1,11 @@
+function* gen(x) {
+  switch (yield x) {
+    case 1:
+      yield "one";
+      break;
+    case 2:
+      return;
+    default:
+      yield "other";
+  }
+}
@@ -0,0 +1,24 @@
+var _marked = babelHelpers.regeneratorRuntime().mark(gen);
+function gen(x) {
+	return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+		while (1) switch (_context.prev = _context.next) {
+			case 0:
+				_context.next = 2;
+				return x;
+			case 2:
+				_context.t0 = _context.sent;
+				_context.next = _context.t0 === 1 ? 5 : _context.t0 === 2 ? 8 : 9;
+				break;
+			case 5:
+				_context.next = 7;
+				return "one";
+			case 7: return _context.abrupt("break", 11);
+			case 8: return _context.abrupt("return");
+			case 9:
+				_context.next = 11;
+				return "other";
+			case 11:
+			case "end": return _context.stop();
+		}
+	}, _marked);
+}
@@ -0,0 +1,14 @@
+function* gen() {
+  try {
+    yield 1;
+  } catch (e) {
+    yield e;
+  } finally {
+    yield 2;
+  }
+  try {
+    yield 3;
+  } catch (err) {
+    console.log(err);
+  }
+}
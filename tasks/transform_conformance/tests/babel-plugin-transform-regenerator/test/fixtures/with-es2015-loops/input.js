@@ -0,0 +1,12 @@
+function* gen(items) {
+  for (const item of items) {
+    let doubled = item * 2;
+    yield () => doubled;
+  }
+  {
+    const x = 1;
+    yield x;
+  }
+  const x = 2;
+  yield x;
+}
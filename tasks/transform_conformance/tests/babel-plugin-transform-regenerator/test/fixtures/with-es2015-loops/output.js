@@ -0,0 +1,61 @@
+var _marked = babelHelpers.regeneratorRuntime().mark(gen);
+function gen(items) {
+	var _iterator, _step, _loop, _x, x;
+	return babelHelpers.regeneratorRuntime().wrap(function gen$(_context2) {
+		while (1) switch (_context2.prev = _context2.next) {
+			case 0:
+				_iterator = babelHelpers.createForOfIteratorHelper(items);
+				_context2.prev = 1;
+				_loop = babelHelpers.regeneratorRuntime().mark(function _callee() {
+					var item, doubled;
+					return babelHelpers.regeneratorRuntime().wrap(function _callee$(_context) {
+						while (1) switch (_context.prev = _context.next) {
+							case 0:
+								item = _step.value;
+								doubled = item * 2;
+								_context.next = 4;
+								return () => doubled;
+							case 4:
+							case "end": return _context.stop();
+						}
+					}, _callee);
+				});
+				_iterator.s();
+			case 4:
+				if ((_step = _iterator.n()).done) {
+					_context2.next = 8;
+					break;
+				}
+				return _context2.delegateYield(_loop(), "t0", 6);
+			case 6:
+				_context2.next = 4;
+				break;
+			case 8:
+				_context2.next = 13;
+				break;
+			case 10:
+				_context2.prev = 10;
+				_context2.t1 = _context2["catch"](1);
+				_iterator.e(_context2.t1);
+			case 13:
+				_context2.prev = 13;
+				_iterator.f();
+				return _context2.finish(13);
+			case 16:
+				_x = 1;
+				_context2.next = 19;
+				return _x;
+			case 19:
+				x = 2;
+				_context2.next = 22;
+				return x;
+			case 22:
+			case "end": return _context2.stop();
+		}
+	}, _marked, null, [[
+		1,
+		10,
+		13,
+		16
+	]]);
+}
@@ -0,0 +1,9 @@
+function* gen(a) {
+  let total = 0;
+  total += yield a;
+  total += yield;
+  foo(yield 1, yield 2);
+  obj.method(a, yield 3);
+  const result = [yield 4, { key: yield 5 }];
+  return a ? yield total : result;
+}
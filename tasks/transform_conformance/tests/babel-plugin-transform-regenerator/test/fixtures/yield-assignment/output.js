@@ -0,0 +1,61 @@
+var _marked = babelHelpers.regeneratorRuntime().mark(gen);
+function gen(a) {
+	var total, result;
+	return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+		while (1) switch (_context.prev = _context.next) {
+			case 0:
+				total = 0;
+				_context.t0 = total;
+				_context.next = 4;
+				return a;
+			case 4:
+				total = _context.t0 + _context.sent;
+				_context.t1 = total;
+				_context.next = 8;
+				return;
+			case 8:
+				total = _context.t1 + _context.sent;
+				_context.t2 = foo;
+				_context.next = 12;
+				return 1;
+			case 12:
+				_context.t3 = _context.sent;
+				_context.next = 15;
+				return 2;
+			case 15:
+				_context.t4 = _context.sent;
+				(0, _context.t2)(_context.t3, _context.t4);
+				_context.t5 = obj;
+				_context.t6 = a;
+				_context.next = 21;
+				return 3;
+			case 21:
+				_context.t7 = _context.sent;
+				_context.t5.method.call(_context.t5, _context.t6, _context.t7);
+				_context.next = 25;
+				return 4;
+			case 25:
+				_context.t8 = _context.sent;
+				_context.next = 28;
+				return 5;
+			case 28:
+				_context.t9 = _context.sent;
+				_context.t10 = { key: _context.t9 };
+				result = [_context.t8, _context.t10];
+				if (!a) {
+					_context.next = 37;
+					break;
+				}
+				_context.next = 34;
+				return total;
+			case 34:
+				_context.t11 = _context.sent;
+				_context.next = 38;
+				break;
+			case 37: _context.t11 = result;
+			case 38: return _context.abrupt("return", _context.t11);
+			case 39:
+			case "end": return _context.stop();
+		}
+	}, _marked);
+}
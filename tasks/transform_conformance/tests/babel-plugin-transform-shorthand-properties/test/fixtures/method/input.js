@@ -0,0 +1,10 @@
+var obj = {
+  foo() {
+    return 1;
+  },
+  bar() {
+    return super.bar();
+  },
+  *gen() {},
+  async baz() {},
+};
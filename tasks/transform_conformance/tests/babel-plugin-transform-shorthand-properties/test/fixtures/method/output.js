@@ -0,0 +1,10 @@
+var obj = {
+	foo: function() {
+		return 1;
+	},
+	bar() {
+		return super.bar();
+	},
+	gen: function* () {},
+	baz: async function() {}
+};
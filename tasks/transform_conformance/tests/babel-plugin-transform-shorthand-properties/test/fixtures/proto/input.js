@@ -0,0 +1,2 @@
+var __proto__ = null;
+var obj = { __proto__ };
@@ -0,0 +1,3 @@
+var a = [...b];
+var c = [1, ...d, 2, ...e];
+var f = [...arguments];
@@ -0,0 +1,7 @@
+f(...args);
+f(1, ...args, 2);
+obj.method(...args);
+a.b.c(...args);
+function g() {
+  return f(...arguments);
+}
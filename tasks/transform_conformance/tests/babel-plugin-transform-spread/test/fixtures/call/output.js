@@ -0,0 +1,8 @@
+var _obj, _a$b;
+f.apply(void 0, babelHelpers.toConsumableArray(args));
+f.apply(void 0, [1].concat(babelHelpers.toConsumableArray(args), [2]));
+(_obj = obj).method.apply(_obj, babelHelpers.toConsumableArray(args));
+(_a$b = a.b).c.apply(_a$b, babelHelpers.toConsumableArray(args));
+function g() {
+	return f.apply(void 0, arguments);
+}
@@ -0,0 +1,3 @@
+var a = [...b];
+var c = [...d, ...e];
+f(...args);
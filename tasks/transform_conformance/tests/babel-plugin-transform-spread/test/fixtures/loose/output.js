@@ -0,0 +1,3 @@
+var a = [].concat(b);
+var c = [].concat(d, e);
+f.apply(void 0, args);
@@ -0,0 +1,2 @@
+new Foo(...args);
+new Foo(1, ...args);
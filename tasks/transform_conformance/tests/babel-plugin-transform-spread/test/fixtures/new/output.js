@@ -0,0 +1,2 @@
+babelHelpers.construct(Foo, babelHelpers.toConsumableArray(args));
+babelHelpers.construct(Foo, [1].concat(babelHelpers.toConsumableArray(args)));
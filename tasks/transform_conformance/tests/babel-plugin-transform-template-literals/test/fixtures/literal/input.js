@@ -0,0 +1,5 @@
+var a = `foo`;
+var b = `foo${bar}`;
+var c = `${foo}bar${baz}`;
+var d = `foo${bar}baz${qux}x`;
+var e = `${foo}`;
@@ -0,0 +1,5 @@
+var a = "foo";
+var b = "foo".concat(bar);
+var c = "".concat(foo, "bar").concat(baz);
+var d = "foo".concat(bar, "baz").concat(qux, "x");
+var e = "".concat(foo);
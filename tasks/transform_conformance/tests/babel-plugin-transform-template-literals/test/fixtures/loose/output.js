@@ -0,0 +1,3 @@
+var a = "foo" + bar + "baz" + qux;
+var b = foo + "bar";
+var c = "" + foo;
@@ -0,0 +1,5 @@
+tag`foo${bar}baz`;
+tag`\unicode and \u{55}`;
+function f() {
+  return tag`x`;
+}
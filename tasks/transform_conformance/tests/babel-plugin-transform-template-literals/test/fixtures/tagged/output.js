@@ -0,0 +1,6 @@
+var _templateObject, _templateObject2, _templateObject3;
+tag(_templateObject || (_templateObject = babelHelpers.taggedTemplateLiteral(["foo", "baz"])), bar);
+tag(_templateObject2 || (_templateObject2 = babelHelpers.taggedTemplateLiteral([void 0], ["\\unicode and \\u{55}"])));
+function f() {
+	return tag(_templateObject3 || (_templateObject3 = babelHelpers.taggedTemplateLiteral(["x"])));
+}
@@ -0,0 +1,44 @@
+import { Injectable, Inject } from "di";
+import { Service } from "./service";
+import type { Config } from "./config";
+import * as ns from "./ns";
+
+enum Kind {
+  A,
+}
+
+class Local {}
+
+@Injectable()
+export class Foo {
+  constructor(
+    @Inject("token") private readonly service: Service,
+    config: Config,
+    local: Local,
+    value?: string,
+  ) {}
+
+  @Prop() name: string;
+  @Prop() count: number | undefined;
+  @Prop() items: string[];
+  @Prop() kind: Kind;
+  @Prop() nested: ns.Nested;
+  @Prop() union: string | number;
+  @Prop() any: any;
+
+  @Method()
+  method(a: number, b: boolean, ...rest: string[]): void {}
+
+  @Method()
+  async asyncMethod(): Promise<Local> {
+    return new Local();
+  }
+
+  @Accessor()
+  get value(): string {
+    return "";
+  }
+
+  @Accessor()
+  set value(v: string) {}
+}
@@ -0,0 +1,71 @@
+import { Injectable, Inject } from "di";
+import { Service } from "./service";
+import * as ns from "./ns";
+var Kind = function(Kind) {
+	Kind[Kind["A"] = 0] = "A";
+	return Kind;
+}(Kind || {});
+class Local {}
+export let Foo = class {
+	constructor(service, config, local, value) {
+		this.service = service;
+	}
+	name;
+	count;
+	items;
+	kind;
+	nested;
+	union;
+	any;
+	method(a, b, ...rest) {}
+	async asyncMethod() {
+		return new Local();
+	}
+	get value() {
+		return "";
+	}
+	set value(v) {}
+};
+babelHelpers.decorate([Prop(), babelHelpers.decorateMetadata("design:type", String)], Foo.prototype, "name", void 0);
+babelHelpers.decorate([Prop(), babelHelpers.decorateMetadata("design:type", Number)], Foo.prototype, "count", void 0);
+babelHelpers.decorate([Prop(), babelHelpers.decorateMetadata("design:type", Array)], Foo.prototype, "items", void 0);
+babelHelpers.decorate([Prop(), babelHelpers.decorateMetadata("design:type", Object)], Foo.prototype, "kind", void 0);
+babelHelpers.decorate([Prop(), babelHelpers.decorateMetadata("design:type", typeof ns === "undefined" ? Object : ns.Nested)], Foo.prototype, "nested", void 0);
+babelHelpers.decorate([Prop(), babelHelpers.decorateMetadata("design:type", Object)], Foo.prototype, "union", void 0);
+babelHelpers.decorate([Prop(), babelHelpers.decorateMetadata("design:type", Object)], Foo.prototype, "any", void 0);
+babelHelpers.decorate([
+	Method(),
+	babelHelpers.decorateMetadata("design:type", Function),
+	babelHelpers.decorateMetadata("design:paramtypes", [
+		Number,
+		Boolean,
+		String
+	]),
+	babelHelpers.decorateMetadata("design:returntype", void 0)
+], Foo.prototype, "method", null);
+babelHelpers.decorate([
+	Method(),
+	babelHelpers.decorateMetadata("design:type", Function),
+	babelHelpers.decorateMetadata("design:paramtypes", []),
+	babelHelpers.decorateMetadata("design:returntype", typeof Promise === "undefined" ? Object : Promise)
+], Foo.prototype, "asyncMethod", null);
+babelHelpers.decorate([
+	Accessor(),
+	babelHelpers.decorateMetadata("design:type", String),
+	babelHelpers.decorateMetadata("design:paramtypes", [])
+], Foo.prototype, "value", null);
+babelHelpers.decorate([
+	Accessor(),
+	babelHelpers.decorateMetadata("design:type", String),
+	babelHelpers.decorateMetadata("design:paramtypes", [String])
+], Foo.prototype, "value", null);
+Foo = babelHelpers.decorate([
+	Injectable(),
+	babelHelpers.decorateParam(0, Inject("token")),
+	babelHelpers.decorateMetadata("design:paramtypes", [
+		typeof Service === "undefined" ? Object : Service,
+		Object,
+		Local,
+		String
+	])
+], Foo);
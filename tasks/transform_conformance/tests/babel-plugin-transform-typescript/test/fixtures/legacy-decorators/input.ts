@@ -0,0 +1,9 @@
+@Component({ selector: "app" })
+export default class AppComponent {
+  @Input() title: string;
+
+  constructor(@Inject(TOKEN) private token: string) {}
+
+  @HostListener("click", ["$event"])
+  onClick(event: Event) {}
+}
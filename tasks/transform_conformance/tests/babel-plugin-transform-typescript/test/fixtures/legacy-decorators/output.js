@@ -0,0 +1,11 @@
+let AppComponent = class {
+	title;
+	constructor(token) {
+		this.token = token;
+	}
+	onClick(event) {}
+};
+babelHelpers.decorate([Input()], AppComponent.prototype, "title", void 0);
+babelHelpers.decorate([HostListener("click", ["$event"])], AppComponent.prototype, "onClick", null);
+AppComponent = babelHelpers.decorate([Component({ selector: "app" }), babelHelpers.decorateParam(0, Inject(TOKEN))], AppComponent);
+export default AppComponent;
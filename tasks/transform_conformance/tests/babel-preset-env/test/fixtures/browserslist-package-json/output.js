@@ -0,0 +1 @@
+Math.pow(a, b);
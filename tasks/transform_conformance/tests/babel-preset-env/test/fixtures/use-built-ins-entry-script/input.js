@@ -0,0 +1,2 @@
+require("core-js/es/array/index.js");
+require("foo");
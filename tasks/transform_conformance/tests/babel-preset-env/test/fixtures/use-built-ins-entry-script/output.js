@@ -0,0 +1,9 @@
+require("core-js/modules/es.array.at.js");
+require("core-js/modules/es.array.find-last.js");
+require("core-js/modules/es.array.find-last-index.js");
+require("core-js/modules/es.array.push.js");
+require("core-js/modules/es.array.to-reversed.js");
+require("core-js/modules/es.array.to-sorted.js");
+require("core-js/modules/es.array.to-spliced.js");
+require("core-js/modules/es.array.with.js");
+require("foo");
@@ -0,0 +1,4 @@
+import "core-js/stable";
+import foo from "foo";
+
+foo();
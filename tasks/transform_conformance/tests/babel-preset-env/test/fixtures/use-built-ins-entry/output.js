@@ -0,0 +1,20 @@
+import "core-js/modules/es.array.push.js";
+import "core-js/modules/es.array.to-reversed.js";
+import "core-js/modules/es.array.to-sorted.js";
+import "core-js/modules/es.array.to-spliced.js";
+import "core-js/modules/es.array.with.js";
+import "core-js/modules/es.regexp.flags.js";
+import "core-js/modules/es.string.is-well-formed.js";
+import "core-js/modules/es.string.to-well-formed.js";
+import "core-js/modules/es.typed-array.to-reversed.js";
+import "core-js/modules/es.typed-array.to-sorted.js";
+import "core-js/modules/es.typed-array.with.js";
+import "core-js/modules/web.dom-exception.stack.js";
+import "core-js/modules/web.immediate.js";
+import "core-js/modules/web.structured-clone.js";
+import "core-js/modules/web.url.can-parse.js";
+import "core-js/modules/web.url-search-params.delete.js";
+import "core-js/modules/web.url-search-params.has.js";
+import "core-js/modules/web.url-search-params.size.js";
+import foo from "foo";
+foo();
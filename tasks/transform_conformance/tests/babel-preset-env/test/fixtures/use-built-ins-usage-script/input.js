@@ -0,0 +1,2 @@
+const values = Object.values(obj);
+const s = "abc".padStart(5);
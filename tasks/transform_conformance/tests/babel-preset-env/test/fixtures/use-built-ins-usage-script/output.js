@@ -0,0 +1,4 @@
+require("core-js/modules/es.object.values.js");
+require("core-js/modules/es.string.pad-start.js");
+const values = Object.values(obj);
+const s = "abc".padStart(5);
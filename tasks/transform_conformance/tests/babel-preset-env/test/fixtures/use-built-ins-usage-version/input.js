@@ -0,0 +1,3 @@
+const a = [1, 2].at(-1);
+const o = Object.hasOwn({}, "a");
+const f = [[1]].flat();
@@ -0,0 +1,5 @@
+import "core-js/modules/es.array.flat.js";
+import "core-js/modules/es.array.unscopables.flat.js";
+const a = [1, 2].at(-1);
+const o = Object.hasOwn({}, "a");
+const f = [[1]].flat();
@@ -0,0 +1,10 @@
+const p = Promise.resolve([1, 2].includes(1));
+const entries = Object.entries({ a: 1 });
+const [first] = entries;
+for (const x of new Set([1])) {}
+async function f() {
+  return globalThis;
+}
+function g(Promise) {
+  return Promise.all([]);
+}
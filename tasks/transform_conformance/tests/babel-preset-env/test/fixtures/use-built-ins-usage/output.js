@@ -0,0 +1,14 @@
+import "core-js/modules/es.promise.js";
+import "core-js/modules/es.array.iterator.js";
+import "core-js/modules/web.dom-collections.iterator.js";
+import "core-js/modules/es.global-this.js";
+const p = Promise.resolve([1, 2].includes(1));
+const entries = Object.entries({ a: 1 });
+const [first] = entries;
+for (const x of new Set([1])) {}
+async function f() {
+  return globalThis;
+}
+function g(Promise) {
+  return Promise.all([]);
+}
@@ -0,0 +1,4 @@
+const a = <Badge count={count()} label="a &amp; b" onClick={() => select(id)} />;
+const b = <Comp {...props} a={1} b={x.y}><p>{z}</p>text</Comp>;
+const c = <Show when={visible()} fallback={<Loading />}>{items().length}</Show>;
+const d = <ui.Button>{label}</ui.Button>;
@@ -0,0 +1,34 @@
+import { createComponent as _createComponent, insert as _insert, template as _template, mergeProps as _mergeProps } from "solid-js/web";
+var _tmpl$ = _template("<p></p>");
+const a = _createComponent(Badge, {
+	get count() {
+		return count();
+	},
+	label: "a & b",
+	onClick: () => select(id)
+});
+const b = _createComponent(Comp, _mergeProps(props, {
+	a: 1,
+	get b() {
+		return x.y;
+	},
+	get children() {
+		return [(() => {
+			var _el$ = _tmpl$();
+			_insert(_el$, z);
+			return _el$;
+		})(), "text"];
+	}
+}));
+const c = _createComponent(Show, {
+	get when() {
+		return visible();
+	},
+	get fallback() {
+		return _createComponent(Loading, {});
+	},
+	get children() {
+		return items().length;
+	}
+});
+const d = _createComponent(ui.Button, { children: label });
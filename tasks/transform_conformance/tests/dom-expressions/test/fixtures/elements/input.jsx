@@ -0,0 +1,9 @@
+const view = (
+  <div class="card" id={id()} onClick={select}>
+    Hello {name()}!
+    <span title={"x"} tabIndex={1}>static &lt; text</span>
+    <input value={value()} disabled ref={input} />
+    <label htmlFor="name" className={active() ? "on" : "off"} style={styles()} />
+    <p {...rest} data-id={item.id}>{text}</p>
+  </div>
+);
@@ -0,0 +1,16 @@
+import { setAttribute as _setAttribute, effect as _effect, use as _use, className as _className, style as _style, spread as _spread, insert as _insert, template as _template } from "solid-js/web";
+var _tmpl$ = _template("<div class=\"card\">Hello <!>!<span title=\"x\" tabIndex=\"1\">static &lt; text</span><input disabled><label for=\"name\"></label><p></p></div>");
+const view = (() => {
+	var _el$ = _tmpl$(), _el$2 = _el$.firstChild, _el$3 = _el$2.nextSibling, _el$4 = _el$3.nextSibling, _el$5 = _el$4.nextSibling, _el$6 = _el$5.nextSibling, _el$7 = _el$6.nextSibling, _el$8 = _el$7.nextSibling;
+	_effect(() => _setAttribute(_el$, "id", id()));
+	_el$.addEventListener("click", select);
+	_effect(() => _el$6.value = value());
+	typeof input === "function" ? _use(input, _el$6) : input = _el$6;
+	_effect(() => _className(_el$7, active() ? "on" : "off"));
+	_effect(() => _style(_el$7, styles()));
+	_spread(_el$8, rest, false, true);
+	_effect(() => _setAttribute(_el$8, "data-id", item.id));
+	_insert(_el$8, text);
+	_insert(_el$, () => name(), _el$3);
+	return _el$;
+})();
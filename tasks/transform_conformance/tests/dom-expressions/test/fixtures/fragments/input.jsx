@@ -0,0 +1,3 @@
+const a = <>a{b()}<br />{c}</>;
+const b = <><Child /></>;
+const c = <div><>{a()}</></div>;
@@ -0,0 +1,15 @@
+import { template as _template, createComponent as _createComponent, insert as _insert } from "solid-js/web";
+var _tmpl$ = _template("<br>");
+var _tmpl$2 = _template("<div></div>");
+const a = [
+	"a",
+	() => b(),
+	_tmpl$(),
+	c
+];
+const b = _createComponent(Child, {});
+const c = (() => {
+	var _el$ = _tmpl$2();
+	_insert(_el$, () => a());
+	return _el$;
+})();
@@ -0,0 +1 @@
+const a = <div>{a()}</div>;
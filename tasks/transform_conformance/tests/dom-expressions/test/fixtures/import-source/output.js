@@ -0,0 +1,7 @@
+import { insert as _insert, template as _template } from "dom-expressions/src/client";
+var _tmpl$ = _template("<div></div>");
+const a = (() => {
+	var _el$ = _tmpl$();
+	_insert(_el$, () => a());
+	return _el$;
+})();
@@ -0,0 +1 @@
+const a = <div id={id()}>{text()}</div>;
@@ -0,0 +1,8 @@
+var _domExpressions = require("solid-js/web");
+var _tmpl$ = _domExpressions.template("<div></div>");
+const a = (() => {
+	var _el$ = _tmpl$();
+	_domExpressions.effect(() => _domExpressions.setAttribute(_el$, "id", id()));
+	_domExpressions.insert(_el$, () => text());
+	return _el$;
+})();
@@ -0,0 +1,4 @@
+const a = <ul><li>1</li><li>2</li></ul>;
+const b = <ul><li>1</li><li>2</li></ul>;
+const c = <div><span>{a()}</span><b /></div>;
+const d = <p>"quotes" &amp; <i title='"'>entities</i></p>;
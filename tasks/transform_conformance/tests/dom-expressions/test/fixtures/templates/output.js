@@ -0,0 +1,12 @@
+import { template as _template, insert as _insert } from "solid-js/web";
+var _tmpl$ = _template("<ul><li>1</li><li>2</li></ul>");
+var _tmpl$2 = _template("<div><span></span><b></b></div>");
+var _tmpl$3 = _template("<p>\"quotes\" &amp; <i title=\"&quot;\">entities</i></p>");
+const a = _tmpl$();
+const b = _tmpl$();
+const c = (() => {
+	var _el$ = _tmpl$2(), _el$2 = _el$.firstChild;
+	_insert(_el$2, () => a());
+	return _el$;
+})();
+const d = _tmpl$3();
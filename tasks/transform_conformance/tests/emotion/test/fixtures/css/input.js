@@ -0,0 +1,12 @@
+import { css, keyframes } from "@emotion/react";
+
+const container = css`
+  color: ${color};
+  padding: 0;
+`;
+
+const object = css({ color: "red" });
+
+const fade = keyframes`
+  from { opacity: 0; }
+`;
@@ -0,0 +1,4 @@
+import { css, keyframes } from "@emotion/react";
+const container = css("color:", color, ";padding:0;label:container;");
+const object = css({ color: "red" }, "label:object;");
+const fade = keyframes("from{opacity:0;}label:fade;");
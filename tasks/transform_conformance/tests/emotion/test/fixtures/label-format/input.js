@@ -0,0 +1,5 @@
+import { css } from "@emotion/css";
+
+const container = css`
+  color: red;
+`;
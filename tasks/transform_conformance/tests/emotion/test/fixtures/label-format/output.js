@@ -0,0 +1,2 @@
+import { css } from "@emotion/css";
+const container = css("color:red;label:input--container;");
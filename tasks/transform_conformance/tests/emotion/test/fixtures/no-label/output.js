@@ -0,0 +1,3 @@
+import { css, injectGlobal } from "@emotion/css";
+const container = css("color:red;");
+injectGlobal("body{margin:0;}");
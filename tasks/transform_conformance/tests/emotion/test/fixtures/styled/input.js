@@ -0,0 +1,12 @@
+import styled from "@emotion/styled";
+import { Link } from "./link";
+
+const Button = styled.button`
+  color: ${(props) => props.color};
+`;
+
+const StyledLink = styled(Link)`
+  color: red;
+`;
+
+const Box = styled.div({ display: "flex" });
@@ -0,0 +1,14 @@
+import styled from "@emotion/styled";
+import { Link } from "./link";
+const Button = styled("button", {
+  target: "e307ycy0",
+  label: "Button"
+})("color:", (props) => props.color, ";");
+const StyledLink = styled(Link, {
+  target: "e307ycy1",
+  label: "StyledLink"
+})("color:red;");
+const Box = styled("div", {
+  target: "e307ycy2",
+  label: "Box"
+})({ display: "flex" });
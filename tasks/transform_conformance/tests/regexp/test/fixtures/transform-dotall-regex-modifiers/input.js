@@ -0,0 +1,3 @@
+a1 = /a.b/sm
+a2 = /[.]./s
+a3 = /a(?:.|\n)b/s
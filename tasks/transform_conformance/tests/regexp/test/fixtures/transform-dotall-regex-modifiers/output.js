@@ -0,0 +1,3 @@
+a1 = /a[\s\S]b/m;
+a2 = /[.][\s\S]/;
+a3 = /a(?:[\s\S]|\n)b/;
@@ -1 +1 @@
-a1 = new RegExp("a.b", "s");
+a1 = /a[\s\S]b/;
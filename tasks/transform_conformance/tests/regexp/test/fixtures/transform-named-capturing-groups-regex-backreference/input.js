@@ -0,0 +1,2 @@
+c1 = /(?<year>\d{4})-(?<month>\d{2})-\k<month>/
+c2 = /(?<a>.)\k<a>(?<b>(?<c>x)\k<c>)/s
@@ -0,0 +1,9 @@
+c1 = babelHelpers.wrapRegExp(/(\d{4})-(\d{2})-\2/, {
+  year: 1,
+  month: 2
+});
+c2 = babelHelpers.wrapRegExp(/([\s\S])\1((x)\3)/, {
+  a: 1,
+  b: 2,
+  c: 3
+});
@@ -0,0 +1,3 @@
+d1 = /[\p{ASCII_Hex_Digit}_]/u
+d2 = /\P{ASCII}/u
+d3 = /[^\p{ASCII_Hex_Digit}]/iu
@@ -0,0 +1,3 @@
+d1 = /[0-9A-Fa-f_]/u;
+d2 = /[\u0080-\u{10FFFF}]/u;
+d3 = /[^0-9A-Fa-f]/iu;
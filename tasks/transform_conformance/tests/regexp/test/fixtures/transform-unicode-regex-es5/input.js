@@ -0,0 +1,8 @@
+const a = /\p{Script=Greek}/u;
+const b = /[[a-z]&&[aeiou]]/v;
+const c = /😀+/u;
+const d = /[😀-😂]/u;
+const e = /^.$/u;
+const f = /[^a]/u;
+const g = /\u{1F600}|\uD83D/u;
+const h = /😀/iu;
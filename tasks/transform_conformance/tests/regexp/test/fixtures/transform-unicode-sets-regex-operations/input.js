@@ -0,0 +1,5 @@
+g1 = /[\p{ASCII_Hex_Digit}--[a-f]]/v
+g2 = /[[a-z]&&[^aeiou]]+/v
+g3 = /[\q{x|y}\d]/v
+g4 = /[\p{RGI_Emoji}]/v
+g5 = /[[a-z]--x]/vi
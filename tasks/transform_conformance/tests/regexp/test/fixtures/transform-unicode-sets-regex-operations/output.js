@@ -0,0 +1,5 @@
+g1 = /[0-9A-F]/u;
+g2 = /[b-df-hj-np-tv-z]+/u;
+g3 = /[0-9x-y]/u;
+g4 = new RegExp("[\\p{RGI_Emoji}]", "v");
+g5 = new RegExp("[[a-z]--x]", "iv");
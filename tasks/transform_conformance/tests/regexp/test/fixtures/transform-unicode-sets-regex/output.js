@@ -1 +1 @@
-g1 = new RegExp("[\\p{White_Space}&&\\p{ASCII}]", "v");
+g1 = /[\u0009-\u000D\u0020]/u;
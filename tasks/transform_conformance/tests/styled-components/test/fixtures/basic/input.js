@@ -0,0 +1,17 @@
+import styled from "styled-components";
+
+const Button = styled.button`
+  color: ${(props) => props.color};
+  /* comment */
+  padding: 4px  8px;
+
+  &:hover {
+    color: blue;
+  }
+`;
+
+const Title = styled.h1({ fontSize: 12 });
+
+export default styled.div`
+  margin: 0;
+`;
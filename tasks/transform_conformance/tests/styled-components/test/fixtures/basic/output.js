@@ -0,0 +1,13 @@
+import styled from "styled-components";
+const Button = styled.button.withConfig({
+  displayName: "input__Button",
+  componentId: "sc-1g3bse2-0"
+})`color:${(props) => props.color};padding:4px 8px;&:hover{color:blue;}`;
+const Title = styled.h1.withConfig({
+  displayName: "input__Title",
+  componentId: "sc-1g3bse2-1"
+})({ fontSize: 12 });
+export default styled.div.withConfig({
+  displayName: "input",
+  componentId: "sc-1g3bse2-2"
+})`margin:0;`;
@@ -0,0 +1,2 @@
+import styled from "styled-components";
+const Button = styled.button.withConfig({ displayName: "Button" })`color:red;`;
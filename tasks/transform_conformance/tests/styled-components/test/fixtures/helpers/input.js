@@ -0,0 +1,17 @@
+import { createGlobalStyle, css, keyframes } from "styled-components";
+
+const mixin = css`
+  color: red; // line comment
+  background: url(http://example.com/a.png);
+`;
+
+const GlobalStyle = createGlobalStyle`
+  body {
+    margin: 0;
+  }
+`;
+
+const fadeIn = keyframes`
+  from { opacity: 0; }
+  to { opacity: 1; }
+`;
@@ -0,0 +1,11 @@
+import styled from "other-library";
+
+const Button = styled.button`
+  color: red;
+`;
+
+function f(styled) {
+  return styled.div`
+    color: red;
+  `;
+}
@@ -0,0 +1,4 @@
+import styled from "styled-components";
+const Button = styled.button.withConfig({ componentId: "lib__sc-10c91px-0" })`
+  color: red;
+`;
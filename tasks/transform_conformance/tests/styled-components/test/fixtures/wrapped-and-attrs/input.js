@@ -0,0 +1,16 @@
+import styled from "styled-components";
+import { Link } from "./link";
+
+const StyledLink = styled(Link)`
+  color: red;
+`;
+
+const Input = styled.input.attrs({ type: "text" })`
+  border: none;
+`;
+
+const components = {
+  Box: styled.div`
+    display: flex;
+  `,
+};
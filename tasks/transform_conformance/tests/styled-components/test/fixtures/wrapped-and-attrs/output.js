@@ -0,0 +1,14 @@
+import styled from "styled-components";
+import { Link } from "./link";
+const StyledLink = styled(Link).withConfig({
+  displayName: "input__StyledLink",
+  componentId: "sc-1w5igza-0"
+})`color:red;`;
+const Input = styled.input.withConfig({
+  displayName: "input__Input",
+  componentId: "sc-1w5igza-1"
+}).attrs({ type: "text" })`border:none;`;
+const components = { Box: styled.div.withConfig({
+  displayName: "input__Box",
+  componentId: "sc-1w5igza-2"
+})`display:flex;` };